@@ -1588,10 +1588,17 @@ fn file_change_counts(changes: &[codex_app_server_protocol::FileUpdateChange]) -
     let mut counts = FileChangeCounts::default();
     for change in changes {
         match &change.kind {
-            PatchChangeKind::Add => counts.add += 1,
+            PatchChangeKind::Add { .. }
+            | PatchChangeKind::AddSymlink { .. }
+            | PatchChangeKind::AddBinary { .. } => counts.add += 1,
             PatchChangeKind::Delete => counts.delete += 1,
-            PatchChangeKind::Update { move_path: Some(_) } => counts.move_ += 1,
-            PatchChangeKind::Update { move_path: None } => counts.update += 1,
+            PatchChangeKind::Update {
+                move_path: Some(_), ..
+            } => counts.move_ += 1,
+            PatchChangeKind::Update {
+                move_path: None, ..
+            }
+            | PatchChangeKind::SetMode { .. } => counts.update += 1,
         }
     }
     counts
@@ -2,4 +2,12 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type FileChange = { "type": "add", content: string, } | { "type": "delete", content: string, } | { "type": "update", unified_diff: string, move_path: string | null, };
+export type FileChange = { "type": "add", content: string,
+/**
+ * Set when the patch also changes the file's executable bit.
+ */
+executable?: boolean, } | { "type": "delete", content: string, } | { "type": "update", unified_diff: string, move_path: string | null,
+/**
+ * Set when the patch also changes the file's executable bit.
+ */
+executable?: boolean, } | { "type": "set_mode", executable: boolean, } | { "type": "add_symlink", target: string, } | { "type": "add_binary", size: number, };
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptArgument } from "./PromptArgument";
+
+/**
+ * A prompt or prompt template that the server offers.
+ */
+export type Prompt = { name: string, title?: string, description?: string, arguments?: Array<PromptArgument>, };
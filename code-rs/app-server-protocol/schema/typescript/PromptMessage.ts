@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptMessageRole } from "./PromptMessageRole";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A single message returned when rendering a prompt.
+ */
+export type PromptMessage = { role: PromptMessageRole, content: JsonValue, };
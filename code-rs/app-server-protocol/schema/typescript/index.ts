@@ -48,6 +48,10 @@ export type { NetworkPolicyRuleAction } from "./NetworkPolicyRuleAction";
 export type { ParsedCommand } from "./ParsedCommand";
 export type { Personality } from "./Personality";
 export type { PlanType } from "./PlanType";
+export type { Prompt } from "./Prompt";
+export type { PromptArgument } from "./PromptArgument";
+export type { PromptMessage } from "./PromptMessage";
+export type { PromptMessageRole } from "./PromptMessageRole";
 export type { RealtimeConversationVersion } from "./RealtimeConversationVersion";
 export type { RealtimeOutputModality } from "./RealtimeOutputModality";
 export type { RealtimeVoice } from "./RealtimeVoice";
@@ -2,4 +2,9 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ConfiguredHookHandler = { "type": "command", command: string, timeoutSec: bigint | null, async: boolean, statusMessage: string | null, } | { "type": "prompt", } | { "type": "agent", };
+export type ConfiguredHookHandler = { "type": "command", command: string, timeoutSec: bigint | null, async: boolean, statusMessage: string | null, } | { "type": "prompt", prompt: string, model: string | null, timeoutSec: bigint | null, statusMessage: string | null, } | { "type": "agent", prompt: string, model: string | null, timeoutSec: bigint | null, statusMessage: string | null, } | { "type": "http", url: string, 
+/**
+ * Names of the configured request headers. Values are omitted because
+ * they commonly carry credentials.
+ */
+headers: Array<string>, timeoutSec: bigint | null, retries: number | null, statusMessage: string | null, };
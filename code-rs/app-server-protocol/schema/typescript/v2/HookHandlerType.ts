@@ -2,4 +2,4 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type HookHandlerType = "command" | "prompt" | "agent";
+export type HookHandlerType = "command" | "prompt" | "agent" | "http";
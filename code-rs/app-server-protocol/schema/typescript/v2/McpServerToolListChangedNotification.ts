@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type McpServerToolListChangedNotification = { threadId: string, 
+/**
+ * Name of the MCP server whose tool list changed.
+ */
+name: string, 
+/**
+ * Names of the tools the server now exposes, after tool filters. The
+ * refreshed list is used from the thread's next turn.
+ */
+tools: Array<string>, };
@@ -2,4 +2,12 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type PatchChangeKind = { "type": "add" } | { "type": "delete" } | { "type": "update", move_path: string | null, };
+export type PatchChangeKind = { "type": "add",
+/**
+ * Set when the patch also changes the file's executable bit.
+ */
+executable?: boolean, } | { "type": "delete" } | { "type": "update", move_path: string | null,
+/**
+ * Set when the patch also changes the file's executable bit.
+ */
+executable?: boolean, } | { "type": "setMode", executable: boolean, } | { "type": "addSymlink", target: string, } | { "type": "addBinary", size: number, };
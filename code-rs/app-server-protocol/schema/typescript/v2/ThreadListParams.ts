@@ -51,4 +51,10 @@ useStateDbOnly?: boolean,
 /**
  * Optional substring filter for the extracted thread title.
  */
-searchTerm?: string | null, };
+searchTerm?: string | null,
+/**
+ * If true, match `searchTerm` against persisted thread content (messages,
+ * tool calls, and tool output) instead of the title, and report where
+ * each thread matched in `searchMatches`. Requires the state DB.
+ */
+searchContent?: boolean, };
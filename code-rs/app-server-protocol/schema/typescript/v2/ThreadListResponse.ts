@@ -2,6 +2,7 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Thread } from "./Thread";
+import type { ThreadSearchMatch } from "./ThreadSearchMatch";
 
 export type ThreadListResponse = { data: Array<Thread>,
 /**
@@ -15,4 +16,9 @@ nextCursor: string | null,
  * Use it with the opposite `sortDirection`; for timestamp sorts it anchors
  * at the start of the page timestamp so same-second updates are not skipped.
  */
-backwardsCursor: string | null, };
+backwardsCursor: string | null,
+/**
+ * Where each thread in `data` matched when `searchContent` was set, in the
+ * same order as `data`.
+ */
+searchMatches?: Array<ThreadSearchMatch>, };
@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ThreadSearchMatch = { threadId: string,
+/**
+ * Turn containing the best-ranked match, if known.
+ */
+turnId: string | null,
+/**
+ * Plain-text excerpt around the match.
+ */
+snippet: string, };
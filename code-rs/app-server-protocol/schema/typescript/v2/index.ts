@@ -213,13 +213,21 @@ export type { McpServerMigration } from "./McpServerMigration";
 export type { McpServerOauthLoginCompletedNotification } from "./McpServerOauthLoginCompletedNotification";
 export type { McpServerOauthLoginParams } from "./McpServerOauthLoginParams";
 export type { McpServerOauthLoginResponse } from "./McpServerOauthLoginResponse";
+export type { McpServerPromptGetParams } from "./McpServerPromptGetParams";
+export type { McpServerPromptGetResponse } from "./McpServerPromptGetResponse";
+export type { McpServerPromptListChangedNotification } from "./McpServerPromptListChangedNotification";
+export type { McpServerPromptListParams } from "./McpServerPromptListParams";
+export type { McpServerPromptListResponse } from "./McpServerPromptListResponse";
+export type { McpServerPrompts } from "./McpServerPrompts";
 export type { McpServerRefreshResponse } from "./McpServerRefreshResponse";
+export type { McpServerResourceListChangedNotification } from "./McpServerResourceListChangedNotification";
 export type { McpServerStartupState } from "./McpServerStartupState";
 export type { McpServerStatus } from "./McpServerStatus";
 export type { McpServerStatusDetail } from "./McpServerStatusDetail";
 export type { McpServerStatusUpdatedNotification } from "./McpServerStatusUpdatedNotification";
 export type { McpServerToolCallParams } from "./McpServerToolCallParams";
 export type { McpServerToolCallResponse } from "./McpServerToolCallResponse";
+export type { McpServerToolListChangedNotification } from "./McpServerToolListChangedNotification";
 export type { McpToolCallError } from "./McpToolCallError";
 export type { McpToolCallProgressNotification } from "./McpToolCallProgressNotification";
 export type { McpToolCallResult } from "./McpToolCallResult";
@@ -392,6 +400,7 @@ export type { ThreadResumeParams } from "./ThreadResumeParams";
 export type { ThreadResumeResponse } from "./ThreadResumeResponse";
 export type { ThreadRollbackParams } from "./ThreadRollbackParams";
 export type { ThreadRollbackResponse } from "./ThreadRollbackResponse";
+export type { ThreadSearchMatch } from "./ThreadSearchMatch";
 export type { ThreadSetNameParams } from "./ThreadSetNameParams";
 export type { ThreadSetNameResponse } from "./ThreadSetNameResponse";
 export type { ThreadShellCommandParams } from "./ThreadShellCommandParams";
@@ -849,6 +849,18 @@ client_request_definitions! {
         response: v2::McpServerToolCallResponse,
     },
 
+    McpServerPromptList => "mcpServer/prompt/list" {
+        params: v2::McpServerPromptListParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::McpServerPromptListResponse,
+    },
+
+    McpServerPromptGet => "mcpServer/prompt/get" {
+        params: v2::McpServerPromptGetParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::McpServerPromptGetResponse,
+    },
+
     WindowsSandboxSetupStart => "windowsSandbox/setupStart" {
         params: v2::WindowsSandboxSetupStartParams,
         serialization: global("windows-sandbox-setup"),
@@ -1466,6 +1478,9 @@ server_notification_definitions! {
     McpToolCallProgress => "item/mcpToolCall/progress" (v2::McpToolCallProgressNotification),
     McpServerOauthLoginCompleted => "mcpServer/oauthLogin/completed" (v2::McpServerOauthLoginCompletedNotification),
     McpServerStatusUpdated => "mcpServer/startupStatus/updated" (v2::McpServerStatusUpdatedNotification),
+    McpServerPromptListChanged => "mcpServer/promptList/changed" (v2::McpServerPromptListChangedNotification),
+    McpServerToolListChanged => "mcpServer/toolList/changed" (v2::McpServerToolListChangedNotification),
+    McpServerResourceListChanged => "mcpServer/resourceList/changed" (v2::McpServerResourceListChangedNotification),
     AccountUpdated => "account/updated" (v2::AccountUpdatedNotification),
     AccountRateLimitsUpdated => "account/rateLimits/updated" (v2::AccountRateLimitsUpdatedNotification),
     AppListUpdated => "app/list/updated" (v2::AppListUpdatedNotification),
@@ -291,21 +291,36 @@ pub fn convert_patch_changes(changes: &HashMap<PathBuf, FileChange>) -> Vec<File
 
 fn map_patch_change_kind(change: &FileChange) -> PatchChangeKind {
     match change {
-        FileChange::Add { .. } => PatchChangeKind::Add,
+        FileChange::Add { executable, .. } => PatchChangeKind::Add {
+            executable: *executable,
+        },
         FileChange::Delete { .. } => PatchChangeKind::Delete,
-        FileChange::Update { move_path, .. } => PatchChangeKind::Update {
+        FileChange::Update {
+            move_path,
+            executable,
+            ..
+        } => PatchChangeKind::Update {
             move_path: move_path.clone(),
+            executable: *executable,
+        },
+        FileChange::SetMode { executable } => PatchChangeKind::SetMode {
+            executable: *executable,
+        },
+        FileChange::AddSymlink { target } => PatchChangeKind::AddSymlink {
+            target: target.clone(),
         },
+        FileChange::AddBinary { size } => PatchChangeKind::AddBinary { size: *size },
     }
 }
 
 fn format_file_change_diff(change: &FileChange) -> String {
     match change {
-        FileChange::Add { content } => content.clone(),
+        FileChange::Add { content, .. } => content.clone(),
         FileChange::Delete { content } => content.clone(),
         FileChange::Update {
             unified_diff,
             move_path,
+            ..
         } => {
             if let Some(path) = move_path {
                 format!("{unified_diff}\n\nMoved to: {}", path.display())
@@ -313,5 +328,8 @@ fn format_file_change_diff(change: &FileChange) -> String {
                 unified_diff.clone()
             }
         }
+        FileChange::AddSymlink { target } => target.display().to_string(),
+        // The kind says everything there is to say about these.
+        FileChange::SetMode { .. } | FileChange::AddBinary { .. } => String::new(),
     }
 }
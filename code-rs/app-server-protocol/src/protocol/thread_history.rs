@@ -2078,6 +2078,7 @@ mod tests {
                     PathBuf::from("README.md"),
                     codex_protocol::protocol::FileChange::Add {
                         content: "hello\n".into(),
+                        executable: None,
                     },
                 )]
                 .into_iter()
@@ -2116,7 +2117,7 @@ mod tests {
                 id: "patch-declined".into(),
                 changes: vec![FileUpdateChange {
                     path: "README.md".into(),
-                    kind: PatchChangeKind::Add,
+                    kind: PatchChangeKind::Add { executable: None },
                     diff: "hello\n".into(),
                 }],
                 status: PatchApplyStatus::Declined,
@@ -2472,6 +2473,7 @@ mod tests {
                     PathBuf::from("README.md"),
                     codex_protocol::protocol::FileChange::Add {
                         content: "hello\n".into(),
+                        executable: None,
                     },
                 )]
                 .into_iter()
@@ -2502,7 +2504,7 @@ mod tests {
                     id: "patch-call".into(),
                     changes: vec![FileUpdateChange {
                         path: "README.md".into(),
-                        kind: PatchChangeKind::Add,
+                        kind: PatchChangeKind::Add { executable: None },
                         diff: "hello\n".into(),
                     }],
                     status: PatchApplyStatus::InProgress,
@@ -2536,6 +2538,7 @@ mod tests {
                     PathBuf::from("README.md"),
                     codex_protocol::protocol::FileChange::Add {
                         content: "hello\n".into(),
+                        executable: None,
                     },
                 )]
                 .into_iter()
@@ -2568,7 +2571,7 @@ mod tests {
                     id: "patch-call".into(),
                     changes: vec![FileUpdateChange {
                         path: "README.md".into(),
-                        kind: PatchChangeKind::Add,
+                        kind: PatchChangeKind::Add { executable: None },
                         diff: "hello\n".into(),
                     }],
                     status: PatchApplyStatus::InProgress,
@@ -17,13 +17,14 @@ use ts_rs::TS;
 
 v2_enum_from_core!(
     pub enum HookEventName from CoreHookEventName {
-        PreToolUse, PermissionRequest, PostToolUse, PreCompact, PostCompact, SessionStart, UserPromptSubmit, Stop
+        PreToolUse, PermissionRequest, PostToolUse, PreCompact, PostCompact, SessionStart, UserPromptSubmit, Stop,
+        SessionEnd, SubagentStart, SubagentStop, Notification
     }
 );
 
 v2_enum_from_core!(
     pub enum HookHandlerType from CoreHookHandlerType {
-        Command, Prompt, Agent
+        Command, Prompt, Agent, Http
     }
 );
 
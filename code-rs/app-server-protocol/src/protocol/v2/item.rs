@@ -929,9 +929,30 @@ pub struct FileUpdateChange {
 #[ts(tag = "type")]
 #[ts(export_to = "v2/")]
 pub enum PatchChangeKind {
-    Add,
+    Add {
+        /// Set when the patch also changes the file's executable bit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        executable: Option<bool>,
+    },
     Delete,
-    Update { move_path: Option<PathBuf> },
+    Update {
+        move_path: Option<PathBuf>,
+        /// Set when the patch also changes the file's executable bit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        executable: Option<bool>,
+    },
+    SetMode {
+        executable: bool,
+    },
+    AddSymlink {
+        target: PathBuf,
+    },
+    AddBinary {
+        #[ts(type = "number")]
+        size: u64,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -2,6 +2,9 @@ use super::shared::v2_enum_from_core;
 use codex_protocol::approvals::ElicitationRequest as CoreElicitationRequest;
 use codex_protocol::items::McpToolCallError as CoreMcpToolCallError;
 use codex_protocol::mcp::CallToolResult as CoreMcpCallToolResult;
+use codex_protocol::mcp::GetPromptResult as CoreMcpGetPromptResult;
+use codex_protocol::mcp::Prompt as McpPrompt;
+pub use codex_protocol::mcp::PromptMessage as McpPromptMessage;
 use codex_protocol::mcp::Resource as McpResource;
 pub use codex_protocol::mcp::ResourceContent as McpResourceContent;
 use codex_protocol::mcp::ResourceTemplate as McpResourceTemplate;
@@ -11,6 +14,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use ts_rs::TS;
 
 v2_enum_from_core!(
@@ -54,6 +58,7 @@ pub struct McpServerStatus {
     pub tools: std::collections::HashMap<String, McpTool>,
     pub resources: Vec<McpResource>,
     pub resource_templates: Vec<McpResourceTemplate>,
+    pub prompts: Vec<McpPrompt>,
     pub auth_status: McpAuthStatus,
 }
 
@@ -84,6 +89,60 @@ pub struct McpResourceReadResponse {
     pub contents: Vec<McpResourceContent>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPromptListParams {
+    pub thread_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPromptListResponse {
+    /// Prompts grouped by MCP server, sorted by server name.
+    pub data: Vec<McpServerPrompts>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPrompts {
+    pub server: String,
+    pub prompts: Vec<McpPrompt>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPromptGetParams {
+    pub thread_id: String,
+    pub server: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub arguments: Option<HashMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPromptGetResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub description: Option<String>,
+    pub messages: Vec<McpPromptMessage>,
+}
+
+impl From<CoreMcpGetPromptResult> for McpServerPromptGetResponse {
+    fn from(result: CoreMcpGetPromptResult) -> Self {
+        Self {
+            description: result.description,
+            messages: result.messages,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -236,6 +295,36 @@ pub struct McpServerStatusUpdatedNotification {
     pub error: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerPromptListChangedNotification {
+    pub thread_id: String,
+    /// Name of the MCP server whose prompt list changed.
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerToolListChangedNotification {
+    pub thread_id: String,
+    /// Name of the MCP server whose tool list changed.
+    pub name: String,
+    /// Names of the tools the server now exposes, after tool filters. The
+    /// refreshed list is used from the thread's next turn.
+    pub tools: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct McpServerResourceListChangedNotification {
+    pub thread_id: String,
+    /// Name of the MCP server whose resource list changed.
+    pub name: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(rename_all = "camelCase")]
@@ -2444,6 +2444,7 @@ fn core_turn_item_into_thread_item_converts_supported_variants() {
             PathBuf::from("README.md"),
             codex_protocol::protocol::FileChange::Add {
                 content: "hello\n".to_string(),
+                executable: None,
             },
         )]
         .into_iter()
@@ -2460,7 +2461,7 @@ fn core_turn_item_into_thread_item_converts_supported_variants() {
             id: "patch-1".to_string(),
             changes: vec![FileUpdateChange {
                 path: "README.md".to_string(),
-                kind: PatchChangeKind::Add,
+                kind: PatchChangeKind::Add { executable: None },
                 diff: "hello\n".to_string(),
             }],
             status: PatchApplyStatus::Completed,
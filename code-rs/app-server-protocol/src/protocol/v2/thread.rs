@@ -858,6 +858,11 @@ pub struct ThreadListParams {
     /// Optional substring filter for the extracted thread title.
     #[ts(optional = nullable)]
     pub search_term: Option<String>,
+    /// If true, match `searchTerm` against persisted thread content (messages,
+    /// tool calls, and tool output) instead of the title, and report where
+    /// each thread matched in `searchMatches`. Requires the state DB.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub search_content: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
@@ -914,6 +919,21 @@ pub struct ThreadListResponse {
     /// Use it with the opposite `sortDirection`; for timestamp sorts it anchors
     /// at the start of the page timestamp so same-second updates are not skipped.
     pub backwards_cursor: Option<String>,
+    /// Where each thread in `data` matched when `searchContent` was set, in the
+    /// same order as `data`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_matches: Vec<ThreadSearchMatch>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadSearchMatch {
+    pub thread_id: String,
+    /// Turn containing the best-ranked match, if known.
+    pub turn_id: Option<String>,
+    /// Plain-text excerpt around the match.
+    pub snippet: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema, TS)]
@@ -1127,6 +1127,7 @@ async fn thread_list(endpoint: &Endpoint, config_overrides: &[String], limit: u3
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })?;
         println!("< thread/list response: {response:?}");
 
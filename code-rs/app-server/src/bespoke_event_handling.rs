@@ -36,8 +36,11 @@ use codex_app_server_protocol::ItemStartedNotification;
 use codex_app_server_protocol::McpServerElicitationAction;
 use codex_app_server_protocol::McpServerElicitationRequestParams;
 use codex_app_server_protocol::McpServerElicitationRequestResponse;
+use codex_app_server_protocol::McpServerPromptListChangedNotification;
+use codex_app_server_protocol::McpServerResourceListChangedNotification;
 use codex_app_server_protocol::McpServerStartupState;
 use codex_app_server_protocol::McpServerStatusUpdatedNotification;
+use codex_app_server_protocol::McpServerToolListChangedNotification;
 use codex_app_server_protocol::ModelReroutedNotification;
 use codex_app_server_protocol::ModelVerificationNotification;
 use codex_app_server_protocol::NetworkApprovalContext as V2NetworkApprovalContext;
@@ -225,6 +228,40 @@ pub(crate) async fn apply_bespoke_event_handling(
                 .send_server_notification(ServerNotification::McpServerStatusUpdated(notification))
                 .await;
         }
+        EventMsg::McpPromptListChanged(event) => {
+            let notification = McpServerPromptListChangedNotification {
+                thread_id: conversation_id.to_string(),
+                name: event.server,
+            };
+            outgoing
+                .send_server_notification(ServerNotification::McpServerPromptListChanged(
+                    notification,
+                ))
+                .await;
+        }
+        EventMsg::McpToolListChanged(event) => {
+            let notification = McpServerToolListChangedNotification {
+                thread_id: conversation_id.to_string(),
+                name: event.server,
+                tools: event.tools,
+            };
+            outgoing
+                .send_server_notification(ServerNotification::McpServerToolListChanged(
+                    notification,
+                ))
+                .await;
+        }
+        EventMsg::McpResourceListChanged(event) => {
+            let notification = McpServerResourceListChangedNotification {
+                thread_id: conversation_id.to_string(),
+                name: event.server,
+            };
+            outgoing
+                .send_server_notification(ServerNotification::McpServerResourceListChanged(
+                    notification,
+                ))
+                .await;
+        }
         EventMsg::Warning(warning_event) => {
             let notification = WarningNotification {
                 thread_id: Some(conversation_id.to_string()),
@@ -1168,6 +1168,16 @@ impl MessageProcessor {
                     .mcp_resource_read(&request_id, params)
                     .await
             }
+            ClientRequest::McpServerPromptList { params, .. } => {
+                self.mcp_processor
+                    .mcp_server_prompt_list(&request_id, params)
+                    .await
+            }
+            ClientRequest::McpServerPromptGet { params, .. } => {
+                self.mcp_processor
+                    .mcp_server_prompt_get(&request_id, params)
+                    .await
+            }
             ClientRequest::McpServerToolCall { params, .. } => {
                 self.mcp_processor
                     .mcp_server_tool_call(&request_id, params)
@@ -475,6 +475,10 @@ fn map_hooks_requirements_to_api(hooks: ManagedHooksRequirementsToml) -> Managed
         session_start,
         user_prompt_submit,
         stop,
+        session_end,
+        subagent_start,
+        subagent_stop,
+        notification,
     } = hooks;
 
     ManagedHooksRequirements {
@@ -488,6 +492,10 @@ fn map_hooks_requirements_to_api(hooks: ManagedHooksRequirementsToml) -> Managed
         session_start: map_hook_matcher_groups_to_api(session_start),
         user_prompt_submit: map_hook_matcher_groups_to_api(user_prompt_submit),
         stop: map_hook_matcher_groups_to_api(stop),
+        session_end: map_hook_matcher_groups_to_api(session_end),
+        subagent_start: map_hook_matcher_groups_to_api(subagent_start),
+        subagent_stop: map_hook_matcher_groups_to_api(subagent_stop),
+        notification: map_hook_matcher_groups_to_api(notification),
     }
 }
 
@@ -524,8 +532,41 @@ fn map_hook_handler_to_api(handler: CoreHookHandlerConfig) -> ConfiguredHookHand
             r#async,
             status_message,
         },
-        CoreHookHandlerConfig::Prompt {} => ConfiguredHookHandler::Prompt {},
-        CoreHookHandlerConfig::Agent {} => ConfiguredHookHandler::Agent {},
+        CoreHookHandlerConfig::Prompt {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => ConfiguredHookHandler::Prompt {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        },
+        CoreHookHandlerConfig::Agent {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => ConfiguredHookHandler::Agent {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        },
+        CoreHookHandlerConfig::Http {
+            url,
+            headers,
+            timeout_sec,
+            retries,
+            status_message,
+        } => ConfiguredHookHandler::Http {
+            url,
+            headers: headers.into_keys().collect(),
+            timeout_sec,
+            retries,
+            status_message,
+        },
     }
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use codex_core::output_redactor_from_config;
 
 #[derive(Clone)]
 pub(crate) struct FeedbackRequestProcessor {
@@ -198,8 +199,11 @@ impl FeedbackRequestProcessor {
         }
 
         let session_source = self.thread_manager.session_source();
+        let config = Arc::clone(&self.config);
 
         let upload_result = tokio::task::spawn_blocking(move || {
+            // Building the redactor may read stored secrets from disk.
+            let redactor = output_redactor_from_config(&config);
             snapshot.upload_feedback(FeedbackUploadOptions {
                 classification: &classification,
                 reason: reason.as_deref(),
@@ -208,6 +212,7 @@ impl FeedbackRequestProcessor {
                 extra_attachment_paths: &attachment_paths,
                 session_source: Some(session_source),
                 logs_override: sqlite_feedback_logs,
+                redactor: Some(&redactor),
             })
         })
         .await;
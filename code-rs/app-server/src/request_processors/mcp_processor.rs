@@ -63,6 +63,26 @@ impl McpRequestProcessor {
             .map(|()| None)
     }
 
+    pub(crate) async fn mcp_server_prompt_list(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptListParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.list_mcp_server_prompts(request_id, params)
+            .await
+            .map(|()| None)
+    }
+
+    pub(crate) async fn mcp_server_prompt_get(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptGetParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.get_mcp_server_prompt(request_id, params)
+            .await
+            .map(|()| None)
+    }
+
     pub(crate) async fn mcp_server_tool_call(
         &self,
         request_id: &ConnectionRequestId,
@@ -279,6 +299,7 @@ impl McpRequestProcessor {
             tools_by_server,
             resources,
             resource_templates,
+            prompts,
             auth_statuses,
         } = snapshot;
 
@@ -293,6 +314,7 @@ impl McpRequestProcessor {
             .chain(auth_statuses.keys().cloned())
             .chain(resources.keys().cloned())
             .chain(resource_templates.keys().cloned())
+            .chain(prompts.keys().cloned())
             .collect();
         server_names.sort();
         server_names.dedup();
@@ -323,6 +345,7 @@ impl McpRequestProcessor {
                 tools: tools_by_server.get(name).cloned().unwrap_or_default(),
                 resources: resources.get(name).cloned().unwrap_or_default(),
                 resource_templates: resource_templates.get(name).cloned().unwrap_or_default(),
+                prompts: prompts.get(name).cloned().unwrap_or_default(),
                 auth_status: auth_statuses
                     .get(name)
                     .cloned()
@@ -411,6 +434,56 @@ impl McpRequestProcessor {
         outgoing.send_result(request_id, result).await;
     }
 
+    async fn list_mcp_server_prompts(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptListParams,
+    ) -> Result<(), JSONRPCErrorError> {
+        let outgoing = Arc::clone(&self.outgoing);
+        let (_, thread) = self.load_thread(&params.thread_id).await?;
+        let request_id = request_id.clone();
+
+        tokio::spawn(async move {
+            let mut data: Vec<McpServerPrompts> = thread
+                .list_mcp_prompts()
+                .await
+                .into_iter()
+                .map(|(server, prompts)| McpServerPrompts { server, prompts })
+                .collect();
+            data.sort_by(|a, b| a.server.cmp(&b.server));
+            outgoing
+                .send_response(request_id, McpServerPromptListResponse { data })
+                .await;
+        });
+        Ok(())
+    }
+
+    async fn get_mcp_server_prompt(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptGetParams,
+    ) -> Result<(), JSONRPCErrorError> {
+        let outgoing = Arc::clone(&self.outgoing);
+        let McpServerPromptGetParams {
+            thread_id,
+            server,
+            name,
+            arguments,
+        } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let request_id = request_id.clone();
+
+        tokio::spawn(async move {
+            let result = thread
+                .get_mcp_prompt(&server, &name, arguments)
+                .await
+                .map(McpServerPromptGetResponse::from)
+                .map_err(|error| internal_error(format!("{error:#}")));
+            outgoing.send_result(request_id, result).await;
+        });
+        Ok(())
+    }
+
     async fn call_mcp_server_tool(
         &self,
         request_id: &ConnectionRequestId,
@@ -14,9 +14,13 @@ struct ThreadListFilters {
     archived: bool,
     cwd_filters: Option<Vec<PathBuf>>,
     search_term: Option<String>,
+    search_content: bool,
     use_state_db_only: bool,
 }
 
+/// A listed thread and, for content searches, where it matched.
+type ThreadListEntry = (StoredThread, Option<ThreadSearchMatch>);
+
 fn collect_resume_override_mismatches(
     request: &ThreadResumeParams,
     config_snapshot: &ThreadConfigSnapshot,
@@ -1776,7 +1780,17 @@ impl ThreadRequestProcessor {
             cwd,
             use_state_db_only,
             search_term,
+            search_content,
         } = params;
+        if search_content
+            && search_term
+                .as_deref()
+                .is_none_or(|term| term.trim().is_empty())
+        {
+            return Err(invalid_request(
+                "searchContent requires a non-empty searchTerm",
+            ));
+        }
         let cwd_filters = normalize_thread_list_cwd_filters(cwd)?;
 
         let requested_page_size = limit
@@ -1788,7 +1802,7 @@ impl ThreadRequestProcessor {
             ThreadSortKey::UpdatedAt => StoreThreadSortKey::UpdatedAt,
         };
         let sort_direction = sort_direction.unwrap_or(SortDirection::Desc);
-        let (stored_threads, next_cursor) = self
+        let (entries, next_cursor) = self
             .list_threads_common(
                 requested_page_size,
                 cursor,
@@ -1800,10 +1814,13 @@ impl ThreadRequestProcessor {
                     archived: archived.unwrap_or(false),
                     cwd_filters,
                     search_term,
+                    search_content,
                     use_state_db_only,
                 },
             )
             .await?;
+        let (stored_threads, search_matches): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+        let search_matches = search_matches.into_iter().flatten().collect();
         let backwards_cursor = stored_threads.first().and_then(|thread| {
             thread_backwards_cursor_for_sort_key(thread, store_sort_key, sort_direction)
         });
@@ -1839,6 +1856,7 @@ impl ThreadRequestProcessor {
             data,
             next_cursor,
             backwards_cursor,
+            search_matches,
         })
     }
 
@@ -3270,13 +3288,14 @@ impl ThreadRequestProcessor {
         sort_key: StoreThreadSortKey,
         sort_direction: SortDirection,
         filters: ThreadListFilters,
-    ) -> Result<(Vec<StoredThread>, Option<String>), JSONRPCErrorError> {
+    ) -> Result<(Vec<ThreadListEntry>, Option<String>), JSONRPCErrorError> {
         let ThreadListFilters {
             model_providers,
             source_kinds,
             archived,
             cwd_filters,
             search_term,
+            search_content,
             use_state_db_only,
         } = filters;
         let mut cursor_obj = cursor;
@@ -3304,25 +3323,53 @@ impl ThreadRequestProcessor {
 
         while remaining > 0 {
             let page_size = remaining.min(THREAD_LIST_MAX_LIMIT);
-            let page = self
-                .thread_store
-                .list_threads(StoreListThreadsParams {
-                    page_size,
-                    cursor: cursor_obj.clone(),
-                    sort_key,
-                    sort_direction: store_sort_direction,
-                    allowed_sources: allowed_sources.to_vec(),
-                    model_providers: model_provider_filter.clone(),
-                    cwd_filters: cwd_filters.clone(),
-                    archived,
-                    search_term: search_term.clone(),
-                    use_state_db_only,
-                })
-                .await
-                .map_err(thread_store_list_error)?;
+            let params = StoreListThreadsParams {
+                page_size,
+                cursor: cursor_obj.clone(),
+                sort_key,
+                sort_direction: store_sort_direction,
+                allowed_sources: allowed_sources.to_vec(),
+                model_providers: model_provider_filter.clone(),
+                cwd_filters: cwd_filters.clone(),
+                archived,
+                search_term: search_term.clone(),
+                use_state_db_only,
+            };
+            let (page_items, page_next_cursor) = if search_content {
+                let page = self
+                    .thread_store
+                    .search_threads(params)
+                    .await
+                    .map_err(thread_store_list_error)?;
+                let page_items = page
+                    .items
+                    .into_iter()
+                    .map(|hit| {
+                        let search_match = ThreadSearchMatch {
+                            thread_id: hit.thread.thread_id.to_string(),
+                            turn_id: hit.turn_id,
+                            snippet: hit.snippet,
+                        };
+                        (hit.thread, Some(search_match))
+                    })
+                    .collect::<Vec<_>>();
+                (page_items, page.next_cursor)
+            } else {
+                let page = self
+                    .thread_store
+                    .list_threads(params)
+                    .await
+                    .map_err(thread_store_list_error)?;
+                let page_items = page
+                    .items
+                    .into_iter()
+                    .map(|thread| (thread, None))
+                    .collect::<Vec<_>>();
+                (page_items, page.next_cursor)
+            };
 
-            let mut filtered = Vec::with_capacity(page.items.len());
-            for it in page.items {
+            let mut filtered = Vec::with_capacity(page_items.len());
+            for (it, search_match) in page_items {
                 let source = with_thread_spawn_agent_metadata(
                     it.source.clone(),
                     it.agent_nickname.clone(),
@@ -3337,7 +3384,7 @@ impl ThreadRequestProcessor {
                         })
                     })
                 {
-                    filtered.push(it);
+                    filtered.push((it, search_match));
                     if filtered.len() >= remaining {
                         break;
                     }
@@ -3346,7 +3393,7 @@ impl ThreadRequestProcessor {
             items.extend(filtered);
             remaining = requested_page_size.saturating_sub(items.len());
 
-            next_cursor = page.next_cursor;
+            next_cursor = page_next_cursor;
             if remaining == 0 {
                 break;
             }
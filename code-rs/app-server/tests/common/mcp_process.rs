@@ -52,6 +52,8 @@ use codex_app_server_protocol::MarketplaceAddParams;
 use codex_app_server_protocol::MarketplaceRemoveParams;
 use codex_app_server_protocol::MarketplaceUpgradeParams;
 use codex_app_server_protocol::McpResourceReadParams;
+use codex_app_server_protocol::McpServerPromptGetParams;
+use codex_app_server_protocol::McpServerPromptListParams;
 use codex_app_server_protocol::McpServerToolCallParams;
 use codex_app_server_protocol::MockExperimentalMethodParams;
 use codex_app_server_protocol::ModelListParams;
@@ -594,6 +596,24 @@ impl McpProcess {
         self.send_request("mcpServer/tool/call", params).await
     }
 
+    /// Send an `mcpServer/prompt/list` JSON-RPC request.
+    pub async fn send_mcp_server_prompt_list_request(
+        &mut self,
+        params: McpServerPromptListParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("mcpServer/prompt/list", params).await
+    }
+
+    /// Send an `mcpServer/prompt/get` JSON-RPC request.
+    pub async fn send_mcp_server_prompt_get_request(
+        &mut self,
+        params: McpServerPromptGetParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("mcpServer/prompt/get", params).await
+    }
+
     /// Send a `skills/list` JSON-RPC request.
     pub async fn send_skills_list_request(
         &mut self,
@@ -334,6 +334,7 @@ async fn external_agent_config_import_creates_session_rollouts() -> Result<()> {
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
@@ -503,6 +504,7 @@ async fn external_agent_config_import_accepts_detected_session_payload_after_res
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
@@ -589,6 +591,7 @@ async fn external_agent_config_import_skips_already_imported_session_versions()
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
@@ -727,6 +730,7 @@ async fn external_agent_config_import_returns_before_background_session_import_f
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
@@ -811,6 +815,7 @@ async fn external_agent_config_import_rejects_undetected_session_paths() -> Resu
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
@@ -934,6 +939,7 @@ async fn external_agent_config_import_compacts_huge_session_before_first_follow_
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let response: JSONRPCResponse = timeout(
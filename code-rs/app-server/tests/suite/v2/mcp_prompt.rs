@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_mock_responses_server_sequence_unchecked;
+use app_test_support::to_response;
+use app_test_support::write_mock_responses_config_toml;
+use axum::Router;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::McpServerPromptGetParams;
+use codex_app_server_protocol::McpServerPromptGetResponse;
+use codex_app_server_protocol::McpServerPromptListParams;
+use codex_app_server_protocol::McpServerPromptListResponse;
+use codex_app_server_protocol::McpServerPrompts;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadStartParams;
+use codex_app_server_protocol::ThreadStartResponse;
+use codex_protocol::mcp::Prompt;
+use codex_protocol::mcp::PromptArgument;
+use pretty_assertions::assert_eq;
+use rmcp::handler::server::ServerHandler;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
+use rmcp::model::ListPromptsResult;
+use rmcp::model::PaginatedRequestParams;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
+use rmcp::model::ServerCapabilities;
+use rmcp::model::ServerInfo;
+use rmcp::service::RequestContext;
+use rmcp::service::RoleServer;
+use rmcp::transport::StreamableHttpServerConfig;
+use rmcp::transport::StreamableHttpService;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(10);
+const TEST_PROMPT_NAME: &str = "review";
+const TEST_PROMPT_DESCRIPTION: &str = "Review a file.";
+
+#[tokio::test]
+async fn mcp_server_prompt_list_and_get_use_thread_servers() -> Result<()> {
+    let server = create_mock_responses_server_sequence_unchecked(Vec::new()).await;
+    let (mcp_server_url, mcp_server_handle) = start_prompt_mcp_server().await?;
+    let codex_home = TempDir::new()?;
+    write_mock_responses_config_toml(
+        codex_home.path(),
+        &server.uri(),
+        &BTreeMap::new(),
+        /*auto_compact_limit*/ 1024,
+        /*requires_openai_auth*/ None,
+        "mock_provider",
+        "compact",
+    )?;
+
+    let config_path = codex_home.path().join("config.toml");
+    let mut config_toml = std::fs::read_to_string(&config_path)?;
+    config_toml.push_str(&format!(
+        r#"
+[mcp_servers.prompt-server]
+url = "{mcp_server_url}/mcp"
+"#
+    ));
+    std::fs::write(config_path, config_toml)?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let thread_start_id = mcp
+        .send_thread_start_request(ThreadStartParams {
+            model: Some("mock-model".to_string()),
+            ..Default::default()
+        })
+        .await?;
+    let thread_start_resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(thread_start_id)),
+    )
+    .await??;
+    let ThreadStartResponse { thread, .. } = to_response(thread_start_resp)?;
+
+    let list_request_id = mcp
+        .send_mcp_server_prompt_list_request(McpServerPromptListParams {
+            thread_id: thread.id.clone(),
+        })
+        .await?;
+    let list_response: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(list_request_id)),
+    )
+    .await??;
+    assert_eq!(
+        to_response::<McpServerPromptListResponse>(list_response)?,
+        McpServerPromptListResponse {
+            data: vec![McpServerPrompts {
+                server: "prompt-server".to_string(),
+                prompts: vec![Prompt {
+                    name: TEST_PROMPT_NAME.to_string(),
+                    title: None,
+                    description: Some(TEST_PROMPT_DESCRIPTION.to_string()),
+                    arguments: Some(vec![PromptArgument {
+                        name: "path".to_string(),
+                        title: None,
+                        description: None,
+                        required: Some(true),
+                    }]),
+                }],
+            }],
+        }
+    );
+
+    let get_request_id = mcp
+        .send_mcp_server_prompt_get_request(McpServerPromptGetParams {
+            thread_id: thread.id,
+            server: "prompt-server".to_string(),
+            name: TEST_PROMPT_NAME.to_string(),
+            arguments: Some(HashMap::from([(
+                "path".to_string(),
+                "src/lib.rs".to_string(),
+            )])),
+        })
+        .await?;
+    let get_response: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(get_request_id)),
+    )
+    .await??;
+    let get_response = to_response::<McpServerPromptGetResponse>(get_response)?;
+    assert_eq!(
+        get_response.description.as_deref(),
+        Some(TEST_PROMPT_DESCRIPTION)
+    );
+    assert_eq!(
+        get_response
+            .messages
+            .iter()
+            .map(|message| message.text())
+            .collect::<Vec<_>>(),
+        vec![Some("Review src/lib.rs.")]
+    );
+
+    mcp_server_handle.abort();
+    let _ = mcp_server_handle.await;
+
+    Ok(())
+}
+
+async fn start_prompt_mcp_server() -> Result<(String, JoinHandle<()>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let mcp_server_url = format!("http://{addr}");
+
+    let mcp_service = StreamableHttpService::new(
+        move || Ok(PromptMcpServer),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig::default(),
+    );
+    let router = Router::new().nest_service("/mcp", mcp_service);
+    let mcp_server_handle = tokio::spawn(async move {
+        let _ = axum::serve(listener, router).await;
+    });
+
+    Ok((mcp_server_url, mcp_server_handle))
+}
+
+#[derive(Clone, Default)]
+struct PromptMcpServer;
+
+impl ServerHandler for PromptMcpServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_prompts().build(),
+            ..ServerInfo::default()
+        }
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, rmcp::ErrorData> {
+        let prompt = serde_json::from_value(json!({
+            "name": TEST_PROMPT_NAME,
+            "description": TEST_PROMPT_DESCRIPTION,
+            "arguments": [{ "name": "path", "required": true }],
+        }))
+        .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))?;
+        Ok(ListPromptsResult {
+            prompts: vec![prompt],
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, rmcp::ErrorData> {
+        let path = request
+            .arguments
+            .as_ref()
+            .and_then(|arguments| arguments.get("path"))
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| rmcp::ErrorData::invalid_params("missing path", None))?;
+        Ok(GetPromptResult {
+            description: Some(TEST_PROMPT_DESCRIPTION.to_string()),
+            messages: vec![PromptMessage::new_text(
+                PromptMessageRole::User,
+                format!("Review {path}."),
+            )],
+        })
+    }
+}
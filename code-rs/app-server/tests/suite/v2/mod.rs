@@ -21,10 +21,12 @@ mod initialize;
 mod marketplace_add;
 mod marketplace_remove;
 mod marketplace_upgrade;
+mod mcp_prompt;
 mod mcp_resource;
 mod mcp_server_elicitation;
 mod mcp_server_status;
 mod mcp_tool;
+mod mcp_tool_list_changed;
 mod memory_reset;
 mod model_list;
 mod model_provider_capabilities_read;
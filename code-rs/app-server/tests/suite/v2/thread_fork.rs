@@ -680,6 +680,7 @@ async fn thread_fork_ephemeral_remains_pathless_and_omits_listing() -> Result<()
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let list_resp: JSONRPCResponse = timeout(
@@ -17,6 +17,7 @@ use codex_app_server_protocol::SessionSource;
 use codex_app_server_protocol::SortDirection;
 use codex_app_server_protocol::ThreadListCwdFilter;
 use codex_app_server_protocol::ThreadListResponse;
+use codex_app_server_protocol::ThreadSearchMatch;
 use codex_app_server_protocol::ThreadSortKey;
 use codex_app_server_protocol::ThreadSourceKind;
 use codex_app_server_protocol::ThreadStartParams;
@@ -93,6 +94,7 @@ async fn list_threads_with_sort(
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -533,6 +535,7 @@ async fn thread_list_respects_cwd_filters() -> Result<()> {
             ])),
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -642,6 +645,7 @@ sqlite = true
             cwd: None,
             use_state_db_only: false,
             search_term: Some("needle".to_string()),
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -660,6 +664,144 @@ sqlite = true
     Ok(())
 }
 
+#[tokio::test]
+async fn thread_list_search_content_returns_matching_turns() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        r#"
+model = "mock-model"
+approval_policy = "never"
+suppress_unstable_features_warning = true
+
+[features]
+sqlite = true
+"#,
+    )?;
+
+    let matching = create_fake_rollout(
+        codex_home.path(),
+        "2025-01-02T10-00-00",
+        "2025-01-02T10:00:00Z",
+        "fix the build",
+        Some("mock_provider"),
+        /*git_info*/ None,
+    )?;
+    let _other = create_fake_rollout(
+        codex_home.path(),
+        "2025-01-02T11-00-00",
+        "2025-01-02T11:00:00Z",
+        "update the readme",
+        Some("mock_provider"),
+        /*git_info*/ None,
+    )?;
+
+    let state_db =
+        codex_state::StateRuntime::init(codex_home.path().to_path_buf(), "mock_provider".into())
+            .await?;
+    state_db
+        .mark_backfill_complete(/*last_watermark*/ None)
+        .await?;
+    let rollout_config = codex_rollout::RolloutConfig {
+        codex_home: codex_home.path().to_path_buf(),
+        sqlite_home: codex_home.path().to_path_buf(),
+        cwd: codex_home.path().to_path_buf(),
+        model_provider_id: "mock_provider".to_string(),
+        generate_memories: false,
+    };
+    let repaired_page = codex_core::RolloutRecorder::list_threads(
+        Some(state_db.clone()),
+        &rollout_config,
+        /*page_size*/ 10,
+        /*cursor*/ None,
+        codex_core::ThreadSortKey::CreatedAt,
+        codex_core::SortDirection::Desc,
+        &[],
+        /*model_providers*/ None,
+        /*cwd_filters*/ None,
+        "mock_provider",
+        /*search_term*/ None,
+    )
+    .await?;
+    assert_eq!(repaired_page.items.len(), 2);
+    state_db
+        .append_thread_content(
+            ThreadId::from_string(&matching)?,
+            &[codex_state::ThreadContentEntry {
+                turn_id: Some("turn-2".to_string()),
+                content: "error: linking with `cc` failed".to_string(),
+            }],
+        )
+        .await?;
+
+    let mut mcp = init_mcp(codex_home.path()).await?;
+    let request_id = mcp
+        .send_thread_list_request(codex_app_server_protocol::ThreadListParams {
+            cursor: None,
+            limit: Some(10),
+            sort_key: None,
+            sort_direction: None,
+            model_providers: Some(vec!["mock_provider".to_string()]),
+            source_kinds: None,
+            archived: None,
+            cwd: None,
+            use_state_db_only: false,
+            search_term: Some("linking".to_string()),
+            search_content: true,
+        })
+        .await?;
+    let resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let ThreadListResponse {
+        data,
+        next_cursor,
+        search_matches,
+        ..
+    } = to_response::<ThreadListResponse>(resp)?;
+
+    assert_eq!(next_cursor, None);
+    let ids: Vec<_> = data.iter().map(|thread| thread.id.as_str()).collect();
+    assert_eq!(ids, vec![matching.as_str()]);
+    assert_eq!(
+        search_matches,
+        vec![ThreadSearchMatch {
+            thread_id: matching,
+            turn_id: Some("turn-2".to_string()),
+            snippet: "error: linking with `cc` failed".to_string(),
+        }]
+    );
+
+    let request_id = mcp
+        .send_thread_list_request(codex_app_server_protocol::ThreadListParams {
+            cursor: None,
+            limit: Some(10),
+            sort_key: None,
+            sort_direction: None,
+            model_providers: None,
+            source_kinds: None,
+            archived: None,
+            cwd: None,
+            use_state_db_only: false,
+            search_term: None,
+            search_content: true,
+        })
+        .await?;
+    let err: JSONRPCError = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    assert_eq!(
+        err.error.message,
+        "searchContent requires a non-empty searchTerm"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn thread_list_state_db_only_returns_sqlite_without_jsonl_repair() -> Result<()> {
     let codex_home = TempDir::new()?;
@@ -703,6 +845,7 @@ sqlite = true
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -741,6 +884,7 @@ sqlite = true
             )),
             use_state_db_only: true,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -770,6 +914,7 @@ sqlite = true
             )),
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let resp: JSONRPCResponse = timeout(
@@ -1465,6 +1610,7 @@ async fn thread_list_backwards_cursor_can_seed_forward_delta_sync() -> Result<()
                 cwd: None,
                 use_state_db_only: false,
                 search_term: None,
+                search_content: false,
             })
             .await?;
         let resp: JSONRPCResponse = timeout(
@@ -1507,6 +1653,7 @@ async fn thread_list_backwards_cursor_can_seed_forward_delta_sync() -> Result<()
                 cwd: None,
                 use_state_db_only: false,
                 search_term: None,
+                search_content: false,
             })
             .await?;
         let resp: JSONRPCResponse = timeout(
@@ -1745,6 +1892,7 @@ async fn thread_list_invalid_cursor_returns_error() -> Result<()> {
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let error: JSONRPCError = timeout(
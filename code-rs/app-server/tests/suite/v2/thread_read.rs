@@ -563,6 +563,7 @@ async fn thread_list_includes_store_thread_without_rollout_path() -> Result<()>
                 cwd: None,
                 use_state_db_only: false,
                 search_term: None,
+                search_content: false,
             },
         })
         .await?
@@ -886,6 +887,7 @@ async fn thread_name_set_is_reflected_in_read_list_and_resume() -> Result<()> {
             cwd: None,
             use_state_db_only: false,
             search_term: None,
+            search_content: false,
         })
         .await?;
     let list_resp: JSONRPCResponse = timeout(
@@ -2247,7 +2247,7 @@ async fn thread_resume_replays_pending_file_change_request_approval() -> Result<
         id: "patch-call".to_string(),
         changes: vec![codex_app_server_protocol::FileUpdateChange {
             path: expected_readme_path.to_string_lossy().into_owned(),
-            kind: PatchChangeKind::Add,
+            kind: PatchChangeKind::Add { executable: None },
             diff: "new line\n".to_string(),
         }],
         status: PatchApplyStatus::InProgress,
@@ -2262,7 +2262,7 @@ async fn turn_start_file_change_approval_v2() -> Result<()> {
         started_changes,
         vec![codex_app_server_protocol::FileUpdateChange {
             path: expected_readme_path.clone(),
-            kind: PatchChangeKind::Add,
+            kind: PatchChangeKind::Add { executable: None },
             diff: "new line\n".to_string(),
         }]
     );
@@ -2554,7 +2554,7 @@ async fn turn_start_streams_apply_patch_change_updates_v2() -> Result<()> {
             .iter()
             .find(|change| change.path == "live.txt")
             .expect("live.txt change");
-        assert!(matches!(change.kind, PatchChangeKind::Add));
+        assert!(matches!(change.kind, PatchChangeKind::Add { .. }));
         streamed_content = change.diff.clone();
     }
 
@@ -3229,7 +3229,7 @@ async fn turn_start_file_change_approval_decline_v2() -> Result<()> {
         started_changes,
         vec![codex_app_server_protocol::FileUpdateChange {
             path: expected_readme_path_str.clone(),
-            kind: PatchChangeKind::Add,
+            kind: PatchChangeKind::Add { executable: None },
             diff: "new line\n".to_string(),
         }]
     );
@@ -0,0 +1,1214 @@
+//! All-or-nothing patch application.
+//!
+//! [`apply_hunks_atomic`] computes the final contents of every file the patch
+//! touches before writing anything. The new contents are staged in temporary
+//! files next to their targets and renamed into place only once every hunk has
+//! verified. If committing fails part way through, the files already replaced
+//! are restored from backups, so a failed patch leaves the tree as it was.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use anyhow::Context;
+use codex_exec_server::CopyOptions;
+use codex_exec_server::CreateDirectoryOptions;
+use codex_exec_server::ExecutorFileSystem;
+use codex_exec_server::FileSystemSandboxContext;
+use codex_exec_server::RemoveOptions;
+use codex_utils_absolute_path::AbsolutePathBuf;
+
+use crate::AffectedPaths;
+use crate::AppliedPatch;
+use crate::AppliedPatchChange;
+use crate::AppliedPatchDelta;
+use crate::AppliedPatchFileChange;
+use crate::ApplyPatchError;
+use crate::ApplyPatchFailure;
+use crate::Hunk;
+use crate::derive_new_contents;
+use crate::note_existing_path_delta_support;
+use crate::parse_patch_reporting_errors;
+use crate::print_summary;
+use crate::read_optional_file_text_for_delta;
+use crate::read_to_update_error;
+use crate::report_failure;
+
+/// Distinguishes temporary files created by concurrent patches in one process.
+static STAGING_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Applies the patch as a single transaction and prints the result to
+/// stdout/stderr.
+///
+/// On failure no file is left modified and the returned delta is empty, unless
+/// restoring a file after a failed commit also failed; the delta then lists the
+/// whole patch and is marked inexact.
+pub async fn apply_patch_atomic(
+    patch: &str,
+    cwd: &AbsolutePathBuf,
+    stdout: &mut impl std::io::Write,
+    stderr: &mut impl std::io::Write,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> Result<AppliedPatchDelta, ApplyPatchFailure> {
+    let hunks = parse_patch_reporting_errors(patch, stderr)?;
+    apply_hunks_atomic(&hunks, cwd, stdout, stderr, fs, sandbox).await
+}
+
+/// Applies hunks as a single transaction; see [`apply_patch_atomic`].
+pub async fn apply_hunks_atomic(
+    hunks: &[Hunk],
+    cwd: &AbsolutePathBuf,
+    stdout: &mut impl std::io::Write,
+    stderr: &mut impl std::io::Write,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> Result<AppliedPatchDelta, ApplyPatchFailure> {
+    let plan = match PatchPlan::build(hunks, cwd, fs, sandbox).await {
+        Ok(plan) => plan,
+        Err(error) => return Err(report_failure(error, AppliedPatchDelta::empty(), stderr)),
+    };
+    let PatchPlan {
+        files,
+        delta,
+        affected,
+    } = plan;
+    match commit(&files, fs, sandbox).await {
+        Ok(()) => {
+            print_summary(&affected, stdout).map_err(|error| {
+                ApplyPatchFailure::new(ApplyPatchError::from(error), delta.clone())
+            })?;
+            Ok(delta)
+        }
+        Err(CommitError {
+            error,
+            restored: true,
+        }) => Err(report_failure(error, AppliedPatchDelta::empty(), stderr)),
+        Err(CommitError {
+            error,
+            restored: false,
+        }) => Err(report_failure(
+            error,
+            AppliedPatchDelta::new(delta.changes, /*exact*/ false),
+            stderr,
+        )),
+    }
+}
+
+/// What was at a path before the patch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OriginalFile {
+    Missing,
+    File { executable: bool },
+    Symlink,
+    Directory,
+}
+
+/// The final state of one path touched by the patch.
+struct PlannedFile {
+    path: AbsolutePathBuf,
+    original: OriginalFile,
+    /// `None` when the path ends up deleted.
+    contents: Option<PlannedContents>,
+}
+
+/// What a path holds once the patch is applied.
+#[derive(Clone)]
+enum PlannedContents {
+    File {
+        contents: Vec<u8>,
+        /// `None` keeps the mode the file already has.
+        executable: Option<bool>,
+    },
+    Symlink {
+        target: PathBuf,
+    },
+}
+
+/// The verified outcome of a patch, computed without touching the filesystem.
+struct PatchPlan {
+    files: Vec<PlannedFile>,
+    delta: AppliedPatchDelta,
+    affected: AffectedPaths,
+}
+
+impl PatchPlan {
+    async fn build(
+        hunks: &[Hunk],
+        cwd: &AbsolutePathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> anyhow::Result<Self> {
+        if hunks.is_empty() {
+            anyhow::bail!("No files were modified.");
+        }
+
+        let mut plan = Self {
+            files: Vec::new(),
+            delta: AppliedPatchDelta::empty(),
+            affected: AffectedPaths {
+                added: Vec::new(),
+                modified: Vec::new(),
+                deleted: Vec::new(),
+            },
+        };
+        for hunk in hunks {
+            let affected_path = hunk.path().to_path_buf();
+            let path_abs = hunk.resolve_path(cwd);
+            match hunk {
+                Hunk::AddFile { contents, .. } => {
+                    let overwritten_content =
+                        plan.read_text_for_delta(&path_abs, fs, sandbox).await;
+                    plan.write(&path_abs, contents.clone().into_bytes(), fs, sandbox)
+                        .await
+                        .with_context(|| format!("Failed to write file {}", path_abs.display()))?;
+                    plan.delta.changes.push(AppliedPatchChange {
+                        path: path_abs.into_path_buf(),
+                        change: AppliedPatchFileChange::Add {
+                            content: contents.clone(),
+                            overwritten_content,
+                        },
+                    });
+                    plan.affected.added.push(affected_path);
+                }
+                Hunk::DeleteFile { .. } => {
+                    let deleted_content = plan.read_text_for_delta(&path_abs, fs, sandbox).await;
+                    plan.delete(&path_abs, fs, sandbox)
+                        .await
+                        .with_context(|| format!("Failed to delete file {}", path_abs.display()))?;
+                    match deleted_content {
+                        Some(content) => plan.delta.changes.push(AppliedPatchChange {
+                            path: path_abs.into_path_buf(),
+                            change: AppliedPatchFileChange::Delete { content },
+                        }),
+                        None => plan.delta.exact = false,
+                    }
+                    plan.affected.deleted.push(affected_path);
+                }
+                Hunk::UpdateFile {
+                    move_path, chunks, ..
+                } => {
+                    let original_contents = plan.read_to_update(&path_abs, fs, sandbox).await?;
+                    let AppliedPatch {
+                        original_contents,
+                        new_contents,
+                    } = derive_new_contents(original_contents, path_abs.as_path(), chunks)?;
+                    let (move_path, overwritten_move_content) = match move_path {
+                        Some(dest) => {
+                            let dest_abs = AbsolutePathBuf::resolve_path_against_base(dest, cwd);
+                            let overwritten_move_content =
+                                plan.read_text_for_delta(&dest_abs, fs, sandbox).await;
+                            plan.write(&dest_abs, new_contents.clone().into_bytes(), fs, sandbox)
+                                .await
+                                .with_context(|| {
+                                    format!("Failed to write file {}", dest_abs.display())
+                                })?;
+                            plan.delete(&path_abs, fs, sandbox).await.with_context(|| {
+                                format!("Failed to remove original {}", path_abs.display())
+                            })?;
+                            (Some(dest_abs.into_path_buf()), overwritten_move_content)
+                        }
+                        None => {
+                            plan.write(&path_abs, new_contents.clone().into_bytes(), fs, sandbox)
+                                .await
+                                .with_context(|| {
+                                    format!("Failed to write file {}", path_abs.display())
+                                })?;
+                            (None, None)
+                        }
+                    };
+                    plan.delta.changes.push(AppliedPatchChange {
+                        path: path_abs.into_path_buf(),
+                        change: AppliedPatchFileChange::Update {
+                            move_path,
+                            old_content: original_contents,
+                            overwritten_move_content,
+                            new_content: new_contents,
+                        },
+                    });
+                    plan.affected.modified.push(affected_path);
+                }
+                Hunk::SetMode { executable, .. } => {
+                    let (contents, was_executable) = plan
+                        .set_mode(&path_abs, *executable, fs, sandbox)
+                        .await
+                        .with_context(|| {
+                            format!("Failed to change the mode of {}", path_abs.display())
+                        })?;
+                    match String::from_utf8(contents) {
+                        Ok(content) => plan.delta.changes.push(AppliedPatchChange {
+                            path: path_abs.into_path_buf(),
+                            change: AppliedPatchFileChange::SetMode {
+                                content,
+                                was_executable,
+                                executable: *executable,
+                            },
+                        }),
+                        Err(_) => plan.delta.exact = false,
+                    }
+                    plan.affected.modified.push(affected_path);
+                }
+                Hunk::AddSymlink { target, .. } => {
+                    let overwritten_content =
+                        plan.read_text_for_delta(&path_abs, fs, sandbox).await;
+                    plan.write_symlink(&path_abs, target.clone(), fs, sandbox)
+                        .await
+                        .with_context(|| {
+                            format!("Failed to create symlink {}", path_abs.display())
+                        })?;
+                    plan.delta.changes.push(AppliedPatchChange {
+                        path: path_abs.into_path_buf(),
+                        change: AppliedPatchFileChange::AddSymlink {
+                            target: target.clone(),
+                            overwritten_content,
+                        },
+                    });
+                    plan.affected.added.push(affected_path);
+                }
+                Hunk::AddBinaryFile { contents, .. } => {
+                    let overwritten_content =
+                        plan.read_text_for_delta(&path_abs, fs, sandbox).await;
+                    plan.write(&path_abs, contents.clone(), fs, sandbox)
+                        .await
+                        .with_context(|| format!("Failed to write file {}", path_abs.display()))?;
+                    plan.delta.changes.push(AppliedPatchChange {
+                        path: path_abs.into_path_buf(),
+                        change: AppliedPatchFileChange::AddBinary {
+                            content: contents.clone(),
+                            overwritten_content,
+                        },
+                    });
+                    plan.affected.added.push(affected_path);
+                }
+            }
+        }
+        Ok(plan)
+    }
+
+    /// The planned file for `path`, if an earlier hunk already touched it.
+    fn planned(&self, path: &AbsolutePathBuf) -> Option<&PlannedFile> {
+        self.files.iter().find(|file| file.path == *path)
+    }
+
+    fn planned_mut(&mut self, path: &AbsolutePathBuf) -> Option<&mut PlannedFile> {
+        self.files.iter_mut().find(|file| file.path == *path)
+    }
+
+    async fn read_text_for_delta(
+        &mut self,
+        path: &AbsolutePathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> Option<String> {
+        if let Some(file) = self.planned(path) {
+            let text = match &file.contents {
+                None => return None,
+                Some(PlannedContents::File { contents, .. }) => {
+                    String::from_utf8(contents.clone()).ok()
+                }
+                Some(PlannedContents::Symlink { .. }) => None,
+            };
+            // Binary files and symlinks have no text to report.
+            self.delta.exact &= text.is_some();
+            return text;
+        }
+        read_optional_file_text_for_delta(path, fs, sandbox, &mut self.delta.exact).await
+    }
+
+    async fn read_to_update(
+        &mut self,
+        path: &AbsolutePathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> Result<String, ApplyPatchError> {
+        if let Some(file) = self.planned(path) {
+            return match &file.contents {
+                Some(PlannedContents::File { contents, .. }) => String::from_utf8(contents.clone())
+                    .map_err(|err| {
+                        read_to_update_error(path, io::Error::new(io::ErrorKind::InvalidData, err))
+                    }),
+                Some(PlannedContents::Symlink { .. }) => Err(read_to_update_error(
+                    path,
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "path is a symlink added earlier in the patch",
+                    ),
+                )),
+                None => Err(read_to_update_error(
+                    path,
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        "file was deleted earlier in the patch",
+                    ),
+                )),
+            };
+        }
+        note_existing_path_delta_support(path, fs, sandbox, &mut self.delta.exact).await;
+        fs.read_file_text(path, sandbox)
+            .await
+            .map_err(|err| read_to_update_error(path, err))
+    }
+
+    async fn write(
+        &mut self,
+        path: &AbsolutePathBuf,
+        contents: Vec<u8>,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<()> {
+        if let Some(file) = self.planned_mut(path) {
+            let executable = match &file.contents {
+                Some(PlannedContents::File { executable, .. }) => *executable,
+                Some(PlannedContents::Symlink { .. }) => return Err(planned_symlink()),
+                None => None,
+            };
+            file.contents = Some(PlannedContents::File {
+                contents,
+                executable,
+            });
+            return Ok(());
+        }
+        self.plan_new(
+            path,
+            PlannedContents::File {
+                contents,
+                executable: None,
+            },
+            fs,
+            sandbox,
+        )
+        .await
+    }
+
+    async fn write_symlink(
+        &mut self,
+        path: &AbsolutePathBuf,
+        target: PathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<()> {
+        if let Some(file) = self.planned_mut(path) {
+            file.contents = Some(PlannedContents::Symlink { target });
+            return Ok(());
+        }
+        self.plan_new(path, PlannedContents::Symlink { target }, fs, sandbox)
+            .await
+    }
+
+    /// Plans `contents` for a path no earlier hunk touched.
+    async fn plan_new(
+        &mut self,
+        path: &AbsolutePathBuf,
+        contents: PlannedContents,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<()> {
+        let original = inspect_path(path, fs, sandbox).await?;
+        if original == OriginalFile::Directory {
+            return Err(is_a_directory());
+        }
+        self.files.push(PlannedFile {
+            path: path.clone(),
+            original,
+            contents: Some(contents),
+        });
+        Ok(())
+    }
+
+    /// Plans a change to the executable bit of a regular file. Returns the
+    /// file's contents and whether it was executable before.
+    async fn set_mode(
+        &mut self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<(Vec<u8>, bool)> {
+        if let Some(file) = self.planned_mut(path) {
+            let original_executable =
+                matches!(file.original, OriginalFile::File { executable: true });
+            return match &mut file.contents {
+                Some(PlannedContents::File {
+                    contents,
+                    executable: planned,
+                }) => {
+                    let was_executable = planned.unwrap_or(original_executable);
+                    *planned = Some(executable);
+                    Ok((contents.clone(), was_executable))
+                }
+                Some(PlannedContents::Symlink { .. }) => Err(planned_symlink()),
+                None => Err(not_found()),
+            };
+        }
+        let was_executable = match inspect_path(path, fs, sandbox).await? {
+            OriginalFile::File { executable } => executable,
+            OriginalFile::Missing => return Err(not_found()),
+            OriginalFile::Directory => return Err(is_a_directory()),
+            OriginalFile::Symlink => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "path is a symlink",
+                ));
+            }
+        };
+        let contents = fs.read_file(path, sandbox).await?;
+        self.files.push(PlannedFile {
+            path: path.clone(),
+            original: OriginalFile::File {
+                executable: was_executable,
+            },
+            contents: Some(PlannedContents::File {
+                contents: contents.clone(),
+                executable: Some(executable),
+            }),
+        });
+        Ok((contents, was_executable))
+    }
+
+    async fn delete(
+        &mut self,
+        path: &AbsolutePathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<()> {
+        if let Some(file) = self.planned_mut(path) {
+            return match file.contents.take() {
+                Some(_) => Ok(()),
+                None => Err(not_found()),
+            };
+        }
+        let original = match inspect_path(path, fs, sandbox).await? {
+            OriginalFile::Missing => return Err(not_found()),
+            OriginalFile::Directory => return Err(is_a_directory()),
+            original @ (OriginalFile::File { .. } | OriginalFile::Symlink) => original,
+        };
+        self.files.push(PlannedFile {
+            path: path.clone(),
+            original,
+            contents: None,
+        });
+        Ok(())
+    }
+}
+
+async fn inspect_path(
+    path: &AbsolutePathBuf,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> io::Result<OriginalFile> {
+    match fs.get_metadata(path, sandbox).await {
+        Ok(metadata) if metadata.is_directory => Ok(OriginalFile::Directory),
+        Ok(metadata) if metadata.is_symlink => Ok(OriginalFile::Symlink),
+        Ok(metadata) => Ok(OriginalFile::File {
+            executable: metadata.is_executable,
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(OriginalFile::Missing),
+        Err(err) => Err(err),
+    }
+}
+
+fn not_found() -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, "No such file or directory")
+}
+
+fn is_a_directory() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "path is a directory")
+}
+
+fn planned_symlink() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "path is a symlink added earlier in the patch",
+    )
+}
+
+/// How a planned file is put into place.
+enum Commit {
+    /// Rename a staged file over a path that did not exist.
+    Create { staged: AbsolutePathBuf },
+    /// Rename a staged file over a regular file, keeping a copy of the
+    /// original to restore.
+    Replace {
+        staged: AbsolutePathBuf,
+        backup: AbsolutePathBuf,
+    },
+    /// Write through a symlink, as a rename would replace the link itself.
+    WriteThrough {
+        contents: Vec<u8>,
+        original: Vec<u8>,
+    },
+    /// Rename the file out of the way; the backup is removed once the whole
+    /// patch has committed.
+    Delete { backup: AbsolutePathBuf },
+}
+
+struct StagedFile {
+    path: AbsolutePathBuf,
+    commit: Commit,
+}
+
+struct CommitError {
+    error: anyhow::Error,
+    /// Whether every file committed before the failure was restored.
+    restored: bool,
+}
+
+/// Stages every planned file, then commits them all, rolling back on failure.
+async fn commit(
+    files: &[PlannedFile],
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> Result<(), CommitError> {
+    let mut staging = Staging::default();
+    for file in files {
+        if let Err(error) = staging.stage(file, fs, sandbox).await {
+            staging.discard(/*keep_backups*/ false, fs, sandbox).await;
+            return Err(CommitError {
+                error,
+                restored: true,
+            });
+        }
+    }
+
+    for (index, file) in staging.files.iter().enumerate() {
+        if let Err(error) = commit_file(file, fs, sandbox).await {
+            // A failed write through a symlink may already have truncated it.
+            let attempted = match file.commit {
+                Commit::WriteThrough { .. } => index + 1,
+                _ => index,
+            };
+            let restore_errors = rollback(&staging.files[..attempted], fs, sandbox).await;
+            let restored = restore_errors.is_empty();
+            // Backups are the only copy of whatever could not be restored.
+            staging
+                .discard(/*keep_backups*/ !restored, fs, sandbox)
+                .await;
+            let error = if restored {
+                error
+            } else {
+                anyhow::anyhow!(
+                    "{error}; the patch was only partly rolled back: {}",
+                    restore_errors.join("; ")
+                )
+            };
+            return Err(CommitError { error, restored });
+        }
+    }
+
+    staging.discard(/*keep_backups*/ false, fs, sandbox).await;
+    Ok(())
+}
+
+async fn commit_file(
+    file: &StagedFile,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> anyhow::Result<()> {
+    let path = &file.path;
+    match &file.commit {
+        Commit::Create { staged } | Commit::Replace { staged, .. } => fs
+            .rename(staged, path, sandbox)
+            .await
+            .with_context(|| format!("Failed to write file {}", path.display())),
+        Commit::WriteThrough { contents, .. } => fs
+            .write_file(path, contents.clone(), sandbox)
+            .await
+            .with_context(|| format!("Failed to write file {}", path.display())),
+        Commit::Delete { backup } => fs
+            .rename(path, backup, sandbox)
+            .await
+            .with_context(|| format!("Failed to delete file {}", path.display())),
+    }
+}
+
+/// Undoes `committed` in reverse order, returning a description of every file
+/// that could not be restored.
+async fn rollback(
+    committed: &[StagedFile],
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for file in committed.iter().rev() {
+        let path = &file.path;
+        let result = match &file.commit {
+            Commit::Create { .. } => {
+                fs.remove(
+                    path,
+                    RemoveOptions {
+                        recursive: false,
+                        force: true,
+                    },
+                    sandbox,
+                )
+                .await
+            }
+            Commit::Replace { backup, .. } | Commit::Delete { backup } => {
+                fs.rename(backup, path, sandbox).await
+            }
+            Commit::WriteThrough { original, .. } => {
+                fs.write_file(path, original.clone(), sandbox).await
+            }
+        };
+        if let Err(err) = result {
+            errors.push(format!("Failed to restore {}: {err}", path.display()));
+        }
+    }
+    errors
+}
+
+/// Files staged for a commit, plus the temporary files and directories created
+/// for them.
+#[derive(Default)]
+struct Staging {
+    files: Vec<StagedFile>,
+    staged: Vec<AbsolutePathBuf>,
+    backups: Vec<AbsolutePathBuf>,
+    created_directories: Vec<AbsolutePathBuf>,
+}
+
+impl Staging {
+    async fn stage(
+        &mut self,
+        file: &PlannedFile,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> anyhow::Result<()> {
+        let path = &file.path;
+        let commit = match (file.original, &file.contents) {
+            (OriginalFile::Missing, None) => return Ok(()),
+            (OriginalFile::Missing, Some(contents)) => {
+                self.create_parent_directories(path, fs, sandbox)
+                    .await
+                    .with_context(|| {
+                        format!("Failed to create parent directories for {}", path.display())
+                    })?;
+                let staged = self.staged_path(path);
+                match contents {
+                    PlannedContents::File {
+                        contents,
+                        executable,
+                    } => {
+                        write_staged_file(path, &staged, contents, *executable, fs, sandbox)
+                            .await?;
+                    }
+                    PlannedContents::Symlink { target } => {
+                        fs.create_symlink(target, &staged, sandbox)
+                            .await
+                            .with_context(|| {
+                                format!("Failed to create symlink {}", path.display())
+                            })?;
+                    }
+                }
+                Commit::Create { staged }
+            }
+            (
+                OriginalFile::File { .. },
+                Some(PlannedContents::File {
+                    contents,
+                    executable,
+                }),
+            ) => {
+                // Copying first keeps the original's permissions on the new file.
+                let staged = self.staged_path(path);
+                fs.copy(path, &staged, CopyOptions { recursive: false }, sandbox)
+                    .await
+                    .with_context(|| format!("Failed to write file {}", path.display()))?;
+                write_staged_file(path, &staged, contents, *executable, fs, sandbox).await?;
+                let backup = self.backup_path(path);
+                fs.copy(path, &backup, CopyOptions { recursive: false }, sandbox)
+                    .await
+                    .with_context(|| format!("Failed to back up {}", path.display()))?;
+                Commit::Replace { staged, backup }
+            }
+            (
+                OriginalFile::File { .. } | OriginalFile::Symlink,
+                Some(PlannedContents::Symlink { target }),
+            ) => {
+                let staged = self.staged_path(path);
+                fs.create_symlink(target, &staged, sandbox)
+                    .await
+                    .with_context(|| format!("Failed to create symlink {}", path.display()))?;
+                // Copying a symlink copies the link, not the file it points to.
+                let backup = self.backup_path(path);
+                fs.copy(path, &backup, CopyOptions { recursive: false }, sandbox)
+                    .await
+                    .with_context(|| format!("Failed to back up {}", path.display()))?;
+                Commit::Replace { staged, backup }
+            }
+            (OriginalFile::Symlink, Some(PlannedContents::File { contents, .. })) => {
+                let original = fs
+                    .read_file(path, sandbox)
+                    .await
+                    .with_context(|| format!("Failed to back up {}", path.display()))?;
+                Commit::WriteThrough {
+                    contents: contents.clone(),
+                    original,
+                }
+            }
+            (OriginalFile::File { .. } | OriginalFile::Symlink, None) => Commit::Delete {
+                backup: self.backup_path(path),
+            },
+            (OriginalFile::Directory, _) => {
+                return Err(is_a_directory())
+                    .with_context(|| format!("Failed to write file {}", path.display()));
+            }
+        };
+        self.files.push(StagedFile {
+            path: path.clone(),
+            commit,
+        });
+        Ok(())
+    }
+
+    fn staged_path(&mut self, path: &AbsolutePathBuf) -> AbsolutePathBuf {
+        let staged = temporary_sibling(path, "new");
+        self.staged.push(staged.clone());
+        staged
+    }
+
+    fn backup_path(&mut self, path: &AbsolutePathBuf) -> AbsolutePathBuf {
+        let backup = temporary_sibling(path, "orig");
+        self.backups.push(backup.clone());
+        backup
+    }
+
+    async fn create_parent_directories(
+        &mut self,
+        path: &AbsolutePathBuf,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> io::Result<()> {
+        let Some(parent) = path.parent() else {
+            return Ok(());
+        };
+        let mut missing = Vec::new();
+        for ancestor in parent.ancestors() {
+            match fs.get_metadata(&ancestor, sandbox).await {
+                Ok(_) => break,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => missing.push(ancestor),
+                Err(err) => return Err(err),
+            }
+        }
+        if missing.is_empty() {
+            return Ok(());
+        }
+        fs.create_directory(&parent, CreateDirectoryOptions { recursive: true }, sandbox)
+            .await?;
+        // `missing` is deepest first, the order they have to be removed in.
+        self.created_directories.extend(missing);
+        Ok(())
+    }
+
+    /// Removes leftover temporary files, and the directories created for the
+    /// patch if they ended up empty.
+    async fn discard(
+        &self,
+        keep_backups: bool,
+        fs: &dyn ExecutorFileSystem,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) {
+        let backups = if keep_backups { &[][..] } else { &self.backups };
+        for path in self.staged.iter().chain(backups) {
+            remove_quietly(path, fs, sandbox).await;
+        }
+        for directory in &self.created_directories {
+            remove_quietly(directory, fs, sandbox).await;
+        }
+    }
+}
+
+/// Writes the staged copy of `path`, applying the planned executable bit.
+async fn write_staged_file(
+    path: &AbsolutePathBuf,
+    staged: &AbsolutePathBuf,
+    contents: &[u8],
+    executable: Option<bool>,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> anyhow::Result<()> {
+    fs.write_file(staged, contents.to_vec(), sandbox)
+        .await
+        .with_context(|| format!("Failed to write file {}", path.display()))?;
+    if let Some(executable) = executable {
+        fs.set_executable(staged, executable, sandbox)
+            .await
+            .with_context(|| format!("Failed to change the mode of {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Best-effort cleanup: missing paths and non-empty directories are left alone.
+async fn remove_quietly(
+    path: &AbsolutePathBuf,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) {
+    let _ = fs
+        .remove(
+            path,
+            RemoveOptions {
+                recursive: false,
+                force: true,
+            },
+            sandbox,
+        )
+        .await;
+}
+
+/// A hidden path next to `path`, so renaming it over `path` stays on the same
+/// filesystem.
+fn temporary_sibling(path: &AbsolutePathBuf, suffix: &str) -> AbsolutePathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let id = STAGING_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let name = format!(
+        ".{file_name}.apply_patch.{}.{id}.{suffix}",
+        std::process::id()
+    );
+    path.parent().unwrap_or_else(|| path.clone()).join(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use codex_exec_server::FileMetadata;
+    use codex_exec_server::LOCAL_FS;
+    use codex_exec_server::ReadDirectoryEntry;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use std::path::Path;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    fn wrap_patch(body: &str) -> String {
+        format!("*** Begin Patch\n{body}\n*** End Patch")
+    }
+
+    fn dir_entries(dir: &Path) -> Vec<String> {
+        let mut entries: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    /// Delegates to the local filesystem but fails renames onto `fail_rename_to`.
+    struct FailingRenameFs {
+        fail_rename_to: PathBuf,
+    }
+
+    #[async_trait]
+    impl ExecutorFileSystem for FailingRenameFs {
+        async fn read_file(
+            &self,
+            path: &AbsolutePathBuf,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<Vec<u8>> {
+            LOCAL_FS.read_file(path, sandbox).await
+        }
+
+        async fn write_file(
+            &self,
+            path: &AbsolutePathBuf,
+            contents: Vec<u8>,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS.write_file(path, contents, sandbox).await
+        }
+
+        async fn create_directory(
+            &self,
+            path: &AbsolutePathBuf,
+            options: CreateDirectoryOptions,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS.create_directory(path, options, sandbox).await
+        }
+
+        async fn get_metadata(
+            &self,
+            path: &AbsolutePathBuf,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<FileMetadata> {
+            LOCAL_FS.get_metadata(path, sandbox).await
+        }
+
+        async fn read_directory(
+            &self,
+            path: &AbsolutePathBuf,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<Vec<ReadDirectoryEntry>> {
+            LOCAL_FS.read_directory(path, sandbox).await
+        }
+
+        async fn remove(
+            &self,
+            path: &AbsolutePathBuf,
+            options: RemoveOptions,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS.remove(path, options, sandbox).await
+        }
+
+        async fn copy(
+            &self,
+            source_path: &AbsolutePathBuf,
+            destination_path: &AbsolutePathBuf,
+            options: CopyOptions,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS
+                .copy(source_path, destination_path, options, sandbox)
+                .await
+        }
+
+        async fn rename(
+            &self,
+            source_path: &AbsolutePathBuf,
+            destination_path: &AbsolutePathBuf,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            if destination_path.as_path() == self.fail_rename_to {
+                return Err(io::Error::other("injected rename failure"));
+            }
+            LOCAL_FS
+                .rename(source_path, destination_path, sandbox)
+                .await
+        }
+
+        async fn set_executable(
+            &self,
+            path: &AbsolutePathBuf,
+            executable: bool,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS.set_executable(path, executable, sandbox).await
+        }
+
+        async fn create_symlink(
+            &self,
+            target: &Path,
+            link_path: &AbsolutePathBuf,
+            sandbox: Option<&FileSystemSandboxContext>,
+        ) -> io::Result<()> {
+            LOCAL_FS.create_symlink(target, link_path, sandbox).await
+        }
+    }
+
+    #[tokio::test]
+    async fn applies_every_hunk_against_the_planned_contents() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        fs::write(dir.path().join("gone.txt"), "bye\n").unwrap();
+        let patch = wrap_patch(
+            "*** Add File: nested/new.txt\n+new\n\
+             *** Update File: a.txt\n@@\n-one\n+two\n\
+             *** Update File: a.txt\n*** Move to: b.txt\n@@\n-two\n+three\n\
+             *** Delete File: gone.txt",
+        );
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        let delta = apply_patch_atomic(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "Success. Updated the following files:\nA nested/new.txt\nM a.txt\nM a.txt\nD gone.txt\n"
+        );
+        assert_eq!(String::from_utf8(stderr).unwrap(), "");
+        assert!(delta.is_exact());
+        assert_eq!(delta.changes().len(), 4);
+        assert_eq!(dir_entries(dir.path()), vec!["b.txt", "nested"]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("b.txt")).unwrap(),
+            "three\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("nested/new.txt")).unwrap(),
+            "new\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_verification_leaves_the_tree_untouched() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        fs::write(dir.path().join("b.txt"), "two\n").unwrap();
+        let patch = wrap_patch(
+            "*** Update File: a.txt\n@@\n-one\n+changed\n\
+             *** Add File: nested/new.txt\n+new\n\
+             *** Update File: b.txt\n@@\n-missing\n+changed",
+        );
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        let failure = apply_patch_atomic(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .expect_err("second update should not verify");
+
+        assert_eq!(failure.delta(), &AppliedPatchDelta::empty());
+        assert!(
+            String::from_utf8(stderr)
+                .unwrap()
+                .starts_with("Failed to find expected lines in")
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["a.txt", "b.txt"]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "one\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_rename_restores_committed_files() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        fs::write(dir.path().join("c.txt"), "three\n").unwrap();
+        fs::write(dir.path().join("gone.txt"), "bye\n").unwrap();
+        let patch = wrap_patch(
+            "*** Update File: a.txt\n@@\n-one\n+changed\n\
+             *** Delete File: gone.txt\n\
+             *** Add File: nested/new.txt\n+new\n\
+             *** Update File: c.txt\n@@\n-three\n+changed",
+        );
+        let file_system = FailingRenameFs {
+            fail_rename_to: dir.path().join("c.txt"),
+        };
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        let failure = apply_patch_atomic(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            &file_system,
+            /*sandbox*/ None,
+        )
+        .await
+        .expect_err("rename onto c.txt should fail");
+
+        assert_eq!(failure.delta(), &AppliedPatchDelta::empty());
+        assert_eq!(
+            String::from_utf8(stderr).unwrap(),
+            format!(
+                "Failed to write file {}\n",
+                dir.path().join("c.txt").display()
+            )
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["a.txt", "c.txt", "gone.txt"]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "one\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("gone.txt")).unwrap(),
+            "bye\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn replaced_files_keep_their_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script = dir.path().join("run.sh");
+        fs::write(&script, "echo one\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let patch = wrap_patch("*** Update File: run.sh\n@@\n-echo one\n+echo two");
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        apply_patch_atomic(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(&script).unwrap(), "echo two\n");
+        assert_eq!(
+            fs::metadata(&script).unwrap().permissions().mode() & 0o777,
+            0o755
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["run.sh"]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn applies_mode_symlink_and_binary_hunks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script = dir.path().join("run.sh");
+        fs::write(&script, "echo one\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o644)).unwrap();
+        fs::write(dir.path().join("latest"), "not a link yet\n").unwrap();
+        let patch = wrap_patch(
+            "*** Update File: run.sh\n@@\n-echo one\n+echo two\n\
+             *** Set Mode: 100755 run.sh\n\
+             *** Add Symlink: latest\n+run.sh\n\
+             *** Add Binary File: data/blob.bin\n+AAEC/w==",
+        );
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        let delta = apply_patch_atomic(
+            &patch,
+            &AbsolutePathBuf::from_absolute_path(dir.path()).unwrap(),
+            &mut stdout,
+            &mut stderr,
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "Success. Updated the following files:\nA latest\nA data/blob.bin\nM run.sh\nM run.sh\n"
+        );
+        assert!(delta.is_exact());
+        assert_eq!(fs::read_to_string(&script).unwrap(), "echo two\n");
+        assert_eq!(
+            fs::metadata(&script).unwrap().permissions().mode() & 0o777,
+            0o755
+        );
+        assert_eq!(
+            fs::read_link(dir.path().join("latest")).unwrap(),
+            PathBuf::from("run.sh")
+        );
+        assert_eq!(
+            fs::read(dir.path().join("data/blob.bin")).unwrap(),
+            vec![0, 1, 2, 255]
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["data", "latest", "run.sh"]);
+    }
+}
@@ -161,13 +161,17 @@ pub async fn maybe_parse_apply_patch_verified(
                 .map(|dir| cwd.join(Path::new(dir)))
                 .unwrap_or_else(|| cwd.clone());
             let mut changes = HashMap::new();
+            let mut modes = Vec::new();
             for hunk in hunks {
                 let path = hunk.resolve_path(&effective_cwd);
                 match hunk {
                     Hunk::AddFile { contents, .. } => {
                         changes.insert(
                             path.into_path_buf(),
-                            ApplyPatchFileChange::Add { content: contents },
+                            ApplyPatchFileChange::Add {
+                                content: contents,
+                                executable: None,
+                            },
                         );
                     }
                     Hunk::DeleteFile { .. } => {
@@ -206,9 +210,39 @@ pub async fn maybe_parse_apply_patch_verified(
                                 unified_diff,
                                 move_path: move_path.map(|p| effective_cwd.join(p).into_path_buf()),
                                 new_content: contents,
+                                executable: None,
                             },
                         );
                     }
+                    Hunk::SetMode { executable, .. } => {
+                        modes.push((path.into_path_buf(), executable));
+                    }
+                    Hunk::AddSymlink { target, .. } => {
+                        changes.insert(
+                            path.into_path_buf(),
+                            ApplyPatchFileChange::AddSymlink { target },
+                        );
+                    }
+                    Hunk::AddBinaryFile { contents, .. } => {
+                        changes.insert(
+                            path.into_path_buf(),
+                            ApplyPatchFileChange::AddBinary { content: contents },
+                        );
+                    }
+                }
+            }
+            // A mode change rides along with an add or update of the same file, whichever
+            // hunk comes first.
+            for (path, mode) in modes {
+                match changes.get_mut(&path) {
+                    Some(
+                        ApplyPatchFileChange::Add { executable, .. }
+                        | ApplyPatchFileChange::Update { executable, .. },
+                    ) => *executable = Some(mode),
+                    Some(_) => {}
+                    None => {
+                        changes.insert(path, ApplyPatchFileChange::SetMode { executable: mode });
+                    }
                 }
             }
             MaybeApplyPatchVerified::Body(ApplyPatchAction {
@@ -797,6 +831,7 @@ PATCH"#,
                         .to_string(),
                         move_path: None,
                         new_content: "updated session directory content\n".to_string(),
+                        executable: None,
                     },
                 )]),
                 patch: argv[1].clone(),
@@ -914,4 +949,35 @@ PATCH"#,
 
         assert!(matches!(result, MaybeApplyPatchVerified::Body(_)));
     }
+
+    #[tokio::test]
+    async fn test_set_mode_before_add_merges_into_add() {
+        let session_dir = tempdir().unwrap();
+        let argv = vec![
+            "apply_patch".to_string(),
+            wrap_patch("*** Set Mode: 100755 run.sh\n*** Add File: run.sh\n+echo hi"),
+        ];
+
+        let result = maybe_parse_apply_patch_verified(
+            &argv,
+            &AbsolutePathBuf::from_absolute_path(session_dir.path()).unwrap(),
+            LOCAL_FS.as_ref(),
+            /*sandbox*/ None,
+        )
+        .await;
+
+        match result {
+            MaybeApplyPatchVerified::Body(action) => assert_eq!(
+                action.changes,
+                HashMap::from([(
+                    session_dir.path().join("run.sh"),
+                    ApplyPatchFileChange::Add {
+                        content: "echo hi\n".to_string(),
+                        executable: Some(true),
+                    },
+                )])
+            ),
+            result => panic!("expected MaybeApplyPatchVerified::Body got {result:?}"),
+        }
+    }
 }
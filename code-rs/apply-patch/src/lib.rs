@@ -1,8 +1,10 @@
+mod atomic;
 mod invocation;
 mod parser;
 mod seek_sequence;
 mod standalone_executable;
 mod streaming_parser;
+mod unified_diff;
 
 use std::collections::HashMap;
 use std::io;
@@ -19,12 +21,16 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 pub use parser::Hunk;
 pub use parser::ParseError;
 use parser::ParseError::*;
+pub use parser::PatchFormat;
 pub use parser::UpdateFileChunk;
+pub use parser::detect_patch_format;
 pub use parser::parse_patch;
 use similar::TextDiff;
 pub use streaming_parser::StreamingPatchParser;
 use thiserror::Error;
 
+pub use atomic::apply_hunks_atomic;
+pub use atomic::apply_patch_atomic;
 pub use invocation::maybe_parse_apply_patch_verified;
 pub use standalone_executable::main;
 
@@ -103,6 +109,8 @@ pub struct ApplyPatchArgs {
 pub enum ApplyPatchFileChange {
     Add {
         content: String,
+        /// Set when the patch also changes the file's executable bit.
+        executable: Option<bool>,
     },
     Delete {
         content: String,
@@ -112,6 +120,17 @@ pub enum ApplyPatchFileChange {
         move_path: Option<PathBuf>,
         /// new_content that will result after the unified_diff is applied.
         new_content: String,
+        /// Set when the patch also changes the file's executable bit.
+        executable: Option<bool>,
+    },
+    SetMode {
+        executable: bool,
+    },
+    AddSymlink {
+        target: PathBuf,
+    },
+    AddBinary {
+        content: Vec<u8>,
     },
 }
 
@@ -170,7 +189,13 @@ impl ApplyPatchAction {
 + {content}
 *** End Patch"#,
         );
-        let changes = HashMap::from([(path.to_path_buf(), ApplyPatchFileChange::Add { content })]);
+        let changes = HashMap::from([(
+            path.to_path_buf(),
+            ApplyPatchFileChange::Add {
+                content,
+                executable: None,
+            },
+        )]);
         #[expect(clippy::expect_used)]
         Self {
             changes,
@@ -243,6 +268,20 @@ pub enum AppliedPatchFileChange {
         overwritten_move_content: Option<String>,
         new_content: String,
     },
+    /// The executable bit of a text file changed; `content` is unchanged.
+    SetMode {
+        content: String,
+        was_executable: bool,
+        executable: bool,
+    },
+    AddSymlink {
+        target: PathBuf,
+        overwritten_content: Option<String>,
+    },
+    AddBinary {
+        content: Vec<u8>,
+        overwritten_content: Option<String>,
+    },
 }
 
 /// A failed patch application together with the textual mutations that were
@@ -274,6 +313,9 @@ impl ApplyPatchFailure {
 }
 
 /// Applies the patch and prints the result to stdout/stderr.
+///
+/// Hunks are applied one at a time, so a failure can leave earlier hunks
+/// applied; see [`apply_patch_atomic`] for the all-or-nothing variant.
 pub async fn apply_patch(
     patch: &str,
     cwd: &AbsolutePathBuf,
@@ -282,8 +324,17 @@ pub async fn apply_patch(
     fs: &dyn ExecutorFileSystem,
     sandbox: Option<&FileSystemSandboxContext>,
 ) -> Result<AppliedPatchDelta, ApplyPatchFailure> {
-    let hunks = match parse_patch(patch) {
-        Ok(source) => source.hunks,
+    let hunks = parse_patch_reporting_errors(patch, stderr)?;
+    apply_hunks(&hunks, cwd, stdout, stderr, fs, sandbox).await
+}
+
+/// Parses `patch`, describing parse errors on stderr.
+fn parse_patch_reporting_errors(
+    patch: &str,
+    stderr: &mut impl std::io::Write,
+) -> Result<Vec<Hunk>, ApplyPatchFailure> {
+    match parse_patch(patch) {
+        Ok(source) => Ok(source.hunks),
         Err(e) => {
             match &e {
                 InvalidPatchError(message) => {
@@ -303,13 +354,11 @@ pub async fn apply_patch(
                     .map_err(ApplyPatchFailure::without_delta)?;
                 }
             }
-            return Err(ApplyPatchFailure::without_delta(
+            Err(ApplyPatchFailure::without_delta(
                 ApplyPatchError::ParseError(e),
-            ));
+            ))
         }
-    };
-
-    apply_hunks(&hunks, cwd, stdout, stderr, fs, sandbox).await
+    }
 }
 
 /// Applies hunks and continues to update stdout/stderr
@@ -329,24 +378,32 @@ pub async fn apply_hunks(
             })?;
             Ok(delta)
         }
-        Err(error) => {
-            let msg = error.to_string();
-            writeln!(stderr, "{msg}").map_err(|error| {
-                ApplyPatchFailure::new(ApplyPatchError::from(error), delta.clone())
-            })?;
-            let error = if let Some(io) = error.downcast_ref::<std::io::Error>() {
-                ApplyPatchError::from(io)
-            } else {
-                ApplyPatchError::IoError(IoError {
-                    context: msg,
-                    source: std::io::Error::other(error),
-                })
-            };
-            Err(ApplyPatchFailure::new(error, delta))
-        }
+        Err(error) => Err(report_failure(error, delta, stderr)),
     }
 }
 
+/// Describes `error` on stderr and pairs it with the changes that were
+/// committed before it happened.
+fn report_failure(
+    error: anyhow::Error,
+    delta: AppliedPatchDelta,
+    stderr: &mut impl std::io::Write,
+) -> ApplyPatchFailure {
+    let msg = error.to_string();
+    if let Err(error) = writeln!(stderr, "{msg}") {
+        return ApplyPatchFailure::new(ApplyPatchError::from(error), delta);
+    }
+    let error = if let Some(io) = error.downcast_ref::<std::io::Error>() {
+        ApplyPatchError::from(io)
+    } else {
+        ApplyPatchError::IoError(IoError {
+            context: msg,
+            source: std::io::Error::other(error),
+        })
+    };
+    ApplyPatchFailure::new(error, delta)
+}
+
 /// Applies each parsed patch hunk to the filesystem.
 /// Returns an error if any of the changes could not be applied.
 /// Tracks file paths affected by applying a patch, preserving the path spelling
@@ -541,6 +598,69 @@ async fn apply_hunks_to_files(
                     modified.push(affected_path);
                 }
             }
+            Hunk::SetMode { executable, .. } => {
+                let metadata = fs.get_metadata(&path_abs, sandbox).await.with_context(|| {
+                    format!("Failed to change the mode of {}", path_abs.display())
+                })?;
+                if !metadata.is_file || metadata.is_symlink {
+                    anyhow::bail!(
+                        "Failed to change the mode of {}: not a regular file",
+                        path_abs.display()
+                    );
+                }
+                let content = fs.read_file_text(&path_abs, sandbox).await.ok();
+                try_write!(
+                    fs.set_executable(&path_abs, *executable, sandbox)
+                        .await
+                        .with_context(|| format!(
+                            "Failed to change the mode of {}",
+                            path_abs.display()
+                        ))
+                );
+                match content {
+                    Some(content) => delta.changes.push(AppliedPatchChange {
+                        path: path_abs.into_path_buf(),
+                        change: AppliedPatchFileChange::SetMode {
+                            content,
+                            was_executable: metadata.is_executable,
+                            executable: *executable,
+                        },
+                    }),
+                    None => delta.exact = false,
+                }
+                modified.push(affected_path);
+            }
+            Hunk::AddSymlink { target, .. } => {
+                let overwritten_content =
+                    read_optional_file_text_for_delta(&path_abs, fs, sandbox, &mut delta.exact)
+                        .await;
+                try_write!(replace_with_symlink(fs, &path_abs, target, sandbox).await);
+                delta.changes.push(AppliedPatchChange {
+                    path: path_abs.into_path_buf(),
+                    change: AppliedPatchFileChange::AddSymlink {
+                        target: target.clone(),
+                        overwritten_content,
+                    },
+                });
+                added.push(affected_path);
+            }
+            Hunk::AddBinaryFile { contents, .. } => {
+                let overwritten_content =
+                    read_optional_file_text_for_delta(&path_abs, fs, sandbox, &mut delta.exact)
+                        .await;
+                try_write!(
+                    write_file_with_missing_parent_retry(fs, &path_abs, contents.clone(), sandbox)
+                        .await
+                );
+                delta.changes.push(AppliedPatchChange {
+                    path: path_abs.into_path_buf(),
+                    change: AppliedPatchFileChange::AddBinary {
+                        content: contents.clone(),
+                        overwritten_content,
+                    },
+                });
+                added.push(affected_path);
+            }
         }
     }
     Ok(AffectedPaths {
@@ -645,6 +765,50 @@ async fn write_file_with_missing_parent_retry(
     }
 }
 
+/// Creates a symlink at `path_abs` pointing at `target`, replacing any file already there.
+async fn replace_with_symlink(
+    fs: &dyn ExecutorFileSystem,
+    path_abs: &AbsolutePathBuf,
+    target: &Path,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> anyhow::Result<()> {
+    let context = || format!("Failed to create symlink {}", path_abs.display());
+    if fs
+        .get_metadata(path_abs, sandbox)
+        .await
+        .is_ok_and(|metadata| metadata.is_directory && !metadata.is_symlink)
+    {
+        anyhow::bail!("{}: path is a directory", context());
+    }
+    fs.remove(
+        path_abs,
+        RemoveOptions {
+            recursive: false,
+            force: true,
+        },
+        sandbox,
+    )
+    .await
+    .with_context(context)?;
+    if let Some(parent_abs) = path_abs.parent() {
+        fs.create_directory(
+            &parent_abs,
+            CreateDirectoryOptions { recursive: true },
+            sandbox,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to create parent directories for {}",
+                path_abs.display()
+            )
+        })?;
+    }
+    fs.create_symlink(target, path_abs, sandbox)
+        .await
+        .with_context(context)
+}
+
 struct AppliedPatch {
     original_contents: String,
     new_contents: String,
@@ -658,13 +822,26 @@ async fn derive_new_contents_from_chunks(
     fs: &dyn ExecutorFileSystem,
     sandbox: Option<&FileSystemSandboxContext>,
 ) -> std::result::Result<AppliedPatch, ApplyPatchError> {
-    let original_contents = fs.read_file_text(path_abs, sandbox).await.map_err(|err| {
-        ApplyPatchError::IoError(IoError {
-            context: format!("Failed to read file to update {}", path_abs.display()),
-            source: err,
-        })
-    })?;
+    let original_contents = fs
+        .read_file_text(path_abs, sandbox)
+        .await
+        .map_err(|err| read_to_update_error(path_abs, err))?;
+    derive_new_contents(original_contents, path_abs.as_path(), chunks)
+}
 
+fn read_to_update_error(path_abs: &AbsolutePathBuf, err: io::Error) -> ApplyPatchError {
+    ApplyPatchError::IoError(IoError {
+        context: format!("Failed to read file to update {}", path_abs.display()),
+        source: err,
+    })
+}
+
+/// Applies the chunks to `original_contents`.
+fn derive_new_contents(
+    original_contents: String,
+    path: &Path,
+    chunks: &[UpdateFileChunk],
+) -> std::result::Result<AppliedPatch, ApplyPatchError> {
     let mut original_lines: Vec<String> = original_contents.split('\n').map(String::from).collect();
 
     // Drop the trailing empty element that results from the final newline so
@@ -673,7 +850,7 @@ async fn derive_new_contents_from_chunks(
         original_lines.pop();
     }
 
-    let replacements = compute_replacements(&original_lines, path_abs.as_path(), chunks)?;
+    let replacements = compute_replacements(&original_lines, path, chunks)?;
     let new_lines = apply_replacements(original_lines, &replacements);
     let mut new_lines = new_lines;
     if !new_lines.last().is_some_and(String::is_empty) {
@@ -3,16 +3,21 @@
 //!
 //! The official Lark grammar for the apply-patch format is:
 //!
-//! start: begin_patch hunk+ end_patch
+//! start: begin_patch hunk+ end_patch | unified_diff
 //! begin_patch: "*** Begin Patch" LF
 //! end_patch: "*** End Patch" LF?
 //!
-//! hunk: add_hunk | delete_hunk | update_hunk
+//! hunk: add_hunk | delete_hunk | update_hunk | set_mode_hunk | add_symlink_hunk | add_binary_hunk
 //! add_hunk: "*** Add File: " filename LF add_line+
 //! delete_hunk: "*** Delete File: " filename LF
 //! update_hunk: "*** Update File: " filename LF change_move? change?
+//! set_mode_hunk: "*** Set Mode: " file_mode " " filename LF
+//! add_symlink_hunk: "*** Add Symlink: " filename LF "+" /(.+)/ LF
+//! add_binary_hunk: "*** Add Binary File: " filename LF base64_line*
 //! filename: /(.+)/
 //! add_line: "+" /(.+)/ LF -> line
+//! file_mode: "100755" | "755" | "100644" | "644"
+//! base64_line: "+" /[A-Za-z0-9+\/=]*/ LF
 //!
 //! change_move: "*** Move to: " filename LF
 //! change: (change_context | change_line)+ eof_line?
@@ -20,9 +25,22 @@
 //! change_line: ("+" | "-" | " ") /(.+)/ LF
 //! eof_line: "*** End of File" LF
 //!
+//! unified_diff: file_diff+
+//! file_diff: git_header file_header? diff_hunk* | file_header diff_hunk+
+//! git_header: "diff --git " /(.+)/ LF git_header_line*
+//! git_header_line: /(index|old mode|new mode|new file mode|deleted file mode|similarity index|dissimilarity index|rename from|rename to) .+/ LF
+//! file_header: "--- " /(.+)/ LF "+++ " /(.+)/ LF
+//! diff_hunk: "@@" /(.*)/ LF diff_line+
+//! diff_line: ("+" | "-" | " ") /(.*)/ LF | "\\ No newline at end of file" LF
+//!
 //! The parser below is a little more lenient than the explicit spec and allows for
 //! leading/trailing whitespace around patch markers.
+//!
+//! Unified diffs are parsed by [`crate::unified_diff`] into the same hunks.
 use crate::ApplyPatchArgs;
+use crate::unified_diff;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use codex_utils_absolute_path::AbsolutePathBuf;
 #[cfg(test)]
 use codex_utils_absolute_path::test_support::PathBufExt;
@@ -36,10 +54,14 @@ pub(crate) const END_PATCH_MARKER: &str = "*** End Patch";
 pub(crate) const ADD_FILE_MARKER: &str = "*** Add File: ";
 pub(crate) const DELETE_FILE_MARKER: &str = "*** Delete File: ";
 pub(crate) const UPDATE_FILE_MARKER: &str = "*** Update File: ";
+pub(crate) const SET_MODE_MARKER: &str = "*** Set Mode: ";
+pub(crate) const ADD_SYMLINK_MARKER: &str = "*** Add Symlink: ";
+pub(crate) const ADD_BINARY_FILE_MARKER: &str = "*** Add Binary File: ";
 pub(crate) const MOVE_TO_MARKER: &str = "*** Move to: ";
 pub(crate) const EOF_MARKER: &str = "*** End of File";
 pub(crate) const CHANGE_CONTEXT_MARKER: &str = "@@ ";
 pub(crate) const EMPTY_CHANGE_CONTEXT_MARKER: &str = "@@";
+pub(crate) const VALID_HUNK_HEADERS: &str = "'*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}', '*** Set Mode: {mode} {path}', '*** Add Symlink: {path}', '*** Add Binary File: {path}'";
 
 /// Currently, the only OpenAI model that knowingly requires lenient parsing is
 /// gpt-4.1. While we could try to require everyone to pass in a strictness
@@ -76,13 +98,34 @@ pub enum Hunk {
         /// should occur later in the file than the previous chunk.
         chunks: Vec<UpdateFileChunk>,
     },
+    /// Sets or clears the executable bit of an existing file.
+    SetMode {
+        path: PathBuf,
+        executable: bool,
+    },
+    /// Creates a symlink at `path`, replacing whatever file is there.
+    AddSymlink {
+        path: PathBuf,
+        /// Stored as given, so a relative target resolves against the
+        /// directory of `path`.
+        target: PathBuf,
+    },
+    /// Like [`Hunk::AddFile`], for contents that are not text.
+    AddBinaryFile {
+        path: PathBuf,
+        contents: Vec<u8>,
+    },
 }
 
 impl Hunk {
     pub fn resolve_path(&self, cwd: &AbsolutePathBuf) -> AbsolutePathBuf {
         let path = match self {
             Hunk::UpdateFile { path, .. } => path,
-            Hunk::AddFile { .. } | Hunk::DeleteFile { .. } => self.path(),
+            Hunk::AddFile { .. }
+            | Hunk::DeleteFile { .. }
+            | Hunk::SetMode { .. }
+            | Hunk::AddSymlink { .. }
+            | Hunk::AddBinaryFile { .. } => self.path(),
         };
         AbsolutePathBuf::resolve_path_against_base(path, cwd)
     }
@@ -92,6 +135,9 @@ impl Hunk {
         match self {
             Hunk::AddFile { path, .. } => path,
             Hunk::DeleteFile { path } => path,
+            Hunk::SetMode { path, .. } => path,
+            Hunk::AddSymlink { path, .. } => path,
+            Hunk::AddBinaryFile { path, .. } => path,
             Hunk::UpdateFile {
                 move_path: Some(path),
                 ..
@@ -123,13 +169,36 @@ pub struct UpdateFileChunk {
     pub is_end_of_file: bool,
 }
 
+/// The formats [`parse_patch`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchFormat {
+    /// The `*** Begin Patch` envelope described above.
+    ApplyPatch,
+    /// A unified diff, as produced by `git diff` or `diff -u`.
+    UnifiedDiff,
+}
+
+/// Parses `patch`, detecting whether it is an apply_patch envelope or a unified diff.
 pub fn parse_patch(patch: &str) -> Result<ApplyPatchArgs, ParseError> {
-    let mode = if PARSE_IN_STRICT_MODE {
+    parse_patch_text(patch, default_parse_mode())
+}
+
+/// Reports which format [`parse_patch`] will parse `patch` as, without validating it. Text that
+/// is not a unified diff is reported as [`PatchFormat::ApplyPatch`].
+pub fn detect_patch_format(patch: &str) -> PatchFormat {
+    let lines: Vec<&str> = patch.trim().lines().collect();
+    match unified_diff_lines(&lines, &default_parse_mode()) {
+        Some(_) => PatchFormat::UnifiedDiff,
+        None => PatchFormat::ApplyPatch,
+    }
+}
+
+fn default_parse_mode() -> ParseMode {
+    if PARSE_IN_STRICT_MODE {
         ParseMode::Strict
     } else {
         ParseMode::Lenient
-    };
-    parse_patch_text(patch, mode)
+    }
 }
 
 enum ParseMode {
@@ -173,6 +242,13 @@ enum ParseMode {
 
 fn parse_patch_text(patch: &str, mode: ParseMode) -> Result<ApplyPatchArgs, ParseError> {
     let lines: Vec<&str> = patch.trim().lines().collect();
+    if let Some((diff_lines, first_line_number)) = unified_diff_lines(&lines, &mode) {
+        return Ok(ApplyPatchArgs {
+            hunks: unified_diff::parse_unified_diff(diff_lines, first_line_number)?,
+            patch: diff_lines.join("\n"),
+            workdir: None,
+        });
+    }
     let (patch_lines, hunk_lines) = match mode {
         ParseMode::Strict => check_patch_boundaries_strict(&lines)?,
         ParseMode::Lenient => check_patch_boundaries_lenient(&lines)?,
@@ -195,6 +271,23 @@ fn parse_patch_text(patch: &str, mode: ParseMode) -> Result<ApplyPatchArgs, Pars
     })
 }
 
+/// Returns the lines of the unified diff in `lines`, if that is what they hold, along with the
+/// line number of the first one.
+fn unified_diff_lines<'a>(
+    lines: &'a [&'a str],
+    mode: &ParseMode,
+) -> Option<(&'a [&'a str], usize)> {
+    if unified_diff::is_unified_diff(lines) {
+        return Some((lines, 1));
+    }
+    match mode {
+        ParseMode::Strict => None,
+        ParseMode::Lenient => strip_heredoc_markers(lines)
+            .filter(|inner_lines| unified_diff::is_unified_diff(inner_lines))
+            .map(|inner_lines| (inner_lines, 2)),
+    }
+}
+
 /// Checks the start and end lines of the patch text for `apply_patch`,
 /// returning an error if they do not match the expected markers.
 fn check_patch_boundaries_strict<'a>(
@@ -224,19 +317,24 @@ fn check_patch_boundaries_lenient<'a>(
         Err(e) => e,
     };
 
-    match original_lines {
-        [first, .., last] => {
+    match strip_heredoc_markers(original_lines) {
+        Some(inner_lines) => check_patch_boundaries_strict(inner_lines),
+        None => Err(original_parse_error),
+    }
+}
+
+/// Returns the lines between the `<<EOF` and `EOF` heredoc markers, if `lines` are wrapped in
+/// them.
+fn strip_heredoc_markers<'a>(lines: &'a [&'a str]) -> Option<&'a [&'a str]> {
+    match lines {
+        [first, .., last]
             if (first == &"<<EOF" || first == &"<<'EOF'" || first == &"<<\"EOF\"")
                 && last.ends_with("EOF")
-                && original_lines.len() >= 4
-            {
-                let inner_lines = &original_lines[1..original_lines.len() - 1];
-                check_patch_boundaries_strict(inner_lines)
-            } else {
-                Err(original_parse_error)
-            }
+                && lines.len() >= 4 =>
+        {
+            Some(&lines[1..lines.len() - 1])
         }
-        _ => Err(original_parse_error),
+        _ => None,
     }
 }
 
@@ -344,14 +442,95 @@ fn parse_one_hunk(lines: &[&str], line_number: usize) -> Result<(Hunk, usize), P
         ));
     }
 
+    if let Some(mode_and_path) = first_line.strip_prefix(SET_MODE_MARKER) {
+        return Ok((parse_set_mode(mode_and_path, line_number)?, 1));
+    } else if let Some(path) = first_line.strip_prefix(ADD_SYMLINK_MARKER) {
+        let target = lines
+            .get(1)
+            .and_then(|line| line.strip_prefix('+'))
+            .filter(|target| !target.is_empty())
+            .ok_or_else(|| missing_symlink_target(path, line_number))?;
+        return Ok((
+            AddSymlink {
+                path: PathBuf::from(path),
+                target: PathBuf::from(target),
+            },
+            2,
+        ));
+    } else if let Some(path) = first_line.strip_prefix(ADD_BINARY_FILE_MARKER) {
+        let encoded_lines: Vec<&str> = lines[1..]
+            .iter()
+            .map_while(|line| line.strip_prefix('+'))
+            .collect();
+        return Ok((
+            AddBinaryFile {
+                path: PathBuf::from(path),
+                contents: decode_binary_contents(path, &encoded_lines.concat(), line_number)?,
+            },
+            1 + encoded_lines.len(),
+        ));
+    }
+
     Err(InvalidHunkError {
         message: format!(
-            "'{first_line}' is not a valid hunk header. Valid hunk headers: '*** Add File: {{path}}', '*** Delete File: {{path}}', '*** Update File: {{path}}'"
+            "'{first_line}' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
         ),
         line_number,
     })
 }
 
+/// Parses the `{mode} {path}` that follows `*** Set Mode: `.
+pub(crate) fn parse_set_mode(mode_and_path: &str, line_number: usize) -> Result<Hunk, ParseError> {
+    let (mode, path) = mode_and_path.split_once(' ').unwrap_or((mode_and_path, ""));
+    let executable = match mode {
+        "100755" | "755" => true,
+        "100644" | "644" => false,
+        _ => {
+            return Err(InvalidHunkError {
+                message: format!(
+                    "Invalid mode '{mode}' in '{SET_MODE_MARKER}{mode_and_path}'. Use 100755 for an executable file or 100644 for a regular one"
+                ),
+                line_number,
+            });
+        }
+    };
+    let path = path.trim_start();
+    if path.is_empty() {
+        return Err(InvalidHunkError {
+            message: format!("Missing path in '{SET_MODE_MARKER}{mode_and_path}'"),
+            line_number,
+        });
+    }
+    Ok(SetMode {
+        path: PathBuf::from(path),
+        executable,
+    })
+}
+
+pub(crate) fn missing_symlink_target(path: &str, line_number: usize) -> ParseError {
+    InvalidHunkError {
+        message: format!(
+            "Add symlink hunk for path '{path}' must be followed by a single '+' line holding the link target"
+        ),
+        line_number,
+    }
+}
+
+/// Decodes the base64 lines of an `*** Add Binary File` hunk.
+pub(crate) fn decode_binary_contents(
+    path: &str,
+    encoded: &str,
+    line_number: usize,
+) -> Result<Vec<u8>, ParseError> {
+    let encoded: String = encoded.split_whitespace().collect();
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|err| InvalidHunkError {
+            message: format!("Add binary file hunk for path '{path}' is not valid base64: {err}"),
+            line_number,
+        })
+}
+
 fn parse_update_file_chunk(
     lines: &[&str],
     line_number: usize,
@@ -449,7 +628,8 @@ fn test_parse_one_hunk() {
         parse_one_hunk(&["bad"], /*line_number*/ 234),
         Err(InvalidHunkError {
             message: "'bad' is not a valid hunk header. \
-            Valid hunk headers: '*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}'".to_string(),
+            Valid hunk headers: '*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}', \
+            '*** Set Mode: {mode} {path}', '*** Add Symlink: {path}', '*** Add Binary File: {path}'".to_string(),
             line_number: 234
         })
     );
@@ -891,3 +1071,99 @@ fn test_parse_patch_lenient() {
         ))
     );
 }
+
+#[test]
+fn test_parse_patch_detects_unified_diff() {
+    let diff = "diff --git a/file.py b/file.py\n--- a/file.py\n+++ b/file.py\n@@ -1,2 +1,2 @@\n import foo\n-foo.old()\n+foo.new()";
+    let expected = || ApplyPatchArgs {
+        hunks: vec![UpdateFile {
+            path: PathBuf::from("file.py"),
+            move_path: None,
+            chunks: vec![UpdateFileChunk {
+                change_context: None,
+                old_lines: vec!["import foo".to_string(), "foo.old()".to_string()],
+                new_lines: vec!["import foo".to_string(), "foo.new()".to_string()],
+                is_end_of_file: false,
+            }],
+        }],
+        patch: diff.to_string(),
+        workdir: None,
+    };
+    assert_eq!(detect_patch_format(diff), PatchFormat::UnifiedDiff);
+    assert_eq!(parse_patch(diff), Ok(expected()));
+
+    let heredoc = format!("<<'EOF'\n{diff}\nEOF\n");
+    assert_eq!(detect_patch_format(&heredoc), PatchFormat::UnifiedDiff);
+    assert_eq!(
+        parse_patch_text(&heredoc, ParseMode::Lenient),
+        Ok(expected())
+    );
+    assert_eq!(
+        parse_patch_text(&heredoc, ParseMode::Strict),
+        Err(InvalidPatchError(
+            "The first line of the patch must be '*** Begin Patch'".to_string()
+        ))
+    );
+
+    assert_eq!(
+        detect_patch_format("*** Begin Patch\n*** Delete File: file.py\n*** End Patch"),
+        PatchFormat::ApplyPatch
+    );
+    assert_eq!(
+        detect_patch_format("--- not a diff"),
+        PatchFormat::ApplyPatch
+    );
+}
+
+#[test]
+fn test_parse_patch_mode_symlink_and_binary_hunks() {
+    let patch = "*** Begin Patch\n\
+                 *** Set Mode: 755 scripts/build.sh\n\
+                 *** Add Symlink: latest\n\
+                 +releases/v2\n\
+                 *** Add Binary File: assets/icon.png\n\
+                 +iVBORw0K\n\
+                 +GgA=\n\
+                 *** Set Mode: 100644 path with spaces.sh\n\
+                 *** End Patch";
+    assert_eq!(
+        parse_patch(patch).map(|args| args.hunks),
+        Ok(vec![
+            SetMode {
+                path: PathBuf::from("scripts/build.sh"),
+                executable: true,
+            },
+            AddSymlink {
+                path: PathBuf::from("latest"),
+                target: PathBuf::from("releases/v2"),
+            },
+            AddBinaryFile {
+                path: PathBuf::from("assets/icon.png"),
+                contents: vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, 0x00],
+            },
+            SetMode {
+                path: PathBuf::from("path with spaces.sh"),
+                executable: false,
+            },
+        ])
+    );
+
+    assert_eq!(
+        parse_patch("*** Begin Patch\n*** Set Mode: 777 run.sh\n*** End Patch"),
+        Err(InvalidHunkError {
+            message: "Invalid mode '777' in '*** Set Mode: 777 run.sh'. Use 100755 for an executable file or 100644 for a regular one".to_string(),
+            line_number: 2,
+        })
+    );
+    assert_eq!(
+        parse_patch("*** Begin Patch\n*** Add Symlink: latest\n*** End Patch"),
+        Err(InvalidHunkError {
+            message: "Add symlink hunk for path 'latest' must be followed by a single '+' line holding the link target".to_string(),
+            line_number: 2,
+        })
+    );
+    assert!(matches!(
+        parse_patch("*** Begin Patch\n*** Add Binary File: a.bin\n+not base64!\n*** End Patch"),
+        Err(InvalidHunkError { line_number: 2, .. })
+    ));
+}
@@ -65,7 +65,7 @@ pub fn run_main() -> i32 {
             return 1;
         }
     };
-    match runtime.block_on(crate::apply_patch(
+    match runtime.block_on(crate::apply_patch_atomic(
         &patch_arg,
         &cwd,
         &mut stdout,
@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
+use crate::parser::ADD_BINARY_FILE_MARKER;
 use crate::parser::ADD_FILE_MARKER;
+use crate::parser::ADD_SYMLINK_MARKER;
 use crate::parser::BEGIN_PATCH_MARKER;
 use crate::parser::CHANGE_CONTEXT_MARKER;
 use crate::parser::DELETE_FILE_MARKER;
@@ -10,8 +12,14 @@ use crate::parser::EOF_MARKER;
 use crate::parser::Hunk;
 use crate::parser::MOVE_TO_MARKER;
 use crate::parser::ParseError;
+use crate::parser::SET_MODE_MARKER;
 use crate::parser::UPDATE_FILE_MARKER;
 use crate::parser::UpdateFileChunk;
+use crate::parser::VALID_HUNK_HEADERS;
+use crate::parser::decode_binary_contents;
+use crate::parser::missing_symlink_target;
+use crate::parser::parse_set_mode;
+use crate::unified_diff;
 
 use Hunk::*;
 use ParseError::*;
@@ -21,6 +29,9 @@ pub struct StreamingPatchParser {
     line_buffer: String,
     state: StreamingParserState,
     line_number: usize,
+    /// The lines received so far when the patch is a unified diff. Unified diffs are reparsed
+    /// as a whole because a hunk's lines only make sense once its file header is known.
+    unified_diff_lines: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -39,7 +50,17 @@ enum StreamingParserMode {
     UpdateFile {
         hunk_line_number: usize,
     },
+    SetMode,
+    AddSymlink {
+        hunk_line_number: usize,
+    },
+    AddBinaryFile {
+        hunk_line_number: usize,
+        /// The base64 lines so far; decoded once the hunk ends.
+        encoded: String,
+    },
     EndedPatch,
+    UnifiedDiff,
 }
 
 impl StreamingPatchParser {
@@ -74,14 +95,40 @@ impl StreamingPatchParser {
         Ok(())
     }
 
+    /// Completes the hunk in progress before a new hunk header or the end of the patch.
+    fn finish_hunk(&mut self, line: &str) -> Result<(), ParseError> {
+        self.ensure_update_hunk_is_not_empty(line)?;
+        match (&mut self.state.mode, self.state.hunks.last_mut()) {
+            (
+                StreamingParserMode::AddSymlink { hunk_line_number },
+                Some(AddSymlink { path, target }),
+            ) if target.as_os_str().is_empty() => Err(missing_symlink_target(
+                &path.to_string_lossy(),
+                *hunk_line_number,
+            )),
+            (
+                StreamingParserMode::AddBinaryFile {
+                    hunk_line_number,
+                    encoded,
+                },
+                Some(AddBinaryFile { path, contents }),
+            ) => {
+                *contents =
+                    decode_binary_contents(&path.to_string_lossy(), encoded, *hunk_line_number)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn handle_hunk_headers_and_end_patch(&mut self, trimmed: &str) -> Result<bool, ParseError> {
         if trimmed == END_PATCH_MARKER {
-            self.ensure_update_hunk_is_not_empty(trimmed)?;
+            self.finish_hunk(trimmed)?;
             self.state.mode = StreamingParserMode::EndedPatch;
             return Ok(true);
         }
         if let Some(path) = trimmed.strip_prefix(ADD_FILE_MARKER) {
-            self.ensure_update_hunk_is_not_empty(trimmed)?;
+            self.finish_hunk(trimmed)?;
             self.state.hunks.push(AddFile {
                 path: PathBuf::from(path),
                 contents: String::new(),
@@ -90,7 +137,7 @@ impl StreamingPatchParser {
             return Ok(true);
         }
         if let Some(path) = trimmed.strip_prefix(DELETE_FILE_MARKER) {
-            self.ensure_update_hunk_is_not_empty(trimmed)?;
+            self.finish_hunk(trimmed)?;
             self.state.hunks.push(DeleteFile {
                 path: PathBuf::from(path),
             });
@@ -98,7 +145,7 @@ impl StreamingPatchParser {
             return Ok(true);
         }
         if let Some(path) = trimmed.strip_prefix(UPDATE_FILE_MARKER) {
-            self.ensure_update_hunk_is_not_empty(trimmed)?;
+            self.finish_hunk(trimmed)?;
             self.state.hunks.push(UpdateFile {
                 path: PathBuf::from(path),
                 move_path: None,
@@ -109,10 +156,42 @@ impl StreamingPatchParser {
             };
             return Ok(true);
         }
+        if let Some(mode_and_path) = trimmed.strip_prefix(SET_MODE_MARKER) {
+            self.finish_hunk(trimmed)?;
+            self.state
+                .hunks
+                .push(parse_set_mode(mode_and_path, self.line_number)?);
+            self.state.mode = StreamingParserMode::SetMode;
+            return Ok(true);
+        }
+        if let Some(path) = trimmed.strip_prefix(ADD_SYMLINK_MARKER) {
+            self.finish_hunk(trimmed)?;
+            self.state.hunks.push(AddSymlink {
+                path: PathBuf::from(path),
+                target: PathBuf::new(),
+            });
+            self.state.mode = StreamingParserMode::AddSymlink {
+                hunk_line_number: self.line_number,
+            };
+            return Ok(true);
+        }
+        if let Some(path) = trimmed.strip_prefix(ADD_BINARY_FILE_MARKER) {
+            self.finish_hunk(trimmed)?;
+            self.state.hunks.push(AddBinaryFile {
+                path: PathBuf::from(path),
+                contents: Vec::new(),
+            });
+            self.state.mode = StreamingParserMode::AddBinaryFile {
+                hunk_line_number: self.line_number,
+                encoded: String::new(),
+            };
+            return Ok(true);
+        }
         Ok(false)
     }
 
     pub fn push_delta(&mut self, delta: &str) -> Result<Vec<Hunk>, ParseError> {
+        let line_number = self.line_number;
         for ch in delta.chars() {
             if ch == '\n' {
                 let mut line = std::mem::take(&mut self.line_buffer);
@@ -124,15 +203,40 @@ impl StreamingPatchParser {
             }
         }
 
+        if matches!(self.state.mode, StreamingParserMode::UnifiedDiff)
+            && self.line_number > line_number
+        {
+            // The diff is incomplete, so its last hunk may not parse yet; keep what parsed
+            // before.
+            if let Ok(hunks) = self.parse_unified_diff() {
+                self.state.hunks = hunks;
+            }
+        }
+
         Ok(self.state.hunks.clone())
     }
 
+    fn parse_unified_diff(&self) -> Result<Vec<Hunk>, ParseError> {
+        let lines: Vec<&str> = self.unified_diff_lines.iter().map(String::as_str).collect();
+        unified_diff::parse_unified_diff(&lines, /*first_line_number*/ 1)
+    }
+
     pub fn finish(&mut self) -> Result<Vec<Hunk>, ParseError> {
+        if matches!(self.state.mode, StreamingParserMode::UnifiedDiff) {
+            if !self.line_buffer.is_empty() {
+                let line = std::mem::take(&mut self.line_buffer);
+                self.line_number += 1;
+                self.process_line(&line)?;
+            }
+            self.state.hunks = self.parse_unified_diff()?;
+            return Ok(self.state.hunks.clone());
+        }
+
         if !self.line_buffer.is_empty() {
             let line = std::mem::take(&mut self.line_buffer);
             self.line_number += 1;
             if line.trim() == END_PATCH_MARKER {
-                self.ensure_update_hunk_is_not_empty(line.trim())?;
+                self.finish_hunk(line.trim())?;
                 self.state.mode = StreamingParserMode::EndedPatch;
             } else {
                 self.process_line(&line)?;
@@ -156,6 +260,11 @@ impl StreamingPatchParser {
                     self.state.mode = StreamingParserMode::StartedPatch;
                     return Ok(());
                 }
+                if unified_diff::starts_unified_diff(line) {
+                    self.state.mode = StreamingParserMode::UnifiedDiff;
+                    self.unified_diff_lines.push(line.to_string());
+                    return Ok(());
+                }
                 Err(InvalidPatchError(
                     "The first line of the patch must be '*** Begin Patch'".to_string(),
                 ))
@@ -166,7 +275,7 @@ impl StreamingPatchParser {
                 }
                 Err(InvalidHunkError {
                     message: format!(
-                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: '*** Add File: {{path}}', '*** Delete File: {{path}}', '*** Update File: {{path}}'"
+                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
                     ),
                     line_number: self.line_number,
                 })
@@ -184,18 +293,18 @@ impl StreamingPatchParser {
                 }
                 Err(InvalidHunkError {
                     message: format!(
-                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: '*** Add File: {{path}}', '*** Delete File: {{path}}', '*** Update File: {{path}}'"
+                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
                     ),
                     line_number: self.line_number,
                 })
             }
-            StreamingParserMode::DeleteFile => {
+            StreamingParserMode::DeleteFile | StreamingParserMode::SetMode => {
                 if self.handle_hunk_headers_and_end_patch(trimmed)? {
                     return Ok(());
                 }
                 Err(InvalidHunkError {
                     message: format!(
-                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: '*** Add File: {{path}}', '*** Delete File: {{path}}', '*** Update File: {{path}}'"
+                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
                     ),
                     line_number: self.line_number,
                 })
@@ -355,7 +464,46 @@ impl StreamingPatchParser {
                     line_number: self.line_number,
                 })
             }
+            StreamingParserMode::AddSymlink { hunk_line_number } => {
+                if self.handle_hunk_headers_and_end_patch(trimmed)? {
+                    return Ok(());
+                }
+                match (line.strip_prefix('+'), self.state.hunks.last_mut()) {
+                    (Some(link_target), Some(AddSymlink { target, .. }))
+                        if target.as_os_str().is_empty() && !link_target.is_empty() =>
+                    {
+                        *target = PathBuf::from(link_target);
+                        Ok(())
+                    }
+                    (_, Some(AddSymlink { path, .. })) => Err(missing_symlink_target(
+                        &path.to_string_lossy(),
+                        hunk_line_number,
+                    )),
+                    _ => Ok(()),
+                }
+            }
+            StreamingParserMode::AddBinaryFile { .. } => {
+                if self.handle_hunk_headers_and_end_patch(trimmed)? {
+                    return Ok(());
+                }
+                if let Some(encoded_line) = line.strip_prefix('+')
+                    && let StreamingParserMode::AddBinaryFile { encoded, .. } = &mut self.state.mode
+                {
+                    encoded.push_str(encoded_line);
+                    return Ok(());
+                }
+                Err(InvalidHunkError {
+                    message: format!(
+                        "'{trimmed}' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
+                    ),
+                    line_number: self.line_number,
+                })
+            }
             StreamingParserMode::EndedPatch => Ok(()),
+            StreamingParserMode::UnifiedDiff => {
+                self.unified_diff_lines.push(line.to_string());
+                Ok(())
+            }
         }
     }
 }
@@ -508,6 +656,9 @@ mod tests {
                     UpdateFile {
                         move_path: None, ..
                     } => "update",
+                    SetMode { .. } => "set-mode",
+                    AddSymlink { .. } => "add-symlink",
+                    AddBinaryFile { .. } => "add-binary",
                 })
                 .collect::<Vec<_>>(),
             vec![
@@ -699,6 +850,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_streaming_patch_parser_streams_unified_diff() {
+        let mut parser = StreamingPatchParser::default();
+        assert_eq!(
+            parser.push_delta("diff --git a/file.txt b/file.txt\n--- a/file.txt\n"),
+            Ok(Vec::new())
+        );
+        let expected = vec![UpdateFile {
+            path: PathBuf::from("file.txt"),
+            move_path: None,
+            chunks: vec![UpdateFileChunk {
+                change_context: None,
+                old_lines: vec!["old".to_string()],
+                new_lines: vec!["new".to_string()],
+                is_end_of_file: false,
+            }],
+        }];
+        assert_eq!(
+            parser.push_delta("+++ b/file.txt\n@@ -1 +1 @@\n-old\n+new"),
+            Ok(vec![UpdateFile {
+                path: PathBuf::from("file.txt"),
+                move_path: None,
+                chunks: vec![UpdateFileChunk {
+                    change_context: None,
+                    old_lines: vec!["old".to_string()],
+                    new_lines: Vec::new(),
+                    is_end_of_file: false,
+                }],
+            }])
+        );
+        assert_eq!(parser.finish(), Ok(expected));
+
+        let mut parser = StreamingPatchParser::default();
+        assert_eq!(parser.push_delta("--- a/file.txt\n@@\n"), Ok(Vec::new()));
+        assert_eq!(
+            parser.finish(),
+            Err(InvalidHunkError {
+                message: "Expected a '+++ ' line after '--- a/file.txt'".to_string(),
+                line_number: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_streaming_patch_parser_mode_symlink_and_binary_hunks() {
+        let mut parser = StreamingPatchParser::default();
+        assert_eq!(
+            parser.push_delta(
+                "*** Begin Patch\n*** Set Mode: 100755 run.sh\n*** Add Symlink: latest\n+releases/v2\n*** Add Binary File: pixel.bin\n+AAEC\n"
+            ),
+            Ok(vec![
+                SetMode {
+                    path: PathBuf::from("run.sh"),
+                    executable: true,
+                },
+                AddSymlink {
+                    path: PathBuf::from("latest"),
+                    target: PathBuf::from("releases/v2"),
+                },
+                AddBinaryFile {
+                    path: PathBuf::from("pixel.bin"),
+                    contents: Vec::new(),
+                },
+            ])
+        );
+        assert_eq!(
+            parser.push_delta("+Aw==\n*** End Patch\n"),
+            Ok(vec![
+                SetMode {
+                    path: PathBuf::from("run.sh"),
+                    executable: true,
+                },
+                AddSymlink {
+                    path: PathBuf::from("latest"),
+                    target: PathBuf::from("releases/v2"),
+                },
+                AddBinaryFile {
+                    path: PathBuf::from("pixel.bin"),
+                    contents: vec![0, 1, 2, 3],
+                },
+            ])
+        );
+
+        let mut parser = StreamingPatchParser::default();
+        assert_eq!(
+            parser.push_delta("*** Begin Patch\n*** Add Symlink: latest\n*** End Patch\n"),
+            Err(InvalidHunkError {
+                message: "Add symlink hunk for path 'latest' must be followed by a single '+' line holding the link target".to_string(),
+                line_number: 2,
+            })
+        );
+    }
+
     #[test]
     fn test_streaming_patch_parser_returns_errors() {
         let mut parser = StreamingPatchParser::default();
@@ -714,8 +958,9 @@ mod tests {
         assert_eq!(
             parser.push_delta("bad\n"),
             Err(InvalidHunkError {
-                message: "'bad' is not a valid hunk header. Valid hunk headers: '*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}'"
-                    .to_string(),
+                message: format!(
+                    "'bad' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
+                ),
                 line_number: 2,
             })
         );
@@ -724,8 +969,9 @@ mod tests {
         assert_eq!(
             parser.push_delta("*** Begin Patch\n*** Add File: file.txt\nbad\n"),
             Err(InvalidHunkError {
-                message: "'bad' is not a valid hunk header. Valid hunk headers: '*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}'"
-                    .to_string(),
+                message: format!(
+                    "'bad' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
+                ),
                 line_number: 3,
             })
         );
@@ -734,8 +980,9 @@ mod tests {
         assert_eq!(
             parser.push_delta("*** Begin Patch\n*** Delete File: file.txt\nbad\n"),
             Err(InvalidHunkError {
-                message: "'bad' is not a valid hunk header. Valid hunk headers: '*** Add File: {path}', '*** Delete File: {path}', '*** Update File: {path}'"
-                    .to_string(),
+                message: format!(
+                    "'bad' is not a valid hunk header. Valid hunk headers: {VALID_HUNK_HEADERS}"
+                ),
                 line_number: 3,
             })
         );
@@ -0,0 +1,949 @@
+//! Parses unified diffs, as produced by `git diff` or `diff -u`, into the same [`Hunk`]s as the
+//! `*** Begin Patch` envelope.
+//!
+//! Each `@@` hunk becomes an [`UpdateFileChunk`] whose old lines are located by content with
+//! `seek_sequence`, exactly like an envelope chunk. The line numbers in `@@` headers are therefore
+//! not used. The line counts bound a hunk while they last, so `--- `/`+++ ` lines inside it are
+//! not mistaken for the next file's header, and hand-written diffs with missing or wrong line
+//! counts still apply.
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::parser::Hunk;
+use crate::parser::ParseError;
+use crate::parser::UpdateFileChunk;
+
+use Hunk::*;
+use ParseError::*;
+
+const DIFF_GIT_PREFIX: &str = "diff --git ";
+const OLD_FILE_PREFIX: &str = "--- ";
+const NEW_FILE_PREFIX: &str = "+++ ";
+const HUNK_HEADER_PREFIX: &str = "@@";
+const DEV_NULL: &str = "/dev/null";
+const NO_NEWLINE_MARKER: char = '\\';
+
+/// Extended header lines `git diff` emits that carry nothing apply_patch needs.
+const IGNORED_HEADER_PREFIXES: [&str; 2] = ["similarity index ", "dissimilarity index "];
+
+/// Whether `lines` start like a unified diff rather than an apply_patch envelope.
+pub(crate) fn is_unified_diff(lines: &[&str]) -> bool {
+    match lines.first() {
+        Some(first) if first.starts_with(DIFF_GIT_PREFIX) => true,
+        Some(_) => is_file_header(lines),
+        None => false,
+    }
+}
+
+/// Whether `first_line` could start a unified diff. Used while the rest of the diff is still
+/// streaming in.
+pub(crate) fn starts_unified_diff(first_line: &str) -> bool {
+    first_line.starts_with(DIFF_GIT_PREFIX) || first_line.starts_with(OLD_FILE_PREFIX)
+}
+
+/// Parses the lines of a unified diff. `first_line_number` is the line number of `lines[0]` in
+/// the text the caller was given, and is only used in error messages.
+pub(crate) fn parse_unified_diff(
+    lines: &[&str],
+    first_line_number: usize,
+) -> Result<Vec<Hunk>, ParseError> {
+    let mut hunks = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        if lines[index].trim().is_empty() {
+            index += 1;
+            continue;
+        }
+        let (hunk, parsed_lines) = parse_file_diff(&lines[index..], first_line_number + index)?;
+        hunks.extend(hunk);
+        index += parsed_lines;
+    }
+    Ok(hunks)
+}
+
+#[derive(Debug, PartialEq)]
+enum DiffPath {
+    DevNull,
+    Path(PathBuf),
+}
+
+/// The file modes git records, as written in `old mode`, `new mode` and `index` lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GitMode {
+    Regular,
+    Executable,
+    Symlink,
+}
+
+#[derive(Default)]
+struct FileDiff {
+    git_paths: Option<(PathBuf, PathBuf)>,
+    old_path: Option<DiffPath>,
+    new_path: Option<DiffPath>,
+    rename_from: Option<PathBuf>,
+    rename_to: Option<PathBuf>,
+    new_file: bool,
+    deleted_file: bool,
+    old_mode: Option<GitMode>,
+    new_mode: Option<GitMode>,
+    /// The mode on an `index` line, which git only writes when the mode is unchanged.
+    index_mode: Option<GitMode>,
+    chunks: Vec<UpdateFileChunk>,
+    /// Line number of the first hunk that adds lines without any context to locate them by.
+    unanchored_hunk_line_number: Option<usize>,
+}
+
+/// Parses the diff of a single file from the start of `lines`. Returns the hunks it describes
+/// and the number of lines parsed.
+fn parse_file_diff(lines: &[&str], line_number: usize) -> Result<(Vec<Hunk>, usize), ParseError> {
+    let mut file = FileDiff::default();
+    let mut index = 0;
+
+    if let Some(paths) = lines[0].strip_prefix(DIFF_GIT_PREFIX) {
+        file.git_paths = Some(
+            parse_git_header_paths(paths).ok_or_else(|| InvalidHunkError {
+                message: format!("Could not parse the paths in '{}'", lines[0]),
+                line_number,
+            })?,
+        );
+        index = 1;
+        while let Some(line) = lines.get(index) {
+            if line.starts_with(DIFF_GIT_PREFIX)
+                || line.starts_with(OLD_FILE_PREFIX)
+                || line.starts_with(HUNK_HEADER_PREFIX)
+                || line.trim().is_empty()
+            {
+                break;
+            }
+            parse_extended_header(line, &mut file, line_number + index)?;
+            index += 1;
+        }
+    }
+
+    if let Some(old_path) = lines
+        .get(index)
+        .and_then(|line| line.strip_prefix(OLD_FILE_PREFIX))
+    {
+        let Some(new_path) = lines
+            .get(index + 1)
+            .and_then(|line| line.strip_prefix(NEW_FILE_PREFIX))
+        else {
+            return Err(InvalidHunkError {
+                message: format!("Expected a '+++ ' line after '{}'", lines[index]),
+                line_number: line_number + index + 1,
+            });
+        };
+        file.old_path =
+            Some(
+                parse_header_path(old_path, "a/").ok_or_else(|| InvalidHunkError {
+                    message: format!("Could not parse the path in '{}'", lines[index]),
+                    line_number: line_number + index,
+                })?,
+            );
+        file.new_path =
+            Some(
+                parse_header_path(new_path, "b/").ok_or_else(|| InvalidHunkError {
+                    message: format!("Could not parse the path in '{}'", lines[index + 1]),
+                    line_number: line_number + index + 1,
+                })?,
+            );
+        index += 2;
+    } else if file.git_paths.is_none() {
+        return Err(InvalidHunkError {
+            message: format!(
+                "'{}' is not a valid file header. Each file in a unified diff starts with 'diff --git' or '--- '",
+                lines[0]
+            ),
+            line_number,
+        });
+    }
+
+    loop {
+        // Blank lines may separate the hunks of a file.
+        let next_hunk = (index..lines.len()).find(|&next| !lines[next].trim().is_empty());
+        let Some(next_hunk) = next_hunk.filter(|&next| lines[next].starts_with(HUNK_HEADER_PREFIX))
+        else {
+            break;
+        };
+        index = next_hunk;
+        let (chunk, parsed_lines) = parse_hunk(&lines[index..], line_number + index)?;
+        if chunk.old_lines.is_empty() && !is_insertion_into_empty_file(lines[index]) {
+            file.unanchored_hunk_line_number = file
+                .unanchored_hunk_line_number
+                .or(Some(line_number + index));
+        }
+        file.chunks.push(chunk);
+        index += parsed_lines;
+    }
+
+    if let Some(line) = lines.get(index)
+        && !line.trim().is_empty()
+        && !line.starts_with(DIFF_GIT_PREFIX)
+        && !line.starts_with(OLD_FILE_PREFIX)
+    {
+        return Err(InvalidHunkError {
+            message: format!("Unexpected line found in unified diff: '{line}'"),
+            line_number: line_number + index,
+        });
+    }
+
+    Ok((file.into_hunks(line_number)?, index))
+}
+
+fn parse_extended_header(
+    line: &str,
+    file: &mut FileDiff,
+    line_number: usize,
+) -> Result<(), ParseError> {
+    let invalid_path = || InvalidHunkError {
+        message: format!("Could not parse the path in '{line}'"),
+        line_number,
+    };
+    if let Some(path) = line.strip_prefix("rename from ") {
+        file.rename_from = Some(
+            parse_path(path)
+                .map(PathBuf::from)
+                .ok_or_else(invalid_path)?,
+        );
+    } else if let Some(path) = line.strip_prefix("rename to ") {
+        file.rename_to = Some(
+            parse_path(path)
+                .map(PathBuf::from)
+                .ok_or_else(invalid_path)?,
+        );
+    } else if let Some(mode) = line.strip_prefix("new file mode ") {
+        file.new_file = true;
+        file.new_mode = Some(parse_git_mode(mode, line_number)?);
+    } else if line.starts_with("deleted file mode ") {
+        file.deleted_file = true;
+    } else if let Some(mode) = line.strip_prefix("old mode ") {
+        file.old_mode = Some(parse_git_mode(mode, line_number)?);
+    } else if let Some(mode) = line.strip_prefix("new mode ") {
+        file.new_mode = Some(parse_git_mode(mode, line_number)?);
+    } else if let Some(index) = line.strip_prefix("index ") {
+        if let Some((_, mode)) = index.split_once(' ') {
+            file.index_mode = Some(parse_git_mode(mode, line_number)?);
+        }
+    } else if line.starts_with("copy from ") || line.starts_with("copy to ") {
+        return Err(InvalidHunkError {
+            message: "Copies are not supported; add the new file instead".to_string(),
+            line_number,
+        });
+    } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+        return Err(InvalidHunkError {
+            message: "Binary diffs are not supported; use '*** Add Binary File: {path}' with base64 contents instead".to_string(),
+            line_number,
+        });
+    } else if !IGNORED_HEADER_PREFIXES
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+    {
+        return Err(InvalidHunkError {
+            message: format!("Unexpected line found in diff header: '{line}'"),
+            line_number,
+        });
+    }
+    Ok(())
+}
+
+fn parse_git_mode(mode: &str, line_number: usize) -> Result<GitMode, ParseError> {
+    match mode.trim() {
+        "100644" | "100664" => Ok(GitMode::Regular),
+        "100755" => Ok(GitMode::Executable),
+        "120000" => Ok(GitMode::Symlink),
+        "160000" => Err(InvalidHunkError {
+            message: "Submodule changes are not supported".to_string(),
+            line_number,
+        }),
+        mode => Err(InvalidHunkError {
+            message: format!("Unsupported file mode '{mode}'"),
+            line_number,
+        }),
+    }
+}
+
+/// Parses one `@@` hunk from the start of `lines`. Returns the chunk and the number of lines
+/// parsed.
+fn parse_hunk(lines: &[&str], line_number: usize) -> Result<(UpdateFileChunk, usize), ParseError> {
+    let mut chunk = UpdateFileChunk {
+        change_context: None,
+        old_lines: Vec::new(),
+        new_lines: Vec::new(),
+        is_end_of_file: false,
+    };
+    let mut index = 1;
+    // Old and new lines the header says are still to come. While both remain, a `--- `/`+++ `
+    // pair is a removed and an added line rather than the next file's header.
+    let mut remaining = hunk_line_counts(lines[0]);
+    // Blank lines are read as empty context lines, since editors often strip the single space
+    // from those. Blank lines at the end of the hunk are separators and are given back.
+    let mut trailing_blank_lines = 0;
+    while let Some(line) = lines.get(index) {
+        let within_counts = remaining.is_some_and(|(old, new)| old > 0 && new > 0);
+        if line.starts_with(HUNK_HEADER_PREFIX)
+            || line.starts_with(DIFF_GIT_PREFIX)
+            || (!within_counts && is_file_header(&lines[index..]))
+        {
+            break;
+        }
+        let (kind, text) = match line.chars().next() {
+            None => {
+                if within_counts {
+                    trailing_blank_lines = 0;
+                } else {
+                    trailing_blank_lines += 1;
+                }
+                consume_counted_lines(&mut remaining, /*old*/ 1, /*new*/ 1);
+                chunk.old_lines.push(String::new());
+                chunk.new_lines.push(String::new());
+                index += 1;
+                continue;
+            }
+            Some(kind) => (kind, &line[kind.len_utf8()..]),
+        };
+        trailing_blank_lines = 0;
+        match kind {
+            ' ' => {
+                consume_counted_lines(&mut remaining, /*old*/ 1, /*new*/ 1);
+                chunk.old_lines.push(text.to_string());
+                chunk.new_lines.push(text.to_string());
+            }
+            '-' => {
+                consume_counted_lines(&mut remaining, /*old*/ 1, /*new*/ 0);
+                chunk.old_lines.push(text.to_string());
+            }
+            '+' => {
+                consume_counted_lines(&mut remaining, /*old*/ 0, /*new*/ 1);
+                chunk.new_lines.push(text.to_string());
+            }
+            NO_NEWLINE_MARKER => {
+                // Only the old side ending without a newline tells us where the lines are: at the
+                // end of the file. apply_patch always ends files with a newline.
+                if lines[index - 1].starts_with(['-', ' ']) {
+                    chunk.is_end_of_file = true;
+                }
+            }
+            _ => break,
+        }
+        index += 1;
+    }
+    for _ in 0..trailing_blank_lines {
+        chunk.old_lines.pop();
+        chunk.new_lines.pop();
+        index -= 1;
+    }
+
+    if chunk.old_lines.is_empty() && chunk.new_lines.is_empty() {
+        return Err(InvalidHunkError {
+            message: "Hunk does not contain any lines".to_string(),
+            line_number,
+        });
+    }
+    Ok((chunk, index))
+}
+
+/// Old and new line counts of a hunk header such as `@@ -1,4 +1,5 @@`. A range without a count
+/// covers one line. `None` for bare `@@` headers.
+fn hunk_line_counts(header: &str) -> Option<(usize, usize)> {
+    let mut ranges = header.strip_prefix(HUNK_HEADER_PREFIX)?.split_whitespace();
+    let old = ranges.next()?.strip_prefix('-')?;
+    let new = ranges.next()?.strip_prefix('+')?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, count)) => count.parse().ok(),
+        None => range.parse::<usize>().ok().map(|_| 1),
+    };
+    Some((count(old)?, count(new)?))
+}
+
+fn consume_counted_lines(remaining: &mut Option<(usize, usize)>, old: usize, new: usize) {
+    if let Some((old_remaining, new_remaining)) = remaining {
+        *old_remaining = old_remaining.saturating_sub(old);
+        *new_remaining = new_remaining.saturating_sub(new);
+    }
+}
+
+fn is_file_header(lines: &[&str]) -> bool {
+    matches!(lines, [old, new, ..] if old.starts_with(OLD_FILE_PREFIX) && new.starts_with(NEW_FILE_PREFIX))
+}
+
+/// Whether a hunk header describes an insertion into an empty file, i.e. `@@ -0,0 +1,n @@`.
+fn is_insertion_into_empty_file(header: &str) -> bool {
+    header
+        .strip_prefix(HUNK_HEADER_PREFIX)
+        .map(str::trim_start)
+        .is_some_and(|range| range.starts_with("-0,0 "))
+}
+
+impl FileDiff {
+    fn into_hunks(self, line_number: usize) -> Result<Vec<Hunk>, ParseError> {
+        let is_add = self.new_file || self.old_path == Some(DiffPath::DevNull);
+        let is_delete = self.deleted_file || self.new_path == Some(DiffPath::DevNull);
+        let is_git_diff = self.git_paths.is_some();
+        let old_mode = self.old_mode.or(self.index_mode);
+        let new_mode = self.new_mode.or(self.index_mode);
+        let (git_old_path, git_new_path) = self.git_paths.unzip();
+        let old_path = self
+            .rename_from
+            .or(self.old_path.and_then(DiffPath::into_path))
+            .or(git_old_path);
+        let new_path = self
+            .rename_to
+            .or(self.new_path.and_then(DiffPath::into_path))
+            .or(git_new_path);
+        let missing_path = || InvalidHunkError {
+            message: "Could not determine which file this diff applies to".to_string(),
+            line_number,
+        };
+
+        match (is_add, is_delete) {
+            (true, true) => Err(InvalidHunkError {
+                message: "A file diff cannot both create and delete the file".to_string(),
+                line_number,
+            }),
+            (true, false) => {
+                let path = new_path.ok_or_else(missing_path)?;
+                if self.chunks.iter().any(|chunk| !chunk.old_lines.is_empty()) {
+                    return Err(InvalidHunkError {
+                        message: format!("Diff creating '{}' must only add lines", path.display()),
+                        line_number,
+                    });
+                }
+                if new_mode == Some(GitMode::Symlink) {
+                    let target = symlink_target(&self.chunks, &path, line_number)?;
+                    return Ok(vec![AddSymlink { path, target }]);
+                }
+                let contents = self
+                    .chunks
+                    .iter()
+                    .flat_map(|chunk| &chunk.new_lines)
+                    .map(|line| format!("{line}\n"))
+                    .collect();
+                let mut hunks = vec![AddFile {
+                    path: path.clone(),
+                    contents,
+                }];
+                if new_mode == Some(GitMode::Executable) {
+                    hunks.push(SetMode {
+                        path,
+                        executable: true,
+                    });
+                }
+                Ok(hunks)
+            }
+            (false, true) => Ok(vec![DeleteFile {
+                path: old_path.ok_or_else(missing_path)?,
+            }]),
+            (false, false) => {
+                // Only git diffs name both sides reliably. A traditional `diff -u x.orig x`
+                // updates the new path in place rather than renaming the backup over it.
+                let (path, move_path) = if is_git_diff {
+                    let path = old_path.ok_or_else(missing_path)?;
+                    let move_path = new_path.filter(|new_path| *new_path != path);
+                    (path, move_path)
+                } else {
+                    (new_path.or(old_path).ok_or_else(missing_path)?, None)
+                };
+                if let Some(unanchored_line_number) = self.unanchored_hunk_line_number {
+                    return Err(InvalidHunkError {
+                        message: format!(
+                            "Hunk for '{}' only adds lines, so there is nothing to locate it by. Include unchanged lines around the addition",
+                            path.display()
+                        ),
+                        line_number: unanchored_line_number,
+                    });
+                }
+                if old_mode == Some(GitMode::Symlink) || new_mode == Some(GitMode::Symlink) {
+                    return symlink_update_hunks(path, move_path, &self.chunks, line_number);
+                }
+                let final_path = move_path.clone().unwrap_or_else(|| path.clone());
+                let mut hunks = Vec::new();
+                if !self.chunks.is_empty() || move_path.is_some() {
+                    hunks.push(UpdateFile {
+                        path,
+                        move_path,
+                        chunks: self.chunks,
+                    });
+                }
+                if let (Some(old_mode), Some(new_mode)) = (old_mode, new_mode)
+                    && old_mode != new_mode
+                {
+                    hunks.push(SetMode {
+                        path: final_path,
+                        executable: new_mode == GitMode::Executable,
+                    });
+                }
+                Ok(hunks)
+            }
+        }
+    }
+}
+
+/// A symlink whose target changes is re-added at its final path, replacing the old link.
+fn symlink_update_hunks(
+    path: PathBuf,
+    move_path: Option<PathBuf>,
+    chunks: &[UpdateFileChunk],
+    line_number: usize,
+) -> Result<Vec<Hunk>, ParseError> {
+    if chunks.is_empty() {
+        return Err(InvalidHunkError {
+            message: format!(
+                "Renaming symlink '{}' is not supported; delete it and add a new one",
+                path.display()
+            ),
+            line_number,
+        });
+    }
+    let final_path = move_path.clone().unwrap_or_else(|| path.clone());
+    let target = symlink_target(chunks, &final_path, line_number)?;
+    let mut hunks = vec![AddSymlink {
+        path: final_path,
+        target,
+    }];
+    if move_path.is_some() {
+        hunks.push(DeleteFile { path });
+    }
+    Ok(hunks)
+}
+
+/// The link target in the diff of a symlink: its one new line.
+fn symlink_target(
+    chunks: &[UpdateFileChunk],
+    path: &Path,
+    line_number: usize,
+) -> Result<PathBuf, ParseError> {
+    match chunks
+        .iter()
+        .flat_map(|chunk| &chunk.new_lines)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [target] if !target.is_empty() => Ok(PathBuf::from(target)),
+        _ => Err(InvalidHunkError {
+            message: format!(
+                "Diff of symlink '{}' must leave a single line holding the link target",
+                path.display()
+            ),
+            line_number,
+        }),
+    }
+}
+
+impl DiffPath {
+    fn into_path(self) -> Option<PathBuf> {
+        match self {
+            DiffPath::DevNull => None,
+            DiffPath::Path(path) => Some(path),
+        }
+    }
+}
+
+/// Parses the `a/<old> b/<new>` part of a `diff --git` line. Unquoted paths may contain spaces,
+/// so when the split is ambiguous we prefer the one naming the same file on both sides.
+fn parse_git_header_paths(paths: &str) -> Option<(PathBuf, PathBuf)> {
+    if paths.starts_with('"') {
+        let (old, rest) = split_quoted(paths)?;
+        let new = parse_path(rest.trim_start())?;
+        return Some((strip_prefix(&old, "a/"), strip_prefix(&new, "b/")));
+    }
+    let paths = paths.strip_prefix("a/")?;
+    let candidates: Vec<(&str, &str)> = paths
+        .match_indices(" b/")
+        .map(|(index, separator)| (&paths[..index], &paths[index + separator.len()..]))
+        .collect();
+    let (old, new) = candidates
+        .iter()
+        .find(|(old, new)| old == new)
+        .or_else(|| candidates.first())?;
+    Some((PathBuf::from(old), PathBuf::from(new)))
+}
+
+/// Parses the path on a `--- ` or `+++ ` line, dropping any timestamp and the git `a/` or `b/`
+/// prefix.
+fn parse_header_path(text: &str, git_prefix: &str) -> Option<DiffPath> {
+    let path = match text.split_once('\t') {
+        Some((path, _timestamp)) => path,
+        None => text.trim_end(),
+    };
+    if path == DEV_NULL {
+        return Some(DiffPath::DevNull);
+    }
+    let path = parse_path(path)?;
+    Some(DiffPath::Path(strip_prefix(&path, git_prefix)))
+}
+
+fn strip_prefix(path: &str, prefix: &str) -> PathBuf {
+    PathBuf::from(path.strip_prefix(prefix).unwrap_or(path))
+}
+
+/// Parses a path that git may have quoted because it contains special characters.
+fn parse_path(text: &str) -> Option<String> {
+    if !text.starts_with('"') {
+        return (!text.is_empty()).then(|| text.to_string());
+    }
+    match split_quoted(text)? {
+        (path, "") => Some(path),
+        _ => None,
+    }
+}
+/// Splits a leading C-style quoted string, as written by git, off `text`. Returns the unquoted
+/// string and the rest of `text`.
+fn split_quoted(text: &str) -> Option<(String, &str)> {
+    let mut bytes = Vec::new();
+    let mut chars = text.strip_prefix('"')?.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '"' => {
+                let rest = &text[1 + index + 1..];
+                return String::from_utf8(bytes).ok().map(|path| (path, rest));
+            }
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                let byte = match escaped {
+                    'a' => 0x07,
+                    'b' => 0x08,
+                    't' => b'\t',
+                    'n' => b'\n',
+                    'v' => 0x0b,
+                    'f' => 0x0c,
+                    'r' => b'\r',
+                    '"' => b'"',
+                    '\\' => b'\\',
+                    '0'..='7' => {
+                        let mut value = escaped.to_digit(8)?;
+                        for _ in 0..2 {
+                            let (_, digit) = chars.next()?;
+                            value = value * 8 + digit.to_digit(8)?;
+                        }
+                        u8::try_from(value).ok()?
+                    }
+                    _ => return None,
+                };
+                bytes.push(byte);
+            }
+            ch => {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(ch.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(diff: &str) -> Result<Vec<Hunk>, ParseError> {
+        let lines: Vec<&str> = diff.lines().collect();
+        parse_unified_diff(&lines, /*first_line_number*/ 1)
+    }
+
+    fn chunk(old_lines: &[&str], new_lines: &[&str]) -> UpdateFileChunk {
+        UpdateFileChunk {
+            change_context: None,
+            old_lines: old_lines.iter().map(ToString::to_string).collect(),
+            new_lines: new_lines.iter().map(ToString::to_string).collect(),
+            is_end_of_file: false,
+        }
+    }
+
+    #[test]
+    fn parses_git_diff() {
+        let diff = "\
+diff --git a/src/app.py b/src/app.py
+index 3b18e51..a3c2f4b 100644
+--- a/src/app.py
++++ b/src/app.py
+@@ -1,4 +1,4 @@ def greet():
+ def greet():
+-    print(\"Hi\")
++    print(\"Hello\")
+ 
+@@ -10,2 +10,3 @@
+ greet()
++greet()
+ # end
+diff --git a/hello.txt b/hello.txt
+new file mode 100644
+index 0000000..557db03
+--- /dev/null
++++ b/hello.txt
+@@ -0,0 +1,2 @@
++Hello
++world
+diff --git a/obsolete.txt b/obsolete.txt
+deleted file mode 100644
+index 557db03..0000000
+--- a/obsolete.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+diff --git a/old name.txt b/new name.txt
+similarity index 90%
+rename from old name.txt
+rename to new name.txt
+index 1111111..2222222 100644
+--- a/old name.txt
++++ b/new name.txt
+@@ -1,2 +1,2 @@
+ keep
+-old
++new
+diff --git a/moved.txt b/dir/moved.txt
+similarity index 100%
+rename from moved.txt
+rename to dir/moved.txt
+diff --git a/script.sh b/script.sh
+old mode 100644
+new mode 100755
+diff --git a/empty.txt b/empty.txt
+new file mode 100644
+index 0000000..e69de29
+";
+        assert_eq!(
+            parse(diff),
+            Ok(vec![
+                UpdateFile {
+                    path: PathBuf::from("src/app.py"),
+                    move_path: None,
+                    chunks: vec![
+                        chunk(
+                            &["def greet():", "    print(\"Hi\")", ""],
+                            &["def greet():", "    print(\"Hello\")", ""],
+                        ),
+                        chunk(&["greet()", "# end"], &["greet()", "greet()", "# end"]),
+                    ],
+                },
+                AddFile {
+                    path: PathBuf::from("hello.txt"),
+                    contents: "Hello\nworld\n".to_string(),
+                },
+                DeleteFile {
+                    path: PathBuf::from("obsolete.txt"),
+                },
+                UpdateFile {
+                    path: PathBuf::from("old name.txt"),
+                    move_path: Some(PathBuf::from("new name.txt")),
+                    chunks: vec![chunk(&["keep", "old"], &["keep", "new"])],
+                },
+                UpdateFile {
+                    path: PathBuf::from("moved.txt"),
+                    move_path: Some(PathBuf::from("dir/moved.txt")),
+                    chunks: Vec::new(),
+                },
+                SetMode {
+                    path: PathBuf::from("script.sh"),
+                    executable: true,
+                },
+                AddFile {
+                    path: PathBuf::from("empty.txt"),
+                    contents: String::new(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_plain_unified_diff() {
+        let diff = "\
+--- a/notes.txt\t2024-01-01 00:00:00.000000000 +0000
++++ b/notes.txt\t2024-01-02 00:00:00.000000000 +0000
+@@ -1,3 +1,3 @@
+ first
+
+-last
+\\ No newline at end of file
++last line
+--- config.orig
++++ config
+@@
+-debug = true
++debug = false
+";
+        assert_eq!(
+            parse(diff),
+            Ok(vec![
+                UpdateFile {
+                    path: PathBuf::from("notes.txt"),
+                    move_path: None,
+                    chunks: vec![UpdateFileChunk {
+                        is_end_of_file: true,
+                        ..chunk(&["first", "", "last"], &["first", "", "last line"])
+                    }],
+                },
+                UpdateFile {
+                    path: PathBuf::from("config"),
+                    move_path: None,
+                    chunks: vec![chunk(&["debug = true"], &["debug = false"])],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn header_line_counts_keep_dashed_lines_inside_the_hunk() {
+        let diff = "\
+--- a/notes.md
++++ b/notes.md
+@@ -1,2 +1,2 @@
+ keep
+--- x
++++ y
+--- a/other.txt
++++ b/other.txt
+@@ -1 +1 @@
+-old
++new
+";
+        assert_eq!(
+            parse(diff),
+            Ok(vec![
+                UpdateFile {
+                    path: PathBuf::from("notes.md"),
+                    move_path: None,
+                    chunks: vec![chunk(&["keep", "-- x"], &["keep", "++ y"])],
+                },
+                UpdateFile {
+                    path: PathBuf::from("other.txt"),
+                    move_path: None,
+                    chunks: vec![chunk(&["old"], &["new"])],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_quoted_paths() {
+        let diff = "\
+diff --git \"a/caf\\303\\251 \\\"menu\\\".txt\" \"b/caf\\303\\251 \\\"menu\\\".txt\"
+--- \"a/caf\\303\\251 \\\"menu\\\".txt\"
++++ \"b/caf\\303\\251 \\\"menu\\\".txt\"
+@@ -1 +1 @@
+-tea
++coffee
+";
+        assert_eq!(
+            parse(diff),
+            Ok(vec![UpdateFile {
+                path: PathBuf::from("café \"menu\".txt"),
+                move_path: None,
+                chunks: vec![chunk(&["tea"], &["coffee"])],
+            }])
+        );
+    }
+
+    #[test]
+    fn parses_mode_and_symlink_changes() {
+        let diff = "\
+diff --git a/bin/run b/bin/run
+new file mode 100755
+index 0000000..1111111
+--- /dev/null
++++ b/bin/run
+@@ -0,0 +1 @@
++echo run
+diff --git a/latest b/latest
+new file mode 120000
+index 0000000..2222222
+--- /dev/null
++++ b/latest
+@@ -0,0 +1 @@
++releases/v1
+\\ No newline at end of file
+diff --git a/current b/current
+index 3333333..4444444 120000
+--- a/current
++++ b/current
+@@ -1 +1 @@
+-releases/v1
+\\ No newline at end of file
++releases/v2
+\\ No newline at end of file
+diff --git a/tool.sh b/bin/tool.sh
+old mode 100755
+new mode 100644
+similarity index 90%
+rename from tool.sh
+rename to bin/tool.sh
+--- a/tool.sh
++++ b/bin/tool.sh
+@@ -1 +1 @@
+-old
++new
+";
+        assert_eq!(
+            parse(diff),
+            Ok(vec![
+                AddFile {
+                    path: PathBuf::from("bin/run"),
+                    contents: "echo run\n".to_string(),
+                },
+                SetMode {
+                    path: PathBuf::from("bin/run"),
+                    executable: true,
+                },
+                AddSymlink {
+                    path: PathBuf::from("latest"),
+                    target: PathBuf::from("releases/v1"),
+                },
+                AddSymlink {
+                    path: PathBuf::from("current"),
+                    target: PathBuf::from("releases/v2"),
+                },
+                UpdateFile {
+                    path: PathBuf::from("tool.sh"),
+                    move_path: Some(PathBuf::from("bin/tool.sh")),
+                    chunks: vec![chunk(&["old"], &["new"])],
+                },
+                SetMode {
+                    path: PathBuf::from("bin/tool.sh"),
+                    executable: false,
+                },
+            ])
+        );
+        assert_eq!(
+            parse("diff --git a/vendor/lib b/vendor/lib\nindex 1111111..2222222 160000\n"),
+            Err(InvalidHunkError {
+                message: "Submodule changes are not supported".to_string(),
+                line_number: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_diffs() {
+        assert_eq!(
+            parse(
+                "diff --git a/logo.png b/logo.png\nindex 1111111..2222222 100644\nBinary files a/logo.png and b/logo.png differ\n"
+            ),
+            Err(InvalidHunkError {
+                message: "Binary diffs are not supported; use '*** Add Binary File: {path}' with base64 contents instead".to_string(),
+                line_number: 3,
+            })
+        );
+        assert_eq!(
+            parse("--- a/file.txt\n+++ b/file.txt\n@@ -4,0 +5,1 @@\n+added\n"),
+            Err(InvalidHunkError {
+                message: "Hunk for 'file.txt' only adds lines, so there is nothing to locate it by. Include unchanged lines around the addition".to_string(),
+                line_number: 3,
+            })
+        );
+        assert_eq!(
+            parse("--- a/file.txt\n@@ -1 +1 @@\n"),
+            Err(InvalidHunkError {
+                message: "Expected a '+++ ' line after '--- a/file.txt'".to_string(),
+                line_number: 2,
+            })
+        );
+        assert_eq!(
+            parse("--- a/file.txt\n+++ b/file.txt\n@@ -1 +1 @@\n-old\n+new\n*** End Patch\n"),
+            Err(InvalidHunkError {
+                message: "Unexpected line found in unified diff: '*** End Patch'".to_string(),
+                line_number: 6,
+            })
+        );
+    }
+}
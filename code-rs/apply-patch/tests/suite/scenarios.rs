@@ -10,7 +10,7 @@ use tempfile::tempdir;
 #[test]
 fn test_apply_patch_scenarios() -> anyhow::Result<()> {
     let scenarios_dir = repo_root()?
-        .join("codex-rs")
+        .join("code-rs")
         .join("apply-patch")
         .join("tests")
         .join("fixtures")
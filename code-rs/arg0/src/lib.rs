@@ -114,7 +114,7 @@ pub fn arg0_dispatch() -> Option<Arg0PathEntryGuard> {
                     Ok(runtime) => runtime,
                     Err(_) => std::process::exit(1),
                 };
-                match runtime.block_on(codex_apply_patch::apply_patch(
+                match runtime.block_on(codex_apply_patch::apply_patch_atomic(
                     &patch_arg,
                     &cwd,
                     &mut stdout,
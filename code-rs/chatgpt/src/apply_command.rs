@@ -1,9 +1,14 @@
 use std::path::PathBuf;
 
 use clap::Parser;
+use codex_apply_patch::PatchFormat;
+use codex_apply_patch::apply_patch_atomic;
+use codex_apply_patch::detect_patch_format;
 use codex_core::config::Config;
+use codex_exec_server::LOCAL_FS;
 use codex_git_utils::ApplyGitRequest;
 use codex_git_utils::apply_git_patch;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_cli::CliConfigOverrides;
 
 use crate::get_task::GetTaskResponse;
@@ -53,8 +58,18 @@ pub async fn apply_diff_from_task(
     }
 }
 
+/// Applies `diff`, which may be a git diff or an apply_patch envelope.
 async fn apply_diff(diff: &str, cwd: Option<PathBuf>) -> anyhow::Result<()> {
     let cwd = cwd.unwrap_or(std::env::current_dir().unwrap_or_else(|_| std::env::temp_dir()));
+    match detect_patch_format(diff) {
+        PatchFormat::UnifiedDiff => apply_git_diff(diff, cwd)?,
+        PatchFormat::ApplyPatch => apply_patch_envelope(diff, cwd).await?,
+    }
+    println!("Successfully applied diff");
+    Ok(())
+}
+
+fn apply_git_diff(diff: &str, cwd: PathBuf) -> anyhow::Result<()> {
     let req = ApplyGitRequest {
         cwd,
         diff: diff.to_string(),
@@ -72,6 +87,28 @@ async fn apply_diff(diff: &str, cwd: Option<PathBuf>) -> anyhow::Result<()> {
             res.stderr
         );
     }
-    println!("Successfully applied diff");
+    Ok(())
+}
+
+async fn apply_patch_envelope(patch: &str, cwd: PathBuf) -> anyhow::Result<()> {
+    let cwd = AbsolutePathBuf::from_absolute_path(cwd)?;
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let result = apply_patch_atomic(
+        patch,
+        &cwd,
+        &mut stdout,
+        &mut stderr,
+        LOCAL_FS.as_ref(),
+        /*sandbox*/ None,
+    )
+    .await;
+    if let Err(err) = result {
+        anyhow::bail!(
+            "apply_patch failed: {err}\nstderr:\n{}",
+            String::from_utf8_lossy(&stderr)
+        );
+    }
+    print!("{}", String::from_utf8_lossy(&stdout));
     Ok(())
 }
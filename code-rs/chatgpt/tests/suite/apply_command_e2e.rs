@@ -116,6 +116,40 @@ async fn test_apply_command_creates_fibonacci_file() {
     );
 }
 
+#[tokio::test]
+async fn test_apply_command_applies_apply_patch_envelope() {
+    let temp_repo = create_temp_git_repo()
+        .await
+        .expect("Failed to create temp git repo");
+    let repo_path = temp_repo.path();
+
+    let fixture_path =
+        find_resource!("tests/task_turn_fixture.json").expect("Failed to find fixture");
+    let fixture_content = std::fs::read_to_string(fixture_path).expect("Failed to read fixture");
+    let mut fixture: serde_json::Value =
+        serde_json::from_str(&fixture_content).expect("Failed to parse fixture");
+    fixture["current_diff_task_turn"]["output_items"][0]["output_diff"]["diff"] =
+        serde_json::Value::String(
+            "*** Begin Patch\n*** Update File: README.md\n@@\n-# Test Repo\n+# Patched Repo\n*** Add File: notes.txt\n+hello\n*** End Patch\n"
+                .to_string(),
+        );
+    let task_response: GetTaskResponse =
+        serde_json::from_value(fixture).expect("Failed to deserialize task");
+
+    apply_diff_from_task(task_response, Some(repo_path.to_path_buf()))
+        .await
+        .expect("Failed to apply diff from task");
+
+    assert_eq!(
+        std::fs::read_to_string(repo_path.join("README.md")).expect("Failed to read README.md"),
+        "# Patched Repo\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(repo_path.join("notes.txt")).expect("Failed to read notes.txt"),
+        "hello\n"
+    );
+}
+
 #[tokio::test]
 async fn test_apply_command_with_merge_conflicts() {
     let temp_repo = create_temp_git_repo()
@@ -17,6 +17,7 @@ use codex_core::spawn::CODEX_SANDBOX_ENV_VAR;
 use codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_sandboxing::landlock::SandboxReport;
 use codex_sandboxing::landlock::allow_network_for_proxy;
 use codex_sandboxing::landlock::create_linux_sandbox_command_args_for_permission_profile;
 #[cfg(target_os = "macos")]
@@ -87,6 +88,7 @@ pub async fn run_command_under_landlock(
         permissions_profile,
         cwd,
         include_managed_config,
+        log_denials,
         config_overrides,
         command,
     } = command;
@@ -104,7 +106,7 @@ pub async fn run_command_under_landlock(
         config_overrides,
         codex_linux_sandbox_exe,
         SandboxType::Landlock,
-        /*log_denials*/ false,
+        log_denials,
         &[],
     )
     .await
@@ -244,6 +246,8 @@ async fn run_command_under_sandbox(
         .as_ref()
         .map(codex_core::config::StartedNetworkProxy::proxy);
 
+    // The Linux sandbox helper records denials in a report read after exit.
+    let mut linux_report = None;
     let mut child = match sandbox_type {
         #[cfg(target_os = "macos")]
         SandboxType::Seatbelt => {
@@ -281,13 +285,17 @@ async fn run_command_under_sandbox(
                 .expect("codex-linux-sandbox executable not found");
             let use_legacy_landlock = config.features.use_legacy_landlock();
             let network_sandbox_policy = config.permissions.network_sandbox_policy();
+            linux_report = log_denials.then(SandboxReport::new).transpose()?;
             let args = create_linux_sandbox_command_args_for_permission_profile(
                 command,
                 cwd.as_path(),
                 &config.permissions.permission_profile(),
+                &config.permissions.resource_limits,
                 sandbox_policy_cwd.as_path(),
                 use_legacy_landlock,
+                log_denials,
                 allow_network_for_proxy(managed_network_requirements_enabled),
+                linux_report.as_ref().map(SandboxReport::path),
             );
             spawn_debug_sandbox_child(
                 codex_linux_sandbox_exe,
@@ -316,6 +324,18 @@ async fn run_command_under_sandbox(
 
     let status = child.wait().await?;
 
+    if let Some(linux_report) = linux_report {
+        let denials = linux_report.read()?.denials;
+        eprintln!("\n=== Sandbox denials ===");
+        if denials.is_empty() {
+            eprintln!("None found.");
+        } else {
+            for denial in denials {
+                eprintln!("{denial}");
+            }
+        }
+    }
+
     #[cfg(target_os = "macos")]
     if let Some(denial_logger) = denial_logger {
         let denials = denial_logger.finish().await;
@@ -89,6 +89,10 @@ pub struct LandlockCommand {
     )]
     pub include_managed_config: bool,
 
+    /// Have the Linux sandbox helper report the syscalls, network attempts and writes it blocked, and print them after exit
+    #[arg(long = "log-denials", default_value_t = false)]
+    pub log_denials: bool,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 
@@ -23,6 +23,11 @@ use codex_exec::Cli as ExecCli;
 use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
 use codex_execpolicy::ExecPolicyCheckCommand;
+use codex_execpolicy::ExecPolicyLintCommand;
+use codex_network_proxy::NETWORK_TRAFFIC_LOG_DIR;
+use codex_network_proxy::NetworkTrafficRecord;
+use codex_network_proxy::read_traffic_log;
+use codex_network_proxy::traffic_har;
 use codex_responses_api_proxy::Args as ResponsesApiProxyArgs;
 use codex_rollout_trace::REDUCED_STATE_FILE_NAME;
 use codex_rollout_trace::replay_bundle;
@@ -45,17 +50,20 @@ mod app_cmd;
 mod desktop_app;
 mod marketplace_cmd;
 mod mcp_cmd;
+mod secrets_cmd;
 #[cfg(not(windows))]
 mod wsl_paths;
 
 use crate::marketplace_cmd::MarketplaceCli;
 use crate::mcp_cmd::McpCli;
+use crate::secrets_cmd::SecretsCli;
 
 use codex_core::build_models_manager;
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::find_codex_home;
+use codex_core::sqlite_thread_store_from_config;
 use codex_features::FEATURES;
 use codex_features::Stage;
 use codex_features::is_known_feature_key;
@@ -120,8 +128,11 @@ enum Subcommand {
     /// Manage Every Code plugins.
     Plugin(PluginCli),
 
-    /// Start Every Code as an MCP server (stdio).
-    McpServer,
+    /// Manage secrets that commands can reference as `$secret:NAME`.
+    Secrets(SecretsCli),
+
+    /// Start Every Code as an MCP server (stdio or streamable HTTP).
+    McpServer(codex_mcp_server::McpServerArgs),
 
     /// [experimental] Run the app server or related tooling.
     AppServer(AppServerCommand),
@@ -218,6 +229,12 @@ enum DebugSubcommand {
     /// Render the model-visible prompt input list as JSON.
     PromptInput(DebugPromptInputCommand),
 
+    /// Export traffic recorded by the network proxy.
+    NetworkLog(DebugNetworkLogCommand),
+
+    /// Import rollout files into the SQLite thread store.
+    ImportRollouts,
+
     /// Replay a rollout trace bundle and write reduced state JSON.
     #[clap(hide = true)]
     TraceReduce(DebugTraceReduceCommand),
@@ -263,6 +280,17 @@ struct DebugModelsCommand {
     bundled: bool,
 }
 
+#[derive(Debug, Parser)]
+struct DebugNetworkLogCommand {
+    /// Export the traffic of this thread as a HAR file.
+    #[arg(long = "har", value_name = "THREAD_ID")]
+    har: String,
+
+    /// Output path for the HAR file. Defaults to stdout.
+    #[arg(long = "output", short = 'o', value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
 #[derive(Debug, Parser)]
 struct DebugTraceReduceCommand {
     /// Trace bundle directory containing manifest.json and trace.jsonl.
@@ -353,6 +381,10 @@ enum ExecpolicySubcommand {
     /// Check execpolicy files against a command.
     #[clap(name = "check")]
     Check(ExecPolicyCheckCommand),
+
+    /// Lint execpolicy files and optionally report rule coverage for a command corpus.
+    #[clap(name = "lint")]
+    Lint(ExecPolicyLintCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -812,13 +844,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
             );
             codex_exec::run_main(exec_cli, arg0_paths.clone()).await?;
         }
-        Some(Subcommand::McpServer) => {
+        Some(Subcommand::McpServer(mcp_server_args)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
                 root_remote_auth_token_env.as_deref(),
                 "mcp-server",
             )?;
-            codex_mcp_server::run_main(arg0_paths.clone(), root_config_overrides).await?;
+            codex_mcp_server::run_main(arg0_paths.clone(), root_config_overrides, mcp_server_args)
+                .await?;
         }
         Some(Subcommand::Mcp(mut mcp_cli)) => {
             reject_remote_mode_for_subcommand(
@@ -830,6 +863,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
             prepend_config_flags(&mut mcp_cli.config_overrides, root_config_overrides.clone());
             mcp_cli.run().await?;
         }
+        Some(Subcommand::Secrets(secrets_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "secrets",
+            )?;
+            secrets_cli.run().await?;
+        }
         Some(Subcommand::Plugin(plugin_cli)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
@@ -1154,6 +1195,22 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
                 )
                 .await?;
             }
+            DebugSubcommand::NetworkLog(cmd) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "debug network-log",
+                )?;
+                run_debug_network_log_command(cmd).await?;
+            }
+            DebugSubcommand::ImportRollouts => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "debug import-rollouts",
+                )?;
+                run_debug_import_rollouts_command(&root_config_overrides, &interactive).await?;
+            }
             DebugSubcommand::TraceReduce(cmd) => {
                 reject_remote_mode_for_subcommand(
                     root_remote.as_deref(),
@@ -1180,6 +1237,14 @@ async fn cli_main(arg0_paths: Arg0DispatchPaths) -> anyhow::Result<()> {
                 )?;
                 run_execpolicycheck(cmd)?
             }
+            ExecpolicySubcommand::Lint(cmd) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "execpolicy lint",
+                )?;
+                cmd.run()?
+            }
         },
         Some(Subcommand::Apply(mut apply_cli)) => {
             reject_remote_mode_for_subcommand(
@@ -1383,6 +1448,37 @@ async fn run_debug_trace_reduce_command(cmd: DebugTraceReduceCommand) -> anyhow:
     Ok(())
 }
 
+async fn run_debug_network_log_command(cmd: DebugNetworkLogCommand) -> anyhow::Result<()> {
+    let codex_home = find_codex_home()?;
+    let records = read_traffic_log(&codex_home.join(NETWORK_TRAFFIC_LOG_DIR))?;
+    let Some(har) = thread_traffic_har(records, &cmd.har) else {
+        anyhow::bail!("no network traffic recorded for thread {}", cmd.har);
+    };
+    match cmd.output {
+        Some(output) => {
+            tokio::fs::write(&output, serde_json::to_vec_pretty(&har)?).await?;
+            println!("{}", output.display());
+        }
+        None => {
+            serde_json::to_writer_pretty(std::io::stdout(), &har)?;
+            println!();
+        }
+    }
+    Ok(())
+}
+
+/// Builds a HAR log from the records of `thread_id`, or `None` when the thread has none.
+fn thread_traffic_har(
+    records: Vec<NetworkTrafficRecord>,
+    thread_id: &str,
+) -> Option<serde_json::Value> {
+    let records = records
+        .into_iter()
+        .filter(|record| record.thread_id.as_deref() == Some(thread_id))
+        .collect::<Vec<_>>();
+    (!records.is_empty()).then(|| traffic_har(&records))
+}
+
 async fn run_debug_prompt_input_command(
     cmd: DebugPromptInputCommand,
     root_config_overrides: CliConfigOverrides,
@@ -1511,6 +1607,34 @@ async fn run_debug_clear_memories_command(
     Ok(())
 }
 
+async fn run_debug_import_rollouts_command(
+    root_config_overrides: &CliConfigOverrides,
+    interactive: &TuiCli,
+) -> anyhow::Result<()> {
+    let cli_kv_overrides = root_config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let overrides = ConfigOverrides {
+        config_profile: interactive.config_profile.clone(),
+        ..Default::default()
+    };
+    let config =
+        Config::load_with_cli_overrides_and_harness_overrides(cli_kv_overrides, overrides).await?;
+
+    let stats = sqlite_thread_store_from_config(&config)
+        .import_rollouts(&config.codex_home)
+        .await?;
+    println!(
+        "Imported {} rollouts from {} ({} skipped while live, {} failed).",
+        stats.imported,
+        config.codex_home.display(),
+        stats.skipped,
+        stats.failed
+    );
+
+    Ok(())
+}
+
 /// Prepend root-level overrides so they have lower precedence than
 /// CLI-specific ones specified after the subcommand (if any).
 fn prepend_config_flags(
@@ -1896,6 +2020,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn debug_network_log_parses_har_thread() {
+        let cli = MultitoolCli::try_parse_from([
+            "codex",
+            "debug",
+            "network-log",
+            "--har",
+            "thread-1",
+            "-o",
+            "/tmp/thread.har",
+        ])
+        .expect("parse");
+
+        let Some(Subcommand::Debug(DebugCommand {
+            subcommand: DebugSubcommand::NetworkLog(cmd),
+        })) = cli.subcommand
+        else {
+            panic!("expected debug network-log subcommand");
+        };
+
+        assert_eq!(cmd.har, "thread-1");
+        assert_eq!(cmd.output, Some(PathBuf::from("/tmp/thread.har")));
+    }
+
+    #[test]
+    fn thread_traffic_har_keeps_only_the_requested_thread() {
+        let record = |thread_id: &str, host: &str| NetworkTrafficRecord {
+            timestamp: "2026-01-02T03:04:05.678Z".to_string(),
+            thread_id: Some(thread_id.to_string()),
+            turn_id: None,
+            client: None,
+            protocol: "http".to_string(),
+            host: host.to_string(),
+            port: Some(80),
+            method: Some("GET".to_string()),
+            path: Some("/".to_string()),
+            decision: "allow".to_string(),
+            reason: None,
+            status: Some(200),
+            request_bytes: Some(0),
+            response_bytes: Some(2),
+            duration_ms: Some(1),
+        };
+        let records = vec![
+            record("thread-1", "one.example.com"),
+            record("thread-2", "two.example.com"),
+        ];
+
+        let har = thread_traffic_har(records.clone(), "thread-1").expect("har");
+        let urls = har["log"]["entries"]
+            .as_array()
+            .expect("entries")
+            .iter()
+            .map(|entry| entry["request"]["url"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(urls, vec![serde_json::json!("http://one.example.com:80/")]);
+        assert_eq!(thread_traffic_har(records, "thread-3"), None);
+    }
+
     #[test]
     fn debug_models_parses_bundled_flag() {
         let cli =
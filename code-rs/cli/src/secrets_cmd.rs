@@ -0,0 +1,212 @@
+use std::io::IsTerminal;
+use std::io::Read;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use clap::Parser;
+use codex_core::config::find_codex_home;
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsBackendKind;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+
+/// Secrets are stored encrypted under the config home and can be referenced
+/// from shell commands as `$secret:NAME`. The value is only added to the
+/// command's environment when it runs and is redacted from its output.
+#[derive(Debug, Parser)]
+#[command(bin_name = "code secrets")]
+pub struct SecretsCli {
+    #[command(subcommand)]
+    subcommand: SecretsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum SecretsSubcommand {
+    /// Store a secret. The value is read from stdin unless `--value` is given.
+    Set(SetSecretArgs),
+    /// List stored secret names. Values are never printed.
+    List(ListSecretsArgs),
+    /// Delete a stored secret.
+    Delete(DeleteSecretArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct ScopeArgs {
+    /// Use the global scope, shared by every project.
+    #[arg(long, conflicts_with = "env")]
+    global: bool,
+
+    /// Use the scope of the given environment instead of the one derived from
+    /// the current directory.
+    #[arg(long, value_name = "ID")]
+    env: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+#[command(bin_name = "code secrets set")]
+struct SetSecretArgs {
+    /// Secret name (uppercase letters, digits and underscores).
+    name: String,
+
+    /// Secret value. Prefer piping it on stdin so it stays out of shell history.
+    #[arg(long)]
+    value: Option<String>,
+
+    #[clap(flatten)]
+    scope: ScopeArgs,
+}
+
+#[derive(Debug, Parser)]
+#[command(bin_name = "code secrets list")]
+struct ListSecretsArgs {
+    /// List secrets from every scope.
+    #[arg(long, conflicts_with_all = ["global", "env"])]
+    all: bool,
+
+    #[clap(flatten)]
+    scope: ScopeArgs,
+}
+
+#[derive(Debug, Parser)]
+#[command(bin_name = "code secrets delete")]
+struct DeleteSecretArgs {
+    /// Secret name to delete.
+    name: String,
+
+    #[clap(flatten)]
+    scope: ScopeArgs,
+}
+
+impl SecretsCli {
+    pub async fn run(self) -> Result<()> {
+        let codex_home = find_codex_home().context("failed to resolve Every Code config home")?;
+        let manager = SecretsManager::new(codex_home.to_path_buf(), SecretsBackendKind::Local);
+
+        match self.subcommand {
+            SecretsSubcommand::Set(args) => run_set(&manager, args),
+            SecretsSubcommand::List(args) => run_list(&manager, args),
+            SecretsSubcommand::Delete(args) => run_delete(&manager, args),
+        }
+    }
+}
+
+fn run_set(manager: &SecretsManager, args: SetSecretArgs) -> Result<()> {
+    let SetSecretArgs { name, value, scope } = args;
+    let name = SecretName::new(&name)?;
+    let scope = scope.resolve()?;
+    let value = match value {
+        Some(value) => value,
+        None => read_value_from_stdin()?,
+    };
+    if value.is_empty() {
+        bail!("secret value must not be empty");
+    }
+
+    manager.set(&scope, &name, &value)?;
+    println!("Stored secret `{name}` in {}.", describe_scope(&scope));
+    Ok(())
+}
+
+fn run_list(manager: &SecretsManager, args: ListSecretsArgs) -> Result<()> {
+    let ListSecretsArgs { all, scope } = args;
+    let scope = if all { None } else { Some(scope.resolve()?) };
+
+    let mut entries = manager.list(scope.as_ref())?;
+    if entries.is_empty() {
+        match &scope {
+            Some(scope) => println!("No secrets stored in {}.", describe_scope(scope)),
+            None => println!("No secrets stored."),
+        }
+        return Ok(());
+    }
+
+    entries.sort_by(|a, b| {
+        describe_scope(&a.scope)
+            .cmp(&describe_scope(&b.scope))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    for entry in entries {
+        println!("{}\t{}", entry.name, describe_scope(&entry.scope));
+    }
+    Ok(())
+}
+
+fn run_delete(manager: &SecretsManager, args: DeleteSecretArgs) -> Result<()> {
+    let DeleteSecretArgs { name, scope } = args;
+    let name = SecretName::new(&name)?;
+    let scope = scope.resolve()?;
+
+    if manager.delete(&scope, &name)? {
+        println!("Deleted secret `{name}` from {}.", describe_scope(&scope));
+    } else {
+        println!("No secret `{name}` in {}.", describe_scope(&scope));
+    }
+    Ok(())
+}
+
+impl ScopeArgs {
+    fn resolve(&self) -> Result<SecretScope> {
+        if self.global {
+            return Ok(SecretScope::Global);
+        }
+        match &self.env {
+            Some(environment_id) => SecretScope::environment(environment_id.as_str()),
+            None => {
+                let cwd = std::env::current_dir().context("failed to read current directory")?;
+                SecretScope::environment(environment_id_from_cwd(&cwd))
+            }
+        }
+    }
+}
+
+fn describe_scope(scope: &SecretScope) -> String {
+    match scope {
+        SecretScope::Global => "the global scope".to_string(),
+        SecretScope::Environment(environment_id) => format!("environment `{environment_id}`"),
+    }
+}
+
+fn read_value_from_stdin() -> Result<String> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        eprintln!("Enter the secret value, then press Ctrl-D:");
+    }
+    let mut value = String::new();
+    stdin
+        .read_to_string(&mut value)
+        .context("failed to read secret value from stdin")?;
+    let trimmed_len = value.trim_end_matches(['\r', '\n']).len();
+    value.truncate(trimmed_len);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn scope_flags_select_global_or_named_environment() {
+        let cli = SecretsCli::try_parse_from(["code secrets", "set", "API_TOKEN", "--global"])
+            .expect("parse");
+        let SecretsSubcommand::Set(args) = cli.subcommand else {
+            panic!("expected set subcommand");
+        };
+        assert_eq!(args.scope.resolve().expect("scope"), SecretScope::Global);
+
+        let cli =
+            SecretsCli::try_parse_from(["code secrets", "delete", "API_TOKEN", "--env", "web"])
+                .expect("parse");
+        let SecretsSubcommand::Delete(args) = cli.subcommand else {
+            panic!("expected delete subcommand");
+        };
+        assert_eq!(
+            args.scope.resolve().expect("scope"),
+            SecretScope::Environment("web".to_string())
+        );
+
+        assert!(SecretsCli::try_parse_from(["code secrets", "list", "--all", "--global"]).is_err());
+    }
+}
@@ -212,6 +212,57 @@ impl From<&ResponsesApiRequest> for ResponseCreateWsRequest {
     }
 }
 
+/// Name of the single string parameter a freeform tool takes when it is
+/// exposed as a [`FunctionTool`].
+pub const FREEFORM_TOOL_INPUT_PARAM: &str = "input";
+
+/// A tool advertised to wire APIs that only know plain function tools (Chat
+/// Completions, Anthropic Messages).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionTool {
+    pub source: FunctionToolSource,
+    pub description: String,
+    pub parameters: Value,
+    pub strict: bool,
+}
+
+impl FunctionTool {
+    /// Name the model uses to call this tool.
+    pub fn name(&self) -> String {
+        self.source.function_name()
+    }
+}
+
+/// The Responses tool a [`FunctionTool`] stands in for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionToolSource {
+    Function {
+        namespace: Option<String>,
+        name: String,
+    },
+    /// A freeform (`custom`) tool whose raw input is passed as the
+    /// [`FREEFORM_TOOL_INPUT_PARAM`] argument.
+    Freeform { name: String },
+}
+
+impl FunctionToolSource {
+    /// Namespaced tools are called `{namespace}{name}`, matching how tool
+    /// names are displayed elsewhere.
+    pub fn function_name(&self) -> String {
+        match self {
+            FunctionToolSource::Function {
+                namespace: Some(namespace),
+                name,
+            } => format!("{namespace}{name}"),
+            FunctionToolSource::Function {
+                namespace: None,
+                name,
+            }
+            | FunctionToolSource::Freeform { name } => name.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ResponseCreateWsRequest {
     pub model: String,
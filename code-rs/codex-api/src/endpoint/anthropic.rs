@@ -0,0 +1,174 @@
+use crate::auth::AuthError;
+use crate::auth::AuthProvider;
+use crate::auth::SharedAuthProvider;
+use crate::common::FunctionTool;
+use crate::common::ResponseStream;
+use crate::common::ResponsesApiRequest;
+use crate::endpoint::responses::ResponsesOptions;
+use crate::endpoint::session::EndpointSession;
+use crate::error::ApiError;
+use crate::provider::Provider;
+use crate::requests::anthropic::build_anthropic_request;
+use crate::requests::headers::build_session_headers;
+use crate::requests::headers::insert_header;
+use crate::sse::spawn_anthropic_stream;
+use crate::telemetry::SseTelemetry;
+use async_trait::async_trait;
+use codex_client::HttpTransport;
+use codex_client::Request;
+use codex_client::RequestTelemetry;
+use http::HeaderMap;
+use http::HeaderValue;
+use http::Method;
+use std::sync::Arc;
+use tracing::instrument;
+
+const ANTHROPIC_VERSION_HEADER: &str = "anthropic-version";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const ANTHROPIC_BETA_HEADER: &str = "anthropic-beta";
+const ANTHROPIC_PROMPT_CACHING_BETA: &str = "prompt-caching-2024-07-31";
+const ANTHROPIC_API_KEY_HEADER: &str = "x-api-key";
+
+/// Streams turns from providers that expose the Anthropic Messages API
+/// (`/v1/messages`).
+///
+/// Like [`ChatClient`](crate::endpoint::ChatClient), requests are built from a
+/// [`ResponsesApiRequest`] and the stream is surfaced as the same
+/// [`ResponseEvent`] sequence as the Responses API. A bearer token from the
+/// auth provider is sent as `x-api-key`, which is what the Messages API
+/// expects for API keys.
+///
+/// [`ResponseEvent`]: crate::common::ResponseEvent
+pub struct AnthropicClient<T: HttpTransport> {
+    session: EndpointSession<T>,
+    sse_telemetry: Option<Arc<dyn SseTelemetry>>,
+}
+
+impl<T: HttpTransport> AnthropicClient<T> {
+    pub fn new(transport: T, provider: Provider, auth: SharedAuthProvider) -> Self {
+        let auth: SharedAuthProvider = Arc::new(AnthropicApiKeyAuth { inner: auth });
+        Self {
+            session: EndpointSession::new(transport, provider, auth),
+            sse_telemetry: None,
+        }
+    }
+
+    pub fn with_telemetry(
+        self,
+        request: Option<Arc<dyn RequestTelemetry>>,
+        sse: Option<Arc<dyn SseTelemetry>>,
+    ) -> Self {
+        Self {
+            session: self.session.with_request_telemetry(request),
+            sse_telemetry: sse,
+        }
+    }
+
+    fn path() -> &'static str {
+        "messages"
+    }
+
+    /// Streams `request` as a Messages API call advertising `tools`.
+    ///
+    /// `compression` and `turn_state` in `options` are Responses-specific and
+    /// ignored here.
+    #[instrument(
+        name = "anthropic.stream_request",
+        level = "info",
+        skip_all,
+        fields(
+            transport = "anthropic_http",
+            http.method = "POST",
+            api.path = "messages"
+        )
+    )]
+    pub async fn stream_request(
+        &self,
+        request: ResponsesApiRequest,
+        tools: Vec<FunctionTool>,
+        options: ResponsesOptions,
+    ) -> Result<ResponseStream, ApiError> {
+        let ResponsesOptions {
+            session_id,
+            thread_id,
+            extra_headers,
+            ..
+        } = options;
+
+        let (body, tools) = build_anthropic_request(&request, &tools)?;
+
+        let mut headers = extra_headers;
+        if let Some(ref thread_id) = thread_id {
+            insert_header(&mut headers, "x-client-request-id", thread_id);
+        }
+        headers.extend(build_session_headers(session_id, thread_id));
+
+        let stream_response = self
+            .session
+            .stream_with(Method::POST, Self::path(), headers, Some(body), |req| {
+                req.headers.insert(
+                    http::header::ACCEPT,
+                    HeaderValue::from_static("text/event-stream"),
+                );
+                if !req.headers.contains_key(ANTHROPIC_VERSION_HEADER) {
+                    req.headers.insert(
+                        ANTHROPIC_VERSION_HEADER,
+                        HeaderValue::from_static(ANTHROPIC_VERSION),
+                    );
+                }
+                if !req.headers.contains_key(ANTHROPIC_BETA_HEADER) {
+                    req.headers.insert(
+                        ANTHROPIC_BETA_HEADER,
+                        HeaderValue::from_static(ANTHROPIC_PROMPT_CACHING_BETA),
+                    );
+                }
+            })
+            .await?;
+
+        Ok(spawn_anthropic_stream(
+            stream_response,
+            self.session.provider().stream_idle_timeout,
+            self.sse_telemetry.clone(),
+            tools,
+        ))
+    }
+}
+
+/// Moves a `Bearer` API key produced by the wrapped provider into the
+/// `x-api-key` header. Providers that already set `x-api-key` (for example via
+/// `env_http_headers`) are left untouched.
+struct AnthropicApiKeyAuth {
+    inner: SharedAuthProvider,
+}
+
+impl AnthropicApiKeyAuth {
+    fn rewrite(headers: &mut HeaderMap) {
+        if headers.contains_key(ANTHROPIC_API_KEY_HEADER) {
+            return;
+        }
+        let Some(api_key) = headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|api_key| HeaderValue::from_str(api_key).ok())
+        else {
+            return;
+        };
+        headers.remove(http::header::AUTHORIZATION);
+        headers.insert(ANTHROPIC_API_KEY_HEADER, api_key);
+    }
+}
+
+#[async_trait]
+impl AuthProvider for AnthropicApiKeyAuth {
+    fn add_auth_headers(&self, headers: &mut HeaderMap) {
+        self.inner.add_auth_headers(headers);
+        Self::rewrite(headers);
+    }
+
+    async fn apply_auth(&self, request: Request) -> Result<Request, AuthError> {
+        let mut request = self.inner.apply_auth(request).await?;
+        Self::rewrite(&mut request.headers);
+        Ok(request)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::auth::SharedAuthProvider;
+use crate::common::FunctionTool;
+use crate::common::ResponseStream;
+use crate::common::ResponsesApiRequest;
+use crate::endpoint::responses::ResponsesOptions;
+use crate::endpoint::session::EndpointSession;
+use crate::error::ApiError;
+use crate::provider::Provider;
+use crate::requests::chat::build_chat_request;
+use crate::requests::headers::build_session_headers;
+use crate::requests::headers::insert_header;
+use crate::requests::headers::subagent_header;
+use crate::sse::spawn_chat_stream;
+use crate::telemetry::SseTelemetry;
+use codex_client::HttpTransport;
+use codex_client::RequestTelemetry;
+use http::HeaderValue;
+use http::Method;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Streams turns from providers that only expose the OpenAI Chat Completions
+/// API (`/v1/chat/completions`).
+///
+/// Requests are built from the same [`ResponsesApiRequest`] used for the
+/// Responses API and the stream is surfaced as the same [`ResponseEvent`]
+/// sequence, so callers can switch wire APIs without changing turn handling.
+///
+/// [`ResponseEvent`]: crate::common::ResponseEvent
+pub struct ChatClient<T: HttpTransport> {
+    session: EndpointSession<T>,
+    sse_telemetry: Option<Arc<dyn SseTelemetry>>,
+}
+
+impl<T: HttpTransport> ChatClient<T> {
+    pub fn new(transport: T, provider: Provider, auth: SharedAuthProvider) -> Self {
+        Self {
+            session: EndpointSession::new(transport, provider, auth),
+            sse_telemetry: None,
+        }
+    }
+
+    pub fn with_telemetry(
+        self,
+        request: Option<Arc<dyn RequestTelemetry>>,
+        sse: Option<Arc<dyn SseTelemetry>>,
+    ) -> Self {
+        Self {
+            session: self.session.with_request_telemetry(request),
+            sse_telemetry: sse,
+        }
+    }
+
+    fn path() -> &'static str {
+        "chat/completions"
+    }
+
+    /// Streams `request` as a Chat Completions call advertising `tools`.
+    ///
+    /// `compression` and `turn_state` in `options` are Responses-specific and
+    /// ignored here.
+    #[instrument(
+        name = "chat.stream_request",
+        level = "info",
+        skip_all,
+        fields(
+            transport = "chat_http",
+            http.method = "POST",
+            api.path = "chat/completions"
+        )
+    )]
+    pub async fn stream_request(
+        &self,
+        request: ResponsesApiRequest,
+        tools: Vec<FunctionTool>,
+        options: ResponsesOptions,
+    ) -> Result<ResponseStream, ApiError> {
+        let ResponsesOptions {
+            session_id,
+            thread_id,
+            session_source,
+            extra_headers,
+            ..
+        } = options;
+
+        let (body, tools) = build_chat_request(&request, &tools)?;
+
+        let mut headers = extra_headers;
+        if let Some(ref thread_id) = thread_id {
+            insert_header(&mut headers, "x-client-request-id", thread_id);
+        }
+        headers.extend(build_session_headers(session_id, thread_id));
+        if let Some(subagent) = subagent_header(&session_source) {
+            insert_header(&mut headers, "x-openai-subagent", &subagent);
+        }
+
+        let stream_response = self
+            .session
+            .stream_with(Method::POST, Self::path(), headers, Some(body), |req| {
+                req.headers.insert(
+                    http::header::ACCEPT,
+                    HeaderValue::from_static("text/event-stream"),
+                );
+            })
+            .await?;
+
+        Ok(spawn_chat_stream(
+            stream_response,
+            self.session.provider().stream_idle_timeout,
+            self.sse_telemetry.clone(),
+            tools,
+        ))
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod anthropic;
+pub(crate) mod chat;
 pub(crate) mod compact;
 pub(crate) mod memories;
 pub(crate) mod models;
@@ -7,6 +9,8 @@ pub(crate) mod responses;
 pub(crate) mod responses_websocket;
 mod session;
 
+pub use anthropic::AnthropicClient;
+pub use chat::ChatClient;
 pub use compact::CompactClient;
 pub use memories::MemoriesClient;
 pub use models::ModelsClient;
@@ -22,6 +22,9 @@ pub use crate::auth::AuthProvider;
 pub use crate::auth::SharedAuthProvider;
 pub use crate::auth::auth_header_telemetry;
 pub use crate::common::CompactionInput;
+pub use crate::common::FREEFORM_TOOL_INPUT_PARAM;
+pub use crate::common::FunctionTool;
+pub use crate::common::FunctionToolSource;
 pub use crate::common::MemorySummarizeInput;
 pub use crate::common::MemorySummarizeOutput;
 pub use crate::common::OpenAiVerbosity;
@@ -39,6 +42,8 @@ pub use crate::common::WS_REQUEST_HEADER_TRACEPARENT_CLIENT_METADATA_KEY;
 pub use crate::common::WS_REQUEST_HEADER_TRACESTATE_CLIENT_METADATA_KEY;
 pub use crate::common::create_text_param_for_request;
 pub use crate::common::response_create_client_metadata;
+pub use crate::endpoint::AnthropicClient;
+pub use crate::endpoint::ChatClient;
 pub use crate::endpoint::CompactClient;
 pub use crate::endpoint::MemoriesClient;
 pub use crate::endpoint::ModelsClient;
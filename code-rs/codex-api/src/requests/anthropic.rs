@@ -0,0 +1,526 @@
+use crate::common::FunctionTool;
+use crate::common::ResponsesApiRequest;
+use crate::error::ApiError;
+use crate::requests::tools::ToolRegistry;
+use crate::requests::tools::custom_tool_arguments;
+use crate::requests::tools::flat_tool_name;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::openai_models::ReasoningEffort;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use tracing::debug;
+
+/// `max_tokens` is mandatory on the Messages API; the Responses request has
+/// no equivalent, so use a ceiling every current Claude model accepts.
+pub(crate) const ANTHROPIC_MAX_OUTPUT_TOKENS: u32 = 32_000;
+
+/// Prefix used to carry `redacted_thinking` payloads in a reasoning item's
+/// `encrypted_content`, distinguishing them from thinking-block signatures.
+pub(crate) const REDACTED_THINKING_PREFIX: &str = "redacted_thinking:";
+
+/// Encodes a canonical Responses request as an Anthropic Messages request body.
+///
+/// Leading developer messages join the instructions as system blocks; later
+/// ones are sent as user text since the Messages API has no mid-conversation
+/// system role. Thinking blocks are replayed from reasoning items that carry a
+/// signature, and prompt-caching breakpoints are placed on the system prompt,
+/// the tool list and the newest message. Structured output (`text.format`) has
+/// no equivalent and is ignored. The Responses `tools` on `request` are
+/// ignored in favor of the plain function tools in `tools`.
+pub(crate) fn build_anthropic_request(
+    request: &ResponsesApiRequest,
+    tools: &[FunctionTool],
+) -> Result<(Value, ToolRegistry), ApiError> {
+    let registry = ToolRegistry::new(tools);
+    let mut tools = tools.iter().map(anthropic_tool).collect::<Vec<_>>();
+    let (mut system, mut messages) = anthropic_messages_from_input(request);
+    let thinking_budget = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort)
+        .and_then(thinking_budget_tokens);
+
+    mark_cache_breakpoint(system.last_mut());
+    mark_cache_breakpoint(tools.last_mut());
+    if let Some(content) = messages
+        .last_mut()
+        .and_then(|message| message.get_mut("content"))
+        .and_then(Value::as_array_mut)
+        && let Some(block) = content.iter_mut().rev().find(|block| {
+            !matches!(
+                block.get("type").and_then(Value::as_str),
+                Some("thinking" | "redacted_thinking")
+            )
+        })
+    {
+        mark_cache_breakpoint(Some(block));
+    }
+
+    let mut body = Map::new();
+    body.insert("model".to_string(), Value::String(request.model.clone()));
+    body.insert(
+        "max_tokens".to_string(),
+        Value::from(ANTHROPIC_MAX_OUTPUT_TOKENS),
+    );
+    body.insert("stream".to_string(), Value::Bool(true));
+    if !system.is_empty() {
+        body.insert("system".to_string(), Value::Array(system));
+    }
+    body.insert("messages".to_string(), Value::Array(messages));
+    if !tools.is_empty() {
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert(
+            "tool_choice".to_string(),
+            anthropic_tool_choice(
+                &request.tool_choice,
+                request.parallel_tool_calls,
+                thinking_budget.is_some(),
+            ),
+        );
+    }
+    if let Some(budget_tokens) = thinking_budget {
+        body.insert(
+            "thinking".to_string(),
+            json!({ "type": "enabled", "budget_tokens": budget_tokens }),
+        );
+    }
+    if request
+        .text
+        .as_ref()
+        .is_some_and(|text| text.format.is_some())
+    {
+        debug!("dropping text.format: not supported by the anthropic messages API");
+    }
+
+    Ok((Value::Object(body), registry))
+}
+
+/// Extended-thinking budget for a reasoning effort, or `None` to leave
+/// thinking disabled. Budgets stay below [`ANTHROPIC_MAX_OUTPUT_TOKENS`] as
+/// the API requires.
+fn thinking_budget_tokens(effort: ReasoningEffort) -> Option<u32> {
+    match effort {
+        ReasoningEffort::None | ReasoningEffort::Minimal => None,
+        ReasoningEffort::Low => Some(4_096),
+        ReasoningEffort::Medium => Some(10_000),
+        ReasoningEffort::High => Some(24_000),
+        ReasoningEffort::XHigh => Some(ANTHROPIC_MAX_OUTPUT_TOKENS - 1),
+    }
+}
+
+fn anthropic_tool(tool: &FunctionTool) -> Value {
+    json!({
+        "name": tool.name(),
+        "description": tool.description,
+        "input_schema": tool.parameters,
+    })
+}
+
+/// Forcing tool use (`any`) is rejected while extended thinking is enabled, so
+/// `required` degrades to `auto` in that case.
+fn anthropic_tool_choice(tool_choice: &str, parallel_tool_calls: bool, thinking: bool) -> Value {
+    let choice_type = match tool_choice {
+        "none" => return json!({ "type": "none" }),
+        "required" if !thinking => "any",
+        _ => "auto",
+    };
+    json!({
+        "type": choice_type,
+        "disable_parallel_tool_use": !parallel_tool_calls,
+    })
+}
+
+fn mark_cache_breakpoint(block: Option<&mut Value>) {
+    if let Some(block) = block.and_then(Value::as_object_mut) {
+        block.insert("cache_control".to_string(), json!({ "type": "ephemeral" }));
+    }
+}
+
+fn anthropic_messages_from_input(request: &ResponsesApiRequest) -> (Vec<Value>, Vec<Value>) {
+    let mut system = Vec::new();
+    if !request.instructions.is_empty() {
+        system.push(text_block(&request.instructions));
+    }
+    let mut messages = Vec::new();
+
+    for item in &request.input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let blocks = message_blocks(content);
+                if blocks.is_empty() {
+                    continue;
+                }
+                match role.as_str() {
+                    "system" | "developer" if messages.is_empty() => system.extend(blocks),
+                    "assistant" => push_blocks(&mut messages, "assistant", blocks),
+                    _ => push_blocks(&mut messages, "user", blocks),
+                }
+            }
+            ResponseItem::Reasoning {
+                content,
+                encrypted_content: Some(encrypted_content),
+                ..
+            } => {
+                let block = match encrypted_content.strip_prefix(REDACTED_THINKING_PREFIX) {
+                    Some(data) => json!({ "type": "redacted_thinking", "data": data }),
+                    None => {
+                        let thinking = content
+                            .iter()
+                            .flatten()
+                            .map(|content| match content {
+                                ReasoningItemContent::ReasoningText { text }
+                                | ReasoningItemContent::Text { text } => text.as_str(),
+                            })
+                            .collect::<String>();
+                        json!({
+                            "type": "thinking",
+                            "thinking": thinking,
+                            "signature": encrypted_content,
+                        })
+                    }
+                };
+                push_blocks(&mut messages, "assistant", vec![block]);
+            }
+            ResponseItem::FunctionCall {
+                name,
+                namespace,
+                arguments,
+                call_id,
+                ..
+            } => {
+                let input = serde_json::from_str::<Value>(arguments)
+                    .ok()
+                    .filter(Value::is_object)
+                    .unwrap_or_else(|| json!({}));
+                let block = json!({
+                    "type": "tool_use",
+                    "id": call_id,
+                    "name": flat_tool_name(namespace.as_deref(), name),
+                    "input": input,
+                });
+                push_blocks(&mut messages, "assistant", vec![block]);
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => {
+                let block = json!({
+                    "type": "tool_use",
+                    "id": call_id,
+                    "name": name,
+                    "input": custom_tool_arguments(input),
+                });
+                push_blocks(&mut messages, "assistant", vec![block]);
+            }
+            ResponseItem::FunctionCallOutput { call_id, output }
+            | ResponseItem::CustomToolCallOutput {
+                call_id, output, ..
+            } => {
+                push_blocks(
+                    &mut messages,
+                    "user",
+                    vec![tool_result_block(call_id, output)],
+                );
+            }
+            ResponseItem::Reasoning { .. }
+            | ResponseItem::LocalShellCall { .. }
+            | ResponseItem::ToolSearchCall { .. }
+            | ResponseItem::ToolSearchOutput { .. }
+            | ResponseItem::WebSearchCall { .. }
+            | ResponseItem::ImageGenerationCall { .. }
+            | ResponseItem::Compaction { .. }
+            | ResponseItem::ContextCompaction { .. }
+            | ResponseItem::Other => {}
+        }
+    }
+
+    (system, messages)
+}
+
+/// Appends content blocks to the trailing message when it has the same role;
+/// the Messages API expects user and assistant turns to alternate.
+fn push_blocks(messages: &mut Vec<Value>, role: &str, blocks: Vec<Value>) {
+    if let Some(last) = messages.last_mut()
+        && last.get("role").and_then(Value::as_str) == Some(role)
+        && let Some(content) = last.get_mut("content").and_then(Value::as_array_mut)
+    {
+        content.extend(blocks);
+        return;
+    }
+    messages.push(json!({ "role": role, "content": blocks }));
+}
+
+fn text_block(text: &str) -> Value {
+    json!({ "type": "text", "text": text })
+}
+
+fn message_blocks(content: &[ContentItem]) -> Vec<Value> {
+    content
+        .iter()
+        .filter_map(|item| match item {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                (!text.is_empty()).then(|| text_block(text))
+            }
+            ContentItem::InputImage { image_url, .. } => Some(image_block(image_url)),
+        })
+        .collect()
+}
+
+fn image_block(image_url: &str) -> Value {
+    let source = match image_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+    {
+        Some((media_type, data)) => json!({
+            "type": "base64",
+            "media_type": media_type,
+            "data": data,
+        }),
+        None => json!({ "type": "url", "url": image_url }),
+    };
+    json!({ "type": "image", "source": source })
+}
+
+fn tool_result_block(call_id: &str, output: &FunctionCallOutputPayload) -> Value {
+    let content = match &output.body {
+        FunctionCallOutputBody::Text(text) => Value::String(text.clone()),
+        FunctionCallOutputBody::ContentItems(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| match item {
+                    FunctionCallOutputContentItem::InputText { text } => text_block(text),
+                    FunctionCallOutputContentItem::InputImage { image_url, .. } => {
+                        image_block(image_url)
+                    }
+                })
+                .collect(),
+        ),
+    };
+    let mut block = json!({
+        "type": "tool_result",
+        "tool_use_id": call_id,
+        "content": content,
+    });
+    if output.success == Some(false)
+        && let Some(block) = block.as_object_mut()
+    {
+        block.insert("is_error".to_string(), Value::Bool(true));
+    }
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::FunctionToolSource;
+    use crate::common::Reasoning;
+    use pretty_assertions::assert_eq;
+
+    fn request(input: Vec<ResponseItem>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "claude-sonnet-4-5".to_string(),
+            instructions: "be helpful".to_string(),
+            input,
+            tools: Vec::new(),
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: true,
+            reasoning: None,
+            store: false,
+            stream: true,
+            include: Vec::new(),
+            service_tier: None,
+            prompt_cache_key: None,
+            text: None,
+            client_metadata: None,
+        }
+    }
+
+    #[test]
+    fn maps_tool_round_trip_and_thinking_blocks() {
+        let input = vec![
+            ResponseItem::Message {
+                id: None,
+                role: "developer".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "sandbox notes".to_string(),
+                }],
+                phase: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![
+                    ContentItem::InputText {
+                        text: "what is this?".to_string(),
+                    },
+                    ContentItem::InputImage {
+                        image_url: "data:image/png;base64,AAAA".to_string(),
+                        detail: None,
+                    },
+                ],
+                phase: None,
+            },
+            ResponseItem::Reasoning {
+                id: "rs_1".to_string(),
+                summary: Vec::new(),
+                content: Some(vec![ReasoningItemContent::ReasoningText {
+                    text: "look at it".to_string(),
+                }]),
+                encrypted_content: Some("sig".to_string()),
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: "{\"command\":[\"ls\"]}".to_string(),
+                call_id: "toolu_1".to_string(),
+            },
+            ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "toolu_2".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "toolu_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("a.txt".to_string()),
+            },
+            ResponseItem::CustomToolCallOutput {
+                call_id: "toolu_2".to_string(),
+                name: None,
+                output: FunctionCallOutputPayload {
+                    body: FunctionCallOutputBody::Text("patch failed".to_string()),
+                    success: Some(false),
+                },
+            },
+        ];
+
+        let (body, _) = build_anthropic_request(&request(input), &[]).expect("anthropic body");
+
+        assert_eq!(
+            body["system"],
+            json!([
+                { "type": "text", "text": "be helpful" },
+                {
+                    "type": "text",
+                    "text": "sandbox notes",
+                    "cache_control": { "type": "ephemeral" }
+                }
+            ])
+        );
+        assert_eq!(
+            body["messages"],
+            json!([
+                {
+                    "role": "user",
+                    "content": [
+                        { "type": "text", "text": "what is this?" },
+                        {
+                            "type": "image",
+                            "source": { "type": "base64", "media_type": "image/png", "data": "AAAA" }
+                        }
+                    ]
+                },
+                {
+                    "role": "assistant",
+                    "content": [
+                        { "type": "thinking", "thinking": "look at it", "signature": "sig" },
+                        {
+                            "type": "tool_use",
+                            "id": "toolu_1",
+                            "name": "shell",
+                            "input": { "command": ["ls"] }
+                        },
+                        {
+                            "type": "tool_use",
+                            "id": "toolu_2",
+                            "name": "apply_patch",
+                            "input": { "input": "*** Begin Patch" }
+                        }
+                    ]
+                },
+                {
+                    "role": "user",
+                    "content": [
+                        { "type": "tool_result", "tool_use_id": "toolu_1", "content": "a.txt" },
+                        {
+                            "type": "tool_result",
+                            "tool_use_id": "toolu_2",
+                            "content": "patch failed",
+                            "is_error": true,
+                            "cache_control": { "type": "ephemeral" }
+                        }
+                    ]
+                }
+            ])
+        );
+        assert_eq!(body["max_tokens"], json!(ANTHROPIC_MAX_OUTPUT_TOKENS));
+        assert_eq!(body.get("tools"), None);
+        assert_eq!(body.get("thinking"), None);
+    }
+
+    #[test]
+    fn maps_tools_and_thinking_budget() {
+        let tools = vec![
+            FunctionTool {
+                source: FunctionToolSource::Function {
+                    namespace: Some("mcp__docs__".to_string()),
+                    name: "search".to_string(),
+                },
+                description: "search docs".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: false,
+            },
+            FunctionTool {
+                source: FunctionToolSource::Freeform {
+                    name: "apply_patch".to_string(),
+                },
+                description: "edit files".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: false,
+            },
+        ];
+        let mut request = request(Vec::new());
+        request.tool_choice = "required".to_string();
+        request.parallel_tool_calls = false;
+        request.reasoning = Some(Reasoning {
+            effort: Some(ReasoningEffort::High),
+            summary: None,
+        });
+
+        let (body, registry) = build_anthropic_request(&request, &tools).expect("anthropic body");
+
+        assert_eq!(
+            body["tools"][0],
+            json!({
+                "name": "mcp__docs__search",
+                "description": "search docs",
+                "input_schema": { "type": "object", "properties": {} }
+            })
+        );
+        assert_eq!(body["tools"][1]["name"], json!("apply_patch"));
+        assert_eq!(
+            body["tools"][1]["cache_control"],
+            json!({ "type": "ephemeral" })
+        );
+        assert_eq!(body["tools"].as_array().map(Vec::len), Some(2));
+        assert_eq!(
+            body["tool_choice"],
+            json!({ "type": "auto", "disable_parallel_tool_use": true })
+        );
+        assert_eq!(
+            body["thinking"],
+            json!({ "type": "enabled", "budget_tokens": 24_000 })
+        );
+        assert_eq!(registry.get("mcp__docs__search"), Some(&tools[0].source));
+    }
+}
@@ -0,0 +1,359 @@
+use crate::common::FunctionTool;
+use crate::common::ResponsesApiRequest;
+use crate::common::TextControls;
+use crate::error::ApiError;
+use crate::requests::tools::ToolRegistry;
+use crate::requests::tools::custom_tool_arguments;
+use crate::requests::tools::flat_tool_name;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+/// Encodes a canonical Responses request as a Chat Completions request body.
+///
+/// The Responses `tools` on `request` are ignored; this wire API only takes
+/// the plain function tools in `tools`.
+pub(crate) fn build_chat_request(
+    request: &ResponsesApiRequest,
+    tools: &[FunctionTool],
+) -> Result<(Value, ToolRegistry), ApiError> {
+    let registry = ToolRegistry::new(tools);
+    let tools = tools.iter().map(chat_function_tool).collect::<Vec<_>>();
+    let messages = chat_messages_from_input(&request.instructions, &request.input);
+
+    let mut body = Map::new();
+    body.insert("model".to_string(), Value::String(request.model.clone()));
+    body.insert("messages".to_string(), Value::Array(messages));
+    body.insert("stream".to_string(), Value::Bool(true));
+    body.insert(
+        "stream_options".to_string(),
+        json!({ "include_usage": true }),
+    );
+    if !tools.is_empty() {
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert(
+            "tool_choice".to_string(),
+            Value::String(request.tool_choice.clone()),
+        );
+        body.insert(
+            "parallel_tool_calls".to_string(),
+            Value::Bool(request.parallel_tool_calls),
+        );
+    }
+    if let Some(effort) = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort)
+    {
+        let effort = serde_json::to_value(effort)
+            .map_err(|e| ApiError::Stream(format!("failed to encode chat request: {e}")))?;
+        body.insert("reasoning_effort".to_string(), effort);
+    }
+    if let Some(response_format) = request.text.as_ref().and_then(chat_response_format) {
+        body.insert("response_format".to_string(), response_format);
+    }
+    if let Some(prompt_cache_key) = &request.prompt_cache_key {
+        body.insert(
+            "prompt_cache_key".to_string(),
+            Value::String(prompt_cache_key.clone()),
+        );
+    }
+
+    Ok((Value::Object(body), registry))
+}
+
+fn chat_response_format(text: &TextControls) -> Option<Value> {
+    let format = text.format.as_ref()?;
+    Some(json!({
+        "type": "json_schema",
+        "json_schema": {
+            "name": format.name,
+            "schema": format.schema,
+            "strict": format.strict,
+        },
+    }))
+}
+
+fn chat_function_tool(tool: &FunctionTool) -> Value {
+    json!({
+        "type": "function",
+        "function": {
+            "name": tool.name(),
+            "description": tool.description,
+            "parameters": tool.parameters,
+            "strict": tool.strict,
+        },
+    })
+}
+
+fn chat_messages_from_input(instructions: &str, input: &[ResponseItem]) -> Vec<Value> {
+    let mut messages = Vec::new();
+    if !instructions.is_empty() {
+        messages.push(json!({ "role": "system", "content": instructions }));
+    }
+
+    for item in input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let role = match role.as_str() {
+                    "developer" => "system",
+                    role => role,
+                };
+                messages.push(json!({
+                    "role": role,
+                    "content": chat_message_content(role, content),
+                }));
+            }
+            ResponseItem::FunctionCall {
+                name,
+                namespace,
+                arguments,
+                call_id,
+                ..
+            } => {
+                let name = flat_tool_name(namespace.as_deref(), name);
+                push_assistant_tool_call(&mut messages, call_id, &name, arguments.clone());
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => {
+                let arguments = custom_tool_arguments(input).to_string();
+                push_assistant_tool_call(&mut messages, call_id, name, arguments);
+            }
+            ResponseItem::FunctionCallOutput { call_id, output }
+            | ResponseItem::CustomToolCallOutput {
+                call_id, output, ..
+            } => {
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": output.body.to_text().unwrap_or_default(),
+                }));
+            }
+            ResponseItem::Reasoning { .. }
+            | ResponseItem::LocalShellCall { .. }
+            | ResponseItem::ToolSearchCall { .. }
+            | ResponseItem::ToolSearchOutput { .. }
+            | ResponseItem::WebSearchCall { .. }
+            | ResponseItem::ImageGenerationCall { .. }
+            | ResponseItem::Compaction { .. }
+            | ResponseItem::ContextCompaction { .. }
+            | ResponseItem::Other => {}
+        }
+    }
+
+    messages
+}
+
+/// Appends a tool call to the trailing assistant message when there is one,
+/// so parallel calls and the text preceding them stay in a single turn.
+fn push_assistant_tool_call(
+    messages: &mut Vec<Value>,
+    call_id: &str,
+    name: &str,
+    arguments: String,
+) {
+    let tool_call = json!({
+        "id": call_id,
+        "type": "function",
+        "function": { "name": name, "arguments": arguments },
+    });
+
+    if let Some(last) = messages.last_mut()
+        && last.get("role").and_then(Value::as_str) == Some("assistant")
+        && let Some(last) = last.as_object_mut()
+    {
+        match last.get_mut("tool_calls").and_then(Value::as_array_mut) {
+            Some(tool_calls) => tool_calls.push(tool_call),
+            None => {
+                last.insert("tool_calls".to_string(), Value::Array(vec![tool_call]));
+            }
+        }
+        return;
+    }
+
+    messages.push(json!({
+        "role": "assistant",
+        "content": Value::Null,
+        "tool_calls": [tool_call],
+    }));
+}
+
+fn chat_message_content(role: &str, content: &[ContentItem]) -> Value {
+    let has_images = content
+        .iter()
+        .any(|item| matches!(item, ContentItem::InputImage { .. }));
+    if role == "user" && has_images {
+        let parts = content
+            .iter()
+            .map(|item| match item {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                    json!({ "type": "text", "text": text })
+                }
+                ContentItem::InputImage { image_url, .. } => {
+                    json!({ "type": "image_url", "image_url": { "url": image_url } })
+                }
+            })
+            .collect();
+        return Value::Array(parts);
+    }
+
+    let text = content
+        .iter()
+        .filter_map(|item| match item {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                Some(text.as_str())
+            }
+            ContentItem::InputImage { .. } => None,
+        })
+        .collect::<Vec<_>>()
+        .join("");
+    Value::String(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::FunctionToolSource;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn request(input: Vec<ResponseItem>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "gpt-oss:20b".to_string(),
+            instructions: "be helpful".to_string(),
+            input,
+            tools: Vec::new(),
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: true,
+            reasoning: None,
+            store: false,
+            stream: true,
+            include: Vec::new(),
+            service_tier: None,
+            prompt_cache_key: None,
+            text: None,
+            client_metadata: None,
+        }
+    }
+
+    #[test]
+    fn maps_messages_and_tool_round_trip() {
+        let input = vec![
+            ResponseItem::Message {
+                id: None,
+                role: "developer".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "sandbox notes".to_string(),
+                }],
+                phase: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "list files".to_string(),
+                }],
+                phase: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: "{\"command\":[\"ls\"]}".to_string(),
+                call_id: "call_1".to_string(),
+            },
+            ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call_2".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("a.txt".to_string()),
+            },
+        ];
+
+        let (body, _) = build_chat_request(&request(input), &[]).expect("chat body");
+
+        assert_eq!(
+            body["messages"],
+            json!([
+                { "role": "system", "content": "be helpful" },
+                { "role": "system", "content": "sandbox notes" },
+                { "role": "user", "content": "list files" },
+                {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [
+                        {
+                            "id": "call_1",
+                            "type": "function",
+                            "function": { "name": "shell", "arguments": "{\"command\":[\"ls\"]}" }
+                        },
+                        {
+                            "id": "call_2",
+                            "type": "function",
+                            "function": {
+                                "name": "apply_patch",
+                                "arguments": "{\"input\":\"*** Begin Patch\"}"
+                            }
+                        }
+                    ]
+                },
+                { "role": "tool", "tool_call_id": "call_1", "content": "a.txt" }
+            ])
+        );
+        assert_eq!(body.get("tools"), None);
+    }
+
+    #[test]
+    fn encodes_function_tools_and_registers_their_sources() {
+        let tools = vec![
+            FunctionTool {
+                source: FunctionToolSource::Function {
+                    namespace: Some("mcp__docs__".to_string()),
+                    name: "search".to_string(),
+                },
+                description: "search docs".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: false,
+            },
+            FunctionTool {
+                source: FunctionToolSource::Freeform {
+                    name: "apply_patch".to_string(),
+                },
+                description: "edit files".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: false,
+            },
+        ];
+
+        let (body, registry) = build_chat_request(&request(Vec::new()), &tools).expect("chat body");
+
+        assert_eq!(
+            body["tools"][0],
+            json!({
+                "type": "function",
+                "function": {
+                    "name": "mcp__docs__search",
+                    "description": "search docs",
+                    "parameters": { "type": "object", "properties": {} },
+                    "strict": false,
+                },
+            })
+        );
+        assert_eq!(body["tools"][1]["function"]["name"], json!("apply_patch"));
+        assert_eq!(registry.get("mcp__docs__search"), Some(&tools[0].source));
+        assert_eq!(registry.get("apply_patch"), Some(&tools[1].source));
+        assert_eq!(body["tool_choice"], json!("auto"));
+        assert_eq!(body["parallel_tool_calls"], json!(true));
+    }
+}
@@ -1,5 +1,8 @@
+pub(crate) mod anthropic;
+pub(crate) mod chat;
 pub(crate) mod headers;
 pub(crate) mod responses;
+pub(crate) mod tools;
 
 pub use responses::Compression;
 pub(crate) use responses::attach_item_ids;
@@ -0,0 +1,92 @@
+//! Tool bookkeeping for wire APIs that only know about plain function tools
+//! (Chat Completions, Anthropic Messages).
+
+use crate::common::FREEFORM_TOOL_INPUT_PARAM;
+use crate::common::FunctionTool;
+use crate::common::FunctionToolSource;
+use codex_protocol::models::ResponseItem;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Tools advertised in a translated request, keyed by the function name the
+/// model will use when calling them.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ToolRegistry {
+    tools: HashMap<String, FunctionToolSource>,
+}
+
+impl ToolRegistry {
+    pub(crate) fn new(tools: &[FunctionTool]) -> Self {
+        Self {
+            tools: tools
+                .iter()
+                .map(|tool| (tool.name(), tool.source.clone()))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn get(&self, function_name: &str) -> Option<&FunctionToolSource> {
+        self.tools.get(function_name)
+    }
+
+    /// Builds the Responses item for a tool call the model made by its
+    /// function name. Freeform tools are unwrapped from their `input`
+    /// argument; unknown names are surfaced as plain function calls so the
+    /// router can report them.
+    pub(crate) fn call_item(
+        &self,
+        call_id: String,
+        function_name: String,
+        arguments: String,
+    ) -> ResponseItem {
+        match self.get(&function_name) {
+            Some(FunctionToolSource::Freeform { name }) => {
+                let input = serde_json::from_str::<Value>(&arguments)
+                    .ok()
+                    .and_then(|value| {
+                        value
+                            .get(FREEFORM_TOOL_INPUT_PARAM)
+                            .and_then(Value::as_str)
+                            .map(str::to_string)
+                    })
+                    .unwrap_or(arguments);
+                ResponseItem::CustomToolCall {
+                    id: None,
+                    status: None,
+                    call_id,
+                    name: name.clone(),
+                    input,
+                }
+            }
+            Some(FunctionToolSource::Function { namespace, name }) => ResponseItem::FunctionCall {
+                id: None,
+                name: name.clone(),
+                namespace: namespace.clone(),
+                arguments,
+                call_id,
+            },
+            None => ResponseItem::FunctionCall {
+                id: None,
+                name: function_name,
+                namespace: None,
+                arguments,
+                call_id,
+            },
+        }
+    }
+}
+
+/// Name the model uses for a Responses function call after flattening.
+pub(crate) fn flat_tool_name(namespace: Option<&str>, name: &str) -> String {
+    FunctionToolSource::Function {
+        namespace: namespace.map(str::to_string),
+        name: name.to_string(),
+    }
+    .function_name()
+}
+
+/// Arguments object used to replay a freeform tool call as a function call.
+pub(crate) fn custom_tool_arguments(input: &str) -> Value {
+    json!({ FREEFORM_TOOL_INPUT_PARAM: input })
+}
@@ -0,0 +1,641 @@
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::rate_limits::parse_all_rate_limits;
+use crate::requests::anthropic::REDACTED_THINKING_PREFIX;
+use crate::requests::tools::ToolRegistry;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+
+const REQUEST_ID_HEADER: &str = "request-id";
+
+pub(crate) fn spawn_anthropic_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    tools: ToolRegistry,
+) -> ResponseStream {
+    let rate_limit_snapshots = parse_all_rate_limits(&stream_response.headers);
+    let upstream_request_id = stream_response
+        .headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(async move {
+        for snapshot in rate_limit_snapshots {
+            let _ = tx_event.send(Ok(ResponseEvent::RateLimits(snapshot))).await;
+        }
+        process_anthropic_sse(
+            stream_response.bytes,
+            tx_event,
+            idle_timeout,
+            telemetry,
+            tools,
+        )
+        .await;
+    });
+
+    ResponseStream {
+        rx_event,
+        upstream_request_id,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicStreamEvent {
+    MessageStart {
+        message: AnthropicMessageStart,
+    },
+    ContentBlockStart {
+        index: usize,
+        content_block: AnthropicContentBlock,
+    },
+    ContentBlockDelta {
+        index: usize,
+        delta: AnthropicBlockDelta,
+    },
+    ContentBlockStop {
+        index: usize,
+    },
+    MessageDelta {
+        #[serde(default)]
+        usage: Option<AnthropicUsage>,
+    },
+    MessageStop,
+    Error {
+        error: AnthropicError,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicMessageStart {
+    id: String,
+    #[serde(default)]
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicContentBlock {
+    Text {
+        #[serde(default)]
+        text: String,
+    },
+    Thinking {
+        #[serde(default)]
+        thinking: String,
+        #[serde(default)]
+        signature: String,
+    },
+    RedactedThinking {
+        data: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        #[serde(default)]
+        input: Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicBlockDelta {
+    TextDelta {
+        text: String,
+    },
+    ThinkingDelta {
+        thinking: String,
+    },
+    SignatureDelta {
+        signature: String,
+    },
+    InputJsonDelta {
+        partial_json: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Token counts reported by `message_start` and `message_delta`. Later events
+/// carry cumulative values, so fields that are present replace earlier ones.
+#[derive(Debug, Default, Deserialize)]
+struct AnthropicUsage {
+    #[serde(default)]
+    input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_creation_input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<i64>,
+    #[serde(default)]
+    output_tokens: Option<i64>,
+}
+
+impl AnthropicUsage {
+    fn merge(&mut self, update: AnthropicUsage) {
+        self.input_tokens = update.input_tokens.or(self.input_tokens);
+        self.cache_creation_input_tokens = update
+            .cache_creation_input_tokens
+            .or(self.cache_creation_input_tokens);
+        self.cache_read_input_tokens = update
+            .cache_read_input_tokens
+            .or(self.cache_read_input_tokens);
+        self.output_tokens = update.output_tokens.or(self.output_tokens);
+    }
+}
+
+impl From<&AnthropicUsage> for TokenUsage {
+    fn from(val: &AnthropicUsage) -> Self {
+        // `input_tokens` excludes cached tokens on this API; Codex counts them
+        // as part of the input.
+        let cached_input_tokens = val.cache_read_input_tokens.unwrap_or(0);
+        let input_tokens = val.input_tokens.unwrap_or(0)
+            + val.cache_creation_input_tokens.unwrap_or(0)
+            + cached_input_tokens;
+        let output_tokens = val.output_tokens.unwrap_or(0);
+        TokenUsage {
+            input_tokens,
+            cached_input_tokens,
+            cached_input_tokens_reported: Some(val.cache_read_input_tokens.is_some()),
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: input_tokens + output_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicError {
+    #[serde(rename = "type", default)]
+    kind: String,
+    #[serde(default)]
+    message: String,
+}
+
+impl From<AnthropicError> for ApiError {
+    fn from(error: AnthropicError) -> Self {
+        match error.kind.as_str() {
+            "overloaded_error" => ApiError::ServerOverloaded,
+            "rate_limit_error" | "api_error" => ApiError::Retryable {
+                message: error.message,
+                delay: None,
+            },
+            "invalid_request_error" if error.message.contains("prompt is too long") => {
+                ApiError::ContextWindowExceeded
+            }
+            "invalid_request_error" => ApiError::InvalidRequest {
+                message: error.message,
+            },
+            _ => ApiError::Stream(format!("{}: {}", error.kind, error.message)),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum OpenBlock {
+    Text {
+        text: String,
+    },
+    Thinking {
+        thinking: String,
+        signature: String,
+    },
+    RedactedThinking {
+        data: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        initial_input: Value,
+        partial_json: String,
+    },
+}
+
+/// Folds Messages API stream events into the item-oriented `ResponseEvent`
+/// sequence produced by the Responses API.
+///
+/// Every content block becomes one output item. Text and thinking blocks are
+/// announced with `OutputItemAdded` so their deltas stream against them; tool
+/// uses are emitted whole once their input JSON is complete.
+#[derive(Debug, Default)]
+struct AnthropicStreamState {
+    response_id: String,
+    blocks: HashMap<usize, OpenBlock>,
+    usage: Option<AnthropicUsage>,
+}
+
+impl AnthropicStreamState {
+    fn message_item(&self, index: usize, text: String) -> ResponseItem {
+        ResponseItem::Message {
+            id: Some(format!("msg_{}_{index}", self.response_id)),
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText { text }],
+            phase: None,
+        }
+    }
+
+    fn reasoning_item(
+        &self,
+        index: usize,
+        thinking: Option<String>,
+        encrypted_content: Option<String>,
+    ) -> ResponseItem {
+        ResponseItem::Reasoning {
+            id: format!("rs_{}_{index}", self.response_id),
+            summary: Vec::new(),
+            content: thinking.map(|text| vec![ReasoningItemContent::ReasoningText { text }]),
+            encrypted_content,
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        event: AnthropicStreamEvent,
+        tools: &ToolRegistry,
+    ) -> Result<Vec<ResponseEvent>, ApiError> {
+        let mut events = Vec::new();
+        match event {
+            AnthropicStreamEvent::MessageStart { message } => {
+                self.response_id = message.id;
+                self.usage = message.usage;
+                events.push(ResponseEvent::Created);
+            }
+            AnthropicStreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => {
+                let block = match content_block {
+                    AnthropicContentBlock::Text { text } => {
+                        events.push(ResponseEvent::OutputItemAdded(
+                            self.message_item(index, String::new()),
+                        ));
+                        if !text.is_empty() {
+                            events.push(ResponseEvent::OutputTextDelta(text.clone()));
+                        }
+                        OpenBlock::Text { text }
+                    }
+                    AnthropicContentBlock::Thinking {
+                        thinking,
+                        signature,
+                    } => {
+                        events.push(ResponseEvent::OutputItemAdded(self.reasoning_item(
+                            index,
+                            Some(String::new()),
+                            /*encrypted_content*/ None,
+                        )));
+                        if !thinking.is_empty() {
+                            events.push(ResponseEvent::ReasoningContentDelta {
+                                delta: thinking.clone(),
+                                content_index: 0,
+                            });
+                        }
+                        OpenBlock::Thinking {
+                            thinking,
+                            signature,
+                        }
+                    }
+                    AnthropicContentBlock::RedactedThinking { data } => {
+                        OpenBlock::RedactedThinking { data }
+                    }
+                    AnthropicContentBlock::ToolUse { id, name, input } => OpenBlock::ToolUse {
+                        id,
+                        name,
+                        initial_input: input,
+                        partial_json: String::new(),
+                    },
+                    AnthropicContentBlock::Other => {
+                        debug!("ignoring unsupported anthropic content block at index {index}");
+                        return Ok(events);
+                    }
+                };
+                self.blocks.insert(index, block);
+            }
+            AnthropicStreamEvent::ContentBlockDelta { index, delta } => {
+                let Some(block) = self.blocks.get_mut(&index) else {
+                    return Ok(events);
+                };
+                match (block, delta) {
+                    (OpenBlock::Text { text }, AnthropicBlockDelta::TextDelta { text: delta }) => {
+                        text.push_str(&delta);
+                        events.push(ResponseEvent::OutputTextDelta(delta));
+                    }
+                    (
+                        OpenBlock::Thinking { thinking, .. },
+                        AnthropicBlockDelta::ThinkingDelta { thinking: delta },
+                    ) => {
+                        thinking.push_str(&delta);
+                        events.push(ResponseEvent::ReasoningContentDelta {
+                            delta,
+                            content_index: 0,
+                        });
+                    }
+                    (
+                        OpenBlock::Thinking { signature, .. },
+                        AnthropicBlockDelta::SignatureDelta { signature: delta },
+                    ) => signature.push_str(&delta),
+                    (
+                        OpenBlock::ToolUse { partial_json, .. },
+                        AnthropicBlockDelta::InputJsonDelta {
+                            partial_json: delta,
+                        },
+                    ) => partial_json.push_str(&delta),
+                    (_, delta) => {
+                        trace!("ignoring anthropic delta {delta:?} for block {index}");
+                    }
+                }
+            }
+            AnthropicStreamEvent::ContentBlockStop { index } => {
+                let Some(block) = self.blocks.remove(&index) else {
+                    return Ok(events);
+                };
+                let item = match block {
+                    OpenBlock::Text { text } => self.message_item(index, text),
+                    OpenBlock::Thinking {
+                        thinking,
+                        signature,
+                    } => self.reasoning_item(
+                        index,
+                        Some(thinking),
+                        (!signature.is_empty()).then_some(signature),
+                    ),
+                    OpenBlock::RedactedThinking { data } => self.reasoning_item(
+                        index,
+                        /*thinking*/ None,
+                        Some(format!("{REDACTED_THINKING_PREFIX}{data}")),
+                    ),
+                    OpenBlock::ToolUse {
+                        id,
+                        name,
+                        initial_input,
+                        partial_json,
+                    } => {
+                        let arguments = if partial_json.trim().is_empty() {
+                            match initial_input {
+                                Value::Null => "{}".to_string(),
+                                input => input.to_string(),
+                            }
+                        } else {
+                            partial_json
+                        };
+                        tools.call_item(id, name, arguments)
+                    }
+                };
+                events.push(ResponseEvent::OutputItemDone(item));
+            }
+            AnthropicStreamEvent::MessageDelta { usage } => {
+                if let Some(update) = usage {
+                    self.usage
+                        .get_or_insert_with(Default::default)
+                        .merge(update);
+                }
+            }
+            AnthropicStreamEvent::MessageStop => {
+                events.push(ResponseEvent::Completed {
+                    response_id: self.response_id.clone(),
+                    token_usage: self.usage.as_ref().map(TokenUsage::from),
+                    end_turn: None,
+                });
+            }
+            AnthropicStreamEvent::Error { error } => return Err(error.into()),
+            AnthropicStreamEvent::Other => {}
+        }
+        Ok(events)
+    }
+}
+
+pub(crate) async fn process_anthropic_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    tools: ToolRegistry,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = AnthropicStreamState::default();
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            Ok(None) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream(
+                        "stream closed before message_stop".into(),
+                    )))
+                    .await;
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("Anthropic SSE event: {}", &sse.data);
+
+        let event: AnthropicStreamEvent = match serde_json::from_str(&sse.data) {
+            Ok(event) => event,
+            Err(e) => {
+                debug!(
+                    "Failed to parse anthropic SSE event: {e}, data: {}",
+                    &sse.data
+                );
+                continue;
+            }
+        };
+
+        let is_stop = matches!(event, AnthropicStreamEvent::MessageStop);
+        match state.handle_event(event, &tools) {
+            Ok(events) => {
+                for event in events {
+                    if tx_event.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(error) => {
+                let _ = tx_event.send(Err(error)).await;
+                return;
+            }
+        }
+        if is_stop {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_anthropic_sse(events: Vec<Value>) -> Vec<Result<ResponseEvent, ApiError>> {
+        let mut body = String::new();
+        for event in events {
+            let kind = event["type"].as_str().unwrap_or_default().to_string();
+            body.push_str(&format!("event: {kind}\ndata: {event}\n\n"));
+        }
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(64);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        tokio::spawn(process_anthropic_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            /*telemetry*/ None,
+            ToolRegistry::default(),
+        ));
+
+        let mut out = Vec::new();
+        while let Some(ev) = rx.recv().await {
+            out.push(ev);
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn streams_thinking_text_and_tool_use() {
+        let events = run_anthropic_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_1", "usage": {
+                "input_tokens": 10, "cache_read_input_tokens": 90, "cache_creation_input_tokens": 0, "output_tokens": 1
+            }}}),
+            json!({"type": "content_block_start", "index": 0, "content_block": {"type": "thinking", "thinking": ""}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "thinking_delta", "thinking": "hmm"}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "signature_delta", "signature": "sig"}}),
+            json!({"type": "content_block_stop", "index": 0}),
+            json!({"type": "ping"}),
+            json!({"type": "content_block_start", "index": 1, "content_block": {"type": "text", "text": ""}}),
+            json!({"type": "content_block_delta", "index": 1, "delta": {"type": "text_delta", "text": "Hi"}}),
+            json!({"type": "content_block_stop", "index": 1}),
+            json!({"type": "content_block_start", "index": 2, "content_block": {"type": "tool_use", "id": "toolu_1", "name": "shell", "input": {}}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "{\"command\":"}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "[\"ls\"]}"}}),
+            json!({"type": "content_block_stop", "index": 2}),
+            json!({"type": "message_delta", "delta": {"stop_reason": "tool_use"}, "usage": {"output_tokens": 25}}),
+            json!({"type": "message_stop"}),
+        ])
+        .await;
+        let events = events
+            .into_iter()
+            .map(|event| event.expect("anthropic stream error"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(events.len(), 10);
+        assert_matches!(events[0], ResponseEvent::Created);
+        assert_matches!(
+            &events[1],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { id, .. }) if id == "rs_msg_1_0"
+        );
+        assert_matches!(
+            &events[2],
+            ResponseEvent::ReasoningContentDelta { delta, content_index: 0 } if delta == "hmm"
+        );
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(ResponseItem::Reasoning {
+                content: Some(content),
+                encrypted_content: Some(signature),
+                ..
+            }) if content == &vec![ReasoningItemContent::ReasoningText { text: "hmm".to_string() }]
+                && signature == "sig"
+        );
+        assert_matches!(
+            &events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { id: Some(id), .. }) if id == "msg_msg_1_1"
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Hi");
+        assert_matches!(
+            &events[6],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. })
+                if content == &vec![ContentItem::OutputText { text: "Hi".to_string() }]
+        );
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemDone(item) if item == &ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: "{\"command\":[\"ls\"]}".to_string(),
+                call_id: "toolu_1".to_string(),
+            }
+        );
+        match &events[9] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage: Some(token_usage),
+                end_turn,
+            } => {
+                assert_eq!(response_id, "msg_1");
+                assert_eq!(token_usage.input_tokens, 100);
+                assert_eq!(token_usage.cached_input_tokens, 90);
+                assert_eq!(token_usage.output_tokens, 25);
+                assert_eq!(token_usage.total_tokens, 125);
+                assert!(end_turn.is_none());
+            }
+            other => panic!("unexpected completed event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_stream_errors() {
+        let events = run_anthropic_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_2"}}),
+            json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}),
+        ])
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert_matches!(events[1], Err(ApiError::ServerOverloaded));
+    }
+
+    #[tokio::test]
+    async fn errors_when_stream_ends_before_message_stop() {
+        let events = run_anthropic_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_3"}}),
+            json!({"type": "content_block_start", "index": 0, "content_block": {"type": "text", "text": ""}}),
+        ])
+        .await;
+
+        assert_matches!(events.last(), Some(Err(ApiError::Stream(_))));
+    }
+}
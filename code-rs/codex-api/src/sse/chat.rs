@@ -0,0 +1,621 @@
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::rate_limits::parse_all_rate_limits;
+use crate::requests::tools::ToolRegistry;
+use crate::sse::responses::Error;
+use crate::sse::responses::api_error_from_response_error;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+const CHAT_DONE_SENTINEL: &str = "[DONE]";
+
+pub(crate) fn spawn_chat_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    tools: ToolRegistry,
+) -> ResponseStream {
+    let rate_limit_snapshots = parse_all_rate_limits(&stream_response.headers);
+    let upstream_request_id = stream_response
+        .headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(async move {
+        for snapshot in rate_limit_snapshots {
+            let _ = tx_event.send(Ok(ResponseEvent::RateLimits(snapshot))).await;
+        }
+        process_chat_sse(
+            stream_response.bytes,
+            tx_event,
+            idle_timeout,
+            telemetry,
+            tools,
+        )
+        .await;
+    });
+
+    ResponseStream {
+        rx_event,
+        upstream_request_id,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    #[serde(default)]
+    delta: Option<ChatDelta>,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChatDelta {
+    #[serde(default)]
+    content: Option<String>,
+    /// Emitted by vLLM, DeepSeek and most OpenAI-compatible reasoning servers.
+    #[serde(default)]
+    reasoning_content: Option<String>,
+    /// Emitted by Ollama and OpenRouter.
+    #[serde(default)]
+    reasoning: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ChatToolCallDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatToolCallDelta {
+    #[serde(default)]
+    index: Option<usize>,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    function: Option<ChatFunctionDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatFunctionDelta {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    #[serde(default)]
+    prompt_tokens: i64,
+    #[serde(default)]
+    completion_tokens: i64,
+    #[serde(default)]
+    total_tokens: i64,
+    #[serde(default)]
+    prompt_tokens_details: Option<ChatPromptTokensDetails>,
+    #[serde(default)]
+    completion_tokens_details: Option<ChatCompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatPromptTokensDetails {
+    #[serde(default)]
+    cached_tokens: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: i64,
+}
+
+impl From<ChatUsage> for TokenUsage {
+    fn from(val: ChatUsage) -> Self {
+        let cached_input_tokens_reported = val.prompt_tokens_details.is_some();
+        TokenUsage {
+            input_tokens: val.prompt_tokens,
+            cached_input_tokens: val
+                .prompt_tokens_details
+                .map(|d| d.cached_tokens)
+                .unwrap_or(0),
+            cached_input_tokens_reported: Some(cached_input_tokens_reported),
+            output_tokens: val.completion_tokens,
+            reasoning_output_tokens: val
+                .completion_tokens_details
+                .map(|d| d.reasoning_tokens)
+                .unwrap_or(0),
+            total_tokens: val.total_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: Option<String>,
+    name: String,
+    arguments: String,
+}
+
+/// Folds Chat Completions chunks into the item-oriented `ResponseEvent`
+/// sequence produced by the Responses API.
+///
+/// Reasoning and assistant text are streamed as deltas against synthetic
+/// items; tool calls are only complete once the stream finishes, so they are
+/// emitted as `OutputItemDone` right before `Completed`.
+#[derive(Debug, Default)]
+struct ChatStreamState {
+    response_id: Option<String>,
+    created: bool,
+    reasoning: Option<String>,
+    reasoning_done: bool,
+    message: Option<String>,
+    tool_calls: BTreeMap<usize, PendingToolCall>,
+    finished: bool,
+    token_usage: Option<TokenUsage>,
+}
+
+impl ChatStreamState {
+    fn response_id(&self) -> &str {
+        self.response_id.as_deref().unwrap_or("chatcmpl")
+    }
+
+    fn reasoning_item_id(&self) -> String {
+        format!("rs_{}", self.response_id())
+    }
+
+    fn message_item_id(&self) -> String {
+        format!("msg_{}", self.response_id())
+    }
+
+    fn reasoning_item(&self, text: String) -> ResponseItem {
+        ResponseItem::Reasoning {
+            id: self.reasoning_item_id(),
+            summary: Vec::new(),
+            content: Some(vec![ReasoningItemContent::ReasoningText { text }]),
+            encrypted_content: None,
+        }
+    }
+
+    fn message_item(&self, text: String) -> ResponseItem {
+        ResponseItem::Message {
+            id: Some(self.message_item_id()),
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText { text }],
+            phase: None,
+        }
+    }
+
+    fn handle_chunk(&mut self, chunk: ChatCompletionChunk) -> Vec<ResponseEvent> {
+        let mut events = Vec::new();
+        if self.response_id.is_none() {
+            self.response_id = chunk.id.filter(|id| !id.is_empty());
+        }
+        if !self.created {
+            self.created = true;
+            events.push(ResponseEvent::Created);
+        }
+        if let Some(usage) = chunk.usage {
+            self.token_usage = Some(usage.into());
+        }
+
+        for choice in chunk.choices {
+            if let Some(delta) = choice.delta {
+                self.handle_delta(delta, &mut events);
+            }
+            if choice.finish_reason.is_some() {
+                self.finished = true;
+            }
+        }
+
+        events
+    }
+
+    fn handle_delta(&mut self, delta: ChatDelta, events: &mut Vec<ResponseEvent>) {
+        if let Some(reasoning) = delta
+            .reasoning_content
+            .or(delta.reasoning)
+            .filter(|text| !text.is_empty())
+        {
+            if self.reasoning_done {
+                // Late reasoning after the answer started; keep it, but it can
+                // no longer be streamed against the closed reasoning item.
+                self.reasoning
+                    .get_or_insert_with(String::new)
+                    .push_str(&reasoning);
+            } else {
+                if self.reasoning.is_none() {
+                    self.reasoning = Some(String::new());
+                    events.push(ResponseEvent::OutputItemAdded(
+                        self.reasoning_item(String::new()),
+                    ));
+                }
+                if let Some(buffer) = self.reasoning.as_mut() {
+                    buffer.push_str(&reasoning);
+                }
+                events.push(ResponseEvent::ReasoningContentDelta {
+                    delta: reasoning,
+                    content_index: 0,
+                });
+            }
+        }
+
+        if let Some(content) = delta.content.filter(|text| !text.is_empty()) {
+            if self.message.is_none() {
+                self.close_reasoning(events);
+                self.message = Some(String::new());
+                events.push(ResponseEvent::OutputItemAdded(
+                    self.message_item(String::new()),
+                ));
+            }
+            if let Some(buffer) = self.message.as_mut() {
+                buffer.push_str(&content);
+            }
+            events.push(ResponseEvent::OutputTextDelta(content));
+        }
+
+        for (position, tool_call) in delta.tool_calls.into_iter().enumerate() {
+            let index = tool_call.index.unwrap_or(position);
+            let pending = self.tool_calls.entry(index).or_default();
+            if let Some(id) = tool_call.id.filter(|id| !id.is_empty()) {
+                pending.id = Some(id);
+            }
+            if let Some(function) = tool_call.function {
+                if let Some(name) = function.name {
+                    pending.name.push_str(&name);
+                }
+                if let Some(arguments) = function.arguments {
+                    pending.arguments.push_str(&arguments);
+                }
+            }
+        }
+    }
+
+    fn close_reasoning(&mut self, events: &mut Vec<ResponseEvent>) {
+        if self.reasoning_done {
+            return;
+        }
+        self.reasoning_done = true;
+        if let Some(text) = self.reasoning.take() {
+            events.push(ResponseEvent::OutputItemDone(self.reasoning_item(text)));
+        }
+    }
+
+    fn finish(mut self, tools: &ToolRegistry) -> Vec<ResponseEvent> {
+        let mut events = Vec::new();
+        self.close_reasoning(&mut events);
+        if let Some(text) = self.reasoning.take() {
+            events.push(ResponseEvent::OutputItemDone(self.reasoning_item(text)));
+        }
+        if let Some(text) = self.message.take() {
+            events.push(ResponseEvent::OutputItemDone(self.message_item(text)));
+        }
+
+        let response_id = self.response_id().to_string();
+        for (index, call) in std::mem::take(&mut self.tool_calls) {
+            let call_id = call
+                .id
+                .unwrap_or_else(|| format!("call_{response_id}_{index}"));
+            events.push(ResponseEvent::OutputItemDone(tools.call_item(
+                call_id,
+                call.name,
+                call.arguments,
+            )));
+        }
+
+        events.push(ResponseEvent::Completed {
+            response_id,
+            token_usage: self.token_usage,
+            end_turn: None,
+        });
+        events
+    }
+}
+
+pub(crate) async fn process_chat_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    tools: ToolRegistry,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = ChatStreamState::default();
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            Ok(None) => {
+                // Some OpenAI-compatible servers close the stream without the
+                // `[DONE]` sentinel once a choice has finished.
+                if state.finished {
+                    for event in state.finish(&tools) {
+                        if tx_event.send(Ok(event)).await.is_err() {
+                            return;
+                        }
+                    }
+                } else {
+                    let _ = tx_event
+                        .send(Err(ApiError::Stream(
+                            "stream closed before chat completion finished".into(),
+                        )))
+                        .await;
+                }
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("Chat SSE event: {}", &sse.data);
+
+        if sse.data.trim() == CHAT_DONE_SENTINEL {
+            for event in state.finish(&tools) {
+                if tx_event.send(Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+            return;
+        }
+
+        let chunk: ChatCompletionChunk = match serde_json::from_str(&sse.data) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                debug!("Failed to parse chat SSE event: {e}, data: {}", &sse.data);
+                continue;
+            }
+        };
+
+        if let Some(error) = chunk.error {
+            let error = match serde_json::from_value::<Error>(error) {
+                Ok(error) => api_error_from_response_error(error),
+                Err(_) => ApiError::Stream("chat completion stream returned an error".into()),
+            };
+            let _ = tx_event.send(Err(error)).await;
+            return;
+        }
+
+        for event in state.handle_chunk(chunk) {
+            if tx_event.send(Ok(event)).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::FunctionTool;
+    use crate::common::FunctionToolSource;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_chat_sse(chunks: Vec<Value>, tools: ToolRegistry) -> Vec<ResponseEvent> {
+        let mut body = String::new();
+        for chunk in chunks {
+            body.push_str(&format!("data: {chunk}\n\n"));
+        }
+        body.push_str("data: [DONE]\n\n");
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(32);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        tokio::spawn(process_chat_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            /*telemetry*/ None,
+            tools,
+        ));
+
+        let mut out = Vec::new();
+        while let Some(ev) = rx.recv().await {
+            out.push(ev.expect("chat stream error"));
+        }
+        out
+    }
+
+    fn function_tool(source: FunctionToolSource) -> FunctionTool {
+        FunctionTool {
+            source,
+            description: String::new(),
+            parameters: json!({}),
+            strict: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_reasoning_then_text_and_usage() {
+        let events = run_chat_sse(
+            vec![
+                json!({"id": "chatcmpl-1", "choices": [{"delta": {"role": "assistant", "reasoning_content": "think"}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"delta": {"content": "Hel"}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"delta": {"content": "lo"}, "finish_reason": "stop"}]}),
+                json!({"id": "chatcmpl-1", "choices": [], "usage": {
+                    "prompt_tokens": 10,
+                    "completion_tokens": 4,
+                    "total_tokens": 14,
+                    "completion_tokens_details": {"reasoning_tokens": 1}
+                }}),
+            ],
+            ToolRegistry::default(),
+        )
+        .await;
+
+        assert_eq!(events.len(), 9);
+        assert_matches!(events[0], ResponseEvent::Created);
+        assert_matches!(
+            &events[1],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { id, .. }) if id == "rs_chatcmpl-1"
+        );
+        assert_matches!(
+            &events[2],
+            ResponseEvent::ReasoningContentDelta { delta, content_index: 0 } if delta == "think"
+        );
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(ResponseItem::Reasoning { content: Some(content), .. })
+                if content == &vec![ReasoningItemContent::ReasoningText { text: "think".to_string() }]
+        );
+        assert_matches!(
+            &events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { id: Some(id), .. }) if id == "msg_chatcmpl-1"
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Hel");
+        assert_matches!(&events[6], ResponseEvent::OutputTextDelta(delta) if delta == "lo");
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. })
+                if content == &vec![ContentItem::OutputText { text: "Hello".to_string() }]
+        );
+        match &events[8] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage: Some(token_usage),
+                end_turn,
+            } => {
+                assert_eq!(response_id, "chatcmpl-1");
+                assert_eq!(token_usage.input_tokens, 10);
+                assert_eq!(token_usage.output_tokens, 4);
+                assert_eq!(token_usage.reasoning_output_tokens, 1);
+                assert_eq!(token_usage.cached_input_tokens_reported, Some(false));
+                assert!(end_turn.is_none());
+            }
+            other => panic!("unexpected completed event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn accumulates_tool_call_deltas_into_items() {
+        let tools = ToolRegistry::new(&[
+            function_tool(FunctionToolSource::Function {
+                namespace: None,
+                name: "shell".to_string(),
+            }),
+            function_tool(FunctionToolSource::Function {
+                namespace: Some("mcp__docs__".to_string()),
+                name: "search".to_string(),
+            }),
+            function_tool(FunctionToolSource::Freeform {
+                name: "apply_patch".to_string(),
+            }),
+        ]);
+
+        let events = run_chat_sse(
+            vec![
+                json!({"id": "c2", "choices": [{"delta": {"tool_calls": [
+                    {"index": 0, "id": "call_a", "type": "function", "function": {"name": "shell", "arguments": "{\"command\":"}},
+                    {"index": 1, "id": "call_b", "type": "function", "function": {"name": "mcp__docs__search", "arguments": "{}"}}
+                ]}}]}),
+                json!({"id": "c2", "choices": [{"delta": {"tool_calls": [
+                    {"index": 0, "function": {"arguments": "[\"ls\"]}"}},
+                    {"index": 2, "id": "call_c", "function": {"name": "apply_patch", "arguments": "{\"input\":\"*** Begin Patch\"}"}}
+                ]}, "finish_reason": "tool_calls"}]}),
+            ],
+            tools,
+        )
+        .await;
+
+        let items = events
+            .into_iter()
+            .filter_map(|event| match event {
+                ResponseEvent::OutputItemDone(item) => Some(item),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                ResponseItem::FunctionCall {
+                    id: None,
+                    name: "shell".to_string(),
+                    namespace: None,
+                    arguments: "{\"command\":[\"ls\"]}".to_string(),
+                    call_id: "call_a".to_string(),
+                },
+                ResponseItem::FunctionCall {
+                    id: None,
+                    name: "search".to_string(),
+                    namespace: Some("mcp__docs__".to_string()),
+                    arguments: "{}".to_string(),
+                    call_id: "call_b".to_string(),
+                },
+                ResponseItem::CustomToolCall {
+                    id: None,
+                    status: None,
+                    call_id: "call_c".to_string(),
+                    name: "apply_patch".to_string(),
+                    input: "*** Begin Patch".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn maps_stream_errors() {
+        let body = format!(
+            "data: {}\n\n",
+            json!({"error": {"code": "context_length_exceeded", "message": "too long"}})
+        );
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(8);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        tokio::spawn(process_chat_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            /*telemetry*/ None,
+            ToolRegistry::default(),
+        ));
+
+        assert_matches!(rx.recv().await, Some(Err(ApiError::ContextWindowExceeded)));
+    }
+}
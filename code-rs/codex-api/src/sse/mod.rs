@@ -1,5 +1,9 @@
+pub(crate) mod anthropic;
+pub(crate) mod chat;
 pub(crate) mod responses;
 
+pub(crate) use anthropic::spawn_anthropic_stream;
+pub(crate) use chat::spawn_chat_stream;
 pub(crate) use responses::ResponsesStreamEvent;
 pub(crate) use responses::process_responses_event;
 pub use responses::spawn_response_stream;
@@ -121,7 +121,7 @@ pub fn spawn_response_stream(
 
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
-struct Error {
+pub(crate) struct Error {
     r#type: Option<String>,
     code: Option<String>,
     message: Option<String>,
@@ -351,27 +351,7 @@ pub fn process_responses_event(
                 if let Some(error) = resp_val.get("error")
                     && let Ok(error) = serde_json::from_value::<Error>(error.clone())
                 {
-                    if is_context_window_error(&error) {
-                        response_error = ApiError::ContextWindowExceeded;
-                    } else if is_quota_exceeded_error(&error) {
-                        response_error = ApiError::QuotaExceeded;
-                    } else if is_usage_not_included(&error) {
-                        response_error = ApiError::UsageNotIncluded;
-                    } else if is_cyber_policy_error(&error) {
-                        let message = cyber_policy_message(error.message);
-                        response_error = ApiError::CyberPolicy { message };
-                    } else if is_invalid_prompt_error(&error) {
-                        let message = error
-                            .message
-                            .unwrap_or_else(|| "Invalid request.".to_string());
-                        response_error = ApiError::InvalidRequest { message };
-                    } else if is_server_overloaded_error(&error) {
-                        response_error = ApiError::ServerOverloaded;
-                    } else {
-                        let delay = try_parse_retry_after(&error);
-                        let message = error.message.unwrap_or_default();
-                        response_error = ApiError::Retryable { message, delay };
-                    }
+                    response_error = api_error_from_response_error(error);
                 }
                 return Err(ResponsesEventError::Api(response_error));
             }
@@ -520,6 +500,31 @@ pub async fn process_sse(
     }
 }
 
+/// Maps a structured error payload reported inside a stream onto an [`ApiError`].
+pub(crate) fn api_error_from_response_error(error: Error) -> ApiError {
+    if is_context_window_error(&error) {
+        ApiError::ContextWindowExceeded
+    } else if is_quota_exceeded_error(&error) {
+        ApiError::QuotaExceeded
+    } else if is_usage_not_included(&error) {
+        ApiError::UsageNotIncluded
+    } else if is_cyber_policy_error(&error) {
+        let message = cyber_policy_message(error.message);
+        ApiError::CyberPolicy { message }
+    } else if is_invalid_prompt_error(&error) {
+        let message = error
+            .message
+            .unwrap_or_else(|| "Invalid request.".to_string());
+        ApiError::InvalidRequest { message }
+    } else if is_server_overloaded_error(&error) {
+        ApiError::ServerOverloaded
+    } else {
+        let delay = try_parse_retry_after(&error);
+        let message = error.message.unwrap_or_default();
+        ApiError::Retryable { message, delay }
+    }
+}
+
 fn try_parse_retry_after(err: &Error) -> Option<Duration> {
     if err.code.as_deref() != Some("rate_limit_exceeded") {
         return None;
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_api::AnthropicClient;
+use codex_api::AuthProvider;
+use codex_api::FunctionTool;
+use codex_api::FunctionToolSource;
+use codex_api::Provider;
+use codex_api::ResponseEvent;
+use codex_api::ResponsesApiRequest;
+use codex_api::ResponsesOptions;
+use codex_api::RetryConfig;
+use codex_client::ReqwestTransport;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use futures::StreamExt;
+use http::HeaderMap;
+use http::HeaderValue;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[derive(Clone)]
+struct BearerAuth;
+
+impl AuthProvider for BearerAuth {
+    fn add_auth_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(
+            http::header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer sk-ant-test"),
+        );
+    }
+}
+
+fn provider(base_url: &str) -> Provider {
+    Provider {
+        name: "anthropic".to_string(),
+        base_url: base_url.to_string(),
+        query_params: None,
+        headers: HeaderMap::new(),
+        retry: RetryConfig {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(1),
+            retry_429: false,
+            retry_5xx: false,
+            retry_transport: true,
+        },
+        stream_idle_timeout: Duration::from_secs(5),
+    }
+}
+
+fn sse_body(events: Vec<Value>) -> String {
+    let mut body = String::new();
+    for event in events {
+        let kind = event["type"].as_str().unwrap_or_default().to_string();
+        body.push_str(&format!("event: {kind}\ndata: {event}\n\n"));
+    }
+    body
+}
+
+#[tokio::test]
+async fn anthropic_client_streams_messages_from_mock_server() {
+    let server = MockServer::start().await;
+    let body = sse_body(vec![
+        json!({"type": "message_start", "message": {"id": "msg_01", "usage": {"input_tokens": 12, "output_tokens": 1}}}),
+        json!({"type": "content_block_start", "index": 0, "content_block": {"type": "text", "text": ""}}),
+        json!({"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta", "text": "Hello"}}),
+        json!({"type": "content_block_stop", "index": 0}),
+        json!({"type": "content_block_start", "index": 1, "content_block": {"type": "tool_use", "id": "toolu_01", "name": "apply_patch", "input": {}}}),
+        json!({"type": "content_block_delta", "index": 1, "delta": {"type": "input_json_delta", "partial_json": "{\"input\":\"*** Begin Patch\"}"}}),
+        json!({"type": "content_block_stop", "index": 1}),
+        json!({"type": "message_delta", "delta": {"stop_reason": "tool_use"}, "usage": {"output_tokens": 7}}),
+        json!({"type": "message_stop"}),
+    ]);
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .and(header("x-api-key", "sk-ant-test"))
+        .and(header("anthropic-version", "2023-06-01"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(body),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = AnthropicClient::new(
+        ReqwestTransport::new(reqwest::Client::new()),
+        provider(&format!("{}/v1", server.uri())),
+        Arc::new(BearerAuth),
+    );
+    let tools = vec![FunctionTool {
+        source: FunctionToolSource::Freeform {
+            name: "apply_patch".to_string(),
+        },
+        description: "edit files".to_string(),
+        parameters: json!({ "type": "object", "properties": { "input": { "type": "string" } } }),
+        strict: false,
+    }];
+    let request = ResponsesApiRequest {
+        model: "claude-sonnet-4-5".to_string(),
+        instructions: "be helpful".to_string(),
+        input: vec![ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "fix it".to_string(),
+            }],
+            phase: None,
+        }],
+        tools: Vec::new(),
+        tool_choice: "auto".to_string(),
+        parallel_tool_calls: true,
+        reasoning: None,
+        store: false,
+        stream: true,
+        include: Vec::new(),
+        service_tier: None,
+        prompt_cache_key: None,
+        text: None,
+        client_metadata: None,
+    };
+
+    let mut stream = client
+        .stream_request(request, tools, ResponsesOptions::default())
+        .await
+        .expect("anthropic stream");
+    let mut items = Vec::new();
+    let mut completed = None;
+    while let Some(event) = stream.next().await {
+        match event.expect("anthropic event") {
+            ResponseEvent::OutputItemDone(item) => items.push(item),
+            ResponseEvent::Completed {
+                response_id,
+                token_usage,
+                ..
+            } => completed = Some((response_id, token_usage)),
+            _ => {}
+        }
+    }
+
+    assert_eq!(
+        items,
+        vec![
+            ResponseItem::Message {
+                id: Some("msg_msg_01_0".to_string()),
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "Hello".to_string(),
+                }],
+                phase: None,
+            },
+            ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "toolu_01".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch".to_string(),
+            },
+        ]
+    );
+    let (response_id, token_usage) = completed.expect("completed event");
+    assert_eq!(response_id, "msg_01");
+    let token_usage = token_usage.expect("token usage");
+    assert_eq!(token_usage.input_tokens, 12);
+    assert_eq!(token_usage.output_tokens, 7);
+
+    let received = server
+        .received_requests()
+        .await
+        .expect("should capture requests");
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].headers.get("authorization"), None);
+    let sent: Value = serde_json::from_slice(&received[0].body).expect("json body");
+    assert_eq!(
+        sent["system"],
+        json!([{ "type": "text", "text": "be helpful", "cache_control": { "type": "ephemeral" } }])
+    );
+    assert_eq!(sent["tools"][0]["name"], json!("apply_patch"));
+    assert_eq!(
+        sent["messages"],
+        json!([{
+            "role": "user",
+            "content": [{ "type": "text", "text": "fix it", "cache_control": { "type": "ephemeral" } }]
+        }])
+    );
+}
@@ -2,12 +2,13 @@
 //!
 //! [`McpConnectionManager`] owns the set of running async RMCP clients keyed by
 //! MCP server name. It coordinates startup status events, keeps server origin
-//! metadata, aggregates tools/resources/templates across servers, routes tool
-//! calls to the right client, and exposes the public manager API used by
+//! metadata, aggregates tools/resources/templates/prompts across servers, routes
+//! tool calls to the right client, and exposes the public manager API used by
 //! `codex-core`.
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -28,8 +29,11 @@ use crate::rmcp_client::MCP_TOOLS_LIST_DURATION_METRIC;
 use crate::rmcp_client::ManagedClient;
 use crate::rmcp_client::StartupOutcomeError;
 use crate::rmcp_client::list_tools_for_client_uncached;
+use crate::roots::mcp_roots;
+use crate::roots::workspace_roots;
 use crate::runtime::McpRuntimeEnvironment;
 use crate::runtime::emit_duration;
+use crate::sampling::SamplingHandlerHandle;
 use crate::server::EffectiveMcpServer;
 use crate::server::McpServerMetadata;
 use crate::tools::ToolInfo;
@@ -50,19 +54,28 @@ use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::McpPromptListChangedEvent;
+use codex_protocol::protocol::McpResourceListChangedEvent;
 use codex_protocol::protocol::McpStartupCompleteEvent;
 use codex_protocol::protocol::McpStartupFailure;
 use codex_protocol::protocol::McpStartupStatus;
 use codex_protocol::protocol::McpStartupUpdateEvent;
+use codex_protocol::protocol::McpToolListChangedEvent;
 use codex_rmcp_client::ElicitationResponse;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::Prompt;
 use rmcp::model::ReadResourceRequestParams;
 use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
 use rmcp::model::Resource;
 use rmcp::model::ResourceTemplate;
+use rmcp::model::Root;
+use tokio::sync::watch;
 use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::instrument;
@@ -74,6 +87,9 @@ pub struct McpConnectionManager {
     server_metadata: HashMap<String, McpServerMetadata>,
     host_owned_codex_apps_enabled: bool,
     elicitation_requests: ElicitationRequestManager,
+    sampling_handler: Option<SamplingHandlerHandle>,
+    /// Workspace roots answered to `roots/list`, shared by every client.
+    roots: watch::Sender<Vec<Root>>,
     startup_cancellation_token: CancellationToken,
 }
 
@@ -91,6 +107,8 @@ impl McpConnectionManager {
                 permission_profile.get().clone(),
                 /*reviewer*/ None,
             ),
+            sampling_handler: None,
+            roots: watch::Sender::new(Vec::new()),
             startup_cancellation_token: CancellationToken::new(),
         }
     }
@@ -157,6 +175,22 @@ impl McpConnectionManager {
         }
     }
 
+    pub fn sampling_handler(&self) -> Option<SamplingHandlerHandle> {
+        self.sampling_handler.clone()
+    }
+
+    /// Update the roots reported to servers, notifying them when they change.
+    pub fn set_roots(&self, cwd: &Path, permission_profile: &PermissionProfile) {
+        let roots = mcp_roots(&workspace_roots(cwd, permission_profile));
+        self.roots.send_if_modified(|current| {
+            if *current == roots {
+                return false;
+            }
+            *current = roots;
+            true
+        });
+    }
+
     pub fn elicitations_auto_deny(&self) -> bool {
         self.elicitation_requests.auto_deny()
     }
@@ -181,11 +215,16 @@ impl McpConnectionManager {
         tool_plugin_provenance: ToolPluginProvenance,
         auth: Option<&CodexAuth>,
         elicitation_reviewer: Option<ElicitationReviewerHandle>,
+        sampling_handler: Option<SamplingHandlerHandle>,
     ) -> (Self, CancellationToken) {
         let cancel_token = CancellationToken::new();
         let mut clients = HashMap::new();
         let mut server_metadata = HashMap::new();
         let mut join_set = JoinSet::new();
+        let roots = watch::Sender::new(mcp_roots(&workspace_roots(
+            &runtime_environment.fallback_cwd(),
+            &initial_permission_profile,
+        )));
         let elicitation_requests = ElicitationRequestManager::new(
             approval_policy.value(),
             initial_permission_profile,
@@ -243,6 +282,8 @@ impl McpConnectionManager {
                 cancel_token.clone(),
                 tx_event.clone(),
                 elicitation_requests.clone(),
+                sampling_handler.clone(),
+                roots.subscribe(),
                 codex_apps_tools_cache_context,
                 Arc::clone(&tool_plugin_provenance),
                 runtime_environment.clone(),
@@ -253,6 +294,7 @@ impl McpConnectionManager {
             let tx_event = tx_event.clone();
             let submit_id = startup_submit_id.clone();
             let auth_entry = auth_entries.get(&server_name).cloned();
+            let roots_changed = roots.subscribe();
             join_set.spawn(async move {
                 let mut outcome = async_managed_client.client().await;
                 if cancel_token.is_cancelled() {
@@ -281,6 +323,17 @@ impl McpConnectionManager {
                 )
                 .await;
 
+                if let Ok(managed_client) = &outcome {
+                    tokio::spawn(forward_list_changes(
+                        server_name.clone(),
+                        managed_client.clone(),
+                        roots_changed,
+                        submit_id,
+                        tx_event,
+                        cancel_token,
+                    ));
+                }
+
                 (server_name, outcome)
             });
         }
@@ -289,6 +342,8 @@ impl McpConnectionManager {
             server_metadata,
             host_owned_codex_apps_enabled,
             elicitation_requests: elicitation_requests.clone(),
+            sampling_handler,
+            roots,
             startup_cancellation_token: cancel_token.clone(),
         };
         tokio::spawn(async move {
@@ -563,6 +618,98 @@ impl McpConnectionManager {
         aggregated
     }
 
+    /// Returns a single map that contains all prompts. Each key is the
+    /// server name and the value is a vector of prompts. Servers that do not
+    /// advertise the prompts capability are skipped.
+    pub async fn list_all_prompts(&self) -> HashMap<String, Vec<Prompt>> {
+        let mut join_set = JoinSet::new();
+
+        for (server_name, async_managed_client) in &self.clients {
+            let server_name = server_name.clone();
+            let Ok(managed_client) = async_managed_client.client().await else {
+                continue;
+            };
+            if !managed_client.server_supports_prompts {
+                continue;
+            }
+            let timeout = managed_client.tool_timeout;
+            let client = managed_client.client.clone();
+
+            join_set.spawn(async move {
+                let mut collected: Vec<Prompt> = Vec::new();
+                let mut cursor: Option<String> = None;
+
+                loop {
+                    let params = cursor.as_ref().map(|next| PaginatedRequestParams {
+                        meta: None,
+                        cursor: Some(next.clone()),
+                    });
+                    let response = match client.list_prompts(params, timeout).await {
+                        Ok(result) => result,
+                        Err(err) => return (server_name, Err(err)),
+                    };
+
+                    collected.extend(response.prompts);
+
+                    match response.next_cursor {
+                        Some(next) => {
+                            if cursor.as_ref() == Some(&next) {
+                                return (
+                                    server_name,
+                                    Err(anyhow!("prompts/list returned duplicate cursor")),
+                                );
+                            }
+                            cursor = Some(next);
+                        }
+                        None => return (server_name, Ok(collected)),
+                    }
+                }
+            });
+        }
+
+        let mut aggregated: HashMap<String, Vec<Prompt>> = HashMap::new();
+
+        while let Some(join_res) = join_set.join_next().await {
+            match join_res {
+                Ok((server_name, Ok(prompts))) => {
+                    aggregated.insert(server_name, prompts);
+                }
+                Ok((server_name, Err(err))) => {
+                    warn!("Failed to list prompts for MCP server '{server_name}': {err:#}");
+                }
+                Err(err) => {
+                    warn!("Task panic when listing prompts for MCP server: {err:#}");
+                }
+            }
+        }
+
+        aggregated
+    }
+
+    /// Render the named prompt on the specified server.
+    pub async fn get_prompt(
+        &self,
+        server: &str,
+        name: &str,
+        arguments: Option<JsonObject>,
+    ) -> Result<GetPromptResult> {
+        let managed = self.client_by_name(server).await?;
+        let client = managed.client.clone();
+        let timeout = managed.tool_timeout;
+
+        client
+            .get_prompt(
+                GetPromptRequestParams {
+                    meta: None,
+                    name: name.to_string(),
+                    arguments,
+                },
+                timeout,
+            )
+            .await
+            .with_context(|| format!("prompts/get failed for `{server}/{name}`"))
+    }
+
     /// Invoke the tool indicated by the (server, tool) pair.
     pub async fn call_tool(
         &self,
@@ -697,6 +844,76 @@ async fn emit_update(
         .await
 }
 
+/// Forward `notifications/*/list_changed` from `server_name` as events until
+/// startup is cancelled or the manager shuts down.
+///
+/// Tool list changes re-fetch `tools/list` first so the refreshed tools are
+/// what the next turn's tool router and `tool_search` index see.
+async fn forward_list_changes(
+    server_name: String,
+    managed_client: ManagedClient,
+    mut roots_changed: watch::Receiver<Vec<Root>>,
+    submit_id: String,
+    tx_event: Sender<Event>,
+    cancel_token: CancellationToken,
+) {
+    let mut tool_list_changed = managed_client.client.subscribe_tool_list_changed();
+    let mut resource_list_changed = managed_client.client.subscribe_resource_list_changed();
+    let mut prompt_list_changed = managed_client.client.subscribe_prompt_list_changed();
+    loop {
+        let msg = tokio::select! {
+            _ = cancel_token.cancelled() => return,
+            changed = tool_list_changed.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                match managed_client.refresh_tools(&server_name).await {
+                    Ok(tools) => EventMsg::McpToolListChanged(McpToolListChangedEvent {
+                        server: server_name.clone(),
+                        tools: tools.into_iter().map(|tool| tool.tool.name.to_string()).collect(),
+                    }),
+                    Err(err) => {
+                        warn!("Failed to refresh tools for MCP server '{server_name}': {err:#}");
+                        continue;
+                    }
+                }
+            }
+            changed = resource_list_changed.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                EventMsg::McpResourceListChanged(McpResourceListChangedEvent {
+                    server: server_name.clone(),
+                })
+            }
+            changed = prompt_list_changed.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                EventMsg::McpPromptListChanged(McpPromptListChangedEvent {
+                    server: server_name.clone(),
+                })
+            }
+            changed = roots_changed.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                if let Err(err) = managed_client.client.notify_roots_list_changed().await {
+                    warn!("Failed to notify MCP server '{server_name}' of roots change: {err:#}");
+                }
+                continue;
+            }
+        };
+        let event = Event {
+            id: submit_id.clone(),
+            msg,
+        };
+        if tx_event.send(event).await.is_err() {
+            return;
+        }
+    }
+}
+
 fn mcp_init_error_display(
     server_name: &str,
     entry: Option<&McpAuthStatusEntry>,
@@ -5,6 +5,9 @@ pub use elicitation::ElicitationReviewerHandle;
 pub use rmcp_client::MCP_SANDBOX_STATE_META_CAPABILITY;
 pub use runtime::McpRuntimeEnvironment;
 pub use runtime::SandboxState;
+pub use sampling::SamplingHandler;
+pub use sampling::SamplingHandlerHandle;
+pub use sampling::SamplingRequest;
 pub use tools::ToolInfo;
 
 pub use mcp::CODEX_APPS_MCP_SERVER_NAME;
@@ -38,6 +41,7 @@ pub use mcp::with_codex_apps_mcp;
 pub use mcp::McpServerStatusSnapshot;
 pub use mcp::McpSnapshotDetail;
 pub use mcp::collect_mcp_server_status_snapshot_with_detail;
+pub use mcp::convert_mcp_prompts;
 pub use mcp::read_mcp_resource;
 
 pub use mcp::McpAuthStatusEntry;
@@ -63,6 +67,8 @@ pub(crate) mod connection_manager;
 pub(crate) mod elicitation;
 pub(crate) mod mcp;
 pub(crate) mod rmcp_client;
+pub(crate) mod roots;
 pub(crate) mod runtime;
+pub(crate) mod sampling;
 pub(crate) mod server;
 pub(crate) mod tools;
@@ -25,6 +25,7 @@ use codex_config::types::ApprovalsReviewer;
 use codex_config::types::OAuthCredentialsStoreMode;
 use codex_login::CodexAuth;
 use codex_plugin::PluginCapabilitySummary;
+use codex_protocol::mcp::Prompt;
 use codex_protocol::mcp::Resource;
 use codex_protocol::mcp::ResourceTemplate;
 use codex_protocol::mcp::Tool;
@@ -56,6 +57,10 @@ impl McpSnapshotDetail {
     fn include_resources(self) -> bool {
         matches!(self, Self::Full)
     }
+
+    fn include_prompts(self) -> bool {
+        matches!(self, Self::Full)
+    }
 }
 
 pub fn qualified_mcp_tool_name_prefix(server_name: &str) -> String {
@@ -284,6 +289,7 @@ pub async fn read_mcp_resource(
         tool_plugin_provenance(config),
         auth,
         /*elicitation_reviewer*/ None,
+        /*sampling_handler*/ None,
     )
     .await;
 
@@ -305,6 +311,7 @@ pub struct McpServerStatusSnapshot {
     pub tools_by_server: HashMap<String, HashMap<String, Tool>>,
     pub resources: HashMap<String, Vec<Resource>>,
     pub resource_templates: HashMap<String, Vec<ResourceTemplate>>,
+    pub prompts: HashMap<String, Vec<Prompt>>,
     pub auth_statuses: HashMap<String, McpAuthStatus>,
 }
 
@@ -323,6 +330,7 @@ pub async fn collect_mcp_server_status_snapshot_with_detail(
             tools_by_server: HashMap::new(),
             resources: HashMap::new(),
             resource_templates: HashMap::new(),
+            prompts: HashMap::new(),
             auth_statuses: HashMap::new(),
         };
     }
@@ -352,6 +360,7 @@ pub async fn collect_mcp_server_status_snapshot_with_detail(
         tool_plugin_provenance,
         auth,
         /*elicitation_reviewer*/ None,
+        /*sampling_handler*/ None,
     )
     .await;
 
@@ -559,12 +568,40 @@ fn convert_mcp_resource_templates(
         .collect::<HashMap<_, _>>()
 }
 
+/// Convert rmcp prompts keyed by server name into protocol prompts, dropping
+/// (and logging) any that fail to convert.
+pub fn convert_mcp_prompts(
+    prompts: HashMap<String, Vec<rmcp::model::Prompt>>,
+) -> HashMap<String, Vec<Prompt>> {
+    prompts
+        .into_iter()
+        .map(|(name, prompts)| {
+            let prompts = prompts
+                .into_iter()
+                .filter_map(|prompt| {
+                    let prompt_name = prompt.name.clone();
+                    match serde_json::to_value(prompt).and_then(Prompt::from_mcp_value) {
+                        Ok(prompt) => Some(prompt),
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed to convert MCP prompt (name={prompt_name:?}): {err}"
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            (name, prompts)
+        })
+        .collect::<HashMap<_, _>>()
+}
+
 async fn collect_mcp_server_status_snapshot_from_manager(
     mcp_connection_manager: &McpConnectionManager,
     auth_status_entries: HashMap<String, crate::mcp::auth::McpAuthStatusEntry>,
     detail: McpSnapshotDetail,
 ) -> McpServerStatusSnapshot {
-    let (tools, resources, resource_templates) = tokio::join!(
+    let (tools, resources, resource_templates, prompts) = tokio::join!(
         mcp_connection_manager.list_all_tools(),
         async {
             if detail.include_resources() {
@@ -580,6 +617,13 @@ async fn collect_mcp_server_status_snapshot_from_manager(
                 HashMap::new()
             }
         },
+        async {
+            if detail.include_prompts() {
+                mcp_connection_manager.list_all_prompts().await
+            } else {
+                HashMap::new()
+            }
+        },
     );
 
     let mut tools_by_server = HashMap::<String, HashMap<String, Tool>>::new();
@@ -599,6 +643,7 @@ async fn collect_mcp_server_status_snapshot_from_manager(
         tools_by_server,
         resources: convert_mcp_resources(resources),
         resource_templates: convert_mcp_resource_templates(resource_templates),
+        prompts: convert_mcp_prompts(prompts),
         auth_statuses: auth_statuses_from_entries(&auth_status_entries),
     }
 }
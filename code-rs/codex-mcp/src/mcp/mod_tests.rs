@@ -163,6 +163,40 @@ fn tool_plugin_provenance_collects_app_and_mcp_sources() {
     );
 }
 
+#[test]
+fn convert_mcp_prompts_keeps_prompt_arguments() {
+    let prompt = rmcp::model::Prompt::new(
+        "summarize",
+        Some("Summarize a topic."),
+        Some(vec![rmcp::model::PromptArgument {
+            name: "topic".to_string(),
+            title: None,
+            description: Some("What to summarize.".to_string()),
+            required: Some(true),
+        }]),
+    );
+
+    let converted = convert_mcp_prompts(HashMap::from([("docs".to_string(), vec![prompt])]));
+
+    assert_eq!(
+        converted,
+        HashMap::from([(
+            "docs".to_string(),
+            vec![Prompt {
+                name: "summarize".to_string(),
+                title: None,
+                description: Some("Summarize a topic.".to_string()),
+                arguments: Some(vec![codex_protocol::mcp::PromptArgument {
+                    name: "topic".to_string(),
+                    title: None,
+                    description: Some("What to summarize.".to_string()),
+                    required: Some(true),
+                }]),
+            }],
+        )])
+    );
+}
+
 #[test]
 fn codex_apps_mcp_url_for_base_url_keeps_existing_paths() {
     assert_eq!(
@@ -12,6 +12,8 @@ use std::env;
 use std::ffi::OsString;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
@@ -32,6 +34,8 @@ use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
 use crate::mcp::ToolPluginProvenance;
 use crate::runtime::McpRuntimeEnvironment;
 use crate::runtime::emit_duration;
+use crate::sampling::SamplingHandlerHandle;
+use crate::sampling::make_sampling_sender;
 use crate::server::EffectiveMcpServer;
 use crate::server::McpServerLaunch;
 use crate::tools::ToolFilter;
@@ -54,6 +58,7 @@ use codex_rmcp_client::ExecutorStdioServerLauncher;
 use codex_rmcp_client::InProcessTransportFactory;
 use codex_rmcp_client::LocalStdioServerLauncher;
 use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::ServerRequestHandlers;
 use codex_rmcp_client::StdioServerLauncher;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
@@ -63,7 +68,10 @@ use rmcp::model::ElicitationCapability;
 use rmcp::model::Implementation;
 use rmcp::model::InitializeRequestParams;
 use rmcp::model::ProtocolVersion;
+use rmcp::model::Root;
+use rmcp::model::RootsCapabilities;
 use rmcp::model::Tool as RmcpTool;
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 use tracing::warn;
 
@@ -88,11 +96,14 @@ const UNTRUSTED_CONNECTOR_META_KEYS: &[&str] = &[
 #[derive(Clone)]
 pub(crate) struct ManagedClient {
     pub(crate) client: Arc<RmcpClient>,
-    pub(crate) tools: Vec<ToolInfo>,
+    /// Filtered tools from the latest `tools/list`, shared across clones so a
+    /// refresh after `notifications/tools/list_changed` is seen by every caller.
+    pub(crate) tools: Arc<RwLock<Vec<ToolInfo>>>,
     pub(crate) tool_filter: ToolFilter,
     pub(crate) tool_timeout: Option<Duration>,
     pub(crate) server_instructions: Option<String>,
     pub(crate) server_supports_sandbox_state_meta_capability: bool,
+    pub(crate) server_supports_prompts: bool,
     pub(crate) codex_apps_tools_cache_context: Option<CodexAppsToolsCacheContext>,
 }
 
@@ -119,7 +130,36 @@ impl ManagedClient {
             );
         }
 
-        self.tools.clone()
+        self.tools
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Re-run `tools/list` against the server and replace the stored tools
+    /// with the filtered result.
+    pub(crate) async fn refresh_tools(&self, server_name: &str) -> Result<Vec<ToolInfo>> {
+        let fetch_start = Instant::now();
+        let tools = list_tools_for_client_uncached(
+            server_name,
+            &self.client,
+            self.tool_timeout,
+            self.server_instructions.as_deref(),
+        )
+        .await?;
+        emit_duration(
+            MCP_TOOLS_FETCH_UNCACHED_DURATION_METRIC,
+            fetch_start.elapsed(),
+            &[],
+        );
+        write_cached_codex_apps_tools_if_needed(
+            server_name,
+            self.codex_apps_tools_cache_context.as_ref(),
+            &tools,
+        );
+        let tools = filter_tools(tools, &self.tool_filter);
+        *self.tools.write().unwrap_or_else(PoisonError::into_inner) = tools.clone();
+        Ok(tools)
     }
 }
 
@@ -143,6 +183,8 @@ impl AsyncManagedClient {
         cancel_token: CancellationToken,
         tx_event: Sender<Event>,
         elicitation_requests: ElicitationRequestManager,
+        sampling_handler: Option<SamplingHandlerHandle>,
+        roots: watch::Receiver<Vec<Root>>,
         codex_apps_tools_cache_context: Option<CodexAppsToolsCacheContext>,
         tool_plugin_provenance: Arc<ToolPluginProvenance>,
         runtime_environment: McpRuntimeEnvironment,
@@ -194,6 +236,8 @@ impl AsyncManagedClient {
                         tool_filter: startup_tool_filter,
                         tx_event,
                         elicitation_requests,
+                        sampling_handler,
+                        roots,
                         codex_apps_tools_cache_context,
                     },
                 )
@@ -476,6 +520,8 @@ async fn start_server_task(
         tool_filter,
         tx_event,
         elicitation_requests,
+        sampling_handler,
+        roots,
         codex_apps_tools_cache_context,
     } = params;
     let elicitation = elicitation_capability_for_server(&server_name);
@@ -484,8 +530,11 @@ async fn start_server_task(
         capabilities: ClientCapabilities {
             experimental: None,
             extensions: None,
-            roots: None,
-            sampling: None,
+            roots: Some(RootsCapabilities {
+                list_changed: Some(true),
+            }),
+            // Only advertise sampling when the host can actually run the model.
+            sampling: sampling_handler.as_ref().map(|_| Default::default()),
             elicitation,
             tasks: None,
         },
@@ -501,9 +550,14 @@ async fn start_server_task(
     };
 
     let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event);
+    let handlers = ServerRequestHandlers {
+        send_sampling: sampling_handler
+            .map(|handler| make_sampling_sender(handler, server_name.clone())),
+        roots: Some(roots),
+    };
 
     let initialize_result = client
-        .initialize(params, startup_timeout, send_elicitation)
+        .initialize_with_handlers(params, startup_timeout, send_elicitation, handlers)
         .await
         .map_err(StartupOutcomeError::from)?;
 
@@ -513,6 +567,7 @@ async fn start_server_task(
         .as_ref()
         .and_then(|exp| exp.get(MCP_SANDBOX_STATE_META_CAPABILITY))
         .is_some();
+    let server_supports_prompts = initialize_result.capabilities.prompts.is_some();
     let list_start = Instant::now();
     let fetch_start = Instant::now();
     let tools = list_tools_for_client_uncached(
@@ -544,11 +599,12 @@ async fn start_server_task(
 
     let managed = ManagedClient {
         client: Arc::clone(&client),
-        tools,
+        tools: Arc::new(RwLock::new(tools)),
         tool_timeout: Some(tool_timeout),
         tool_filter,
         server_instructions: initialize_result.instructions,
         server_supports_sandbox_state_meta_capability,
+        server_supports_prompts,
         codex_apps_tools_cache_context,
     };
 
@@ -561,6 +617,8 @@ struct StartServerTaskParams {
     tool_filter: ToolFilter,
     tx_event: Sender<Event>,
     elicitation_requests: ElicitationRequestManager,
+    sampling_handler: Option<SamplingHandlerHandle>,
+    roots: watch::Receiver<Vec<Root>>,
     codex_apps_tools_cache_context: Option<CodexAppsToolsCacheContext>,
 }
 
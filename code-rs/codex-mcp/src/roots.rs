@@ -0,0 +1,108 @@
+//! Workspace roots reported to MCP servers through `roots/list`.
+//!
+//! The roots are the session cwd followed by the writable roots of the active
+//! permission profile. The connection manager publishes them on a watch
+//! channel shared by every client, and each client sends
+//! `notifications/roots/list_changed` when the published value changes.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::models::PermissionProfile;
+use rmcp::model::Root;
+use url::Url;
+
+/// Returns the cwd plus any writable roots, without duplicates.
+pub(crate) fn workspace_roots(cwd: &Path, permission_profile: &PermissionProfile) -> Vec<PathBuf> {
+    let mut roots = vec![cwd.to_path_buf()];
+    for writable_root in permission_profile
+        .file_system_sandbox_policy()
+        .get_writable_roots_with_cwd(cwd)
+    {
+        let root = writable_root.root.to_path_buf();
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+    }
+    roots
+}
+
+pub(crate) fn mcp_roots(roots: &[PathBuf]) -> Vec<Root> {
+    roots
+        .iter()
+        .filter_map(|path| {
+            let uri = Url::from_directory_path(path).ok()?;
+            Some(Root {
+                uri: uri.to_string(),
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::protocol::SandboxPolicy;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn workspace_roots_is_cwd_only_with_full_access() {
+        let cwd = TempDir::new().expect("tempdir");
+
+        assert_eq!(
+            workspace_roots(cwd.path(), &PermissionProfile::Disabled),
+            vec![cwd.path().to_path_buf()]
+        );
+    }
+
+    #[test]
+    fn workspace_roots_appends_writable_roots_after_cwd() {
+        let cwd = TempDir::new().expect("tempdir");
+        let extra = TempDir::new().expect("tempdir");
+        let permission_profile = PermissionProfile::from_legacy_sandbox_policy_for_cwd(
+            &SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![extra.path().try_into().expect("absolute path")],
+                network_access: false,
+                exclude_tmpdir_env_var: true,
+                exclude_slash_tmp: true,
+            },
+            cwd.path(),
+        );
+
+        let roots = workspace_roots(cwd.path(), &permission_profile);
+
+        assert_eq!(roots.first(), Some(&cwd.path().to_path_buf()));
+        assert_eq!(
+            roots
+                .iter()
+                .filter(|root| root.as_path() == extra.path())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn mcp_roots_use_file_uris_named_after_the_directory() {
+        let cwd = TempDir::new().expect("tempdir");
+        let name = cwd
+            .path()
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+
+        let roots = mcp_roots(&[cwd.path().to_path_buf()]);
+
+        assert_eq!(roots.len(), 1);
+        assert_eq!(
+            roots[0].uri,
+            Url::from_directory_path(cwd.path())
+                .expect("file url")
+                .to_string()
+        );
+        assert_eq!(roots[0].name, name);
+    }
+}
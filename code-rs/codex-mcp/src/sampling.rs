@@ -0,0 +1,44 @@
+//! MCP sampling request routing.
+//!
+//! Servers can ask Codex to run an LLM completion with
+//! `sampling/createMessage`. The connection manager only advertises the
+//! sampling capability when the host supplies a [`SamplingHandler`], which is
+//! responsible for approval, running the model, and accounting for its usage.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use codex_rmcp_client::SendSampling;
+use futures::future::BoxFuture;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
+use rmcp::model::RequestId;
+
+#[derive(Debug, Clone)]
+pub struct SamplingRequest {
+    pub server_name: String,
+    pub request_id: RequestId,
+    pub params: CreateMessageRequestParams,
+}
+
+pub trait SamplingHandler: Send + Sync {
+    fn create_message(
+        &self,
+        request: SamplingRequest,
+    ) -> BoxFuture<'static, Result<CreateMessageResult>>;
+}
+
+pub type SamplingHandlerHandle = Arc<dyn SamplingHandler>;
+
+pub(crate) fn make_sampling_sender(
+    handler: SamplingHandlerHandle,
+    server_name: String,
+) -> SendSampling {
+    Box::new(move |request_id, params| {
+        handler.create_message(SamplingRequest {
+            server_name: server_name.clone(),
+            request_id,
+            params,
+        })
+    })
+}
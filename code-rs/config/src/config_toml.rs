@@ -20,6 +20,7 @@ use crate::types::Notice;
 use crate::types::OAuthCredentialsStoreMode;
 use crate::types::OtelConfigToml;
 use crate::types::PluginConfig;
+use crate::types::RedactionToml;
 use crate::types::SandboxWorkspaceWrite;
 use crate::types::ShellEnvironmentPolicyToml;
 use crate::types::SkillsConfig;
@@ -431,6 +432,10 @@ pub struct ConfigToml {
     /// OTEL configuration.
     pub otel: Option<OtelConfigToml>,
 
+    /// Redaction applied to tool output, hook output, rollouts and feedback
+    /// uploads.
+    pub redaction: Option<RedactionToml>,
+
     /// Windows-specific configuration.
     #[serde(default)]
     pub windows: Option<WindowsToml>,
@@ -489,6 +494,9 @@ pub struct DebugConfigLockToml {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ThreadStoreToml {
     Local {},
+    /// Store thread metadata, turns, and items in a dedicated SQLite database
+    /// instead of rollout files.
+    Sqlite {},
     #[schemars(skip)]
     InMemory {
         id: String,
@@ -47,6 +47,14 @@ pub struct HookEventsToml {
     pub user_prompt_submit: Vec<MatcherGroup>,
     #[serde(rename = "Stop", default)]
     pub stop: Vec<MatcherGroup>,
+    #[serde(rename = "SessionEnd", default)]
+    pub session_end: Vec<MatcherGroup>,
+    #[serde(rename = "SubagentStart", default)]
+    pub subagent_start: Vec<MatcherGroup>,
+    #[serde(rename = "SubagentStop", default)]
+    pub subagent_stop: Vec<MatcherGroup>,
+    #[serde(rename = "Notification", default)]
+    pub notification: Vec<MatcherGroup>,
 }
 
 impl HookEventsToml {
@@ -60,6 +68,10 @@ impl HookEventsToml {
             session_start,
             user_prompt_submit,
             stop,
+            session_end,
+            subagent_start,
+            subagent_stop,
+            notification,
         } = self;
         pre_tool_use.is_empty()
             && permission_request.is_empty()
@@ -69,6 +81,10 @@ impl HookEventsToml {
             && session_start.is_empty()
             && user_prompt_submit.is_empty()
             && stop.is_empty()
+            && session_end.is_empty()
+            && subagent_start.is_empty()
+            && subagent_stop.is_empty()
+            && notification.is_empty()
     }
 
     pub fn handler_count(&self) -> usize {
@@ -81,6 +97,10 @@ impl HookEventsToml {
             session_start,
             user_prompt_submit,
             stop,
+            session_end,
+            subagent_start,
+            subagent_stop,
+            notification,
         } = self;
         [
             pre_tool_use,
@@ -91,6 +111,10 @@ impl HookEventsToml {
             session_start,
             user_prompt_submit,
             stop,
+            session_end,
+            subagent_start,
+            subagent_stop,
+            notification,
         ]
         .into_iter()
         .flatten()
@@ -98,7 +122,7 @@ impl HookEventsToml {
         .sum()
     }
 
-    pub fn into_matcher_groups(self) -> [(HookEventName, Vec<MatcherGroup>); 8] {
+    pub fn into_matcher_groups(self) -> [(HookEventName, Vec<MatcherGroup>); 12] {
         [
             (HookEventName::PreToolUse, self.pre_tool_use),
             (HookEventName::PermissionRequest, self.permission_request),
@@ -108,6 +132,10 @@ impl HookEventsToml {
             (HookEventName::SessionStart, self.session_start),
             (HookEventName::UserPromptSubmit, self.user_prompt_submit),
             (HookEventName::Stop, self.stop),
+            (HookEventName::SessionEnd, self.session_end),
+            (HookEventName::SubagentStart, self.subagent_start),
+            (HookEventName::SubagentStop, self.subagent_stop),
+            (HookEventName::Notification, self.notification),
         ]
     }
 }
@@ -134,9 +162,38 @@ pub enum HookHandlerConfig {
         status_message: Option<String>,
     },
     #[serde(rename = "prompt")]
-    Prompt {},
+    Prompt {
+        prompt: String,
+        #[serde(default)]
+        model: Option<String>,
+        #[serde(default, rename = "timeout")]
+        timeout_sec: Option<u64>,
+        #[serde(default, rename = "statusMessage")]
+        status_message: Option<String>,
+    },
     #[serde(rename = "agent")]
-    Agent {},
+    Agent {
+        prompt: String,
+        #[serde(default)]
+        model: Option<String>,
+        #[serde(default, rename = "timeout")]
+        timeout_sec: Option<u64>,
+        #[serde(default, rename = "statusMessage")]
+        status_message: Option<String>,
+    },
+    #[serde(rename = "http")]
+    Http {
+        url: String,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+        #[serde(default, rename = "timeout")]
+        timeout_sec: Option<u64>,
+        /// Extra attempts after a timeout, connection error, 429, or 5xx.
+        #[serde(default)]
+        retries: Option<u32>,
+        #[serde(default, rename = "statusMessage")]
+        status_message: Option<String>,
+    },
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
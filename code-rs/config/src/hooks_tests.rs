@@ -84,6 +84,107 @@ statusMessage = "checking"
     );
 }
 
+#[test]
+fn prompt_hook_deserializes_from_toml() {
+    let parsed: HookEventsToml = toml::from_str(
+        r#"
+[[Stop]]
+
+[[Stop.hooks]]
+type = "prompt"
+prompt = "Block the turn if tests were not run. $ARGUMENTS"
+model = "gpt-5.1-codex-mini"
+timeout = 30
+"#,
+    )
+    .expect("prompt hook TOML should deserialize");
+
+    assert_eq!(
+        parsed,
+        HookEventsToml {
+            stop: vec![MatcherGroup {
+                matcher: None,
+                hooks: vec![HookHandlerConfig::Prompt {
+                    prompt: "Block the turn if tests were not run. $ARGUMENTS".to_string(),
+                    model: Some("gpt-5.1-codex-mini".to_string()),
+                    timeout_sec: Some(30),
+                    status_message: None,
+                }],
+            }],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn agent_hook_deserializes_from_toml() {
+    let parsed: HookEventsToml = toml::from_str(
+        r#"
+[[Stop]]
+
+[[Stop.hooks]]
+type = "agent"
+prompt = "Verify that the test suite was run after the last edit."
+statusMessage = "Verifying tests"
+"#,
+    )
+    .expect("agent hook TOML should deserialize");
+
+    assert_eq!(
+        parsed,
+        HookEventsToml {
+            stop: vec![MatcherGroup {
+                matcher: None,
+                hooks: vec![HookHandlerConfig::Agent {
+                    prompt: "Verify that the test suite was run after the last edit.".to_string(),
+                    model: None,
+                    timeout_sec: None,
+                    status_message: Some("Verifying tests".to_string()),
+                }],
+            }],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn http_hook_deserializes_from_toml() {
+    let parsed: HookEventsToml = toml::from_str(
+        r#"
+[[PermissionRequest]]
+matcher = "^Bash$"
+
+[[PermissionRequest.hooks]]
+type = "http"
+url = "https://policy.example.com/hooks"
+headers = { Authorization = "Bearer token" }
+timeout = 5
+retries = 2
+"#,
+    )
+    .expect("http hook TOML should deserialize");
+
+    assert_eq!(
+        parsed,
+        HookEventsToml {
+            permission_request: vec![MatcherGroup {
+                matcher: Some("^Bash$".to_string()),
+                hooks: vec![HookHandlerConfig::Http {
+                    url: "https://policy.example.com/hooks".to_string(),
+                    headers: BTreeMap::from([(
+                        "Authorization".to_string(),
+                        "Bearer token".to_string(),
+                    )]),
+                    timeout_sec: Some(5),
+                    retries: Some(2),
+                    status_message: None,
+                }],
+            }],
+            ..Default::default()
+        }
+    );
+}
+
 #[test]
 fn hooks_toml_deserializes_inline_events_and_state_map() {
     let parsed: HooksToml = toml::from_str(
@@ -1,11 +1,15 @@
 use std::collections::BTreeMap;
 
+use codex_network_proxy::NetworkCredential;
 use codex_network_proxy::NetworkDomainPermission as ProxyNetworkDomainPermission;
+use codex_network_proxy::NetworkHttpRule;
+use codex_network_proxy::NetworkHttpRuleDecision;
 use codex_network_proxy::NetworkMode;
 use codex_network_proxy::NetworkProxyConfig;
 use codex_network_proxy::NetworkUnixSocketPermission as ProxyNetworkUnixSocketPermission;
 use codex_network_proxy::normalize_host;
 use codex_protocol::permissions::FileSystemAccessMode;
+use codex_protocol::permissions::ResourceLimits;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -27,6 +31,7 @@ impl PermissionsToml {
 pub struct PermissionProfileToml {
     pub filesystem: Option<FilesystemPermissionsToml>,
     pub network: Option<NetworkToml>,
+    pub resources: Option<ResourceLimitsToml>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
@@ -52,6 +57,43 @@ pub enum FilesystemPermissionToml {
     Scoped(BTreeMap<String, FileSystemAccessMode>),
 }
 
+/// Resource limits applied to commands run under a permission profile.
+///
+/// These are currently enforced only by the Linux sandbox helper.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ResourceLimitsToml {
+    /// Maximum memory for the command and its children, in MiB.
+    #[schemars(range(min = 1))]
+    pub max_memory_mb: Option<u64>,
+    /// Maximum CPU time per process, in seconds.
+    #[schemars(range(min = 1))]
+    pub max_cpu_seconds: Option<u64>,
+    /// Maximum number of processes the command may have running at once. Only enforced where a
+    /// cgroup v2 scope with the `pids` controller can be created.
+    #[schemars(range(min = 1))]
+    pub max_processes: Option<u64>,
+    /// Maximum number of open file descriptors per process.
+    #[schemars(range(min = 1))]
+    pub max_open_files: Option<u64>,
+    /// Maximum size of any file written by the command, in MiB.
+    #[schemars(range(min = 1))]
+    pub max_file_size_mb: Option<u64>,
+}
+
+impl ResourceLimitsToml {
+    pub fn to_resource_limits(&self) -> ResourceLimits {
+        const MIB: u64 = 1024 * 1024;
+        ResourceLimits {
+            max_memory_bytes: self.max_memory_mb.map(|mb| mb.saturating_mul(MIB)),
+            max_cpu_seconds: self.max_cpu_seconds,
+            max_processes: self.max_processes,
+            max_open_files: self.max_open_files,
+            max_file_size_bytes: self.max_file_size_mb.map(|mb| mb.saturating_mul(MIB)),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
 pub struct NetworkDomainPermissionsToml {
     #[serde(flatten)]
@@ -103,6 +145,63 @@ impl std::fmt::Display for NetworkDomainPermissionToml {
     }
 }
 
+/// A method- and path-level rule for HTTP requests. Rules are checked in order and the first
+/// one matching host, method and path decides; HTTPS requests are only visible to the rules when
+/// `mitm` is enabled.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct NetworkHttpRuleToml {
+    /// Host pattern, using the same syntax as the `domains` table.
+    pub host: String,
+    /// HTTP methods the rule applies to. Omit to match every method.
+    pub methods: Option<Vec<String>>,
+    /// Glob matched against the request path, e.g. `/repos/our-org/**`. Omit to match every path.
+    pub path: Option<String>,
+    pub decision: NetworkDomainPermissionToml,
+}
+
+impl NetworkHttpRuleToml {
+    pub fn to_network_http_rule(&self) -> NetworkHttpRule {
+        NetworkHttpRule {
+            host: self.host.clone(),
+            methods: self.methods.clone().unwrap_or_default(),
+            path: self.path.clone(),
+            decision: match self.decision {
+                NetworkDomainPermissionToml::Allow => NetworkHttpRuleDecision::Allow,
+                NetworkDomainPermissionToml::Deny => NetworkHttpRuleDecision::Deny,
+            },
+        }
+    }
+}
+
+const AUTHORIZATION_HEADER: &str = "authorization";
+
+/// A credential the proxy adds to HTTPS requests for `host`, replacing whatever the client sent.
+/// Requires `mitm`; plain HTTP requests to the host have their credentials stripped instead.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct NetworkCredentialToml {
+    /// Host pattern, using the same syntax as the `domains` table.
+    pub host: String,
+    /// Header the credential is sent in. Defaults to `authorization`.
+    pub header: Option<String>,
+    /// Header value. `$secret:NAME` references are resolved from the secrets store per request.
+    pub value: String,
+}
+
+impl NetworkCredentialToml {
+    pub fn to_network_credential(&self) -> NetworkCredential {
+        NetworkCredential {
+            host: self.host.clone(),
+            header: self
+                .header
+                .clone()
+                .unwrap_or_else(|| AUTHORIZATION_HEADER.to_string()),
+            value: self.value.clone(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
 pub struct NetworkUnixSocketPermissionsToml {
     #[serde(flatten)]
@@ -156,8 +255,15 @@ pub struct NetworkToml {
     #[schemars(with = "Option<NetworkModeSchema>")]
     pub mode: Option<NetworkMode>,
     pub domains: Option<NetworkDomainPermissionsToml>,
+    pub http_rules: Option<Vec<NetworkHttpRuleToml>>,
+    pub credentials: Option<Vec<NetworkCredentialToml>>,
     pub unix_sockets: Option<NetworkUnixSocketPermissionsToml>,
     pub allow_local_binding: Option<bool>,
+    /// Terminate HTTPS CONNECT tunnels so limited mode and `http_rules` apply to the requests
+    /// inside them.
+    pub mitm: Option<bool>,
+    /// Record every request the proxy sees under `$CODEX_HOME/log/network`. Defaults to false.
+    pub traffic_log: Option<bool>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
@@ -202,6 +308,24 @@ impl NetworkToml {
         if let Some(domains) = self.domains.as_ref() {
             overlay_network_domain_permissions(config, domains);
         }
+        if let Some(http_rules) = self.http_rules.as_ref() {
+            prepend_network_http_rules(
+                config,
+                http_rules
+                    .iter()
+                    .map(NetworkHttpRuleToml::to_network_http_rule)
+                    .collect(),
+            );
+        }
+        if let Some(credentials) = self.credentials.as_ref() {
+            // The first credential for a host and header wins, so later layers go first.
+            let mut credentials: Vec<NetworkCredential> = credentials
+                .iter()
+                .map(NetworkCredentialToml::to_network_credential)
+                .collect();
+            credentials.append(&mut config.network.credentials);
+            config.network.credentials = credentials;
+        }
         if let Some(unix_sockets) = self.unix_sockets.as_ref() {
             let mut proxy_unix_sockets = config.network.unix_sockets.take().unwrap_or_default();
             for (path, permission) in &unix_sockets.entries {
@@ -219,6 +343,12 @@ impl NetworkToml {
         if let Some(allow_local_binding) = self.allow_local_binding {
             config.network.allow_local_binding = allow_local_binding;
         }
+        if let Some(mitm) = self.mitm {
+            config.network.mitm = mitm;
+        }
+        if let Some(traffic_log) = self.traffic_log {
+            config.network.traffic_log = traffic_log;
+        }
     }
 
     pub fn to_network_proxy_config(&self) -> NetworkProxyConfig {
@@ -228,6 +358,16 @@ impl NetworkToml {
     }
 }
 
+/// Adds `rules` ahead of the rules already in `config`. Layers are applied lowest precedence
+/// first and the first matching rule wins, so later layers must come first.
+pub fn prepend_network_http_rules(
+    config: &mut NetworkProxyConfig,
+    mut rules: Vec<NetworkHttpRule>,
+) {
+    rules.append(&mut config.network.http_rules);
+    config.network.http_rules = rules;
+}
+
 pub fn overlay_network_domain_permissions(
     config: &mut NetworkProxyConfig,
     domains: &NetworkDomainPermissionsToml,
@@ -171,6 +171,7 @@ impl RequirementsExecPolicyToml {
                     pattern: PrefixPattern {
                         first: Arc::from(head.as_str()),
                         rest: rest.clone(),
+                        excluded_flags: Vec::new().into(),
                     },
                     decision,
                     justification: justification.clone(),
@@ -960,6 +960,96 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
     }
 }
 
+/// Redaction applied to command output, MCP tool results, hook output,
+/// rollouts and feedback uploads.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct RedactionToml {
+    /// When `false`, skips the built-in patterns for API keys, bearer tokens
+    /// and `password=`-style assignments. Defaults to `true`.
+    pub builtin_patterns: Option<bool>,
+
+    /// Additional regular expressions whose matches are redacted.
+    pub patterns: Option<Vec<String>>,
+
+    /// When set, also redacts long tokens that look randomly generated.
+    pub entropy: Option<EntropyRedactionToml>,
+
+    /// Case-insensitive globs naming environment variables whose values are
+    /// redacted verbatim. Defaults to `*KEY*`, `*SECRET*`, `*TOKEN*` and
+    /// `*PASSWORD*`.
+    pub env_vars: Option<Vec<String>>,
+
+    /// When `false`, values stored with `code secrets` are only redacted once
+    /// a command has referenced them. Defaults to `true`.
+    pub stored_secrets: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct EntropyRedactionToml {
+    /// Shortest token considered. Defaults to 20.
+    pub min_length: Option<usize>,
+
+    /// Shannon entropy, in bits per character, above which a token is
+    /// redacted. Defaults to 4.0, which keeps hex digests visible.
+    pub threshold: Option<f64>,
+}
+
+/// Effective redaction settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedactionConfig {
+    pub builtin_patterns: bool,
+    pub patterns: Vec<String>,
+    pub entropy: Option<EntropyRedactionConfig>,
+    pub env_vars: Vec<EnvironmentVariablePattern>,
+    pub stored_secrets: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyRedactionConfig {
+    pub min_length: usize,
+    pub threshold: f64,
+}
+
+const DEFAULT_REDACTED_ENV_VARS: [&str; 4] = ["*KEY*", "*SECRET*", "*TOKEN*", "*PASSWORD*"];
+const DEFAULT_ENTROPY_MIN_LENGTH: usize = 20;
+const DEFAULT_ENTROPY_THRESHOLD: f64 = 4.0;
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        RedactionToml::default().into()
+    }
+}
+
+impl From<RedactionToml> for RedactionConfig {
+    fn from(toml: RedactionToml) -> Self {
+        let env_vars = toml
+            .env_vars
+            .unwrap_or_else(|| {
+                DEFAULT_REDACTED_ENV_VARS
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .into_iter()
+            .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
+            .collect();
+        let entropy = toml.entropy.map(|entropy| EntropyRedactionConfig {
+            min_length: entropy.min_length.unwrap_or(DEFAULT_ENTROPY_MIN_LENGTH),
+            threshold: entropy.threshold.unwrap_or(DEFAULT_ENTROPY_THRESHOLD),
+        });
+
+        Self {
+            builtin_patterns: toml.builtin_patterns.unwrap_or(true),
+            patterns: toml.patterns.unwrap_or_default(),
+            entropy,
+            env_vars,
+            stored_secrets: toml.stored_secrets.unwrap_or(true),
+        }
+    }
+}
+
 #[cfg(test)]
 #[path = "types_tests.rs"]
 mod tests;
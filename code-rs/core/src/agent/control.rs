@@ -5,6 +5,9 @@ use crate::agent::role::DEFAULT_ROLE_NAME;
 use crate::agent::role::resolve_role_config;
 use crate::agent::status::is_final;
 use crate::codex_thread::ThreadConfigSnapshot;
+use crate::hook_runtime::SubagentHookTarget;
+use crate::hook_runtime::run_subagent_start_hooks;
+use crate::hook_runtime::run_subagent_stop_hooks;
 use crate::session::emit_subagent_session_started;
 use crate::session_prefix::format_subagent_context_line;
 use crate::session_prefix::format_subagent_notification_message;
@@ -13,6 +16,7 @@ use crate::thread_manager::ResumeThreadWithHistoryOptions;
 use crate::thread_manager::ThreadManagerState;
 use crate::thread_rollout_truncation::truncate_rollout_to_last_n_fork_turns;
 use codex_features::Feature;
+use codex_hooks::SubagentStopStatus;
 use codex_protocol::AgentPath;
 use codex_protocol::SessionId;
 use codex_protocol::ThreadId;
@@ -71,6 +75,31 @@ pub(crate) struct ListedAgent {
     pub(crate) last_task_message: Option<String>,
 }
 
+fn subagent_hook_target(agent_id: ThreadId, metadata: &AgentMetadata) -> SubagentHookTarget {
+    SubagentHookTarget {
+        agent_id,
+        agent_type: metadata
+            .agent_role
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ROLE_NAME.to_string()),
+        agent_nickname: metadata.agent_nickname.clone(),
+    }
+}
+
+fn subagent_stop_status(status: &AgentStatus) -> Option<SubagentStopStatus> {
+    match status {
+        AgentStatus::Completed(last_assistant_message) => Some(SubagentStopStatus::Completed {
+            last_assistant_message: last_assistant_message.clone(),
+        }),
+        AgentStatus::Errored(error) => Some(SubagentStopStatus::Errored {
+            error: error.clone(),
+        }),
+        AgentStatus::Shutdown => Some(SubagentStopStatus::Shutdown),
+        AgentStatus::NotFound => Some(SubagentStopStatus::NotFound),
+        AgentStatus::PendingInit | AgentStatus::Running | AgentStatus::Interrupted => None,
+    }
+}
+
 fn default_agent_nickname_list() -> Vec<&'static str> {
     AGENT_NAMES
         .lines()
@@ -317,21 +346,36 @@ impl AgentControl {
         )
         .await;
 
+        if let Some(SessionSource::SubAgent(SubAgentSource::ThreadSpawn {
+            parent_thread_id, ..
+        })) = notification_source.as_ref()
+            && let Ok(parent_thread) = state.get_thread(*parent_thread_id).await
+        {
+            let parent_session = &parent_thread.codex.session;
+            let turn_context = parent_session.new_default_turn().await;
+            run_subagent_start_hooks(
+                parent_session,
+                &turn_context,
+                subagent_hook_target(new_thread.thread_id, &agent_metadata),
+            )
+            .await;
+        }
+
         self.send_input(new_thread.thread_id, initial_operation)
             .await?;
-        if !new_thread.thread.enabled(Feature::MultiAgentV2) {
-            let child_reference = agent_metadata
-                .agent_path
-                .as_ref()
-                .map(ToString::to_string)
-                .unwrap_or_else(|| new_thread.thread_id.to_string());
-            self.maybe_start_completion_watcher(
-                new_thread.thread_id,
-                notification_source,
-                child_reference,
-                agent_metadata.agent_path.clone(),
-            );
-        }
+        let child_reference = agent_metadata
+            .agent_path
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| new_thread.thread_id.to_string());
+        self.maybe_start_completion_watcher(
+            new_thread.thread_id,
+            notification_source,
+            child_reference,
+            agent_metadata.agent_path.clone(),
+            subagent_hook_target(new_thread.thread_id, &agent_metadata),
+            /*notify_parent*/ !new_thread.thread.enabled(Feature::MultiAgentV2),
+        );
 
         Ok(LiveAgent {
             thread_id: new_thread.thread_id,
@@ -607,19 +651,19 @@ impl AgentControl {
         // Resumed threads are re-registered in-memory and need the same listener
         // attachment path as freshly spawned threads.
         state.notify_thread_created(resumed_thread.thread_id);
-        if !resumed_thread.thread.enabled(Feature::MultiAgentV2) {
-            let child_reference = agent_metadata
-                .agent_path
-                .as_ref()
-                .map(ToString::to_string)
-                .unwrap_or_else(|| resumed_thread.thread_id.to_string());
-            self.maybe_start_completion_watcher(
-                resumed_thread.thread_id,
-                Some(notification_source.clone()),
-                child_reference,
-                agent_metadata.agent_path.clone(),
-            );
-        }
+        let child_reference = agent_metadata
+            .agent_path
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| resumed_thread.thread_id.to_string());
+        self.maybe_start_completion_watcher(
+            resumed_thread.thread_id,
+            Some(notification_source.clone()),
+            child_reference,
+            agent_metadata.agent_path.clone(),
+            subagent_hook_target(resumed_thread.thread_id, &agent_metadata),
+            /*notify_parent*/ !resumed_thread.thread.enabled(Feature::MultiAgentV2),
+        );
         self.persist_thread_spawn_edge_for_source(
             resumed_thread.thread.as_ref(),
             resumed_thread.thread_id,
@@ -939,13 +983,16 @@ impl AgentControl {
     /// Starts a detached watcher for sub-agents spawned from another thread.
     ///
     /// This is only enabled for `SubAgentSource::ThreadSpawn`, where a parent thread exists and
-    /// can receive completion notifications.
+    /// can run `SubagentStop` hooks. When `notify_parent` is set, the parent also receives a
+    /// completion notification.
     fn maybe_start_completion_watcher(
         &self,
         child_thread_id: ThreadId,
         session_source: Option<SessionSource>,
         child_reference: String,
         child_agent_path: Option<AgentPath>,
+        hook_target: SubagentHookTarget,
+        notify_parent: bool,
     ) {
         let Some(SessionSource::SubAgent(SubAgentSource::ThreadSpawn {
             parent_thread_id, ..
@@ -976,6 +1023,17 @@ impl AgentControl {
             let Ok(state) = control.upgrade() else {
                 return;
             };
+            if let Some(stop_status) = subagent_stop_status(&status)
+                && let Ok(parent_thread) = state.get_thread(parent_thread_id).await
+            {
+                let parent_session = &parent_thread.codex.session;
+                let turn_context = parent_session.new_default_turn().await;
+                run_subagent_stop_hooks(parent_session, &turn_context, hook_target, stop_status)
+                    .await;
+            }
+            if !notify_parent {
+                return;
+            }
             let child_thread = state.get_thread(child_thread_id).await.ok();
             let message = format_subagent_notification_message(child_reference.as_str(), &status);
             if child_agent_path.is_some()
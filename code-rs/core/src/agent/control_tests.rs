@@ -1338,6 +1338,12 @@ async fn multi_agent_v2_completion_queues_message_for_direct_parent() {
         })),
         tester_path.to_string(),
         Some(tester_path.clone()),
+        SubagentHookTarget {
+            agent_id: tester_thread_id,
+            agent_type: "explorer".to_string(),
+            agent_nickname: None,
+        },
+        /*notify_parent*/ true,
     );
     let tester_turn = tester_thread.codex.session.new_default_turn().await;
     tester_thread
@@ -1424,6 +1430,12 @@ async fn completion_watcher_notifies_parent_when_child_is_missing() {
         })),
         child_thread_id.to_string(),
         /*child_agent_path*/ None,
+        SubagentHookTarget {
+            agent_id: child_thread_id,
+            agent_type: "explorer".to_string(),
+            agent_nickname: None,
+        },
+        /*notify_parent*/ true,
     );
 
     assert_eq!(wait_for_subagent_notification(&parent_thread).await, true);
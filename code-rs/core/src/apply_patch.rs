@@ -79,8 +79,12 @@ pub(crate) fn convert_apply_patch_to_protocol(
     let mut result = HashMap::with_capacity(action.changes().len());
     for (path, change) in action.changes() {
         let protocol_change = match change {
-            ApplyPatchFileChange::Add { content, .. } => FileChange::Add {
+            ApplyPatchFileChange::Add {
+                content,
+                executable,
+            } => FileChange::Add {
                 content: content.clone(),
+                executable: *executable,
             },
             ApplyPatchFileChange::Delete { content } => FileChange::Delete {
                 content: content.clone(),
@@ -89,9 +93,20 @@ pub(crate) fn convert_apply_patch_to_protocol(
                 unified_diff,
                 move_path,
                 new_content: _new_content,
+                executable,
             } => FileChange::Update {
                 unified_diff: unified_diff.clone(),
                 move_path: move_path.clone(),
+                executable: *executable,
+            },
+            ApplyPatchFileChange::SetMode { executable } => FileChange::SetMode {
+                executable: *executable,
+            },
+            ApplyPatchFileChange::AddSymlink { target } => FileChange::AddSymlink {
+                target: target.clone(),
+            },
+            ApplyPatchFileChange::AddBinary { content } => FileChange::AddBinary {
+                size: content.len() as u64,
             },
         };
         result.insert(path.to_path_buf(), protocol_change);
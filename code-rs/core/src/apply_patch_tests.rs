@@ -16,7 +16,8 @@ fn convert_apply_patch_maps_add_variant() {
     assert_eq!(
         got.get(p.as_path()),
         Some(&FileChange::Add {
-            content: "hello".to_string()
+            content: "hello".to_string(),
+            executable: None,
         })
     );
 }
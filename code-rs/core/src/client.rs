@@ -31,8 +31,10 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 
+use codex_api::AnthropicClient as ApiAnthropicClient;
 use codex_api::ApiError;
 use codex_api::AuthProvider;
+use codex_api::ChatClient as ApiChatClient;
 use codex_api::CompactClient as ApiCompactClient;
 use codex_api::CompactionInput as ApiCompactionInput;
 use codex_api::Compression;
@@ -84,6 +86,7 @@ use codex_protocol::protocol::W3cTraceContext;
 use codex_rollout_trace::CompactionTraceContext;
 use codex_rollout_trace::InferenceTraceAttempt;
 use codex_rollout_trace::InferenceTraceContext;
+use codex_tools::create_function_tools_for_api;
 use codex_tools::create_tools_json_for_responses_api;
 use eventsource_stream::Event;
 use eventsource_stream::EventStreamError;
@@ -141,6 +144,8 @@ pub const X_RESPONSESAPI_INCLUDE_TIMING_METRICS_HEADER: &str =
     "x-responsesapi-include-timing-metrics";
 const RESPONSES_WEBSOCKETS_V2_BETA_HEADER_VALUE: &str = "responses_websockets=2026-02-06";
 const RESPONSES_ENDPOINT: &str = "/responses";
+const CHAT_COMPLETIONS_ENDPOINT: &str = "/chat/completions";
+const ANTHROPIC_MESSAGES_ENDPOINT: &str = "/messages";
 const RESPONSES_COMPACT_ENDPOINT: &str = "/responses/compact";
 const MEMORIES_SUMMARIZE_ENDPOINT: &str = "/memories/trace_summarize";
 #[cfg(test)]
@@ -1195,6 +1200,89 @@ impl ModelClientSession {
             return Ok(stream);
         }
 
+        self.stream_http_api(
+            WireApi::Responses,
+            prompt,
+            model_info,
+            session_telemetry,
+            effort,
+            summary,
+            service_tier,
+            turn_metadata_header,
+            inference_trace,
+        )
+        .await
+    }
+
+    /// Streams a turn via the Chat Completions or Anthropic Messages API.
+    ///
+    /// The request is built exactly like a Responses request and re-encoded by
+    /// `codex-api`, so prompt construction stays identical across wire APIs.
+    /// Tools are flattened to plain functions since neither API has
+    /// namespaces or freeform tools.
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(
+        name = "model_client.stream_translated_api",
+        level = "info",
+        skip_all,
+        fields(
+            model = %model_info.slug,
+            wire_api = %wire_api,
+            transport = "http",
+            http.method = "POST",
+            turn.has_metadata_header = turn_metadata_header.is_some()
+        )
+    )]
+    async fn stream_translated_api(
+        &self,
+        wire_api: WireApi,
+        prompt: &Prompt,
+        model_info: &ModelInfo,
+        session_telemetry: &SessionTelemetry,
+        effort: Option<ReasoningEffortConfig>,
+        summary: ReasoningSummaryConfig,
+        service_tier: Option<String>,
+        turn_metadata_header: Option<&str>,
+        inference_trace: &InferenceTraceContext,
+    ) -> Result<ResponseStream> {
+        self.stream_http_api(
+            wire_api,
+            prompt,
+            model_info,
+            session_telemetry,
+            effort,
+            summary,
+            service_tier,
+            turn_metadata_header,
+            inference_trace,
+        )
+        .await
+    }
+
+    /// Sends one HTTP streaming request for `wire_api`, retrying after a 401 once auth has been
+    /// recovered.
+    #[allow(clippy::too_many_arguments)]
+    async fn stream_http_api(
+        &self,
+        wire_api: WireApi,
+        prompt: &Prompt,
+        model_info: &ModelInfo,
+        session_telemetry: &SessionTelemetry,
+        effort: Option<ReasoningEffortConfig>,
+        summary: ReasoningSummaryConfig,
+        service_tier: Option<String>,
+        turn_metadata_header: Option<&str>,
+        inference_trace: &InferenceTraceContext,
+    ) -> Result<ResponseStream> {
+        let endpoint = match wire_api {
+            WireApi::Responses => RESPONSES_ENDPOINT,
+            WireApi::Chat => CHAT_COMPLETIONS_ENDPOINT,
+            WireApi::Anthropic => ANTHROPIC_MESSAGES_ENDPOINT,
+        };
+        let tools = match wire_api {
+            WireApi::Responses => Vec::new(),
+            WireApi::Chat | WireApi::Anthropic => create_function_tools_for_api(&prompt.tools)?,
+        };
         let auth_manager = self.client.state.provider.auth_manager();
         let mut auth_recovery = auth_manager
             .as_ref()
@@ -1211,10 +1299,15 @@ impl ModelClientSession {
             let (request_telemetry, sse_telemetry) = Self::build_streaming_telemetry(
                 session_telemetry,
                 request_auth_context,
-                RequestRouteTelemetry::for_endpoint(RESPONSES_ENDPOINT),
+                RequestRouteTelemetry::for_endpoint(endpoint),
                 self.client.state.auth_env_telemetry.clone(),
             );
-            let compression = self.responses_request_compression(client_setup.auth.as_ref());
+            let compression = match wire_api {
+                WireApi::Responses => {
+                    self.responses_request_compression(client_setup.auth.as_ref())
+                }
+                WireApi::Chat | WireApi::Anthropic => Compression::None,
+            };
             let options = self.build_responses_options(turn_metadata_header, compression);
 
             let request = self.client.build_responses_request(
@@ -1227,13 +1320,34 @@ impl ModelClientSession {
             )?;
             let inference_trace_attempt = inference_trace.start_attempt();
             inference_trace_attempt.record_started(&request);
-            let client = ApiResponsesClient::new(
-                transport,
-                client_setup.api_provider,
-                client_setup.api_auth,
-            )
-            .with_telemetry(Some(request_telemetry), Some(sse_telemetry));
-            let stream_result = client.stream_request(request, options).await;
+            let stream_result = match wire_api {
+                WireApi::Responses => {
+                    ApiResponsesClient::new(
+                        transport,
+                        client_setup.api_provider,
+                        client_setup.api_auth,
+                    )
+                    .with_telemetry(Some(request_telemetry), Some(sse_telemetry))
+                    .stream_request(request, options)
+                    .await
+                }
+                WireApi::Chat => {
+                    ApiChatClient::new(transport, client_setup.api_provider, client_setup.api_auth)
+                        .with_telemetry(Some(request_telemetry), Some(sse_telemetry))
+                        .stream_request(request, tools.clone(), options)
+                        .await
+                }
+                WireApi::Anthropic => {
+                    ApiAnthropicClient::new(
+                        transport,
+                        client_setup.api_provider,
+                        client_setup.api_auth,
+                    )
+                    .with_telemetry(Some(request_telemetry), Some(sse_telemetry))
+                    .stream_request(request, tools.clone(), options)
+                    .await
+                }
+            };
 
             match stream_result {
                 Ok(stream) => {
@@ -1565,6 +1679,20 @@ impl ModelClientSession {
                 )
                 .await
             }
+            WireApi::Chat | WireApi::Anthropic => {
+                self.stream_translated_api(
+                    wire_api,
+                    prompt,
+                    model_info,
+                    session_telemetry,
+                    effort,
+                    summary,
+                    service_tier,
+                    turn_metadata_header,
+                    inference_trace,
+                )
+                .await
+            }
         }
     }
 
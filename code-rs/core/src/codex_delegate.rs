@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
 use async_channel::Receiver;
@@ -535,8 +536,13 @@ async fn handle_patch_approval(
         let patch = changes
             .iter()
             .map(|(path, change)| match change {
-                codex_protocol::protocol::FileChange::Add { content } => {
-                    format!("*** Add File: {}\n{}", path.display(), content)
+                codex_protocol::protocol::FileChange::Add {
+                    content,
+                    executable,
+                } => {
+                    let mut hunk = format!("*** Add File: {}\n{}", path.display(), content);
+                    push_set_mode_hunk(&mut hunk, path, *executable);
+                    hunk
                 }
                 codex_protocol::protocol::FileChange::Delete { content } => {
                     format!("*** Delete File: {}\n{}", path.display(), content)
@@ -544,8 +550,9 @@ async fn handle_patch_approval(
                 codex_protocol::protocol::FileChange::Update {
                     unified_diff,
                     move_path,
+                    executable,
                 } => {
-                    if let Some(move_path) = move_path {
+                    let mut hunk = if let Some(move_path) = move_path {
                         format!(
                             "*** Update File: {}\n*** Move to: {}\n{}",
                             path.display(),
@@ -554,7 +561,21 @@ async fn handle_patch_approval(
                         )
                     } else {
                         format!("*** Update File: {}\n{}", path.display(), unified_diff)
-                    }
+                    };
+                    push_set_mode_hunk(&mut hunk, path, *executable);
+                    hunk
+                }
+                codex_protocol::protocol::FileChange::SetMode { executable } => {
+                    set_mode_hunk(path, *executable)
+                }
+                codex_protocol::protocol::FileChange::AddSymlink { target } => {
+                    format!("*** Add Symlink: {}\n+{}", path.display(), target.display())
+                }
+                codex_protocol::protocol::FileChange::AddBinary { size } => {
+                    format!(
+                        "*** Add Binary File: {}\n({size} bytes of binary content)",
+                        path.display()
+                    )
                 }
             })
             .collect::<Vec<_>>()
@@ -609,6 +630,19 @@ async fn handle_patch_approval(
         .await;
 }
 
+fn set_mode_hunk(path: &Path, executable: bool) -> String {
+    let mode = if executable { "100755" } else { "100644" };
+    format!("*** Set Mode: {mode} {}", path.display())
+}
+
+/// Appends the mode change that rides along with an add or update of `path`.
+fn push_set_mode_hunk(hunk: &mut String, path: &Path, executable: Option<bool>) {
+    if let Some(executable) = executable {
+        hunk.push('\n');
+        hunk.push_str(&set_mode_hunk(path, executable));
+    }
+}
+
 async fn handle_request_user_input(
     codex: &Codex,
     id: String,
@@ -15,6 +15,8 @@ use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_protocol::error::CodexErr;
 use codex_protocol::error::Result as CodexResult;
 use codex_protocol::mcp::CallToolResult;
+use codex_protocol::mcp::GetPromptResult;
+use codex_protocol::mcp::Prompt;
 use codex_protocol::models::ActivePermissionProfile;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::PermissionProfile;
@@ -504,6 +506,33 @@ impl CodexThread {
             .await
     }
 
+    /// Lists the prompts offered by this thread's MCP servers, keyed by server name.
+    pub async fn list_mcp_prompts(&self) -> HashMap<String, Vec<Prompt>> {
+        codex_mcp::convert_mcp_prompts(self.codex.session.list_all_prompts().await)
+    }
+
+    pub async fn get_mcp_prompt(
+        &self,
+        server: &str,
+        name: &str,
+        arguments: Option<HashMap<String, String>>,
+    ) -> anyhow::Result<GetPromptResult> {
+        let arguments = arguments.map(|arguments| {
+            arguments
+                .into_iter()
+                .map(|(key, value)| (key, serde_json::Value::String(value)))
+                .collect()
+        });
+        let result = self
+            .codex
+            .session
+            .get_prompt(server, name, arguments)
+            .await?;
+        let result = serde_json::to_value(result)?;
+
+        Ok(GetPromptResult::from_mcp_value(result)?)
+    }
+
     pub fn enabled(&self, feature: Feature) -> bool {
         self.codex.enabled(feature)
     }
@@ -28,6 +28,7 @@ use codex_config::permissions_toml::NetworkDomainPermissionsToml;
 use codex_config::permissions_toml::NetworkToml;
 use codex_config::permissions_toml::PermissionProfileToml;
 use codex_config::permissions_toml::PermissionsToml;
+use codex_config::permissions_toml::ResourceLimitsToml;
 use codex_config::profile_toml::ConfigProfile;
 use codex_config::types::AppToolApproval;
 use codex_config::types::ApprovalsReviewer;
@@ -47,6 +48,7 @@ use codex_config::types::Notifications;
 use codex_config::types::OtelConfig;
 use codex_config::types::OtelConfigToml;
 use codex_config::types::OtelExporterKind;
+use codex_config::types::RedactionConfig;
 use codex_config::types::SandboxWorkspaceWrite;
 use codex_config::types::SessionPickerViewMode;
 use codex_config::types::SkillsConfig;
@@ -77,6 +79,7 @@ use codex_protocol::permissions::FileSystemSandboxEntry;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::FileSystemSpecialPath;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::NetworkAccess;
 use codex_protocol::protocol::RealtimeVoice;
 use codex_protocol::protocol::SandboxPolicy;
@@ -826,9 +829,14 @@ allow_upstream_proxy = false
                                 NetworkDomainPermissionToml::Allow,
                             )]),
                         }),
+                        http_rules: None,
+                        credentials: None,
                         unix_sockets: None,
                         allow_local_binding: None,
+                        mitm: None,
+                        traffic_log: None,
                     }),
+                    resources: None,
                 },
             )]),
         }
@@ -860,6 +868,7 @@ async fn permissions_profiles_network_enabled_allows_runtime_network_without_pro
                             enabled: Some(true),
                             ..Default::default()
                         }),
+                        resources: None,
                     },
                 )]),
             }),
@@ -909,6 +918,7 @@ async fn permissions_profiles_proxy_policy_starts_managed_network_proxy() -> std
                             enable_socks5: Some(false),
                             ..Default::default()
                         }),
+                        resources: None,
                     },
                 )]),
             }),
@@ -968,6 +978,7 @@ async fn permissions_profiles_network_disabled_by_default_does_not_start_proxy()
                             }),
                             ..Default::default()
                         }),
+                        resources: None,
                     },
                 )]),
             }),
@@ -1015,6 +1026,7 @@ async fn default_permissions_profile_populates_runtime_sandbox_policy() -> std::
                         ]),
                     }),
                     network: None,
+                    resources: None,
                 },
             )]),
         }),
@@ -1284,6 +1296,7 @@ async fn permission_profile_override_preserves_configured_network_policy_without
                             }),
                             ..Default::default()
                         }),
+                        resources: None,
                     },
                 )]),
             }),
@@ -1329,6 +1342,7 @@ async fn project_root_glob_none_compiles_to_filesystem_pattern_entry() -> std::i
                             )]),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -1401,6 +1415,7 @@ async fn permissions_profiles_require_default_permissions() -> std::io::Result<(
                             )]),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -1862,6 +1877,7 @@ async fn permissions_profiles_allow_direct_write_roots_outside_workspace_root()
                             )]),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -1920,6 +1936,7 @@ async fn permissions_profiles_reject_nested_entries_for_non_project_roots() -> s
                             )]),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -1977,6 +1994,7 @@ async fn permissions_profiles_allow_unknown_special_paths() -> std::io::Result<(
             )]),
         }),
         network: None,
+        resources: None,
     })
     .await?;
 
@@ -2023,6 +2041,7 @@ async fn permissions_profiles_allow_unknown_special_paths_with_nested_entries()
             )]),
         }),
         network: None,
+        resources: None,
     })
     .await?;
 
@@ -2050,6 +2069,7 @@ async fn permissions_profiles_allow_missing_filesystem_with_warning() -> std::io
     let config = load_workspace_permission_profile(PermissionProfileToml {
         filesystem: None,
         network: None,
+        resources: None,
     })
     .await?;
 
@@ -2073,6 +2093,54 @@ async fn permissions_profiles_allow_missing_filesystem_with_warning() -> std::io
     Ok(())
 }
 
+#[tokio::test]
+async fn permissions_profiles_resources_populate_resource_limits() -> std::io::Result<()> {
+    let config = load_workspace_permission_profile(PermissionProfileToml {
+        filesystem: None,
+        network: None,
+        resources: Some(ResourceLimitsToml {
+            max_memory_mb: Some(512),
+            max_cpu_seconds: Some(60),
+            max_processes: Some(128),
+            max_open_files: None,
+            max_file_size_mb: Some(1),
+        }),
+    })
+    .await?;
+
+    assert_eq!(
+        config.permissions.resource_limits,
+        ResourceLimits {
+            max_memory_bytes: Some(512 * 1024 * 1024),
+            max_cpu_seconds: Some(60),
+            max_processes: Some(128),
+            max_open_files: None,
+            max_file_size_bytes: Some(1024 * 1024),
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn permissions_profiles_reject_zero_resource_limits() {
+    let err = load_workspace_permission_profile(PermissionProfileToml {
+        filesystem: None,
+        network: None,
+        resources: Some(ResourceLimitsToml {
+            max_processes: Some(0),
+            ..Default::default()
+        }),
+    })
+    .await
+    .expect_err("zero process limit should be rejected");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        err.to_string(),
+        "permissions.workspace.resources.max_processes must be greater than zero"
+    );
+}
+
 #[tokio::test]
 async fn permissions_profiles_allow_empty_filesystem_with_warning() -> std::io::Result<()> {
     let config = load_workspace_permission_profile(PermissionProfileToml {
@@ -2081,6 +2149,7 @@ async fn permissions_profiles_allow_empty_filesystem_with_warning() -> std::io::
             entries: BTreeMap::new(),
         }),
         network: None,
+        resources: None,
     })
     .await?;
 
@@ -2122,6 +2191,7 @@ async fn permissions_profiles_reject_project_root_parent_traversal() -> std::io:
                             )]),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -2168,6 +2238,7 @@ async fn permissions_profiles_allow_network_enablement() -> std::io::Result<()>
                             enabled: Some(true),
                             ..Default::default()
                         }),
+                        resources: None,
                     },
                 )]),
             }),
@@ -2361,6 +2432,22 @@ async fn legacy_remote_thread_store_endpoint_is_rejected() {
     assert!(err.to_string().contains("no longer supported"));
 }
 
+#[tokio::test]
+async fn sqlite_thread_store_is_selectable() {
+    let cfg: ConfigToml = toml::from_str(r#"experimental_thread_store = { type = "sqlite" }"#)
+        .expect("sqlite thread store should deserialize");
+
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        tempdir().expect("tempdir").abs(),
+    )
+    .await
+    .expect("load config with sqlite thread store");
+
+    assert_eq!(config.experimental_thread_store, ThreadStoreConfig::Sqlite);
+}
+
 #[test]
 fn profile_tui_rejects_unsupported_settings() {
     let err = toml::from_str::<ConfigToml>(
@@ -7043,6 +7130,7 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
                 shell_environment_policy: ShellEnvironmentPolicy::default(),
                 windows_sandbox_mode: None,
                 windows_sandbox_private_desktop: true,
+                resource_limits: Default::default(),
             },
             approvals_reviewer: ApprovalsReviewer::User,
             enforce_residency: Constrained::allow_any(/*initial_value*/ None),
@@ -7147,6 +7235,7 @@ async fn test_precedence_fixture_with_o3_profile() -> std::io::Result<()> {
             tui_theme: None,
             tui_session_picker_view: SessionPickerViewMode::Dense,
             otel: OtelConfig::default(),
+            redaction: RedactionConfig::default(),
         },
         o3_profile_config
     );
@@ -7415,6 +7504,7 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
             shell_environment_policy: ShellEnvironmentPolicy::default(),
             windows_sandbox_mode: None,
             windows_sandbox_private_desktop: true,
+            resource_limits: Default::default(),
         },
         approvals_reviewer: ApprovalsReviewer::User,
         enforce_residency: Constrained::allow_any(/*initial_value*/ None),
@@ -7519,6 +7609,7 @@ async fn test_precedence_fixture_with_gpt3_profile() -> std::io::Result<()> {
         tui_theme: None,
         tui_session_picker_view: SessionPickerViewMode::Dense,
         otel: OtelConfig::default(),
+        redaction: RedactionConfig::default(),
     };
 
     assert_eq!(expected_gpt3_profile_config, gpt3_profile_config);
@@ -7573,6 +7664,7 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
             shell_environment_policy: ShellEnvironmentPolicy::default(),
             windows_sandbox_mode: None,
             windows_sandbox_private_desktop: true,
+            resource_limits: Default::default(),
         },
         approvals_reviewer: ApprovalsReviewer::User,
         enforce_residency: Constrained::allow_any(/*initial_value*/ None),
@@ -7677,6 +7769,7 @@ async fn test_precedence_fixture_with_zdr_profile() -> std::io::Result<()> {
         tui_theme: None,
         tui_session_picker_view: SessionPickerViewMode::Dense,
         otel: OtelConfig::default(),
+        redaction: RedactionConfig::default(),
     };
 
     assert_eq!(expected_zdr_profile_config, zdr_profile_config);
@@ -7716,6 +7809,7 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
             shell_environment_policy: ShellEnvironmentPolicy::default(),
             windows_sandbox_mode: None,
             windows_sandbox_private_desktop: true,
+            resource_limits: Default::default(),
         },
         approvals_reviewer: ApprovalsReviewer::User,
         enforce_residency: Constrained::allow_any(/*initial_value*/ None),
@@ -7820,6 +7914,7 @@ async fn test_precedence_fixture_with_gpt5_profile() -> std::io::Result<()> {
         tui_theme: None,
         tui_session_picker_view: SessionPickerViewMode::Dense,
         otel: OtelConfig::default(),
+        redaction: RedactionConfig::default(),
     };
 
     assert_eq!(expected_gpt5_profile_config, gpt5_profile_config);
@@ -96,6 +96,7 @@ use codex_protocol::openai_models::ModelsResponse;
 use codex_protocol::openai_models::ReasoningEffort;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_utils_absolute_path::AbsolutePathBuf;
@@ -116,6 +117,7 @@ use crate::config::permissions::compile_permission_profile_selection;
 use crate::config::permissions::default_builtin_permission_profile_name;
 use crate::config::permissions::get_readable_roots_required_for_codex_runtime;
 use crate::config::permissions::network_proxy_config_for_profile_selection;
+use crate::config::permissions::resource_limits_for_profile_selection;
 use crate::config::permissions::validate_user_permission_profile_names;
 use crate::config_lock::config_without_lock_controls;
 use crate::config_lock::lock_layer_from_config;
@@ -141,6 +143,7 @@ pub use codex_sandboxing::system_bwrap_warning;
 pub use managed_features::ManagedFeatures;
 pub use network_proxy_spec::NetworkProxySpec;
 pub use network_proxy_spec::StartedNetworkProxy;
+pub(crate) use network_proxy_spec::exec_policy_http_rules;
 pub(crate) use permissions::resolve_permission_profile;
 
 const DEFAULT_IGNORE_LARGE_UNTRACKED_DIRS: i64 = 200;
@@ -260,6 +263,9 @@ pub struct Permissions {
     pub windows_sandbox_mode: Option<WindowsSandboxModeToml>,
     /// Whether the final Windows sandboxed child should run on a private desktop.
     pub windows_sandbox_private_desktop: bool,
+    /// Resource limits from the selected permission profile, enforced by the
+    /// Linux sandbox helper.
+    pub resource_limits: ResourceLimits,
 }
 
 impl Permissions {
@@ -380,6 +386,9 @@ pub enum ThreadStoreConfig {
     /// Persist threads locally using rollout JSONL files and sqlite metadata.
     #[default]
     Local,
+    /// Persist threads in a SQLite database kept apart from the local store's
+    /// state DB.
+    Sqlite,
     /// In-memory thread store for test and debug configurations.
     InMemory { id: String },
 }
@@ -816,6 +825,10 @@ pub struct Config {
 
     /// OTEL configuration (exporter type, endpoint, headers, etc.).
     pub otel: codex_config::types::OtelConfig,
+
+    /// Redaction applied to tool output, hook output, rollouts and feedback
+    /// uploads.
+    pub redaction: codex_config::types::RedactionConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -1734,6 +1747,7 @@ fn resolve_tool_suggest_config_from_config(
 fn thread_store_config(thread_store: Option<ThreadStoreToml>) -> ThreadStoreConfig {
     match thread_store {
         Some(ThreadStoreToml::Local {}) => ThreadStoreConfig::Local,
+        Some(ThreadStoreToml::Sqlite {}) => ThreadStoreConfig::Sqlite,
         Some(ThreadStoreToml::InMemory { id }) => ThreadStoreConfig::InMemory { id },
         None => ThreadStoreConfig::Local,
     }
@@ -2297,6 +2311,14 @@ impl Config {
             || permission_config_syntax.is_none();
         let using_implicit_builtin_profile =
             permission_config_syntax.is_none() && default_permissions.is_none();
+        let resource_limits = if profiles_are_active {
+            let default_permissions = default_permissions.unwrap_or_else(|| {
+                default_builtin_permission_profile_name(&active_project, windows_sandbox_level)
+            });
+            resource_limits_for_profile_selection(cfg.permissions.as_ref(), default_permissions)?
+        } else {
+            ResourceLimits::default()
+        };
         let (
             configured_network_proxy_config,
             permission_profile,
@@ -2975,6 +2997,13 @@ impl Config {
             .set(effective_permission_profile)
             .map_err(std::io::Error::from)?;
         let otel = otel::resolve_config(cfg.otel.unwrap_or_default(), &mut startup_warnings);
+        let redaction: codex_config::types::RedactionConfig =
+            cfg.redaction.unwrap_or_default().into();
+        for pattern in &redaction.patterns {
+            codex_secrets::validate_redaction_pattern(pattern).map_err(|err| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{err:#}"))
+            })?;
+        }
         let config = Self {
             model,
             service_tier,
@@ -2994,6 +3023,7 @@ impl Config {
                 shell_environment_policy,
                 windows_sandbox_mode,
                 windows_sandbox_private_desktop,
+                resource_limits,
             },
             approvals_reviewer: constrained_approvals_reviewer.value(),
             enforce_residency: enforce_residency.value,
@@ -3200,6 +3230,7 @@ impl Config {
                 .map(|t| t.keymap.clone())
                 .unwrap_or_default(),
             otel,
+            redaction,
         };
         Ok(config)
         })
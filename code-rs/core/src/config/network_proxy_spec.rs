@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use codex_config::NetworkConstraints;
+use codex_config::permissions_toml::prepend_network_http_rules;
+use codex_execpolicy::Decision;
 use codex_execpolicy::Policy;
 use codex_network_proxy::BlockedRequestObserver;
 use codex_network_proxy::ConfigReloader;
 use codex_network_proxy::ConfigState;
 use codex_network_proxy::NetworkDecision;
+use codex_network_proxy::NetworkHttpRule;
+use codex_network_proxy::NetworkHttpRuleDecision;
 use codex_network_proxy::NetworkPolicyDecider;
 use codex_network_proxy::NetworkProxy;
 use codex_network_proxy::NetworkProxyAuditMetadata;
@@ -78,6 +82,10 @@ impl NetworkProxySpec {
         self.config.network.enabled
     }
 
+    pub(crate) fn traffic_log_enabled(&self) -> bool {
+        self.config.network.traffic_log
+    }
+
     pub fn proxy_host_and_port(&self) -> String {
         host_and_port_from_network_addr(&self.config.network.proxy_url, /*default_port*/ 3128)
     }
@@ -354,6 +362,24 @@ fn apply_exec_policy_network_rules(config: &mut NetworkProxyConfig, exec_policy:
     let (allowed_domains, denied_domains) = exec_policy.compiled_network_domains();
     upsert_network_domains(config, allowed_domains, /*allow*/ true);
     upsert_network_domains(config, denied_domains, /*allow*/ false);
+    prepend_network_http_rules(config, exec_policy_http_rules(exec_policy));
+}
+
+/// Converts execpolicy `network_rule`s scoped to methods or paths into proxy HTTP rules.
+pub(crate) fn exec_policy_http_rules(exec_policy: &Policy) -> Vec<NetworkHttpRule> {
+    exec_policy
+        .http_network_rules()
+        .map(|rule| NetworkHttpRule {
+            host: rule.host.clone(),
+            methods: rule.methods.clone(),
+            path: rule.path.clone(),
+            decision: if rule.decision == Decision::Allow {
+                NetworkHttpRuleDecision::Allow
+            } else {
+                NetworkHttpRuleDecision::Deny
+            },
+        })
+        .collect()
 }
 
 fn upsert_network_domains(config: &mut NetworkProxyConfig, hosts: Vec<String>, allow: bool) {
@@ -9,6 +9,7 @@ use codex_config::permissions_toml::FilesystemPermissionsToml;
 use codex_config::permissions_toml::NetworkToml;
 use codex_config::permissions_toml::PermissionProfileToml;
 use codex_config::permissions_toml::PermissionsToml;
+use codex_config::permissions_toml::ResourceLimitsToml;
 use codex_config::types::SandboxWorkspaceWrite;
 use codex_network_proxy::NetworkProxyConfig;
 #[cfg(test)]
@@ -21,6 +22,7 @@ use codex_protocol::permissions::FileSystemSandboxEntry;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::FileSystemSpecialPath;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 use super::ProjectConfig;
@@ -172,6 +174,50 @@ pub(crate) fn network_proxy_config_for_profile_selection(
     ))
 }
 
+pub(crate) fn resource_limits_for_profile_selection(
+    permissions: Option<&PermissionsToml>,
+    profile_name: &str,
+) -> io::Result<ResourceLimits> {
+    if is_builtin_permission_profile_name(profile_name) {
+        return Ok(ResourceLimits::default());
+    }
+    reject_unknown_builtin_permission_profile(profile_name)?;
+    let Some(permissions) = permissions else {
+        return Ok(ResourceLimits::default());
+    };
+    let profile = resolve_permission_profile(permissions, profile_name)?;
+    let Some(resources) = profile.resources.as_ref() else {
+        return Ok(ResourceLimits::default());
+    };
+    validate_resource_limits(profile_name, resources)?;
+    Ok(resources.to_resource_limits())
+}
+
+fn validate_resource_limits(profile_name: &str, resources: &ResourceLimitsToml) -> io::Result<()> {
+    let ResourceLimitsToml {
+        max_memory_mb,
+        max_cpu_seconds,
+        max_processes,
+        max_open_files,
+        max_file_size_mb,
+    } = resources;
+    for (key, value) in [
+        ("max_memory_mb", max_memory_mb),
+        ("max_cpu_seconds", max_cpu_seconds),
+        ("max_processes", max_processes),
+        ("max_open_files", max_open_files),
+        ("max_file_size_mb", max_file_size_mb),
+    ] {
+        if *value == Some(0) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("permissions.{profile_name}.resources.{key} must be greater than zero"),
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn compile_permission_profile(
     permissions: &PermissionsToml,
     profile_name: &str,
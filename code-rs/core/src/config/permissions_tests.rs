@@ -71,6 +71,7 @@ async fn restricted_read_implicitly_allows_helper_executables() -> std::io::Resu
                             entries: BTreeMap::new(),
                         }),
                         network: None,
+                        resources: None,
                     },
                 )]),
             }),
@@ -370,6 +371,7 @@ fn read_write_trailing_glob_suffix_compiles_as_subpath() -> std::io::Result<()>
                         )]),
                     }),
                     network: None,
+                    resources: None,
                 },
             )]),
         },
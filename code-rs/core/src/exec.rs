@@ -34,6 +34,8 @@ use codex_protocol::models::PermissionProfile;
 use codex_protocol::permissions::FileSystemSandboxKind;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimitKind;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecCommandOutputDeltaEvent;
@@ -44,6 +46,8 @@ use codex_sandboxing::SandboxManager;
 use codex_sandboxing::SandboxTransformRequest;
 use codex_sandboxing::SandboxType;
 use codex_sandboxing::SandboxablePreference;
+use codex_sandboxing::landlock::SandboxReport;
+use codex_sandboxing::landlock::SandboxReportContents;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_pty::DEFAULT_OUTPUT_BYTES_CAP;
 use codex_utils_pty::process_group::kill_child_process_group;
@@ -373,12 +377,14 @@ pub fn build_exec_request(
         .transform(SandboxTransformRequest {
             command,
             permissions: permission_profile,
+            resource_limits: ResourceLimits::default(),
             sandbox: sandbox_type,
             enforce_managed_network,
             network: network.as_ref(),
             sandbox_policy_cwd: sandbox_cwd,
             codex_linux_sandbox_exe: codex_linux_sandbox_exe.as_deref(),
             use_legacy_landlock,
+            log_denials: false,
             windows_sandbox_level,
             windows_sandbox_private_desktop,
         })
@@ -439,6 +445,8 @@ pub(crate) async fn execute_exec_request(
         network_sandbox_policy,
         windows_sandbox_filesystem_overrides,
         arg0,
+        resource_limits,
+        sandbox_report,
     } = exec_request;
 
     let params = ExecParams {
@@ -467,7 +475,32 @@ pub(crate) async fn execute_exec_request(
     )
     .await;
     let duration = start.elapsed();
-    finalize_exec_result(raw_output_result, sandbox, duration)
+    let sandbox_report = read_sandbox_report(sandbox_report).await;
+    finalize_exec_result(
+        raw_output_result,
+        sandbox,
+        &resource_limits,
+        sandbox_report.as_ref(),
+        duration,
+    )
+}
+
+/// Reads what the Linux sandbox helper recorded once it has exited.
+pub(crate) async fn read_sandbox_report(
+    report: Option<SandboxReport>,
+) -> Option<SandboxReportContents> {
+    let report = report?;
+    match tokio::task::spawn_blocking(move || report.read()).await {
+        Ok(Ok(contents)) => Some(contents),
+        Ok(Err(err)) => {
+            tracing::warn!("failed to read Linux sandbox report: {err}");
+            None
+        }
+        Err(err) => {
+            tracing::warn!("failed to read Linux sandbox report: {err}");
+            None
+        }
+    }
 }
 
 async fn get_raw_output_result(
@@ -718,31 +751,44 @@ async fn exec_windows_sandbox(
 fn finalize_exec_result(
     raw_output_result: std::result::Result<RawExecToolCallOutput, CodexErr>,
     sandbox_type: SandboxType,
+    resource_limits: &ResourceLimits,
+    sandbox_report: Option<&SandboxReportContents>,
     duration: Duration,
 ) -> Result<ExecToolCallOutput> {
     match raw_output_result {
         Ok(raw_output) => {
             #[allow(unused_mut)]
             let mut timed_out = raw_output.timed_out;
+            #[allow(unused_mut)]
+            let mut signal = None;
 
             #[cfg(target_family = "unix")]
             {
-                if let Some(signal) = raw_output.exit_status.signal() {
-                    if signal == TIMEOUT_CODE {
+                if let Some(exit_signal) = raw_output.exit_status.signal() {
+                    if exit_signal == TIMEOUT_CODE {
                         timed_out = true;
                     } else {
-                        return Err(CodexErr::Sandbox(SandboxErr::Signal(signal)));
+                        signal = Some(exit_signal);
                     }
                 }
             }
 
             let mut exit_code = raw_output.exit_status.code().unwrap_or(-1);
-            if timed_out {
+            let stderr = raw_output.stderr.from_utf8_lossy();
+            let resource_limit_exceeded = if timed_out {
                 exit_code = EXEC_TIMEOUT_EXIT_CODE;
+                None
+            } else {
+                resource_limit_exceeded(sandbox_type, resource_limits, signal, sandbox_report)
+            };
+            if let Some(signal) = signal {
+                if resource_limit_exceeded.is_none() {
+                    return Err(CodexErr::Sandbox(SandboxErr::Signal(signal)));
+                }
+                exit_code = 128 + signal;
             }
 
             let stdout = raw_output.stdout.from_utf8_lossy();
-            let stderr = raw_output.stderr.from_utf8_lossy();
             let aggregated_output = raw_output.aggregated_output.from_utf8_lossy();
             let exec_output = ExecToolCallOutput {
                 exit_code,
@@ -751,6 +797,10 @@ fn finalize_exec_result(
                 aggregated_output,
                 duration,
                 timed_out,
+                resource_limit_exceeded,
+                sandbox_denials: sandbox_report
+                    .map(|report| report.denials.clone())
+                    .unwrap_or_default(),
             };
 
             if timed_out {
@@ -759,6 +809,12 @@ fn finalize_exec_result(
                 }));
             }
 
+            if exec_output.resource_limit_exceeded.is_some() {
+                return Err(CodexErr::Sandbox(SandboxErr::ResourceLimitExceeded {
+                    output: Box::new(exec_output),
+                }));
+            }
+
             if is_likely_sandbox_denied(sandbox_type, &exec_output) {
                 return Err(CodexErr::Sandbox(SandboxErr::Denied {
                     output: Box::new(exec_output),
@@ -775,6 +831,21 @@ fn finalize_exec_result(
     }
 }
 
+/// Resource limits are only enforced by the Linux sandbox helper, so a limit
+/// kill is only reported for commands it ran with limits configured, based on
+/// the signal that terminated it and its sandbox report.
+fn resource_limit_exceeded(
+    sandbox_type: SandboxType,
+    resource_limits: &ResourceLimits,
+    signal: Option<i32>,
+    sandbox_report: Option<&SandboxReportContents>,
+) -> Option<ResourceLimitKind> {
+    if sandbox_type != SandboxType::LinuxSeccomp || resource_limits.is_empty() {
+        return None;
+    }
+    codex_sandboxing::landlock::resource_limit_exceeded(signal, sandbox_report)
+}
+
 /// We don't have a fully deterministic way to tell if our command failed
 /// because of the sandbox - a command in the user's zshrc file might hit an
 /// error, but the command itself might fail or succeed for other reasons.
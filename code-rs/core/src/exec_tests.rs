@@ -23,6 +23,8 @@ fn make_exec_output(
         aggregated_output: StreamOutput::new(aggregated.to_string()),
         duration: Duration::from_millis(1),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     }
 }
 
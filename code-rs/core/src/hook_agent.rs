@@ -0,0 +1,144 @@
+//! Sub-agent runs for `agent` hooks.
+//!
+//! `codex-hooks` decides when an agent hook runs and how its answer is parsed;
+//! this module spawns the short-lived, read-only sub-agent it delegates to.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+
+use codex_features::Feature;
+use codex_hooks::HookAgentEvaluator;
+use codex_hooks::HookAgentRequest;
+use codex_protocol::config_types::WebSearchMode;
+use codex_protocol::error::CodexErr;
+use codex_protocol::error::Result as CodexResult;
+use codex_protocol::models::PermissionProfile;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::SubAgentSource;
+use codex_protocol::user_input::UserInput;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::codex_delegate::run_codex_thread_one_shot;
+use crate::config::Config;
+use crate::config::Constrained;
+use crate::session::session::Session;
+
+const HOOK_AGENT_SOURCE: &str = "hook";
+
+/// Builds the evaluator handed to [`codex_hooks::HooksConfig`] so agent hooks
+/// can spawn sub-agents of the session that owns them.
+///
+/// Hooks are built before the session exists, so the session is read from a
+/// slot that is filled in once construction finishes.
+pub(crate) fn hook_agent_evaluator(session: Arc<RwLock<Weak<Session>>>) -> HookAgentEvaluator {
+    Arc::new(move |request| {
+        let session = Arc::clone(&session);
+        Box::pin(async move {
+            let Some(session) = session.read().await.upgrade() else {
+                return Err("agent hooks are not available in this session".to_string());
+            };
+            run_hook_agent(session, request)
+                .await
+                .map_err(|err| err.to_string())
+        })
+    })
+}
+
+async fn run_hook_agent(session: Arc<Session>, request: HookAgentRequest) -> CodexResult<String> {
+    let HookAgentRequest {
+        model,
+        instructions,
+        input,
+        output_schema,
+        cwd,
+    } = request;
+    let parent_ctx = session.new_default_turn().await;
+    let model = model.unwrap_or_else(|| parent_ctx.model_info.slug.clone());
+    let config = build_hook_agent_config(&parent_ctx.config, model, instructions, cwd)?;
+
+    // The hook engine drops this future when the hook times out; take the
+    // sub-agent down with it.
+    let cancel_token = CancellationToken::new();
+    let _cancel_on_drop = cancel_token.clone().drop_guard();
+    let codex = run_codex_thread_one_shot(
+        config,
+        Arc::clone(&session.services.auth_manager),
+        Arc::clone(&session.services.models_manager),
+        vec![UserInput::Text {
+            text: input,
+            text_elements: Vec::new(),
+        }],
+        Arc::clone(&session),
+        parent_ctx,
+        cancel_token,
+        SubAgentSource::Other(HOOK_AGENT_SOURCE.to_string()),
+        Some(output_schema),
+        /*initial_history*/ None,
+    )
+    .await?;
+
+    while let Ok(event) = codex.next_event().await {
+        match event.msg {
+            EventMsg::TurnComplete(turn_complete) => {
+                return turn_complete.last_agent_message.ok_or_else(|| {
+                    CodexErr::Stream("agent hook finished without a message".into(), None)
+                });
+            }
+            EventMsg::TurnAborted(_) => return Err(CodexErr::TurnAborted),
+            _ => {}
+        }
+    }
+    Err(CodexErr::InternalAgentDied)
+}
+
+/// Derives the sub-agent config from the parent's: read-only sandbox, no
+/// approvals, and none of the tools that could reach outside the workspace or
+/// recurse into more hooks and agents.
+fn build_hook_agent_config(
+    parent_config: &Config,
+    model: String,
+    instructions: String,
+    cwd: std::path::PathBuf,
+) -> CodexResult<Config> {
+    let mut config = parent_config.clone();
+    config.model = Some(model);
+    config.cwd = AbsolutePathBuf::from_absolute_path(&cwd)?;
+    config.developer_instructions = Some(instructions);
+    config.permissions.approval_policy = Constrained::allow_only(AskForApproval::Never);
+    let sandbox_policy = SandboxPolicy::new_read_only_policy();
+    config.permissions.permission_profile = Constrained::allow_only(
+        PermissionProfile::from_legacy_sandbox_policy(&sandbox_policy),
+    );
+    config
+        .permissions
+        .set_legacy_sandbox_policy(sandbox_policy, config.cwd.as_path())
+        .map_err(|err| {
+            CodexErr::Fatal(format!("agent hook could not set sandbox policy: {err}"))
+        })?;
+    config
+        .web_search_mode
+        .set(WebSearchMode::Disabled)
+        .map_err(|err| {
+            CodexErr::Fatal(format!("agent hook could not disable web search: {err}"))
+        })?;
+    config
+        .mcp_servers
+        .set(HashMap::new())
+        .map_err(|err| CodexErr::Fatal(format!("agent hook could not clear MCP servers: {err}")))?;
+    for feature in [
+        Feature::SpawnCsv,
+        Feature::Collab,
+        Feature::MultiAgentV2,
+        Feature::CodexHooks,
+        Feature::Apps,
+        Feature::Plugins,
+    ] {
+        let _ = config.features.disable(feature);
+    }
+    Ok(config)
+}
@@ -0,0 +1,123 @@
+//! Model requests for `prompt` hooks.
+//!
+//! `codex-hooks` decides when a prompt hook runs and how its answer is parsed;
+//! this module supplies the one-shot model call it delegates to.
+
+use std::sync::Arc;
+
+use codex_hooks::HookPromptEvaluator;
+use codex_hooks::HookPromptRequest;
+use codex_models_manager::manager::RefreshStrategy;
+use codex_models_manager::manager::SharedModelsManager;
+use codex_otel::SessionTelemetry;
+use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
+use codex_protocol::error::CodexErr;
+use codex_protocol::error::Result as CodexResult;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_rollout_trace::InferenceTraceContext;
+use futures::prelude::*;
+
+use crate::Prompt;
+use crate::client::ModelClient;
+use crate::client_common::ResponseEvent;
+use crate::config::Config;
+use crate::session::turn::get_last_assistant_message_from_turn;
+
+/// Builds the evaluator handed to [`codex_hooks::HooksConfig`] so prompt hooks
+/// can reach the session's model provider.
+pub(crate) fn hook_prompt_evaluator(
+    model_client: ModelClient,
+    models_manager: SharedModelsManager,
+    session_telemetry: SessionTelemetry,
+    config: Arc<Config>,
+) -> HookPromptEvaluator {
+    Arc::new(move |request| {
+        let model_client = model_client.clone();
+        let models_manager = Arc::clone(&models_manager);
+        let session_telemetry = session_telemetry.clone();
+        let config = Arc::clone(&config);
+        Box::pin(async move {
+            evaluate_hook_prompt(
+                &model_client,
+                &models_manager,
+                &session_telemetry,
+                &config,
+                request,
+            )
+            .await
+            .map_err(|err| err.to_string())
+        })
+    })
+}
+
+async fn evaluate_hook_prompt(
+    model_client: &ModelClient,
+    models_manager: &SharedModelsManager,
+    session_telemetry: &SessionTelemetry,
+    config: &Config,
+    request: HookPromptRequest,
+) -> CodexResult<String> {
+    let HookPromptRequest {
+        model,
+        instructions,
+        input,
+        output_schema,
+    } = request;
+    let model = models_manager
+        .get_default_model(
+            &model.or_else(|| config.model.clone()),
+            RefreshStrategy::OnlineIfUncached,
+        )
+        .await;
+    let model_info = models_manager
+        .get_model_info(model.as_str(), &config.to_models_manager_config())
+        .await;
+    let reasoning_effort = if model_info.supports_reasoning_summaries {
+        model_info.default_reasoning_level
+    } else {
+        None
+    };
+    let prompt = Prompt {
+        input: vec![ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText { text: input }],
+            phase: None,
+        }],
+        base_instructions: BaseInstructions { text: instructions },
+        output_schema: Some(output_schema),
+        ..Default::default()
+    };
+
+    let mut client_session = model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &model_info,
+            session_telemetry,
+            reasoning_effort,
+            ReasoningSummaryConfig::None,
+            config.service_tier.clone(),
+            /*turn_metadata_header*/ None,
+            &InferenceTraceContext::disabled(),
+        )
+        .await?;
+    let mut items = Vec::new();
+    loop {
+        let Some(event) = stream.next().await else {
+            return Err(CodexErr::Stream(
+                "stream closed before response.completed".into(),
+                None,
+            ));
+        };
+        match event? {
+            ResponseEvent::OutputItemDone(item) => items.push(item),
+            ResponseEvent::Completed { .. } => break,
+            _ => {}
+        }
+    }
+    get_last_assistant_message_from_turn(&items)
+        .ok_or_else(|| CodexErr::Stream("prompt hook model returned no message".into(), None))
+}
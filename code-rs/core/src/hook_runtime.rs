@@ -5,6 +5,7 @@ use std::time::Duration;
 use codex_analytics::CompactionTrigger;
 use codex_analytics::HookRunFact;
 use codex_analytics::build_track_events_context;
+use codex_hooks::NotificationType;
 use codex_hooks::PermissionRequestDecision;
 use codex_hooks::PermissionRequestOutcome;
 use codex_hooks::PermissionRequestRequest;
@@ -12,11 +13,14 @@ use codex_hooks::PostToolUseOutcome;
 use codex_hooks::PostToolUseRequest;
 use codex_hooks::PreToolUseOutcome;
 use codex_hooks::PreToolUseRequest;
+use codex_hooks::SessionEndReason;
 use codex_hooks::SessionStartOutcome;
+use codex_hooks::SubagentStopStatus;
 use codex_hooks::UserPromptSubmitOutcome;
 use codex_hooks::UserPromptSubmitRequest;
 use codex_otel::HOOK_RUN_DURATION_METRIC;
 use codex_otel::HOOK_RUN_METRIC;
+use codex_protocol::ThreadId;
 use codex_protocol::items::TurnItem;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
@@ -34,6 +38,8 @@ use serde_json::Value;
 use crate::context::ContextualUserFragment;
 use crate::context::HookAdditionalContext;
 use crate::event_mapping::parse_turn_item;
+use crate::redaction::RedactionSource;
+use crate::redaction::redact_text;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::tools::hook_names::HookToolName;
@@ -318,6 +324,107 @@ pub(crate) async fn run_post_compact_hooks(
     }
 }
 
+pub(crate) async fn run_session_end_hooks(sess: &Arc<Session>, turn_context: &Arc<TurnContext>) {
+    let request = codex_hooks::SessionEndRequest {
+        session_id: sess.conversation_id,
+        cwd: turn_context.cwd.clone(),
+        transcript_path: sess.hook_transcript_path().await,
+        reason: SessionEndReason::Shutdown,
+    };
+    let preview_runs = sess.hooks().preview_session_end(&request);
+    emit_hook_started_events(sess, turn_context, preview_runs).await;
+
+    let outcome = sess.hooks().run_session_end(request).await;
+    emit_hook_completed_events(sess, turn_context, outcome.hook_events).await;
+}
+
+/// Identifies a sub-agent to the `SubagentStart` and `SubagentStop` hooks of
+/// the session that spawned it.
+pub(crate) struct SubagentHookTarget {
+    pub(crate) agent_id: ThreadId,
+    pub(crate) agent_type: String,
+    pub(crate) agent_nickname: Option<String>,
+}
+
+pub(crate) async fn run_subagent_start_hooks(
+    sess: &Arc<Session>,
+    turn_context: &Arc<TurnContext>,
+    target: SubagentHookTarget,
+) {
+    let request = codex_hooks::SubagentStartRequest {
+        session_id: sess.conversation_id,
+        cwd: turn_context.cwd.clone(),
+        transcript_path: sess.hook_transcript_path().await,
+        agent_id: target.agent_id,
+        agent_type: target.agent_type,
+        agent_nickname: target.agent_nickname,
+    };
+    let preview_runs = sess.hooks().preview_subagent_start(&request);
+    emit_hook_started_events(sess, turn_context, preview_runs).await;
+
+    let outcome = sess.hooks().run_subagent_start(request).await;
+    emit_hook_completed_events(sess, turn_context, outcome.hook_events).await;
+}
+
+pub(crate) async fn run_subagent_stop_hooks(
+    sess: &Arc<Session>,
+    turn_context: &Arc<TurnContext>,
+    target: SubagentHookTarget,
+    status: SubagentStopStatus,
+) {
+    let request = codex_hooks::SubagentStopRequest {
+        session_id: sess.conversation_id,
+        cwd: turn_context.cwd.clone(),
+        transcript_path: sess.hook_transcript_path().await,
+        agent_id: target.agent_id,
+        agent_type: target.agent_type,
+        agent_nickname: target.agent_nickname,
+        status,
+    };
+    let preview_runs = sess.hooks().preview_subagent_stop(&request);
+    emit_hook_started_events(sess, turn_context, preview_runs).await;
+
+    let outcome = sess.hooks().run_subagent_stop(request).await;
+    emit_hook_completed_events(sess, turn_context, outcome.hook_events).await;
+}
+
+/// Runs `Notification` hooks in the background. Notifications are
+/// informational, so hook output never changes what the turn does next and
+/// the caller does not wait for slow hooks.
+pub(crate) fn spawn_notification_hooks(
+    sess: &Arc<Session>,
+    turn_context: &Arc<TurnContext>,
+    notification_type: NotificationType,
+    message: String,
+) {
+    let sess = Arc::clone(sess);
+    let turn_context = Arc::clone(turn_context);
+    tokio::spawn(async move {
+        run_notification_hooks(&sess, &turn_context, notification_type, message).await;
+    });
+}
+
+async fn run_notification_hooks(
+    sess: &Session,
+    turn_context: &TurnContext,
+    notification_type: NotificationType,
+    message: String,
+) {
+    let request = codex_hooks::NotificationRequest {
+        session_id: sess.conversation_id,
+        turn_id: turn_context.sub_id.clone(),
+        cwd: turn_context.cwd.clone(),
+        transcript_path: sess.hook_transcript_path().await,
+        notification_type,
+        message,
+    };
+    let preview_runs = sess.hooks().preview_notification(&request);
+    emit_hook_started_events(sess, turn_context, preview_runs).await;
+
+    let outcome = sess.hooks().run_notification(request).await;
+    emit_hook_completed_events(sess, turn_context, outcome.hook_events).await;
+}
+
 pub(crate) async fn run_user_prompt_submit_hooks(
     sess: &Arc<Session>,
     turn_context: &Arc<TurnContext>,
@@ -430,6 +537,10 @@ pub(crate) async fn record_additional_contexts(
     turn_context: &Arc<TurnContext>,
     additional_contexts: Vec<String>,
 ) {
+    let additional_contexts = additional_contexts
+        .iter()
+        .map(|context| redact_text(sess, RedactionSource::HookOutput, context))
+        .collect();
     let developer_messages = additional_context_messages(additional_contexts);
     if developer_messages.is_empty() {
         return;
@@ -448,8 +559,8 @@ fn additional_context_messages(additional_contexts: Vec<String>) -> Vec<Response
 }
 
 async fn emit_hook_started_events(
-    sess: &Arc<Session>,
-    turn_context: &Arc<TurnContext>,
+    sess: &Session,
+    turn_context: &TurnContext,
     preview_runs: Vec<HookRunSummary>,
 ) {
     for run in preview_runs {
@@ -465,11 +576,14 @@ async fn emit_hook_started_events(
 }
 
 pub(crate) async fn emit_hook_completed_events(
-    sess: &Arc<Session>,
-    turn_context: &Arc<TurnContext>,
+    sess: &Session,
+    turn_context: &TurnContext,
     completed_events: Vec<HookCompletedEvent>,
 ) {
-    for completed in completed_events {
+    for mut completed in completed_events {
+        for entry in &mut completed.run.entries {
+            entry.text = redact_text(sess, RedactionSource::HookOutput, &entry.text);
+        }
         emit_hook_completed_metrics(turn_context, &completed);
         track_hook_completed_analytics(sess, turn_context, &completed);
         sess.send_event(turn_context, EventMsg::HookCompleted(completed))
@@ -494,8 +608,8 @@ fn emit_hook_completed_metrics(turn_context: &TurnContext, completed: &HookCompl
 }
 
 fn track_hook_completed_analytics(
-    sess: &Arc<Session>,
-    turn_context: &Arc<TurnContext>,
+    sess: &Session,
+    turn_context: &TurnContext,
     completed: &HookCompletedEvent,
 ) {
     let (tracking, hook) =
@@ -537,6 +651,10 @@ fn hook_run_metric_tags(run: &HookRunSummary) -> [(&'static str, &'static str);
         HookEventName::SessionStart => "SessionStart",
         HookEventName::UserPromptSubmit => "UserPromptSubmit",
         HookEventName::Stop => "Stop",
+        HookEventName::SessionEnd => "SessionEnd",
+        HookEventName::SubagentStart => "SubagentStart",
+        HookEventName::SubagentStop => "SubagentStop",
+        HookEventName::Notification => "Notification",
     };
     let hook_source = match run.source {
         HookSource::System => "system",
@@ -3,6 +3,7 @@ use crate::spawn::StdioPolicy;
 use crate::spawn::spawn_child_async;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::models::PermissionProfile;
+use codex_protocol::permissions::ResourceLimits;
 use codex_sandboxing::landlock::CODEX_LINUX_SANDBOX_ARG0;
 use codex_sandboxing::landlock::allow_network_for_proxy;
 use codex_sandboxing::landlock::create_linux_sandbox_command_args_for_permission_profile;
@@ -38,9 +39,12 @@ where
         command,
         command_cwd.as_path(),
         permission_profile,
+        &ResourceLimits::default(),
         sandbox_policy_cwd,
         use_legacy_landlock,
+        /*log_denials*/ false,
         allow_network_for_proxy(/*enforce_managed_network*/ false),
+        /*report_file*/ None,
     );
     let codex_linux_sandbox_exe = codex_linux_sandbox_exe.as_ref();
     // Preserve the helper alias when we already have it; otherwise force argv0
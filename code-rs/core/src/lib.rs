@@ -43,6 +43,8 @@ mod goals;
 pub use goals::ExternalGoalPreviousStatus;
 pub use goals::ExternalGoalSet;
 mod guardian;
+mod hook_agent;
+mod hook_prompt;
 mod hook_runtime;
 mod installation_id;
 pub(crate) mod landlock;
@@ -79,8 +81,11 @@ pub(crate) mod mentions {
     pub(crate) use crate::plugins::collect_explicit_plugin_mentions;
     pub(crate) use crate::plugins::collect_tool_mentions_from_messages;
 }
+mod redaction;
+pub use redaction::output_redactor_from_config;
 mod sandbox_tags;
 pub mod sandboxing;
+pub(crate) mod secret_injection;
 mod session_prefix;
 mod session_startup_prewarm;
 mod shell_detect;
@@ -119,6 +124,7 @@ pub use thread_manager::StartThreadOptions;
 pub use thread_manager::ThreadManager;
 pub use thread_manager::ThreadShutdownReport;
 pub use thread_manager::build_models_manager;
+pub use thread_manager::sqlite_thread_store_from_config;
 pub use thread_manager::thread_store_from_config;
 pub use web_search::web_search_action_detail;
 pub use web_search::web_search_detail;
@@ -29,6 +29,7 @@ use crate::hook_runtime::run_permission_request_hooks;
 use crate::mcp_openai_file::rewrite_mcp_tool_arguments_for_openai_files;
 use crate::mcp_tool_approval_templates::RenderedMcpToolApprovalParam;
 use crate::mcp_tool_approval_templates::render_mcp_tool_approval_template;
+use crate::redaction::redact_mcp_tool_result;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::tools::hook_names::HookToolName;
@@ -352,7 +353,7 @@ async fn handle_approved_mcp_tool_call(
             .clone()
             .unwrap_or_else(|| JsonValue::Object(serde_json::Map::new())),
     };
-    let result = async {
+    let mut result = async {
         let rewritten_arguments = rewrite?;
         let result = execute_mcp_tool_call(
             sess,
@@ -380,8 +381,9 @@ async fn handle_approved_mcp_tool_call(
         },
     ))
     .await;
-    if let Err(error) = &result {
-        tracing::warn!("MCP tool call error: {error:?}");
+    match &mut result {
+        Ok(result) => redact_mcp_tool_result(sess, result),
+        Err(error) => tracing::warn!("MCP tool call error: {error:?}"),
     }
     let duration = start.elapsed();
     notify_mcp_tool_call_completed(
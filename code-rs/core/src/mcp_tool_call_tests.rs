@@ -1125,6 +1125,7 @@ async fn install_host_owned_codex_apps_manager(session: &Session, turn_context:
         codex_mcp::ToolPluginProvenance::default(),
         auth.as_ref(),
         /*elicitation_reviewer*/ None,
+        /*sampling_handler*/ None,
     )
     .await;
     *session.services.mcp_connection_manager.write().await = manager;
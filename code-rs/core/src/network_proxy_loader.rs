@@ -1,3 +1,4 @@
+use crate::config::exec_policy_http_rules;
 use crate::config::find_codex_home;
 use crate::config::resolve_permission_profile;
 use crate::exec_policy::ExecPolicyError;
@@ -16,6 +17,7 @@ use codex_config::loader::load_config_layers_state;
 use codex_config::permissions_toml::NetworkToml;
 use codex_config::permissions_toml::PermissionsToml;
 use codex_config::permissions_toml::overlay_network_domain_permissions;
+use codex_config::permissions_toml::prepend_network_http_rules;
 use codex_exec_server::LOCAL_FS;
 use codex_network_proxy::ConfigReloader;
 use codex_network_proxy::ConfigState;
@@ -240,6 +242,7 @@ fn apply_exec_policy_network_rules(
             codex_network_proxy::NetworkDomainPermission::Deny,
         );
     }
+    prepend_network_http_rules(config, exec_policy_http_rules(exec_policy));
 }
 
 fn upsert_network_domain(
@@ -248,3 +248,80 @@ default_permissions = "workspace"
         Some(vec!["blocked.example.com".to_string()])
     );
 }
+
+#[test]
+fn http_rules_from_later_layers_and_execpolicy_are_checked_first() {
+    let lower_network: toml::Value = toml::from_str(
+        r#"
+default_permissions = "workspace"
+
+[permissions.workspace.network]
+mitm = true
+
+[[permissions.workspace.network.http_rules]]
+host = "registry.npmjs.org"
+decision = "deny"
+"#,
+    )
+    .expect("lower layer should parse");
+    let higher_network: toml::Value = toml::from_str(
+        r#"
+default_permissions = "workspace"
+
+[[permissions.workspace.network.http_rules]]
+host = "registry.npmjs.org"
+methods = ["GET", "HEAD"]
+decision = "allow"
+"#,
+    )
+    .expect("higher layer should parse");
+
+    let mut config = NetworkProxyConfig::default();
+    apply_network_tables(
+        &mut config,
+        network_tables_from_toml(&lower_network).expect("lower layer should deserialize"),
+    )
+    .expect("lower layer should apply");
+    apply_network_tables(
+        &mut config,
+        network_tables_from_toml(&higher_network).expect("higher layer should deserialize"),
+    )
+    .expect("higher layer should apply");
+
+    let mut parser = codex_execpolicy::PolicyParser::new();
+    parser
+        .parse(
+            "network.rules",
+            r#"network_rule(host="api.github.com", protocol="https", decision="allow", methods=["POST"], path="/repos/our-org/*")"#,
+        )
+        .expect("execpolicy should parse");
+    apply_exec_policy_network_rules(&mut config, &parser.build());
+
+    assert!(config.network.mitm);
+    assert_eq!(
+        config
+            .network
+            .http_rules
+            .iter()
+            .map(|rule| (rule.host.as_str(), rule.methods.join(","), rule.decision))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "api.github.com",
+                "POST".to_string(),
+                codex_network_proxy::NetworkHttpRuleDecision::Allow,
+            ),
+            (
+                "registry.npmjs.org",
+                "GET,HEAD".to_string(),
+                codex_network_proxy::NetworkHttpRuleDecision::Allow,
+            ),
+            (
+                "registry.npmjs.org",
+                String::new(),
+                codex_network_proxy::NetworkHttpRuleDecision::Deny,
+            ),
+        ]
+    );
+    assert_eq!(config.network.allowed_domains(), None);
+}
@@ -0,0 +1,391 @@
+//! Redaction of secrets from text the session records or shares.
+//!
+//! Every session builds an [`OutputRedactor`] from the `[redaction]` config
+//! table, seeded with the values of sensitive environment variables and of
+//! the secrets stored with `code secrets`. Command output, MCP tool results,
+//! hook output and rollout items pass through it before they reach the
+//! conversation history or disk. Each redaction is counted in the
+//! `codex.redaction.hits` metric, tagged by where the text came from and which
+//! detector matched.
+
+use codex_config::types::RedactionConfig;
+use codex_otel::REDACTION_HIT_METRIC;
+use codex_protocol::error::CodexErr;
+use codex_protocol::error::SandboxErr;
+use codex_protocol::exec_output::ExecToolCallOutput;
+use codex_protocol::mcp::CallToolResult;
+use codex_protocol::protocol::RolloutItem;
+use codex_rollout::EventPersistenceMode;
+use codex_rollout::is_persisted_rollout_item;
+use codex_secrets::EntropyRule;
+use codex_secrets::OutputRedactor;
+use codex_secrets::RedactionHits;
+use codex_secrets::RedactionRules;
+use codex_secrets::SecretsBackendKind;
+use codex_secrets::SecretsManager;
+use serde_json::Value as JsonValue;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::session::session::Session;
+use crate::tools::context::ExecCommandToolOutput;
+use crate::tools::sandboxing::ToolError;
+
+/// Environment variable values shorter than this are too likely to occur in
+/// ordinary output (`1`, `true`, a port number) to be redacted verbatim.
+const MIN_ENV_VALUE_LEN: usize = 8;
+
+/// Longest trailing token [`RedactionCarryOver`] holds back for the next
+/// chunk. Longer tokens are released so output without whitespace still
+/// streams.
+const MAX_CARRY_OVER_BYTES: usize = 4096;
+
+/// JSON keys whose string values are identifiers or encoded payloads rather
+/// than text. Redacting them would break resuming a rollout.
+const PRESERVED_JSON_KEYS: &[&str] = &[
+    "id",
+    "call_id",
+    "turn_id",
+    "thread_id",
+    "encrypted_content",
+    "image_url",
+    "data",
+    "blob",
+];
+
+/// Where redacted text came from; the `source` tag of the hit metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RedactionSource {
+    CommandOutput,
+    McpToolResult,
+    HookOutput,
+    Rollout,
+}
+
+impl RedactionSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CommandOutput => "command_output",
+            Self::McpToolResult => "mcp_tool_result",
+            Self::HookOutput => "hook_output",
+            Self::Rollout => "rollout",
+        }
+    }
+}
+
+/// Builds the redactor described by `config`, including the values of
+/// matching environment variables and, unless disabled, stored secrets.
+pub fn output_redactor_from_config(config: &Config) -> OutputRedactor {
+    let secrets_manager = config
+        .redaction
+        .stored_secrets
+        .then(|| SecretsManager::new(config.codex_home.to_path_buf(), SecretsBackendKind::Local));
+    build_output_redactor(
+        &config.redaction,
+        secrets_manager.as_ref(),
+        std::env::vars(),
+    )
+}
+
+fn build_output_redactor(
+    redaction: &RedactionConfig,
+    secrets_manager: Option<&SecretsManager>,
+    env: impl IntoIterator<Item = (String, String)>,
+) -> OutputRedactor {
+    let rules = RedactionRules {
+        builtin_patterns: redaction.builtin_patterns,
+        custom_patterns: redaction.patterns.clone(),
+        entropy: redaction.entropy.as_ref().map(|entropy| EntropyRule {
+            min_length: entropy.min_length,
+            threshold: entropy.threshold,
+        }),
+    };
+    // Patterns are validated when the config is loaded, so this only fails
+    // for configs built by hand.
+    let mut redactor = OutputRedactor::new(&rules).unwrap_or_else(|err| {
+        warn!("ignoring invalid redaction rules: {err:#}");
+        OutputRedactor::default()
+    });
+
+    for (name, value) in env {
+        if value.len() >= MIN_ENV_VALUE_LEN
+            && redaction
+                .env_vars
+                .iter()
+                .any(|pattern| pattern.matches(&name))
+        {
+            redactor.add_known_value(&value);
+        }
+    }
+
+    if let Some(secrets_manager) = secrets_manager {
+        add_stored_secrets(&mut redactor, secrets_manager);
+    }
+    redactor
+}
+
+fn add_stored_secrets(redactor: &mut OutputRedactor, secrets_manager: &SecretsManager) {
+    let entries = match secrets_manager.list(/*scope_filter*/ None) {
+        Ok(entries) => entries,
+        Err(err) => {
+            warn!("failed to list stored secrets for redaction: {err:#}");
+            return;
+        }
+    };
+    for entry in entries {
+        match secrets_manager.get(&entry.scope, &entry.name) {
+            Ok(Some(value)) => redactor.add_known_value(&value),
+            Ok(None) => {}
+            Err(err) => warn!(
+                "failed to read stored secret `{}` for redaction: {err:#}",
+                entry.name
+            ),
+        }
+    }
+}
+
+/// Redacts `text` with the session's redactor and records any hits.
+pub(crate) fn redact_text(session: &Session, source: RedactionSource, text: &str) -> String {
+    let (redacted, hits) = {
+        let redactor = session
+            .services
+            .output_redactor
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        redactor.redact(text)
+    };
+    record_redaction_hits(session, source, &hits);
+    redacted
+}
+
+fn record_redaction_hits(session: &Session, source: RedactionSource, hits: &RedactionHits) {
+    for (kind, count) in hits.iter() {
+        session.services.session_telemetry.counter(
+            REDACTION_HIT_METRIC,
+            i64::try_from(count).unwrap_or(i64::MAX),
+            &[("source", source.as_str()), ("kind", kind.as_str())],
+        );
+    }
+}
+
+/// Redacts every stream of an exec result.
+pub(crate) fn redact_exec_output(session: &Session, output: &mut ExecToolCallOutput) {
+    for stream in [
+        &mut output.stdout,
+        &mut output.stderr,
+        &mut output.aggregated_output,
+    ] {
+        stream.text = redact_text(session, RedactionSource::CommandOutput, &stream.text);
+    }
+}
+
+/// Redacts command output that arrives in chunks, such as the deltas and
+/// polls of a unified exec process.
+///
+/// A secret split across two chunks would slip past redaction if each chunk
+/// were redacted alone. The trailing token of each chunk may continue in the
+/// next one, so it is held back and redacted together with that chunk.
+#[derive(Debug, Default)]
+pub(crate) struct RedactionCarryOver {
+    pending: String,
+}
+
+impl RedactionCarryOver {
+    /// Redacts `text` together with the held-back tail and returns what can
+    /// be released. With `flush`, nothing is held back.
+    fn redact(
+        &mut self,
+        redactor: &OutputRedactor,
+        text: &str,
+        flush: bool,
+    ) -> (String, RedactionHits) {
+        self.pending.push_str(text);
+        let split = if flush || redactor.is_noop() {
+            self.pending.len()
+        } else {
+            carry_over_start(&self.pending)
+        };
+        let tail = self.pending.split_off(split);
+        let ready = std::mem::replace(&mut self.pending, tail);
+        if ready.is_empty() {
+            return (ready, RedactionHits::default());
+        }
+        redactor.redact(&ready)
+    }
+}
+
+/// Returns where the trailing token of `text` starts, or the end of `text`
+/// when it ends in whitespace or the token is too long to hold back.
+fn carry_over_start(text: &str) -> usize {
+    let start = text
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_whitespace())
+        .map_or(0, |(index, ch)| index + ch.len_utf8());
+    if text.len() - start > MAX_CARRY_OVER_BYTES {
+        text.len()
+    } else {
+        start
+    }
+}
+
+/// Redacts the next chunk of streamed command output and records any hits.
+pub(crate) fn redact_output_chunk(
+    session: &Session,
+    carry_over: &mut RedactionCarryOver,
+    text: &str,
+    flush: bool,
+) -> String {
+    let (redacted, hits) = {
+        let redactor = session
+            .services
+            .output_redactor
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        carry_over.redact(&redactor, text, flush)
+    };
+    record_redaction_hits(session, RedactionSource::CommandOutput, &hits);
+    redacted
+}
+
+/// Redacts the output of a unified exec poll. The tail held back from one
+/// poll is returned with the next, and released once the process has exited.
+pub(crate) fn redact_unified_exec_output(
+    session: &Session,
+    carry_over: &mut RedactionCarryOver,
+    output: &mut ExecCommandToolOutput,
+) {
+    let text = String::from_utf8_lossy(&output.raw_output);
+    let flush = output.process_id.is_none();
+    let redacted = redact_output_chunk(session, carry_over, &text, flush);
+    if redacted != text {
+        output.raw_output = redacted.into_bytes();
+    }
+}
+
+/// Redacts an exec result, including the output carried by sandbox failures.
+pub(crate) fn redact_exec_result(
+    session: &Session,
+    mut result: Result<ExecToolCallOutput, ToolError>,
+) -> Result<ExecToolCallOutput, ToolError> {
+    match &mut result {
+        Ok(output) => redact_exec_output(session, output),
+        Err(ToolError::Codex(CodexErr::Sandbox(
+            SandboxErr::Denied { output, .. }
+            | SandboxErr::Timeout { output }
+            | SandboxErr::ResourceLimitExceeded { output },
+        ))) => redact_exec_output(session, output),
+        Err(_) => {}
+    }
+    result
+}
+
+/// Redacts the text content and structured content of an MCP tool result.
+/// Images and embedded resource blobs are left untouched.
+pub(crate) fn redact_mcp_tool_result(session: &Session, result: &mut CallToolResult) {
+    let mut hits = RedactionHits::default();
+    {
+        let redactor = session
+            .services
+            .output_redactor
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if redactor.is_noop() {
+            return;
+        }
+        for content in &mut result.content {
+            redact_json_strings(&redactor, content, &mut hits);
+        }
+        if let Some(structured_content) = &mut result.structured_content {
+            redact_json_strings(&redactor, structured_content, &mut hits);
+        }
+    }
+    record_redaction_hits(session, RedactionSource::McpToolResult, &hits);
+}
+
+/// Redacts rollout items before they are written. Items that are never
+/// persisted are passed through unchanged.
+pub(crate) fn redact_rollout_items(session: &Session, items: &[RolloutItem]) -> Vec<RolloutItem> {
+    let mut hits = RedactionHits::default();
+    let redacted = {
+        let redactor = session
+            .services
+            .output_redactor
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if redactor.is_noop() {
+            return items.to_vec();
+        }
+        items
+            .iter()
+            .map(|item| redact_rollout_item(&redactor, item, &mut hits))
+            .collect()
+    };
+    record_redaction_hits(session, RedactionSource::Rollout, &hits);
+    redacted
+}
+
+fn redact_rollout_item(
+    redactor: &OutputRedactor,
+    item: &RolloutItem,
+    hits: &mut RedactionHits,
+) -> RolloutItem {
+    if !is_persisted_rollout_item(item, EventPersistenceMode::Extended) {
+        return item.clone();
+    }
+    let mut value = match serde_json::to_value(item) {
+        Ok(value) => value,
+        Err(err) => {
+            warn!("failed to serialize rollout item for redaction: {err}");
+            return item.clone();
+        }
+    };
+    let mut item_hits = RedactionHits::default();
+    redact_json_strings(redactor, &mut value, &mut item_hits);
+    if item_hits.is_empty() {
+        return item.clone();
+    }
+    match serde_json::from_value(value) {
+        Ok(redacted) => {
+            hits.merge(item_hits);
+            redacted
+        }
+        Err(err) => {
+            warn!("failed to restore redacted rollout item: {err}");
+            item.clone()
+        }
+    }
+}
+
+fn redact_json_strings(redactor: &OutputRedactor, value: &mut JsonValue, hits: &mut RedactionHits) {
+    match value {
+        JsonValue::String(text) => {
+            // Inline images and other data URLs are encoded payloads.
+            if text.starts_with("data:") {
+                return;
+            }
+            let (redacted, found) = redactor.redact(text);
+            if !found.is_empty() {
+                *text = redacted;
+                hits.merge(found);
+            }
+        }
+        JsonValue::Array(values) => {
+            for value in values {
+                redact_json_strings(redactor, value, hits);
+            }
+        }
+        JsonValue::Object(map) => {
+            for (key, value) in map {
+                if !PRESERVED_JSON_KEYS.contains(&key.as_str()) {
+                    redact_json_strings(redactor, value, hits);
+                }
+            }
+        }
+        JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "redaction_tests.rs"]
+mod tests;
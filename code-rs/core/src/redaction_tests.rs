@@ -0,0 +1,125 @@
+use super::*;
+use codex_config::types::EntropyRedactionConfig;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use pretty_assertions::assert_eq;
+
+fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+        .collect()
+}
+
+#[test]
+fn build_output_redactor_adds_matching_env_values() {
+    let redactor = build_output_redactor(
+        &RedactionConfig::default(),
+        /*secrets_manager*/ None,
+        env(&[
+            ("DEPLOY_TOKEN", "tok-7f3a9c21e5"),
+            ("GITHUB_API_KEY", "short"),
+            ("HOME", "/home/someone/projects"),
+        ]),
+    );
+
+    let (redacted, hits) =
+        redactor.redact("deploy tok-7f3a9c21e5 from /home/someone/projects with key short");
+
+    assert_eq!(
+        redacted,
+        "deploy [REDACTED_SECRET] from /home/someone/projects with key short"
+    );
+    assert_eq!(hits.total(), 1);
+}
+
+#[test]
+fn rollout_redaction_keeps_ids_and_images() {
+    let token = "R8x2Lq9ZtV4mK7cWn3PbY6sJd1FhUe5GoA0i";
+    let image_url = format!("data:image/png;base64,{token}");
+    let redaction = RedactionConfig {
+        entropy: Some(EntropyRedactionConfig {
+            min_length: 20,
+            threshold: 4.0,
+        }),
+        ..RedactionConfig::default()
+    };
+    let redactor = build_output_redactor(&redaction, /*secrets_manager*/ None, Vec::new());
+    let item = RolloutItem::ResponseItem(ResponseItem::Message {
+        id: Some(format!("msg_{token}")),
+        role: "user".to_string(),
+        content: vec![
+            ContentItem::InputText {
+                text: format!("the token is {token}"),
+            },
+            ContentItem::InputImage {
+                image_url: image_url.clone(),
+                detail: None,
+            },
+        ],
+        phase: None,
+    });
+
+    let mut hits = RedactionHits::default();
+    let redacted = redact_rollout_item(&redactor, &item, &mut hits);
+
+    let expected = RolloutItem::ResponseItem(ResponseItem::Message {
+        id: Some(format!("msg_{token}")),
+        role: "user".to_string(),
+        content: vec![
+            ContentItem::InputText {
+                text: "the token is [REDACTED_SECRET]".to_string(),
+            },
+            ContentItem::InputImage {
+                image_url,
+                detail: None,
+            },
+        ],
+        phase: None,
+    });
+    assert_eq!(
+        serde_json::to_value(redacted).expect("serialize redacted item"),
+        serde_json::to_value(expected).expect("serialize expected item")
+    );
+    assert_eq!(hits.total(), 1);
+}
+
+#[test]
+fn carry_over_redacts_secrets_split_across_chunks() {
+    let redactor = build_output_redactor(
+        &RedactionConfig::default(),
+        /*secrets_manager*/ None,
+        env(&[("DEPLOY_TOKEN", "tok-7f3a9c21e5")]),
+    );
+    let mut carry_over = RedactionCarryOver::default();
+
+    let (first, _) = carry_over.redact(&redactor, "deploy tok-7f3a", /*flush*/ false);
+    let (second, hits) = carry_over.redact(&redactor, "9c21e5 done\nnext", /*flush*/ false);
+    let (last, _) = carry_over.redact(&redactor, "", /*flush*/ true);
+
+    assert_eq!(first, "deploy ");
+    assert_eq!(second, "[REDACTED_SECRET] done\n");
+    assert_eq!(hits.total(), 1);
+    assert_eq!(last, "next");
+}
+
+#[test]
+fn carry_over_releases_output_when_nothing_can_be_redacted() {
+    let mut carry_over = RedactionCarryOver::default();
+
+    let (released, _) = carry_over.redact(
+        &OutputRedactor::default(),
+        "partial tok-7f3a",
+        /*flush*/ false,
+    );
+
+    assert_eq!(released, "partial tok-7f3a");
+}
+
+#[test]
+fn carry_over_start_skips_tokens_too_long_to_hold_back() {
+    let long_token = "a".repeat(MAX_CARRY_OVER_BYTES + 1);
+
+    assert_eq!(carry_over_start("prompt> "), "prompt> ".len());
+    assert_eq!(carry_over_start("deploy tok-7f3a"), "deploy ".len());
+    assert_eq!(carry_over_start(&long_token), long_token.len());
+}
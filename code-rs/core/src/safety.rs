@@ -171,7 +171,11 @@ fn is_write_patch_constrained_to_writable_paths(
 
     for (path, change) in action.changes() {
         match change {
-            ApplyPatchFileChange::Add { .. } | ApplyPatchFileChange::Delete { .. } => {
+            ApplyPatchFileChange::Add { .. }
+            | ApplyPatchFileChange::Delete { .. }
+            | ApplyPatchFileChange::SetMode { .. }
+            | ApplyPatchFileChange::AddSymlink { .. }
+            | ApplyPatchFileChange::AddBinary { .. } => {
                 if !is_path_writable(path) {
                     return false;
                 }
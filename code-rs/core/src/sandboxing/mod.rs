@@ -22,10 +22,12 @@ use codex_protocol::models::PermissionProfile;
 pub use codex_protocol::models::SandboxPermissions;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_sandboxing::SandboxExecRequest;
 use codex_sandboxing::SandboxType;
 use codex_sandboxing::compatibility_sandbox_policy_for_permission_profile;
+use codex_sandboxing::landlock::SandboxReport;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashMap;
 
@@ -59,6 +61,10 @@ pub struct ExecRequest {
     pub network_sandbox_policy: NetworkSandboxPolicy,
     pub(crate) windows_sandbox_filesystem_overrides: Option<WindowsSandboxFilesystemOverrides>,
     pub arg0: Option<String>,
+    /// Limits the Linux sandbox helper enforces for this command.
+    pub resource_limits: ResourceLimits,
+    /// Report the Linux sandbox helper records limit kills in.
+    pub sandbox_report: Option<SandboxReport>,
 }
 
 impl ExecRequest {
@@ -96,6 +102,8 @@ impl ExecRequest {
             network_sandbox_policy,
             windows_sandbox_filesystem_overrides: None,
             arg0,
+            resource_limits: ResourceLimits::default(),
+            sandbox_report: None,
         }
     }
 
@@ -125,6 +133,8 @@ impl ExecRequest {
             file_system_sandbox_policy,
             network_sandbox_policy,
             arg0,
+            resource_limits,
+            sandbox_report,
         } = request;
         let ExecOptions {
             expiration,
@@ -157,6 +167,8 @@ impl ExecRequest {
             network_sandbox_policy,
             windows_sandbox_filesystem_overrides: None,
             arg0,
+            resource_limits,
+            sandbox_report,
         }
     }
 }
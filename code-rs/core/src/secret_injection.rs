@@ -0,0 +1,189 @@
+//! `$secret:NAME` references in commands run by the shell and unified exec
+//! tools.
+//!
+//! References are resolved against the session's secrets store right before
+//! a command is spawned. Only commands run through a shell (`bash -lc`,
+//! `sh -c`) may reference secrets: each reference in the script is rewritten
+//! into a `${CODE_SECRET_NAME}` shell expansion and the value is added to the
+//! child's environment under that name, so the value never appears in the
+//! command the model wrote and cannot replace a variable like `PATH`. Injected
+//! values are added to the session's output redactor (see
+//! [`crate::redaction`]) for the rest of the session.
+//!
+//! The same references in `network.credentials` values are resolved by
+//! [`ProxyCredentialResolver`] when the network proxy injects them into a
+//! request.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use codex_network_proxy::CredentialResolver;
+use codex_secrets::SecretName;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+use codex_secrets::expand_secret_references;
+use codex_secrets::secret_env_var_name;
+use codex_secrets::secret_references;
+use codex_secrets::substitute_secret_references;
+use codex_shell_command::bash::extract_bash_command;
+
+use crate::session::session::Session;
+
+/// Secret values referenced by a command, keyed by secret name.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub(crate) struct SecretEnv(HashMap<SecretName, String>);
+
+impl SecretEnv {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Rewrites the secret references in the script of a shell-wrapped
+    /// `command` into environment expansions; the command to actually spawn.
+    pub(crate) fn rewrite_command(&self, command: &[String]) -> Vec<String> {
+        match command {
+            [shell, flag, script] if !self.is_empty() => vec![
+                shell.clone(),
+                flag.clone(),
+                expand_secret_references(script),
+            ],
+            _ => command.to_vec(),
+        }
+    }
+
+    /// Adds the secrets to `env` under their `CODE_SECRET_` names and records
+    /// them as explicit overrides so a shell snapshot cannot clobber them.
+    pub(crate) fn apply(
+        &self,
+        env: &mut HashMap<String, String>,
+        explicit_env_overrides: &mut HashMap<String, String>,
+    ) {
+        for (name, value) in &self.0 {
+            let env_var = secret_env_var_name(name);
+            env.insert(env_var.clone(), value.clone());
+            explicit_env_overrides.insert(env_var, value.clone());
+        }
+    }
+}
+
+impl fmt::Debug for SecretEnv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.0.keys().map(SecretName::as_str).collect::<Vec<_>>();
+        names.sort();
+        f.debug_tuple("SecretEnv").field(&names).finish()
+    }
+}
+
+/// Resolves the `$secret:NAME` references in `command`, which runs in `cwd`.
+///
+/// Commands without references never touch the secrets store. References are
+/// only allowed in the script of a shell-wrapped command, since nothing else
+/// expands the rewritten `${CODE_SECRET_NAME}`. The error is a message meant
+/// for the model.
+pub(crate) fn inject_command_secrets(
+    session: &Session,
+    cwd: &Path,
+    command: &[String],
+) -> Result<SecretEnv, String> {
+    let mut environment_id = None;
+    let secret_env = resolve_secret_env(command, |name| {
+        let environment_id = environment_id.get_or_insert_with(|| environment_id_from_cwd(cwd));
+        session
+            .services
+            .secrets_manager
+            .resolve(environment_id, name)
+    })?;
+    if !secret_env.is_empty() {
+        let mut redactor = session
+            .services
+            .output_redactor
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for value in secret_env.0.values() {
+            redactor.add_known_value(value);
+        }
+    }
+    Ok(secret_env)
+}
+
+fn resolve_secret_env(
+    command: &[String],
+    mut resolve: impl FnMut(&SecretName) -> anyhow::Result<Option<String>>,
+) -> Result<SecretEnv, String> {
+    if !command.iter().any(|arg| !secret_references(arg).is_empty()) {
+        return Ok(SecretEnv::default());
+    }
+    let Some((_, script)) = extract_bash_command(command) else {
+        return Err(
+            "`$secret:NAME` references are only supported in commands run through a shell, \
+             such as `bash -lc \"curl -H 'Authorization: Bearer $secret:API_TOKEN' ...\"`"
+                .to_string(),
+        );
+    };
+
+    let mut names = Vec::new();
+    for name in secret_references(script) {
+        if name.as_str().starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(format!(
+                "secret name `{name}` is not a valid identifier; secret names must not start with a digit"
+            ));
+        }
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut env = HashMap::with_capacity(names.len());
+    for name in names {
+        match resolve(&name) {
+            Ok(Some(value)) => {
+                env.insert(name, value);
+            }
+            Ok(None) => {
+                return Err(format!(
+                    "secret `{name}` is not set; ask the user to add it with `code secrets set {name}`"
+                ));
+            }
+            Err(err) => return Err(format!("failed to read secret `{name}`: {err:#}")),
+        }
+    }
+    Ok(SecretEnv(env))
+}
+
+/// Resolves `$secret:NAME` references in network proxy credentials against
+/// the secrets store, scoped to the session's working directory.
+pub(crate) struct ProxyCredentialResolver {
+    secrets_manager: SecretsManager,
+    environment_id: String,
+}
+
+impl ProxyCredentialResolver {
+    pub(crate) fn new(secrets_manager: SecretsManager, cwd: &Path) -> Self {
+        Self {
+            secrets_manager,
+            environment_id: environment_id_from_cwd(cwd),
+        }
+    }
+}
+
+impl CredentialResolver for ProxyCredentialResolver {
+    fn resolve(&self, value: &str) -> anyhow::Result<String> {
+        resolve_credential_value(value, |name| {
+            self.secrets_manager.resolve(&self.environment_id, name)
+        })
+    }
+}
+
+fn resolve_credential_value(
+    value: &str,
+    mut resolve: impl FnMut(&SecretName) -> anyhow::Result<Option<String>>,
+) -> anyhow::Result<String> {
+    substitute_secret_references(value, |name| {
+        resolve(name)?.ok_or_else(|| anyhow::anyhow!("secret `{name}` is not set"))
+    })
+}
+
+#[cfg(test)]
+#[path = "secret_injection_tests.rs"]
+mod tests;
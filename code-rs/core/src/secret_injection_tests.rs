@@ -0,0 +1,108 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn command(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| (*arg).to_string()).collect()
+}
+
+#[test]
+fn resolve_secret_env_rewrites_references_into_env_expansions() {
+    let command = command(&[
+        "bash",
+        "-lc",
+        "curl -H \"Authorization: $secret:API_TOKEN\" -u $secret:USER:$secret:API_TOKEN",
+    ]);
+    let mut resolved = Vec::new();
+    let secret_env = resolve_secret_env(&command, |name| {
+        resolved.push(name.to_string());
+        Ok(Some(format!("value-of-{name}")))
+    })
+    .expect("secrets resolve");
+
+    assert_eq!(resolved, vec!["API_TOKEN".to_string(), "USER".to_string()]);
+    assert_eq!(
+        secret_env.rewrite_command(&command),
+        vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "curl -H \"Authorization: ${CODE_SECRET_API_TOKEN}\" -u ${CODE_SECRET_USER}:${CODE_SECRET_API_TOKEN}".to_string(),
+        ]
+    );
+
+    let mut env = HashMap::new();
+    let mut explicit_env_overrides = HashMap::new();
+    secret_env.apply(&mut env, &mut explicit_env_overrides);
+    assert_eq!(
+        env.get("CODE_SECRET_API_TOKEN").map(String::as_str),
+        Some("value-of-API_TOKEN")
+    );
+    assert_eq!(env, explicit_env_overrides);
+    assert_eq!(
+        format!("{secret_env:?}"),
+        "SecretEnv([\"API_TOKEN\", \"USER\"])"
+    );
+}
+
+#[test]
+fn resolve_secret_env_leaves_commands_without_references_alone() {
+    let command = command(&["echo", "$HOME"]);
+    let secret_env = resolve_secret_env(&command, |_| panic!("no secret should be resolved"))
+        .expect("nothing to resolve");
+
+    assert!(secret_env.is_empty());
+    assert_eq!(secret_env.rewrite_command(&command), command);
+}
+
+#[test]
+fn resolve_secret_env_reports_missing_secrets() {
+    let err = resolve_secret_env(&command(&["bash", "-lc", "echo $secret:MISSING"]), |_| {
+        Ok(None)
+    })
+    .expect_err("missing secret should fail");
+
+    assert_eq!(
+        err,
+        "secret `MISSING` is not set; ask the user to add it with `code secrets set MISSING`"
+    );
+}
+
+#[test]
+fn resolve_secret_env_rejects_references_outside_a_shell_script() {
+    let err = resolve_secret_env(&command(&["curl", "-u", "$secret:API_TOKEN"]), |_| {
+        panic!("no secret should be resolved")
+    })
+    .expect_err("direct argv should be rejected");
+
+    assert!(
+        err.starts_with(
+            "`$secret:NAME` references are only supported in commands run through a shell"
+        ),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn resolve_secret_env_rejects_names_that_are_not_identifiers() {
+    let err = resolve_secret_env(&command(&["bash", "-lc", "echo $secret:1TOKEN"]), |_| {
+        panic!("no secret should be resolved")
+    })
+    .expect_err("leading digit should be rejected");
+
+    assert_eq!(
+        err,
+        "secret name `1TOKEN` is not a valid identifier; secret names must not start with a digit"
+    );
+}
+
+#[test]
+fn resolve_credential_value_substitutes_secrets() {
+    let value = resolve_credential_value("Bearer $secret:NPM_TOKEN", |name| {
+        Ok(Some(format!("value-of-{name}")))
+    })
+    .expect("credential resolves");
+    assert_eq!(value, "Bearer value-of-NPM_TOKEN");
+
+    let err = resolve_credential_value("Bearer $secret:MISSING", |_| Ok(None))
+        .expect_err("missing secret should fail");
+    assert_eq!(err.to_string(), "secret `MISSING` is not set");
+}
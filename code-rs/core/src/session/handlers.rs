@@ -48,6 +48,7 @@ use codex_protocol::request_permissions::RequestPermissionsResponse;
 use codex_protocol::request_user_input::RequestUserInputResponse;
 
 use crate::context_manager::is_user_turn_boundary;
+use crate::hook_runtime::run_session_end_hooks;
 use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::ModeKind;
 use codex_protocol::config_types::Settings;
@@ -619,6 +620,8 @@ pub async fn set_thread_memory_mode(sess: &Arc<Session>, sub_id: String, mode: T
 
 pub async fn shutdown(sess: &Arc<Session>, sub_id: String) -> bool {
     sess.abort_all_tasks(TurnAbortReason::Interrupted).await;
+    let turn_context = sess.new_default_turn_with_sub_id(sub_id.clone()).await;
+    run_session_end_hooks(sess, &turn_context).await;
     let _ = sess.conversation.shutdown().await;
     sess.services
         .unified_exec_manager
@@ -17,7 +17,10 @@ use codex_protocol::mcp_approval_meta::TOOL_PARAMS_KEY as MCP_ELICITATION_TOOL_P
 use codex_protocol::mcp_approval_meta::TOOL_TITLE_KEY as MCP_ELICITATION_TOOL_TITLE_KEY;
 use rmcp::model::CreateElicitationRequestParams;
 use rmcp::model::ElicitationAction;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
 use rmcp::model::Meta;
+use rmcp::model::Prompt;
 use serde_json::Map;
 
 const MCP_ELICITATION_DECLINE_MESSAGE_KEY: &str = "message";
@@ -244,6 +247,37 @@ impl Session {
             .await
     }
 
+    #[expect(
+        clippy::await_holding_invalid_type,
+        reason = "MCP prompt calls are serialized through the session-owned manager guard"
+    )]
+    pub async fn list_all_prompts(&self) -> HashMap<String, Vec<Prompt>> {
+        self.services
+            .mcp_connection_manager
+            .read()
+            .await
+            .list_all_prompts()
+            .await
+    }
+
+    #[expect(
+        clippy::await_holding_invalid_type,
+        reason = "MCP prompt calls are serialized through the session-owned manager guard"
+    )]
+    pub async fn get_prompt(
+        &self,
+        server: &str,
+        name: &str,
+        arguments: Option<JsonObject>,
+    ) -> anyhow::Result<GetPromptResult> {
+        self.services
+            .mcp_connection_manager
+            .read()
+            .await
+            .get_prompt(server, name, arguments)
+            .await
+    }
+
     #[expect(
         clippy::await_holding_invalid_type,
         reason = "MCP tool calls are serialized through the session-owned manager guard"
@@ -317,6 +351,13 @@ impl Session {
             guard.cancel();
             *guard = CancellationToken::new();
         }
+        // Keep answering sampling requests through the session's handler.
+        let sampling_handler = self
+            .services
+            .mcp_connection_manager
+            .read()
+            .await
+            .sampling_handler();
         let (refreshed_manager, cancel_token) = McpConnectionManager::new(
             &mcp_servers,
             store_mode,
@@ -332,6 +373,7 @@ impl Session {
             tool_plugin_provenance,
             auth.as_ref(),
             elicitation_reviewer,
+            sampling_handler,
         )
         .await;
         {
@@ -0,0 +1,393 @@
+//! `sampling/createMessage` requests from MCP servers.
+//!
+//! A server may ask Codex to run a completion on its behalf. Each request is
+//! shown to the user as an approval elicitation on the active turn, then run as
+//! a one-shot call against the turn's model. The call's token usage is added to
+//! the thread total but not to the context window usage, since the sampled
+//! messages never enter the conversation history.
+//!
+//! `maxTokens` and `stopSequences` are enforced on the streamed text, which is
+//! cut at whichever is reached first and reported in `stopReason`. The turn's
+//! model and settings are always used, so `modelPreferences` and `temperature`
+//! are treated as hints and ignored.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Weak;
+
+use anyhow::Context;
+use anyhow::anyhow;
+use anyhow::bail;
+use codex_app_server_protocol::McpElicitationObjectType;
+use codex_app_server_protocol::McpElicitationSchema;
+use codex_app_server_protocol::McpServerElicitationRequest;
+use codex_app_server_protocol::McpServerElicitationRequestParams;
+use codex_async_utils::OrCancelExt;
+use codex_mcp::McpPermissionPromptAutoApproveContext;
+use codex_mcp::SamplingHandler;
+use codex_mcp::SamplingHandlerHandle;
+use codex_mcp::SamplingRequest;
+use codex_mcp::mcp_permission_prompt_is_auto_approved;
+use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
+use codex_protocol::mcp_approval_meta::APPROVAL_KIND_KEY;
+use codex_protocol::mcp_approval_meta::APPROVAL_KIND_MCP_SAMPLING;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::TokenUsage;
+use codex_rmcp_client::ElicitationAction;
+use codex_rollout_trace::InferenceTraceContext;
+use codex_utils_string::approx_bytes_for_tokens;
+use codex_utils_string::take_bytes_at_char_boundary;
+use futures::future::BoxFuture;
+use futures::prelude::*;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
+use serde_json::Value;
+use serde_json::json;
+
+use super::Session;
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::session::turn::get_last_assistant_message_from_turn;
+use crate::session::turn_context::TurnContext;
+
+const MCP_SAMPLING_REQUEST_ID_PREFIX: &str = "mcp_sampling";
+const MCP_SAMPLING_APPROVAL_PREVIEW_BYTES: usize = 1_000;
+
+struct SessionMcpSamplingHandler {
+    session: Weak<Session>,
+}
+
+impl SamplingHandler for SessionMcpSamplingHandler {
+    fn create_message(
+        &self,
+        request: SamplingRequest,
+    ) -> BoxFuture<'static, anyhow::Result<CreateMessageResult>> {
+        let session = self.session.clone();
+        Box::pin(async move {
+            let session = session
+                .upgrade()
+                .ok_or_else(|| anyhow!("session has shut down"))?;
+            run_mcp_sampling(session, request).await
+        })
+    }
+}
+
+impl Session {
+    pub(crate) fn mcp_sampling_handler(self: &Arc<Self>) -> SamplingHandlerHandle {
+        Arc::new(SessionMcpSamplingHandler {
+            session: Arc::downgrade(self),
+        })
+    }
+}
+
+async fn run_mcp_sampling(
+    session: Arc<Session>,
+    request: SamplingRequest,
+) -> anyhow::Result<CreateMessageResult> {
+    let Some((turn_context, cancellation_token)) =
+        session.active_turn_context_and_cancellation_token().await
+    else {
+        bail!("sampling requests are only accepted while a turn is running");
+    };
+    let prompt = sampling_prompt(&request.params)?;
+    let limits = sampling_limits(&request.params)?;
+
+    if !sampling_is_approved(&session, &turn_context, &request, &prompt).await {
+        bail!("user rejected the sampling request");
+    }
+
+    let output = stream_sampling_prompt(&session, &turn_context, &prompt, &limits)
+        .or_cancel(&cancellation_token)
+        .await
+        .map_err(|_| anyhow!("sampling request was cancelled"))??;
+    if let Some(token_usage) = output.token_usage.as_ref() {
+        session
+            .record_out_of_band_token_usage(&turn_context, token_usage)
+            .await;
+    }
+
+    serde_json::from_value(json!({
+        "role": "assistant",
+        "content": { "type": "text", "text": output.text },
+        "model": turn_context.model_info.slug,
+        "stopReason": output.stop_reason.as_str(),
+    }))
+    .context("failed to build sampling result")
+}
+
+async fn sampling_is_approved(
+    session: &Session,
+    turn_context: &TurnContext,
+    request: &SamplingRequest,
+    prompt: &Prompt,
+) -> bool {
+    let approval_policy = turn_context.approval_policy.value();
+    if mcp_permission_prompt_is_auto_approved(
+        approval_policy,
+        &turn_context.permission_profile(),
+        McpPermissionPromptAutoApproveContext::default(),
+    ) {
+        return true;
+    }
+    let prompts_allowed = match approval_policy {
+        AskForApproval::Never => false,
+        AskForApproval::Granular(granular_config) => granular_config.allows_mcp_elicitations(),
+        AskForApproval::OnFailure | AskForApproval::OnRequest | AskForApproval::UnlessTrusted => {
+            true
+        }
+    };
+    if !prompts_allowed {
+        return false;
+    }
+
+    let request_id = rmcp::model::RequestId::String(
+        format!("{MCP_SAMPLING_REQUEST_ID_PREFIX}_{}", request.request_id).into(),
+    );
+    let params = McpServerElicitationRequestParams {
+        thread_id: session.conversation_id.to_string(),
+        turn_id: Some(turn_context.sub_id.clone()),
+        server_name: request.server_name.clone(),
+        request: McpServerElicitationRequest::Form {
+            meta: Some(json!({ APPROVAL_KIND_KEY: APPROVAL_KIND_MCP_SAMPLING })),
+            message: sampling_approval_message(&request.server_name, prompt),
+            requested_schema: McpElicitationSchema {
+                schema_uri: None,
+                type_: McpElicitationObjectType::Object,
+                properties: BTreeMap::new(),
+                required: None,
+            },
+        },
+    };
+    session
+        .request_mcp_server_elicitation(turn_context, request_id, params)
+        .await
+        .is_some_and(|response| response.action == ElicitationAction::Accept)
+}
+
+fn sampling_approval_message(server_name: &str, prompt: &Prompt) -> String {
+    let text = prompt
+        .input
+        .iter()
+        .filter_map(|item| match item {
+            ResponseItem::Message { content, .. } => Some(content),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|content| match content {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                Some(text.as_str())
+            }
+            ContentItem::InputImage { .. } => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let preview = take_bytes_at_char_boundary(&text, MCP_SAMPLING_APPROVAL_PREVIEW_BYTES);
+    let ellipsis = if preview.len() < text.len() {
+        "…"
+    } else {
+        ""
+    };
+    format!("MCP server `{server_name}` wants to run a model request:\n\n{preview}{ellipsis}")
+}
+
+/// Convert MCP sampling messages into a model prompt.
+///
+/// Only text and image content are supported; images from the assistant role
+/// are rejected because the Responses API only accepts them as input.
+fn sampling_prompt(params: &CreateMessageRequestParams) -> anyhow::Result<Prompt> {
+    let params = serde_json::to_value(params).context("failed to serialize sampling request")?;
+    let mut input = Vec::new();
+    for message in params["messages"].as_array().into_iter().flatten() {
+        let role = message["role"].as_str().unwrap_or("user");
+        // Newer protocol versions allow a list of content blocks per message.
+        let blocks = match &message["content"] {
+            Value::Array(blocks) => blocks.iter().collect::<Vec<_>>(),
+            block => vec![block],
+        };
+        let content = blocks
+            .into_iter()
+            .map(|block| sampling_content_item(role, block))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        input.push(ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content,
+            phase: None,
+        });
+    }
+    if input.is_empty() {
+        bail!("sampling request has no messages");
+    }
+
+    Ok(Prompt {
+        input,
+        base_instructions: BaseInstructions {
+            text: params["systemPrompt"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        },
+        ..Default::default()
+    })
+}
+
+/// Why a sampling request stopped, as reported in `stopReason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SamplingStopReason {
+    EndTurn,
+    StopSequence,
+    MaxTokens,
+}
+
+impl SamplingStopReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::EndTurn => "endTurn",
+            Self::StopSequence => "stopSequence",
+            Self::MaxTokens => "maxTokens",
+        }
+    }
+}
+
+/// Limits a sampling request places on the generated text. Tokens are
+/// approximated from the text's length.
+#[derive(Debug, Default, PartialEq)]
+struct SamplingLimits {
+    max_tokens: Option<usize>,
+    stop_sequences: Vec<String>,
+}
+
+impl SamplingLimits {
+    /// Cuts `text` at the first stop sequence or at the token limit, whichever
+    /// comes first. Returns `None` when neither has been reached.
+    fn apply(&self, text: &str) -> Option<(String, SamplingStopReason)> {
+        let stop = self
+            .stop_sequences
+            .iter()
+            .filter_map(|sequence| text.find(sequence.as_str()))
+            .min();
+        let max_bytes = self.max_tokens.map(approx_bytes_for_tokens);
+        match (stop, max_bytes) {
+            (Some(stop), Some(max_bytes)) if max_bytes < stop => Some((
+                take_bytes_at_char_boundary(text, max_bytes).to_string(),
+                SamplingStopReason::MaxTokens,
+            )),
+            (Some(stop), _) => Some((text[..stop].to_string(), SamplingStopReason::StopSequence)),
+            (None, Some(max_bytes)) if text.len() > max_bytes => Some((
+                take_bytes_at_char_boundary(text, max_bytes).to_string(),
+                SamplingStopReason::MaxTokens,
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn sampling_limits(params: &CreateMessageRequestParams) -> anyhow::Result<SamplingLimits> {
+    let params = serde_json::to_value(params).context("failed to serialize sampling request")?;
+    let max_tokens = params["maxTokens"]
+        .as_u64()
+        .map(|max_tokens| usize::try_from(max_tokens).unwrap_or(usize::MAX));
+    let stop_sequences = params["stopSequences"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter(|sequence| !sequence.is_empty())
+        .map(str::to_string)
+        .collect();
+    Ok(SamplingLimits {
+        max_tokens,
+        stop_sequences,
+    })
+}
+
+fn sampling_content_item(role: &str, block: &Value) -> anyhow::Result<ContentItem> {
+    match (role, block["type"].as_str()) {
+        ("assistant", Some("text")) => Ok(ContentItem::OutputText {
+            text: block["text"].as_str().unwrap_or_default().to_string(),
+        }),
+        (_, Some("text")) => Ok(ContentItem::InputText {
+            text: block["text"].as_str().unwrap_or_default().to_string(),
+        }),
+        ("user", Some("image")) => {
+            let mime_type = block["mimeType"].as_str().unwrap_or("image/png");
+            let data = block["data"].as_str().unwrap_or_default();
+            Ok(ContentItem::InputImage {
+                image_url: format!("data:{mime_type};base64,{data}"),
+                detail: None,
+            })
+        }
+        (role, kind) => bail!(
+            "unsupported sampling content `{}` for role `{role}`",
+            kind.unwrap_or("unknown")
+        ),
+    }
+}
+
+/// Text a sampling request produced and why it stopped.
+struct SamplingOutput {
+    text: String,
+    stop_reason: SamplingStopReason,
+    token_usage: Option<TokenUsage>,
+}
+
+async fn stream_sampling_prompt(
+    session: &Session,
+    turn_context: &TurnContext,
+    prompt: &Prompt,
+    limits: &SamplingLimits,
+) -> anyhow::Result<SamplingOutput> {
+    let mut client_session = session.services.model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            prompt,
+            &turn_context.model_info,
+            &turn_context.session_telemetry,
+            turn_context.reasoning_effort,
+            ReasoningSummaryConfig::None,
+            turn_context.config.service_tier.clone(),
+            /*turn_metadata_header*/ None,
+            &InferenceTraceContext::disabled(),
+        )
+        .await?;
+    let mut items = Vec::new();
+    let mut streamed = String::new();
+    let token_usage = loop {
+        let Some(event) = stream.next().await else {
+            bail!("stream closed before response.completed");
+        };
+        match event? {
+            ResponseEvent::OutputTextDelta(delta) => {
+                streamed.push_str(&delta);
+                // Returning drops the stream, which ends the model request.
+                if let Some((text, stop_reason)) = limits.apply(&streamed) {
+                    return Ok(SamplingOutput {
+                        text,
+                        stop_reason,
+                        token_usage: None,
+                    });
+                }
+            }
+            ResponseEvent::OutputItemDone(item) => items.push(item),
+            ResponseEvent::Completed { token_usage, .. } => break token_usage,
+            _ => {}
+        }
+    };
+    let text = get_last_assistant_message_from_turn(&items)
+        .ok_or_else(|| anyhow!("model returned no message for the sampling request"))?;
+    let (text, stop_reason) = limits
+        .apply(&text)
+        .unwrap_or((text, SamplingStopReason::EndTurn));
+    Ok(SamplingOutput {
+        text,
+        stop_reason,
+        token_usage,
+    })
+}
+
+#[cfg(test)]
+#[path = "mcp_sampling_tests.rs"]
+mod tests;
@@ -0,0 +1,127 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn params(value: Value) -> CreateMessageRequestParams {
+    serde_json::from_value(value).expect("sampling params should deserialize")
+}
+
+#[test]
+fn sampling_prompt_maps_roles_system_prompt_and_images() {
+    let prompt = sampling_prompt(&params(json!({
+        "messages": [
+            { "role": "user", "content": { "type": "text", "text": "Describe this." } },
+            {
+                "role": "user",
+                "content": { "type": "image", "data": "AAAA", "mimeType": "image/jpeg" },
+            },
+            { "role": "assistant", "content": { "type": "text", "text": "A square." } },
+        ],
+        "systemPrompt": "Be brief.",
+        "maxTokens": 32,
+    })))
+    .expect("prompt should convert");
+
+    assert_eq!(prompt.base_instructions.text, "Be brief.");
+    assert_eq!(
+        prompt.input,
+        vec![
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "Describe this.".to_string(),
+                }],
+                phase: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputImage {
+                    image_url: "data:image/jpeg;base64,AAAA".to_string(),
+                    detail: None,
+                }],
+                phase: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "A square.".to_string(),
+                }],
+                phase: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn sampling_prompt_rejects_audio_content() {
+    let err = sampling_prompt(&params(json!({
+        "messages": [{
+            "role": "user",
+            "content": { "type": "audio", "data": "AAAA", "mimeType": "audio/wav" },
+        }],
+        "maxTokens": 32,
+    })))
+    .expect_err("audio is not supported");
+
+    assert_eq!(
+        err.to_string(),
+        "unsupported sampling content `audio` for role `user`"
+    );
+}
+
+#[test]
+fn sampling_approval_message_truncates_long_prompts() {
+    let long_text = "x".repeat(MCP_SAMPLING_APPROVAL_PREVIEW_BYTES + 10);
+    let prompt = sampling_prompt(&params(json!({
+        "messages": [{ "role": "user", "content": { "type": "text", "text": long_text } }],
+        "maxTokens": 32,
+    })))
+    .expect("prompt should convert");
+
+    assert_eq!(
+        sampling_approval_message("docs", &prompt),
+        format!(
+            "MCP server `docs` wants to run a model request:\n\n{}…",
+            "x".repeat(MCP_SAMPLING_APPROVAL_PREVIEW_BYTES)
+        )
+    );
+}
+
+#[test]
+fn sampling_limits_read_max_tokens_and_stop_sequences() {
+    let limits = sampling_limits(&params(json!({
+        "messages": [{ "role": "user", "content": { "type": "text", "text": "Count." } }],
+        "maxTokens": 32,
+        "stopSequences": ["\n\n", ""],
+        "temperature": 0.2,
+    })))
+    .expect("limits should convert");
+
+    assert_eq!(
+        limits,
+        SamplingLimits {
+            max_tokens: Some(32),
+            stop_sequences: vec!["\n\n".to_string()],
+        }
+    );
+}
+
+#[test]
+fn sampling_limits_cut_text_at_whichever_limit_comes_first() {
+    let limits = SamplingLimits {
+        max_tokens: Some(2),
+        stop_sequences: vec!["STOP".to_string()],
+    };
+
+    assert_eq!(limits.apply("one two"), None);
+    assert_eq!(
+        limits.apply("oneSTOP two"),
+        Some(("one".to_string(), SamplingStopReason::StopSequence))
+    );
+    assert_eq!(
+        limits.apply("one two three STOP"),
+        Some(("one two ".to_string(), SamplingStopReason::MaxTokens))
+    );
+}
@@ -32,9 +32,13 @@ use crate::context::PersonalitySpecInstructions;
 use crate::default_skill_metadata_budget;
 use crate::environment_selection::ResolvedTurnEnvironments;
 use crate::exec_policy::ExecPolicyManager;
+use crate::hook_agent::hook_agent_evaluator;
+use crate::hook_prompt::hook_prompt_evaluator;
+use crate::hook_runtime::spawn_notification_hooks;
 use crate::parse_turn_item;
 use crate::path_utils::normalize_for_native_workdir;
 use crate::realtime_conversation::RealtimeConversationManager;
+use crate::redaction::redact_rollout_items;
 use crate::session_prefix::format_subagent_notification_message;
 use crate::skills::SkillRenderSideEffects;
 use crate::skills_load_input_from_config;
@@ -55,8 +59,11 @@ use codex_exec_server::FileSystemSandboxContext;
 use codex_features::FEATURES;
 use codex_features::Feature;
 use codex_features::unstable_features_warning_event;
+use codex_hooks::HookAgentEvaluator;
+use codex_hooks::HookPromptEvaluator;
 use codex_hooks::Hooks;
 use codex_hooks::HooksConfig;
+use codex_hooks::NotificationType;
 use codex_login::AuthManager;
 use codex_login::CodexAuth;
 use codex_login::auth_env_telemetry::collect_auth_env_telemetry;
@@ -127,6 +134,7 @@ use codex_rollout_trace::ThreadStartedTraceMetadata;
 use codex_rollout_trace::ThreadTraceContext;
 use codex_sandboxing::policy_transforms::intersect_permission_profiles;
 use codex_shell_command::parse_command::parse_command;
+use codex_shell_command::parse_command::shlex_join;
 use codex_terminal_detection::user_agent;
 use codex_thread_store::CreateThreadParams;
 use codex_thread_store::LiveThread;
@@ -187,6 +195,7 @@ use codex_protocol::exec_output::StreamOutput;
 mod config_lock;
 mod handlers;
 mod mcp;
+mod mcp_sampling;
 mod multi_agents;
 mod review;
 mod rollout_reconstruction;
@@ -1430,6 +1439,13 @@ impl Session {
             config.as_ref(),
             self.services.plugins_manager.as_ref(),
             self.services.user_shell.as_ref(),
+            hook_prompt_evaluator(
+                self.services.model_client.clone(),
+                Arc::clone(&self.services.models_manager),
+                self.services.session_telemetry.clone(),
+                Arc::clone(&config),
+            ),
+            hook_agent_evaluator(Arc::clone(&self.services.hook_agent_session)),
         )
         .await;
 
@@ -1900,8 +1916,8 @@ impl Session {
         reason = "active turn checks and turn state updates must remain atomic"
     )]
     pub async fn request_command_approval(
-        &self,
-        turn_context: &TurnContext,
+        self: &Arc<Self>,
+        turn_context: &Arc<TurnContext>,
         call_id: String,
         approval_id: Option<String>,
         command: Vec<String>,
@@ -1932,6 +1948,10 @@ impl Session {
         }
 
         let parsed_cmd = parse_command(&command);
+        let notification_message = format!(
+            "Codex needs your approval to run `{}`",
+            shlex_join(&command)
+        );
         let proposed_network_policy_amendments = network_approval_context.as_ref().map(|context| {
             vec![
                 NetworkPolicyAmendment {
@@ -1968,6 +1988,12 @@ impl Session {
             parsed_cmd,
         });
         self.send_event(turn_context, event).await;
+        spawn_notification_hooks(
+            self,
+            turn_context,
+            NotificationType::PermissionPrompt,
+            notification_message,
+        );
         rx_approve.await.unwrap_or(ReviewDecision::Abort)
     }
 
@@ -1976,8 +2002,8 @@ impl Session {
         reason = "active turn checks and turn state updates must remain atomic"
     )]
     pub async fn request_patch_approval(
-        &self,
-        turn_context: &TurnContext,
+        self: &Arc<Self>,
+        turn_context: &Arc<TurnContext>,
         call_id: String,
         changes: HashMap<PathBuf, FileChange>,
         reason: Option<String>,
@@ -2009,6 +2035,12 @@ impl Session {
             grant_root,
         });
         self.send_event(turn_context, event).await;
+        spawn_notification_hooks(
+            self,
+            turn_context,
+            NotificationType::PermissionPrompt,
+            "Codex needs your approval to apply a patch".to_string(),
+        );
         rx_approve
     }
 
@@ -2173,6 +2205,12 @@ impl Session {
             cwd: Some(cwd),
         });
         self.send_event(turn_context.as_ref(), event).await;
+        spawn_notification_hooks(
+            self,
+            turn_context,
+            NotificationType::PermissionPrompt,
+            "Codex needs your approval for additional permissions".to_string(),
+        );
         tokio::select! {
             biased;
             _ = cancellation_token.cancelled() => {
@@ -2794,7 +2832,9 @@ impl Session {
 
     pub(crate) async fn persist_rollout_items(&self, items: &[RolloutItem]) {
         if let Some(live_thread) = self.live_thread()
-            && let Err(e) = live_thread.append_items(items).await
+            && let Err(e) = live_thread
+                .append_items(&redact_rollout_items(self, items))
+                .await
         {
             error!("failed to record rollout items: {e:#}");
         }
@@ -2867,6 +2907,27 @@ impl Session {
         self.send_token_count_event(turn_context).await;
     }
 
+    /// Add usage from a model call made outside the conversation, such as an
+    /// MCP sampling request, to the thread total. The last-call usage is left
+    /// alone because it describes how full the conversation's context is.
+    pub(crate) async fn record_out_of_band_token_usage(
+        &self,
+        turn_context: &TurnContext,
+        token_usage: &TokenUsage,
+    ) {
+        {
+            let mut state = self.state.lock().await;
+            let mut info = state.token_info().unwrap_or(TokenUsageInfo {
+                total_token_usage: TokenUsage::default(),
+                last_token_usage: TokenUsage::default(),
+                model_context_window: turn_context.model_context_window(),
+            });
+            info.total_token_usage.add_assign(token_usage);
+            state.set_token_info(Some(info));
+        }
+        self.send_token_count_event(turn_context).await;
+    }
+
     pub(crate) async fn recompute_token_usage(&self, turn_context: &TurnContext) {
         let history = self.clone_history().await;
         let base_instructions = self.get_base_instructions().await;
@@ -3345,6 +3406,8 @@ async fn build_hooks_for_config(
     config: &Config,
     plugins_manager: &PluginsManager,
     user_shell: &crate::shell::Shell,
+    prompt_evaluator: HookPromptEvaluator,
+    agent_evaluator: HookAgentEvaluator,
 ) -> Hooks {
     let mut hook_shell_argv = user_shell.derive_exec_args("", /*use_login_shell*/ false);
     let hook_shell_program = hook_shell_argv.remove(0);
@@ -3368,6 +3431,8 @@ async fn build_hooks_for_config(
         plugin_hook_load_warnings,
         shell_program: Some(hook_shell_program),
         shell_args: hook_shell_argv,
+        prompt_evaluator: Some(prompt_evaluator),
+        agent_evaluator: Some(agent_evaluator),
     })
 }
 
@@ -1,11 +1,17 @@
 use super::*;
 use crate::goals::GoalRuntimeState;
+use crate::redaction::output_redactor_from_config;
+use crate::secret_injection::ProxyCredentialResolver;
+use codex_network_proxy::NETWORK_TRAFFIC_LOG_DIR;
+use codex_network_proxy::NetworkTrafficLog;
 use codex_protocol::SessionId;
 use codex_protocol::config_types::ServiceTier;
 use codex_protocol::permissions::FileSystemPath;
 use codex_protocol::permissions::FileSystemSpecialPath;
 use codex_protocol::protocol::ThreadSource;
 use codex_protocol::protocol::TurnEnvironmentSelection;
+use codex_secrets::SecretsBackendKind;
+use codex_secrets::SecretsManager;
 use tokio::sync::Semaphore;
 
 /// Context for an initialized model agent
@@ -762,6 +768,8 @@ impl Session {
                             Arc::clone(network_policy_decider_session),
                         )
                     });
+            let secrets_manager =
+                SecretsManager::new(config.codex_home.to_path_buf(), SecretsBackendKind::Local);
             let (network_proxy, session_network_proxy) =
                 if let Some(spec) = config.permissions.network.as_ref() {
                     let current_exec_policy = exec_policy.current();
@@ -781,22 +789,31 @@ impl Session {
                             managed_network_requirements_enabled,
                     ))
                     .await?;
+                    if spec.traffic_log_enabled() {
+                        match NetworkTrafficLog::shared(
+                            config.codex_home.join(NETWORK_TRAFFIC_LOG_DIR).to_path_buf(),
+                        ) {
+                            Ok(traffic_log) => {
+                                network_proxy
+                                    .proxy()
+                                    .set_traffic_log(Some(traffic_log))
+                                    .await;
+                            }
+                            Err(err) => warn!("failed to open network traffic log: {err:#}"),
+                        }
+                    }
+                    network_proxy
+                        .proxy()
+                        .set_credential_resolver(Some(Arc::new(ProxyCredentialResolver::new(
+                            secrets_manager.clone(),
+                            &config.cwd,
+                        ))))
+                        .await;
                     (Some(network_proxy), Some(session_network_proxy))
                 } else {
                     (None, None)
                 };
 
-            let hooks =
-                build_hooks_for_config(&config, plugins_manager.as_ref(), &default_shell).await;
-            for warning in hooks.startup_warnings() {
-                post_session_configured_events.push(Event {
-                    id: INITIAL_SUBMIT_ID.to_owned(),
-                    msg: EventMsg::Warning(WarningEvent {
-                        message: warning.clone(),
-                    }),
-                });
-            }
-
             let analytics_events_client = analytics_events_client.unwrap_or_else(|| {
                 AnalyticsEventsClient::new(
                     Arc::clone(&auth_manager),
@@ -810,6 +827,40 @@ impl Session {
                 SessionId::from(thread_id)
             };
             let agent_control = agent_control.with_session_id(session_id);
+            let model_client = ModelClient::new(
+                Some(Arc::clone(&auth_manager)),
+                session_id,
+                thread_id,
+                installation_id.clone(),
+                session_configuration.provider.clone(),
+                session_configuration.session_source.clone(),
+                config.model_verbosity,
+                config.features.enabled(Feature::EnableRequestCompression),
+                config.features.enabled(Feature::RuntimeMetrics),
+                Self::build_model_client_beta_features_header(config.as_ref()),
+            );
+            let hook_agent_session = Arc::new(RwLock::new(std::sync::Weak::<Session>::new()));
+            let hooks = build_hooks_for_config(
+                &config,
+                plugins_manager.as_ref(),
+                &default_shell,
+                hook_prompt_evaluator(
+                    model_client.clone(),
+                    Arc::clone(&models_manager),
+                    session_telemetry.clone(),
+                    Arc::clone(&config),
+                ),
+                hook_agent_evaluator(Arc::clone(&hook_agent_session)),
+            )
+            .await;
+            for warning in hooks.startup_warnings() {
+                post_session_configured_events.push(Event {
+                    id: INITIAL_SUBMIT_ID.to_owned(),
+                    msg: EventMsg::Warning(WarningEvent {
+                        message: warning.clone(),
+                    }),
+                });
+            }
             let services = SessionServices {
                 // Initialize the MCP connection manager with an uninitialized
                 // instance. It will be replaced with one created via
@@ -830,6 +881,7 @@ impl Session {
                 main_execve_wrapper_exe: config.main_execve_wrapper_exe.clone(),
                 analytics_events_client,
                 hooks: arc_swap::ArcSwap::from_pointee(hooks),
+                hook_agent_session,
                 rollout_thread_trace,
                 user_shell: Arc::new(default_shell),
                 shell_snapshot_tx,
@@ -852,20 +904,11 @@ impl Session {
                 state_db: state_db_ctx.clone(),
                 live_thread: live_thread_init.as_ref().cloned(),
                 thread_store: Arc::clone(&thread_store),
-                model_client: ModelClient::new(
-                    Some(Arc::clone(&auth_manager)),
-                    session_id,
-                    thread_id,
-                    installation_id.clone(),
-                    session_configuration.provider.clone(),
-                    session_configuration.session_source.clone(),
-                    config.model_verbosity,
-                    config.features.enabled(Feature::EnableRequestCompression),
-                    config.features.enabled(Feature::RuntimeMetrics),
-                    Self::build_model_client_beta_features_header(config.as_ref()),
-                ),
+                model_client,
                 code_mode_service: crate::tools::code_mode::CodeModeService::new(),
                 environment_manager,
+                secrets_manager,
+                output_redactor: std::sync::RwLock::new(output_redactor_from_config(&config)),
             };
             services
                 .model_client
@@ -894,6 +937,7 @@ impl Session {
                 services,
                 next_internal_sub_id: AtomicU64::new(0),
             });
+            *sess.services.hook_agent_session.write().await = Arc::downgrade(&sess);
             if let Some(network_policy_decider_session) = network_policy_decider_session {
                 let mut guard = network_policy_decider_session.write().await;
                 *guard = Arc::downgrade(&sess);
@@ -992,6 +1036,7 @@ impl Session {
                 tool_plugin_provenance,
                 auth,
                 Some(sess.mcp_elicitation_reviewer()),
+                Some(sess.mcp_sampling_handler()),
             )
             .instrument(info_span!(
                 "session_init.mcp_manager_init",
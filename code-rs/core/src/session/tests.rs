@@ -46,6 +46,7 @@ use codex_protocol::permissions::FileSystemPath;
 use codex_protocol::permissions::FileSystemSandboxEntry;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::FileSystemSpecialPath;
+use codex_protocol::permissions::ResourceLimitKind;
 use codex_protocol::protocol::NonSteerableTurnKind;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_protocol::protocol::TurnEnvironmentSelection;
@@ -2793,6 +2794,8 @@ async fn includes_timed_out_message() {
         aggregated_output: StreamOutput::new("Command output".to_string()),
         duration: StdDuration::from_secs(1),
         timed_out: true,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let (_, turn_context) = make_session_and_context().await;
 
@@ -2804,6 +2807,56 @@ async fn includes_timed_out_message() {
     );
 }
 
+#[tokio::test]
+async fn includes_resource_limit_message() {
+    let exec = ExecToolCallOutput {
+        exit_code: 137,
+        stdout: StreamOutput::new(String::new()),
+        stderr: StreamOutput::new(String::new()),
+        aggregated_output: StreamOutput::new("Command output".to_string()),
+        duration: StdDuration::from_secs(1),
+        timed_out: false,
+        resource_limit_exceeded: Some(ResourceLimitKind::Memory),
+        sandbox_denials: Vec::new(),
+    };
+    let (_, turn_context) = make_session_and_context().await;
+
+    let out = format_exec_output_str(&exec, turn_context.truncation_policy);
+
+    assert_eq!(
+        out,
+        "command was killed after exceeding the sandbox memory limit\nCommand output"
+    );
+}
+
+#[tokio::test]
+async fn includes_linux_sandbox_denials() {
+    // Only the helper's report counts; a look-alike line in the command's own
+    // output stays part of the output.
+    let stderr = "curl: (7) Failed to connect\ndenied ptrace (blocked by seccomp)\n".to_string();
+    let exec = ExecToolCallOutput {
+        exit_code: 7,
+        stdout: StreamOutput::new(String::new()),
+        stderr: StreamOutput::new(stderr.clone()),
+        aggregated_output: StreamOutput::new(stderr),
+        duration: StdDuration::from_secs(1),
+        timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: vec!["connect 93.184.216.34:443 (blocked by network sandbox)".to_string()],
+    };
+    let (_, turn_context) = make_session_and_context().await;
+
+    let out = format_exec_output_str(&exec, turn_context.truncation_policy);
+
+    assert_eq!(
+        out,
+        "sandbox blocked the following operations:\n\
+         - connect 93.184.216.34:443 (blocked by network sandbox)\n\
+         curl: (7) Failed to connect\n\
+         denied ptrace (blocked by seccomp)\n"
+    );
+}
+
 #[tokio::test]
 async fn turn_context_with_model_updates_model_fields() {
     let (session, mut turn_context) = make_session_and_context().await;
@@ -3860,6 +3913,7 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
             legacy_notify_argv: config.notify.clone(),
             ..HooksConfig::default()
         })),
+        hook_agent_session: Arc::new(RwLock::new(std::sync::Weak::new())),
         rollout_thread_trace: codex_rollout_trace::ThreadTraceContext::disabled(),
         user_shell: Arc::new(default_user_shell()),
         shell_snapshot_tx: watch::channel(None).0,
@@ -3899,6 +3953,11 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
         ),
         code_mode_service: crate::tools::code_mode::CodeModeService::new(),
         environment_manager: Arc::new(codex_exec_server::EnvironmentManager::default_for_tests()),
+        secrets_manager: codex_secrets::SecretsManager::new(
+            config.codex_home.to_path_buf(),
+            codex_secrets::SecretsBackendKind::Local,
+        ),
+        output_redactor: std::sync::RwLock::new(codex_secrets::OutputRedactor::default()),
     };
 
     let plugin_outcome = services
@@ -5575,6 +5634,7 @@ where
             legacy_notify_argv: config.notify.clone(),
             ..HooksConfig::default()
         })),
+        hook_agent_session: Arc::new(RwLock::new(std::sync::Weak::new())),
         rollout_thread_trace: codex_rollout_trace::ThreadTraceContext::disabled(),
         user_shell: Arc::new(default_user_shell()),
         shell_snapshot_tx: watch::channel(None).0,
@@ -5614,6 +5674,11 @@ where
         ),
         code_mode_service: crate::tools::code_mode::CodeModeService::new(),
         environment_manager: Arc::new(codex_exec_server::EnvironmentManager::default_for_tests()),
+        secrets_manager: codex_secrets::SecretsManager::new(
+            config.codex_home.to_path_buf(),
+            codex_secrets::SecretsBackendKind::Local,
+        ),
+        output_redactor: std::sync::RwLock::new(codex_secrets::OutputRedactor::default()),
     };
 
     let plugin_outcome = services
@@ -27,6 +27,7 @@ use crate::hook_runtime::record_additional_contexts;
 use crate::hook_runtime::record_pending_input;
 use crate::hook_runtime::run_pending_session_start_hooks;
 use crate::hook_runtime::run_user_prompt_submit_hooks;
+use crate::hook_runtime::spawn_notification_hooks;
 use crate::injection::ToolMentionKind;
 use crate::injection::app_id_from_path;
 use crate::injection::tool_kind_for_path;
@@ -73,6 +74,7 @@ use codex_hooks::HookEvent;
 use codex_hooks::HookEventAfterAgent;
 use codex_hooks::HookPayload;
 use codex_hooks::HookResult;
+use codex_hooks::NotificationType;
 use codex_protocol::config_types::ModeKind;
 use codex_protocol::config_types::ServiceTier;
 use codex_protocol::error::CodexErr;
@@ -627,6 +629,12 @@ pub(crate) async fn run_turn(
                         .await;
                         return None;
                     }
+                    spawn_notification_hooks(
+                        &sess,
+                        &turn_context,
+                        NotificationType::IdlePrompt,
+                        "Codex is waiting for your input".to_string(),
+                    );
                     break;
                 }
                 continue;
@@ -1479,6 +1487,9 @@ pub(super) fn realtime_text_for_event(msg: &EventMsg) -> Option<String> {
         | EventMsg::ThreadGoalUpdated(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpPromptListChanged(_)
+        | EventMsg::McpToolListChanged(_)
+        | EventMsg::McpResourceListChanged(_)
         | EventMsg::McpToolCallBegin(_)
         | EventMsg::McpToolCallEnd(_)
         | EventMsg::WebSearchBegin(_)
@@ -693,6 +693,8 @@ impl Session {
             mcp_connection_manager.set_approval_policy(&session_configuration.approval_policy);
             mcp_connection_manager
                 .set_permission_profile(session_configuration.permission_profile());
+            mcp_connection_manager
+                .set_roots(cwd.as_path(), &session_configuration.permission_profile());
         }
 
         let model_info = self
@@ -337,6 +337,12 @@ impl Session {
         {
             warn!("failed to apply goal runtime turn-start event: {err}");
         }
+        if let Some(network_proxy) = self.services.network_proxy.as_ref() {
+            network_proxy
+                .proxy()
+                .set_active_turn_id(Some(turn_context.sub_id.clone()))
+                .await;
+        }
         let queued_response_items = self.take_queued_response_items_for_next_turn().await;
         let mailbox_items = self.get_pending_input().await;
         let turn_state = {
@@ -775,6 +781,12 @@ impl Session {
             if !cleared_active_turn {
                 return;
             }
+            if let Some(network_proxy) = self.services.network_proxy.as_ref() {
+                network_proxy
+                    .proxy()
+                    .set_active_turn_id(/*turn_id*/ None)
+                    .await;
+            }
             if let Err(err) = self
                 .goal_runtime_apply(GoalRuntimeEvent::MaybeContinueIfIdle)
                 .await
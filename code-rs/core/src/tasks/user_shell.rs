@@ -40,6 +40,7 @@ use crate::session::session::Session;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
+use codex_protocol::permissions::ResourceLimits;
 
 const USER_SHELL_TIMEOUT_MS: u64 = 60 * 60 * 1000; // 1 hour
 
@@ -200,6 +201,8 @@ pub(crate) async fn execute_user_shell_command(
         network_sandbox_policy: permission_profile.network_sandbox_policy(),
         windows_sandbox_filesystem_overrides: None,
         arg0: None,
+        resource_limits: ResourceLimits::default(),
+        sandbox_report: None,
     };
 
     let stdout_stream = Some(StdoutStream {
@@ -222,6 +225,8 @@ pub(crate) async fn execute_user_shell_command(
                 aggregated_output: StreamOutput::new(aborted_message.clone()),
                 duration: Duration::ZERO,
                 timed_out: false,
+                resource_limit_exceeded: None,
+                sandbox_denials: Vec::new(),
             };
             persist_user_shell_output(
                 &session,
@@ -300,6 +305,8 @@ pub(crate) async fn execute_user_shell_command(
                 aggregated_output: StreamOutput::new(message.clone()),
                 duration: Duration::ZERO,
                 timed_out: false,
+                resource_limit_exceeded: None,
+                sandbox_denials: Vec::new(),
             };
             session
                 .send_event(
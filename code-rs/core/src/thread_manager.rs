@@ -57,6 +57,7 @@ use codex_thread_store::LocalThreadStore;
 use codex_thread_store::LocalThreadStoreConfig;
 use codex_thread_store::ReadThreadByRolloutPathParams;
 use codex_thread_store::ReadThreadParams;
+use codex_thread_store::SqliteThreadStore;
 use codex_thread_store::StoredThread;
 use codex_thread_store::ThreadStore;
 use codex_thread_store::ThreadStoreError;
@@ -276,10 +277,26 @@ pub fn thread_store_from_config(
             LocalThreadStoreConfig::from_config(config),
             state_db,
         )),
+        ThreadStoreConfig::Sqlite => Arc::new(sqlite_thread_store_from_config(config)),
         ThreadStoreConfig::InMemory { id } => InMemoryThreadStore::for_id(id),
     }
 }
 
+/// Subdirectory of `sqlite_home` holding the SQLite thread store's database.
+const SQLITE_THREAD_STORE_DIR: &str = "thread-store";
+
+/// Builds the SQLite thread store for `config`.
+///
+/// Its database lives under `sqlite_home` but never in it: the local store
+/// deletes state DB threads that have no rollout file, which would wipe every
+/// thread this store writes.
+pub fn sqlite_thread_store_from_config(config: &Config) -> SqliteThreadStore {
+    SqliteThreadStore::open(
+        config.sqlite_home.join(SQLITE_THREAD_STORE_DIR),
+        config.model_provider_id.clone(),
+    )
+}
+
 impl ThreadManager {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
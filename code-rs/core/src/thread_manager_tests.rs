@@ -9,6 +9,7 @@ use crate::tasks::InterruptedTurnHistoryMarker;
 use crate::tasks::interrupted_turn_history_marker;
 use codex_features::Feature;
 use codex_models_manager::manager::RefreshStrategy;
+use codex_protocol::models::BaseInstructions;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ReasoningItemReasoningSummary;
 use codex_protocol::models::ResponseItem;
@@ -18,9 +19,14 @@ use codex_protocol::protocol::InitialHistory;
 use codex_protocol::protocol::InternalSessionSource;
 use codex_protocol::protocol::ResumedHistory;
 use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::ThreadMemoryMode;
 use codex_protocol::protocol::ThreadSource;
 use codex_protocol::protocol::TurnStartedEvent;
 use codex_protocol::protocol::UserMessageEvent;
+use codex_thread_store::AppendThreadItemsParams;
+use codex_thread_store::CreateThreadParams;
+use codex_thread_store::ThreadEventPersistenceMode;
+use codex_thread_store::ThreadPersistenceMetadata;
 use core_test_support::PathBufExt;
 use core_test_support::PathExt;
 use core_test_support::responses::mount_models_once;
@@ -731,6 +737,78 @@ async fn resume_stopped_thread_from_rollout_preserves_thread_source() {
         .expect("shutdown resumed thread");
 }
 
+#[tokio::test]
+async fn sqlite_thread_store_keeps_threads_out_of_the_local_state_db() {
+    let temp_dir = tempdir().expect("tempdir");
+    let mut config = test_config().await;
+    config.codex_home = temp_dir.path().join("codex-home").abs();
+    config.sqlite_home = temp_dir.path().join("sqlite-home");
+    config.cwd = config.codex_home.abs();
+    config.experimental_thread_store = ThreadStoreConfig::Sqlite;
+    std::fs::create_dir_all(&config.codex_home).expect("create codex home");
+
+    let local_state_db = init_state_db(&config).await.expect("local state db");
+    let thread_store = thread_store_from_config(&config, Some(local_state_db.clone()));
+    assert!(
+        thread_store
+            .as_any()
+            .downcast_ref::<SqliteThreadStore>()
+            .is_some(),
+        "expected the configured sqlite store"
+    );
+
+    let thread_id = ThreadId::new();
+    thread_store
+        .create_thread(CreateThreadParams {
+            thread_id,
+            forked_from_id: None,
+            source: SessionSource::Exec,
+            thread_source: None,
+            base_instructions: BaseInstructions::default(),
+            dynamic_tools: Vec::new(),
+            metadata: ThreadPersistenceMetadata {
+                cwd: Some(config.cwd.to_path_buf()),
+                model_provider: config.model_provider_id.clone(),
+                memory_mode: ThreadMemoryMode::Enabled,
+            },
+            event_persistence_mode: ThreadEventPersistenceMode::Limited,
+        })
+        .await
+        .expect("create thread");
+    thread_store
+        .append_items(AppendThreadItemsParams {
+            thread_id,
+            items: vec![RolloutItem::ResponseItem(user_msg("hello"))],
+        })
+        .await
+        .expect("append items");
+    thread_store
+        .persist_thread(thread_id)
+        .await
+        .expect("persist thread");
+
+    let thread = thread_store
+        .read_thread(ReadThreadParams {
+            thread_id,
+            include_archived: false,
+            include_history: true,
+        })
+        .await
+        .expect("read thread from sqlite store");
+    assert_eq!(thread.thread_id, thread_id);
+    assert!(
+        codex_state::state_db_path(&config.sqlite_home.join("thread-store")).exists(),
+        "sqlite store should keep its own state DB"
+    );
+    assert_eq!(
+        local_state_db
+            .get_thread(thread_id)
+            .await
+            .expect("query local state db"),
+        None
+    );
+}
+
 #[tokio::test]
 async fn rollout_path_resume_and_fork_read_history_through_thread_store() {
     let temp_dir = tempdir().expect("tempdir");
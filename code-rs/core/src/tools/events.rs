@@ -357,7 +357,9 @@ impl ToolEmitter {
                 };
                 (event, result)
             }
-            Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output }))) => {
+            Err(ToolError::Codex(CodexErr::Sandbox(
+                SandboxErr::Timeout { output } | SandboxErr::ResourceLimitExceeded { output },
+            ))) => {
                 let response = self.format_exec_output_for_model(&output, ctx);
                 let event = ToolEventStage::Failure(ToolEventFailure::Output(*output));
                 let result = Err(FunctionCallError::RespondToModel(response));
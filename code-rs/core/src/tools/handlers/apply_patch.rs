@@ -144,34 +144,62 @@ impl ApplyPatchArgumentDiffConsumer {
 }
 
 fn convert_apply_patch_hunks_to_protocol(hunks: &[Hunk]) -> HashMap<PathBuf, FileChange> {
-    hunks
-        .iter()
-        .map(|hunk| {
-            let path = hunk_source_path(hunk).to_path_buf();
-            let change = match hunk {
-                Hunk::AddFile { contents, .. } => FileChange::Add {
-                    content: contents.clone(),
-                },
-                Hunk::DeleteFile { .. } => FileChange::Delete {
-                    content: String::new(),
-                },
-                Hunk::UpdateFile {
-                    chunks, move_path, ..
-                } => FileChange::Update {
-                    unified_diff: format_update_chunks_for_progress(chunks),
-                    move_path: move_path.clone(),
-                },
-            };
-            (path, change)
-        })
-        .collect()
+    let mut changes = HashMap::new();
+    let mut modes = Vec::new();
+    for hunk in hunks {
+        let path = hunk_source_path(hunk).to_path_buf();
+        let change = match hunk {
+            Hunk::AddFile { contents, .. } => FileChange::Add {
+                content: contents.clone(),
+                executable: None,
+            },
+            Hunk::DeleteFile { .. } => FileChange::Delete {
+                content: String::new(),
+            },
+            Hunk::UpdateFile {
+                chunks, move_path, ..
+            } => FileChange::Update {
+                unified_diff: format_update_chunks_for_progress(chunks),
+                move_path: move_path.clone(),
+                executable: None,
+            },
+            Hunk::SetMode { executable, .. } => {
+                modes.push((path, *executable));
+                continue;
+            }
+            Hunk::AddSymlink { target, .. } => FileChange::AddSymlink {
+                target: target.clone(),
+            },
+            Hunk::AddBinaryFile { contents, .. } => FileChange::AddBinary {
+                size: contents.len() as u64,
+            },
+        };
+        changes.insert(path, change);
+    }
+    // A mode change rides along with an add or update of the same file, whichever hunk
+    // comes first.
+    for (path, mode) in modes {
+        match changes.get_mut(&path) {
+            Some(FileChange::Add { executable, .. } | FileChange::Update { executable, .. }) => {
+                *executable = Some(mode);
+            }
+            Some(_) => {}
+            None => {
+                changes.insert(path, FileChange::SetMode { executable: mode });
+            }
+        }
+    }
+    changes
 }
 
 fn hunk_source_path(hunk: &Hunk) -> &Path {
     match hunk {
-        Hunk::AddFile { path, .. } | Hunk::DeleteFile { path } | Hunk::UpdateFile { path, .. } => {
-            path
-        }
+        Hunk::AddFile { path, .. }
+        | Hunk::DeleteFile { path }
+        | Hunk::UpdateFile { path, .. }
+        | Hunk::SetMode { path, .. }
+        | Hunk::AddSymlink { path, .. }
+        | Hunk::AddBinaryFile { path, .. } => path,
     }
 }
 
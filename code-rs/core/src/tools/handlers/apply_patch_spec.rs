@@ -18,11 +18,14 @@ Your patch language is a stripped‑down, file‑oriented diff format designed t
 
 Within that envelope, you get a sequence of file operations.
 You MUST include a header to specify the action you are taking.
-Each operation starts with one of three headers:
+Each operation starts with one of these headers:
 
 *** Add File: <path> - create a new file. Every following line is a + line (the initial contents).
 *** Delete File: <path> - remove an existing file. Nothing follows.
 *** Update File: <path> - patch an existing file in place (optionally with a rename).
+*** Set Mode: <mode> <path> - make a file executable (100755) or not (100644). Nothing follows.
+*** Add Symlink: <path> - create a symbolic link. A single + line holds the link target.
+*** Add Binary File: <path> - create a binary file. Every following line is a + line of base64-encoded contents.
 
 May be immediately followed by *** Move to: <new path> if you want to rename the file.
 Then one or more “hunks”, each introduced by @@ (optionally followed by a hunk header).
@@ -50,10 +53,13 @@ The full grammar definition is below:
 Patch := Begin { FileOp } End
 Begin := "*** Begin Patch" NEWLINE
 End := "*** End Patch" NEWLINE
-FileOp := AddFile | DeleteFile | UpdateFile
+FileOp := AddFile | DeleteFile | UpdateFile | SetMode | AddSymlink | AddBinaryFile
 AddFile := "*** Add File: " path NEWLINE { "+" line NEWLINE }
 DeleteFile := "*** Delete File: " path NEWLINE
 UpdateFile := "*** Update File: " path NEWLINE [ MoveTo ] { Hunk }
+SetMode := "*** Set Mode: " ("100755" | "100644") " " path NEWLINE
+AddSymlink := "*** Add Symlink: " path NEWLINE "+" target NEWLINE
+AddBinaryFile := "*** Add Binary File: " path NEWLINE { "+" base64 NEWLINE }
 MoveTo := "*** Move to: " newPath NEWLINE
 Hunk := "@@" [ header ] NEWLINE { HunkLine } [ "*** End of File" NEWLINE ]
 HunkLine := (" " | "-" | "+") text NEWLINE
@@ -71,6 +77,8 @@ A full patch can combine several operations:
 *** Delete File: obsolete.txt
 *** End Patch
 
+You may also send a standard unified diff, as produced by `git diff`, instead of the envelope. Hunks are located by their content rather than their line numbers, so include unchanged context lines around every change.
+
 It is important to remember:
 
 - You must include a header with your intended action (Add/Delete/Update)
@@ -137,6 +137,7 @@ fn diff_consumer_streams_apply_patch_changes() {
                 PathBuf::from("hello.txt"),
                 FileChange::Add {
                     content: String::new(),
+                    executable: None,
                 },
             )]),
         )
@@ -165,6 +166,7 @@ fn diff_consumer_streams_apply_patch_changes() {
                 PathBuf::from("hello.txt"),
                 FileChange::Add {
                     content: "hello\nworld\n".to_string(),
+                    executable: None,
                 },
             )]),
         )
@@ -184,6 +186,7 @@ fn diff_consumer_sends_next_update_after_buffer_interval() {
             PathBuf::from("hello.txt"),
             FileChange::Add {
                 content: String::new(),
+                executable: None,
             },
         )])
     );
@@ -199,6 +202,7 @@ fn diff_consumer_sends_next_update_after_buffer_interval() {
             PathBuf::from("hello.txt"),
             FileChange::Add {
                 content: "hello\n".to_string(),
+                executable: None,
             },
         )])
     );
@@ -286,3 +290,43 @@ fn write_permissions_for_paths_keep_dirs_outside_workspace_root() {
         Some(vec![expected_outside])
     );
 }
+
+#[test]
+fn convert_hunks_merges_mode_into_add_of_same_file_in_either_order() {
+    for patch in [
+        "*** Begin Patch\n*** Add File: run.sh\n+echo hi\n*** Set Mode: 100755 run.sh\n*** End Patch",
+        "*** Begin Patch\n*** Set Mode: 100755 run.sh\n*** Add File: run.sh\n+echo hi\n*** End Patch",
+    ] {
+        let hunks = codex_apply_patch::parse_patch(patch)
+            .expect("patch should parse")
+            .hunks;
+
+        assert_eq!(
+            convert_apply_patch_hunks_to_protocol(&hunks),
+            HashMap::from([(
+                PathBuf::from("run.sh"),
+                FileChange::Add {
+                    content: "echo hi\n".to_string(),
+                    executable: Some(true),
+                },
+            )])
+        );
+    }
+}
+
+#[test]
+fn convert_hunks_keeps_lone_mode_change() {
+    let hunks = codex_apply_patch::parse_patch(
+        "*** Begin Patch\n*** Set Mode: 100644 run.sh\n*** End Patch",
+    )
+    .expect("patch should parse")
+    .hunks;
+
+    assert_eq!(
+        convert_apply_patch_hunks_to_protocol(&hunks),
+        HashMap::from([(
+            PathBuf::from("run.sh"),
+            FileChange::SetMode { executable: false },
+        )])
+    );
+}
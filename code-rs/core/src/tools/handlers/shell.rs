@@ -7,6 +7,8 @@ use std::sync::Arc;
 use crate::exec::ExecParams;
 use crate::exec_policy::ExecApprovalRequest;
 use crate::function_tool::FunctionCallError;
+use crate::redaction::redact_exec_result;
+use crate::secret_injection::inject_command_secrets;
 use crate::session::turn_context::TurnContext;
 use crate::tools::context::FunctionToolOutput;
 use crate::tools::context::ToolInvocation;
@@ -208,6 +210,16 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         return Ok(output);
     }
 
+    // The model-visible command keeps its `$secret:NAME` references; only the
+    // spawned command sees the values.
+    let secret_env = inject_command_secrets(
+        session.as_ref(),
+        exec_params.cwd.as_path(),
+        &exec_params.command,
+    )
+    .map_err(FunctionCallError::RespondToModel)?;
+    secret_env.apply(&mut exec_params.env, &mut explicit_env_overrides);
+
     let source = ExecCommandSource::Agent;
     let emitter = ToolEmitter::shell(
         exec_params.command.clone(),
@@ -243,7 +255,7 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         .await;
 
     let req = ShellRequest {
-        command: exec_params.command.clone(),
+        command: secret_env.rewrite_command(&exec_params.command),
         hook_command,
         cwd: exec_params.cwd.clone(),
         timeout_ms: exec_params.expiration.timeout_ms(),
@@ -284,6 +296,7 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         )
         .await
         .map(|result| result.output);
+    let out = redact_exec_result(session.as_ref(), out);
     let event_ctx = ToolEventCtx::new(
         session.as_ref(),
         turn.as_ref(),
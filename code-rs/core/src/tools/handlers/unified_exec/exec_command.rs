@@ -2,6 +2,9 @@ use std::sync::Arc;
 
 use crate::function_tool::FunctionCallError;
 use crate::maybe_emit_implicit_skill_invocation;
+use crate::redaction::RedactionSource;
+use crate::redaction::redact_text;
+use crate::secret_injection::inject_command_secrets;
 use crate::tools::context::ExecCommandToolOutput;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolPayload;
@@ -295,6 +298,14 @@ impl ToolHandler for ExecCommandHandler {
             });
         }
 
+        let secret_env = match inject_command_secrets(session.as_ref(), cwd.as_path(), &command) {
+            Ok(secret_env) => secret_env,
+            Err(err) => {
+                manager.release_process_id(process_id).await;
+                return Err(FunctionCallError::RespondToModel(err));
+            }
+        };
+
         emit_unified_exec_tty_metric(&turn.session_telemetry, tty);
         match manager
             .exec_command(
@@ -314,6 +325,7 @@ impl ToolHandler for ExecCommandHandler {
                         .permissions_preapproved,
                     justification,
                     prefix_rule,
+                    secret_env,
                 },
                 &context,
             )
@@ -321,7 +333,11 @@ impl ToolHandler for ExecCommandHandler {
         {
             Ok(response) => Ok(response),
             Err(UnifiedExecError::SandboxDenied { output, .. }) => {
-                let output_text = output.aggregated_output.text;
+                let output_text = redact_text(
+                    session.as_ref(),
+                    RedactionSource::CommandOutput,
+                    &output.aggregated_output.text,
+                );
                 let original_token_count = approx_token_count(&output_text);
                 Ok(ExecCommandToolOutput {
                     event_call_id: context.call_id.clone(),
@@ -108,15 +108,43 @@ pub fn format_exec_output_str(
     formatted_truncate_text(&content, truncation_policy)
 }
 
-/// Extracts exec output content and prepends a timeout message if the command timed out.
+/// Extracts exec output content and prepends a timeout or resource limit
+/// message if the command was killed for either reason.
 fn build_content_with_timeout(exec_output: &ExecToolCallOutput) -> String {
+    let content = build_content_with_sandbox_denials(exec_output);
     if exec_output.timed_out {
         format!(
-            "command timed out after {} milliseconds\n{}",
+            "command timed out after {} milliseconds\n{content}",
             exec_output.duration.as_millis(),
-            exec_output.aggregated_output.text
+        )
+    } else if let Some(limit) = exec_output.resource_limit_exceeded {
+        format!(
+            "command was killed after exceeding the sandbox {} limit\n{content}",
+            limit.description(),
         )
     } else {
-        exec_output.aggregated_output.text.clone()
+        content
+    }
+}
+
+/// Summarizes the operations the Linux sandbox helper recorded as blocked
+/// ahead of the command output, so the model can ask for the matching
+/// permission instead of retrying.
+///
+/// Denials come from the helper's sandbox report only; the command's own
+/// output is never scanned for them.
+fn build_content_with_sandbox_denials(exec_output: &ExecToolCallOutput) -> String {
+    let output = &exec_output.aggregated_output.text;
+    if exec_output.sandbox_denials.is_empty() {
+        return output.clone();
+    }
+
+    let mut content = String::from("sandbox blocked the following operations:\n");
+    for denial in &exec_output.sandbox_denials {
+        content.push_str("- ");
+        content.push_str(denial);
+        content.push('\n');
     }
+    content.push_str(output);
+    content
 }
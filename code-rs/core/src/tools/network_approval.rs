@@ -521,7 +521,7 @@ impl NetworkApprovalService {
             let available_decisions = None;
             session
                 .request_command_approval(
-                    turn_context.as_ref(),
+                    &turn_context,
                     guardian_approval_id,
                     /*approval_id*/ None,
                     prompt_command,
@@ -215,6 +215,7 @@ fn denied_blocked_request(host: &str) -> BlockedRequest {
         decision: Some("deny".to_string()),
         source: Some("decider".to_string()),
         port: Some(80),
+        path: None,
     })
 }
 
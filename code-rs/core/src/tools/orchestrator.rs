@@ -80,11 +80,13 @@ impl ToolOrchestrator {
         let attempt_with_network_approval = SandboxAttempt {
             sandbox: attempt.sandbox,
             permissions: attempt.permissions,
+            resource_limits: attempt.resource_limits,
             enforce_managed_network: attempt.enforce_managed_network,
             manager: attempt.manager,
             sandbox_cwd: attempt.sandbox_cwd,
             codex_linux_sandbox_exe: attempt.codex_linux_sandbox_exe,
             use_legacy_landlock: attempt.use_legacy_landlock,
+            log_denials: attempt.log_denials,
             windows_sandbox_level: attempt.windows_sandbox_level,
             windows_sandbox_private_desktop: attempt.windows_sandbox_private_desktop,
             network_denial_cancellation_token: network_approval
@@ -227,15 +229,18 @@ impl ToolOrchestrator {
 
         // Platform-specific flag gating is handled by SandboxManager::select_initial.
         let use_legacy_landlock = turn_ctx.features.use_legacy_landlock();
+        let log_denials = turn_ctx.features.sandbox_denial_log();
         let sandbox_cwd = tool.sandbox_cwd(req).unwrap_or(&turn_ctx.cwd);
         let initial_attempt = SandboxAttempt {
             sandbox: initial_sandbox,
             permissions: &turn_ctx.permission_profile,
+            resource_limits: turn_ctx.config.permissions.resource_limits,
             enforce_managed_network: managed_network_active,
             manager: &self.sandbox,
             sandbox_cwd,
             codex_linux_sandbox_exe: turn_ctx.codex_linux_sandbox_exe.as_ref(),
             use_legacy_landlock,
+            log_denials,
             windows_sandbox_level: turn_ctx.windows_sandbox_level,
             windows_sandbox_private_desktop: turn_ctx
                 .config
@@ -349,11 +354,13 @@ impl ToolOrchestrator {
                 let escalated_attempt = SandboxAttempt {
                     sandbox: SandboxType::None,
                     permissions: &turn_ctx.permission_profile,
+                    resource_limits: turn_ctx.config.permissions.resource_limits,
                     enforce_managed_network: managed_network_active,
                     manager: &self.sandbox,
                     sandbox_cwd,
                     codex_linux_sandbox_exe: None,
                     use_legacy_landlock,
+                    log_denials,
                     windows_sandbox_level: turn_ctx.windows_sandbox_level,
                     windows_sandbox_private_desktop: turn_ctx
                         .config
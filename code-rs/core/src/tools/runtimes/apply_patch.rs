@@ -212,7 +212,7 @@ impl ToolRuntime<ApplyPatchRequest, ApplyPatchRuntimeOutput> for ApplyPatchRunti
         let sandbox = Self::file_system_sandbox_context_for_attempt(req, attempt);
         let mut stdout = Vec::new();
         let mut stderr = Vec::new();
-        let result = codex_apply_patch::apply_patch(
+        let result = codex_apply_patch::apply_patch_atomic(
             &req.action.patch,
             &req.action.cwd,
             &mut stdout,
@@ -237,6 +237,8 @@ impl ToolRuntime<ApplyPatchRequest, ApplyPatchRuntimeOutput> for ApplyPatchRunti
             aggregated_output: StreamOutput::new(format!("{stdout}{stderr}")),
             duration: started_at.elapsed(),
             timed_out: false,
+            resource_limit_exceeded: None,
+            sandbox_denials: Vec::new(),
         };
         if failed && is_likely_sandbox_denied(attempt.sandbox, &output) {
             return Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied {
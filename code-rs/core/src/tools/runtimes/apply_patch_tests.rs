@@ -55,6 +55,7 @@ fn guardian_review_request_includes_patch_context() {
             path.to_path_buf(),
             FileChange::Add {
                 content: "hello".to_string(),
+                executable: None,
             },
         )]),
         exec_approval_requirement: ExecApprovalRequirement::NeedsApproval {
@@ -146,11 +147,13 @@ fn file_system_sandbox_context_uses_active_attempt() {
     let attempt = SandboxAttempt {
         sandbox: SandboxType::MacosSeatbelt,
         permissions: &permissions,
+        resource_limits: Default::default(),
         enforce_managed_network: false,
         manager: &manager,
         sandbox_cwd: &path,
         codex_linux_sandbox_exe: None,
         use_legacy_landlock: true,
+        log_denials: false,
         windows_sandbox_level: WindowsSandboxLevel::RestrictedToken,
         windows_sandbox_private_desktop: true,
         network_denial_cancellation_token: None,
@@ -198,11 +201,13 @@ fn no_sandbox_attempt_has_no_file_system_context() {
     let attempt = SandboxAttempt {
         sandbox: SandboxType::None,
         permissions: &permissions,
+        resource_limits: Default::default(),
         enforce_managed_network: false,
         manager: &manager,
         sandbox_cwd: &path,
         codex_linux_sandbox_exe: None,
         use_legacy_landlock: false,
+        log_denials: false,
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         windows_sandbox_private_desktop: false,
         network_denial_cancellation_token: None,
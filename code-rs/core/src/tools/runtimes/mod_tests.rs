@@ -108,11 +108,13 @@ async fn explicit_escalation_prepares_exec_without_managed_network() -> anyhow::
     let attempt = SandboxAttempt {
         sandbox: SandboxType::None,
         permissions: &permissions,
+        resource_limits: Default::default(),
         enforce_managed_network: false,
         manager: &manager,
         sandbox_cwd: &cwd,
         codex_linux_sandbox_exe: None,
         use_legacy_landlock: false,
+        log_denials: false,
         windows_sandbox_level: WindowsSandboxLevel::Disabled,
         windows_sandbox_private_desktop: false,
         network_denial_cancellation_token: None,
@@ -37,6 +37,7 @@ use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::GuardianCommandSource;
 use codex_protocol::protocol::NetworkPolicyRuleAction;
@@ -46,6 +47,7 @@ use codex_sandboxing::SandboxManager;
 use codex_sandboxing::SandboxTransformRequest;
 use codex_sandboxing::SandboxType;
 use codex_sandboxing::SandboxablePreference;
+use codex_sandboxing::landlock::SandboxReport;
 use codex_shell_command::bash::parse_shell_lc_plain_commands;
 use codex_shell_command::bash::parse_shell_lc_single_command_prefix;
 use codex_shell_escalation::EscalateServer;
@@ -65,6 +67,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
@@ -147,6 +150,8 @@ pub(super) async fn try_run_zsh_fork(
         network_sandbox_policy,
         windows_sandbox_filesystem_overrides: _windows_sandbox_filesystem_overrides,
         arg0,
+        resource_limits,
+        sandbox_report,
     } = sandbox_exec_request;
     let ParsedShellCommand { script, login, .. } = extract_shell_script(&command)?;
     let effective_timeout = Duration::from_millis(
@@ -170,6 +175,9 @@ pub(super) async fn try_run_zsh_fork(
         sandbox_policy_cwd,
         codex_linux_sandbox_exe: ctx.turn.codex_linux_sandbox_exe.clone(),
         use_legacy_landlock: ctx.turn.features.use_legacy_landlock(),
+        log_denials: ctx.turn.features.sandbox_denial_log(),
+        resource_limits,
+        sandbox_report: Mutex::new(sandbox_report),
     };
     let main_execve_wrapper_exe = ctx
         .session
@@ -271,6 +279,11 @@ pub(crate) async fn prepare_unified_exec_zsh_fork(
         sandbox_policy_cwd: exec_request.windows_sandbox_policy_cwd.clone(),
         codex_linux_sandbox_exe: ctx.turn.codex_linux_sandbox_exe.clone(),
         use_legacy_landlock: ctx.turn.features.use_legacy_landlock(),
+        log_denials: ctx.turn.features.sandbox_denial_log(),
+        resource_limits: exec_request.resource_limits,
+        // Unified exec spawns the shell itself; only intercepted execs go
+        // through this executor.
+        sandbox_report: Mutex::new(None),
     };
     let escalation_policy = CoreShellActionProvider {
         policy: Arc::clone(&exec_policy),
@@ -755,6 +768,10 @@ struct CoreShellCommandExecutor {
     sandbox_policy_cwd: AbsolutePathBuf,
     codex_linux_sandbox_exe: Option<PathBuf>,
     use_legacy_landlock: bool,
+    log_denials: bool,
+    resource_limits: ResourceLimits,
+    /// Report for the shell command itself, handed to its single `run`.
+    sandbox_report: Mutex<Option<SandboxReport>>,
 }
 
 struct PrepareSandboxedExecParams<'a> {
@@ -802,6 +819,12 @@ impl ShellCommandExecutor for CoreShellCommandExecutor {
                 network_sandbox_policy: self.network_sandbox_policy,
                 windows_sandbox_filesystem_overrides: None,
                 arg0: self.arg0.clone(),
+                resource_limits: self.resource_limits,
+                sandbox_report: self
+                    .sandbox_report
+                    .lock()
+                    .ok()
+                    .and_then(|mut report| report.take()),
             },
             /*stdout_stream*/ None,
             after_spawn,
@@ -919,12 +942,14 @@ impl CoreShellCommandExecutor {
         let exec_request = sandbox_manager.transform(SandboxTransformRequest {
             command,
             permissions: permission_profile,
+            resource_limits: self.resource_limits,
             sandbox,
             enforce_managed_network: self.network.is_some(),
             network: self.network.as_ref(),
             sandbox_policy_cwd: &self.sandbox_policy_cwd,
             codex_linux_sandbox_exe: self.codex_linux_sandbox_exe.as_deref(),
             use_legacy_landlock: self.use_legacy_landlock,
+            log_denials: self.log_denials,
             windows_sandbox_level: self.windows_sandbox_level,
             windows_sandbox_private_desktop: false,
         })?;
@@ -989,6 +1014,8 @@ fn map_exec_result(
         aggregated_output: StreamOutput::new(result.output.clone()),
         duration: result.duration,
         timed_out: result.timed_out,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
 
     if result.timed_out {
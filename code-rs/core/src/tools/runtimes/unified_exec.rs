@@ -306,7 +306,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecProcess> for UnifiedExecRunt
                         .manager
                         .open_session_with_exec_env(
                             req.process_id,
-                            &prepared.exec_request,
+                            prepared.exec_request,
                             req.tty,
                             prepared.spawn_lifecycle,
                             req.environment.as_ref(),
@@ -340,7 +340,7 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecProcess> for UnifiedExecRunt
         self.manager
             .open_session_with_exec_env(
                 req.process_id,
-                &exec_env,
+                exec_env,
                 req.tty,
                 Box::new(NoopSpawnLifecycle),
                 req.environment.as_ref(),
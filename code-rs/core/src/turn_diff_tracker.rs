@@ -12,14 +12,58 @@ use codex_apply_patch::AppliedPatchFileChange;
 const ZERO_OID: &str = "0000000000000000000000000000000000000000";
 const DEV_NULL: &str = "/dev/null";
 const REGULAR_FILE_MODE: &str = "100644";
+const EXECUTABLE_FILE_MODE: &str = "100755";
+const SYMLINK_MODE: &str = "120000";
+
+/// The git file mode of a tracked path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileMode {
+    Regular,
+    Executable,
+    Symlink,
+}
+
+impl FileMode {
+    fn from_executable(executable: bool) -> Self {
+        if executable {
+            Self::Executable
+        } else {
+            Self::Regular
+        }
+    }
+
+    fn as_git_mode(self) -> &'static str {
+        match self {
+            Self::Regular => REGULAR_FILE_MODE,
+            Self::Executable => EXECUTABLE_FILE_MODE,
+            Self::Symlink => SYMLINK_MODE,
+        }
+    }
+}
+
+/// A file as the tracker knows it. A symlink's contents are its target, as in git.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TrackedFile {
+    mode: FileMode,
+    contents: Vec<u8>,
+}
+
+impl TrackedFile {
+    fn regular(contents: impl Into<Vec<u8>>) -> Self {
+        Self {
+            mode: FileMode::Regular,
+            contents: contents.into(),
+        }
+    }
+}
 
 /// Tracks the net text diff for the current turn from committed apply_patch
 /// mutations, without rereading the workspace filesystem.
 pub struct TurnDiffTracker {
     valid: bool,
     display_root: Option<PathBuf>,
-    baseline_by_path: HashMap<PathBuf, String>,
-    current_by_path: HashMap<PathBuf, String>,
+    baseline_by_path: HashMap<PathBuf, TrackedFile>,
+    current_by_path: HashMap<PathBuf, TrackedFile>,
     origin_by_current_path: HashMap<PathBuf, PathBuf>,
 }
 
@@ -112,7 +156,12 @@ impl TurnDiffTracker {
             AppliedPatchFileChange::Add {
                 content,
                 overwritten_content,
-            } => self.apply_add(source_path, content, overwritten_content.as_deref()),
+            } => self.apply_add(
+                source_path,
+                content.as_bytes(),
+                /*symlink*/ false,
+                overwritten_content.as_deref(),
+            ),
             AppliedPatchFileChange::Delete { content } => self.apply_delete(source_path, content),
             AppliedPatchFileChange::Update {
                 move_path,
@@ -126,31 +175,120 @@ impl TurnDiffTracker {
                 overwritten_move_content.as_deref(),
                 new_content,
             ),
+            AppliedPatchFileChange::SetMode {
+                content,
+                was_executable,
+                executable,
+            } => self.apply_set_mode(source_path, content, *was_executable, *executable),
+            AppliedPatchFileChange::AddSymlink {
+                target,
+                overwritten_content,
+            } => self.apply_add(
+                source_path,
+                target.to_string_lossy().as_bytes(),
+                /*symlink*/ true,
+                overwritten_content.as_deref(),
+            ),
+            AppliedPatchFileChange::AddBinary {
+                content,
+                overwritten_content,
+            } => self.apply_add(
+                source_path,
+                content,
+                /*symlink*/ false,
+                overwritten_content.as_deref(),
+            ),
         }
     }
 
-    fn apply_add(&mut self, path: &Path, content: &str, overwritten_content: Option<&str>) {
+    fn apply_add(
+        &mut self,
+        path: &Path,
+        content: &[u8],
+        symlink: bool,
+        overwritten_content: Option<&str>,
+    ) {
         self.origin_by_current_path.remove(path);
         if !self.current_by_path.contains_key(path)
             && !self.baseline_by_path.contains_key(path)
             && let Some(overwritten_content) = overwritten_content
         {
-            self.baseline_by_path
-                .insert(path.to_path_buf(), overwritten_content.to_string());
+            self.baseline_by_path.insert(
+                path.to_path_buf(),
+                TrackedFile::regular(overwritten_content),
+            );
         }
-        self.current_by_path
-            .insert(path.to_path_buf(), content.to_string());
+        let mode = if symlink {
+            FileMode::Symlink
+        } else {
+            self.current_mode(path)
+        };
+        self.current_by_path.insert(
+            path.to_path_buf(),
+            TrackedFile {
+                mode,
+                contents: content.to_vec(),
+            },
+        );
     }
 
     fn apply_delete(&mut self, path: &Path, content: &str) {
         if self.current_by_path.remove(path).is_none() && !self.baseline_by_path.contains_key(path)
         {
             self.baseline_by_path
-                .insert(path.to_path_buf(), content.to_string());
+                .insert(path.to_path_buf(), TrackedFile::regular(content));
         }
         self.origin_by_current_path.remove(path);
     }
 
+    fn apply_set_mode(
+        &mut self,
+        path: &Path,
+        content: &str,
+        was_executable: bool,
+        executable: bool,
+    ) {
+        let was_mode = FileMode::from_executable(was_executable);
+        match self.current_by_path.get_mut(path) {
+            Some(current) => {
+                // Earlier changes assume files start out regular; the mode seen
+                // on disk corrects that when the mode has not changed since.
+                if let Some(baseline) = self.baseline_by_path.get_mut(path)
+                    && baseline.mode == current.mode
+                    && baseline.mode != FileMode::Symlink
+                {
+                    baseline.mode = was_mode;
+                }
+                current.mode = FileMode::from_executable(executable);
+            }
+            None => {
+                self.baseline_by_path.insert(
+                    path.to_path_buf(),
+                    TrackedFile {
+                        mode: was_mode,
+                        contents: content.as_bytes().to_vec(),
+                    },
+                );
+                self.current_by_path.insert(
+                    path.to_path_buf(),
+                    TrackedFile {
+                        mode: FileMode::from_executable(executable),
+                        contents: content.as_bytes().to_vec(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// The mode a rewrite of `path` keeps: writing a file leaves its mode alone,
+    /// but replaces a symlink with a regular file.
+    fn current_mode(&self, path: &Path) -> FileMode {
+        match self.current_by_path.get(path).map(|file| file.mode) {
+            Some(FileMode::Executable) => FileMode::Executable,
+            Some(FileMode::Regular | FileMode::Symlink) | None => FileMode::Regular,
+        }
+    }
+
     fn apply_update(
         &mut self,
         source_path: &Path,
@@ -163,8 +301,9 @@ impl TurnDiffTracker {
             && !self.baseline_by_path.contains_key(source_path)
         {
             self.baseline_by_path
-                .insert(source_path.to_path_buf(), old_content.to_string());
+                .insert(source_path.to_path_buf(), TrackedFile::regular(old_content));
         }
+        let mode = self.current_mode(source_path);
 
         match move_path {
             Some(dest_path) => {
@@ -174,7 +313,7 @@ impl TurnDiffTracker {
                 {
                     self.baseline_by_path.insert(
                         dest_path.to_path_buf(),
-                        overwritten_move_content.to_string(),
+                        TrackedFile::regular(overwritten_move_content),
                     );
                 }
                 let origin = self
@@ -182,8 +321,9 @@ impl TurnDiffTracker {
                     .remove(source_path)
                     .unwrap_or_else(|| source_path.to_path_buf());
                 self.current_by_path.remove(source_path);
+                // A moved file is written afresh, so it does not keep its mode.
                 self.current_by_path
-                    .insert(dest_path.to_path_buf(), new_content.to_string());
+                    .insert(dest_path.to_path_buf(), TrackedFile::regular(new_content));
                 self.origin_by_current_path.remove(dest_path);
                 if dest_path != origin.as_path() {
                     self.origin_by_current_path
@@ -191,8 +331,13 @@ impl TurnDiffTracker {
                 }
             }
             None => {
-                self.current_by_path
-                    .insert(source_path.to_path_buf(), new_content.to_string());
+                self.current_by_path.insert(
+                    source_path.to_path_buf(),
+                    TrackedFile {
+                        mode,
+                        contents: new_content.as_bytes().to_vec(),
+                    },
+                );
             }
         }
     }
@@ -218,70 +363,85 @@ impl TurnDiffTracker {
     fn render_path_diff(&self, path: &Path) -> Option<String> {
         self.render_diff(
             path,
-            self.baseline_by_path.get(path).map(String::as_str),
+            self.baseline_by_path.get(path),
             path,
-            self.current_by_path.get(path).map(String::as_str),
+            self.current_by_path.get(path),
         )
     }
 
     fn render_rename_diff(&self, source_path: &Path, dest_path: &Path) -> Option<String> {
         self.render_diff(
             source_path,
-            self.baseline_by_path.get(source_path).map(String::as_str),
+            self.baseline_by_path.get(source_path),
             dest_path,
-            self.current_by_path.get(dest_path).map(String::as_str),
+            self.current_by_path.get(dest_path),
         )
     }
 
     fn render_diff(
         &self,
         left_path: &Path,
-        left_content: Option<&str>,
+        left: Option<&TrackedFile>,
         right_path: &Path,
-        right_content: Option<&str>,
+        right: Option<&TrackedFile>,
     ) -> Option<String> {
-        if left_content == right_content {
+        if left == right {
             return None;
         }
 
         let left_display = self.display_path(left_path);
         let right_display = self.display_path(right_path);
-        let left_oid = left_content.map_or_else(
-            || ZERO_OID.to_string(),
-            |content| git_blob_oid(content.as_bytes()),
-        );
-        let right_oid = right_content.map_or_else(
-            || ZERO_OID.to_string(),
-            |content| git_blob_oid(content.as_bytes()),
-        );
-
         let mut diff = format!("diff --git a/{left_display} b/{right_display}\n");
-        match (left_content, right_content) {
-            (None, Some(_)) => diff.push_str(&format!("new file mode {REGULAR_FILE_MODE}\n")),
-            (Some(_), None) => diff.push_str(&format!("deleted file mode {REGULAR_FILE_MODE}\n")),
-            (Some(_), Some(_)) => {}
+        match (left, right) {
+            (None, Some(right)) => {
+                diff.push_str(&format!("new file mode {}\n", right.mode.as_git_mode()));
+            }
+            (Some(left), None) => {
+                diff.push_str(&format!("deleted file mode {}\n", left.mode.as_git_mode()));
+            }
+            (Some(left), Some(right)) => {
+                if left.mode != right.mode {
+                    diff.push_str(&format!("old mode {}\n", left.mode.as_git_mode()));
+                    diff.push_str(&format!("new mode {}\n", right.mode.as_git_mode()));
+                }
+                if left.contents == right.contents {
+                    return Some(diff);
+                }
+            }
             (None, None) => return None,
         }
 
+        let left_contents = left.map(|file| file.contents.as_slice());
+        let right_contents = right.map(|file| file.contents.as_slice());
+        let left_oid = left_contents.map_or_else(|| ZERO_OID.to_string(), git_blob_oid);
+        let right_oid = right_contents.map_or_else(|| ZERO_OID.to_string(), git_blob_oid);
         diff.push_str(&format!("index {left_oid}..{right_oid}\n"));
 
-        let old_header = if left_content.is_some() {
+        let old_header = if left.is_some() {
             format!("a/{left_display}")
         } else {
             DEV_NULL.to_string()
         };
-        let new_header = if right_content.is_some() {
+        let new_header = if right.is_some() {
             format!("b/{right_display}")
         } else {
             DEV_NULL.to_string()
         };
 
-        let unified =
-            similar::TextDiff::from_lines(left_content.unwrap_or(""), right_content.unwrap_or(""))
-                .unified_diff()
-                .context_radius(3)
-                .header(&old_header, &new_header)
-                .to_string();
+        let (Ok(left_text), Ok(right_text)) = (
+            std::str::from_utf8(left_contents.unwrap_or_default()),
+            std::str::from_utf8(right_contents.unwrap_or_default()),
+        ) else {
+            diff.push_str(&format!(
+                "Binary files {old_header} and {new_header} differ\n"
+            ));
+            return Some(diff);
+        };
+        let unified = similar::TextDiff::from_lines(left_text, right_text)
+            .unified_diff()
+            .context_radius(3)
+            .header(&old_header, &new_header)
+            .to_string();
         diff.push_str(&unified);
         Some(diff)
     }
@@ -328,3 +328,40 @@ index {left_oid_b}..{right_oid_b}
     );
     assert_eq!(tracker.get_unified_diff(), Some(expected));
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn renders_mode_symlink_and_binary_changes() {
+    let dir = tempdir().expect("tempdir");
+    fs::write(dir.path().join("run.sh"), "echo hi\n").expect("seed script");
+
+    let mut tracker = TurnDiffTracker::with_display_root(dir.path().to_path_buf());
+    let delta = apply_verified_patch(
+        dir.path(),
+        "*** Begin Patch\n*** Set Mode: 100755 run.sh\n*** Add Symlink: latest\n+run.sh\n*** Add Binary File: blob.bin\n+AP8=\n*** End Patch",
+    )
+    .await;
+    tracker.track_delta(&delta);
+
+    let blob_oid = format!("{:x}", git_blob_sha1_hex_bytes(&[0, 255]));
+    let link_oid = git_blob_sha1_hex("run.sh");
+    let expected = format!(
+        r#"diff --git a/blob.bin b/blob.bin
+new file mode {REGULAR_FILE_MODE}
+index {ZERO_OID}..{blob_oid}
+Binary files {DEV_NULL} and b/blob.bin differ
+diff --git a/latest b/latest
+new file mode {SYMLINK_MODE}
+index {ZERO_OID}..{link_oid}
+--- {DEV_NULL}
++++ b/latest
+@@ -0,0 +1 @@
++run.sh
+\ No newline at end of file
+diff --git a/run.sh b/run.sh
+old mode {REGULAR_FILE_MODE}
+new mode {EXECUTABLE_FILE_MODE}
+"#,
+    );
+    assert_eq!(tracker.get_unified_diff(), Some(expected));
+}
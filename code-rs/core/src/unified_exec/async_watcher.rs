@@ -9,6 +9,10 @@ use tokio::time::Sleep;
 use super::UnifiedExecContext;
 use super::process::UnifiedExecProcess;
 use crate::exec::MAX_EXEC_OUTPUT_DELTAS_PER_CALL;
+use crate::redaction::RedactionCarryOver;
+use crate::redaction::RedactionSource;
+use crate::redaction::redact_output_chunk;
+use crate::redaction::redact_text;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::tools::events::ToolEmitter;
@@ -22,6 +26,8 @@ use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecCommandOutputDeltaEvent;
 use codex_protocol::protocol::ExecCommandSource;
 use codex_protocol::protocol::ExecOutputStream;
+use codex_sandboxing::landlock::SandboxReportContents;
+use codex_sandboxing::landlock::resource_limit_exceeded;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 pub(crate) const TRAILING_OUTPUT_GRACE: Duration = Duration::from_millis(100);
@@ -35,7 +41,7 @@ pub(crate) const TRAILING_OUTPUT_GRACE: Duration = Duration::from_millis(100);
 const UNIFIED_EXEC_OUTPUT_DELTA_MAX_BYTES: usize = 8192;
 
 /// Spawn a background task that continuously reads from the PTY, appends to the
-/// shared transcript, and emits ExecCommandOutputDelta events on UTF‑8
+/// shared transcript, and emits redacted ExecCommandOutputDelta events on UTF‑8
 /// boundaries.
 pub(crate) fn start_streaming_output(
     process: &UnifiedExecProcess,
@@ -54,6 +60,7 @@ pub(crate) fn start_streaming_output(
         use tokio::sync::broadcast::error::RecvError;
 
         let mut pending = Vec::<u8>::new();
+        let mut redaction = RedactionCarryOver::default();
         let mut emitted_deltas: usize = 0;
 
         let mut grace_sleep: Option<Pin<Box<Sleep>>> = None;
@@ -70,7 +77,6 @@ pub(crate) fn start_streaming_output(
                         sleep.as_mut().await;
                     }
                 }, if grace_sleep.is_some() => {
-                    output_drained.notify_one();
                     break;
                 }
 
@@ -81,13 +87,13 @@ pub(crate) fn start_streaming_output(
                             continue;
                         },
                         Err(RecvError::Closed) => {
-                            output_drained.notify_one();
                             break;
                         }
                     };
 
                     process_chunk(
                         &mut pending,
+                        &mut redaction,
                         &transcript,
                         &call_id,
                         &session_ref,
@@ -98,6 +104,16 @@ pub(crate) fn start_streaming_output(
                 }
             }
         }
+
+        // Release the tail held back for redaction before the end event.
+        let tail = redact_output_chunk(
+            session_ref.as_ref(),
+            &mut redaction,
+            "",
+            /*flush*/ true,
+        );
+        emit_output_delta(&call_id, &session_ref, &turn_ref, &mut emitted_deltas, tail).await;
+        output_drained.notify_one();
     });
 }
 
@@ -123,6 +139,7 @@ pub(crate) fn spawn_exit_watcher(
         output_drained.notified().await;
 
         let duration = Instant::now().saturating_duration_since(started_at);
+        let sandbox_report = process.take_sandbox_report().await;
         if let Some(message) = process.failure_message() {
             emit_failed_exec_end_for_unified_exec(
                 session_ref,
@@ -135,6 +152,7 @@ pub(crate) fn spawn_exit_watcher(
                 String::new(),
                 message,
                 duration,
+                sandbox_report,
             )
             .await;
         } else {
@@ -150,14 +168,17 @@ pub(crate) fn spawn_exit_watcher(
                 String::new(),
                 exit_code,
                 duration,
+                sandbox_report,
             )
             .await;
         }
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_chunk(
     pending: &mut Vec<u8>,
+    redaction: &mut RedactionCarryOver,
     transcript: &Arc<Mutex<HeadTailBuffer>>,
     call_id: &str,
     session_ref: &Arc<Session>,
@@ -176,10 +197,34 @@ async fn process_chunk(
             continue;
         }
 
+        let redacted = redact_output_chunk(
+            session_ref.as_ref(),
+            redaction,
+            &String::from_utf8_lossy(&prefix),
+            /*flush*/ false,
+        );
+        emit_output_delta(call_id, session_ref, turn_ref, emitted_deltas, redacted).await;
+    }
+}
+
+async fn emit_output_delta(
+    call_id: &str,
+    session_ref: &Arc<Session>,
+    turn_ref: &Arc<TurnContext>,
+    emitted_deltas: &mut usize,
+    text: String,
+) {
+    // Held-back text can push a redacted chunk past the per-event cap.
+    let mut text = text.into_bytes();
+    while let Some(chunk) = split_valid_utf8_prefix(&mut text) {
+        if *emitted_deltas >= MAX_EXEC_OUTPUT_DELTAS_PER_CALL {
+            return;
+        }
+
         let event = ExecCommandOutputDeltaEvent {
             call_id: call_id.to_string(),
             stream: ExecOutputStream::Stdout,
-            chunk: prefix,
+            chunk,
         };
         session_ref
             .send_event(turn_ref.as_ref(), EventMsg::ExecCommandOutputDelta(event))
@@ -190,7 +235,9 @@ async fn process_chunk(
 
 /// Emit an ExecCommandEnd event for a unified exec session, using the transcript
 /// as the primary source of aggregated_output and falling back to the provided
-/// text when the transcript is empty.
+/// text when the transcript is empty. The PTY only reports exit codes, so a
+/// resource limit kill is taken from the sandbox report alone, along with
+/// what the sandbox blocked.
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn emit_exec_end_for_unified_exec(
     session_ref: Arc<Session>,
@@ -203,8 +250,13 @@ pub(crate) async fn emit_exec_end_for_unified_exec(
     fallback_output: String,
     exit_code: i32,
     duration: Duration,
+    sandbox_report: Option<SandboxReportContents>,
 ) {
-    let aggregated_output = resolve_aggregated_output(&transcript, fallback_output).await;
+    let aggregated_output = redact_text(
+        session_ref.as_ref(),
+        RedactionSource::CommandOutput,
+        &resolve_aggregated_output(&transcript, fallback_output).await,
+    );
     let output = ExecToolCallOutput {
         exit_code,
         stdout: StreamOutput::new(aggregated_output.clone()),
@@ -212,6 +264,13 @@ pub(crate) async fn emit_exec_end_for_unified_exec(
         aggregated_output: StreamOutput::new(aggregated_output),
         duration,
         timed_out: false,
+        resource_limit_exceeded: resource_limit_exceeded(
+            /*signal*/ None,
+            sandbox_report.as_ref(),
+        ),
+        sandbox_denials: sandbox_report
+            .map(|report| report.denials)
+            .unwrap_or_default(),
     };
     let event_ctx = ToolEventCtx::new(
         session_ref.as_ref(),
@@ -248,12 +307,18 @@ pub(crate) async fn emit_failed_exec_end_for_unified_exec(
     fallback_output: String,
     message: String,
     duration: Duration,
+    sandbox_report: Option<SandboxReportContents>,
 ) {
     let stdout = if fallback_output.is_empty() {
         resolve_aggregated_output(&transcript, fallback_output).await
     } else {
         fallback_output
     };
+    let stdout = redact_text(
+        session_ref.as_ref(),
+        RedactionSource::CommandOutput,
+        &stdout,
+    );
     let aggregated_output = if stdout.is_empty() {
         message.clone()
     } else {
@@ -266,6 +331,13 @@ pub(crate) async fn emit_failed_exec_end_for_unified_exec(
         aggregated_output: StreamOutput::new(aggregated_output),
         duration,
         timed_out: false,
+        resource_limit_exceeded: resource_limit_exceeded(
+            /*signal*/ None,
+            sandbox_report.as_ref(),
+        ),
+        sandbox_denials: sandbox_report
+            .map(|report| report.denials)
+            .unwrap_or_default(),
     };
     let event_ctx = ToolEventCtx::new(
         session_ref.as_ref(),
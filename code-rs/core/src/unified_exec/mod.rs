@@ -36,6 +36,7 @@ use rand::rng;
 use tokio::sync::Mutex;
 
 use crate::sandboxing::SandboxPermissions;
+use crate::secret_injection::SecretEnv;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::tools::network_approval::DeferredNetworkApproval;
@@ -103,6 +104,8 @@ pub(crate) struct ExecCommandRequest {
     pub additional_permissions_preapproved: bool,
     pub justification: Option<String>,
     pub prefix_rule: Option<Vec<String>>,
+    /// Secrets referenced by `command`, injected only when it is spawned.
+    pub secret_env: SecretEnv,
 }
 
 #[derive(Debug)]
@@ -9,7 +9,12 @@ use crate::session::turn_context::TurnContext;
 use crate::tools::context::ExecCommandToolOutput;
 use crate::unified_exec::WriteStdinRequest;
 use crate::unified_exec::process::OutputHandles;
+use codex_protocol::permissions::ResourceLimitKind;
 use codex_sandboxing::SandboxType;
+use codex_sandboxing::landlock::SandboxReport;
+use codex_sandboxing::landlock::SandboxReportContents;
+use codex_sandboxing::landlock::resource_limit_exceeded_report_line;
+use codex_sandboxing::landlock::sandbox_denial_report_line;
 use codex_utils_output_truncation::approx_token_count;
 use core_test_support::get_remote_test_env;
 use core_test_support::skip_if_sandbox;
@@ -93,7 +98,7 @@ async fn exec_command_with_tty(
         manager
             .open_session_with_exec_env(
                 process_id,
-                &request,
+                request,
                 tty,
                 Box::new(NoopSpawnLifecycle),
                 turn.environments
@@ -512,7 +517,7 @@ async fn completed_pipe_commands_preserve_exit_code() -> anyhow::Result<()> {
     let process = UnifiedExecProcessManager::default()
         .open_session_with_exec_env(
             /*process_id*/ 1234,
-            &request,
+            request,
             /*tty*/ false,
             Box::new(NoopSpawnLifecycle),
             &environment,
@@ -534,6 +539,50 @@ async fn completed_pipe_commands_preserve_exit_code() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn exited_processes_hand_over_their_sandbox_report_once() -> anyhow::Result<()> {
+    let (_, turn) = make_session_and_context().await;
+    let mut request = test_exec_request(
+        &turn,
+        vec!["bash".to_string(), "-lc".to_string(), "exit 0".to_string()],
+        turn.cwd.clone(),
+        shell_env(),
+    );
+    let report = SandboxReport::new()?;
+    let report_path = report.path().to_path_buf();
+    std::fs::write(
+        &report_path,
+        format!(
+            "{}{}",
+            resource_limit_exceeded_report_line(ResourceLimitKind::Memory),
+            sandbox_denial_report_line("ptrace (blocked by seccomp)"),
+        ),
+    )?;
+    request.sandbox_report = Some(report);
+
+    let environment = codex_exec_server::Environment::default_for_tests();
+    let process = UnifiedExecProcessManager::default()
+        .open_session_with_exec_env(
+            /*process_id*/ 1234,
+            request,
+            /*tty*/ false,
+            Box::new(NoopSpawnLifecycle),
+            &environment,
+        )
+        .await?;
+
+    assert_eq!(
+        process.take_sandbox_report().await,
+        Some(SandboxReportContents {
+            resource_limit_exceeded: Some(ResourceLimitKind::Memory),
+            denials: vec!["ptrace (blocked by seccomp)".to_string()],
+        })
+    );
+    assert_eq!(process.take_sandbox_report().await, None);
+    std::fs::remove_file(report_path)?;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn unified_exec_uses_remote_exec_server_when_configured() -> anyhow::Result<()> {
     skip_if_sandbox!(Ok(()));
@@ -554,7 +603,7 @@ async fn unified_exec_uses_remote_exec_server_when_configured() -> anyhow::Resul
     let process = manager
         .open_session_with_exec_env(
             /*process_id*/ 1234,
-            &request,
+            request,
             /*tty*/ true,
             Box::new(NoopSpawnLifecycle),
             remote_test_env.environment(),
@@ -609,7 +658,7 @@ async fn remote_exec_server_rejects_inherited_fd_launches() -> anyhow::Result<()
     let err = manager
         .open_session_with_exec_env(
             /*process_id*/ 1234,
-            &request,
+            request,
             /*tty*/ true,
             Box::new(TestSpawnLifecycle {
                 inherited_fds: vec![42],
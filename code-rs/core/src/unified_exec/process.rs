@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
 
 use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+use std::sync::PoisonError;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use tokio::sync::Mutex;
@@ -13,6 +15,11 @@ use tokio::time::Duration;
 use tokio_util::sync::CancellationToken;
 
 use crate::exec::is_likely_sandbox_denied;
+use crate::exec::read_sandbox_report;
+use crate::redaction::RedactionCarryOver;
+use crate::redaction::redact_unified_exec_output;
+use crate::session::session::Session;
+use crate::tools::context::ExecCommandToolOutput;
 use codex_exec_server::ExecProcess;
 use codex_exec_server::ReadResponse as ExecReadResponse;
 use codex_exec_server::StartedExecProcess;
@@ -21,6 +28,8 @@ use codex_protocol::exec_output::ExecToolCallOutput;
 use codex_protocol::exec_output::StreamOutput;
 use codex_protocol::protocol::TruncationPolicy;
 use codex_sandboxing::SandboxType;
+use codex_sandboxing::landlock::SandboxReport;
+use codex_sandboxing::landlock::SandboxReportContents;
 use codex_utils_output_truncation::formatted_truncate_text;
 use codex_utils_pty::ExecCommandSession;
 use codex_utils_pty::SpawnedPty;
@@ -84,6 +93,8 @@ pub(crate) struct UnifiedExecProcess {
     state_rx: watch::Receiver<ProcessState>,
     output_task: Option<JoinHandle<()>>,
     sandbox_type: SandboxType,
+    sandbox_report: Mutex<Option<SandboxReport>>,
+    poll_redaction: StdMutex<RedactionCarryOver>,
     _spawn_lifecycle: Option<SpawnLifecycleHandle>,
 }
 
@@ -101,6 +112,7 @@ impl UnifiedExecProcess {
     fn new(
         process_handle: ProcessHandle,
         sandbox_type: SandboxType,
+        sandbox_report: Option<SandboxReport>,
         spawn_lifecycle: Option<SpawnLifecycleHandle>,
     ) -> Self {
         let output_buffer = Arc::new(Mutex::new(HeadTailBuffer::default()));
@@ -125,6 +137,8 @@ impl UnifiedExecProcess {
             state_rx,
             output_task: None,
             sandbox_type,
+            sandbox_report: Mutex::new(sandbox_report),
+            poll_redaction: StdMutex::new(RedactionCarryOver::default()),
             _spawn_lifecycle: spawn_lifecycle,
         }
     }
@@ -229,6 +243,23 @@ impl UnifiedExecProcess {
         self.sandbox_type
     }
 
+    /// Reads what the Linux sandbox helper recorded for this process. The
+    /// report is consumed, so call this once the process has exited.
+    pub(super) async fn take_sandbox_report(&self) -> Option<SandboxReportContents> {
+        let report = self.sandbox_report.lock().await.take();
+        read_sandbox_report(report).await
+    }
+
+    /// Redacts the output of a poll. A trailing token that may continue in
+    /// the next poll is held back until then, or until the process exits.
+    pub(super) fn redact_poll_output(&self, session: &Session, output: &mut ExecCommandToolOutput) {
+        let mut carry_over = self
+            .poll_redaction
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        redact_unified_exec_output(session, &mut carry_over, output);
+    }
+
     pub(super) fn failure_message(&self) -> Option<String> {
         self.state_rx.borrow().failure_message.clone()
     }
@@ -283,6 +314,7 @@ impl UnifiedExecProcess {
     pub(super) async fn from_spawned(
         spawned: SpawnedPty,
         sandbox_type: SandboxType,
+        sandbox_report: Option<SandboxReport>,
         spawn_lifecycle: SpawnLifecycleHandle,
     ) -> Result<Self, UnifiedExecError> {
         let SpawnedPty {
@@ -295,6 +327,7 @@ impl UnifiedExecProcess {
         let mut managed = Self::new(
             ProcessHandle::Local(Box::new(process_handle)),
             sandbox_type,
+            sandbox_report,
             Some(spawn_lifecycle),
         );
         managed.output_task = Some(Self::spawn_local_output_task(
@@ -345,7 +378,12 @@ impl UnifiedExecProcess {
         sandbox_type: SandboxType,
     ) -> Result<Self, UnifiedExecError> {
         let process_handle = ProcessHandle::ExecServer(Arc::clone(&started.process));
-        let mut managed = Self::new(process_handle, sandbox_type, /*spawn_lifecycle*/ None);
+        let mut managed = Self::new(
+            process_handle,
+            sandbox_type,
+            /*sandbox_report*/ None,
+            /*spawn_lifecycle*/ None,
+        );
         let output_handles = managed.output_handles();
         managed.output_task = Some(Self::spawn_exec_server_output_task(
             started,
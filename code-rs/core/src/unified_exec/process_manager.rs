@@ -277,6 +277,7 @@ fn fail_process_with_message(process: &UnifiedExecProcess, message: String) -> U
 
 #[allow(clippy::too_many_arguments)]
 async fn emit_failed_initial_exec_end_if_unstored(
+    process: &UnifiedExecProcess,
     process_started_alive: bool,
     context: &UnifiedExecContext,
     request: &ExecCommandRequest,
@@ -301,6 +302,7 @@ async fn emit_failed_initial_exec_end_if_unstored(
         fallback_output,
         message,
         wall_time,
+        process.take_sandbox_report().await,
     )
     .await;
 }
@@ -469,6 +471,7 @@ impl UnifiedExecProcessManager {
             )
             .await;
             emit_failed_initial_exec_end_if_unstored(
+                process.as_ref(),
                 process_started_alive,
                 context,
                 &request,
@@ -489,6 +492,7 @@ impl UnifiedExecProcessManager {
             )
             .await;
             emit_failed_initial_exec_end_if_unstored(
+                process.as_ref(),
                 process_started_alive,
                 context,
                 &request,
@@ -541,6 +545,7 @@ impl UnifiedExecProcessManager {
             .await;
             if let Err(message) = finish_result {
                 emit_failed_initial_exec_end_if_unstored(
+                    process.as_ref(),
                     process_started_alive,
                     context,
                     &request,
@@ -567,6 +572,7 @@ impl UnifiedExecProcessManager {
                 text.clone(),
                 exit,
                 wall_time,
+                process.take_sandbox_report().await,
             )
             .await;
 
@@ -576,7 +582,7 @@ impl UnifiedExecProcessManager {
         };
 
         let original_token_count = approx_token_count(&text);
-        let response = ExecCommandToolOutput {
+        let mut response = ExecCommandToolOutput {
             event_call_id: context.call_id.clone(),
             chunk_id,
             wall_time,
@@ -587,6 +593,7 @@ impl UnifiedExecProcessManager {
             original_token_count: Some(original_token_count),
             hook_command: Some(request.hook_command.clone()),
         };
+        process.redact_poll_output(&context.session, &mut response);
 
         Ok(response)
     }
@@ -720,7 +727,7 @@ impl UnifiedExecProcessManager {
             }
         };
 
-        let response = ExecCommandToolOutput {
+        let mut response = ExecCommandToolOutput {
             event_call_id,
             chunk_id,
             wall_time,
@@ -731,6 +738,9 @@ impl UnifiedExecProcessManager {
             original_token_count: Some(original_token_count),
             hook_command: Some(hook_command),
         };
+        if let Some(session) = session.as_ref() {
+            process.redact_poll_output(session, &mut response);
+        }
 
         Ok(response)
     }
@@ -865,12 +875,13 @@ impl UnifiedExecProcessManager {
     pub(crate) async fn open_session_with_exec_env(
         &self,
         process_id: i32,
-        request: &ExecRequest,
+        mut request: ExecRequest,
         tty: bool,
         mut spawn_lifecycle: SpawnLifecycleHandle,
         environment: &codex_exec_server::Environment,
     ) -> Result<UnifiedExecProcess, UnifiedExecError> {
         let inherited_fds = spawn_lifecycle.inherited_fds();
+        let sandbox_report = request.sandbox_report.take();
 
         #[cfg(target_os = "windows")]
         if request.sandbox == codex_sandboxing::SandboxType::WindowsRestrictedToken {
@@ -922,6 +933,7 @@ impl UnifiedExecProcessManager {
             return UnifiedExecProcess::from_spawned(
                 spawned.map_err(|err| UnifiedExecError::create_process(err.to_string()))?,
                 request.sandbox,
+                sandbox_report,
                 spawn_lifecycle,
             )
             .await;
@@ -935,7 +947,7 @@ impl UnifiedExecProcessManager {
 
             let started = environment
                 .get_exec_backend()
-                .start(exec_server_params_for_request(process_id, request, tty))
+                .start(exec_server_params_for_request(process_id, &request, tty))
                 .await
                 .map_err(|err| UnifiedExecError::create_process(err.to_string()))?;
             spawn_lifecycle.after_spawn();
@@ -971,7 +983,8 @@ impl UnifiedExecProcessManager {
         let spawned =
             spawn_result.map_err(|err| UnifiedExecError::create_process(err.to_string()))?;
         spawn_lifecycle.after_spawn();
-        UnifiedExecProcess::from_spawned(spawned, request.sandbox, spawn_lifecycle).await
+        UnifiedExecProcess::from_spawned(spawned, request.sandbox, sandbox_report, spawn_lifecycle)
+            .await
     }
 
     pub(super) async fn open_session_with_sandbox(
@@ -989,7 +1002,11 @@ impl UnifiedExecProcessManager {
             CODEX_THREAD_ID_ENV_VAR.to_string(),
             context.session.conversation_id.to_string(),
         );
-        let env = apply_unified_exec_env(env);
+        let mut env = apply_unified_exec_env(env);
+        let mut explicit_env_overrides = context.turn.shell_environment_policy.r#set.clone();
+        request
+            .secret_env
+            .apply(&mut env, &mut explicit_env_overrides);
         let exec_server_env_config = ExecServerEnvConfig {
             policy: exec_env_policy_from_shell_policy(&context.turn.shell_environment_policy),
             local_policy_env,
@@ -1019,14 +1036,14 @@ impl UnifiedExecProcessManager {
             })
             .await;
         let req = UnifiedExecToolRequest {
-            command: request.command.clone(),
+            command: request.secret_env.rewrite_command(&request.command),
             hook_command: request.hook_command.clone(),
             process_id: request.process_id,
             cwd,
             environment: Arc::clone(&request.environment),
             env,
             exec_server_env_config: Some(exec_server_env_config),
-            explicit_env_overrides: context.turn.shell_environment_policy.r#set.clone(),
+            explicit_env_overrides,
             network: request.network.clone(),
             tty: request.tty,
             sandbox_permissions: request.sandbox_permissions,
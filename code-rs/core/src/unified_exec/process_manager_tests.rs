@@ -114,6 +114,8 @@ fn exec_server_params_use_env_policy_overlay_contract() {
         network_sandbox_policy,
         windows_sandbox_filesystem_overrides: None,
         arg0: None,
+        resource_limits: codex_protocol::permissions::ResourceLimits::default(),
+        sandbox_report: None,
     };
 
     let params =
@@ -187,6 +189,7 @@ async fn failed_initial_end_for_unstored_process_uses_fallback_output() {
         additional_permissions_preapproved: false,
         justification: None,
         prefix_rule: None,
+        secret_env: crate::secret_injection::SecretEnv::default(),
     };
 
     let transcript = Arc::new(tokio::sync::Mutex::new(HeadTailBuffer::default()));
@@ -24,6 +24,8 @@ async fn formats_basic_record() {
         aggregated_output: StreamOutput::new("hi".to_string()),
         duration: Duration::from_secs(1),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let (_, turn_context) = make_session_and_context().await;
     let item = user_shell_command_record_item("echo hi", &exec_output, &turn_context);
@@ -48,6 +50,8 @@ async fn uses_aggregated_output_over_streams() {
         aggregated_output: StreamOutput::new("combined output wins".to_string()),
         duration: Duration::from_millis(120),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let (_, turn_context) = make_session_and_context().await;
     let record = format_user_shell_command_record("false", &exec_output, &turn_context);
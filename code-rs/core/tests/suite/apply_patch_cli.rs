@@ -1040,6 +1040,7 @@ async fn apply_patch_custom_tool_streaming_emits_updated_changes() -> Result<()>
             .get(&std::path::PathBuf::from("streamed.txt")),
         Some(&codex_protocol::protocol::FileChange::Add {
             content: String::new(),
+            executable: None,
         })
     );
     assert_eq!(
@@ -1050,6 +1051,7 @@ async fn apply_patch_custom_tool_streaming_emits_updated_changes() -> Result<()>
             .get(&std::path::PathBuf::from("streamed.txt")),
         Some(&codex_protocol::protocol::FileChange::Add {
             content: "hello\nworld\n".to_string(),
+            executable: None,
         })
     );
     assert_eq!(
@@ -12,15 +12,20 @@ use crate::RemoveOptions;
 use crate::local_file_system::DirectFileSystem;
 use crate::protocol::FS_COPY_METHOD;
 use crate::protocol::FS_CREATE_DIRECTORY_METHOD;
+use crate::protocol::FS_CREATE_SYMLINK_METHOD;
 use crate::protocol::FS_GET_METADATA_METHOD;
 use crate::protocol::FS_READ_DIRECTORY_METHOD;
 use crate::protocol::FS_READ_FILE_METHOD;
 use crate::protocol::FS_REMOVE_METHOD;
+use crate::protocol::FS_RENAME_METHOD;
+use crate::protocol::FS_SET_EXECUTABLE_METHOD;
 use crate::protocol::FS_WRITE_FILE_METHOD;
 use crate::protocol::FsCopyParams;
 use crate::protocol::FsCopyResponse;
 use crate::protocol::FsCreateDirectoryParams;
 use crate::protocol::FsCreateDirectoryResponse;
+use crate::protocol::FsCreateSymlinkParams;
+use crate::protocol::FsCreateSymlinkResponse;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsGetMetadataResponse;
 use crate::protocol::FsReadDirectoryEntry;
@@ -30,6 +35,10 @@ use crate::protocol::FsReadFileParams;
 use crate::protocol::FsReadFileResponse;
 use crate::protocol::FsRemoveParams;
 use crate::protocol::FsRemoveResponse;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsRenameResponse;
+use crate::protocol::FsSetExecutableParams;
+use crate::protocol::FsSetExecutableResponse;
 use crate::protocol::FsWriteFileParams;
 use crate::protocol::FsWriteFileResponse;
 use crate::rpc::internal_error;
@@ -55,6 +64,12 @@ pub(crate) enum FsHelperRequest {
     Remove(FsRemoveParams),
     #[serde(rename = "fs/copy")]
     Copy(FsCopyParams),
+    #[serde(rename = "fs/rename")]
+    Rename(FsRenameParams),
+    #[serde(rename = "fs/setExecutable")]
+    SetExecutable(FsSetExecutableParams),
+    #[serde(rename = "fs/createSymlink")]
+    CreateSymlink(FsCreateSymlinkParams),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -81,6 +96,12 @@ pub(crate) enum FsHelperPayload {
     Remove(FsRemoveResponse),
     #[serde(rename = "fs/copy")]
     Copy(FsCopyResponse),
+    #[serde(rename = "fs/rename")]
+    Rename(FsRenameResponse),
+    #[serde(rename = "fs/setExecutable")]
+    SetExecutable(FsSetExecutableResponse),
+    #[serde(rename = "fs/createSymlink")]
+    CreateSymlink(FsCreateSymlinkResponse),
 }
 
 impl FsHelperPayload {
@@ -93,6 +114,9 @@ impl FsHelperPayload {
             Self::ReadDirectory(_) => FS_READ_DIRECTORY_METHOD,
             Self::Remove(_) => FS_REMOVE_METHOD,
             Self::Copy(_) => FS_COPY_METHOD,
+            Self::Rename(_) => FS_RENAME_METHOD,
+            Self::SetExecutable(_) => FS_SET_EXECUTABLE_METHOD,
+            Self::CreateSymlink(_) => FS_CREATE_SYMLINK_METHOD,
         }
     }
 
@@ -157,6 +181,37 @@ impl FsHelperPayload {
             other => Err(unexpected_response(FS_COPY_METHOD, other.operation())),
         }
     }
+
+    pub(crate) fn expect_rename(self) -> Result<FsRenameResponse, JSONRPCErrorError> {
+        match self {
+            Self::Rename(response) => Ok(response),
+            other => Err(unexpected_response(FS_RENAME_METHOD, other.operation())),
+        }
+    }
+
+    pub(crate) fn expect_set_executable(
+        self,
+    ) -> Result<FsSetExecutableResponse, JSONRPCErrorError> {
+        match self {
+            Self::SetExecutable(response) => Ok(response),
+            other => Err(unexpected_response(
+                FS_SET_EXECUTABLE_METHOD,
+                other.operation(),
+            )),
+        }
+    }
+
+    pub(crate) fn expect_create_symlink(
+        self,
+    ) -> Result<FsCreateSymlinkResponse, JSONRPCErrorError> {
+        match self {
+            Self::CreateSymlink(response) => Ok(response),
+            other => Err(unexpected_response(
+                FS_CREATE_SYMLINK_METHOD,
+                other.operation(),
+            )),
+        }
+    }
 }
 
 fn unexpected_response(expected: &str, actual: &str) -> JSONRPCErrorError {
@@ -215,6 +270,7 @@ pub(crate) async fn run_direct_request(
                 is_directory: metadata.is_directory,
                 is_file: metadata.is_file,
                 is_symlink: metadata.is_symlink,
+                is_executable: metadata.is_executable,
                 created_at_ms: metadata.created_at_ms,
                 modified_at_ms: metadata.modified_at_ms,
             }))
@@ -263,6 +319,31 @@ pub(crate) async fn run_direct_request(
                 .map_err(map_fs_error)?;
             Ok(FsHelperPayload::Copy(FsCopyResponse {}))
         }
+        FsHelperRequest::Rename(params) => {
+            file_system
+                .rename(
+                    &params.source_path,
+                    &params.destination_path,
+                    /*sandbox*/ None,
+                )
+                .await
+                .map_err(map_fs_error)?;
+            Ok(FsHelperPayload::Rename(FsRenameResponse {}))
+        }
+        FsHelperRequest::SetExecutable(params) => {
+            file_system
+                .set_executable(&params.path, params.executable, /*sandbox*/ None)
+                .await
+                .map_err(map_fs_error)?;
+            Ok(FsHelperPayload::SetExecutable(FsSetExecutableResponse {}))
+        }
+        FsHelperRequest::CreateSymlink(params) => {
+            file_system
+                .create_symlink(&params.target, &params.link_path, /*sandbox*/ None)
+                .await
+                .map_err(map_fs_error)?;
+            Ok(FsHelperPayload::CreateSymlink(FsCreateSymlinkResponse {}))
+        }
     }
 }
 
@@ -65,6 +65,8 @@ pub use protocol::FsCopyParams;
 pub use protocol::FsCopyResponse;
 pub use protocol::FsCreateDirectoryParams;
 pub use protocol::FsCreateDirectoryResponse;
+pub use protocol::FsCreateSymlinkParams;
+pub use protocol::FsCreateSymlinkResponse;
 pub use protocol::FsGetMetadataParams;
 pub use protocol::FsGetMetadataResponse;
 pub use protocol::FsReadDirectoryEntry;
@@ -74,6 +76,10 @@ pub use protocol::FsReadFileParams;
 pub use protocol::FsReadFileResponse;
 pub use protocol::FsRemoveParams;
 pub use protocol::FsRemoveResponse;
+pub use protocol::FsRenameParams;
+pub use protocol::FsRenameResponse;
+pub use protocol::FsSetExecutableParams;
+pub use protocol::FsSetExecutableResponse;
 pub use protocol::FsWriteFileParams;
 pub use protocol::FsWriteFileResponse;
 pub use protocol::HttpHeader;
@@ -148,6 +148,37 @@ impl ExecutorFileSystem for LocalFileSystem {
             .copy(source_path, destination_path, options, sandbox)
             .await
     }
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let (file_system, sandbox) = self.file_system_for(sandbox)?;
+        file_system
+            .rename(source_path, destination_path, sandbox)
+            .await
+    }
+
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let (file_system, sandbox) = self.file_system_for(sandbox)?;
+        file_system.set_executable(path, executable, sandbox).await
+    }
+
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let (file_system, sandbox) = self.file_system_for(sandbox)?;
+        file_system.create_symlink(target, link_path, sandbox).await
+    }
 }
 
 #[async_trait]
@@ -234,6 +265,41 @@ impl ExecutorFileSystem for UnsandboxedFileSystem {
             )
             .await
     }
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_platform_sandbox_context(sandbox)?;
+        self.file_system
+            .rename(source_path, destination_path, /*sandbox*/ None)
+            .await
+    }
+
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_platform_sandbox_context(sandbox)?;
+        self.file_system
+            .set_executable(path, executable, /*sandbox*/ None)
+            .await
+    }
+
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_platform_sandbox_context(sandbox)?;
+        self.file_system
+            .create_symlink(target, link_path, /*sandbox*/ None)
+            .await
+    }
 }
 
 #[async_trait]
@@ -291,6 +357,7 @@ impl ExecutorFileSystem for DirectFileSystem {
             is_directory: metadata.is_dir(),
             is_file: metadata.is_file(),
             is_symlink: symlink_metadata.file_type().is_symlink(),
+            is_executable: is_executable(&metadata),
             created_at_ms: metadata.created().ok().map_or(0, system_time_to_unix_ms),
             modified_at_ms: metadata.modified().ok().map_or(0, system_time_to_unix_ms),
         })
@@ -395,6 +462,58 @@ impl ExecutorFileSystem for DirectFileSystem {
         .await
         .map_err(|err| io::Error::other(format!("filesystem task failed: {err}")))?
     }
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_sandbox_context(sandbox)?;
+        tokio::fs::rename(source_path.as_path(), destination_path.as_path()).await
+    }
+
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_sandbox_context(sandbox)?;
+        let metadata = tokio::fs::metadata(path.as_path()).await?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = metadata.permissions().mode();
+            // Like `chmod +x` under a typical umask, grant execute to whoever
+            // can read the file.
+            let mode = if executable {
+                mode | ((mode & 0o444) >> 2)
+            } else {
+                mode & !0o111
+            };
+            tokio::fs::set_permissions(path.as_path(), std::fs::Permissions::from_mode(mode)).await
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (metadata, executable);
+            Ok(())
+        }
+    }
+
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        reject_sandbox_context(sandbox)?;
+        let target = target.to_path_buf();
+        let link_path = link_path.to_path_buf();
+        tokio::task::spawn_blocking(move || create_symlink(&target, &link_path))
+            .await
+            .map_err(|err| io::Error::other(format!("filesystem task failed: {err}")))?
+    }
 }
 
 fn reject_sandbox_context(sandbox: Option<&FileSystemSandboxContext>) -> io::Result<()> {
@@ -497,6 +616,44 @@ fn copy_symlink(source: &Path, target: &Path) -> io::Result<()> {
     }
 }
 
+fn create_symlink(target: &Path, link_path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link_path)
+    }
+    #[cfg(windows)]
+    {
+        let resolved_target = link_path
+            .parent()
+            .map_or_else(|| target.to_path_buf(), |parent| parent.join(target));
+        if resolved_target.is_dir() {
+            std::os::windows::fs::symlink_dir(target, link_path)
+        } else {
+            std::os::windows::fs::symlink_file(target, link_path)
+        }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (target, link_path);
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "creating symlinks is unsupported on this platform",
+        ))
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &std::fs::Metadata) -> bool {
+    false
+}
+
 #[cfg(windows)]
 fn symlink_points_to_directory(source: &Path) -> io::Result<bool> {
     use std::os::windows::fs::FileTypeExt;
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use std::path::Path;
 use tokio::io;
 use tracing::trace;
 
@@ -17,10 +18,13 @@ use crate::RemoveOptions;
 use crate::client::LazyRemoteExecServerClient;
 use crate::protocol::FsCopyParams;
 use crate::protocol::FsCreateDirectoryParams;
+use crate::protocol::FsCreateSymlinkParams;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsReadDirectoryParams;
 use crate::protocol::FsReadFileParams;
 use crate::protocol::FsRemoveParams;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsSetExecutableParams;
 use crate::protocol::FsWriteFileParams;
 
 const INVALID_REQUEST_ERROR_CODE: i64 = -32600;
@@ -118,6 +122,7 @@ impl ExecutorFileSystem for RemoteFileSystem {
             is_directory: response.is_directory,
             is_file: response.is_file,
             is_symlink: response.is_symlink,
+            is_executable: response.is_executable,
             created_at_ms: response.created_at_ms,
             modified_at_ms: response.modified_at_ms,
         })
@@ -188,6 +193,63 @@ impl ExecutorFileSystem for RemoteFileSystem {
             .map_err(map_remote_error)?;
         Ok(())
     }
+
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        trace!("remote fs rename");
+        let client = self.client.get().await.map_err(map_remote_error)?;
+        client
+            .fs_rename(FsRenameParams {
+                source_path: source_path.clone(),
+                destination_path: destination_path.clone(),
+                sandbox: remote_sandbox_context(sandbox),
+            })
+            .await
+            .map_err(map_remote_error)?;
+        Ok(())
+    }
+
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        trace!("remote fs set_executable");
+        let client = self.client.get().await.map_err(map_remote_error)?;
+        client
+            .fs_set_executable(FsSetExecutableParams {
+                path: path.clone(),
+                executable,
+                sandbox: remote_sandbox_context(sandbox),
+            })
+            .await
+            .map_err(map_remote_error)?;
+        Ok(())
+    }
+
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        trace!("remote fs create_symlink");
+        let client = self.client.get().await.map_err(map_remote_error)?;
+        client
+            .fs_create_symlink(FsCreateSymlinkParams {
+                target: target.to_path_buf(),
+                link_path: link_path.clone(),
+                sandbox: remote_sandbox_context(sandbox),
+            })
+            .await
+            .map_err(map_remote_error)?;
+        Ok(())
+    }
 }
 
 fn remote_sandbox_context(
@@ -3,6 +3,7 @@ use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD;
 use codex_app_server_protocol::JSONRPCErrorError;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use std::path::Path;
 use tokio::io;
 
 use crate::CopyOptions;
@@ -19,10 +20,13 @@ use crate::fs_helper::FsHelperRequest;
 use crate::fs_sandbox::FileSystemSandboxRunner;
 use crate::protocol::FsCopyParams;
 use crate::protocol::FsCreateDirectoryParams;
+use crate::protocol::FsCreateSymlinkParams;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsReadDirectoryParams;
 use crate::protocol::FsReadFileParams;
 use crate::protocol::FsRemoveParams;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsSetExecutableParams;
 use crate::protocol::FsWriteFileParams;
 
 #[derive(Clone)]
@@ -139,6 +143,7 @@ impl ExecutorFileSystem for SandboxedFileSystem {
             is_directory: response.is_directory,
             is_file: response.is_file,
             is_symlink: response.is_symlink,
+            is_executable: response.is_executable,
             created_at_ms: response.created_at_ms,
             modified_at_ms: response.modified_at_ms,
         })
@@ -216,6 +221,69 @@ impl ExecutorFileSystem for SandboxedFileSystem {
         .map_err(map_sandbox_error)?;
         Ok(())
     }
+
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let sandbox = require_platform_sandbox(sandbox)?;
+        self.run_sandboxed(
+            sandbox,
+            FsHelperRequest::Rename(FsRenameParams {
+                source_path: source_path.clone(),
+                destination_path: destination_path.clone(),
+                sandbox: None,
+            }),
+        )
+        .await?
+        .expect_rename()
+        .map_err(map_sandbox_error)?;
+        Ok(())
+    }
+
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let sandbox = require_platform_sandbox(sandbox)?;
+        self.run_sandboxed(
+            sandbox,
+            FsHelperRequest::SetExecutable(FsSetExecutableParams {
+                path: path.clone(),
+                executable,
+                sandbox: None,
+            }),
+        )
+        .await?
+        .expect_set_executable()
+        .map_err(map_sandbox_error)?;
+        Ok(())
+    }
+
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()> {
+        let sandbox = require_platform_sandbox(sandbox)?;
+        self.run_sandboxed(
+            sandbox,
+            FsHelperRequest::CreateSymlink(FsCreateSymlinkParams {
+                target: target.to_path_buf(),
+                link_path: link_path.clone(),
+                sandbox: None,
+            }),
+        )
+        .await?
+        .expect_create_symlink()
+        .map_err(map_sandbox_error)?;
+        Ok(())
+    }
 }
 
 fn require_platform_sandbox(
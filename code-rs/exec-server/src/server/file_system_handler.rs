@@ -15,6 +15,8 @@ use crate::protocol::FsCopyParams;
 use crate::protocol::FsCopyResponse;
 use crate::protocol::FsCreateDirectoryParams;
 use crate::protocol::FsCreateDirectoryResponse;
+use crate::protocol::FsCreateSymlinkParams;
+use crate::protocol::FsCreateSymlinkResponse;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsGetMetadataResponse;
 use crate::protocol::FsReadDirectoryEntry;
@@ -24,6 +26,10 @@ use crate::protocol::FsReadFileParams;
 use crate::protocol::FsReadFileResponse;
 use crate::protocol::FsRemoveParams;
 use crate::protocol::FsRemoveResponse;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsRenameResponse;
+use crate::protocol::FsSetExecutableParams;
+use crate::protocol::FsSetExecutableResponse;
 use crate::protocol::FsWriteFileParams;
 use crate::protocol::FsWriteFileResponse;
 use crate::rpc::internal_error;
@@ -101,6 +107,7 @@ impl FileSystemHandler {
             is_directory: metadata.is_directory,
             is_file: metadata.is_file,
             is_symlink: metadata.is_symlink,
+            is_executable: metadata.is_executable,
             created_at_ms: metadata.created_at_ms,
             modified_at_ms: metadata.modified_at_ms,
         })
@@ -159,6 +166,43 @@ impl FileSystemHandler {
             .map_err(map_fs_error)?;
         Ok(FsCopyResponse {})
     }
+
+    pub(crate) async fn rename(
+        &self,
+        params: FsRenameParams,
+    ) -> Result<FsRenameResponse, JSONRPCErrorError> {
+        self.file_system
+            .rename(
+                &params.source_path,
+                &params.destination_path,
+                params.sandbox.as_ref(),
+            )
+            .await
+            .map_err(map_fs_error)?;
+        Ok(FsRenameResponse {})
+    }
+
+    pub(crate) async fn set_executable(
+        &self,
+        params: FsSetExecutableParams,
+    ) -> Result<FsSetExecutableResponse, JSONRPCErrorError> {
+        self.file_system
+            .set_executable(&params.path, params.executable, params.sandbox.as_ref())
+            .await
+            .map_err(map_fs_error)?;
+        Ok(FsSetExecutableResponse {})
+    }
+
+    pub(crate) async fn create_symlink(
+        &self,
+        params: FsCreateSymlinkParams,
+    ) -> Result<FsCreateSymlinkResponse, JSONRPCErrorError> {
+        self.file_system
+            .create_symlink(&params.target, &params.link_path, params.sandbox.as_ref())
+            .await
+            .map_err(map_fs_error)?;
+        Ok(FsCreateSymlinkResponse {})
+    }
 }
 
 fn map_fs_error(err: io::Error) -> JSONRPCErrorError {
@@ -20,6 +20,8 @@ use crate::protocol::FsCopyParams;
 use crate::protocol::FsCopyResponse;
 use crate::protocol::FsCreateDirectoryParams;
 use crate::protocol::FsCreateDirectoryResponse;
+use crate::protocol::FsCreateSymlinkParams;
+use crate::protocol::FsCreateSymlinkResponse;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsGetMetadataResponse;
 use crate::protocol::FsReadDirectoryParams;
@@ -28,6 +30,10 @@ use crate::protocol::FsReadFileParams;
 use crate::protocol::FsReadFileResponse;
 use crate::protocol::FsRemoveParams;
 use crate::protocol::FsRemoveResponse;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsRenameResponse;
+use crate::protocol::FsSetExecutableParams;
+use crate::protocol::FsSetExecutableResponse;
 use crate::protocol::FsWriteFileParams;
 use crate::protocol::FsWriteFileResponse;
 use crate::protocol::HttpRequestParams;
@@ -264,6 +270,30 @@ impl ExecServerHandler {
         self.file_system.copy(params).await
     }
 
+    pub(crate) async fn fs_rename(
+        &self,
+        params: FsRenameParams,
+    ) -> Result<FsRenameResponse, JSONRPCErrorError> {
+        self.require_initialized_for("filesystem")?;
+        self.file_system.rename(params).await
+    }
+
+    pub(crate) async fn fs_set_executable(
+        &self,
+        params: FsSetExecutableParams,
+    ) -> Result<FsSetExecutableResponse, JSONRPCErrorError> {
+        self.require_initialized_for("filesystem")?;
+        self.file_system.set_executable(params).await
+    }
+
+    pub(crate) async fn fs_create_symlink(
+        &self,
+        params: FsCreateSymlinkParams,
+    ) -> Result<FsCreateSymlinkResponse, JSONRPCErrorError> {
+        self.require_initialized_for("filesystem")?;
+        self.file_system.create_symlink(params).await
+    }
+
     fn require_initialized_for(
         &self,
         method_family: &str,
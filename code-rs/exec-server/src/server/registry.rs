@@ -7,17 +7,23 @@ use crate::protocol::EXEC_WRITE_METHOD;
 use crate::protocol::ExecParams;
 use crate::protocol::FS_COPY_METHOD;
 use crate::protocol::FS_CREATE_DIRECTORY_METHOD;
+use crate::protocol::FS_CREATE_SYMLINK_METHOD;
 use crate::protocol::FS_GET_METADATA_METHOD;
 use crate::protocol::FS_READ_DIRECTORY_METHOD;
 use crate::protocol::FS_READ_FILE_METHOD;
 use crate::protocol::FS_REMOVE_METHOD;
+use crate::protocol::FS_RENAME_METHOD;
+use crate::protocol::FS_SET_EXECUTABLE_METHOD;
 use crate::protocol::FS_WRITE_FILE_METHOD;
 use crate::protocol::FsCopyParams;
 use crate::protocol::FsCreateDirectoryParams;
+use crate::protocol::FsCreateSymlinkParams;
 use crate::protocol::FsGetMetadataParams;
 use crate::protocol::FsReadDirectoryParams;
 use crate::protocol::FsReadFileParams;
 use crate::protocol::FsRemoveParams;
+use crate::protocol::FsRenameParams;
+use crate::protocol::FsSetExecutableParams;
 use crate::protocol::FsWriteFileParams;
 use crate::protocol::HTTP_REQUEST_METHOD;
 use crate::protocol::HttpRequestParams;
@@ -114,5 +120,23 @@ pub(crate) fn build_router() -> RpcRouter<ExecServerHandler> {
             handler.fs_copy(params).await
         },
     );
+    router.request(
+        FS_RENAME_METHOD,
+        |handler: Arc<ExecServerHandler>, params: FsRenameParams| async move {
+            handler.fs_rename(params).await
+        },
+    );
+    router.request(
+        FS_SET_EXECUTABLE_METHOD,
+        |handler: Arc<ExecServerHandler>, params: FsSetExecutableParams| async move {
+            handler.fs_set_executable(params).await
+        },
+    );
+    router.request(
+        FS_CREATE_SYMLINK_METHOD,
+        |handler: Arc<ExecServerHandler>, params: FsCreateSymlinkParams| async move {
+            handler.fs_create_symlink(params).await
+        },
+    );
     router
 }
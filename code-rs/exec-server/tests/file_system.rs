@@ -2,7 +2,6 @@
 
 mod common;
 
-#[cfg(target_os = "linux")]
 use std::os::unix::fs::PermissionsExt;
 use std::os::unix::fs::symlink;
 use std::path::Path;
@@ -506,6 +505,114 @@ async fn file_system_copy_rejects_directory_without_recursive(use_remote: bool)
     Ok(())
 }
 
+#[test_case(false ; "local")]
+#[test_case(true ; "remote")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn file_system_rename_replaces_destination(use_remote: bool) -> Result<()> {
+    let context = create_file_system_context(use_remote).await?;
+    let file_system = context.file_system;
+
+    let tmp = TempDir::new()?;
+    let source = tmp.path().join("source.txt");
+    let destination = tmp.path().join("destination.txt");
+    std::fs::write(&source, "new")?;
+    std::fs::write(&destination, "old")?;
+
+    file_system
+        .rename(
+            &absolute_path(source.clone()),
+            &absolute_path(destination.clone()),
+            /*sandbox*/ None,
+        )
+        .await
+        .with_context(|| format!("mode={use_remote}"))?;
+
+    assert!(!source.exists(), "mode={use_remote}");
+    assert_eq!(std::fs::read_to_string(&destination)?, "new");
+
+    Ok(())
+}
+
+#[test_case(false ; "local")]
+#[test_case(true ; "remote")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn file_system_set_executable_toggles_execute_bits(use_remote: bool) -> Result<()> {
+    let context = create_file_system_context(use_remote).await?;
+    let file_system = context.file_system;
+
+    let tmp = TempDir::new()?;
+    let script = tmp.path().join("run.sh");
+    std::fs::write(&script, "echo hi\n")?;
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o640))?;
+    let script_path = absolute_path(script.clone());
+
+    file_system
+        .set_executable(
+            &script_path,
+            /*executable*/ true,
+            /*sandbox*/ None,
+        )
+        .await
+        .with_context(|| format!("mode={use_remote}"))?;
+    assert_eq!(
+        std::fs::metadata(&script)?.permissions().mode() & 0o777,
+        0o750
+    );
+    assert!(
+        file_system
+            .get_metadata(&script_path, /*sandbox*/ None)
+            .await?
+            .is_executable
+    );
+
+    file_system
+        .set_executable(
+            &script_path,
+            /*executable*/ false,
+            /*sandbox*/ None,
+        )
+        .await
+        .with_context(|| format!("mode={use_remote}"))?;
+    assert_eq!(
+        std::fs::metadata(&script)?.permissions().mode() & 0o777,
+        0o640
+    );
+    assert!(
+        !file_system
+            .get_metadata(&script_path, /*sandbox*/ None)
+            .await?
+            .is_executable
+    );
+
+    Ok(())
+}
+
+#[test_case(false ; "local")]
+#[test_case(true ; "remote")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn file_system_create_symlink_keeps_relative_target(use_remote: bool) -> Result<()> {
+    let context = create_file_system_context(use_remote).await?;
+    let file_system = context.file_system;
+
+    let tmp = TempDir::new()?;
+    std::fs::write(tmp.path().join("target.txt"), "hello")?;
+    let link = tmp.path().join("link.txt");
+
+    file_system
+        .create_symlink(
+            Path::new("target.txt"),
+            &absolute_path(link.clone()),
+            /*sandbox*/ None,
+        )
+        .await
+        .with_context(|| format!("mode={use_remote}"))?;
+
+    assert_eq!(std::fs::read_link(&link)?, PathBuf::from("target.txt"));
+    assert_eq!(std::fs::read_to_string(&link)?, "hello");
+
+    Ok(())
+}
+
 #[test_case(false ; "local")]
 #[test_case(true ; "remote")]
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
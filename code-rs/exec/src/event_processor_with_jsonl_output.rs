@@ -187,9 +187,12 @@ impl EventProcessorWithJsonOutput {
                         .map(|change| FileUpdateChange {
                             path: change.path,
                             kind: match change.kind {
-                                PatchChangeKind::Add => ExecPatchChangeKind::Add,
+                                PatchChangeKind::Add { .. }
+                                | PatchChangeKind::AddSymlink { .. }
+                                | PatchChangeKind::AddBinary { .. } => ExecPatchChangeKind::Add,
                                 PatchChangeKind::Delete => ExecPatchChangeKind::Delete,
-                                PatchChangeKind::Update { .. } => ExecPatchChangeKind::Update,
+                                PatchChangeKind::Update { .. }
+                                | PatchChangeKind::SetMode { .. } => ExecPatchChangeKind::Update,
                             },
                         })
                         .collect(),
@@ -1357,6 +1357,7 @@ async fn resolve_resume_thread_id(
                         cwd: None,
                         use_state_db_only: false,
                         search_term: None,
+                        search_content: false,
                     },
                 },
                 "thread/list",
@@ -1422,6 +1423,7 @@ async fn resolve_resume_thread_id(
                     cwd: None,
                     use_state_db_only: false,
                     search_term: Some(session_id.to_string()),
+                    search_content: false,
                 },
             },
             "thread/list",
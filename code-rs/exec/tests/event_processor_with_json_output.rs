@@ -797,7 +797,7 @@ fn file_change_completion_maps_change_kinds() {
                 changes: vec![
                     ApiFileUpdateChange {
                         path: "a/added.txt".to_string(),
-                        kind: ApiPatchChangeKind::Add,
+                        kind: ApiPatchChangeKind::Add { executable: None },
                         diff: String::new(),
                     },
                     ApiFileUpdateChange {
@@ -807,7 +807,10 @@ fn file_change_completion_maps_change_kinds() {
                     },
                     ApiFileUpdateChange {
                         path: "c/modified.txt".to_string(),
-                        kind: ApiPatchChangeKind::Update { move_path: None },
+                        kind: ApiPatchChangeKind::Update {
+                            move_path: None,
+                            executable: None,
+                        },
                         diff: "@@ -1 +1 @@".to_string(),
                     },
                 ],
@@ -859,7 +862,10 @@ fn file_change_declined_maps_to_failed_status() {
                 id: "patch-2".to_string(),
                 changes: vec![ApiFileUpdateChange {
                     path: "file.txt".to_string(),
-                    kind: ApiPatchChangeKind::Update { move_path: None },
+                    kind: ApiPatchChangeKind::Update {
+                        move_path: None,
+                        executable: None,
+                    },
                     diff: "@@ -1 +1 @@".to_string(),
                 }],
                 status: ApiPatchApplyStatus::Declined,
@@ -0,0 +1,464 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use clap::Parser;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use serde::Serialize;
+
+use crate::Decision;
+use crate::MatchOptions;
+use crate::PatternToken;
+use crate::Policy;
+use crate::PolicyParser;
+use crate::PrefixPattern;
+use crate::PrefixRule;
+use crate::RuleRef;
+use crate::policy::render_pattern_token;
+
+/// Rules with more optional tokens than this are skipped by the shadowing check.
+const MAX_EXPANDED_OPTIONAL_TOKENS: usize = 6;
+
+/// Arguments for linting one or more execpolicy files.
+#[derive(Debug, Parser, Clone)]
+pub struct ExecPolicyLintCommand {
+    /// Paths to execpolicy rule files, lowest precedence first (repeatable). Each file is parsed
+    /// on its own and layered on the previous ones with `Policy::merge_overlay`.
+    #[arg(short = 'r', long = "rules", value_name = "PATH", required = true)]
+    pub rules: Vec<PathBuf>,
+
+    /// Command corpus to replay for a coverage report (repeatable). Each line is a JSON array of
+    /// tokens or a shell command; blank lines and lines starting with `#` are ignored.
+    #[arg(long = "corpus", value_name = "PATH")]
+    pub corpus: Vec<PathBuf>,
+
+    /// Pretty-print the JSON output.
+    #[arg(long)]
+    pub pretty: bool,
+
+    /// Resolve absolute program paths against basename rules when replaying the corpus.
+    #[arg(long)]
+    pub resolve_host_executables: bool,
+}
+
+impl ExecPolicyLintCommand {
+    /// Lint the policies for this command and render the report as JSON.
+    pub fn run(&self) -> Result<()> {
+        let report = self.report()?;
+        let json = if self.pretty {
+            serde_json::to_string_pretty(&report)?
+        } else {
+            serde_json::to_string(&report)?
+        };
+        println!("{json}");
+
+        Ok(())
+    }
+
+    /// Load the policies and corpus for this command and build the lint report.
+    pub fn report(&self) -> Result<LintReport> {
+        let sources = self
+            .rules
+            .iter()
+            .map(|path| load_policy_source(path.as_path()))
+            .collect::<Result<Vec<_>>>()?;
+        let corpus = if self.corpus.is_empty() {
+            None
+        } else {
+            let mut commands = Vec::new();
+            for path in &self.corpus {
+                commands.extend(load_corpus(path)?);
+            }
+            Some(commands)
+        };
+
+        Ok(lint_policies(
+            &sources,
+            corpus.as_deref(),
+            &MatchOptions {
+                resolve_host_executables: self.resolve_host_executables,
+            },
+        ))
+    }
+}
+
+/// Findings for a set of layered policy files.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintReport {
+    /// Rules that never affect a decision because another rule matches every command they match
+    /// with an equal or stricter decision.
+    pub shadowed_rules: Vec<ShadowedRule>,
+    /// Rules with identical patterns but different decisions.
+    pub conflicting_rules: Vec<ConflictingRules>,
+    /// Effective `host_executable()` paths that do not exist on this machine.
+    pub missing_host_executables: Vec<MissingHostExecutable>,
+    /// Corpus replay results; omitted when no corpus was provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<CoverageReport>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintRule {
+    /// Policy file that defined the rule.
+    pub source: String,
+    pub pattern: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub excluded_flags: Vec<String>,
+    pub decision: Decision,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShadowedRule {
+    pub rule: LintRule,
+    pub shadowed_by: LintRule,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictingRules {
+    pub pattern: Vec<String>,
+    pub rules: Vec<LintRule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingHostExecutable {
+    pub source: String,
+    pub name: String,
+    pub path: AbsolutePathBuf,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageReport {
+    /// Number of commands replayed.
+    pub commands: usize,
+    /// Every rule with the number of commands it matched.
+    pub rules: Vec<RuleCoverage>,
+    /// Commands that no rule matched and would fall through to heuristics.
+    pub unmatched_commands: Vec<UnmatchedCommand>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleCoverage {
+    pub rule: LintRule,
+    pub hits: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmatchedCommand {
+    pub command: Vec<String>,
+    pub count: usize,
+}
+
+/// Lint `sources`, given as `(identifier, policy)` pairs lowest precedence first, and replay
+/// `corpus` against the merged policy when provided.
+pub fn lint_policies(
+    sources: &[(String, Policy)],
+    corpus: Option<&[Vec<String>]>,
+    options: &MatchOptions,
+) -> LintReport {
+    let rules = collect_rules(sources);
+    let merged = sources.iter().fold(Policy::empty(), |merged, (_, policy)| {
+        merged.merge_overlay(policy)
+    });
+
+    LintReport {
+        shadowed_rules: find_shadowed_rules(&rules),
+        conflicting_rules: find_conflicting_rules(&rules),
+        missing_host_executables: find_missing_host_executables(sources),
+        coverage: corpus.map(|commands| replay_corpus(&merged, &rules, commands, options)),
+    }
+}
+
+struct SourcedRule<'a> {
+    source: &'a str,
+    rule: &'a RuleRef,
+    prefix_rule: &'a PrefixRule,
+}
+
+impl SourcedRule<'_> {
+    fn to_lint_rule(&self) -> LintRule {
+        let pattern = &self.prefix_rule.pattern;
+        LintRule {
+            source: self.source.to_string(),
+            pattern: std::iter::once(pattern.first.to_string())
+                .chain(pattern.rest.iter().map(render_pattern_token))
+                .collect(),
+            excluded_flags: pattern.excluded_flags.to_vec(),
+            decision: self.prefix_rule.decision,
+        }
+    }
+}
+
+/// Prefix rules in precedence order: by source, then by program, then by definition order.
+fn collect_rules(sources: &[(String, Policy)]) -> Vec<SourcedRule<'_>> {
+    let mut rules = Vec::new();
+    for (source, policy) in sources {
+        let mut programs = policy.rules().iter_all().collect::<Vec<_>>();
+        programs.sort_by(|(left, _), (right, _)| left.cmp(right));
+        for (_program, program_rules) in programs {
+            for rule in program_rules {
+                let Some(prefix_rule) = rule.as_any().downcast_ref::<PrefixRule>() else {
+                    continue;
+                };
+                rules.push(SourcedRule {
+                    source,
+                    rule,
+                    prefix_rule,
+                });
+            }
+        }
+    }
+    rules
+}
+
+fn find_shadowed_rules(rules: &[SourcedRule<'_>]) -> Vec<ShadowedRule> {
+    let mut shadowed = Vec::new();
+    for (index, rule) in rules.iter().enumerate() {
+        let shadowing_rule = rules.iter().enumerate().find(|(other_index, other)| {
+            if *other_index == index
+                || other.prefix_rule.decision < rule.prefix_rule.decision
+                || !pattern_covers(&other.prefix_rule.pattern, &rule.prefix_rule.pattern)
+            {
+                return false;
+            }
+            // Of two equivalent rules with the same decision, only the later one is redundant.
+            let equivalent = other.prefix_rule.decision == rule.prefix_rule.decision
+                && pattern_covers(&rule.prefix_rule.pattern, &other.prefix_rule.pattern);
+            !equivalent || *other_index < index
+        });
+        if let Some((_, other)) = shadowing_rule {
+            shadowed.push(ShadowedRule {
+                rule: rule.to_lint_rule(),
+                shadowed_by: other.to_lint_rule(),
+            });
+        }
+    }
+    shadowed
+}
+
+fn find_conflicting_rules(rules: &[SourcedRule<'_>]) -> Vec<ConflictingRules> {
+    let mut groups: Vec<(&PrefixPattern, Vec<&SourcedRule<'_>>)> = Vec::new();
+    for rule in rules {
+        let pattern = &rule.prefix_rule.pattern;
+        match groups.iter_mut().find(|(existing, _)| *existing == pattern) {
+            Some((_, group)) => group.push(rule),
+            None => groups.push((pattern, vec![rule])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, group)| {
+            group
+                .iter()
+                .any(|rule| rule.prefix_rule.decision != group[0].prefix_rule.decision)
+        })
+        .map(|(_, group)| {
+            let rules = group
+                .iter()
+                .map(|rule| rule.to_lint_rule())
+                .collect::<Vec<_>>();
+            ConflictingRules {
+                pattern: rules[0].pattern.clone(),
+                rules,
+            }
+        })
+        .collect()
+}
+
+/// Checks the effective `host_executable()` definition for each name; later sources replace
+/// earlier ones, matching `Policy::merge_overlay`.
+fn find_missing_host_executables(sources: &[(String, Policy)]) -> Vec<MissingHostExecutable> {
+    let mut effective: HashMap<&str, (&str, &Arc<[AbsolutePathBuf]>)> = HashMap::new();
+    for (source, policy) in sources {
+        for (name, paths) in policy.host_executables() {
+            effective.insert(name, (source.as_str(), paths));
+        }
+    }
+
+    let mut names = effective.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+    let mut missing = Vec::new();
+    for name in names {
+        let (source, paths) = effective[name];
+        for path in paths.iter() {
+            if !path.as_path().exists() {
+                missing.push(MissingHostExecutable {
+                    source: source.to_string(),
+                    name: name.to_string(),
+                    path: path.clone(),
+                });
+            }
+        }
+    }
+    missing
+}
+
+fn replay_corpus(
+    merged: &Policy,
+    rules: &[SourcedRule<'_>],
+    commands: &[Vec<String>],
+    options: &MatchOptions,
+) -> CoverageReport {
+    let mut hits = vec![0; rules.len()];
+    let mut unmatched_commands: Vec<UnmatchedCommand> = Vec::new();
+    for command in commands {
+        let matched_rules = merged.matching_rules_with_options(command, options);
+        if matched_rules.is_empty() {
+            match unmatched_commands
+                .iter_mut()
+                .find(|unmatched| &unmatched.command == command)
+            {
+                Some(unmatched) => unmatched.count += 1,
+                None => unmatched_commands.push(UnmatchedCommand {
+                    command: command.clone(),
+                    count: 1,
+                }),
+            }
+            continue;
+        }
+        for (matched_rule, _) in matched_rules {
+            if let Some(index) = rules
+                .iter()
+                .position(|rule| Arc::ptr_eq(rule.rule, &matched_rule))
+            {
+                hits[index] += 1;
+            }
+        }
+    }
+
+    CoverageReport {
+        commands: commands.len(),
+        rules: rules
+            .iter()
+            .zip(hits)
+            .map(|(rule, hits)| RuleCoverage {
+                rule: rule.to_lint_rule(),
+                hits,
+            })
+            .collect(),
+        unmatched_commands,
+    }
+}
+
+/// Returns true when every command matched by `narrow` is also matched by `broad`. This is
+/// conservative: it may return false for patterns that do overlap completely.
+fn pattern_covers(broad: &PrefixPattern, narrow: &PrefixPattern) -> bool {
+    if broad.first != narrow.first
+        || !broad
+            .excluded_flags
+            .iter()
+            .all(|flag| narrow.excluded_flags.contains(flag))
+    {
+        return false;
+    }
+    let (Some(broad_variants), Some(narrow_variants)) = (
+        expand_optionals(&broad.rest),
+        expand_optionals(&narrow.rest),
+    ) else {
+        return false;
+    };
+
+    narrow_variants.iter().all(|narrow_tokens| {
+        broad_variants.iter().any(|broad_tokens| {
+            broad_tokens.len() <= narrow_tokens.len()
+                && broad_tokens
+                    .iter()
+                    .zip(narrow_tokens)
+                    .all(|(broad_token, narrow_token)| token_covers(broad_token, narrow_token))
+        })
+    })
+}
+
+/// Expands optional tokens into every concrete token sequence they allow.
+fn expand_optionals(tokens: &[PatternToken]) -> Option<Vec<Vec<&PatternToken>>> {
+    let optional_count = tokens
+        .iter()
+        .filter(|token| matches!(token, PatternToken::Optional(_)))
+        .count();
+    if optional_count > MAX_EXPANDED_OPTIONAL_TOKENS {
+        return None;
+    }
+
+    let mut variants: Vec<Vec<&PatternToken>> = vec![Vec::new()];
+    for token in tokens {
+        match token {
+            PatternToken::Optional(inner) => {
+                let with_token = variants
+                    .iter()
+                    .cloned()
+                    .map(|mut variant| {
+                        variant.push(inner.as_ref());
+                        variant
+                    })
+                    .collect::<Vec<_>>();
+                variants.extend(with_token);
+            }
+            _ => variants.iter_mut().for_each(|variant| variant.push(token)),
+        }
+    }
+    Some(variants)
+}
+
+fn token_covers(broad: &PatternToken, narrow: &PatternToken) -> bool {
+    match (broad, narrow) {
+        (PatternToken::Any, _) => true,
+        (_, PatternToken::Any) => false,
+        _ if broad == narrow => true,
+        (_, PatternToken::Single(_) | PatternToken::Alts(_)) => narrow
+            .alternatives()
+            .iter()
+            .all(|literal| broad.matches(literal)),
+        _ => false,
+    }
+}
+
+fn load_policy_source(path: &Path) -> Result<(String, Policy)> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read policy at {}", path.display()))?;
+    let identifier = path.to_string_lossy().to_string();
+    let mut parser = PolicyParser::new();
+    parser
+        .parse(&identifier, &contents)
+        .with_context(|| format!("failed to parse policy at {}", path.display()))?;
+    Ok((identifier, parser.build()))
+}
+
+fn load_corpus(path: &Path) -> Result<Vec<Vec<String>>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read corpus at {}", path.display()))?;
+    let mut commands = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let command = parse_corpus_line(line)
+            .with_context(|| format!("invalid command at {}:{}", path.display(), index + 1))?;
+        commands.push(command);
+    }
+    Ok(commands)
+}
+
+fn parse_corpus_line(line: &str) -> Result<Vec<String>> {
+    let command = if line.starts_with('[') {
+        serde_json::from_str::<Vec<String>>(line)?
+    } else {
+        shlex::split(line).context("command has invalid shell syntax")?
+    };
+    if command.is_empty() {
+        anyhow::bail!("command cannot be empty");
+    }
+    Ok(command)
+}
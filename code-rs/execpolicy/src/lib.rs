@@ -2,8 +2,10 @@ pub(crate) mod amend;
 pub(crate) mod decision;
 pub(crate) mod error;
 pub(crate) mod execpolicycheck;
+pub(crate) mod execpolicylint;
 mod executable_name;
 pub(crate) mod parser;
+mod pattern_token_value;
 pub(crate) mod policy;
 pub mod rule;
 
@@ -17,10 +19,21 @@ pub use error::Result;
 pub use error::TextPosition;
 pub use error::TextRange;
 pub use execpolicycheck::ExecPolicyCheckCommand;
+pub use execpolicylint::ConflictingRules;
+pub use execpolicylint::CoverageReport;
+pub use execpolicylint::ExecPolicyLintCommand;
+pub use execpolicylint::LintReport;
+pub use execpolicylint::LintRule;
+pub use execpolicylint::MissingHostExecutable;
+pub use execpolicylint::RuleCoverage;
+pub use execpolicylint::ShadowedRule;
+pub use execpolicylint::UnmatchedCommand;
+pub use execpolicylint::lint_policies;
 pub use parser::PolicyParser;
 pub use policy::Evaluation;
 pub use policy::MatchOptions;
 pub use policy::Policy;
+pub use rule::NetworkRule;
 pub use rule::NetworkRuleProtocol;
 pub use rule::PatternToken;
 pub use rule::PrefixPattern;
@@ -28,3 +41,5 @@ pub use rule::PrefixRule;
 pub use rule::Rule;
 pub use rule::RuleMatch;
 pub use rule::RuleRef;
+pub use rule::TokenGlob;
+pub use rule::TokenRegex;
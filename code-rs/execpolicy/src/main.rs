@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 use codex_execpolicy::ExecPolicyCheckCommand;
+use codex_execpolicy::ExecPolicyLintCommand;
 
 /// CLI for evaluating exec policies
 #[derive(Parser)]
@@ -8,11 +9,15 @@ use codex_execpolicy::ExecPolicyCheckCommand;
 enum Cli {
     /// Evaluate a command against a policy.
     Check(ExecPolicyCheckCommand),
+
+    /// Report shadowed, conflicting, and unused rules across policies.
+    Lint(ExecPolicyLintCommand),
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
     match cli {
         Cli::Check(cmd) => cmd.run(),
+        Cli::Lint(cmd) => cmd.run(),
     }
 }
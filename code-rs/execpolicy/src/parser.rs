@@ -26,13 +26,18 @@ use crate::error::TextPosition;
 use crate::error::TextRange;
 use crate::executable_name::executable_lookup_key;
 use crate::executable_name::executable_path_lookup_key;
+use crate::pattern_token_value::PatternTokenValue;
 use crate::rule::NetworkRule;
 use crate::rule::NetworkRuleProtocol;
 use crate::rule::PatternToken;
 use crate::rule::PrefixPattern;
 use crate::rule::PrefixRule;
 use crate::rule::RuleRef;
+use crate::rule::TokenGlob;
+use crate::rule::TokenRegex;
+use crate::rule::normalize_network_rule_methods;
 use crate::rule::validate_match_examples;
+use crate::rule::validate_network_rule_path;
 use crate::rule::validate_not_match_examples;
 
 pub struct PolicyParser {
@@ -183,6 +188,8 @@ fn parse_pattern<'v>(pattern: UnpackList<Value<'v>>) -> Result<Vec<PatternToken>
 fn parse_pattern_token<'v>(value: Value<'v>) -> Result<PatternToken> {
     if let Some(s) = value.unpack_str() {
         Ok(PatternToken::Single(s.to_string()))
+    } else if let Some(token) = value.downcast_ref::<PatternTokenValue>() {
+        Ok(token.0.clone())
     } else if let Some(list) = ListRef::from_value(value) {
         let tokens: Vec<String> = list
             .content()
@@ -209,12 +216,53 @@ fn parse_pattern_token<'v>(value: Value<'v>) -> Result<PatternToken> {
         }
     } else {
         Err(Error::InvalidPattern(format!(
-            "pattern element must be a string or list of strings (got {})",
+            "pattern element must be a string, list of strings, or pattern token (got {})",
             value.get_type()
         )))
     }
 }
 
+fn parse_excluded_flags<'v>(flags: UnpackList<Value<'v>>) -> Result<Vec<String>> {
+    flags
+        .items
+        .into_iter()
+        .map(|value| {
+            let flag = value.unpack_str().ok_or_else(|| {
+                Error::InvalidRule(format!(
+                    "exclude_flags entries must be strings (got {})",
+                    value.get_type()
+                ))
+            })?;
+            if flag == "-" || flag == "--" || !flag.starts_with('-') {
+                return Err(Error::InvalidRule(format!(
+                    "exclude_flags entries must be flags starting with `-` (got {flag:?})"
+                )));
+            }
+            if flag.chars().any(char::is_whitespace) {
+                return Err(Error::InvalidRule(format!(
+                    "exclude_flags entries cannot contain whitespace (got {flag:?})"
+                )));
+            }
+            Ok(flag.to_string())
+        })
+        .collect()
+}
+
+fn parse_network_rule_methods<'v>(methods: UnpackList<Value<'v>>) -> Result<Vec<String>> {
+    methods
+        .items
+        .into_iter()
+        .map(|value| {
+            value.unpack_str().map(str::to_string).ok_or_else(|| {
+                Error::InvalidRule(format!(
+                    "network_rule methods entries must be strings (got {})",
+                    value.get_type()
+                ))
+            })
+        })
+        .collect()
+}
+
 fn parse_examples<'v>(examples: UnpackList<Value<'v>>) -> Result<Vec<Vec<String>>> {
     examples.items.into_iter().map(parse_example).collect()
 }
@@ -351,6 +399,7 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         r#match: Option<UnpackList<Value<'v>>>,
         not_match: Option<UnpackList<Value<'v>>>,
         justification: Option<&'v str>,
+        exclude_flags: Option<UnpackList<Value<'v>>>,
         eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<NoneType> {
         let decision = match decision {
@@ -367,6 +416,11 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         };
 
         let pattern_tokens = parse_pattern(pattern)?;
+        let excluded_flags: Arc<[String]> = exclude_flags
+            .map(parse_excluded_flags)
+            .transpose()?
+            .unwrap_or_default()
+            .into();
 
         let matches: Vec<Vec<String>> =
             r#match.map(parse_examples).transpose()?.unwrap_or_default();
@@ -384,6 +438,13 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
             .split_first()
             .ok_or_else(|| Error::InvalidPattern("pattern cannot be empty".to_string()))?;
 
+        if first_token.alternatives().is_empty() {
+            return Err(Error::InvalidPattern(
+                "first pattern element must be a string or list of strings".to_string(),
+            )
+            .into());
+        }
+
         let rest: Arc<[PatternToken]> = remaining_tokens.to_vec().into();
 
         let rules: Vec<RuleRef> = first_token
@@ -394,6 +455,7 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
                     pattern: PrefixPattern {
                         first: Arc::from(head.as_str()),
                         rest: rest.clone(),
+                        excluded_flags: excluded_flags.clone(),
                     },
                     decision,
                     justification: justification.clone(),
@@ -406,11 +468,45 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         Ok(NoneType)
     }
 
+    fn glob<'v>(pattern: &'v str) -> anyhow::Result<PatternTokenValue> {
+        if pattern.is_empty() {
+            return Err(Error::InvalidPattern("glob pattern cannot be empty".to_string()).into());
+        }
+        Ok(PatternTokenValue(PatternToken::Glob(TokenGlob::new(
+            pattern,
+        ))))
+    }
+
+    fn regex<'v>(pattern: &'v str) -> anyhow::Result<PatternTokenValue> {
+        if pattern.is_empty() {
+            return Err(Error::InvalidPattern("regex pattern cannot be empty".to_string()).into());
+        }
+        Ok(PatternTokenValue(PatternToken::Regex(TokenRegex::new(
+            pattern,
+        )?)))
+    }
+
+    fn any_token() -> anyhow::Result<PatternTokenValue> {
+        Ok(PatternTokenValue(PatternToken::Any))
+    }
+
+    fn optional<'v>(token: Value<'v>) -> anyhow::Result<PatternTokenValue> {
+        let token = parse_pattern_token(token)?;
+        if matches!(token, PatternToken::Optional(_)) {
+            return Err(
+                Error::InvalidPattern("optional tokens cannot be nested".to_string()).into(),
+            );
+        }
+        Ok(PatternTokenValue(PatternToken::Optional(Box::new(token))))
+    }
+
     fn network_rule<'v>(
         host: &'v str,
         protocol: &'v str,
         decision: &'v str,
         justification: Option<&'v str>,
+        methods: Option<UnpackList<Value<'v>>>,
+        path: Option<&'v str>,
         eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<NoneType> {
         let protocol = NetworkRuleProtocol::parse(protocol)?;
@@ -422,6 +518,22 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
             Some(raw) => Some(raw.to_string()),
             None => None,
         };
+        let methods = normalize_network_rule_methods(
+            protocol,
+            methods
+                .map(parse_network_rule_methods)
+                .transpose()?
+                .unwrap_or_default(),
+        )?;
+        if let Some(path) = path {
+            validate_network_rule_path(protocol, path)?;
+        }
+        if decision == Decision::Prompt && (!methods.is_empty() || path.is_some()) {
+            return Err(Error::InvalidRule(
+                "network_rule methods and path only support decision allow or deny".to_string(),
+            )
+            .into());
+        }
 
         let mut builder = policy_builder(eval);
         builder.add_network_rule(NetworkRule {
@@ -429,6 +541,8 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
             protocol,
             decision,
             justification,
+            methods,
+            path: path.map(str::to_string),
         });
         Ok(NoneType)
     }
@@ -0,0 +1,51 @@
+#![allow(clippy::needless_lifetimes)]
+
+use std::fmt;
+
+use allocative::Allocative;
+use starlark::any::ProvidesStaticType;
+use starlark::values::AllocValue;
+use starlark::values::Heap;
+use starlark::values::NoSerialize;
+use starlark::values::StarlarkValue;
+use starlark::values::Value;
+use starlark::values::starlark_value;
+
+use crate::rule::PatternToken;
+
+/// Starlark value returned by the `glob`, `regex`, `any_token`, and `optional` builtins so it
+/// can be used as an element of a `prefix_rule` pattern.
+#[derive(Clone, Debug, PartialEq, Eq, ProvidesStaticType, NoSerialize, Allocative)]
+pub(crate) struct PatternTokenValue(#[allocative(skip)] pub(crate) PatternToken);
+
+impl fmt::Display for PatternTokenValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_pattern_token(&self.0, f)
+    }
+}
+
+fn fmt_pattern_token(token: &PatternToken, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match token {
+        PatternToken::Single(value) => write!(f, "{value:?}"),
+        PatternToken::Alts(alternatives) => write!(f, "{alternatives:?}"),
+        PatternToken::Glob(glob) => write!(f, "glob({:?})", glob.as_str()),
+        PatternToken::Regex(regex) => write!(f, "regex({:?})", regex.as_str()),
+        PatternToken::Any => write!(f, "any_token()"),
+        PatternToken::Optional(inner) => {
+            write!(f, "optional(")?;
+            fmt_pattern_token(inner, f)?;
+            write!(f, ")")
+        }
+    }
+}
+
+#[starlark_value(type = "PatternToken")]
+impl<'v> StarlarkValue<'v> for PatternTokenValue {
+    type Canonical = PatternTokenValue;
+}
+
+impl<'v> AllocValue<'v> for PatternTokenValue {
+    fn alloc_value(self, heap: &'v Heap) -> Value<'v> {
+        heap.alloc_simple(self)
+    }
+}
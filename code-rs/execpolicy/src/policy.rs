@@ -72,7 +72,11 @@ impl Policy {
                 let Some(prefix_rule) = rule.as_any().downcast_ref::<PrefixRule>() else {
                     continue;
                 };
-                if prefix_rule.decision != Decision::Allow {
+                // A prefix that only applies without certain flags cannot be rendered as a
+                // plain allowed prefix.
+                if prefix_rule.decision != Decision::Allow
+                    || !prefix_rule.pattern.excluded_flags.is_empty()
+                {
                     continue;
                 }
 
@@ -101,6 +105,7 @@ impl Policy {
                     .map(|token| PatternToken::Single(token.clone()))
                     .collect::<Vec<_>>()
                     .into(),
+                excluded_flags: Vec::new().into(),
             },
             decision,
             justification: None,
@@ -130,6 +135,8 @@ impl Policy {
             protocol,
             decision,
             justification,
+            methods: Vec::new(),
+            path: None,
         });
         Ok(())
     }
@@ -168,7 +175,11 @@ impl Policy {
         let mut allowed = Vec::new();
         let mut denied = Vec::new();
 
-        for rule in &self.network_rules {
+        for rule in self
+            .network_rules
+            .iter()
+            .filter(|rule| !rule.is_http_scoped())
+        {
             match rule.decision {
                 Decision::Allow => {
                     denied.retain(|entry| entry != &rule.host);
@@ -185,6 +196,14 @@ impl Policy {
         (allowed, denied)
     }
 
+    /// Network rules scoped to HTTP methods or paths, in policy order. They are not part of
+    /// [`Self::compiled_network_domains`]; the parser rejects `prompt` for them.
+    pub fn http_network_rules(&self) -> impl Iterator<Item = &NetworkRule> {
+        self.network_rules
+            .iter()
+            .filter(|rule| rule.is_http_scoped())
+    }
+
     pub fn check<F>(&self, cmd: &[String], heuristics_fallback: &F) -> Evaluation
     where
         F: Fn(&[String]) -> Decision,
@@ -271,16 +290,11 @@ impl Policy {
         heuristics_fallback: HeuristicsFallback<'_>,
         options: &MatchOptions,
     ) -> Vec<RuleMatch> {
-        let matched_rules = self
-            .match_exact_rules(cmd)
-            .filter(|matched_rules| !matched_rules.is_empty())
-            .or_else(|| {
-                options
-                    .resolve_host_executables
-                    .then(|| self.match_host_executable_rules(cmd))
-                    .filter(|matched_rules| !matched_rules.is_empty())
-            })
-            .unwrap_or_default();
+        let matched_rules: Vec<RuleMatch> = self
+            .matching_rules_with_options(cmd, options)
+            .into_iter()
+            .map(|(_, rule_match)| rule_match)
+            .collect();
 
         if matched_rules.is_empty()
             && let Some(heuristics_fallback) = heuristics_fallback
@@ -294,17 +308,39 @@ impl Policy {
         }
     }
 
-    fn match_exact_rules(&self, cmd: &[String]) -> Option<Vec<RuleMatch>> {
+    /// Returns each rule that matches `cmd` alongside its match, without a heuristics fallback.
+    pub(crate) fn matching_rules_with_options(
+        &self,
+        cmd: &[String],
+        options: &MatchOptions,
+    ) -> Vec<(RuleRef, RuleMatch)> {
+        self.match_exact_rules(cmd)
+            .filter(|matched_rules| !matched_rules.is_empty())
+            .or_else(|| {
+                options
+                    .resolve_host_executables
+                    .then(|| self.match_host_executable_rules(cmd))
+                    .filter(|matched_rules| !matched_rules.is_empty())
+            })
+            .unwrap_or_default()
+    }
+
+    fn match_exact_rules(&self, cmd: &[String]) -> Option<Vec<(RuleRef, RuleMatch)>> {
         let first = cmd.first()?;
         Some(
             self.rules_by_program
                 .get_vec(first)
-                .map(|rules| rules.iter().filter_map(|rule| rule.matches(cmd)).collect())
+                .map(|rules| {
+                    rules
+                        .iter()
+                        .filter_map(|rule| Some((rule.clone(), rule.matches(cmd)?)))
+                        .collect()
+                })
                 .unwrap_or_default(),
         )
     }
 
-    fn match_host_executable_rules(&self, cmd: &[String]) -> Vec<RuleMatch> {
+    fn match_host_executable_rules(&self, cmd: &[String]) -> Vec<(RuleRef, RuleMatch)> {
         let Some(first) = cmd.first() else {
             return Vec::new();
         };
@@ -328,8 +364,10 @@ impl Policy {
             .collect::<Vec<_>>();
         rules
             .iter()
-            .filter_map(|rule| rule.matches(&basename_command))
-            .map(|rule_match| rule_match.with_resolved_program(&program))
+            .filter_map(|rule| {
+                let rule_match = rule.matches(&basename_command)?;
+                Some((rule.clone(), rule_match.with_resolved_program(&program)))
+            })
             .collect()
     }
 }
@@ -339,10 +377,14 @@ fn upsert_domain(entries: &mut Vec<String>, host: &str) {
     entries.push(host.to_string());
 }
 
-fn render_pattern_token(token: &PatternToken) -> String {
+pub(crate) fn render_pattern_token(token: &PatternToken) -> String {
     match token {
         PatternToken::Single(value) => value.clone(),
         PatternToken::Alts(alternatives) => format!("[{}]", alternatives.join("|")),
+        PatternToken::Glob(glob) => glob.as_str().to_string(),
+        PatternToken::Regex(regex) => format!("/{}/", regex.as_str()),
+        PatternToken::Any => "*".to_string(),
+        PatternToken::Optional(inner) => format!("{}?", render_pattern_token(inner)),
     }
 }
 
@@ -4,58 +4,189 @@ use crate::error::Result;
 use crate::policy::MatchOptions;
 use crate::policy::Policy;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use regex_lite::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use shlex::try_join;
 use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
+use wildmatch::WildMatch;
 
 /// Matches a single command token, either a fixed string or one of several allowed alternatives.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PatternToken {
     Single(String),
     Alts(Vec<String>),
+    /// Shell-style glob (`*` and `?`) that must match the whole token.
+    Glob(TokenGlob),
+    /// Regular expression that must match the whole token.
+    Regex(TokenRegex),
+    /// Matches any single token.
+    Any,
+    /// Matches the wrapped token when present; otherwise matches nothing.
+    Optional(Box<PatternToken>),
 }
 
 impl PatternToken {
-    fn matches(&self, token: &str) -> bool {
+    pub(crate) fn matches(&self, token: &str) -> bool {
         match self {
             Self::Single(expected) => expected == token,
             Self::Alts(alternatives) => alternatives.iter().any(|alt| alt == token),
+            Self::Glob(glob) => glob.is_match(token),
+            Self::Regex(regex) => regex.is_match(token),
+            Self::Any => true,
+            Self::Optional(inner) => inner.matches(token),
         }
     }
 
+    /// Literal strings this token accepts. Empty for tokens that are not literals.
     pub fn alternatives(&self) -> &[String] {
         match self {
             Self::Single(expected) => std::slice::from_ref(expected),
             Self::Alts(alternatives) => alternatives,
+            Self::Glob(_) | Self::Regex(_) | Self::Any | Self::Optional(_) => &[],
         }
     }
 }
 
+/// Glob used by [`PatternToken::Glob`], compiled once when the policy is parsed; equality
+/// compares the source pattern.
+#[derive(Clone, Debug)]
+pub struct TokenGlob {
+    source: String,
+    glob: WildMatch,
+}
+
+impl TokenGlob {
+    pub fn new(source: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            glob: WildMatch::new(source),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    fn is_match(&self, token: &str) -> bool {
+        self.glob.matches(token)
+    }
+}
+
+impl PartialEq for TokenGlob {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for TokenGlob {}
+
+/// Regex used by [`PatternToken::Regex`]. The expression is anchored so it must match the
+/// entire token; equality compares the source expression.
+#[derive(Clone, Debug)]
+pub struct TokenRegex {
+    source: String,
+    regex: Regex,
+}
+
+impl TokenRegex {
+    pub fn new(source: &str) -> Result<Self> {
+        let regex = Regex::new(&format!("^(?:{source})$"))
+            .map_err(|err| Error::InvalidPattern(format!("invalid regex `{source}`: {err}")))?;
+        Ok(Self {
+            source: source.to_string(),
+            regex,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    fn is_match(&self, token: &str) -> bool {
+        self.regex.is_match(token)
+    }
+}
+
+impl PartialEq for TokenRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for TokenRegex {}
+
 /// Prefix matcher for commands with support for alternative match tokens.
 /// First token is fixed since we key by the first token in policy.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PrefixPattern {
     pub first: Arc<str>,
     pub rest: Arc<[PatternToken]>,
+    /// Flags that prevent a match when they appear anywhere in the command before `--`.
+    pub excluded_flags: Arc<[String]>,
 }
 
 impl PrefixPattern {
     pub fn matches_prefix(&self, cmd: &[String]) -> Option<Vec<String>> {
-        let pattern_length = self.rest.len() + 1;
-        if cmd.len() < pattern_length || cmd[0] != self.first.as_ref() {
+        let (program, args) = cmd.split_first()?;
+        if program != self.first.as_ref() {
             return None;
         }
-
-        for (pattern_token, cmd_token) in self.rest.iter().zip(&cmd[1..pattern_length]) {
-            if !pattern_token.matches(cmd_token) {
-                return None;
-            }
+        if self
+            .excluded_flags
+            .iter()
+            .any(|flag| contains_flag(args, flag))
+        {
+            return None;
         }
 
-        Some(cmd[..pattern_length].to_vec())
+        let matched_len = match_tokens(&self.rest, args)?;
+        Some(cmd[..=matched_len].to_vec())
+    }
+}
+
+/// Returns how many leading tokens of `cmd` are consumed by `pattern`, preferring to consume
+/// optional tokens when both choices lead to a match.
+fn match_tokens(pattern: &[PatternToken], cmd: &[String]) -> Option<usize> {
+    let Some((pattern_token, remaining_pattern)) = pattern.split_first() else {
+        return Some(0);
+    };
+
+    let consumed = cmd
+        .split_first()
+        .filter(|(cmd_token, _)| pattern_token.matches(cmd_token))
+        .and_then(|(_, remaining_cmd)| match_tokens(remaining_pattern, remaining_cmd))
+        .map(|matched_len| matched_len + 1);
+    match pattern_token {
+        PatternToken::Optional(_) => consumed.or_else(|| match_tokens(remaining_pattern, cmd)),
+        _ => consumed,
+    }
+}
+
+fn contains_flag(args: &[String], flag: &str) -> bool {
+    args.iter()
+        .take_while(|arg| arg.as_str() != "--")
+        .any(|arg| flag_matches(flag, arg))
+}
+
+/// Matches `--flag`, `--flag=value`, and short flags inside a bundle such as `-fu`.
+fn flag_matches(flag: &str, arg: &str) -> bool {
+    if arg == flag {
+        return true;
+    }
+    if flag.starts_with("--") {
+        return arg
+            .strip_prefix(flag)
+            .is_some_and(|value| value.starts_with('='));
+    }
+
+    match (flag.strip_prefix('-'), arg.strip_prefix('-')) {
+        (Some(short), Some(bundle)) if short.len() == 1 && !bundle.starts_with('-') => {
+            bundle.chars().all(|c| c.is_ascii_alphanumeric()) && bundle.contains(short)
+        }
+        _ => false,
     }
 }
 
@@ -151,6 +282,72 @@ pub struct NetworkRule {
     pub protocol: NetworkRuleProtocol,
     pub decision: Decision,
     pub justification: Option<String>,
+    /// HTTP methods the rule is limited to. Empty means every method.
+    pub methods: Vec<String>,
+    /// Glob the request path must match, e.g. `/repos/our-org/**`.
+    pub path: Option<String>,
+}
+
+impl NetworkRule {
+    /// Returns true when the rule is scoped to particular HTTP methods or paths rather than the
+    /// whole host. Such rules are enforced per request by the network proxy instead of through
+    /// the allowed and denied domain lists.
+    pub fn is_http_scoped(&self) -> bool {
+        !self.methods.is_empty() || self.path.is_some()
+    }
+}
+
+pub(crate) fn normalize_network_rule_methods(
+    protocol: NetworkRuleProtocol,
+    raw: Vec<String>,
+) -> Result<Vec<String>> {
+    if !raw.is_empty()
+        && !matches!(
+            protocol,
+            NetworkRuleProtocol::Http | NetworkRuleProtocol::Https
+        )
+    {
+        return Err(Error::InvalidRule(format!(
+            "network_rule methods require protocol http or https (got {})",
+            protocol.as_policy_string()
+        )));
+    }
+    let mut methods = Vec::with_capacity(raw.len());
+    for method in raw {
+        let method = method.trim().to_ascii_uppercase();
+        if method.is_empty() || !method.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(Error::InvalidRule(format!(
+                "network_rule methods must be HTTP method names (got {method:?})"
+            )));
+        }
+        if !methods.contains(&method) {
+            methods.push(method);
+        }
+    }
+    Ok(methods)
+}
+
+pub(crate) fn validate_network_rule_path(protocol: NetworkRuleProtocol, path: &str) -> Result<()> {
+    if !matches!(
+        protocol,
+        NetworkRuleProtocol::Http | NetworkRuleProtocol::Https
+    ) {
+        return Err(Error::InvalidRule(format!(
+            "network_rule path requires protocol http or https (got {})",
+            protocol.as_policy_string()
+        )));
+    }
+    if !path.starts_with('/') {
+        return Err(Error::InvalidRule(format!(
+            "network_rule path must start with `/` (got {path:?})"
+        )));
+    }
+    if path.chars().any(char::is_whitespace) {
+        return Err(Error::InvalidRule(
+            "network_rule path cannot contain whitespace".to_string(),
+        ));
+    }
+    Ok(())
 }
 
 pub(crate) fn normalize_network_rule_host(raw: &str) -> Result<String> {
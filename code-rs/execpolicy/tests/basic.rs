@@ -5,19 +5,29 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use anyhow::Result;
+use codex_execpolicy::ConflictingRules;
+use codex_execpolicy::CoverageReport;
 use codex_execpolicy::Decision;
 use codex_execpolicy::Error;
 use codex_execpolicy::Evaluation;
+use codex_execpolicy::ExecPolicyLintCommand;
+use codex_execpolicy::LintReport;
+use codex_execpolicy::LintRule;
 use codex_execpolicy::MatchOptions;
+use codex_execpolicy::MissingHostExecutable;
 use codex_execpolicy::NetworkRuleProtocol;
 use codex_execpolicy::PatternToken;
 use codex_execpolicy::Policy;
 use codex_execpolicy::PolicyParser;
 use codex_execpolicy::PrefixPattern;
 use codex_execpolicy::PrefixRule;
+use codex_execpolicy::RuleCoverage;
 use codex_execpolicy::RuleMatch;
 use codex_execpolicy::RuleRef;
+use codex_execpolicy::ShadowedRule;
+use codex_execpolicy::UnmatchedCommand;
 use codex_execpolicy::blocking_append_allow_prefix_rule;
+use codex_execpolicy::lint_policies;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use pretty_assertions::assert_eq;
 use tempfile::tempdir;
@@ -127,6 +137,83 @@ network_rule(host = "prompt-only.example.com", protocol = "https", decision = "p
     Ok(())
 }
 
+#[test]
+fn network_rules_with_methods_or_paths_stay_out_of_domain_lists() -> Result<()> {
+    let policy_src = r#"
+network_rule(host = "api.github.com", protocol = "https", decision = "allow", methods = ["post"], path = "/repos/our-org/*")
+network_rule(host = "registry.npmjs.org", protocol = "https", decision = "deny", methods = ["PUT", "DELETE"])
+network_rule(host = "registry.npmjs.org", protocol = "https", decision = "allow")
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("network.rules", policy_src)?;
+    let policy = parser.build();
+
+    let http_rules = policy
+        .http_network_rules()
+        .map(|rule| {
+            (
+                rule.host.as_str(),
+                rule.decision,
+                rule.methods.clone(),
+                rule.path.as_deref(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        http_rules,
+        vec![
+            (
+                "api.github.com",
+                Decision::Allow,
+                vec!["POST".to_string()],
+                Some("/repos/our-org/*"),
+            ),
+            (
+                "registry.npmjs.org",
+                Decision::Forbidden,
+                vec!["PUT".to_string(), "DELETE".to_string()],
+                None,
+            ),
+        ]
+    );
+
+    let (allowed, denied) = policy.compiled_network_domains();
+    assert_eq!(allowed, vec!["registry.npmjs.org".to_string()]);
+    assert_eq!(denied, Vec::<String>::new());
+    Ok(())
+}
+
+#[test]
+fn network_rule_rejects_invalid_methods_and_paths() {
+    for (policy_src, expected) in [
+        (
+            r#"network_rule(host="example.com", protocol="socks5_tcp", decision="allow", methods=["GET"])"#,
+            "methods require protocol http or https",
+        ),
+        (
+            r#"network_rule(host="example.com", protocol="https", decision="allow", methods=["GET /"])"#,
+            "must be HTTP method names",
+        ),
+        (
+            r#"network_rule(host="example.com", protocol="https", decision="allow", path="admin/*")"#,
+            "path must start with `/`",
+        ),
+        (
+            r#"network_rule(host="example.com", protocol="https", decision="prompt", path="/admin/*")"#,
+            "methods and path only support decision allow or deny",
+        ),
+    ] {
+        let mut parser = PolicyParser::new();
+        let err = parser
+            .parse("network.rules", policy_src)
+            .expect_err("invalid network_rule should fail");
+        assert!(
+            err.to_string().contains(expected),
+            "unexpected error for {policy_src}: {err}"
+        );
+    }
+}
+
 #[test]
 fn network_rule_rejects_wildcard_hosts() {
     let mut parser = PolicyParser::new();
@@ -257,6 +344,7 @@ fn add_prefix_rule_extends_policy() -> Result<()> {
             pattern: PrefixPattern {
                 first: Arc::from("ls"),
                 rest: vec![PatternToken::Single(String::from("-l"))].into(),
+                excluded_flags: Vec::new().into(),
             },
             decision: Decision::Prompt,
             justification: None,
@@ -318,6 +406,7 @@ prefix_rule(
                 pattern: PrefixPattern {
                     first: Arc::from("git"),
                     rest: Vec::<PatternToken>::new().into(),
+                    excluded_flags: Vec::new().into(),
                 },
                 decision: Decision::Prompt,
                 justification: None,
@@ -326,6 +415,7 @@ prefix_rule(
                 pattern: PrefixPattern {
                     first: Arc::from("git"),
                     rest: vec![PatternToken::Single("commit".to_string())].into(),
+                    excluded_flags: Vec::new().into(),
                 },
                 decision: Decision::Forbidden,
                 justification: None,
@@ -395,6 +485,7 @@ prefix_rule(
             pattern: PrefixPattern {
                 first: Arc::from("bash"),
                 rest: vec![PatternToken::Alts(vec!["-c".to_string(), "-l".to_string()])].into(),
+                excluded_flags: Vec::new().into(),
             },
             decision: Decision::Allow,
             justification: None,
@@ -406,6 +497,7 @@ prefix_rule(
             pattern: PrefixPattern {
                 first: Arc::from("sh"),
                 rest: vec![PatternToken::Alts(vec!["-c".to_string(), "-l".to_string()])].into(),
+                excluded_flags: Vec::new().into(),
             },
             decision: Decision::Allow,
             justification: None,
@@ -467,6 +559,7 @@ prefix_rule(
                     ]),
                 ]
                 .into(),
+                excluded_flags: Vec::new().into(),
             },
             decision: Decision::Allow,
             justification: None,
@@ -553,6 +646,196 @@ prefix_rule(
     Ok(())
 }
 
+#[test]
+fn wildcard_and_optional_tokens_match_variable_length_prefixes() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["npm", optional("--silent"), "run", any_token()],
+    match = ["npm run build", "npm --silent run test -- --watch"],
+    not_match = ["npm run", "npm --verbose run build"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let rules = rule_snapshots(policy.rules().get_vec("npm").context("missing npm rules")?);
+    assert_eq!(
+        vec![RuleSnapshot::Prefix(PrefixRule {
+            pattern: PrefixPattern {
+                first: Arc::from("npm"),
+                rest: vec![
+                    PatternToken::Optional(Box::new(PatternToken::Single("--silent".to_string()))),
+                    PatternToken::Single("run".to_string()),
+                    PatternToken::Any,
+                ]
+                .into(),
+                excluded_flags: Vec::new().into(),
+            },
+            decision: Decision::Allow,
+            justification: None,
+        })],
+        rules
+    );
+
+    let without_optional = policy.check(&tokens(&["npm", "run", "build", "--prod"]), &allow_all);
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::PrefixRuleMatch {
+                matched_prefix: tokens(&["npm", "run", "build"]),
+                decision: Decision::Allow,
+                resolved_program: None,
+                justification: None,
+            }],
+        },
+        without_optional
+    );
+
+    let with_optional = policy.check(&tokens(&["npm", "--silent", "run", "lint"]), &allow_all);
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::PrefixRuleMatch {
+                matched_prefix: tokens(&["npm", "--silent", "run", "lint"]),
+                decision: Decision::Allow,
+                resolved_program: None,
+                justification: None,
+            }],
+        },
+        with_optional
+    );
+    Ok(())
+}
+
+#[test]
+fn glob_and_regex_tokens_match_whole_tokens() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["git", "checkout", regex("v[0-9]+\\.[0-9]+")],
+    match = ["git checkout v1.2"],
+    not_match = ["git checkout v1.2-rc", "git checkout main"],
+)
+prefix_rule(
+    pattern = ["cat", glob("*.md")],
+    match = ["cat README.md", "cat .md"],
+    not_match = ["cat README.md.bak", "cat notes.txt"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let checkout = policy.check(&tokens(&["git", "checkout", "v10.0"]), &prompt_all);
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::PrefixRuleMatch {
+                matched_prefix: tokens(&["git", "checkout", "v10.0"]),
+                decision: Decision::Allow,
+                resolved_program: None,
+                justification: None,
+            }],
+        },
+        checkout
+    );
+
+    let cat = policy.check(&tokens(&["cat", "notes.txt"]), &prompt_all);
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Prompt,
+            matched_rules: vec![RuleMatch::HeuristicsRuleMatch {
+                command: tokens(&["cat", "notes.txt"]),
+                decision: Decision::Prompt,
+            }],
+        },
+        cat
+    );
+    Ok(())
+}
+
+#[test]
+fn excluded_flags_prevent_matches() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["git", "push", any_token()],
+    exclude_flags = ["--force", "-f"],
+    match = ["git push origin main", "git push --force-with-lease origin", "git push origin -- -f"],
+    not_match = [
+        "git push --force origin",
+        "git push origin --force=true",
+        "git push -f origin",
+        "git push -uf origin",
+    ],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let force_push = policy.check(&tokens(&["git", "push", "origin", "--force"]), &prompt_all);
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Prompt,
+            matched_rules: vec![RuleMatch::HeuristicsRuleMatch {
+                command: tokens(&["git", "push", "origin", "--force"]),
+                decision: Decision::Prompt,
+            }],
+        },
+        force_push
+    );
+    assert_eq!(policy.get_allowed_prefixes(), Vec::<Vec<String>>::new());
+    Ok(())
+}
+
+#[test]
+fn not_match_examples_cover_pattern_tokens() {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["npm", "run", any_token()],
+    exclude_flags = ["--force"],
+    not_match = ["npm run build --verbose"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    let err = parser
+        .parse("test.rules", policy_src)
+        .expect_err("expected not_match validation error");
+    assert!(matches!(err, Error::ExampleDidMatch { .. }));
+}
+
+#[test]
+fn invalid_pattern_tokens_are_rejected() {
+    let cases = [
+        (
+            r#"prefix_rule(pattern = ["git", regex("(")])"#,
+            "invalid regex `(`",
+        ),
+        (
+            r#"prefix_rule(pattern = [glob("g*"), "status"])"#,
+            "first pattern element must be a string or list of strings",
+        ),
+        (
+            r#"prefix_rule(pattern = ["git", optional(optional("-v"))])"#,
+            "optional tokens cannot be nested",
+        ),
+        (
+            r#"prefix_rule(pattern = ["git"], exclude_flags = ["force"])"#,
+            "exclude_flags entries must be flags starting with `-`",
+        ),
+    ];
+    for (policy_src, expected) in cases {
+        let mut parser = PolicyParser::new();
+        let err = parser
+            .parse("test.rules", policy_src)
+            .expect_err("expected parse error");
+        assert!(
+            err.to_string().contains(expected),
+            "expected `{err}` to contain `{expected}`"
+        );
+    }
+}
+
 #[test]
 fn strictest_decision_wins_across_matches() -> Result<()> {
     let policy_src = r#"
@@ -961,3 +1244,155 @@ host_executable(name = "git", paths = ["{git_path_literal}"])
     );
     Ok(())
 }
+
+fn parse_policy_source(identifier: &str, policy_src: &str) -> Result<(String, Policy)> {
+    let mut parser = PolicyParser::new();
+    parser.parse(identifier, policy_src)?;
+    Ok((identifier.to_string(), parser.build()))
+}
+
+fn lint_rule(source: &str, pattern: &[&str], decision: Decision) -> LintRule {
+    LintRule {
+        source: source.to_string(),
+        pattern: tokens(pattern),
+        excluded_flags: Vec::new(),
+        decision,
+    }
+}
+
+#[test]
+fn lint_reports_shadowed_and_conflicting_rules() -> Result<()> {
+    let sources = vec![
+        parse_policy_source(
+            "base.rules",
+            r#"
+prefix_rule(pattern = ["git", "status"])
+prefix_rule(pattern = ["git", "push"], decision = "prompt")
+prefix_rule(pattern = ["npm", "run", any_token()])
+prefix_rule(pattern = ["npm", "run", "build"])
+prefix_rule(pattern = ["npm", "run", glob("test*")], exclude_flags = ["--watch"])
+"#,
+        )?,
+        parse_policy_source(
+            "team.rules",
+            r#"
+prefix_rule(pattern = ["git"], decision = "prompt")
+prefix_rule(pattern = ["git", "push"], decision = "forbidden")
+"#,
+        )?,
+    ];
+
+    let report = lint_policies(&sources, None, &MatchOptions::default());
+
+    assert_eq!(
+        report,
+        LintReport {
+            shadowed_rules: vec![
+                ShadowedRule {
+                    rule: lint_rule("base.rules", &["git", "status"], Decision::Allow),
+                    shadowed_by: lint_rule("team.rules", &["git"], Decision::Prompt),
+                },
+                ShadowedRule {
+                    rule: lint_rule("base.rules", &["git", "push"], Decision::Prompt),
+                    shadowed_by: lint_rule("team.rules", &["git"], Decision::Prompt),
+                },
+                ShadowedRule {
+                    rule: lint_rule("base.rules", &["npm", "run", "build"], Decision::Allow),
+                    shadowed_by: lint_rule("base.rules", &["npm", "run", "*"], Decision::Allow),
+                },
+                ShadowedRule {
+                    rule: LintRule {
+                        source: "base.rules".to_string(),
+                        pattern: tokens(&["npm", "run", "test*"]),
+                        excluded_flags: vec!["--watch".to_string()],
+                        decision: Decision::Allow,
+                    },
+                    shadowed_by: lint_rule("base.rules", &["npm", "run", "*"], Decision::Allow),
+                },
+            ],
+            conflicting_rules: vec![ConflictingRules {
+                pattern: tokens(&["git", "push"]),
+                rules: vec![
+                    lint_rule("base.rules", &["git", "push"], Decision::Prompt),
+                    lint_rule("team.rules", &["git", "push"], Decision::Forbidden),
+                ],
+            }],
+            missing_host_executables: Vec::new(),
+            coverage: None,
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn lint_reports_missing_host_executables_and_corpus_coverage() -> Result<()> {
+    let tmp = tempdir().context("create temp dir")?;
+    let existing_git = tmp.path().join(host_executable_name("git"));
+    fs::write(&existing_git, "").context("write fake git")?;
+    let missing_git = tmp.path().join("missing").join(host_executable_name("git"));
+    let policy_path = tmp.path().join("default.rules");
+    fs::write(
+        &policy_path,
+        format!(
+            r#"
+prefix_rule(pattern = ["git", "status"])
+prefix_rule(pattern = ["git", "push"], decision = "prompt")
+host_executable(name = "git", paths = ["{}", "{}"])
+"#,
+            starlark_string(&existing_git.to_string_lossy()),
+            starlark_string(&missing_git.to_string_lossy()),
+        ),
+    )
+    .context("write policy")?;
+    let corpus_path = tmp.path().join("commands.txt");
+    fs::write(
+        &corpus_path,
+        r#"# commands from past sessions
+git status
+["git", "status", "--short"]
+git log -1
+
+git log -1
+"#,
+    )
+    .context("write corpus")?;
+
+    let report = ExecPolicyLintCommand {
+        rules: vec![policy_path.clone()],
+        corpus: vec![corpus_path],
+        pretty: false,
+        resolve_host_executables: false,
+    }
+    .report()?;
+
+    let source = policy_path.to_string_lossy().to_string();
+    assert_eq!(
+        report.missing_host_executables,
+        vec![MissingHostExecutable {
+            source: source.clone(),
+            name: "git".to_string(),
+            path: AbsolutePathBuf::try_from(missing_git)?,
+        }]
+    );
+    assert_eq!(
+        report.coverage,
+        Some(CoverageReport {
+            commands: 4,
+            rules: vec![
+                RuleCoverage {
+                    rule: lint_rule(&source, &["git", "status"], Decision::Allow),
+                    hits: 2,
+                },
+                RuleCoverage {
+                    rule: lint_rule(&source, &["git", "push"], Decision::Prompt),
+                    hits: 0,
+                },
+            ],
+            unmatched_commands: vec![UnmatchedCommand {
+                command: tokens(&["git", "log", "-1"]),
+                count: 2,
+            }],
+        })
+    );
+    Ok(())
+}
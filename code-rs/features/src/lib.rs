@@ -116,6 +116,9 @@ pub enum Feature {
     /// Use the legacy Landlock Linux sandbox fallback instead of the default
     /// bubblewrap pipeline.
     UseLegacyLandlock,
+    /// Have the Linux sandbox helper report the operations it blocked so they
+    /// can be shown to the model.
+    SandboxDenialLog,
     /// Allow the model to request approval and propose exec rules.
     RequestRule,
     /// Enable Windows sandbox (restricted token) on Windows.
@@ -334,6 +337,10 @@ impl Features {
         self.enabled(Feature::UseLegacyLandlock)
     }
 
+    pub fn sandbox_denial_log(&self) -> bool {
+        self.enabled(Feature::SandboxDenialLog)
+    }
+
     pub fn enable(&mut self, f: Feature) -> &mut Self {
         self.enabled.insert(f);
         self
@@ -860,6 +867,12 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::Deprecated,
         default_enabled: false,
     },
+    FeatureSpec {
+        id: Feature::SandboxDenialLog,
+        key: "sandbox_denial_log",
+        stage: Stage::UnderDevelopment,
+        default_enabled: false,
+    },
     FeatureSpec {
         id: Feature::RequestRule,
         key: "request_rule",
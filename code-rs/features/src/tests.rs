@@ -48,6 +48,16 @@ fn use_legacy_landlock_is_deprecated_and_disabled_by_default() {
     assert_eq!(Feature::UseLegacyLandlock.default_enabled(), false);
 }
 
+#[test]
+fn sandbox_denial_log_is_under_development() {
+    assert_eq!(Feature::SandboxDenialLog.stage(), Stage::UnderDevelopment);
+    assert_eq!(Feature::SandboxDenialLog.default_enabled(), false);
+    assert_eq!(
+        feature_for_key("sandbox_denial_log"),
+        Some(Feature::SandboxDenialLog)
+    );
+}
+
 #[test]
 fn use_linux_sandbox_bwrap_is_removed_and_disabled_by_default() {
     assert_eq!(Feature::UseLinuxSandboxBwrap.stage(), Stage::Removed);
@@ -12,8 +12,11 @@ use std::time::Duration;
 use anyhow::Result;
 use anyhow::anyhow;
 use codex_login::AuthEnvTelemetry;
+use codex_otel::REDACTION_HIT_METRIC;
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::SessionSource;
+use codex_secrets::OutputRedactor;
+use codex_secrets::RedactionHits;
 use tracing::Event;
 use tracing::Level;
 use tracing::field::Visit;
@@ -352,6 +355,8 @@ pub struct FeedbackUploadOptions<'a> {
     pub extra_attachment_paths: &'a [FeedbackAttachmentPath],
     pub session_source: Option<SessionSource>,
     pub logs_override: Option<Vec<u8>>,
+    /// Applied to the reason and every attachment before upload.
+    pub redactor: Option<&'a OutputRedactor>,
 }
 
 impl FeedbackSnapshot {
@@ -405,9 +410,14 @@ impl FeedbackSnapshot {
             ..Default::default()
         });
 
+        let mut redaction_hits = RedactionHits::default();
+        let reason = options
+            .reason
+            .map(|reason| redact_feedback_text(options.redactor, reason, &mut redaction_hits));
+
         let tags = self.upload_tags(
             options.classification,
-            options.reason,
+            reason.as_deref(),
             options.tags,
             options.session_source.as_ref(),
         );
@@ -430,13 +440,13 @@ impl FeedbackSnapshot {
             tags,
             ..Default::default()
         };
-        if let Some(r) = options.reason {
+        if let Some(r) = reason {
             use sentry::protocol::Exception;
             use sentry::protocol::Values;
 
             event.exception = Values::from(vec![Exception {
                 ty: title,
-                value: Some(r.to_string()),
+                value: Some(r),
                 ..Default::default()
             }]);
         }
@@ -447,8 +457,13 @@ impl FeedbackSnapshot {
             options.extra_attachment_paths,
             options.logs_override,
         ) {
+            let attachment = match options.redactor {
+                Some(redactor) => redact_attachment(redactor, attachment, &mut redaction_hits),
+                None => attachment,
+            };
             envelope.add_item(EnvelopeItem::Attachment(attachment));
         }
+        record_redaction_hits(&redaction_hits);
 
         client.send_envelope(envelope);
         client.flush(Some(Duration::from_secs(UPLOAD_TIMEOUT_SECS)));
@@ -566,6 +581,49 @@ impl FeedbackSnapshot {
     }
 }
 
+fn redact_feedback_text(
+    redactor: Option<&OutputRedactor>,
+    text: &str,
+    hits: &mut RedactionHits,
+) -> String {
+    let Some(redactor) = redactor else {
+        return text.to_string();
+    };
+    let (redacted, found) = redactor.redact(text);
+    hits.merge(found);
+    redacted
+}
+
+fn redact_attachment(
+    redactor: &OutputRedactor,
+    mut attachment: sentry::protocol::Attachment,
+    hits: &mut RedactionHits,
+) -> sentry::protocol::Attachment {
+    // Attachments are logs and rollouts; any invalid UTF-8 is replaced only
+    // when the attachment also contains a secret.
+    let (redacted, found) = redactor.redact(&String::from_utf8_lossy(&attachment.buffer));
+    if !found.is_empty() {
+        attachment.buffer = redacted.into_bytes();
+        hits.merge(found);
+    }
+    attachment
+}
+
+fn record_redaction_hits(hits: &RedactionHits) {
+    let Some(metrics) = codex_otel::global() else {
+        return;
+    };
+    for (kind, count) in hits.iter() {
+        if let Err(err) = metrics.counter(
+            REDACTION_HIT_METRIC,
+            i64::try_from(count).unwrap_or(i64::MAX),
+            &[("source", "feedback"), ("kind", kind.as_str())],
+        ) {
+            tracing::warn!("failed to record redaction metric: {err}");
+        }
+    }
+}
+
 fn display_classification(classification: &str) -> String {
     match classification {
         "bug" => "Bug".to_string(),
@@ -684,6 +742,26 @@ mod tests {
         pretty_assertions::assert_eq!(snap.tags.get("cached").map(String::as_str), Some("true"));
     }
 
+    #[test]
+    fn redact_attachment_only_rewrites_attachments_with_secrets() {
+        let mut redactor = OutputRedactor::default();
+        redactor.add_known_value("hunter2hunter2");
+        let attachment = |buffer: &[u8]| sentry::protocol::Attachment {
+            buffer: buffer.to_vec(),
+            filename: String::from("codex-logs.log"),
+            content_type: Some("text/plain".to_string()),
+            ty: None,
+        };
+        let mut hits = RedactionHits::default();
+
+        let redacted = redact_attachment(&redactor, attachment(b"login hunter2hunter2"), &mut hits);
+        let untouched = redact_attachment(&redactor, attachment(b"\xff no secrets"), &mut hits);
+
+        assert_eq!(redacted.buffer, b"login [REDACTED_SECRET]".to_vec());
+        assert_eq!(untouched.buffer, b"\xff no secrets".to_vec());
+        assert_eq!(hits.total(), 1);
+    }
+
     #[test]
     fn feedback_attachments_gate_connectivity_diagnostics() {
         let extra_filename = format!("codex-feedback-extra-{}.jsonl", ThreadId::new());
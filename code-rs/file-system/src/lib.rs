@@ -33,6 +33,9 @@ pub struct FileMetadata {
     pub is_directory: bool,
     pub is_file: bool,
     pub is_symlink: bool,
+    /// Whether the file has an executable bit set. Always false on platforms
+    /// without them.
+    pub is_executable: bool,
     pub created_at_ms: i64,
     pub modified_at_ms: i64,
 }
@@ -189,4 +192,32 @@ pub trait ExecutorFileSystem: Send + Sync {
         copy_options: CopyOptions,
         sandbox: Option<&FileSystemSandboxContext>,
     ) -> FileSystemResult<()>;
+
+    /// Renames `source_path` to `destination_path`, replacing an existing file
+    /// there. Atomic when both paths are on the same filesystem.
+    async fn rename(
+        &self,
+        source_path: &AbsolutePathBuf,
+        destination_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()>;
+
+    /// Sets or clears the executable bits of the file at `path`, as `chmod +x`
+    /// or `chmod -x` would. A no-op on platforms without them.
+    async fn set_executable(
+        &self,
+        path: &AbsolutePathBuf,
+        executable: bool,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()>;
+
+    /// Creates a symlink at `link_path` pointing at `target`. `target` is
+    /// stored as given, so a relative target resolves against the link's
+    /// directory.
+    async fn create_symlink(
+        &self,
+        target: &Path,
+        link_path: &AbsolutePathBuf,
+        sandbox: Option<&FileSystemSandboxContext>,
+    ) -> FileSystemResult<()>;
 }
@@ -62,7 +62,12 @@ mod tests {
                 pre_tool_use: vec![MatcherGroup {
                     matcher: None,
                     hooks: vec![
-                        HookHandlerConfig::Prompt {},
+                        HookHandlerConfig::Prompt {
+                            prompt: "check it".to_string(),
+                            model: None,
+                            timeout_sec: None,
+                            status_message: None,
+                        },
                         HookHandlerConfig::Command {
                             command: "echo hi".to_string(),
                             timeout_sec: None,
@@ -73,7 +78,12 @@ mod tests {
                 }],
                 session_start: vec![MatcherGroup {
                     matcher: None,
-                    hooks: vec![HookHandlerConfig::Agent {}],
+                    hooks: vec![HookHandlerConfig::Agent {
+                        prompt: "verify it".to_string(),
+                        model: None,
+                        timeout_sec: None,
+                        status_message: None,
+                    }],
                 }],
                 ..Default::default()
             },
@@ -0,0 +1,166 @@
+//! Runs `agent` hook handlers.
+//!
+//! An agent hook hands the event payload and its configured prompt to a
+//! short-lived sub-agent that can inspect the workspace with read-only tools
+//! before answering. Its final message uses the same allow/deny decision as a
+//! prompt hook and is rewritten into command-hook stdout the same way.
+
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+use tokio::time::timeout;
+
+use super::ConfiguredHandler;
+use super::command_runner::CommandRunResult;
+use super::prompt_runner::command_output_for_response;
+use super::prompt_runner::render_prompt;
+use super::prompt_runner::response_schema;
+use super::prompt_runner::run_result;
+use crate::types::HookAgentEvaluator;
+use crate::types::HookAgentRequest;
+
+const AGENT_HANDLER_LABEL: &str = "agent";
+
+const AGENT_HOOK_INSTRUCTIONS: &str = "You are a verification agent that runs as a Codex hook. \
+The user message contains the condition to verify followed by the hook event payload as JSON. \
+Inspect the workspace with your read-only tools as needed; do not modify files. \
+When you are done, reply with a single JSON object. \
+Set `decision` to \"allow\" when the condition holds and \"deny\" when it does not. \
+When denying, set `reason` to a short explanation addressed to the coding agent. \
+Use `additionalContext` only for information the agent should see before continuing; otherwise set it to null.";
+
+pub(crate) async fn run_agent(
+    evaluator: Option<&HookAgentEvaluator>,
+    handler: &ConfiguredHandler,
+    prompt: &str,
+    model: Option<&str>,
+    input_json: &str,
+    cwd: &Path,
+) -> CommandRunResult {
+    let started_at = chrono::Utc::now().timestamp();
+    let started = Instant::now();
+
+    let Some(evaluator) = evaluator else {
+        return run_result(
+            started_at,
+            started,
+            Err("agent hooks are not available in this session".to_string()),
+        );
+    };
+    let request = HookAgentRequest {
+        model: model.map(str::to_string),
+        instructions: AGENT_HOOK_INSTRUCTIONS.to_string(),
+        input: render_prompt(prompt, input_json),
+        output_schema: response_schema(),
+        cwd: cwd.to_path_buf(),
+    };
+    let timeout_duration = Duration::from_secs(handler.timeout_sec);
+    let result = match timeout(timeout_duration, evaluator(request)).await {
+        Ok(Ok(response)) => {
+            command_output_for_response(handler.event_name, AGENT_HANDLER_LABEL, &response)
+        }
+        Ok(Err(err)) => Err(format!("agent hook run failed: {err}")),
+        Err(_) => Err(format!("hook timed out after {}s", handler.timeout_sec)),
+    };
+    run_result(started_at, started, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookSource;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::run_agent;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::types::HookAgentEvaluator;
+    use crate::types::HookAgentRequest;
+
+    fn handler(timeout_sec: u64) -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name: HookEventName::Stop,
+            matcher: None,
+            kind: HandlerKind::Agent {
+                prompt: "Check that the tests were run.".to_string(),
+                model: None,
+            },
+            timeout_sec,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn run_agent_translates_final_message_into_stop_block() {
+        let seen = Arc::new(Mutex::new(None::<HookAgentRequest>));
+        let seen_for_evaluator = Arc::clone(&seen);
+        let evaluator: HookAgentEvaluator = Arc::new(move |request| {
+            *seen_for_evaluator.lock().expect("lock") = Some(request);
+            Box::pin(async {
+                Ok(
+                    r#"{"decision":"deny","reason":"cargo test was never run","additionalContext":null}"#
+                        .to_string(),
+                )
+            })
+        });
+
+        let result = run_agent(
+            Some(&evaluator),
+            &handler(/*timeout_sec*/ 5),
+            "Check that the tests were run.",
+            /*model*/ None,
+            r#"{"hook_event_name":"Stop"}"#,
+            Path::new("/repo"),
+        )
+        .await;
+
+        assert_eq!(result.error, None);
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&result.stdout).expect("json stdout"),
+            json!({ "decision": "block", "reason": "cargo test was never run" })
+        );
+        let request = seen.lock().expect("lock").take().expect("request sent");
+        assert_eq!(request.cwd, Path::new("/repo"));
+        assert_eq!(
+            request.input,
+            "Check that the tests were run.\n\n{\"hook_event_name\":\"Stop\"}"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_agent_times_out() {
+        let evaluator: HookAgentEvaluator = Arc::new(|_| {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(String::new())
+            })
+        });
+
+        let result = run_agent(
+            Some(&evaluator),
+            &handler(/*timeout_sec*/ 0),
+            "Check it",
+            /*model*/ None,
+            "{}",
+            Path::new("/repo"),
+        )
+        .await;
+
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.error.as_deref(), Some("hook timed out after 0s"));
+    }
+}
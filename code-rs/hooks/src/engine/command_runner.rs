@@ -24,13 +24,14 @@ pub(crate) struct CommandRunResult {
 pub(crate) async fn run_command(
     shell: &CommandShell,
     handler: &ConfiguredHandler,
+    command: &str,
     input_json: &str,
     cwd: &Path,
 ) -> CommandRunResult {
     let started_at = chrono::Utc::now().timestamp();
     let started = Instant::now();
 
-    let mut command = build_command(shell, handler);
+    let mut command = build_command(shell, handler, command);
     command
         .current_dir(cwd)
         .stdin(Stdio::piped())
@@ -100,17 +101,17 @@ pub(crate) async fn run_command(
     }
 }
 
-fn build_command(shell: &CommandShell, handler: &ConfiguredHandler) -> Command {
+fn build_command(shell: &CommandShell, handler: &ConfiguredHandler, script: &str) -> Command {
     let mut command = if shell.program.is_empty() {
         default_shell_command()
     } else {
         Command::new(&shell.program)
     };
     if shell.program.is_empty() {
-        command.arg(&handler.command);
+        command.arg(script);
     } else {
         command.args(&shell.args);
-        command.arg(&handler.command);
+        command.arg(script);
     }
     command.envs(&handler.env);
     command
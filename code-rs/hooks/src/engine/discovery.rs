@@ -22,6 +22,7 @@ use serde::Serialize;
 use std::collections::HashMap;
 
 use super::ConfiguredHandler;
+use super::HandlerKind;
 use super::HookListEntry;
 use crate::config_rules::hook_states_from_stack;
 use crate::events::common::matcher_pattern_for_event;
@@ -30,6 +31,17 @@ use codex_protocol::protocol::HookHandlerType;
 use codex_protocol::protocol::HookSource;
 use codex_protocol::protocol::HookTrustStatus;
 
+/// Prompt hooks wait on a model round trip, so they get a shorter default
+/// than command hooks.
+const DEFAULT_PROMPT_TIMEOUT_SEC: u64 = 30;
+
+/// Agent hooks may take several tool-calling turns before they answer.
+const DEFAULT_AGENT_TIMEOUT_SEC: u64 = 120;
+
+/// Per-attempt default for `http` hooks, which usually call a nearby policy
+/// service.
+const DEFAULT_HTTP_TIMEOUT_SEC: u64 = 30;
+
 pub(crate) struct DiscoveryResult {
     pub handlers: Vec<ConfiguredHandler>,
     pub hook_entries: Vec<HookListEntry>,
@@ -385,93 +397,212 @@ fn append_matcher_groups(
             continue;
         }
         for (handler_index, handler) in group.hooks.iter().cloned().enumerate() {
-            match handler {
-                HookHandlerConfig::Command {
-                    command,
+            let Some(resolved) = resolve_handler(handler, source, warnings) else {
+                continue;
+            };
+            let ResolvedHandler {
+                handler_type,
+                kind,
+                timeout_sec,
+                status_message,
+                normalized_handler,
+            } = resolved;
+            let current_hash = command_hook_hash(event_name, matcher, &group, normalized_handler);
+            // TODO(abhinav): replace this positional suffix with a durable hook id.
+            let key = crate::hook_key(&source.key_source, event_name, group_index, handler_index);
+            let state = source.hook_states.get(&key);
+            let enabled = hook_enabled(source.is_managed, state);
+            let trusted_hash = hook_trusted_hash(source.is_managed, state);
+            let trust_status = hook_trust_status(source.is_managed, &current_hash, trusted_hash);
+            let command = match &kind {
+                HandlerKind::Command { command } => Some(command.clone()),
+                HandlerKind::Prompt { .. } => None,
+            };
+            hook_entries.push(HookListEntry {
+                key,
+                event_name,
+                handler_type,
+                matcher: matcher.map(ToOwned::to_owned),
+                command,
+                timeout_sec,
+                status_message: status_message.clone(),
+                source_path: source.path.clone(),
+                source: source.source,
+                plugin_id: source.plugin_id.clone(),
+                display_order: *display_order,
+                enabled,
+                is_managed: source.is_managed,
+                current_hash,
+                trust_status,
+            });
+            if enabled
+                && matches!(
+                    trust_status,
+                    HookTrustStatus::Managed | HookTrustStatus::Trusted
+                )
+            {
+                handlers.push(ConfiguredHandler {
+                    event_name,
+                    matcher: matcher.map(ToOwned::to_owned),
+                    kind,
                     timeout_sec,
-                    r#async,
                     status_message,
-                } => {
-                    if r#async {
-                        warnings.push(format!(
-                            "skipping async hook in {}: async hooks are not supported yet",
-                            source.path.display()
-                        ));
-                        continue;
-                    }
-                    if command.trim().is_empty() {
-                        warnings.push(format!(
-                            "skipping empty hook command in {}",
-                            source.path.display()
-                        ));
-                        continue;
-                    }
-                    let timeout_sec = timeout_sec.unwrap_or(600).max(1);
-                    let normalized_handler = HookHandlerConfig::Command {
-                        command: command.clone(),
-                        timeout_sec: Some(timeout_sec),
-                        r#async,
-                        status_message: status_message.clone(),
-                    };
-                    let current_hash =
-                        command_hook_hash(event_name, matcher, &group, normalized_handler);
-                    let command = source.env.iter().fold(command, |command, (key, value)| {
-                        command.replace(&format!("${{{key}}}"), value)
-                    });
-                    // TODO(abhinav): replace this positional suffix with a durable hook id.
-                    let key =
-                        crate::hook_key(&source.key_source, event_name, group_index, handler_index);
-                    let state = source.hook_states.get(&key);
-                    let enabled = hook_enabled(source.is_managed, state);
-                    let trusted_hash = hook_trusted_hash(source.is_managed, state);
-                    let trust_status =
-                        hook_trust_status(source.is_managed, &current_hash, trusted_hash);
-                    hook_entries.push(HookListEntry {
-                        key,
-                        event_name,
-                        handler_type: HookHandlerType::Command,
-                        matcher: matcher.map(ToOwned::to_owned),
-                        command: Some(command.clone()),
-                        timeout_sec,
-                        status_message: status_message.clone(),
-                        source_path: source.path.clone(),
-                        source: source.source,
-                        plugin_id: source.plugin_id.clone(),
-                        display_order: *display_order,
-                        enabled,
-                        is_managed: source.is_managed,
-                        current_hash,
-                        trust_status,
-                    });
-                    if enabled
-                        && matches!(
-                            trust_status,
-                            HookTrustStatus::Managed | HookTrustStatus::Trusted
-                        )
-                    {
-                        handlers.push(ConfiguredHandler {
-                            event_name,
-                            matcher: matcher.map(ToOwned::to_owned),
-                            command,
-                            timeout_sec,
-                            status_message,
-                            source_path: source.path.clone(),
-                            source: source.source,
-                            display_order: *display_order,
-                            env: source.env.clone(),
-                        });
-                    }
-                    *display_order += 1;
-                }
-                HookHandlerConfig::Prompt {} => warnings.push(format!(
-                    "skipping prompt hook in {}: prompt hooks are not supported yet",
+                    source_path: source.path.clone(),
+                    source: source.source,
+                    display_order: *display_order,
+                    env: source.env.clone(),
+                });
+            }
+            *display_order += 1;
+        }
+    }
+}
+
+/// A configured handler after validation and defaulting, ready to be hashed
+/// for trust and registered.
+struct ResolvedHandler {
+    handler_type: HookHandlerType,
+    kind: HandlerKind,
+    timeout_sec: u64,
+    status_message: Option<String>,
+    normalized_handler: HookHandlerConfig,
+}
+
+fn resolve_handler(
+    handler: HookHandlerConfig,
+    source: &HookHandlerSource<'_>,
+    warnings: &mut Vec<String>,
+) -> Option<ResolvedHandler> {
+    match handler {
+        HookHandlerConfig::Command {
+            command,
+            timeout_sec,
+            r#async,
+            status_message,
+        } => {
+            if r#async {
+                warnings.push(format!(
+                    "skipping async hook in {}: async hooks are not supported yet",
+                    source.path.display()
+                ));
+                return None;
+            }
+            if command.trim().is_empty() {
+                warnings.push(format!(
+                    "skipping empty hook command in {}",
+                    source.path.display()
+                ));
+                return None;
+            }
+            let timeout_sec = timeout_sec.unwrap_or(600).max(1);
+            let normalized_handler = HookHandlerConfig::Command {
+                command: command.clone(),
+                timeout_sec: Some(timeout_sec),
+                r#async,
+                status_message: status_message.clone(),
+            };
+            let command = source.env.iter().fold(command, |command, (key, value)| {
+                command.replace(&format!("${{{key}}}"), value)
+            });
+            Some(ResolvedHandler {
+                handler_type: HookHandlerType::Command,
+                kind: HandlerKind::Command { command },
+                timeout_sec,
+                status_message,
+                normalized_handler,
+            })
+        }
+        HookHandlerConfig::Prompt {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => {
+            if prompt.trim().is_empty() {
+                warnings.push(format!(
+                    "skipping empty hook prompt in {}",
+                    source.path.display()
+                ));
+                return None;
+            }
+            let timeout_sec = timeout_sec.unwrap_or(DEFAULT_PROMPT_TIMEOUT_SEC).max(1);
+            let normalized_handler = HookHandlerConfig::Prompt {
+                prompt: prompt.clone(),
+                model: model.clone(),
+                timeout_sec: Some(timeout_sec),
+                status_message: status_message.clone(),
+            };
+            Some(ResolvedHandler {
+                handler_type: HookHandlerType::Prompt,
+                kind: HandlerKind::Prompt { prompt, model },
+                timeout_sec,
+                status_message,
+                normalized_handler,
+            })
+        }
+        HookHandlerConfig::Agent {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => {
+            if prompt.trim().is_empty() {
+                warnings.push(format!(
+                    "skipping empty hook prompt in {}",
                     source.path.display()
-                )),
-                HookHandlerConfig::Agent {} => warnings.push(format!(
-                    "skipping agent hook in {}: agent hooks are not supported yet",
+                ));
+                return None;
+            }
+            let timeout_sec = timeout_sec.unwrap_or(DEFAULT_AGENT_TIMEOUT_SEC).max(1);
+            let normalized_handler = HookHandlerConfig::Agent {
+                prompt: prompt.clone(),
+                model: model.clone(),
+                timeout_sec: Some(timeout_sec),
+                status_message: status_message.clone(),
+            };
+            Some(ResolvedHandler {
+                handler_type: HookHandlerType::Agent,
+                kind: HandlerKind::Agent { prompt, model },
+                timeout_sec,
+                status_message,
+                normalized_handler,
+            })
+        }
+        HookHandlerConfig::Http {
+            url,
+            headers,
+            timeout_sec,
+            retries,
+            status_message,
+        } => {
+            let url = url.trim().to_string();
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                warnings.push(format!(
+                    "skipping http hook in {}: url must start with http:// or https://",
                     source.path.display()
-                )),
+                ));
+                return None;
             }
+            let timeout_sec = timeout_sec.unwrap_or(DEFAULT_HTTP_TIMEOUT_SEC).max(1);
+            let retries = retries.unwrap_or(0);
+            let normalized_handler = HookHandlerConfig::Http {
+                url: url.clone(),
+                headers: headers.clone(),
+                timeout_sec: Some(timeout_sec),
+                retries: Some(retries),
+                status_message: status_message.clone(),
+            };
+            Some(ResolvedHandler {
+                handler_type: HookHandlerType::Http,
+                kind: HandlerKind::Http {
+                    url,
+                    headers,
+                    retries,
+                },
+                timeout_sec,
+                status_message,
+                normalized_handler,
+            })
         }
     }
 }
@@ -567,6 +698,7 @@ mod tests {
     use codex_config::ConfigLayerSource;
     use codex_config::HookEventsToml;
     use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookHandlerType;
     use codex_protocol::protocol::HookSource;
     use codex_utils_absolute_path::AbsolutePathBuf;
     use codex_utils_absolute_path::test_support::PathBufExt;
@@ -574,6 +706,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::ConfiguredHandler;
+    use super::HandlerKind;
     use super::append_matcher_groups;
     use codex_config::HookHandlerConfig;
     use codex_config::HookStateToml;
@@ -639,7 +772,9 @@ mod tests {
             vec![ConfiguredHandler {
                 event_name: HookEventName::UserPromptSubmit,
                 matcher: None,
-                command: "echo hello".to_string(),
+                kind: HandlerKind::Command {
+                    command: "echo hello".to_string(),
+                },
                 timeout_sec: 600,
                 status_message: None,
                 source_path: source_path.clone(),
@@ -674,7 +809,9 @@ mod tests {
             vec![ConfiguredHandler {
                 event_name: HookEventName::PreToolUse,
                 matcher: Some("^Bash$".to_string()),
-                command: "echo hello".to_string(),
+                kind: HandlerKind::Command {
+                    command: "echo hello".to_string(),
+                },
                 timeout_sec: 600,
                 status_message: None,
                 source_path: source_path.clone(),
@@ -732,6 +869,120 @@ mod tests {
         assert_eq!(handlers[0].matcher.as_deref(), Some("Edit|Write"));
     }
 
+    #[test]
+    fn stop_prompt_hook_is_discovered_with_default_timeout() {
+        let mut handlers = Vec::new();
+        let mut hook_entries = Vec::new();
+        let mut warnings = Vec::new();
+        let mut display_order = 0;
+        let source_path = source_path();
+        let hook_states = std::collections::HashMap::new();
+
+        append_matcher_groups(
+            &mut handlers,
+            &mut hook_entries,
+            &mut warnings,
+            &mut display_order,
+            &hook_handler_source(&source_path, &hook_states),
+            HookEventName::Stop,
+            vec![MatcherGroup {
+                matcher: None,
+                hooks: vec![
+                    HookHandlerConfig::Prompt {
+                        prompt: "Were tests run? $ARGUMENTS".to_string(),
+                        model: Some("gpt-5.1-codex-mini".to_string()),
+                        timeout_sec: None,
+                        status_message: None,
+                    },
+                    HookHandlerConfig::Prompt {
+                        prompt: "  ".to_string(),
+                        model: None,
+                        timeout_sec: None,
+                        status_message: None,
+                    },
+                ],
+            }],
+        );
+
+        assert_eq!(
+            warnings,
+            vec![format!(
+                "skipping empty hook prompt in {}",
+                source_path.display()
+            )]
+        );
+        assert_eq!(
+            handlers,
+            vec![ConfiguredHandler {
+                event_name: HookEventName::Stop,
+                matcher: None,
+                kind: HandlerKind::Prompt {
+                    prompt: "Were tests run? $ARGUMENTS".to_string(),
+                    model: Some("gpt-5.1-codex-mini".to_string()),
+                },
+                timeout_sec: 30,
+                status_message: None,
+                source_path: source_path.clone(),
+                source: hook_source(),
+                display_order: 0,
+                env: std::collections::HashMap::new(),
+            }]
+        );
+        assert_eq!(hook_entries.len(), 1);
+        assert_eq!(hook_entries[0].handler_type, HookHandlerType::Prompt);
+        assert_eq!(hook_entries[0].command, None);
+    }
+
+    #[test]
+    fn stop_agent_hook_is_discovered_with_default_timeout() {
+        let mut handlers = Vec::new();
+        let mut hook_entries = Vec::new();
+        let mut warnings = Vec::new();
+        let mut display_order = 0;
+        let source_path = source_path();
+        let hook_states = std::collections::HashMap::new();
+
+        append_matcher_groups(
+            &mut handlers,
+            &mut hook_entries,
+            &mut warnings,
+            &mut display_order,
+            &hook_handler_source(&source_path, &hook_states),
+            HookEventName::Stop,
+            vec![MatcherGroup {
+                matcher: None,
+                hooks: vec![HookHandlerConfig::Agent {
+                    prompt: "Verify that the tests were run.".to_string(),
+                    model: None,
+                    timeout_sec: None,
+                    status_message: Some("Verifying tests".to_string()),
+                }],
+            }],
+        );
+
+        assert_eq!(warnings, Vec::<String>::new());
+        assert_eq!(
+            handlers,
+            vec![ConfiguredHandler {
+                event_name: HookEventName::Stop,
+                matcher: None,
+                kind: HandlerKind::Agent {
+                    prompt: "Verify that the tests were run.".to_string(),
+                    model: None,
+                },
+                timeout_sec: 120,
+                status_message: Some("Verifying tests".to_string()),
+                source_path: source_path.clone(),
+                source: hook_source(),
+                display_order: 0,
+                env: std::collections::HashMap::new(),
+            }]
+        );
+        assert_eq!(hook_entries.len(), 1);
+        assert_eq!(hook_entries[0].handler_type, HookHandlerType::Agent);
+        assert_eq!(hook_entries[0].command, None);
+    }
+
     #[test]
     fn toml_hook_discovery_ignores_malformed_state_entries() {
         let layer = ConfigLayerEntry::new(
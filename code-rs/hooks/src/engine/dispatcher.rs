@@ -5,15 +5,18 @@ use futures::future::join_all;
 use codex_protocol::protocol::HookCompletedEvent;
 use codex_protocol::protocol::HookEventName;
 use codex_protocol::protocol::HookExecutionMode;
-use codex_protocol::protocol::HookHandlerType;
 use codex_protocol::protocol::HookRunStatus;
 use codex_protocol::protocol::HookRunSummary;
 use codex_protocol::protocol::HookScope;
 
-use super::CommandShell;
 use super::ConfiguredHandler;
+use super::HandlerKind;
+use super::HookExecutor;
+use super::agent_runner::run_agent;
 use super::command_runner::CommandRunResult;
 use super::command_runner::run_command;
+use super::http_runner::run_http;
+use super::prompt_runner::run_prompt;
 use crate::events::common::matches_matcher;
 
 #[derive(Debug)]
@@ -48,7 +51,10 @@ pub(crate) fn select_handlers_for_matcher_inputs(
             | HookEventName::PostToolUse
             | HookEventName::SessionStart
             | HookEventName::PreCompact
-            | HookEventName::PostCompact => {
+            | HookEventName::PostCompact
+            | HookEventName::SubagentStart
+            | HookEventName::SubagentStop
+            | HookEventName::Notification => {
                 if matcher_inputs.is_empty() {
                     matches_matcher(handler.matcher.as_deref(), /*input*/ None)
                 } else {
@@ -57,7 +63,9 @@ pub(crate) fn select_handlers_for_matcher_inputs(
                         .any(|input| matches_matcher(handler.matcher.as_deref(), Some(input)))
                 }
             }
-            HookEventName::UserPromptSubmit | HookEventName::Stop => true,
+            HookEventName::UserPromptSubmit | HookEventName::Stop | HookEventName::SessionEnd => {
+                true
+            }
         })
         .cloned()
         .collect()
@@ -67,7 +75,7 @@ pub(crate) fn running_summary(handler: &ConfiguredHandler) -> HookRunSummary {
     HookRunSummary {
         id: handler.run_id(),
         event_name: handler.event_name,
-        handler_type: HookHandlerType::Command,
+        handler_type: handler.handler_type(),
         execution_mode: HookExecutionMode::Sync,
         scope: scope_for_event(handler.event_name),
         source_path: handler.source_path.clone(),
@@ -83,7 +91,7 @@ pub(crate) fn running_summary(handler: &ConfiguredHandler) -> HookRunSummary {
 }
 
 pub(crate) async fn execute_handlers<T>(
-    shell: &CommandShell,
+    executor: &HookExecutor,
     handlers: Vec<ConfiguredHandler>,
     input_json: String,
     cwd: &Path,
@@ -93,7 +101,7 @@ pub(crate) async fn execute_handlers<T>(
     let results = join_all(
         handlers
             .iter()
-            .map(|handler| run_command(shell, handler, &input_json, cwd)),
+            .map(|handler| run_handler(executor, handler, &input_json, cwd)),
     )
     .await;
 
@@ -104,6 +112,47 @@ pub(crate) async fn execute_handlers<T>(
         .collect()
 }
 
+/// Runs one handler and reports its result in command form, so every handler
+/// type goes through the same per-event output parsing.
+async fn run_handler(
+    executor: &HookExecutor,
+    handler: &ConfiguredHandler,
+    input_json: &str,
+    cwd: &Path,
+) -> CommandRunResult {
+    match &handler.kind {
+        HandlerKind::Command { command } => {
+            run_command(&executor.shell, handler, command, input_json, cwd).await
+        }
+        HandlerKind::Prompt { prompt, model } => {
+            run_prompt(
+                executor.prompt_evaluator.as_ref(),
+                handler,
+                prompt,
+                model.as_deref(),
+                input_json,
+            )
+            .await
+        }
+        HandlerKind::Agent { prompt, model } => {
+            run_agent(
+                executor.agent_evaluator.as_ref(),
+                handler,
+                prompt,
+                model.as_deref(),
+                input_json,
+                cwd,
+            )
+            .await
+        }
+        HandlerKind::Http {
+            url,
+            headers,
+            retries,
+        } => run_http(handler, url, headers, *retries, input_json).await,
+    }
+}
+
 pub(crate) fn completed_summary(
     handler: &ConfiguredHandler,
     run_result: &CommandRunResult,
@@ -113,7 +162,7 @@ pub(crate) fn completed_summary(
     HookRunSummary {
         id: handler.run_id(),
         event_name: handler.event_name,
-        handler_type: HookHandlerType::Command,
+        handler_type: handler.handler_type(),
         execution_mode: HookExecutionMode::Sync,
         scope: scope_for_event(handler.event_name),
         source_path: handler.source_path.clone(),
@@ -130,14 +179,18 @@ pub(crate) fn completed_summary(
 
 fn scope_for_event(event_name: HookEventName) -> HookScope {
     match event_name {
-        HookEventName::SessionStart => HookScope::Thread,
+        HookEventName::SessionStart
+        | HookEventName::SessionEnd
+        | HookEventName::SubagentStart
+        | HookEventName::SubagentStop => HookScope::Thread,
         HookEventName::PreToolUse
         | HookEventName::PermissionRequest
         | HookEventName::PostToolUse
         | HookEventName::PreCompact
         | HookEventName::PostCompact
         | HookEventName::UserPromptSubmit
-        | HookEventName::Stop => HookScope::Turn,
+        | HookEventName::Stop
+        | HookEventName::Notification => HookScope::Turn,
     }
 }
 
@@ -149,6 +202,7 @@ mod tests {
     use codex_utils_absolute_path::test_support::test_path_buf;
 
     use super::ConfiguredHandler;
+    use super::HandlerKind;
     use super::select_handlers;
     use super::select_handlers_for_matcher_inputs;
 
@@ -161,7 +215,9 @@ mod tests {
         ConfiguredHandler {
             event_name,
             matcher: matcher.map(str::to_owned),
-            command: command.to_string(),
+            kind: HandlerKind::Command {
+                command: command.to_string(),
+            },
             timeout_sec: 5,
             status_message: None,
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -428,8 +484,23 @@ mod tests {
         let selected = select_handlers(&handlers, HookEventName::Stop, /*matcher_input*/ None);
 
         assert_eq!(selected.len(), 3);
-        assert_eq!(selected[0].command, "first");
-        assert_eq!(selected[1].command, "second");
-        assert_eq!(selected[2].command, "third");
+        assert_eq!(
+            selected[0].kind,
+            HandlerKind::Command {
+                command: "first".to_string()
+            }
+        );
+        assert_eq!(
+            selected[1].kind,
+            HandlerKind::Command {
+                command: "second".to_string()
+            }
+        );
+        assert_eq!(
+            selected[2].kind,
+            HandlerKind::Command {
+                command: "third".to_string()
+            }
+        );
     }
 }
@@ -0,0 +1,289 @@
+//! Runs `http` hook handlers.
+//!
+//! An HTTP hook POSTs the same JSON payload a command hook reads on stdin to a
+//! configured URL. A 2xx response body is treated as the hook's stdout, so each
+//! event's existing output parsing and outcome handling apply unchanged. Any
+//! other outcome fails the hook without blocking, like a command that crashed.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_client::build_reqwest_client_with_custom_ca;
+use reqwest::StatusCode;
+use reqwest::header::CONTENT_TYPE;
+use tokio::time::timeout;
+
+use super::ConfiguredHandler;
+use super::command_runner::CommandRunResult;
+use super::prompt_runner::run_result;
+
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+const MAX_RETRY_BACKOFF_EXPONENT: u32 = 5;
+
+/// How a single POST attempt ended when it did not produce a usable body.
+struct AttemptError {
+    message: String,
+    retryable: bool,
+}
+
+pub(crate) async fn run_http(
+    handler: &ConfiguredHandler,
+    url: &str,
+    headers: &BTreeMap<String, String>,
+    retries: u32,
+    input_json: &str,
+) -> CommandRunResult {
+    let started_at = chrono::Utc::now().timestamp();
+    let started = Instant::now();
+
+    let client = match http_client() {
+        Ok(client) => client,
+        Err(err) => {
+            return run_result(
+                started_at,
+                started,
+                Err(format!("failed to build http hook client: {err}")),
+            );
+        }
+    };
+
+    // The hook's timeout covers every attempt and the backoff between them.
+    let attempts = async {
+        let mut attempt = 0;
+        loop {
+            match post_once(client, url, headers, input_json).await {
+                Ok(body) => break Ok(body),
+                Err(err) if err.retryable && attempt < retries => {
+                    tokio::time::sleep(
+                        RETRY_BASE_DELAY * 2u32.pow(attempt.min(MAX_RETRY_BACKOFF_EXPONENT)),
+                    )
+                    .await;
+                    attempt += 1;
+                }
+                Err(err) => break Err(err.message),
+            }
+        }
+    };
+    let result = match timeout(Duration::from_secs(handler.timeout_sec), attempts).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("hook timed out after {}s", handler.timeout_sec)),
+    };
+    run_result(started_at, started, result)
+}
+
+/// Client shared by every HTTP hook, so connections are pooled across calls.
+fn http_client() -> Result<&'static reqwest::Client, &'static str> {
+    static CLIENT: OnceLock<Result<reqwest::Client, String>> = OnceLock::new();
+    CLIENT
+        .get_or_init(|| {
+            build_reqwest_client_with_custom_ca(reqwest::Client::builder())
+                .map_err(|err| err.to_string())
+        })
+        .as_ref()
+        .map_err(String::as_str)
+}
+
+async fn post_once(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &BTreeMap<String, String>,
+    input_json: &str,
+) -> Result<String, AttemptError> {
+    let mut request = client
+        .post(url)
+        .header(CONTENT_TYPE, "application/json")
+        .body(input_json.to_string());
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+
+    let send_and_read = async {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+        Ok::<_, reqwest::Error>((status, body))
+    };
+    match send_and_read.await {
+        Ok((status, body)) if status.is_success() => Ok(body),
+        Ok((status, body)) => Err(AttemptError {
+            message: status_error_message(status, &body),
+            retryable: status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error(),
+        }),
+        Err(err) => Err(AttemptError {
+            retryable: !err.is_builder(),
+            message: format!("http hook request failed: {err}"),
+        }),
+    }
+}
+
+fn status_error_message(status: StatusCode, body: &str) -> String {
+    let body = body.trim();
+    if body.is_empty() {
+        format!("http hook returned {status}")
+    } else {
+        format!("http hook returned {status}: {body}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookSource;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::body_json;
+    use wiremock::matchers::header;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+
+    use super::run_http;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::engine::output_parser;
+
+    fn handler(url: &str, retries: u32) -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name: HookEventName::PreToolUse,
+            matcher: None,
+            kind: HandlerKind::Http {
+                url: url.to_string(),
+                headers: BTreeMap::new(),
+                retries,
+            },
+            timeout_sec: 5,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn response_body_is_parsed_as_hook_output() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hooks"))
+            .and(header("authorization", "Bearer policy-token"))
+            .and(header("content-type", "application/json"))
+            .and(body_json(json!({ "hook_event_name": "PreToolUse" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "hookSpecificOutput": {
+                    "hookEventName": "PreToolUse",
+                    "permissionDecision": "deny",
+                    "permissionDecisionReason": "blocked by policy service",
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = format!("{}/hooks", server.uri());
+        let headers = BTreeMap::from([(
+            "Authorization".to_string(),
+            "Bearer policy-token".to_string(),
+        )]);
+
+        let result = run_http(
+            &handler(&url, /*retries*/ 0),
+            &url,
+            &headers,
+            /*retries*/ 0,
+            r#"{"hook_event_name":"PreToolUse"}"#,
+        )
+        .await;
+
+        assert_eq!(result.error, None);
+        assert_eq!(result.exit_code, Some(0));
+        let parsed = output_parser::parse_pre_tool_use(&result.stdout).expect("parsed output");
+        assert_eq!(
+            parsed.block_reason.as_deref(),
+            Some("blocked by policy service")
+        );
+    }
+
+    #[tokio::test]
+    async fn server_errors_are_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(""))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = server.uri();
+
+        let result = run_http(
+            &handler(&url, /*retries*/ 1),
+            &url,
+            &BTreeMap::new(),
+            /*retries*/ 1,
+            "{}",
+        )
+        .await;
+
+        assert_eq!(result.error, None);
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(result.stdout, "");
+    }
+
+    #[tokio::test]
+    async fn client_errors_fail_without_retry() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("forbidden"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let url = server.uri();
+
+        let result = run_http(
+            &handler(&url, /*retries*/ 3),
+            &url,
+            &BTreeMap::new(),
+            /*retries*/ 3,
+            "{}",
+        )
+        .await;
+
+        assert_eq!(result.exit_code, None);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("http hook returned 403 Forbidden: forbidden")
+        );
+    }
+
+    #[tokio::test]
+    async fn timeout_bounds_all_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+        let url = server.uri();
+        let handler = ConfiguredHandler {
+            timeout_sec: 1,
+            ..handler(&url, /*retries*/ 10)
+        };
+
+        let started = std::time::Instant::now();
+        let result = run_http(&handler, &url, &BTreeMap::new(), /*retries*/ 10, "{}").await;
+
+        assert_eq!(result.exit_code, None);
+        assert_eq!(result.error.as_deref(), Some("hook timed out after 1s"));
+        assert!(started.elapsed() < std::time::Duration::from_secs(3));
+    }
+}
@@ -1,28 +1,38 @@
+pub(crate) mod agent_runner;
 pub(crate) mod command_runner;
 pub(crate) mod discovery;
 pub(crate) mod dispatcher;
+pub(crate) mod http_runner;
 pub(crate) mod output_parser;
+pub(crate) mod prompt_runner;
 pub(crate) mod schema_loader;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use crate::events::compact::PostCompactRequest;
 use crate::events::compact::PreCompactOutcome;
 use crate::events::compact::PreCompactRequest;
 use crate::events::compact::StatelessHookOutcome;
+use crate::events::notification::NotificationRequest;
 use crate::events::permission_request::PermissionRequestOutcome;
 use crate::events::permission_request::PermissionRequestRequest;
 use crate::events::post_tool_use::PostToolUseOutcome;
 use crate::events::post_tool_use::PostToolUseRequest;
 use crate::events::pre_tool_use::PreToolUseOutcome;
 use crate::events::pre_tool_use::PreToolUseRequest;
+use crate::events::session_end::SessionEndRequest;
 use crate::events::session_start::SessionStartOutcome;
 use crate::events::session_start::SessionStartRequest;
 use crate::events::stop::StopOutcome;
 use crate::events::stop::StopRequest;
+use crate::events::subagent::SubagentStartRequest;
+use crate::events::subagent::SubagentStopRequest;
 use crate::events::user_prompt_submit::UserPromptSubmitOutcome;
 use crate::events::user_prompt_submit::UserPromptSubmitRequest;
 use crate::output_spill::HookOutputSpiller;
+use crate::types::HookAgentEvaluator;
+use crate::types::HookPromptEvaluator;
 use codex_config::ConfigLayerStack;
 use codex_plugin::PluginHookSource;
 use codex_protocol::ThreadId;
@@ -39,11 +49,39 @@ pub(crate) struct CommandShell {
     pub args: Vec<String>,
 }
 
+/// Everything a handler may need at run time beyond its own configuration.
+#[derive(Clone)]
+pub(crate) struct HookExecutor {
+    pub shell: CommandShell,
+    pub prompt_evaluator: Option<HookPromptEvaluator>,
+    pub agent_evaluator: Option<HookAgentEvaluator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HandlerKind {
+    Command {
+        command: String,
+    },
+    Prompt {
+        prompt: String,
+        model: Option<String>,
+    },
+    Agent {
+        prompt: String,
+        model: Option<String>,
+    },
+    Http {
+        url: String,
+        headers: BTreeMap<String, String>,
+        retries: u32,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct ConfiguredHandler {
     pub event_name: codex_protocol::protocol::HookEventName,
     pub matcher: Option<String>,
-    pub command: String,
+    pub kind: HandlerKind,
     pub timeout_sec: u64,
     pub status_message: Option<String>,
     pub source_path: AbsolutePathBuf,
@@ -62,6 +100,15 @@ impl ConfiguredHandler {
         )
     }
 
+    pub fn handler_type(&self) -> HookHandlerType {
+        match self.kind {
+            HandlerKind::Command { .. } => HookHandlerType::Command,
+            HandlerKind::Prompt { .. } => HookHandlerType::Prompt,
+            HandlerKind::Agent { .. } => HookHandlerType::Agent,
+            HandlerKind::Http { .. } => HookHandlerType::Http,
+        }
+    }
+
     fn event_name_label(&self) -> &'static str {
         match self.event_name {
             codex_protocol::protocol::HookEventName::PreToolUse => "pre-tool-use",
@@ -72,6 +119,10 @@ impl ConfiguredHandler {
             codex_protocol::protocol::HookEventName::SessionStart => "session-start",
             codex_protocol::protocol::HookEventName::UserPromptSubmit => "user-prompt-submit",
             codex_protocol::protocol::HookEventName::Stop => "stop",
+            codex_protocol::protocol::HookEventName::SessionEnd => "session-end",
+            codex_protocol::protocol::HookEventName::SubagentStart => "subagent-start",
+            codex_protocol::protocol::HookEventName::SubagentStop => "subagent-stop",
+            codex_protocol::protocol::HookEventName::Notification => "notification",
         }
     }
 }
@@ -99,7 +150,7 @@ pub struct HookListEntry {
 pub(crate) struct ClaudeHooksEngine {
     handlers: Vec<ConfiguredHandler>,
     warnings: Vec<String>,
-    shell: CommandShell,
+    executor: HookExecutor,
     output_spiller: HookOutputSpiller,
 }
 
@@ -115,7 +166,11 @@ impl ClaudeHooksEngine {
             return Self {
                 handlers: Vec::new(),
                 warnings: Vec::new(),
-                shell,
+                executor: HookExecutor {
+                    shell,
+                    prompt_evaluator: None,
+                    agent_evaluator: None,
+                },
                 output_spiller: HookOutputSpiller::new(),
             };
         }
@@ -129,11 +184,31 @@ impl ClaudeHooksEngine {
         Self {
             handlers: discovered.handlers,
             warnings: discovered.warnings,
-            shell,
+            executor: HookExecutor {
+                shell,
+                prompt_evaluator: None,
+                agent_evaluator: None,
+            },
             output_spiller: HookOutputSpiller::new(),
         }
     }
 
+    pub(crate) fn with_prompt_evaluator(
+        mut self,
+        prompt_evaluator: Option<HookPromptEvaluator>,
+    ) -> Self {
+        self.executor.prompt_evaluator = prompt_evaluator;
+        self
+    }
+
+    pub(crate) fn with_agent_evaluator(
+        mut self,
+        agent_evaluator: Option<HookAgentEvaluator>,
+    ) -> Self {
+        self.executor.agent_evaluator = agent_evaluator;
+        self
+    }
+
     pub(crate) fn warnings(&self) -> &[String] {
         &self.warnings
     }
@@ -170,7 +245,8 @@ impl ClaudeHooksEngine {
     ) -> SessionStartOutcome {
         let session_id = request.session_id;
         let mut outcome =
-            crate::events::session_start::run(&self.handlers, &self.shell, request, turn_id).await;
+            crate::events::session_start::run(&self.handlers, &self.executor, request, turn_id)
+                .await;
         outcome.additional_contexts = self
             .maybe_spill_texts(session_id, outcome.additional_contexts)
             .await;
@@ -178,14 +254,14 @@ impl ClaudeHooksEngine {
     }
 
     pub(crate) async fn run_pre_tool_use(&self, request: PreToolUseRequest) -> PreToolUseOutcome {
-        crate::events::pre_tool_use::run(&self.handlers, &self.shell, request).await
+        crate::events::pre_tool_use::run(&self.handlers, &self.executor, request).await
     }
 
     pub(crate) async fn run_permission_request(
         &self,
         request: PermissionRequestRequest,
     ) -> PermissionRequestOutcome {
-        crate::events::permission_request::run(&self.handlers, &self.shell, request).await
+        crate::events::permission_request::run(&self.handlers, &self.executor, request).await
     }
 
     pub(crate) async fn run_post_tool_use(
@@ -194,7 +270,7 @@ impl ClaudeHooksEngine {
     ) -> PostToolUseOutcome {
         let session_id = request.session_id;
         let mut outcome =
-            crate::events::post_tool_use::run(&self.handlers, &self.shell, request).await;
+            crate::events::post_tool_use::run(&self.handlers, &self.executor, request).await;
         outcome.additional_contexts = self
             .maybe_spill_texts(session_id, outcome.additional_contexts)
             .await;
@@ -209,7 +285,7 @@ impl ClaudeHooksEngine {
     }
 
     pub(crate) async fn run_pre_compact(&self, request: PreCompactRequest) -> PreCompactOutcome {
-        crate::events::compact::run_pre(&self.handlers, &self.shell, request).await
+        crate::events::compact::run_pre(&self.handlers, &self.executor, request).await
     }
 
     pub(crate) fn preview_post_compact(&self, request: &PostCompactRequest) -> Vec<HookRunSummary> {
@@ -220,7 +296,7 @@ impl ClaudeHooksEngine {
         &self,
         request: PostCompactRequest,
     ) -> StatelessHookOutcome {
-        crate::events::compact::run_post(&self.handlers, &self.shell, request).await
+        crate::events::compact::run_post(&self.handlers, &self.executor, request).await
     }
 
     pub(crate) fn preview_user_prompt_submit(
@@ -236,7 +312,7 @@ impl ClaudeHooksEngine {
     ) -> UserPromptSubmitOutcome {
         let session_id = request.session_id;
         let mut outcome =
-            crate::events::user_prompt_submit::run(&self.handlers, &self.shell, request).await;
+            crate::events::user_prompt_submit::run(&self.handlers, &self.executor, request).await;
         outcome.additional_contexts = self
             .maybe_spill_texts(session_id, outcome.additional_contexts)
             .await;
@@ -249,13 +325,63 @@ impl ClaudeHooksEngine {
 
     pub(crate) async fn run_stop(&self, request: StopRequest) -> StopOutcome {
         let session_id = request.session_id;
-        let mut outcome = crate::events::stop::run(&self.handlers, &self.shell, request).await;
+        let mut outcome = crate::events::stop::run(&self.handlers, &self.executor, request).await;
         outcome.continuation_fragments = self
             .maybe_spill_prompt_fragments(session_id, outcome.continuation_fragments)
             .await;
         outcome
     }
 
+    pub(crate) fn preview_session_end(&self, request: &SessionEndRequest) -> Vec<HookRunSummary> {
+        crate::events::session_end::preview(&self.handlers, request)
+    }
+
+    pub(crate) async fn run_session_end(&self, request: SessionEndRequest) -> StatelessHookOutcome {
+        crate::events::session_end::run(&self.handlers, &self.executor, request).await
+    }
+
+    pub(crate) fn preview_subagent_start(
+        &self,
+        request: &SubagentStartRequest,
+    ) -> Vec<HookRunSummary> {
+        crate::events::subagent::preview_start(&self.handlers, request)
+    }
+
+    pub(crate) async fn run_subagent_start(
+        &self,
+        request: SubagentStartRequest,
+    ) -> StatelessHookOutcome {
+        crate::events::subagent::run_start(&self.handlers, &self.executor, request).await
+    }
+
+    pub(crate) fn preview_subagent_stop(
+        &self,
+        request: &SubagentStopRequest,
+    ) -> Vec<HookRunSummary> {
+        crate::events::subagent::preview_stop(&self.handlers, request)
+    }
+
+    pub(crate) async fn run_subagent_stop(
+        &self,
+        request: SubagentStopRequest,
+    ) -> StatelessHookOutcome {
+        crate::events::subagent::run_stop(&self.handlers, &self.executor, request).await
+    }
+
+    pub(crate) fn preview_notification(
+        &self,
+        request: &NotificationRequest,
+    ) -> Vec<HookRunSummary> {
+        crate::events::notification::preview(&self.handlers, request)
+    }
+
+    pub(crate) async fn run_notification(
+        &self,
+        request: NotificationRequest,
+    ) -> StatelessHookOutcome {
+        crate::events::notification::run(&self.handlers, &self.executor, request).await
+    }
+
     async fn maybe_spill_texts(&self, session_id: ThreadId, texts: Vec<String>) -> Vec<String> {
         self.output_spiller
             .maybe_spill_texts(session_id, texts)
@@ -28,6 +28,7 @@ use tempfile::tempdir;
 
 use super::ClaudeHooksEngine;
 use super::CommandShell;
+use super::HandlerKind;
 use crate::events::pre_tool_use::PreToolUseRequest;
 
 fn cwd() -> AbsolutePathBuf {
@@ -131,6 +132,8 @@ with Path(r"{log_path}").open("a", encoding="utf-8") as handle:
         plugin_hook_load_warnings: Vec::new(),
         shell_program: None,
         shell_args: Vec::new(),
+        prompt_evaluator: None,
+        agent_evaluator: None,
     });
     assert!(listed.hooks[0].is_managed);
     let cwd = cwd();
@@ -720,6 +723,8 @@ print(json.dumps({
         plugin_hook_load_warnings: Vec::new(),
         shell_program: None,
         shell_args: Vec::new(),
+        prompt_evaluator: None,
+        agent_evaluator: None,
     });
     assert_eq!(
         listed.hooks[0].plugin_id.as_deref(),
@@ -806,14 +811,16 @@ fn plugin_hook_sources_expand_plugin_placeholders() {
     );
 
     assert_eq!(
-        engine.handlers[0].command,
-        format!(
-            "run {} {} {} {}",
-            plugin_root.display(),
-            plugin_root.display(),
-            plugin_data_root.display(),
-            plugin_data_root.display()
-        )
+        engine.handlers[0].kind,
+        HandlerKind::Command {
+            command: format!(
+                "run {} {} {} {}",
+                plugin_root.display(),
+                plugin_root.display(),
+                plugin_data_root.display(),
+                plugin_data_root.display()
+            ),
+        }
     );
     assert_eq!(
         engine.handlers[0].env,
@@ -0,0 +1,410 @@
+//! Runs `prompt` hook handlers.
+//!
+//! A prompt hook sends the event payload and its configured prompt to a model
+//! and asks for an allow/deny decision. The decision is rewritten into the JSON
+//! a command hook would print on stdout for the same event, so each event's
+//! existing output parsing and outcome handling apply unchanged.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::protocol::HookEventName;
+use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
+use tokio::time::timeout;
+
+use super::ConfiguredHandler;
+use super::command_runner::CommandRunResult;
+use crate::types::HookPromptEvaluator;
+use crate::types::HookPromptRequest;
+
+const ARGUMENTS_PLACEHOLDER: &str = "$ARGUMENTS";
+
+const PROMPT_HOOK_INSTRUCTIONS: &str = "You are a policy check that runs as a Codex hook. \
+The user message contains the policy to enforce followed by the hook event payload as JSON. \
+Decide whether the event satisfies the policy and reply with a single JSON object. \
+Set `decision` to \"allow\" when the policy is satisfied and \"deny\" when it is not. \
+When denying, set `reason` to a short explanation addressed to the coding agent. \
+Use `additionalContext` only for information the agent should see before continuing; otherwise set it to null.";
+
+/// Names prompt hooks in error messages and the default deny reason.
+pub(super) const PROMPT_HANDLER_LABEL: &str = "prompt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PromptHookDecision {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptHookResponse {
+    decision: PromptHookDecision,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    additional_context: Option<String>,
+}
+
+pub(crate) async fn run_prompt(
+    evaluator: Option<&HookPromptEvaluator>,
+    handler: &ConfiguredHandler,
+    prompt: &str,
+    model: Option<&str>,
+    input_json: &str,
+) -> CommandRunResult {
+    let started_at = chrono::Utc::now().timestamp();
+    let started = Instant::now();
+
+    let Some(evaluator) = evaluator else {
+        return run_result(
+            started_at,
+            started,
+            Err("prompt hooks are not available in this session".to_string()),
+        );
+    };
+    let request = HookPromptRequest {
+        model: model.map(str::to_string),
+        instructions: PROMPT_HOOK_INSTRUCTIONS.to_string(),
+        input: render_prompt(prompt, input_json),
+        output_schema: response_schema(),
+    };
+    let timeout_duration = Duration::from_secs(handler.timeout_sec);
+    let result = match timeout(timeout_duration, evaluator(request)).await {
+        Ok(Ok(response)) => {
+            command_output_for_response(handler.event_name, PROMPT_HANDLER_LABEL, &response)
+        }
+        Ok(Err(err)) => Err(format!("prompt hook model request failed: {err}")),
+        Err(_) => Err(format!("hook timed out after {}s", handler.timeout_sec)),
+    };
+    run_result(started_at, started, result)
+}
+
+/// Reports a model-backed handler's outcome in command form: rewritten stdout
+/// with a zero exit code on success, or an execution error.
+pub(super) fn run_result(
+    started_at: i64,
+    started: Instant,
+    result: Result<String, String>,
+) -> CommandRunResult {
+    let (exit_code, stdout, error) = match result {
+        Ok(stdout) => (Some(0), stdout, None),
+        Err(error) => (None, String::new(), Some(error)),
+    };
+    CommandRunResult {
+        started_at,
+        completed_at: chrono::Utc::now().timestamp(),
+        duration_ms: started.elapsed().as_millis().try_into().unwrap_or(i64::MAX),
+        exit_code,
+        stdout,
+        stderr: String::new(),
+        error,
+    }
+}
+
+/// Substitutes the event payload for `$ARGUMENTS`, or appends it when the
+/// prompt does not reference the placeholder.
+pub(super) fn render_prompt(prompt: &str, input_json: &str) -> String {
+    if prompt.contains(ARGUMENTS_PLACEHOLDER) {
+        prompt.replace(ARGUMENTS_PLACEHOLDER, input_json)
+    } else {
+        format!("{prompt}\n\n{input_json}")
+    }
+}
+
+pub(super) fn response_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "decision": { "type": "string", "enum": ["allow", "deny"] },
+            "reason": { "type": ["string", "null"] },
+            "additionalContext": { "type": ["string", "null"] }
+        },
+        "required": ["decision", "reason", "additionalContext"],
+        "additionalProperties": false
+    })
+}
+
+/// Rewrites a model decision as the command-hook stdout for `event_name`.
+///
+/// `allow` only ever means "no objection": a `PermissionRequest` prompt hook
+/// can deny an approval but never grants one on its own. `handler_label`
+/// names the handler type in error messages and the default deny reason.
+pub(super) fn command_output_for_response(
+    event_name: HookEventName,
+    handler_label: &str,
+    response: &str,
+) -> Result<String, String> {
+    let response: PromptHookResponse = serde_json::from_str(response.trim())
+        .map_err(|err| format!("{handler_label} hook returned invalid decision JSON: {err}"))?;
+    let deny = response.decision == PromptHookDecision::Deny;
+    let reason = response
+        .reason
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty())
+        .unwrap_or_else(|| format!("denied by {handler_label} hook"));
+    let additional_context = response
+        .additional_context
+        .filter(|context| !context.trim().is_empty());
+    let event_label = event_name_label(event_name);
+
+    let mut output = json!({});
+    match event_name {
+        HookEventName::PreToolUse => {
+            let mut specific = json!({ "hookEventName": event_label });
+            if deny {
+                specific["permissionDecision"] = json!("deny");
+                specific["permissionDecisionReason"] = json!(reason);
+            }
+            if let Some(additional_context) = additional_context {
+                specific["additionalContext"] = json!(additional_context);
+            }
+            output["hookSpecificOutput"] = specific;
+        }
+        HookEventName::PermissionRequest => {
+            if deny {
+                output["hookSpecificOutput"] = json!({
+                    "hookEventName": event_label,
+                    "decision": { "behavior": "deny", "message": reason },
+                });
+            }
+        }
+        HookEventName::PostToolUse | HookEventName::UserPromptSubmit => {
+            if deny {
+                output["decision"] = json!("block");
+                output["reason"] = json!(reason);
+            }
+            if let Some(additional_context) = additional_context {
+                output["hookSpecificOutput"] = json!({
+                    "hookEventName": event_label,
+                    "additionalContext": additional_context,
+                });
+            }
+        }
+        HookEventName::Stop => {
+            if deny {
+                output["decision"] = json!("block");
+                output["reason"] = json!(reason);
+            }
+        }
+        HookEventName::SessionStart => {
+            if deny {
+                output["continue"] = json!(false);
+                output["stopReason"] = json!(reason);
+            }
+            if let Some(additional_context) = additional_context {
+                output["hookSpecificOutput"] = json!({
+                    "hookEventName": event_label,
+                    "additionalContext": additional_context,
+                });
+            }
+        }
+        HookEventName::PreCompact
+        | HookEventName::PostCompact
+        | HookEventName::SessionEnd
+        | HookEventName::SubagentStart
+        | HookEventName::SubagentStop
+        | HookEventName::Notification => {
+            if deny {
+                output["continue"] = json!(false);
+                output["stopReason"] = json!(reason);
+            }
+        }
+    }
+    Ok(output.to_string())
+}
+
+fn event_name_label(event_name: HookEventName) -> &'static str {
+    match event_name {
+        HookEventName::PreToolUse => "PreToolUse",
+        HookEventName::PermissionRequest => "PermissionRequest",
+        HookEventName::PostToolUse => "PostToolUse",
+        HookEventName::PreCompact => "PreCompact",
+        HookEventName::PostCompact => "PostCompact",
+        HookEventName::SessionStart => "SessionStart",
+        HookEventName::UserPromptSubmit => "UserPromptSubmit",
+        HookEventName::Stop => "Stop",
+        HookEventName::SessionEnd => "SessionEnd",
+        HookEventName::SubagentStart => "SubagentStart",
+        HookEventName::SubagentStop => "SubagentStop",
+        HookEventName::Notification => "Notification",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookSource;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::PROMPT_HANDLER_LABEL;
+    use super::command_output_for_response;
+    use super::render_prompt;
+    use super::run_prompt;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::engine::output_parser;
+    use crate::types::HookPromptEvaluator;
+    use crate::types::HookPromptRequest;
+
+    fn handler(event_name: HookEventName) -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name,
+            matcher: None,
+            kind: HandlerKind::Prompt {
+                prompt: "Only allow read-only commands. $ARGUMENTS".to_string(),
+                model: Some("gpt-5.1-codex-mini".to_string()),
+            },
+            timeout_sec: 5,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn render_prompt_substitutes_or_appends_payload() {
+        assert_eq!(
+            render_prompt("Check this: $ARGUMENTS", r#"{"a":1}"#),
+            r#"Check this: {"a":1}"#
+        );
+        assert_eq!(
+            render_prompt("Check this.", r#"{"a":1}"#),
+            "Check this.\n\n{\"a\":1}"
+        );
+    }
+
+    #[test]
+    fn pre_tool_use_deny_parses_as_block() {
+        let stdout = command_output_for_response(
+            HookEventName::PreToolUse,
+            PROMPT_HANDLER_LABEL,
+            r#"{"decision":"deny","reason":"rm is not read-only","additionalContext":null}"#,
+        )
+        .expect("valid decision");
+
+        let parsed = output_parser::parse_pre_tool_use(&stdout).expect("parsed output");
+        assert_eq!(parsed.invalid_reason, None);
+        assert_eq!(parsed.block_reason.as_deref(), Some("rm is not read-only"));
+    }
+
+    #[test]
+    fn user_prompt_submit_allow_keeps_additional_context() {
+        let stdout = command_output_for_response(
+            HookEventName::UserPromptSubmit,
+            PROMPT_HANDLER_LABEL,
+            r#"{"decision":"allow","reason":null,"additionalContext":"Prefer small diffs."}"#,
+        )
+        .expect("valid decision");
+
+        let parsed = output_parser::parse_user_prompt_submit(&stdout).expect("parsed output");
+        assert!(!parsed.should_block);
+        assert_eq!(
+            parsed.additional_context.as_deref(),
+            Some("Prefer small diffs.")
+        );
+    }
+
+    #[test]
+    fn stop_deny_without_reason_uses_default_reason() {
+        let stdout = command_output_for_response(
+            HookEventName::Stop,
+            PROMPT_HANDLER_LABEL,
+            r#"{"decision":"deny","reason":" ","additionalContext":null}"#,
+        )
+        .expect("valid decision");
+
+        let parsed = output_parser::parse_stop(&stdout).expect("parsed output");
+        assert!(parsed.should_block);
+        assert_eq!(parsed.reason.as_deref(), Some("denied by prompt hook"));
+    }
+
+    #[test]
+    fn permission_request_allow_does_not_grant_approval() {
+        let stdout = command_output_for_response(
+            HookEventName::PermissionRequest,
+            PROMPT_HANDLER_LABEL,
+            r#"{"decision":"allow","reason":null,"additionalContext":null}"#,
+        )
+        .expect("valid decision");
+
+        let parsed = output_parser::parse_permission_request(&stdout).expect("parsed output");
+        assert_eq!(parsed.decision, None);
+    }
+
+    #[test]
+    fn invalid_decision_json_is_an_error() {
+        let error = command_output_for_response(
+            HookEventName::Stop,
+            PROMPT_HANDLER_LABEL,
+            "looks fine to me",
+        )
+        .expect_err("invalid decision");
+        assert!(
+            error.starts_with("prompt hook returned invalid decision JSON"),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn run_prompt_sends_rendered_request_to_evaluator() {
+        let seen = Arc::new(Mutex::new(None::<HookPromptRequest>));
+        let seen_for_evaluator = Arc::clone(&seen);
+        let evaluator: HookPromptEvaluator = Arc::new(move |request| {
+            *seen_for_evaluator.lock().expect("lock") = Some(request);
+            Box::pin(async {
+                Ok(r#"{"decision":"deny","reason":"no","additionalContext":null}"#.to_string())
+            })
+        });
+
+        let result = run_prompt(
+            Some(&evaluator),
+            &handler(HookEventName::Stop),
+            "Were tests run? $ARGUMENTS",
+            Some("gpt-5.1-codex-mini"),
+            r#"{"hook_event_name":"Stop"}"#,
+        )
+        .await;
+
+        assert_eq!(result.error, None);
+        assert_eq!(result.exit_code, Some(0));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&result.stdout).expect("json stdout"),
+            json!({ "decision": "block", "reason": "no" })
+        );
+        let request = seen.lock().expect("lock").take().expect("request sent");
+        assert_eq!(request.model.as_deref(), Some("gpt-5.1-codex-mini"));
+        assert_eq!(
+            request.input,
+            r#"Were tests run? {"hook_event_name":"Stop"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn run_prompt_without_evaluator_fails() {
+        let result = run_prompt(
+            /*evaluator*/ None,
+            &handler(HookEventName::PreToolUse),
+            "Check it",
+            /*model*/ None,
+            "{}",
+        )
+        .await;
+
+        assert_eq!(result.exit_code, None);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("prompt hooks are not available in this session")
+        );
+    }
+}
@@ -20,6 +20,14 @@ pub(crate) struct GeneratedHookSchemas {
     pub user_prompt_submit_command_output: Value,
     pub stop_command_input: Value,
     pub stop_command_output: Value,
+    pub session_end_command_input: Value,
+    pub session_end_command_output: Value,
+    pub subagent_start_command_input: Value,
+    pub subagent_start_command_output: Value,
+    pub subagent_stop_command_input: Value,
+    pub subagent_stop_command_output: Value,
+    pub notification_command_input: Value,
+    pub notification_command_output: Value,
 }
 
 pub(crate) fn generated_hook_schemas() -> &'static GeneratedHookSchemas {
@@ -89,6 +97,38 @@ pub(crate) fn generated_hook_schemas() -> &'static GeneratedHookSchemas {
             "stop.command.output",
             include_str!("../../schema/generated/stop.command.output.schema.json"),
         ),
+        session_end_command_input: parse_json_schema(
+            "session-end.command.input",
+            include_str!("../../schema/generated/session-end.command.input.schema.json"),
+        ),
+        session_end_command_output: parse_json_schema(
+            "session-end.command.output",
+            include_str!("../../schema/generated/session-end.command.output.schema.json"),
+        ),
+        subagent_start_command_input: parse_json_schema(
+            "subagent-start.command.input",
+            include_str!("../../schema/generated/subagent-start.command.input.schema.json"),
+        ),
+        subagent_start_command_output: parse_json_schema(
+            "subagent-start.command.output",
+            include_str!("../../schema/generated/subagent-start.command.output.schema.json"),
+        ),
+        subagent_stop_command_input: parse_json_schema(
+            "subagent-stop.command.input",
+            include_str!("../../schema/generated/subagent-stop.command.input.schema.json"),
+        ),
+        subagent_stop_command_output: parse_json_schema(
+            "subagent-stop.command.output",
+            include_str!("../../schema/generated/subagent-stop.command.output.schema.json"),
+        ),
+        notification_command_input: parse_json_schema(
+            "notification.command.input",
+            include_str!("../../schema/generated/notification.command.input.schema.json"),
+        ),
+        notification_command_output: parse_json_schema(
+            "notification.command.output",
+            include_str!("../../schema/generated/notification.command.output.schema.json"),
+        ),
     })
 }
 
@@ -122,5 +162,13 @@ mod tests {
         assert_eq!(schemas.user_prompt_submit_command_output["type"], "object");
         assert_eq!(schemas.stop_command_input["type"], "object");
         assert_eq!(schemas.stop_command_output["type"], "object");
+        assert_eq!(schemas.session_end_command_input["type"], "object");
+        assert_eq!(schemas.session_end_command_output["type"], "object");
+        assert_eq!(schemas.subagent_start_command_input["type"], "object");
+        assert_eq!(schemas.subagent_start_command_output["type"], "object");
+        assert_eq!(schemas.subagent_stop_command_input["type"], "object");
+        assert_eq!(schemas.subagent_stop_command_output["type"], "object");
+        assert_eq!(schemas.notification_command_input["type"], "object");
+        assert_eq!(schemas.notification_command_output["type"], "object");
     }
 }
@@ -6,7 +6,9 @@ use codex_protocol::protocol::HookRunStatus;
 use codex_protocol::protocol::HookRunSummary;
 
 use crate::engine::ConfiguredHandler;
+use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
+use crate::engine::output_parser;
 
 pub(crate) fn join_text_chunks(chunks: Vec<String>) -> Option<String> {
     if chunks.is_empty() {
@@ -105,8 +107,11 @@ pub(crate) fn matcher_pattern_for_event(
         | HookEventName::PostToolUse
         | HookEventName::SessionStart
         | HookEventName::PreCompact
-        | HookEventName::PostCompact => matcher,
-        HookEventName::UserPromptSubmit | HookEventName::Stop => None,
+        | HookEventName::PostCompact
+        | HookEventName::SubagentStart
+        | HookEventName::SubagentStop
+        | HookEventName::Notification => matcher,
+        HookEventName::UserPromptSubmit | HookEventName::Stop | HookEventName::SessionEnd => None,
     }
 }
 
@@ -155,6 +160,102 @@ fn is_exact_matcher(matcher: &str) -> bool {
         .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '|')
 }
 
+/// Per-handler result for events whose only control is the universal
+/// `continue`/`stopReason` output.
+#[derive(Default)]
+pub(crate) struct StatelessHandlerData {
+    pub should_stop: bool,
+    pub stop_reason: Option<String>,
+}
+
+pub(crate) fn parse_stateless_completed(
+    handler: &ConfiguredHandler,
+    run_result: CommandRunResult,
+    turn_id: Option<String>,
+    event_label: &'static str,
+    parse_output: fn(&str) -> Option<output_parser::StatelessHookOutput>,
+) -> dispatcher::ParsedHandler<StatelessHandlerData> {
+    let mut entries = Vec::new();
+    let mut status = HookRunStatus::Completed;
+    let mut should_stop = false;
+    let mut stop_reason = None;
+
+    match run_result.error.as_deref() {
+        Some(error) => {
+            status = HookRunStatus::Failed;
+            entries.push(HookOutputEntry {
+                kind: HookOutputEntryKind::Error,
+                text: error.to_string(),
+            });
+        }
+        None => match run_result.exit_code {
+            Some(0) => {
+                let trimmed_stdout = run_result.stdout.trim();
+                if trimmed_stdout.is_empty() {
+                } else if let Some(parsed) = parse_output(&run_result.stdout) {
+                    if let Some(system_message) = parsed.universal.system_message {
+                        entries.push(HookOutputEntry {
+                            kind: HookOutputEntryKind::Warning,
+                            text: system_message,
+                        });
+                    }
+                    let _ = parsed.universal.suppress_output;
+                    if !parsed.universal.continue_processing {
+                        status = HookRunStatus::Stopped;
+                        should_stop = true;
+                        stop_reason = parsed.universal.stop_reason.clone();
+                        entries.push(HookOutputEntry {
+                            kind: HookOutputEntryKind::Stop,
+                            text: parsed
+                                .universal
+                                .stop_reason
+                                .unwrap_or_else(|| format!("{event_label} hook stopped execution")),
+                        });
+                    } else if let Some(invalid_reason) = parsed.invalid_reason {
+                        status = HookRunStatus::Failed;
+                        entries.push(HookOutputEntry {
+                            kind: HookOutputEntryKind::Error,
+                            text: invalid_reason,
+                        });
+                    }
+                } else if output_parser::looks_like_json(&run_result.stdout) {
+                    status = HookRunStatus::Failed;
+                    entries.push(HookOutputEntry {
+                        kind: HookOutputEntryKind::Error,
+                        text: format!("hook returned invalid {event_label} hook JSON output"),
+                    });
+                }
+            }
+            Some(code) => {
+                status = HookRunStatus::Failed;
+                entries.push(HookOutputEntry {
+                    kind: HookOutputEntryKind::Error,
+                    text: trimmed_non_empty(&run_result.stderr)
+                        .unwrap_or_else(|| format!("hook exited with code {code}")),
+                });
+            }
+            None => {
+                status = HookRunStatus::Failed;
+                entries.push(HookOutputEntry {
+                    kind: HookOutputEntryKind::Error,
+                    text: "hook process terminated without an exit code".to_string(),
+                });
+            }
+        },
+    }
+
+    dispatcher::ParsedHandler {
+        completed: HookCompletedEvent {
+            turn_id,
+            run: dispatcher::completed_summary(handler, &run_result, status, entries),
+        },
+        data: StatelessHandlerData {
+            should_stop,
+            stop_reason,
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use codex_protocol::protocol::HookEventName;
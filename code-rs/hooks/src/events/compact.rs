@@ -10,8 +10,8 @@ use codex_protocol::protocol::HookRunSummary;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 use super::common;
-use crate::engine::CommandShell;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
 use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
 use crate::engine::output_parser;
@@ -38,13 +38,29 @@ pub struct PostCompactRequest {
     pub trigger: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct StatelessHookOutcome {
     pub hook_events: Vec<HookCompletedEvent>,
     pub should_stop: bool,
     pub stop_reason: Option<String>,
 }
 
+impl StatelessHookOutcome {
+    pub(crate) fn from_results(
+        results: Vec<dispatcher::ParsedHandler<common::StatelessHandlerData>>,
+    ) -> Self {
+        let should_stop = results.iter().any(|result| result.data.should_stop);
+        let stop_reason = results
+            .iter()
+            .find_map(|result| result.data.stop_reason.clone());
+        Self {
+            hook_events: results.into_iter().map(|result| result.completed).collect(),
+            should_stop,
+            stop_reason,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct PreCompactOutcome {
     pub hook_events: Vec<HookCompletedEvent>,
@@ -68,7 +84,7 @@ pub(crate) fn preview_pre(
 
 pub(crate) async fn run_pre(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    executor: &HookExecutor,
     request: PreCompactRequest,
 ) -> PreCompactOutcome {
     let matched = dispatcher::select_handlers(
@@ -100,7 +116,7 @@ pub(crate) async fn run_pre(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        executor,
         matched,
         input_json,
         request.cwd.as_path(),
@@ -147,7 +163,7 @@ pub(crate) fn preview_post(
 
 pub(crate) async fn run_post(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    executor: &HookExecutor,
     request: PostCompactRequest,
 ) -> StatelessHookOutcome {
     let matched = dispatcher::select_handlers(
@@ -156,11 +172,7 @@ pub(crate) async fn run_post(
         Some(request.trigger.as_str()),
     );
     if matched.is_empty() {
-        return StatelessHookOutcome {
-            hook_events: Vec::new(),
-            should_stop: false,
-            stop_reason: None,
-        };
+        return StatelessHookOutcome::default();
     }
 
     let input_json = match post_command_input_json(&request) {
@@ -179,7 +191,7 @@ pub(crate) async fn run_post(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        executor,
         matched,
         input_json,
         request.cwd.as_path(),
@@ -187,15 +199,7 @@ pub(crate) async fn run_post(
         parse_post_completed,
     )
     .await;
-    let should_stop = results.iter().any(|result| result.data.should_stop);
-    let stop_reason = results
-        .iter()
-        .find_map(|result| result.data.stop_reason.clone());
-    StatelessHookOutcome {
-        hook_events: results.into_iter().map(|result| result.completed).collect(),
-        should_stop,
-        stop_reason,
-    }
+    StatelessHookOutcome::from_results(results)
 }
 
 fn post_command_input_json(request: &PostCompactRequest) -> Result<String, serde_json::Error> {
@@ -210,17 +214,11 @@ fn post_command_input_json(request: &PostCompactRequest) -> Result<String, serde
     })
 }
 
-#[derive(Default)]
-struct CompactHandlerData {
-    should_stop: bool,
-    stop_reason: Option<String>,
-}
-
 fn parse_pre_completed(
     handler: &ConfiguredHandler,
     run_result: CommandRunResult,
     turn_id: Option<String>,
-) -> dispatcher::ParsedHandler<CompactHandlerData> {
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
     let mut entries = Vec::new();
     let mut status = HookRunStatus::Completed;
     let mut should_stop = false;
@@ -295,7 +293,7 @@ fn parse_pre_completed(
             turn_id,
             run: dispatcher::completed_summary(handler, &run_result, status, entries),
         },
-        data: CompactHandlerData {
+        data: common::StatelessHandlerData {
             should_stop,
             stop_reason,
         },
@@ -306,8 +304,8 @@ fn parse_post_completed(
     handler: &ConfiguredHandler,
     run_result: CommandRunResult,
     turn_id: Option<String>,
-) -> dispatcher::ParsedHandler<CompactHandlerData> {
-    parse_completed(
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
+    common::parse_stateless_completed(
         handler,
         run_result,
         turn_id,
@@ -316,94 +314,6 @@ fn parse_post_completed(
     )
 }
 
-fn parse_completed(
-    handler: &ConfiguredHandler,
-    run_result: CommandRunResult,
-    turn_id: Option<String>,
-    event_label: &'static str,
-    parse_output: fn(&str) -> Option<output_parser::StatelessHookOutput>,
-) -> dispatcher::ParsedHandler<CompactHandlerData> {
-    let mut entries = Vec::new();
-    let mut status = HookRunStatus::Completed;
-    let mut should_stop = false;
-    let mut stop_reason = None;
-
-    match run_result.error.as_deref() {
-        Some(error) => {
-            status = HookRunStatus::Failed;
-            entries.push(HookOutputEntry {
-                kind: HookOutputEntryKind::Error,
-                text: error.to_string(),
-            });
-        }
-        None => match run_result.exit_code {
-            Some(0) => {
-                let trimmed_stdout = run_result.stdout.trim();
-                if trimmed_stdout.is_empty() {
-                } else if let Some(parsed) = parse_output(&run_result.stdout) {
-                    if let Some(system_message) = parsed.universal.system_message {
-                        entries.push(HookOutputEntry {
-                            kind: HookOutputEntryKind::Warning,
-                            text: system_message,
-                        });
-                    }
-                    let _ = parsed.universal.suppress_output;
-                    if !parsed.universal.continue_processing {
-                        status = HookRunStatus::Stopped;
-                        should_stop = true;
-                        stop_reason = parsed.universal.stop_reason.clone();
-                        entries.push(HookOutputEntry {
-                            kind: HookOutputEntryKind::Stop,
-                            text: parsed
-                                .universal
-                                .stop_reason
-                                .unwrap_or_else(|| format!("{event_label} hook stopped execution")),
-                        });
-                    } else if let Some(invalid_reason) = parsed.invalid_reason {
-                        status = HookRunStatus::Failed;
-                        entries.push(HookOutputEntry {
-                            kind: HookOutputEntryKind::Error,
-                            text: invalid_reason,
-                        });
-                    }
-                } else if output_parser::looks_like_json(&run_result.stdout) {
-                    status = HookRunStatus::Failed;
-                    entries.push(HookOutputEntry {
-                        kind: HookOutputEntryKind::Error,
-                        text: format!("hook returned invalid {event_label} hook JSON output"),
-                    });
-                }
-            }
-            Some(code) => {
-                status = HookRunStatus::Failed;
-                entries.push(HookOutputEntry {
-                    kind: HookOutputEntryKind::Error,
-                    text: common::trimmed_non_empty(&run_result.stderr)
-                        .unwrap_or_else(|| format!("hook exited with code {code}")),
-                });
-            }
-            None => {
-                status = HookRunStatus::Failed;
-                entries.push(HookOutputEntry {
-                    kind: HookOutputEntryKind::Error,
-                    text: "hook process terminated without an exit code".to_string(),
-                });
-            }
-        },
-    }
-
-    dispatcher::ParsedHandler {
-        completed: HookCompletedEvent {
-            turn_id,
-            run: dispatcher::completed_summary(handler, &run_result, status, entries),
-        },
-        data: CompactHandlerData {
-            should_stop,
-            stop_reason,
-        },
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use codex_protocol::ThreadId;
@@ -421,6 +331,7 @@ mod tests {
     use super::post_command_input_json;
     use super::pre_command_input_json;
     use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
     use crate::engine::command_runner::CommandRunResult;
 
     #[test]
@@ -584,7 +495,9 @@ mod tests {
         ConfiguredHandler {
             event_name,
             matcher: None,
-            command: "python3 compact_hook.py".to_string(),
+            kind: HandlerKind::Command {
+                command: "python3 compact_hook.py".to_string(),
+            },
             timeout_sec: 5,
             status_message: Some("running compact hook".to_string()),
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -1,8 +1,11 @@
 pub(crate) mod common;
 pub mod compact;
+pub mod notification;
 pub mod permission_request;
 pub mod post_tool_use;
 pub mod pre_tool_use;
+pub mod session_end;
 pub mod session_start;
 pub mod stop;
+pub mod subagent;
 pub mod user_prompt_submit;
@@ -0,0 +1,217 @@
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookRunSummary;
+use codex_utils_absolute_path::AbsolutePathBuf;
+
+use super::common;
+use super::compact::StatelessHookOutcome;
+use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
+use crate::engine::command_runner::CommandRunResult;
+use crate::engine::dispatcher;
+use crate::engine::output_parser;
+use crate::schema::NotificationCommandInput;
+use crate::schema::NullableString;
+
+#[derive(Debug, Clone, Copy)]
+pub enum NotificationType {
+    /// Codex is waiting for the user to approve a command, patch, or
+    /// permission request.
+    PermissionPrompt,
+    /// Codex finished its turn and is waiting for the next prompt.
+    IdlePrompt,
+}
+
+impl NotificationType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PermissionPrompt => "permission_prompt",
+            Self::IdlePrompt => "idle_prompt",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationRequest {
+    pub session_id: ThreadId,
+    pub turn_id: String,
+    pub cwd: AbsolutePathBuf,
+    pub transcript_path: Option<PathBuf>,
+    pub notification_type: NotificationType,
+    pub message: String,
+}
+
+pub(crate) fn preview(
+    handlers: &[ConfiguredHandler],
+    request: &NotificationRequest,
+) -> Vec<HookRunSummary> {
+    dispatcher::select_handlers(
+        handlers,
+        HookEventName::Notification,
+        Some(request.notification_type.as_str()),
+    )
+    .into_iter()
+    .map(|handler| dispatcher::running_summary(&handler))
+    .collect()
+}
+
+pub(crate) async fn run(
+    handlers: &[ConfiguredHandler],
+    executor: &HookExecutor,
+    request: NotificationRequest,
+) -> StatelessHookOutcome {
+    let matched = dispatcher::select_handlers(
+        handlers,
+        HookEventName::Notification,
+        Some(request.notification_type.as_str()),
+    );
+    if matched.is_empty() {
+        return StatelessHookOutcome::default();
+    }
+
+    let input_json = match command_input_json(&request) {
+        Ok(input_json) => input_json,
+        Err(error) => {
+            return StatelessHookOutcome {
+                hook_events: common::serialization_failure_hook_events(
+                    matched,
+                    Some(request.turn_id),
+                    format!("failed to serialize notification hook input: {error}"),
+                ),
+                should_stop: false,
+                stop_reason: None,
+            };
+        }
+    };
+
+    let results = dispatcher::execute_handlers(
+        executor,
+        matched,
+        input_json,
+        request.cwd.as_path(),
+        Some(request.turn_id),
+        parse_completed,
+    )
+    .await;
+    StatelessHookOutcome::from_results(results)
+}
+
+fn command_input_json(request: &NotificationRequest) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&NotificationCommandInput {
+        session_id: request.session_id.to_string(),
+        turn_id: request.turn_id.clone(),
+        transcript_path: NullableString::from_path(request.transcript_path.clone()),
+        cwd: request.cwd.display().to_string(),
+        hook_event_name: "Notification".to_string(),
+        notification_type: request.notification_type.as_str().to_string(),
+        message: request.message.clone(),
+    })
+}
+
+fn parse_completed(
+    handler: &ConfiguredHandler,
+    run_result: CommandRunResult,
+    turn_id: Option<String>,
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
+    common::parse_stateless_completed(
+        handler,
+        run_result,
+        turn_id,
+        "Notification",
+        output_parser::parse_notification,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookRunStatus;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::NotificationRequest;
+    use super::NotificationType;
+    use super::command_input_json;
+    use super::parse_completed;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::engine::command_runner::CommandRunResult;
+
+    #[test]
+    fn notification_input_includes_type_and_message() {
+        let input_json = command_input_json(&request()).expect("serialize command input");
+        let input: serde_json::Value =
+            serde_json::from_str(&input_json).expect("parse command input");
+
+        assert_eq!(
+            input,
+            json!({
+                "session_id": request().session_id.to_string(),
+                "turn_id": "turn-1",
+                "transcript_path": null,
+                "cwd": test_path_buf("/tmp").display().to_string(),
+                "hook_event_name": "Notification",
+                "notification_type": "permission_prompt",
+                "message": "Codex needs your approval to run `cargo test`",
+            })
+        );
+    }
+
+    #[test]
+    fn notification_ignores_plain_stdout() {
+        let parsed = parse_completed(
+            &handler(),
+            run_result(Some(0), "posted to #builds\n"),
+            Some("turn-1".to_string()),
+        );
+
+        assert_eq!(parsed.completed.run.status, HookRunStatus::Completed);
+        assert_eq!(parsed.completed.run.entries, Vec::new());
+        assert_eq!(parsed.completed.turn_id.as_deref(), Some("turn-1"));
+    }
+
+    fn request() -> NotificationRequest {
+        NotificationRequest {
+            session_id: ThreadId::from_string("00000000-0000-4000-8000-000000000001")
+                .expect("valid thread id"),
+            turn_id: "turn-1".to_string(),
+            cwd: test_path_buf("/tmp").abs(),
+            transcript_path: None,
+            notification_type: NotificationType::PermissionPrompt,
+            message: "Codex needs your approval to run `cargo test`".to_string(),
+        }
+    }
+
+    fn handler() -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name: HookEventName::Notification,
+            matcher: Some("permission_prompt".to_string()),
+            kind: HandlerKind::Command {
+                command: "python3 notify_hook.py".to_string(),
+            },
+            timeout_sec: 5,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: codex_protocol::protocol::HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    fn run_result(exit_code: Option<i32>, stdout: &str) -> CommandRunResult {
+        CommandRunResult {
+            started_at: 1_700_000_000,
+            completed_at: 1_700_000_001,
+            duration_ms: 12,
+            exit_code,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            error: None,
+        }
+    }
+}
@@ -11,8 +11,8 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 use serde_json::Value;
 
 use super::common;
-use crate::engine::CommandShell;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
 use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
 use crate::engine::output_parser;
@@ -69,7 +69,7 @@ pub(crate) fn preview(
 
 pub(crate) async fn run(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    executor: &HookExecutor,
     request: PostToolUseRequest,
 ) -> PostToolUseOutcome {
     let matcher_inputs = common::matcher_inputs(&request.tool_name, &request.matcher_aliases);
@@ -102,7 +102,7 @@ pub(crate) async fn run(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        executor,
         matched,
         input_json,
         request.cwd.as_path(),
@@ -328,6 +328,7 @@ mod tests {
     use super::parse_completed;
     use super::preview;
     use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
     use crate::engine::command_runner::CommandRunResult;
     use crate::events::common;
 
@@ -544,7 +545,9 @@ mod tests {
         ConfiguredHandler {
             event_name: HookEventName::PostToolUse,
             matcher: Some("^Bash$".to_string()),
-            command: "python3 post_tool_use_hook.py".to_string(),
+            kind: HandlerKind::Command {
+                command: "python3 post_tool_use_hook.py".to_string(),
+            },
             timeout_sec: 5,
             status_message: Some("running post tool use hook".to_string()),
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookRunSummary;
+use codex_utils_absolute_path::AbsolutePathBuf;
+
+use super::common;
+use super::compact::StatelessHookOutcome;
+use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
+use crate::engine::command_runner::CommandRunResult;
+use crate::engine::dispatcher;
+use crate::engine::output_parser;
+use crate::schema::NullableString;
+use crate::schema::SessionEndCommandInput;
+
+#[derive(Debug, Clone, Copy)]
+pub enum SessionEndReason {
+    Shutdown,
+}
+
+impl SessionEndReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Shutdown => "shutdown",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionEndRequest {
+    pub session_id: ThreadId,
+    pub cwd: AbsolutePathBuf,
+    pub transcript_path: Option<PathBuf>,
+    pub reason: SessionEndReason,
+}
+
+pub(crate) fn preview(
+    handlers: &[ConfiguredHandler],
+    _request: &SessionEndRequest,
+) -> Vec<HookRunSummary> {
+    dispatcher::select_handlers(
+        handlers,
+        HookEventName::SessionEnd,
+        /*matcher_input*/ None,
+    )
+    .into_iter()
+    .map(|handler| dispatcher::running_summary(&handler))
+    .collect()
+}
+
+pub(crate) async fn run(
+    handlers: &[ConfiguredHandler],
+    executor: &HookExecutor,
+    request: SessionEndRequest,
+) -> StatelessHookOutcome {
+    let matched = dispatcher::select_handlers(
+        handlers,
+        HookEventName::SessionEnd,
+        /*matcher_input*/ None,
+    );
+    if matched.is_empty() {
+        return StatelessHookOutcome::default();
+    }
+
+    let input_json = match command_input_json(&request) {
+        Ok(input_json) => input_json,
+        Err(error) => {
+            return StatelessHookOutcome {
+                hook_events: common::serialization_failure_hook_events(
+                    matched,
+                    /*turn_id*/ None,
+                    format!("failed to serialize session end hook input: {error}"),
+                ),
+                should_stop: false,
+                stop_reason: None,
+            };
+        }
+    };
+
+    let results = dispatcher::execute_handlers(
+        executor,
+        matched,
+        input_json,
+        request.cwd.as_path(),
+        /*turn_id*/ None,
+        parse_completed,
+    )
+    .await;
+    StatelessHookOutcome::from_results(results)
+}
+
+fn command_input_json(request: &SessionEndRequest) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&SessionEndCommandInput {
+        session_id: request.session_id.to_string(),
+        transcript_path: NullableString::from_path(request.transcript_path.clone()),
+        cwd: request.cwd.display().to_string(),
+        hook_event_name: "SessionEnd".to_string(),
+        reason: request.reason.as_str().to_string(),
+    })
+}
+
+fn parse_completed(
+    handler: &ConfiguredHandler,
+    run_result: CommandRunResult,
+    turn_id: Option<String>,
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
+    common::parse_stateless_completed(
+        handler,
+        run_result,
+        turn_id,
+        "SessionEnd",
+        output_parser::parse_session_end,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookOutputEntry;
+    use codex_protocol::protocol::HookOutputEntryKind;
+    use codex_protocol::protocol::HookRunStatus;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::SessionEndReason;
+    use super::SessionEndRequest;
+    use super::command_input_json;
+    use super::parse_completed;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::engine::command_runner::CommandRunResult;
+
+    #[test]
+    fn session_end_input_includes_reason() {
+        let input_json = command_input_json(&request()).expect("serialize command input");
+        let input: serde_json::Value =
+            serde_json::from_str(&input_json).expect("parse command input");
+
+        assert_eq!(
+            input,
+            json!({
+                "session_id": request().session_id.to_string(),
+                "transcript_path": null,
+                "cwd": test_path_buf("/tmp").display().to_string(),
+                "hook_event_name": "SessionEnd",
+                "reason": "shutdown",
+            })
+        );
+    }
+
+    #[test]
+    fn system_message_is_reported_as_warning() {
+        let parsed = parse_completed(
+            &handler(),
+            run_result(Some(0), r#"{"systemMessage":"uploaded session log"}"#),
+            /*turn_id*/ None,
+        );
+
+        assert_eq!(parsed.completed.run.status, HookRunStatus::Completed);
+        assert_eq!(
+            parsed.completed.run.entries,
+            vec![HookOutputEntry {
+                kind: HookOutputEntryKind::Warning,
+                text: "uploaded session log".to_string(),
+            }]
+        );
+    }
+
+    fn request() -> SessionEndRequest {
+        SessionEndRequest {
+            session_id: ThreadId::from_string("00000000-0000-4000-8000-000000000001")
+                .expect("valid thread id"),
+            cwd: test_path_buf("/tmp").abs(),
+            transcript_path: None,
+            reason: SessionEndReason::Shutdown,
+        }
+    }
+
+    fn handler() -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name: HookEventName::SessionEnd,
+            matcher: None,
+            kind: HandlerKind::Command {
+                command: "python3 session_end_hook.py".to_string(),
+            },
+            timeout_sec: 5,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: codex_protocol::protocol::HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    fn run_result(exit_code: Option<i32>, stdout: &str) -> CommandRunResult {
+        CommandRunResult {
+            started_at: 1_700_000_000,
+            completed_at: 1_700_000_001,
+            duration_ms: 12,
+            exit_code,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            error: None,
+        }
+    }
+}
@@ -10,8 +10,8 @@ use codex_protocol::protocol::HookRunSummary;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 use super::common;
-use crate::engine::CommandShell;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
 use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
 use crate::engine::output_parser;
@@ -75,7 +75,7 @@ pub(crate) fn preview(
 
 pub(crate) async fn run(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    executor: &HookExecutor,
     request: SessionStartRequest,
     turn_id: Option<String>,
 ) -> SessionStartOutcome {
@@ -112,7 +112,7 @@ pub(crate) async fn run(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        executor,
         matched,
         input_json,
         request.cwd.as_path(),
@@ -259,6 +259,7 @@ mod tests {
     use super::SessionStartHandlerData;
     use super::parse_completed;
     use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
     use crate::engine::command_runner::CommandRunResult;
 
     #[test]
@@ -357,7 +358,9 @@ mod tests {
         ConfiguredHandler {
             event_name: HookEventName::SessionStart,
             matcher: None,
-            command: "echo hook".to_string(),
+            kind: HandlerKind::Command {
+                command: "echo hook".to_string(),
+            },
             timeout_sec: 600,
             status_message: None,
             source_path: test_path_buf("/tmp/hooks.json").abs(),
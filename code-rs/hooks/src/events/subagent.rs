@@ -0,0 +1,413 @@
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookRunSummary;
+use codex_utils_absolute_path::AbsolutePathBuf;
+
+use super::common;
+use super::compact::StatelessHookOutcome;
+use crate::engine::ConfiguredHandler;
+use crate::engine::HookExecutor;
+use crate::engine::command_runner::CommandRunResult;
+use crate::engine::dispatcher;
+use crate::engine::output_parser;
+use crate::schema::NullableString;
+use crate::schema::SubagentStartCommandInput;
+use crate::schema::SubagentStopCommandInput;
+
+/// A sub-agent spawned by the session that owns the hooks.
+///
+/// `session_id`, `cwd`, and `transcript_path` on the requests below describe
+/// that parent session; the sub-agent itself is identified by `agent_id`.
+#[derive(Debug, Clone)]
+pub struct SubagentStartRequest {
+    pub session_id: ThreadId,
+    pub cwd: AbsolutePathBuf,
+    pub transcript_path: Option<PathBuf>,
+    pub agent_id: ThreadId,
+    pub agent_type: String,
+    pub agent_nickname: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SubagentStopStatus {
+    Completed {
+        last_assistant_message: Option<String>,
+    },
+    Errored {
+        error: String,
+    },
+    Shutdown,
+    NotFound,
+}
+
+impl SubagentStopStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Completed { .. } => "completed",
+            Self::Errored { .. } => "errored",
+            Self::Shutdown => "shutdown",
+            Self::NotFound => "not_found",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubagentStopRequest {
+    pub session_id: ThreadId,
+    pub cwd: AbsolutePathBuf,
+    pub transcript_path: Option<PathBuf>,
+    pub agent_id: ThreadId,
+    pub agent_type: String,
+    pub agent_nickname: Option<String>,
+    pub status: SubagentStopStatus,
+}
+
+pub(crate) fn preview_start(
+    handlers: &[ConfiguredHandler],
+    request: &SubagentStartRequest,
+) -> Vec<HookRunSummary> {
+    dispatcher::select_handlers(
+        handlers,
+        HookEventName::SubagentStart,
+        Some(request.agent_type.as_str()),
+    )
+    .into_iter()
+    .map(|handler| dispatcher::running_summary(&handler))
+    .collect()
+}
+
+pub(crate) async fn run_start(
+    handlers: &[ConfiguredHandler],
+    executor: &HookExecutor,
+    request: SubagentStartRequest,
+) -> StatelessHookOutcome {
+    let matched = dispatcher::select_handlers(
+        handlers,
+        HookEventName::SubagentStart,
+        Some(request.agent_type.as_str()),
+    );
+    if matched.is_empty() {
+        return StatelessHookOutcome::default();
+    }
+
+    let input_json = match start_command_input_json(&request) {
+        Ok(input_json) => input_json,
+        Err(error) => {
+            return StatelessHookOutcome {
+                hook_events: common::serialization_failure_hook_events(
+                    matched,
+                    /*turn_id*/ None,
+                    format!("failed to serialize subagent start hook input: {error}"),
+                ),
+                should_stop: false,
+                stop_reason: None,
+            };
+        }
+    };
+
+    let results = dispatcher::execute_handlers(
+        executor,
+        matched,
+        input_json,
+        request.cwd.as_path(),
+        /*turn_id*/ None,
+        parse_start_completed,
+    )
+    .await;
+    StatelessHookOutcome::from_results(results)
+}
+
+fn start_command_input_json(request: &SubagentStartRequest) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&SubagentStartCommandInput {
+        session_id: request.session_id.to_string(),
+        transcript_path: NullableString::from_path(request.transcript_path.clone()),
+        cwd: request.cwd.display().to_string(),
+        hook_event_name: "SubagentStart".to_string(),
+        agent_id: request.agent_id.to_string(),
+        agent_type: request.agent_type.clone(),
+        agent_nickname: NullableString::from_string(request.agent_nickname.clone()),
+    })
+}
+
+pub(crate) fn preview_stop(
+    handlers: &[ConfiguredHandler],
+    request: &SubagentStopRequest,
+) -> Vec<HookRunSummary> {
+    dispatcher::select_handlers(
+        handlers,
+        HookEventName::SubagentStop,
+        Some(request.agent_type.as_str()),
+    )
+    .into_iter()
+    .map(|handler| dispatcher::running_summary(&handler))
+    .collect()
+}
+
+pub(crate) async fn run_stop(
+    handlers: &[ConfiguredHandler],
+    executor: &HookExecutor,
+    request: SubagentStopRequest,
+) -> StatelessHookOutcome {
+    let matched = dispatcher::select_handlers(
+        handlers,
+        HookEventName::SubagentStop,
+        Some(request.agent_type.as_str()),
+    );
+    if matched.is_empty() {
+        return StatelessHookOutcome::default();
+    }
+
+    let input_json = match stop_command_input_json(&request) {
+        Ok(input_json) => input_json,
+        Err(error) => {
+            return StatelessHookOutcome {
+                hook_events: common::serialization_failure_hook_events(
+                    matched,
+                    /*turn_id*/ None,
+                    format!("failed to serialize subagent stop hook input: {error}"),
+                ),
+                should_stop: false,
+                stop_reason: None,
+            };
+        }
+    };
+
+    let results = dispatcher::execute_handlers(
+        executor,
+        matched,
+        input_json,
+        request.cwd.as_path(),
+        /*turn_id*/ None,
+        parse_stop_completed,
+    )
+    .await;
+    StatelessHookOutcome::from_results(results)
+}
+
+fn stop_command_input_json(request: &SubagentStopRequest) -> Result<String, serde_json::Error> {
+    let (last_assistant_message, error) = match &request.status {
+        SubagentStopStatus::Completed {
+            last_assistant_message,
+        } => (last_assistant_message.clone(), None),
+        SubagentStopStatus::Errored { error } => (None, Some(error.clone())),
+        SubagentStopStatus::Shutdown | SubagentStopStatus::NotFound => (None, None),
+    };
+    serde_json::to_string(&SubagentStopCommandInput {
+        session_id: request.session_id.to_string(),
+        transcript_path: NullableString::from_path(request.transcript_path.clone()),
+        cwd: request.cwd.display().to_string(),
+        hook_event_name: "SubagentStop".to_string(),
+        agent_id: request.agent_id.to_string(),
+        agent_type: request.agent_type.clone(),
+        agent_nickname: NullableString::from_string(request.agent_nickname.clone()),
+        status: request.status.as_str().to_string(),
+        last_assistant_message: NullableString::from_string(last_assistant_message),
+        error: NullableString::from_string(error),
+    })
+}
+
+fn parse_start_completed(
+    handler: &ConfiguredHandler,
+    run_result: CommandRunResult,
+    turn_id: Option<String>,
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
+    common::parse_stateless_completed(
+        handler,
+        run_result,
+        turn_id,
+        "SubagentStart",
+        output_parser::parse_subagent_start,
+    )
+}
+
+fn parse_stop_completed(
+    handler: &ConfiguredHandler,
+    run_result: CommandRunResult,
+    turn_id: Option<String>,
+) -> dispatcher::ParsedHandler<common::StatelessHandlerData> {
+    common::parse_stateless_completed(
+        handler,
+        run_result,
+        turn_id,
+        "SubagentStop",
+        output_parser::parse_subagent_stop,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookOutputEntry;
+    use codex_protocol::protocol::HookOutputEntryKind;
+    use codex_protocol::protocol::HookRunStatus;
+    use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::SubagentStartRequest;
+    use super::SubagentStopRequest;
+    use super::SubagentStopStatus;
+    use super::parse_stop_completed;
+    use super::start_command_input_json;
+    use super::stop_command_input_json;
+    use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
+    use crate::engine::command_runner::CommandRunResult;
+    use crate::engine::dispatcher;
+
+    #[test]
+    fn subagent_start_input_identifies_parent_and_child() {
+        let input_json =
+            start_command_input_json(&start_request()).expect("serialize command input");
+        let input: serde_json::Value =
+            serde_json::from_str(&input_json).expect("parse command input");
+
+        assert_eq!(
+            input,
+            json!({
+                "session_id": parent_id().to_string(),
+                "transcript_path": null,
+                "cwd": test_path_buf("/tmp").display().to_string(),
+                "hook_event_name": "SubagentStart",
+                "agent_id": child_id().to_string(),
+                "agent_type": "explorer",
+                "agent_nickname": "Ada",
+            })
+        );
+    }
+
+    #[test]
+    fn subagent_stop_input_reports_completion() {
+        let input_json = stop_command_input_json(&stop_request(SubagentStopStatus::Completed {
+            last_assistant_message: Some("found the bug".to_string()),
+        }))
+        .expect("serialize command input");
+        let input: serde_json::Value =
+            serde_json::from_str(&input_json).expect("parse command input");
+
+        assert_eq!(
+            input,
+            json!({
+                "session_id": parent_id().to_string(),
+                "transcript_path": null,
+                "cwd": test_path_buf("/tmp").display().to_string(),
+                "hook_event_name": "SubagentStop",
+                "agent_id": child_id().to_string(),
+                "agent_type": "explorer",
+                "agent_nickname": "Ada",
+                "status": "completed",
+                "last_assistant_message": "found the bug",
+                "error": null,
+            })
+        );
+    }
+
+    #[test]
+    fn subagent_stop_input_reports_error() {
+        let input_json = stop_command_input_json(&stop_request(SubagentStopStatus::Errored {
+            error: "stream disconnected".to_string(),
+        }))
+        .expect("serialize command input");
+        let input: serde_json::Value =
+            serde_json::from_str(&input_json).expect("parse command input");
+
+        assert_eq!(input["status"], "errored");
+        assert_eq!(input["last_assistant_message"], serde_json::Value::Null);
+        assert_eq!(input["error"], "stream disconnected");
+    }
+
+    #[test]
+    fn subagent_handlers_match_on_agent_type() {
+        let handlers = vec![
+            handler(HookEventName::SubagentStop, Some("explorer")),
+            handler(HookEventName::SubagentStop, Some("worker")),
+        ];
+
+        let selected =
+            dispatcher::select_handlers(&handlers, HookEventName::SubagentStop, Some("explorer"));
+
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].matcher.as_deref(), Some("explorer"));
+    }
+
+    #[test]
+    fn subagent_stop_rejects_block_decision() {
+        let parsed = parse_stop_completed(
+            &handler(HookEventName::SubagentStop, /*matcher*/ None),
+            run_result(Some(0), r#"{"decision":"block","reason":"keep going"}"#),
+            /*turn_id*/ None,
+        );
+
+        assert_eq!(parsed.completed.run.status, HookRunStatus::Failed);
+        assert_eq!(
+            parsed.completed.run.entries,
+            vec![HookOutputEntry {
+                kind: HookOutputEntryKind::Error,
+                text: "hook returned invalid SubagentStop hook JSON output".to_string(),
+            }]
+        );
+    }
+
+    fn parent_id() -> ThreadId {
+        ThreadId::from_string("00000000-0000-4000-8000-000000000001").expect("valid thread id")
+    }
+
+    fn child_id() -> ThreadId {
+        ThreadId::from_string("00000000-0000-4000-8000-000000000002").expect("valid thread id")
+    }
+
+    fn start_request() -> SubagentStartRequest {
+        SubagentStartRequest {
+            session_id: parent_id(),
+            cwd: test_path_buf("/tmp").abs(),
+            transcript_path: None,
+            agent_id: child_id(),
+            agent_type: "explorer".to_string(),
+            agent_nickname: Some("Ada".to_string()),
+        }
+    }
+
+    fn stop_request(status: SubagentStopStatus) -> SubagentStopRequest {
+        SubagentStopRequest {
+            session_id: parent_id(),
+            cwd: test_path_buf("/tmp").abs(),
+            transcript_path: None,
+            agent_id: child_id(),
+            agent_type: "explorer".to_string(),
+            agent_nickname: Some("Ada".to_string()),
+            status,
+        }
+    }
+
+    fn handler(event_name: HookEventName, matcher: Option<&str>) -> ConfiguredHandler {
+        ConfiguredHandler {
+            event_name,
+            matcher: matcher.map(str::to_string),
+            kind: HandlerKind::Command {
+                command: "python3 subagent_hook.py".to_string(),
+            },
+            timeout_sec: 5,
+            status_message: None,
+            source_path: test_path_buf("/tmp/hooks.json").abs(),
+            source: codex_protocol::protocol::HookSource::User,
+            display_order: 0,
+            env: std::collections::HashMap::new(),
+        }
+    }
+
+    fn run_result(exit_code: Option<i32>, stdout: &str) -> CommandRunResult {
+        CommandRunResult {
+            started_at: 1_700_000_000,
+            completed_at: 1_700_000_001,
+            duration_ms: 12,
+            exit_code,
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+            error: None,
+        }
+    }
+}
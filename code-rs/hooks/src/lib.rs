@@ -15,7 +15,7 @@ pub use declarations::PluginHookDeclaration;
 pub use declarations::plugin_hook_declarations;
 pub use engine::HookListEntry;
 /// Hook event names as they appear in hooks JSON and config files.
-pub const HOOK_EVENT_NAMES: [&str; 8] = [
+pub const HOOK_EVENT_NAMES: [&str; 12] = [
     "PreToolUse",
     "PermissionRequest",
     "PostToolUse",
@@ -24,26 +24,35 @@ pub const HOOK_EVENT_NAMES: [&str; 8] = [
     "SessionStart",
     "UserPromptSubmit",
     "Stop",
+    "SessionEnd",
+    "SubagentStart",
+    "SubagentStop",
+    "Notification",
 ];
 
 /// Hook event names whose matcher fields are meaningful during dispatch.
 ///
 /// Other events can appear in hooks JSON, but Codex ignores their matcher
 /// fields because those events do not dispatch against a tool, compaction
-/// trigger, or session-start source.
-pub const HOOK_EVENT_NAMES_WITH_MATCHERS: [&str; 6] = [
+/// trigger, session-start source, sub-agent type, or notification type.
+pub const HOOK_EVENT_NAMES_WITH_MATCHERS: [&str; 9] = [
     "PreToolUse",
     "PermissionRequest",
     "PostToolUse",
     "PreCompact",
     "PostCompact",
     "SessionStart",
+    "SubagentStart",
+    "SubagentStop",
+    "Notification",
 ];
 
 pub use events::compact::PostCompactRequest;
 pub use events::compact::PreCompactOutcome;
 pub use events::compact::PreCompactRequest;
 pub use events::compact::StatelessHookOutcome;
+pub use events::notification::NotificationRequest;
+pub use events::notification::NotificationType;
 pub use events::permission_request::PermissionRequestDecision;
 pub use events::permission_request::PermissionRequestOutcome;
 pub use events::permission_request::PermissionRequestRequest;
@@ -51,11 +60,16 @@ pub use events::post_tool_use::PostToolUseOutcome;
 pub use events::post_tool_use::PostToolUseRequest;
 pub use events::pre_tool_use::PreToolUseOutcome;
 pub use events::pre_tool_use::PreToolUseRequest;
+pub use events::session_end::SessionEndReason;
+pub use events::session_end::SessionEndRequest;
 pub use events::session_start::SessionStartOutcome;
 pub use events::session_start::SessionStartRequest;
 pub use events::session_start::SessionStartSource;
 pub use events::stop::StopOutcome;
 pub use events::stop::StopRequest;
+pub use events::subagent::SubagentStartRequest;
+pub use events::subagent::SubagentStopRequest;
+pub use events::subagent::SubagentStopStatus;
 pub use events::user_prompt_submit::UserPromptSubmitOutcome;
 pub use events::user_prompt_submit::UserPromptSubmitRequest;
 pub use legacy_notify::legacy_notify_json;
@@ -67,10 +81,14 @@ pub use registry::command_from_argv;
 pub use registry::list_hooks;
 pub use schema::write_schema_fixtures;
 pub use types::Hook;
+pub use types::HookAgentEvaluator;
+pub use types::HookAgentRequest;
 pub use types::HookEvent;
 pub use types::HookEventAfterAgent;
 pub use types::HookEventAfterToolUse;
 pub use types::HookPayload;
+pub use types::HookPromptEvaluator;
+pub use types::HookPromptRequest;
 pub use types::HookResponse;
 pub use types::HookResult;
 pub use types::HookToolInput;
@@ -88,6 +106,10 @@ pub fn hook_event_key_label(event_name: HookEventName) -> &'static str {
         HookEventName::SessionStart => "session_start",
         HookEventName::UserPromptSubmit => "user_prompt_submit",
         HookEventName::Stop => "stop",
+        HookEventName::SessionEnd => "session_end",
+        HookEventName::SubagentStart => "subagent_start",
+        HookEventName::SubagentStop => "subagent_stop",
+        HookEventName::Notification => "notification",
     }
 }
 
@@ -9,21 +9,27 @@ use crate::events::compact::PostCompactRequest;
 use crate::events::compact::PreCompactOutcome;
 use crate::events::compact::PreCompactRequest;
 use crate::events::compact::StatelessHookOutcome;
+use crate::events::notification::NotificationRequest;
 use crate::events::permission_request::PermissionRequestOutcome;
 use crate::events::permission_request::PermissionRequestRequest;
 use crate::events::post_tool_use::PostToolUseOutcome;
 use crate::events::post_tool_use::PostToolUseRequest;
 use crate::events::pre_tool_use::PreToolUseOutcome;
 use crate::events::pre_tool_use::PreToolUseRequest;
+use crate::events::session_end::SessionEndRequest;
 use crate::events::session_start::SessionStartOutcome;
 use crate::events::session_start::SessionStartRequest;
 use crate::events::stop::StopOutcome;
 use crate::events::stop::StopRequest;
+use crate::events::subagent::SubagentStartRequest;
+use crate::events::subagent::SubagentStopRequest;
 use crate::events::user_prompt_submit::UserPromptSubmitOutcome;
 use crate::events::user_prompt_submit::UserPromptSubmitRequest;
 use crate::types::Hook;
+use crate::types::HookAgentEvaluator;
 use crate::types::HookEvent;
 use crate::types::HookPayload;
+use crate::types::HookPromptEvaluator;
 use crate::types::HookResponse;
 
 #[derive(Default, Clone)]
@@ -35,6 +41,10 @@ pub struct HooksConfig {
     pub plugin_hook_load_warnings: Vec<String>,
     pub shell_program: Option<String>,
     pub shell_args: Vec<String>,
+    /// Runs model requests for `prompt` hooks. Prompt hooks fail when unset.
+    pub prompt_evaluator: Option<HookPromptEvaluator>,
+    /// Runs sub-agents for `agent` hooks. Agent hooks fail when unset.
+    pub agent_evaluator: Option<HookAgentEvaluator>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -73,7 +83,9 @@ impl Hooks {
                 program: config.shell_program.unwrap_or_default(),
                 args: config.shell_args,
             },
-        );
+        )
+        .with_prompt_evaluator(config.prompt_evaluator)
+        .with_agent_evaluator(config.agent_evaluator);
         Self {
             after_agent,
             after_tool_use: Vec::new(),
@@ -204,6 +216,50 @@ impl Hooks {
     pub async fn run_stop(&self, request: StopRequest) -> StopOutcome {
         self.engine.run_stop(request).await
     }
+
+    pub fn preview_session_end(
+        &self,
+        request: &SessionEndRequest,
+    ) -> Vec<codex_protocol::protocol::HookRunSummary> {
+        self.engine.preview_session_end(request)
+    }
+
+    pub async fn run_session_end(&self, request: SessionEndRequest) -> StatelessHookOutcome {
+        self.engine.run_session_end(request).await
+    }
+
+    pub fn preview_subagent_start(
+        &self,
+        request: &SubagentStartRequest,
+    ) -> Vec<codex_protocol::protocol::HookRunSummary> {
+        self.engine.preview_subagent_start(request)
+    }
+
+    pub async fn run_subagent_start(&self, request: SubagentStartRequest) -> StatelessHookOutcome {
+        self.engine.run_subagent_start(request).await
+    }
+
+    pub fn preview_subagent_stop(
+        &self,
+        request: &SubagentStopRequest,
+    ) -> Vec<codex_protocol::protocol::HookRunSummary> {
+        self.engine.preview_subagent_stop(request)
+    }
+
+    pub async fn run_subagent_stop(&self, request: SubagentStopRequest) -> StatelessHookOutcome {
+        self.engine.run_subagent_stop(request).await
+    }
+
+    pub fn preview_notification(
+        &self,
+        request: &NotificationRequest,
+    ) -> Vec<codex_protocol::protocol::HookRunSummary> {
+        self.engine.preview_notification(request)
+    }
+
+    pub async fn run_notification(&self, request: NotificationRequest) -> StatelessHookOutcome {
+        self.engine.run_notification(request).await
+    }
 }
 
 pub fn list_hooks(config: HooksConfig) -> HookListOutcome {
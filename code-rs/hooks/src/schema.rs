@@ -29,6 +29,14 @@ const USER_PROMPT_SUBMIT_INPUT_FIXTURE: &str = "user-prompt-submit.command.input
 const USER_PROMPT_SUBMIT_OUTPUT_FIXTURE: &str = "user-prompt-submit.command.output.schema.json";
 const STOP_INPUT_FIXTURE: &str = "stop.command.input.schema.json";
 const STOP_OUTPUT_FIXTURE: &str = "stop.command.output.schema.json";
+const SESSION_END_INPUT_FIXTURE: &str = "session-end.command.input.schema.json";
+const SESSION_END_OUTPUT_FIXTURE: &str = "session-end.command.output.schema.json";
+const SUBAGENT_START_INPUT_FIXTURE: &str = "subagent-start.command.input.schema.json";
+const SUBAGENT_START_OUTPUT_FIXTURE: &str = "subagent-start.command.output.schema.json";
+const SUBAGENT_STOP_INPUT_FIXTURE: &str = "subagent-stop.command.input.schema.json";
+const SUBAGENT_STOP_OUTPUT_FIXTURE: &str = "subagent-stop.command.output.schema.json";
+const NOTIFICATION_INPUT_FIXTURE: &str = "notification.command.input.schema.json";
+const NOTIFICATION_OUTPUT_FIXTURE: &str = "notification.command.output.schema.json";
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(transparent)]
@@ -89,6 +97,14 @@ pub(crate) enum HookEventNameWire {
     UserPromptSubmit,
     #[serde(rename = "Stop")]
     Stop,
+    #[serde(rename = "SessionEnd")]
+    SessionEnd,
+    #[serde(rename = "SubagentStart")]
+    SubagentStart,
+    #[serde(rename = "SubagentStop")]
+    SubagentStop,
+    #[serde(rename = "Notification")]
+    Notification,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -150,6 +166,42 @@ pub(crate) struct PostCompactCommandOutputWire {
     pub universal: HookUniversalOutputWire,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "session-end.command.output")]
+pub(crate) struct SessionEndCommandOutputWire {
+    #[serde(flatten)]
+    pub universal: HookUniversalOutputWire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "subagent-start.command.output")]
+pub(crate) struct SubagentStartCommandOutputWire {
+    #[serde(flatten)]
+    pub universal: HookUniversalOutputWire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "subagent-stop.command.output")]
+pub(crate) struct SubagentStopCommandOutputWire {
+    #[serde(flatten)]
+    pub universal: HookUniversalOutputWire,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "notification.command.output")]
+pub(crate) struct NotificationCommandOutputWire {
+    #[serde(flatten)]
+    pub universal: HookUniversalOutputWire,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -462,6 +514,67 @@ pub(crate) struct StopCommandInput {
     pub last_assistant_message: NullableString,
 }
 
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "session-end.command.input")]
+pub(crate) struct SessionEndCommandInput {
+    pub session_id: String,
+    pub transcript_path: NullableString,
+    pub cwd: String,
+    #[schemars(schema_with = "session_end_hook_event_name_schema")]
+    pub hook_event_name: String,
+    #[schemars(schema_with = "session_end_reason_schema")]
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "subagent-start.command.input")]
+pub(crate) struct SubagentStartCommandInput {
+    pub session_id: String,
+    pub transcript_path: NullableString,
+    pub cwd: String,
+    #[schemars(schema_with = "subagent_start_hook_event_name_schema")]
+    pub hook_event_name: String,
+    pub agent_id: String,
+    pub agent_type: String,
+    pub agent_nickname: NullableString,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "subagent-stop.command.input")]
+pub(crate) struct SubagentStopCommandInput {
+    pub session_id: String,
+    pub transcript_path: NullableString,
+    pub cwd: String,
+    #[schemars(schema_with = "subagent_stop_hook_event_name_schema")]
+    pub hook_event_name: String,
+    pub agent_id: String,
+    pub agent_type: String,
+    pub agent_nickname: NullableString,
+    #[schemars(schema_with = "subagent_stop_status_schema")]
+    pub status: String,
+    pub last_assistant_message: NullableString,
+    pub error: NullableString,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "notification.command.input")]
+pub(crate) struct NotificationCommandInput {
+    pub session_id: String,
+    /// Codex extension: expose the active turn id to internal turn-scoped hooks.
+    pub turn_id: String,
+    pub transcript_path: NullableString,
+    pub cwd: String,
+    #[schemars(schema_with = "notification_hook_event_name_schema")]
+    pub hook_event_name: String,
+    #[schemars(schema_with = "notification_type_schema")]
+    pub notification_type: String,
+    pub message: String,
+}
+
 pub fn write_schema_fixtures(schema_root: &Path) -> anyhow::Result<()> {
     let generated_dir = schema_root.join(GENERATED_DIR);
     ensure_empty_dir(&generated_dir)?;
@@ -530,6 +643,38 @@ pub fn write_schema_fixtures(schema_root: &Path) -> anyhow::Result<()> {
         &generated_dir.join(STOP_OUTPUT_FIXTURE),
         schema_json::<StopCommandOutputWire>()?,
     )?;
+    write_schema(
+        &generated_dir.join(SESSION_END_INPUT_FIXTURE),
+        schema_json::<SessionEndCommandInput>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(SESSION_END_OUTPUT_FIXTURE),
+        schema_json::<SessionEndCommandOutputWire>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(SUBAGENT_START_INPUT_FIXTURE),
+        schema_json::<SubagentStartCommandInput>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(SUBAGENT_START_OUTPUT_FIXTURE),
+        schema_json::<SubagentStartCommandOutputWire>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(SUBAGENT_STOP_INPUT_FIXTURE),
+        schema_json::<SubagentStopCommandInput>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(SUBAGENT_STOP_OUTPUT_FIXTURE),
+        schema_json::<SubagentStopCommandOutputWire>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(NOTIFICATION_INPUT_FIXTURE),
+        schema_json::<NotificationCommandInput>()?,
+    )?;
+    write_schema(
+        &generated_dir.join(NOTIFICATION_OUTPUT_FIXTURE),
+        schema_json::<NotificationCommandOutputWire>()?,
+    )?;
 
     Ok(())
 }
@@ -617,6 +762,22 @@ fn stop_hook_event_name_schema(_gen: &mut SchemaGenerator) -> Schema {
     string_const_schema("Stop")
 }
 
+fn session_end_hook_event_name_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_const_schema("SessionEnd")
+}
+
+fn subagent_start_hook_event_name_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_const_schema("SubagentStart")
+}
+
+fn subagent_stop_hook_event_name_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_const_schema("SubagentStop")
+}
+
+fn notification_hook_event_name_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_const_schema("Notification")
+}
+
 fn permission_mode_schema(_gen: &mut SchemaGenerator) -> Schema {
     string_enum_schema(&[
         "default",
@@ -635,6 +796,18 @@ fn compaction_trigger_schema(_gen: &mut SchemaGenerator) -> Schema {
     string_enum_schema(&["manual", "auto"])
 }
 
+fn session_end_reason_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_enum_schema(&["shutdown"])
+}
+
+fn subagent_stop_status_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_enum_schema(&["completed", "errored", "shutdown", "not_found"])
+}
+
+fn notification_type_schema(_gen: &mut SchemaGenerator) -> Schema {
+    string_enum_schema(&["permission_prompt", "idle_prompt"])
+}
+
 fn string_const_schema(value: &str) -> Schema {
     let mut schema = SchemaObject {
         instance_type: Some(InstanceType::String.into()),
@@ -664,6 +837,9 @@ fn default_continue() -> bool {
 
 #[cfg(test)]
 mod tests {
+    use super::NOTIFICATION_INPUT_FIXTURE;
+    use super::NOTIFICATION_OUTPUT_FIXTURE;
+    use super::NotificationCommandInput;
     use super::PERMISSION_REQUEST_INPUT_FIXTURE;
     use super::PERMISSION_REQUEST_OUTPUT_FIXTURE;
     use super::POST_COMPACT_INPUT_FIXTURE;
@@ -679,10 +855,16 @@ mod tests {
     use super::PostToolUseCommandInput;
     use super::PreCompactCommandInput;
     use super::PreToolUseCommandInput;
+    use super::SESSION_END_INPUT_FIXTURE;
+    use super::SESSION_END_OUTPUT_FIXTURE;
     use super::SESSION_START_INPUT_FIXTURE;
     use super::SESSION_START_OUTPUT_FIXTURE;
     use super::STOP_INPUT_FIXTURE;
     use super::STOP_OUTPUT_FIXTURE;
+    use super::SUBAGENT_START_INPUT_FIXTURE;
+    use super::SUBAGENT_START_OUTPUT_FIXTURE;
+    use super::SUBAGENT_STOP_INPUT_FIXTURE;
+    use super::SUBAGENT_STOP_OUTPUT_FIXTURE;
     use super::StopCommandInput;
     use super::USER_PROMPT_SUBMIT_INPUT_FIXTURE;
     use super::USER_PROMPT_SUBMIT_OUTPUT_FIXTURE;
@@ -743,6 +925,30 @@ mod tests {
             STOP_OUTPUT_FIXTURE => {
                 include_str!("../schema/generated/stop.command.output.schema.json")
             }
+            SESSION_END_INPUT_FIXTURE => {
+                include_str!("../schema/generated/session-end.command.input.schema.json")
+            }
+            SESSION_END_OUTPUT_FIXTURE => {
+                include_str!("../schema/generated/session-end.command.output.schema.json")
+            }
+            SUBAGENT_START_INPUT_FIXTURE => {
+                include_str!("../schema/generated/subagent-start.command.input.schema.json")
+            }
+            SUBAGENT_START_OUTPUT_FIXTURE => {
+                include_str!("../schema/generated/subagent-start.command.output.schema.json")
+            }
+            SUBAGENT_STOP_INPUT_FIXTURE => {
+                include_str!("../schema/generated/subagent-stop.command.input.schema.json")
+            }
+            SUBAGENT_STOP_OUTPUT_FIXTURE => {
+                include_str!("../schema/generated/subagent-stop.command.output.schema.json")
+            }
+            NOTIFICATION_INPUT_FIXTURE => {
+                include_str!("../schema/generated/notification.command.input.schema.json")
+            }
+            NOTIFICATION_OUTPUT_FIXTURE => {
+                include_str!("../schema/generated/notification.command.output.schema.json")
+            }
             _ => panic!("unexpected fixture name: {name}"),
         }
     }
@@ -774,6 +980,14 @@ mod tests {
             USER_PROMPT_SUBMIT_OUTPUT_FIXTURE,
             STOP_INPUT_FIXTURE,
             STOP_OUTPUT_FIXTURE,
+            SESSION_END_INPUT_FIXTURE,
+            SESSION_END_OUTPUT_FIXTURE,
+            SUBAGENT_START_INPUT_FIXTURE,
+            SUBAGENT_START_OUTPUT_FIXTURE,
+            SUBAGENT_STOP_INPUT_FIXTURE,
+            SUBAGENT_STOP_OUTPUT_FIXTURE,
+            NOTIFICATION_INPUT_FIXTURE,
+            NOTIFICATION_OUTPUT_FIXTURE,
         ] {
             let expected = normalize_newlines(expected_fixture(fixture));
             let actual = std::fs::read_to_string(schema_root.join("generated").join(fixture))
@@ -818,6 +1032,11 @@ mod tests {
             &schema_json::<StopCommandInput>().expect("serialize stop input schema"),
         )
         .expect("parse stop input schema");
+        let notification: Value = serde_json::from_slice(
+            &schema_json::<NotificationCommandInput>()
+                .expect("serialize notification input schema"),
+        )
+        .expect("parse notification input schema");
 
         for schema in [
             &pre_tool_use,
@@ -827,6 +1046,7 @@ mod tests {
             &post_compact,
             &user_prompt_submit,
             &stop,
+            &notification,
         ] {
             assert_eq!(schema["properties"]["turn_id"]["type"], "string");
             assert!(
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use chrono::DateTime;
@@ -12,6 +13,50 @@ use serde::Serializer;
 
 pub type HookFn = Arc<dyn for<'a> Fn(&'a HookPayload) -> BoxFuture<'a, HookResult> + Send + Sync>;
 
+/// Runs one model request on behalf of a `prompt` hook and resolves to the
+/// model's final text response, or to an error message.
+///
+/// The hooks crate does not own a model client, so the host session supplies
+/// this through [`crate::HooksConfig`].
+pub type HookPromptEvaluator =
+    Arc<dyn Fn(HookPromptRequest) -> BoxFuture<'static, Result<String, String>> + Send + Sync>;
+
+/// A single model request issued by a `prompt` hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookPromptRequest {
+    /// Model configured on the hook. `None` means the session's current model.
+    pub model: Option<String>,
+    /// System instructions describing the decision format.
+    pub instructions: String,
+    /// The configured prompt with the hook event payload substituted in.
+    pub input: String,
+    /// JSON schema the final response must conform to.
+    pub output_schema: serde_json::Value,
+}
+
+/// Runs a short-lived, read-only sub-agent on behalf of an `agent` hook and
+/// resolves to the agent's final message, or to an error message.
+///
+/// Like [`HookPromptEvaluator`], this is supplied by the host session through
+/// [`crate::HooksConfig`].
+pub type HookAgentEvaluator =
+    Arc<dyn Fn(HookAgentRequest) -> BoxFuture<'static, Result<String, String>> + Send + Sync>;
+
+/// A single sub-agent run requested by an `agent` hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookAgentRequest {
+    /// Model configured on the hook. `None` means the session's current model.
+    pub model: Option<String>,
+    /// Developer instructions describing the task and the decision format.
+    pub instructions: String,
+    /// The configured prompt with the hook event payload substituted in.
+    pub input: String,
+    /// JSON schema the agent's final message must conform to.
+    pub output_schema: serde_json::Value,
+    /// Working directory of the event that triggered the hook.
+    pub cwd: PathBuf,
+}
+
 #[derive(Debug)]
 pub enum HookResult {
     /// Success: hook completed successfully.
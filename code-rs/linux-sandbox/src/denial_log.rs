@@ -0,0 +1,779 @@
+//! Denial logging for the sandboxed command.
+//!
+//! With `--log-denials`, the helper forks before installing its seccomp
+//! filter. The child installs the filter with `SECCOMP_RET_USER_NOTIF` in
+//! place of `EPERM` and hands the notification listener to the parent, which
+//! answers every notification while the command runs:
+//! - syscalls the sandbox always blocks are failed with `EPERM`, exactly as
+//!   the plain filter would, after recording what was attempted, and
+//! - filesystem writes are let through unchanged after checking whether the
+//!   sandbox is going to reject them.
+//!
+//! Once the command exits, the parent records what was blocked in the sandbox
+//! report file, which the caller reads back into the command's output. It
+//! never makes a policy decision itself: letting a write through only hands
+//! it back to the kernel, where bubblewrap's mounts or Landlock still apply.
+
+use std::collections::BTreeMap;
+use std::ffi::CString;
+use std::fs::File;
+use std::io;
+use std::mem::size_of;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::os::fd::AsRawFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::fd::RawFd;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::ffi::OsStringExt;
+use std::os::unix::fs::FileExt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::thread;
+
+use codex_protocol::protocol::FileSystemSandboxPolicy;
+use seccompiler::BackendError;
+use seccompiler::BpfProgram;
+use seccompiler::SeccompCmpArgLen;
+use seccompiler::SeccompCmpOp;
+use seccompiler::SeccompCondition;
+use seccompiler::SeccompRule;
+
+/// Stop recording after this many distinct denials so a command stuck in a
+/// retry loop cannot grow the sandbox report without bound.
+const MAX_REPORTED_DENIALS: usize = 32;
+const POLL_INTERVAL_MS: libc::c_int = 10;
+/// Longest socket address read from the command, `sizeof(sockaddr_storage)`.
+const MAX_SOCKADDR_LEN: usize = 128;
+const PAGE_SIZE: u64 = 4096;
+
+/// `BPF_RET | BPF_K`: return the constant in `k`.
+const BPF_RET_K: u16 = 0x06;
+const SECCOMP_RET_ERRNO_EPERM: u32 = libc::SECCOMP_RET_ERRNO | libc::EPERM as u32;
+
+// `_IOWR('!', 0, struct seccomp_notif)`, `_IOWR('!', 1, struct
+// seccomp_notif_resp)` and `_IOW('!', 2, __u64)` from `<linux/seccomp.h>`.
+const SECCOMP_IOCTL_NOTIF_RECV: u64 = 0xc050_2100;
+const SECCOMP_IOCTL_NOTIF_SEND: u64 = 0xc018_2101;
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u64 = 0x4008_2102;
+
+/// How the supervisor decides whether the sandbox rejects a write.
+#[derive(Debug, Clone)]
+pub(crate) enum WriteCheck {
+    /// Bubblewrap rejects writes with read-only mounts, which the supervisor
+    /// shares with the command, so the filesystem answers directly.
+    ReadOnlyMounts,
+    /// Landlock rejects writes, which the unrestricted supervisor cannot
+    /// observe, so the policy is consulted instead.
+    Policy {
+        file_system_sandbox_policy: FileSystemSandboxPolicy,
+        cwd: PathBuf,
+    },
+}
+
+impl WriteCheck {
+    /// Returns why a write to `path` is rejected, or `None` when it is
+    /// allowed.
+    fn denial_reason(&self, path: &Path) -> Option<&'static str> {
+        match self {
+            Self::ReadOnlyMounts => {
+                // Creating, removing and renaming entries needs the parent to
+                // be writable; the target only matters once it exists.
+                let target = if path.symlink_metadata().is_ok() {
+                    path
+                } else {
+                    path.parent()?
+                };
+                let target = CString::new(target.as_os_str().as_bytes()).ok()?;
+                let res = unsafe {
+                    libc::faccessat(
+                        libc::AT_FDCWD,
+                        target.as_ptr(),
+                        libc::W_OK,
+                        libc::AT_EACCESS,
+                    )
+                };
+                (res < 0 && io::Error::last_os_error().raw_os_error() == Some(libc::EROFS))
+                    .then_some("read-only file system")
+            }
+            Self::Policy {
+                file_system_sandbox_policy,
+                cwd,
+            } => {
+                // The legacy Landlock ruleset always leaves `/dev/null`
+                // writable.
+                if path == Path::new("/dev/null")
+                    || file_system_sandbox_policy.can_write_path_with_cwd(path, cwd)
+                {
+                    None
+                } else {
+                    Some("outside the writable roots")
+                }
+            }
+        }
+    }
+}
+
+/// Child end of the channel that carries the seccomp listener to the
+/// supervisor.
+#[derive(Debug)]
+pub(crate) struct DenialLogSender {
+    socket: OwnedFd,
+}
+
+/// Supervisor end of the channel that carries the seccomp listener.
+#[derive(Debug)]
+pub(crate) struct DenialLogReceiver {
+    socket: OwnedFd,
+}
+
+pub(crate) fn denial_log_channel() -> io::Result<(DenialLogSender, DenialLogReceiver)> {
+    let mut fds = [0; 2];
+    let res = unsafe {
+        libc::socketpair(
+            libc::AF_UNIX,
+            libc::SOCK_STREAM | libc::SOCK_CLOEXEC,
+            0,
+            fds.as_mut_ptr(),
+        )
+    };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let (sender, receiver) =
+        unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+    Ok((
+        DenialLogSender { socket: sender },
+        DenialLogReceiver { socket: receiver },
+    ))
+}
+
+impl DenialLogSender {
+    /// Hands `listener` to the supervisor.
+    ///
+    /// The listener is closed here so the command never inherits it: a
+    /// process holding it could answer its own notifications.
+    pub(crate) fn send_listener(self, listener: OwnedFd) -> io::Result<()> {
+        let mut payload = [0_u8; 1];
+        let mut iov = libc::iovec {
+            iov_base: payload.as_mut_ptr().cast(),
+            iov_len: payload.len(),
+        };
+        let mut control = vec![0_u8; control_space_for_fd()];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = control.len() as _;
+        unsafe {
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(size_of::<RawFd>() as libc::c_uint) as _;
+            libc::CMSG_DATA(cmsg)
+                .cast::<RawFd>()
+                .write_unaligned(listener.as_raw_fd());
+        }
+        if unsafe { libc::sendmsg(self.socket.as_raw_fd(), &msg, 0) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+impl DenialLogReceiver {
+    /// Waits for the child to send its listener. Returns `None` when the
+    /// child exec'd or exited without installing a filter.
+    fn recv_listener(&self) -> io::Result<Option<OwnedFd>> {
+        let mut payload = [0_u8; 1];
+        let mut iov = libc::iovec {
+            iov_base: payload.as_mut_ptr().cast(),
+            iov_len: payload.len(),
+        };
+        let mut control = vec![0_u8; control_space_for_fd()];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = control.len() as _;
+        loop {
+            let read =
+                unsafe { libc::recvmsg(self.socket.as_raw_fd(), &mut msg, libc::MSG_CMSG_CLOEXEC) };
+            if read > 0 {
+                break;
+            }
+            if read == 0 {
+                return Ok(None);
+            }
+            let err = io::Error::last_os_error();
+            if err.kind() != io::ErrorKind::Interrupted {
+                return Err(err);
+            }
+        }
+        let cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+        if cmsg.is_null() {
+            return Ok(None);
+        }
+        let (level, ty) = unsafe { ((*cmsg).cmsg_level, (*cmsg).cmsg_type) };
+        if level != libc::SOL_SOCKET || ty != libc::SCM_RIGHTS {
+            return Ok(None);
+        }
+        let fd = unsafe { libc::CMSG_DATA(cmsg).cast::<RawFd>().read_unaligned() };
+        Ok(Some(unsafe { OwnedFd::from_raw_fd(fd) }))
+    }
+}
+
+fn control_space_for_fd() -> usize {
+    unsafe { libc::CMSG_SPACE(size_of::<RawFd>() as libc::c_uint) as usize }
+}
+
+/// Installs `program` on the current thread with every `EPERM` match turned
+/// into a user notification, and returns the notification listener.
+pub(crate) fn install_seccomp_listener_on_current_thread(
+    mut program: BpfProgram,
+) -> io::Result<OwnedFd> {
+    // The filter's only `EPERM` return is its match action, so rewriting it
+    // routes exactly the matched syscalls to the supervisor.
+    for instruction in &mut program {
+        if instruction.code == BPF_RET_K && instruction.k == SECCOMP_RET_ERRNO_EPERM {
+            instruction.k = libc::SECCOMP_RET_USER_NOTIF;
+        }
+    }
+    let len = u16::try_from(program.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "seccomp filter is too long"))?;
+    let prog = libc::sock_fprog {
+        len,
+        filter: program.as_mut_ptr().cast(),
+    };
+    let fd = unsafe {
+        libc::syscall(
+            libc::SYS_seccomp,
+            libc::SECCOMP_SET_MODE_FILTER,
+            libc::SECCOMP_FILTER_FLAG_NEW_LISTENER,
+            &prog as *const libc::sock_fprog,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as RawFd) })
+}
+
+/// Whether the kernel can let a notified syscall continue
+/// (`SECCOMP_USER_NOTIF_FLAG_CONTINUE`, Linux 5.5). Without it a traced write
+/// could only be failed, so writes are not traced at all.
+pub(crate) fn can_trace_writes() -> bool {
+    let mut uts: libc::utsname = unsafe { std::mem::zeroed() };
+    if unsafe { libc::uname(&mut uts) } < 0 {
+        return false;
+    }
+    let release = unsafe { std::ffi::CStr::from_ptr(uts.release.as_ptr()) };
+    parse_kernel_version(&release.to_string_lossy()).is_some_and(|version| version >= (5, 5))
+}
+
+fn parse_kernel_version(release: &str) -> Option<(u32, u32)> {
+    let mut parts = release.split(['.', '-']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// A path argument of a traced syscall, optionally relative to a directory
+/// file descriptor argument.
+#[derive(Debug, Clone, Copy)]
+struct PathArg {
+    dirfd: Option<usize>,
+    path: usize,
+}
+
+impl PathArg {
+    const fn at(dirfd: usize, path: usize) -> Self {
+        Self {
+            dirfd: Some(dirfd),
+            path,
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    const fn cwd(path: usize) -> Self {
+        Self { dirfd: None, path }
+    }
+}
+
+/// A syscall that may write to the filesystem.
+#[derive(Debug)]
+struct TracedWrite {
+    nr: libc::c_long,
+    name: &'static str,
+    /// Argument holding `open` flags; only opens for writing are traced.
+    flags: Option<u8>,
+    /// Paths the syscall writes, in the order they are checked.
+    paths: &'static [PathArg],
+}
+
+const TRACED_WRITES: &[TracedWrite] = &[
+    TracedWrite {
+        nr: libc::SYS_openat,
+        name: "openat",
+        flags: Some(2),
+        paths: &[PathArg::at(0, 1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_openat2,
+        name: "openat2",
+        flags: None,
+        paths: &[PathArg::at(0, 1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_mkdirat,
+        name: "mkdirat",
+        flags: None,
+        paths: &[PathArg::at(0, 1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_unlinkat,
+        name: "unlinkat",
+        flags: None,
+        paths: &[PathArg::at(0, 1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_renameat,
+        name: "renameat",
+        flags: None,
+        paths: &[PathArg::at(0, 1), PathArg::at(2, 3)],
+    },
+    TracedWrite {
+        nr: libc::SYS_renameat2,
+        name: "renameat2",
+        flags: None,
+        paths: &[PathArg::at(0, 1), PathArg::at(2, 3)],
+    },
+    TracedWrite {
+        nr: libc::SYS_symlinkat,
+        name: "symlinkat",
+        flags: None,
+        paths: &[PathArg::at(1, 2)],
+    },
+    TracedWrite {
+        nr: libc::SYS_linkat,
+        name: "linkat",
+        flags: None,
+        paths: &[PathArg::at(2, 3)],
+    },
+];
+
+/// Path-only variants that newer architectures dropped.
+#[cfg(target_arch = "x86_64")]
+const LEGACY_TRACED_WRITES: &[TracedWrite] = &[
+    TracedWrite {
+        nr: libc::SYS_open,
+        name: "open",
+        flags: Some(1),
+        paths: &[PathArg::cwd(0)],
+    },
+    TracedWrite {
+        nr: libc::SYS_creat,
+        name: "creat",
+        flags: None,
+        paths: &[PathArg::cwd(0)],
+    },
+    TracedWrite {
+        nr: libc::SYS_mkdir,
+        name: "mkdir",
+        flags: None,
+        paths: &[PathArg::cwd(0)],
+    },
+    TracedWrite {
+        nr: libc::SYS_rmdir,
+        name: "rmdir",
+        flags: None,
+        paths: &[PathArg::cwd(0)],
+    },
+    TracedWrite {
+        nr: libc::SYS_unlink,
+        name: "unlink",
+        flags: None,
+        paths: &[PathArg::cwd(0)],
+    },
+    TracedWrite {
+        nr: libc::SYS_rename,
+        name: "rename",
+        flags: None,
+        paths: &[PathArg::cwd(0), PathArg::cwd(1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_symlink,
+        name: "symlink",
+        flags: None,
+        paths: &[PathArg::cwd(1)],
+    },
+    TracedWrite {
+        nr: libc::SYS_link,
+        name: "link",
+        flags: None,
+        paths: &[PathArg::cwd(1)],
+    },
+];
+
+#[cfg(not(target_arch = "x86_64"))]
+const LEGACY_TRACED_WRITES: &[TracedWrite] = &[];
+
+fn traced_writes() -> impl Iterator<Item = &'static TracedWrite> {
+    TRACED_WRITES.iter().chain(LEGACY_TRACED_WRITES)
+}
+
+/// Seccomp rules matching the syscalls that may write to the filesystem.
+///
+/// Opens are only matched when they ask for write access, so reads never
+/// wait on the supervisor.
+pub(crate) fn write_trace_rules() -> Result<BTreeMap<i64, Vec<SeccompRule>>, BackendError> {
+    let mut rules = BTreeMap::new();
+    for write in traced_writes() {
+        let write_rules = match write.flags {
+            Some(flags) => [libc::O_WRONLY, libc::O_RDWR, libc::O_CREAT, libc::O_TRUNC]
+                .into_iter()
+                .map(|flag| {
+                    SeccompRule::new(vec![SeccompCondition::new(
+                        flags,
+                        SeccompCmpArgLen::Dword,
+                        SeccompCmpOp::MaskedEq(flag as u64),
+                        flag as u64,
+                    )?])
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![],
+        };
+        rules.insert(write.nr, write_rules);
+    }
+    Ok(rules)
+}
+
+/// Answers seccomp notifications on a background thread while the command
+/// runs.
+pub(crate) struct DenialMonitor {
+    stop: Arc<AtomicBool>,
+    handle: thread::JoinHandle<Vec<String>>,
+}
+
+impl DenialMonitor {
+    pub(crate) fn start(receiver: DenialLogReceiver, write_check: WriteCheck) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let monitor_stop = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            let listener = match receiver.recv_listener() {
+                Ok(Some(listener)) => listener,
+                Ok(None) => return Vec::new(),
+                Err(err) => {
+                    eprintln!("codex-linux-sandbox: failed to receive seccomp listener: {err}");
+                    return Vec::new();
+                }
+            };
+            let mut log = DenialLog::default();
+            supervise(&listener, &write_check, &monitor_stop, &mut log);
+            log.into_lines()
+        });
+        Self { stop, handle }
+    }
+
+    /// Stops answering notifications and returns the recorded denials.
+    ///
+    /// Anything the command left running is no longer supervised; its
+    /// notified syscalls fail with `ENOSYS` once the listener is closed.
+    pub(crate) fn stop(self) -> Vec<String> {
+        self.stop.store(true, Ordering::SeqCst);
+        self.handle
+            .join()
+            .unwrap_or_else(|_| panic!("denial log monitor thread panicked"))
+    }
+}
+
+fn supervise(listener: &OwnedFd, write_check: &WriteCheck, stop: &AtomicBool, log: &mut DenialLog) {
+    let mut poll_fd = libc::pollfd {
+        fd: listener.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    while !stop.load(Ordering::SeqCst) {
+        let res = unsafe { libc::poll(&mut poll_fd, 1, POLL_INTERVAL_MS) };
+        if res == 0 {
+            continue;
+        }
+        if res < 0 {
+            if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            return;
+        }
+        if poll_fd.revents & libc::POLLIN != 0 {
+            handle_notification(listener, write_check, log);
+        } else if poll_fd.revents & (libc::POLLHUP | libc::POLLERR) != 0 {
+            // Every process using the filter has exited.
+            return;
+        }
+    }
+}
+
+fn handle_notification(listener: &OwnedFd, write_check: &WriteCheck, log: &mut DenialLog) {
+    let mut notif: libc::seccomp_notif = unsafe { std::mem::zeroed() };
+    if unsafe {
+        libc::ioctl(
+            listener.as_raw_fd(),
+            SECCOMP_IOCTL_NOTIF_RECV as _,
+            &mut notif,
+        )
+    } < 0
+    {
+        // The notifying process may already be gone.
+        return;
+    }
+    let nr = libc::c_long::from(notif.data.nr);
+    let mut resp: libc::seccomp_notif_resp = unsafe { std::mem::zeroed() };
+    resp.id = notif.id;
+    let denial = match traced_writes().find(|write| write.nr == nr) {
+        Some(write) => {
+            resp.flags = libc::SECCOMP_USER_NOTIF_FLAG_CONTINUE as u32;
+            write_denial(write, &notif, write_check)
+        }
+        None => {
+            resp.error = -libc::EPERM;
+            Some(blocked_syscall_denial(&notif))
+        }
+    };
+    // Anything read from the process is only trustworthy if it was still
+    // waiting on this notification afterwards.
+    if let Some(denial) = denial
+        && notification_is_valid(listener, notif.id)
+    {
+        log.record(denial);
+    }
+    unsafe {
+        libc::ioctl(
+            listener.as_raw_fd(),
+            SECCOMP_IOCTL_NOTIF_SEND as _,
+            &mut resp,
+        );
+    }
+}
+
+fn notification_is_valid(listener: &OwnedFd, id: u64) -> bool {
+    let mut id = id;
+    unsafe {
+        libc::ioctl(
+            listener.as_raw_fd(),
+            SECCOMP_IOCTL_NOTIF_ID_VALID as _,
+            &mut id,
+        ) == 0
+    }
+}
+
+fn write_denial(
+    write: &TracedWrite,
+    notif: &libc::seccomp_notif,
+    write_check: &WriteCheck,
+) -> Option<String> {
+    let pid = notif.pid;
+    let args = notif.data.args;
+    write.paths.iter().find_map(|path_arg| {
+        let path = read_process_path(pid, args[path_arg.path])?;
+        let path = match path_arg.dirfd {
+            _ if path.is_absolute() => path,
+            Some(dirfd) => resolve_dirfd(pid, args[dirfd] as libc::c_int)?.join(path),
+            None => resolve_dirfd(pid, libc::AT_FDCWD)?.join(path),
+        };
+        let reason = write_check.denial_reason(&path)?;
+        Some(format!("{} {} ({reason})", write.name, path.display()))
+    })
+}
+
+fn resolve_dirfd(pid: u32, dirfd: libc::c_int) -> Option<PathBuf> {
+    let link = if dirfd == libc::AT_FDCWD {
+        format!("/proc/{pid}/cwd")
+    } else {
+        format!("/proc/{pid}/fd/{dirfd}")
+    };
+    std::fs::read_link(link).ok()
+}
+
+fn blocked_syscall_denial(notif: &libc::seccomp_notif) -> String {
+    let pid = notif.pid;
+    let args = notif.data.args;
+    let nr = libc::c_long::from(notif.data.nr);
+    let target = match nr {
+        libc::SYS_socket | libc::SYS_socketpair => Some(socket_family_name(args[0] as libc::c_int)),
+        libc::SYS_connect | libc::SYS_bind => read_sockaddr(pid, args[1], args[2]),
+        libc::SYS_sendto => read_sockaddr(pid, args[4], args[5]),
+        _ => None,
+    };
+    let reason = if is_network_syscall(nr) {
+        "blocked by network sandbox"
+    } else {
+        "blocked by seccomp"
+    };
+    match target {
+        Some(target) => format!("{} {target} ({reason})", syscall_name(nr)),
+        None => format!("{} ({reason})", syscall_name(nr)),
+    }
+}
+
+fn is_network_syscall(nr: libc::c_long) -> bool {
+    !matches!(
+        nr,
+        libc::SYS_ptrace
+            | libc::SYS_process_vm_readv
+            | libc::SYS_process_vm_writev
+            | libc::SYS_io_uring_setup
+            | libc::SYS_io_uring_enter
+            | libc::SYS_io_uring_register
+    )
+}
+
+fn syscall_name(nr: libc::c_long) -> String {
+    let name = match nr {
+        libc::SYS_ptrace => "ptrace",
+        libc::SYS_process_vm_readv => "process_vm_readv",
+        libc::SYS_process_vm_writev => "process_vm_writev",
+        libc::SYS_io_uring_setup => "io_uring_setup",
+        libc::SYS_io_uring_enter => "io_uring_enter",
+        libc::SYS_io_uring_register => "io_uring_register",
+        libc::SYS_socket => "socket",
+        libc::SYS_socketpair => "socketpair",
+        libc::SYS_connect => "connect",
+        libc::SYS_accept => "accept",
+        libc::SYS_accept4 => "accept4",
+        libc::SYS_bind => "bind",
+        libc::SYS_listen => "listen",
+        libc::SYS_getpeername => "getpeername",
+        libc::SYS_getsockname => "getsockname",
+        libc::SYS_shutdown => "shutdown",
+        libc::SYS_sendto => "sendto",
+        libc::SYS_sendmmsg => "sendmmsg",
+        libc::SYS_recvmmsg => "recvmmsg",
+        libc::SYS_getsockopt => "getsockopt",
+        libc::SYS_setsockopt => "setsockopt",
+        _ => return format!("syscall {nr}"),
+    };
+    name.to_string()
+}
+
+fn socket_family_name(family: libc::c_int) -> String {
+    match family {
+        libc::AF_UNIX => "AF_UNIX".to_string(),
+        libc::AF_INET => "AF_INET".to_string(),
+        libc::AF_INET6 => "AF_INET6".to_string(),
+        libc::AF_NETLINK => "AF_NETLINK".to_string(),
+        libc::AF_PACKET => "AF_PACKET".to_string(),
+        _ => format!("address family {family}"),
+    }
+}
+
+fn read_sockaddr(pid: u32, addr: u64, len: u64) -> Option<String> {
+    if addr == 0 {
+        return None;
+    }
+    let len = usize::try_from(len).ok()?.min(MAX_SOCKADDR_LEN);
+    let bytes = read_process_memory(pid, addr, len)?;
+    format_sockaddr(&bytes)
+}
+
+fn format_sockaddr(bytes: &[u8]) -> Option<String> {
+    let family = libc::c_int::from(u16::from_ne_bytes([*bytes.first()?, *bytes.get(1)?]));
+    let port = || {
+        bytes
+            .get(2..4)
+            .map(|port| u16::from_be_bytes([port[0], port[1]]))
+    };
+    match family {
+        libc::AF_INET => {
+            let octets: [u8; 4] = bytes.get(4..8)?.try_into().ok()?;
+            Some(format!("{}:{}", Ipv4Addr::from(octets), port()?))
+        }
+        libc::AF_INET6 => {
+            let octets: [u8; 16] = bytes.get(8..24)?.try_into().ok()?;
+            Some(format!("[{}]:{}", Ipv6Addr::from(octets), port()?))
+        }
+        libc::AF_UNIX => {
+            let path = bytes.get(2..)?;
+            match path.split_first() {
+                // Abstract socket names start with a NUL byte.
+                Some((0, name)) => Some(format!("@{}", String::from_utf8_lossy(name))),
+                _ => {
+                    let end = path
+                        .iter()
+                        .position(|byte| *byte == 0)
+                        .unwrap_or(path.len());
+                    Some(String::from_utf8_lossy(&path[..end]).into_owned())
+                }
+            }
+        }
+        _ => Some(socket_family_name(family)),
+    }
+}
+
+fn read_process_memory(pid: u32, addr: u64, len: usize) -> Option<Vec<u8>> {
+    let mem = File::open(format!("/proc/{pid}/mem")).ok()?;
+    let mut buf = vec![0_u8; len];
+    let read = mem.read_at(&mut buf, addr).ok()?;
+    buf.truncate(read);
+    Some(buf)
+}
+
+/// Reads a NUL-terminated path from the process, one page at a time so a
+/// string ending just before an unmapped page is still read.
+fn read_process_path(pid: u32, addr: u64) -> Option<PathBuf> {
+    if addr == 0 {
+        return None;
+    }
+    let mem = File::open(format!("/proc/{pid}/mem")).ok()?;
+    let mut path = Vec::new();
+    let mut offset = addr;
+    while path.len() < libc::PATH_MAX as usize {
+        let mut chunk = vec![0_u8; (PAGE_SIZE - offset % PAGE_SIZE) as usize];
+        let read = mem.read_at(&mut chunk, offset).ok()?;
+        if read == 0 {
+            return None;
+        }
+        if let Some(end) = chunk[..read].iter().position(|byte| *byte == 0) {
+            path.extend_from_slice(&chunk[..end]);
+            return Some(PathBuf::from(std::ffi::OsString::from_vec(path)));
+        }
+        path.extend_from_slice(&chunk[..read]);
+        offset += read as u64;
+    }
+    None
+}
+
+/// Distinct denials in the order they were first seen.
+#[derive(Debug, Default)]
+struct DenialLog {
+    denials: Vec<String>,
+    truncated: bool,
+}
+
+impl DenialLog {
+    fn record(&mut self, denial: String) {
+        if self.denials.contains(&denial) {
+            return;
+        }
+        if self.denials.len() >= MAX_REPORTED_DENIALS {
+            self.truncated = true;
+            return;
+        }
+        self.denials.push(denial);
+    }
+
+    fn into_lines(self) -> Vec<String> {
+        let mut lines = self.denials;
+        if self.truncated {
+            lines.push("further denials were not recorded".to_string());
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+#[path = "denial_log_tests.rs"]
+mod tests;
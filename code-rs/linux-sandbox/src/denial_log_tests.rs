@@ -0,0 +1,81 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn sockaddr_bytes(family: libc::c_int, rest: &[u8]) -> Vec<u8> {
+    let mut bytes = (family as u16).to_ne_bytes().to_vec();
+    bytes.extend_from_slice(rest);
+    bytes
+}
+
+#[test]
+fn format_sockaddr_renders_inet_and_unix_addresses() {
+    let inet = sockaddr_bytes(libc::AF_INET, &[0x01, 0xbb, 93, 184, 216, 34, 0, 0]);
+    assert_eq!(
+        format_sockaddr(&inet),
+        Some("93.184.216.34:443".to_string())
+    );
+
+    let mut inet6_rest = vec![0x00, 0x50, 0, 0, 0, 0];
+    inet6_rest.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+    let inet6 = sockaddr_bytes(libc::AF_INET6, &inet6_rest);
+    assert_eq!(format_sockaddr(&inet6), Some("[::1]:80".to_string()));
+
+    let unix = sockaddr_bytes(libc::AF_UNIX, b"/run/docker.sock\0");
+    assert_eq!(format_sockaddr(&unix), Some("/run/docker.sock".to_string()));
+    let abstract_unix = sockaddr_bytes(libc::AF_UNIX, b"\0bus");
+    assert_eq!(format_sockaddr(&abstract_unix), Some("@bus".to_string()));
+
+    let truncated = sockaddr_bytes(libc::AF_INET, &[0x01, 0xbb]);
+    assert_eq!(format_sockaddr(&truncated), None);
+}
+
+#[test]
+fn denial_log_dedupes_and_caps_entries() {
+    let mut log = DenialLog::default();
+    log.record("ptrace (blocked by seccomp)".to_string());
+    log.record("ptrace (blocked by seccomp)".to_string());
+    assert_eq!(log.denials, vec!["ptrace (blocked by seccomp)".to_string()]);
+
+    for index in 0..MAX_REPORTED_DENIALS {
+        log.record(format!("openat /tmp/{index} (read-only file system)"));
+    }
+    let lines = log.into_lines();
+    assert_eq!(lines.len(), MAX_REPORTED_DENIALS + 1);
+    assert_eq!(
+        lines.last().map(String::as_str),
+        Some("further denials were not recorded")
+    );
+}
+
+#[test]
+fn policy_write_check_reports_paths_outside_writable_roots() {
+    let cwd = PathBuf::from("/workspace");
+    let write_check = WriteCheck::Policy {
+        file_system_sandbox_policy: FileSystemSandboxPolicy::from(
+            &codex_protocol::protocol::SandboxPolicy::new_workspace_write_policy(),
+        ),
+        cwd: cwd.clone(),
+    };
+
+    assert_eq!(write_check.denial_reason(&cwd.join("src/main.rs")), None);
+    assert_eq!(write_check.denial_reason(Path::new("/dev/null")), None);
+    assert_eq!(
+        write_check.denial_reason(Path::new("/etc/hosts")),
+        Some("outside the writable roots")
+    );
+}
+
+#[test]
+fn write_trace_rules_cover_every_traced_syscall() {
+    let rules = write_trace_rules().expect("build write trace rules");
+    assert_eq!(rules.len(), traced_writes().count());
+    assert_eq!(rules.get(&libc::SYS_openat).map(Vec::len), Some(4));
+    assert_eq!(rules.get(&libc::SYS_unlinkat).map(Vec::len), Some(0));
+}
+
+#[test]
+fn parse_kernel_version_reads_major_and_minor() {
+    assert_eq!(parse_kernel_version("6.8.0-45-generic"), Some((6, 8)));
+    assert_eq!(parse_kernel_version("5.4-rc1"), Some((5, 4)));
+    assert_eq!(parse_kernel_version("unknown"), None);
+}
@@ -12,6 +12,11 @@ use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::NetworkSandboxPolicy;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
+use crate::denial_log::DenialLogSender;
+use crate::denial_log::can_trace_writes;
+use crate::denial_log::install_seccomp_listener_on_current_thread;
+use crate::denial_log::write_trace_rules;
+
 use landlock::ABI;
 #[allow(unused_imports)]
 use landlock::Access;
@@ -39,12 +44,16 @@ use seccompiler::apply_filter;
 /// - installing the network seccomp filter when network access is disabled.
 ///
 /// Filesystem restrictions are intentionally handled by bubblewrap.
+///
+/// With `denial_log`, blocked syscalls and filesystem writes are reported to
+/// the denial log supervisor instead of failing silently.
 pub(crate) fn apply_permission_profile_to_current_thread(
     permission_profile: &PermissionProfile,
     cwd: &Path,
     apply_landlock_fs: bool,
     allow_network_for_proxy: bool,
     proxy_routed_network: bool,
+    denial_log: Option<DenialLogSender>,
 ) -> Result<()> {
     let (file_system_sandbox_policy, network_sandbox_policy) =
         permission_profile.to_runtime_permissions();
@@ -53,19 +62,32 @@ pub(crate) fn apply_permission_profile_to_current_thread(
         allow_network_for_proxy,
         proxy_routed_network,
     );
+    let trace_writes = denial_log.is_some()
+        && !file_system_sandbox_policy.has_full_disk_write_access()
+        && can_trace_writes();
 
     // `PR_SET_NO_NEW_PRIVS` is required for seccomp, but it also prevents
     // setuid privilege elevation. Many `bwrap` deployments rely on setuid, so
     // we avoid this unless we need seccomp or we are explicitly using the
     // legacy Landlock filesystem pipeline.
     if network_seccomp_mode.is_some()
+        || trace_writes
         || (apply_landlock_fs && !file_system_sandbox_policy.has_full_disk_write_access())
     {
         set_no_new_privs()?;
     }
 
-    if let Some(mode) = network_seccomp_mode {
-        install_network_seccomp_filter_on_current_thread(mode)?;
+    match denial_log {
+        Some(denial_log) => install_denial_log_seccomp_filter_on_current_thread(
+            network_seccomp_mode,
+            trace_writes,
+            denial_log,
+        )?,
+        None => {
+            if let Some(mode) = network_seccomp_mode {
+                install_network_seccomp_filter_on_current_thread(mode)?;
+            }
+        }
     }
 
     if apply_landlock_fs && !file_system_sandbox_policy.has_full_disk_write_access() {
@@ -169,6 +191,51 @@ fn install_filesystem_landlock_rules_on_current_thread(
 fn install_network_seccomp_filter_on_current_thread(
     mode: NetworkSeccompMode,
 ) -> std::result::Result<(), SandboxErr> {
+    let prog = compile_seccomp_filter(network_seccomp_rules(mode)?)?;
+
+    apply_filter(&prog)?;
+
+    Ok(())
+}
+
+/// Installs the seccomp filter for a run with denial logging and hands its
+/// listener to the denial log supervisor.
+///
+/// The filter matches what the network filter would deny plus, with
+/// `trace_writes`, filesystem writes. If the kernel cannot create a listener,
+/// the plain network filter is installed so the sandbox is unchanged.
+fn install_denial_log_seccomp_filter_on_current_thread(
+    mode: Option<NetworkSeccompMode>,
+    trace_writes: bool,
+    denial_log: DenialLogSender,
+) -> Result<()> {
+    let mut rules = match mode {
+        Some(mode) => network_seccomp_rules(mode)?,
+        None => BTreeMap::new(),
+    };
+    if trace_writes {
+        rules.extend(write_trace_rules().map_err(SandboxErr::from)?);
+    }
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    let prog = compile_seccomp_filter(rules)?;
+    match install_seccomp_listener_on_current_thread(prog) {
+        Ok(listener) => denial_log.send_listener(listener)?,
+        Err(err) => {
+            eprintln!("codex-linux-sandbox: denial logging unavailable: {err}");
+            if let Some(mode) = mode {
+                install_network_seccomp_filter_on_current_thread(mode)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn network_seccomp_rules(
+    mode: NetworkSeccompMode,
+) -> std::result::Result<BTreeMap<i64, Vec<SeccompRule>>, SandboxErr> {
     fn deny_syscall(rules: &mut BTreeMap<i64, Vec<SeccompRule>>, nr: i64) {
         rules.insert(nr, vec![]); // empty rule vec = unconditional match
     }
@@ -246,6 +313,14 @@ fn install_network_seccomp_filter_on_current_thread(
         }
     }
 
+    Ok(rules)
+}
+
+/// Compiles `rules` into a filter that allows everything else and fails
+/// matching syscalls with `EPERM`.
+fn compile_seccomp_filter(
+    rules: BTreeMap<i64, Vec<SeccompRule>>,
+) -> std::result::Result<BpfProgram, SandboxErr> {
     let filter = SeccompFilter::new(
         rules,
         SeccompAction::Allow,                     // default – allow
@@ -259,11 +334,7 @@ fn install_network_seccomp_filter_on_current_thread(
         },
     )?;
 
-    let prog: BpfProgram = filter.try_into()?;
-
-    apply_filter(&prog)?;
-
-    Ok(())
+    Ok(filter.try_into()?)
 }
 
 #[cfg(test)]
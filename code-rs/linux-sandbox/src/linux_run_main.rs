@@ -5,9 +5,12 @@ use std::fs;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::Read;
+use std::io::Write;
 use std::os::fd::AsRawFd;
 use std::os::fd::FromRawFd;
+use std::os::fd::RawFd;
 use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::OpenOptionsExt;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -20,16 +23,25 @@ use std::time::Duration;
 use crate::bwrap::BwrapNetworkMode;
 use crate::bwrap::BwrapOptions;
 use crate::bwrap::create_bwrap_command_args;
+use crate::denial_log::DenialLogSender;
+use crate::denial_log::DenialMonitor;
+use crate::denial_log::WriteCheck;
+use crate::denial_log::denial_log_channel;
 use crate::landlock::apply_permission_profile_to_current_thread;
 use crate::launcher::exec_bwrap;
 use crate::launcher::preferred_bwrap_supports_argv0;
 use crate::proxy_routing::activate_proxy_routes_in_netns;
 use crate::proxy_routing::prepare_host_proxy_route_spec;
+use crate::resource_limits::CgroupScope;
+use crate::resource_limits::apply_rlimits;
 use codex_protocol::error::Result as CodexResult;
 use codex_protocol::models::PermissionProfile;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::FileSystemSandboxPolicy;
 use codex_protocol::protocol::NetworkSandboxPolicy;
 use codex_sandboxing::landlock::CODEX_LINUX_SANDBOX_ARG0;
+use codex_sandboxing::landlock::resource_limit_exceeded_report_line;
+use codex_sandboxing::landlock::sandbox_denial_report_line;
 
 static BWRAP_CHILD_PID: AtomicI32 = AtomicI32::new(0);
 static PENDING_FORWARDED_SIGNAL: AtomicI32 = AtomicI32::new(0);
@@ -100,12 +112,41 @@ pub struct LandlockCommand {
     )]
     pub permission_profile: Option<PermissionProfile>,
 
+    /// Resource limits for the command, as JSON.
+    ///
+    /// Applied once by the outer stage; the limits are inherited by
+    /// bubblewrap and the inner stage.
+    #[arg(
+        long = "resource-limits",
+        hide = true,
+        value_parser = parse_resource_limits
+    )]
+    pub resource_limits: Option<ResourceLimits>,
+
+    /// File to record resource limit kills and denials in, for the caller to
+    /// read once the command exits.
+    ///
+    /// Opened and unlinked by the outer stage before any other setup, so the
+    /// sandboxed command cannot write to it.
+    #[arg(long = "report-file", hide = true)]
+    pub report_file: Option<PathBuf>,
+
+    /// Internal: descriptor of the report file, passed through bubblewrap to
+    /// the inner stage so its denial log supervisor can record denials.
+    #[arg(long = "report-fd", hide = true)]
+    pub report_fd: Option<RawFd>,
+
     /// Opt-in: use the legacy Landlock Linux sandbox fallback.
     ///
     /// When not set, the helper uses the default bubblewrap pipeline.
     #[arg(long = "use-legacy-landlock", hide = true, default_value_t = false)]
     pub use_legacy_landlock: bool,
 
+    /// Record the syscalls, network attempts and filesystem writes the
+    /// sandbox blocked in the report file once the command exits.
+    #[arg(long = "log-denials", hide = true, default_value_t = false)]
+    pub log_denials: bool,
+
     /// Internal: apply seccomp and `no_new_privs` in the already-sandboxed
     /// process, then exec the user command.
     ///
@@ -149,7 +190,11 @@ pub fn run_main() -> ! {
         sandbox_policy_cwd,
         command_cwd,
         permission_profile,
+        resource_limits,
+        report_file,
+        report_fd,
         use_legacy_landlock,
+        log_denials,
         apply_seccomp_then_exec,
         allow_network_for_proxy,
         proxy_route_spec,
@@ -172,6 +217,18 @@ pub fn run_main() -> ! {
         network_sandbox_policy,
         &sandbox_policy_cwd,
     );
+    let report = match report_fd {
+        Some(fd) => adopt_report_fd(fd),
+        None => report_file.as_deref().and_then(open_report_file),
+    };
+    let report = if !apply_seccomp_then_exec && let Some(resource_limits) = resource_limits {
+        enforce_resource_limits(&resource_limits, report)
+    } else {
+        report
+    };
+    // Denials are only ever recorded in the report, never on the command's
+    // own stderr where it could forge them.
+    let denial_report = report.filter(|_| log_denials);
 
     // Inner stage: apply seccomp/no_new_privs after bubblewrap has already
     // established the filesystem view.
@@ -185,12 +242,15 @@ pub fn run_main() -> ! {
             }
         }
         let proxy_routing_active = allow_network_for_proxy;
+        let denial_log =
+            denial_report.map(|report| start_denial_log(WriteCheck::ReadOnlyMounts, report));
         if let Err(e) = apply_permission_profile_to_current_thread(
             &permission_profile,
             &sandbox_policy_cwd,
             /*apply_landlock_fs*/ false,
             allow_network_for_proxy,
             proxy_routing_active,
+            denial_log,
         ) {
             panic!("error applying Linux sandbox restrictions: {e:?}");
         }
@@ -198,12 +258,15 @@ pub fn run_main() -> ! {
     }
 
     if file_system_sandbox_policy.has_full_disk_write_access() && !allow_network_for_proxy {
+        let denial_log =
+            denial_report.map(|report| start_denial_log(WriteCheck::ReadOnlyMounts, report));
         if let Err(e) = apply_permission_profile_to_current_thread(
             &permission_profile,
             &sandbox_policy_cwd,
             /*apply_landlock_fs*/ false,
             allow_network_for_proxy,
             /*proxy_routed_network*/ false,
+            denial_log,
         ) {
             panic!("error applying Linux sandbox restrictions: {e:?}");
         }
@@ -228,6 +291,8 @@ pub fn run_main() -> ! {
             permission_profile: &permission_profile,
             allow_network_for_proxy,
             proxy_route_spec,
+            log_denials,
+            report_fd: denial_report.as_ref().map(AsRawFd::as_raw_fd),
             command,
         });
         run_bwrap_with_proc_fallback(
@@ -236,18 +301,29 @@ pub fn run_main() -> ! {
             &file_system_sandbox_policy,
             network_sandbox_policy,
             inner,
+            denial_report,
             !no_proc,
             allow_network_for_proxy,
         );
     }
 
     // Legacy path: Landlock enforcement only, when bwrap sandboxing is not enabled.
+    let denial_log = denial_report.map(|report| {
+        start_denial_log(
+            WriteCheck::Policy {
+                file_system_sandbox_policy: file_system_sandbox_policy.clone(),
+                cwd: sandbox_policy_cwd.clone(),
+            },
+            report,
+        )
+    });
     if let Err(e) = apply_permission_profile_to_current_thread(
         &permission_profile,
         &sandbox_policy_cwd,
         /*apply_landlock_fs*/ true,
         allow_network_for_proxy,
         /*proxy_routed_network*/ false,
+        denial_log,
     ) {
         panic!("error applying legacy Linux sandbox restrictions: {e:?}");
     }
@@ -278,6 +354,10 @@ fn parse_permission_profile(value: &str) -> std::result::Result<PermissionProfil
     serde_json::from_str(value).map_err(|err| format!("invalid permission profile JSON: {err}"))
 }
 
+fn parse_resource_limits(value: &str) -> std::result::Result<ResourceLimits, String> {
+    serde_json::from_str(value).map_err(|err| format!("invalid resource limits JSON: {err}"))
+}
+
 fn resolve_permission_profile(
     permission_profile: Option<PermissionProfile>,
 ) -> Result<EffectivePermissions, ResolvePermissionProfileError> {
@@ -320,6 +400,7 @@ fn run_bwrap_with_proc_fallback(
     file_system_sandbox_policy: &FileSystemSandboxPolicy,
     network_sandbox_policy: NetworkSandboxPolicy,
     inner: Vec<String>,
+    inner_report: Option<File>,
     mount_proc: bool,
     allow_network_for_proxy: bool,
 ) -> ! {
@@ -354,6 +435,9 @@ fn run_bwrap_with_proc_fallback(
         options,
     )
     .unwrap_or_else(|err| exit_with_bwrap_build_error(err));
+    // The inner stage finds the report under the descriptor number it was
+    // given, so keep it open across the bubblewrap exec.
+    bwrap_args.preserved_files.extend(inner_report);
     apply_inner_command_argv0(&mut bwrap_args.args);
     run_or_exec_bwrap(bwrap_args);
 }
@@ -486,6 +570,164 @@ fn resolve_true_command() -> String {
     "true".to_string()
 }
 
+/// Opens the caller's report file and unlinks it, leaving this process with
+/// the only descriptor the helper holds for it.
+///
+/// The descriptor is close-on-exec, so neither bubblewrap nor the command
+/// inherits it unless it is handed to the inner stage on purpose. A missing
+/// report is not fatal: the caller then only loses the details the helper
+/// would have recorded.
+fn open_report_file(path: &Path) -> Option<File> {
+    let file = OpenOptions::new()
+        .append(true)
+        .custom_flags(libc::O_NOFOLLOW)
+        .open(path);
+    let file = match file {
+        Ok(file) => file,
+        Err(err) => {
+            eprintln!(
+                "codex-linux-sandbox: failed to open report file {}: {err}",
+                path.display()
+            );
+            return None;
+        }
+    };
+    if let Err(err) = fs::remove_file(path) {
+        eprintln!(
+            "codex-linux-sandbox: failed to unlink report file {}: {err}",
+            path.display()
+        );
+        return None;
+    }
+    Some(file)
+}
+
+/// Takes over the report descriptor the outer stage kept open across the
+/// bubblewrap exec, marking it close-on-exec again before anything else runs
+/// so the command never inherits it.
+fn adopt_report_fd(fd: RawFd) -> Option<File> {
+    if unsafe { libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) } < 0 {
+        let err = std::io::Error::last_os_error();
+        eprintln!("codex-linux-sandbox: invalid report descriptor {fd}: {err}");
+        return None;
+    }
+    Some(unsafe { File::from_raw_fd(fd) })
+}
+
+/// Applies resource limits before any sandbox setup runs.
+///
+/// Without a cgroup scope the limits are plain rlimits inherited by the rest
+/// of the pipeline. With a scope, the helper forks: the child joins the scope
+/// and continues setting up the sandbox, while this process waits so it can
+/// record which limit killed the command in `report` and remove the scope
+/// afterwards.
+///
+/// Returns the report for the rest of the pipeline in the process that goes
+/// on to run the command.
+fn enforce_resource_limits(limits: &ResourceLimits, report: Option<File>) -> Option<File> {
+    let Some(cgroup) = CgroupScope::create(limits) else {
+        apply_rlimits(limits, /*cgroup_enforced*/ false);
+        return report;
+    };
+    let setup_signal_mask = ForwardedSignalMask::block();
+    let parent_pid = unsafe { libc::getpid() };
+    let pid = unsafe { libc::fork() };
+    if pid < 0 {
+        let err = std::io::Error::last_os_error();
+        cgroup.remove();
+        panic!("failed to fork for resource limit supervision: {err}");
+    }
+
+    if pid == 0 {
+        reset_forwarded_signal_handlers_to_default();
+        setup_signal_mask.restore();
+        terminate_with_parent(parent_pid);
+        if let Err(err) = cgroup.join_current_process() {
+            panic!("failed to join resource limit cgroup: {err}");
+        }
+        apply_rlimits(limits, /*cgroup_enforced*/ true);
+        return report;
+    }
+
+    // The supervisor holds the report; keep the command from reaching into it
+    // through `/proc`.
+    unsafe {
+        libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0);
+    }
+    let signal_forwarders = install_bwrap_signal_forwarders(pid);
+    setup_signal_mask.restore();
+    let status = wait_for_bwrap_child(pid);
+    let cleanup_signal_mask = ForwardedSignalMask::block();
+    signal_forwarders.restore();
+    // Only the scope's event counters go in the report: the command controls
+    // its own exit code. A `SIGXCPU` or `SIGXFSZ` kill reaches the caller
+    // through this process's own exit status instead.
+    let exceeded_limit = if libc::WIFEXITED(status) && libc::WEXITSTATUS(status) == 0 {
+        None
+    } else {
+        cgroup.exceeded_limit()
+    };
+    cgroup.remove();
+    cleanup_signal_mask.restore();
+    if let (Some(limit), Some(mut report)) = (exceeded_limit, report)
+        && let Err(err) = report.write_all(resource_limit_exceeded_report_line(limit).as_bytes())
+    {
+        eprintln!("codex-linux-sandbox: failed to write report file: {err}");
+    }
+    exit_with_wait_status(status);
+}
+
+/// Forks a supervisor that reports what the sandbox blocks.
+///
+/// Returns in the child, which goes on to apply the sandbox and exec the
+/// command. The parent answers the child's seccomp notifications until the
+/// child exits, then records the denials in `report` and exits with the
+/// child's status.
+fn start_denial_log(write_check: WriteCheck, report: File) -> DenialLogSender {
+    let (sender, receiver) = denial_log_channel()
+        .unwrap_or_else(|err| panic!("failed to create denial log channel: {err}"));
+    let setup_signal_mask = ForwardedSignalMask::block();
+    let parent_pid = unsafe { libc::getpid() };
+    let pid = unsafe { libc::fork() };
+    if pid < 0 {
+        let err = std::io::Error::last_os_error();
+        panic!("failed to fork for denial logging: {err}");
+    }
+
+    if pid == 0 {
+        drop(receiver);
+        drop(report);
+        reset_forwarded_signal_handlers_to_default();
+        setup_signal_mask.restore();
+        terminate_with_parent(parent_pid);
+        return sender;
+    }
+
+    drop(sender);
+    // The supervisor holds the seccomp listener and the report; keep the
+    // command from reaching into them through `/proc`.
+    unsafe {
+        libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0);
+    }
+    let monitor = DenialMonitor::start(receiver, write_check);
+    let signal_forwarders = install_bwrap_signal_forwarders(pid);
+    setup_signal_mask.restore();
+    let status = wait_for_bwrap_child(pid);
+    let cleanup_signal_mask = ForwardedSignalMask::block();
+    signal_forwarders.restore();
+    let denials = monitor.stop();
+    cleanup_signal_mask.restore();
+    let mut report = report;
+    let lines = denials
+        .iter()
+        .map(|denial| sandbox_denial_report_line(denial))
+        .collect::<String>();
+    if let Err(err) = report.write_all(lines.as_bytes()) {
+        eprintln!("codex-linux-sandbox: failed to write report file: {err}");
+    }
+    exit_with_wait_status(status);
+}
+
 fn run_or_exec_bwrap(bwrap_args: crate::bwrap::BwrapArgs) -> ! {
     if bwrap_args.synthetic_mount_targets.is_empty()
         && bwrap_args.protected_create_targets.is_empty()
@@ -1394,6 +1636,8 @@ struct InnerSeccompCommandArgs<'a> {
     permission_profile: &'a PermissionProfile,
     allow_network_for_proxy: bool,
     proxy_route_spec: Option<String>,
+    log_denials: bool,
+    report_fd: Option<RawFd>,
     command: Vec<String>,
 }
 
@@ -1405,6 +1649,8 @@ fn build_inner_seccomp_command(args: InnerSeccompCommandArgs<'_>) -> Vec<String>
         permission_profile,
         allow_network_for_proxy,
         proxy_route_spec,
+        log_denials,
+        report_fd,
         command,
     } = args;
     let current_exe = match std::env::current_exe() {
@@ -1437,6 +1683,13 @@ fn build_inner_seccomp_command(args: InnerSeccompCommandArgs<'_>) -> Vec<String>
         inner.push("--proxy-route-spec".to_string());
         inner.push(proxy_route_spec);
     }
+    if log_denials {
+        inner.push("--log-denials".to_string());
+    }
+    if let Some(report_fd) = report_fd {
+        inner.push("--report-fd".to_string());
+        inner.push(report_fd.to_string());
+    }
     inner.push("--".to_string());
     inner.extend(command);
     inner
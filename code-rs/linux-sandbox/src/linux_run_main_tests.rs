@@ -468,6 +468,8 @@ fn managed_proxy_inner_command_includes_route_spec() {
         permission_profile: &permission_profile,
         allow_network_for_proxy: true,
         proxy_route_spec: Some("{\"routes\":[]}".to_string()),
+        log_denials: false,
+        report_fd: None,
         command: vec!["/bin/true".to_string()],
     });
 
@@ -484,6 +486,8 @@ fn inner_command_includes_permission_profile_flag() {
         permission_profile: &permission_profile,
         allow_network_for_proxy: false,
         proxy_route_spec: None,
+        log_denials: false,
+        report_fd: None,
         command: vec!["/bin/true".to_string()],
     });
 
@@ -503,10 +507,39 @@ fn non_managed_inner_command_omits_route_spec() {
         permission_profile: &permission_profile,
         allow_network_for_proxy: false,
         proxy_route_spec: None,
+        log_denials: false,
+        report_fd: None,
         command: vec!["/bin/true".to_string()],
     });
 
     assert!(!args.iter().any(|arg| arg == "--proxy-route-spec"));
+    assert!(!args.iter().any(|arg| arg == "--log-denials"));
+}
+
+#[test]
+fn inner_command_forwards_log_denials_flag() {
+    let permission_profile = read_only_permission_profile();
+    let args = build_inner_seccomp_command(InnerSeccompCommandArgs {
+        sandbox_policy_cwd: Path::new("/tmp"),
+        command_cwd: None,
+        permission_profile: &permission_profile,
+        allow_network_for_proxy: false,
+        proxy_route_spec: None,
+        log_denials: true,
+        report_fd: Some(7),
+        command: vec!["/bin/true".to_string()],
+    });
+
+    let separator = args
+        .iter()
+        .position(|arg| arg == "--")
+        .expect("command separator");
+    assert!(args[..separator].iter().any(|arg| arg == "--log-denials"));
+    assert!(
+        args[..separator]
+            .windows(2)
+            .any(|window| window == ["--report-fd", "7"])
+    );
 }
 
 #[test]
@@ -519,6 +552,8 @@ fn managed_proxy_inner_command_requires_route_spec() {
             permission_profile: &permission_profile,
             allow_network_for_proxy: true,
             proxy_route_spec: None,
+            log_denials: false,
+            report_fd: None,
             command: vec!["/bin/true".to_string()],
         })
     });
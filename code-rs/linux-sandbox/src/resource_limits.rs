@@ -0,0 +1,243 @@
+//! Resource limits for the sandboxed command.
+//!
+//! Limits are applied as rlimits on the helper process so they are inherited
+//! by bubblewrap and the final command. Memory and process count are better
+//! enforced by a cgroup v2 scope. When a scope with the `memory` and `pids`
+//! controllers can be created, those two limits are written there instead.
+//! Otherwise memory falls back to `RLIMIT_DATA`, which only bounds each
+//! process on its own, and the process count is not enforced: `RLIMIT_NPROC`
+//! counts every process the user owns, so on a shared machine it could fail
+//! the command at once or block its forks at random.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use codex_protocol::permissions::ResourceLimitKind;
+use codex_protocol::permissions::ResourceLimits;
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+const CGROUP_REMOVE_ATTEMPTS: usize = 50;
+const CGROUP_REMOVE_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// A transient cgroup v2 scope holding the sandboxed command.
+#[derive(Debug)]
+pub(crate) struct CgroupScope {
+    path: PathBuf,
+}
+
+impl CgroupScope {
+    /// Creates a scope enforcing the memory and process-count limits.
+    ///
+    /// The scope is created next to the helper's own cgroup because a cgroup
+    /// that contains processes cannot delegate controllers to its children.
+    /// Returns `None` when no limit needs a cgroup or when cgroup v2 is not
+    /// writable or lacks a required controller; callers fall back to rlimits.
+    pub(crate) fn create(limits: &ResourceLimits) -> Option<Self> {
+        if limits.max_memory_bytes.is_none() && limits.max_processes.is_none() {
+            return None;
+        }
+        let current = fs::read_to_string("/proc/self/cgroup").ok()?;
+        let current = current_cgroup_path(&current)?;
+        let parent = Path::new(current).parent().unwrap_or(Path::new("/"));
+        let parent = Path::new(CGROUP_ROOT).join(parent.strip_prefix("/").unwrap_or(parent));
+        let path = parent.join(format!("codex-sandbox-{}", std::process::id()));
+        fs::create_dir(&path).ok()?;
+        let scope = Self { path };
+        if let Err(err) = scope.write_limits(limits) {
+            eprintln!(
+                "codex-linux-sandbox: cgroup resource limits unavailable, falling back to rlimits: {err}"
+            );
+            scope.remove();
+            return None;
+        }
+        Some(scope)
+    }
+
+    fn write_limits(&self, limits: &ResourceLimits) -> io::Result<()> {
+        let controllers = fs::read_to_string(self.path.join("cgroup.controllers"))?;
+        let controllers: Vec<&str> = controllers.split_whitespace().collect();
+        if let Some(max_memory_bytes) = limits.max_memory_bytes {
+            require_controller(&controllers, "memory")?;
+            fs::write(self.path.join("memory.max"), max_memory_bytes.to_string())?;
+            // Keep the command from escaping the memory limit by swapping.
+            // Some kernels are built without swap accounting, so this is
+            // best effort.
+            let _ = fs::write(self.path.join("memory.swap.max"), "0");
+        }
+        if let Some(max_processes) = limits.max_processes {
+            require_controller(&controllers, "pids")?;
+            fs::write(self.path.join("pids.max"), max_processes.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Moves the calling process into the scope.
+    pub(crate) fn join_current_process(&self) -> io::Result<()> {
+        fs::write(self.path.join("cgroup.procs"), "0")
+    }
+
+    /// Reports the limit recorded by the scope's event counters, if any.
+    pub(crate) fn exceeded_limit(&self) -> Option<ResourceLimitKind> {
+        if read_event_counter(&self.path.join("memory.events"), "oom_kill") > 0 {
+            return Some(ResourceLimitKind::Memory);
+        }
+        if read_event_counter(&self.path.join("pids.events"), "max") > 0 {
+            return Some(ResourceLimitKind::Processes);
+        }
+        None
+    }
+
+    /// Kills anything left in the scope and removes it.
+    pub(crate) fn remove(self) {
+        let _ = fs::write(self.path.join("cgroup.kill"), "1");
+        for _ in 0..CGROUP_REMOVE_ATTEMPTS {
+            match fs::remove_dir(&self.path) {
+                Ok(()) => return,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return,
+                Err(_) => thread::sleep(CGROUP_REMOVE_RETRY_DELAY),
+            }
+        }
+        eprintln!(
+            "codex-linux-sandbox: failed to remove cgroup {}",
+            self.path.display()
+        );
+    }
+}
+
+/// Applies the configured limits to the current process with `setrlimit`.
+///
+/// `cgroup_enforced` skips the memory fallback because the cgroup scope
+/// already enforces it.
+pub(crate) fn apply_rlimits(limits: &ResourceLimits, cgroup_enforced: bool) {
+    if !cgroup_enforced && limits.max_processes.is_some() {
+        eprintln!(
+            "codex-linux-sandbox: max_processes is not enforced without a cgroup v2 scope with the `pids` controller"
+        );
+    }
+    for rlimit in rlimits_for(limits, cgroup_enforced) {
+        if let Err(err) = set_rlimit(&rlimit) {
+            panic!("failed to apply {}: {err}", rlimit.name);
+        }
+    }
+}
+
+#[cfg(target_env = "gnu")]
+type RlimitResource = libc::__rlimit_resource_t;
+#[cfg(not(target_env = "gnu"))]
+type RlimitResource = libc::c_int;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Rlimit {
+    name: &'static str,
+    resource: RlimitResource,
+    soft: u64,
+    hard: u64,
+}
+
+impl Rlimit {
+    fn new(name: &'static str, resource: RlimitResource, limit: u64) -> Self {
+        Self {
+            name,
+            resource,
+            soft: limit,
+            hard: limit,
+        }
+    }
+}
+
+fn rlimits_for(limits: &ResourceLimits, cgroup_enforced: bool) -> Vec<Rlimit> {
+    let mut rlimits = Vec::new();
+    if let Some(max_cpu_seconds) = limits.max_cpu_seconds {
+        // The soft limit delivers SIGXCPU; the hard limit follows up with
+        // SIGKILL if the command ignores it.
+        rlimits.push(Rlimit {
+            hard: max_cpu_seconds.saturating_add(1),
+            ..Rlimit::new("RLIMIT_CPU", libc::RLIMIT_CPU, max_cpu_seconds)
+        });
+    }
+    if let Some(max_file_size_bytes) = limits.max_file_size_bytes {
+        rlimits.push(Rlimit::new(
+            "RLIMIT_FSIZE",
+            libc::RLIMIT_FSIZE,
+            max_file_size_bytes,
+        ));
+    }
+    if let Some(max_open_files) = limits.max_open_files {
+        rlimits.push(Rlimit::new(
+            "RLIMIT_NOFILE",
+            libc::RLIMIT_NOFILE,
+            max_open_files,
+        ));
+    }
+    if !cgroup_enforced && let Some(max_memory_bytes) = limits.max_memory_bytes {
+        rlimits.push(Rlimit::new(
+            "RLIMIT_DATA",
+            libc::RLIMIT_DATA,
+            max_memory_bytes,
+        ));
+    }
+    rlimits
+}
+
+fn set_rlimit(rlimit: &Rlimit) -> io::Result<()> {
+    let mut current = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    if unsafe { libc::getrlimit(rlimit.resource, &mut current) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // An unprivileged process cannot raise its hard limit, so never ask for
+    // more than is already allowed.
+    let hard = (rlimit.hard as libc::rlim_t).min(current.rlim_max);
+    let soft = (rlimit.soft as libc::rlim_t).min(hard);
+    let limit = libc::rlimit {
+        rlim_cur: soft,
+        rlim_max: hard,
+    };
+    if unsafe { libc::setrlimit(rlimit.resource, &limit) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn require_controller(controllers: &[&str], controller: &str) -> io::Result<()> {
+    if controllers.contains(&controller) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("the `{controller}` cgroup controller is not delegated"),
+        ))
+    }
+}
+
+/// Extracts the cgroup v2 path from the contents of `/proc/self/cgroup`.
+fn current_cgroup_path(proc_self_cgroup: &str) -> Option<&str> {
+    proc_self_cgroup
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .filter(|path| path.starts_with('/'))
+}
+
+fn read_event_counter(path: &Path, key: &str) -> u64 {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|events| parse_event_counter(&events, key))
+        .unwrap_or(0)
+}
+
+fn parse_event_counter(events: &str, key: &str) -> Option<u64> {
+    events.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        (name == key).then(|| value.trim().parse().ok())?
+    })
+}
+
+#[cfg(test)]
+#[path = "resource_limits_tests.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn current_cgroup_path_reads_unified_hierarchy_entry() {
+    let proc_self_cgroup = "\
+12:pids:/user.slice
+0::/user.slice/user-1000.slice/session-2.scope
+";
+    assert_eq!(
+        current_cgroup_path(proc_self_cgroup),
+        Some("/user.slice/user-1000.slice/session-2.scope")
+    );
+    assert_eq!(current_cgroup_path("12:pids:/user.slice\n"), None);
+}
+
+#[test]
+fn parse_event_counter_finds_named_counter() {
+    let memory_events = "low 0\nhigh 0\nmax 3\noom 1\noom_kill 1\noom_group_kill 0\n";
+    assert_eq!(parse_event_counter(memory_events, "oom_kill"), Some(1));
+    assert_eq!(parse_event_counter(memory_events, "max"), Some(3));
+    assert_eq!(parse_event_counter(memory_events, "missing"), None);
+}
+
+#[test]
+fn rlimits_fall_back_for_memory_but_not_processes_without_cgroup() {
+    let limits = ResourceLimits {
+        max_memory_bytes: Some(1 << 30),
+        max_cpu_seconds: Some(10),
+        max_processes: Some(64),
+        max_open_files: Some(256),
+        max_file_size_bytes: Some(1 << 20),
+    };
+
+    let names = |cgroup_enforced| {
+        rlimits_for(&limits, cgroup_enforced)
+            .into_iter()
+            .map(|rlimit| rlimit.name)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(/*cgroup_enforced*/ false),
+        vec!["RLIMIT_CPU", "RLIMIT_FSIZE", "RLIMIT_NOFILE", "RLIMIT_DATA"]
+    );
+    assert_eq!(
+        names(/*cgroup_enforced*/ true),
+        vec!["RLIMIT_CPU", "RLIMIT_FSIZE", "RLIMIT_NOFILE"]
+    );
+    assert_eq!(
+        rlimits_for(&limits, /*cgroup_enforced*/ true)[0],
+        Rlimit {
+            name: "RLIMIT_CPU",
+            resource: libc::RLIMIT_CPU,
+            soft: 10,
+            hard: 11,
+        }
+    );
+}
+
+#[test]
+fn cgroup_scope_is_skipped_without_memory_or_process_limits() {
+    let limits = ResourceLimits {
+        max_cpu_seconds: Some(10),
+        ..Default::default()
+    };
+    assert!(CgroupScope::create(&limits).is_none());
+}
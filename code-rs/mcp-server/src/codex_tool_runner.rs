@@ -9,6 +9,7 @@ use crate::exec_approval::handle_exec_approval_request;
 use crate::outgoing_message::OutgoingMessageSender;
 use crate::outgoing_message::OutgoingNotificationMeta;
 use crate::patch_approval::handle_patch_approval_request;
+use crate::thread_resources::ThreadResources;
 use codex_core::CodexThread;
 use codex_core::NewThread;
 use codex_core::ThreadManager;
@@ -62,6 +63,7 @@ pub async fn run_codex_tool_session(
     config: CodexConfig,
     outgoing: Arc<OutgoingMessageSender>,
     thread_manager: Arc<ThreadManager>,
+    thread_resources: Arc<ThreadResources>,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
 ) {
     let NewThread {
@@ -81,6 +83,7 @@ pub async fn run_codex_tool_session(
             return;
         }
     };
+    thread_resources.thread_started().await;
 
     let session_configured_event = Event {
         // Use a fake id value for now.
@@ -137,6 +140,7 @@ pub async fn run_codex_tool_session(
         thread_id,
         thread,
         outgoing,
+        thread_resources,
         id,
         running_requests_id_to_codex_uuid,
     )
@@ -147,6 +151,7 @@ pub async fn run_codex_tool_session_reply(
     thread_id: ThreadId,
     thread: Arc<CodexThread>,
     outgoing: Arc<OutgoingMessageSender>,
+    thread_resources: Arc<ThreadResources>,
     request_id: RequestId,
     prompt: String,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
@@ -187,6 +192,7 @@ pub async fn run_codex_tool_session_reply(
         thread_id,
         thread,
         outgoing,
+        thread_resources,
         request_id,
         running_requests_id_to_codex_uuid,
     )
@@ -197,6 +203,7 @@ async fn run_codex_tool_session_inner(
     thread_id: ThreadId,
     thread: Arc<CodexThread>,
     outgoing: Arc<OutgoingMessageSender>,
+    thread_resources: Arc<ThreadResources>,
     request_id: RequestId,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
 ) {
@@ -216,6 +223,7 @@ async fn run_codex_tool_session_inner(
                         }),
                     )
                     .await;
+                thread_resources.observe_event(thread_id, &event.msg).await;
 
                 match event.msg {
                     EventMsg::ExecApprovalRequest(ev) => {
@@ -323,7 +331,11 @@ async fn run_codex_tool_session_inner(
                     EventMsg::ThreadGoalUpdated(_) => {
                         // Ignore thread goal metadata updates in MCP tool runner.
                     }
-                    EventMsg::McpStartupUpdate(_) | EventMsg::McpStartupComplete(_) => {
+                    EventMsg::McpStartupUpdate(_)
+                    | EventMsg::McpStartupComplete(_)
+                    | EventMsg::McpPromptListChanged(_)
+                    | EventMsg::McpToolListChanged(_)
+                    | EventMsg::McpResourceListChanged(_) => {
                         // Ignored in MCP tool runner.
                     }
                     EventMsg::AgentMessage(AgentMessageEvent { .. }) => {
@@ -0,0 +1,598 @@
+//! MCP streamable HTTP transport.
+//!
+//! Each session gets its own [`MessageProcessor`], so resource subscriptions
+//! stay per client while threads are shared through [`SharedServerState`]. A
+//! POST that carries a request is answered with an SSE stream holding the
+//! `codex/event` notifications tagged with that request, followed by its
+//! response. Everything else goes to the session's GET stream, or to an
+//! in-flight POST stream when the client has not opened one.
+//!
+//! Clients that disappear without `DELETE` would otherwise keep their session
+//! forever, so sessions left idle with no open stream are closed, and the
+//! number of open sessions is capped.
+
+use std::collections::HashMap;
+use std::io::Result as IoResult;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+use axum::Router;
+use axum::body::Body;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::HeaderName;
+use axum::http::HeaderValue;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::http::header::AUTHORIZATION;
+use axum::http::header::ORIGIN;
+use axum::http::header::WWW_AUTHENTICATE;
+use axum::middleware;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::response::sse::Event;
+use axum::response::sse::KeepAlive;
+use axum::response::sse::Sse;
+use axum::routing::get;
+use axum::routing::post;
+use constant_time_eq::constant_time_eq;
+use futures::StreamExt;
+use rmcp::model::ClientRequest;
+use rmcp::model::JsonRpcMessage;
+use rmcp::model::RequestId;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::debug;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::CHANNEL_CAPACITY;
+use crate::IncomingMessage;
+use crate::message_processor::MessageProcessor;
+use crate::message_processor::SharedServerState;
+use crate::outgoing_message::OutgoingJsonRpcMessage;
+use crate::outgoing_message::OutgoingMessage;
+use crate::outgoing_message::OutgoingMessageSender;
+use crate::run_message_processor;
+
+const MCP_ENDPOINT_PATH: &str = "/mcp";
+const MCP_SESSION_ID_HEADER: &str = "mcp-session-id";
+/// How long a session may go without a request or an open stream.
+const HTTP_SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const HTTP_SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+/// `initialize` is refused with `503` while this many sessions are open.
+const MAX_HTTP_SESSIONS: usize = 64;
+
+type StreamSender = mpsc::UnboundedSender<OutgoingJsonRpcMessage>;
+
+struct HttpListenerState {
+    shared: SharedServerState,
+    bearer_token: Option<String>,
+    sessions: Mutex<HashMap<String, Arc<HttpSession>>>,
+}
+
+struct HttpSession {
+    id: String,
+    incoming_tx: mpsc::Sender<IncomingMessage>,
+    streams: Arc<Mutex<SessionStreams>>,
+    tasks: Vec<JoinHandle<()>>,
+    last_active: Mutex<Instant>,
+}
+
+impl HttpSession {
+    fn touch(&self, now: Instant) {
+        *self
+            .last_active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        let last_active = *self
+            .last_active
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        now.saturating_duration_since(last_active) >= HTTP_SESSION_IDLE_TIMEOUT
+            && !self
+                .streams
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .has_open_stream()
+    }
+}
+
+impl Drop for HttpSession {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+/// Open SSE streams of one session.
+#[derive(Default)]
+struct SessionStreams {
+    /// POST streams keyed by the request they answer.
+    requests: HashMap<RequestId, StreamSender>,
+    /// The stream opened with GET, if any.
+    standalone: Option<StreamSender>,
+}
+
+impl SessionStreams {
+    fn has_open_stream(&self) -> bool {
+        self.standalone
+            .iter()
+            .chain(self.requests.values())
+            .any(|stream| !stream.is_closed())
+    }
+
+    fn route(&mut self, message: OutgoingMessage) {
+        let related_request_id = related_request_id(&message);
+        let mut message = OutgoingJsonRpcMessage::from(message);
+
+        // The response is the last message of its request's stream; dropping
+        // the sender ends the stream.
+        if matches!(
+            message,
+            JsonRpcMessage::Response(_) | JsonRpcMessage::Error(_)
+        ) {
+            match related_request_id.and_then(|id| self.requests.remove(&id)) {
+                Some(stream) => {
+                    let _ = stream.send(message);
+                }
+                None => debug!("dropping response for a request without an open stream"),
+            }
+            return;
+        }
+
+        if let Some(stream) = related_request_id.and_then(|id| self.requests.get(&id)) {
+            match stream.send(message) {
+                Ok(()) => return,
+                Err(err) => message = err.0,
+            }
+        }
+        if let Some(stream) = self.standalone.take() {
+            match stream.send(message) {
+                Ok(()) => {
+                    self.standalone = Some(stream);
+                    return;
+                }
+                Err(err) => message = err.0,
+            }
+        }
+        self.requests.retain(|_, stream| !stream.is_closed());
+        match self.requests.values().next() {
+            Some(stream) => {
+                let _ = stream.send(message);
+            }
+            None => debug!("dropping server message; the client has no open stream"),
+        }
+    }
+}
+
+fn remove_idle_sessions(sessions: &mut HashMap<String, Arc<HttpSession>>, now: Instant) {
+    sessions.retain(|session_id, session| {
+        let idle = session.is_idle(now);
+        if idle {
+            info!(%session_id, "mcp-server HTTP session closed after being idle");
+        }
+        !idle
+    });
+}
+
+/// Returns the client request a message belongs to: the request a response
+/// answers, or the `_meta.requestId` of a `codex/event` notification.
+fn related_request_id(message: &OutgoingMessage) -> Option<RequestId> {
+    match message {
+        OutgoingMessage::Response(response) => Some(response.id.clone()),
+        OutgoingMessage::Error(error) => Some(error.id.clone()),
+        OutgoingMessage::Notification(notification) => notification
+            .params
+            .as_ref()
+            .and_then(|params| params.get("_meta"))
+            .and_then(|meta| meta.get("requestId"))
+            .and_then(|request_id| serde_json::from_value(request_id.clone()).ok()),
+        OutgoingMessage::Request(_) => None,
+    }
+}
+
+#[allow(clippy::print_stderr)]
+fn print_startup_banner(addr: SocketAddr) {
+    eprintln!("codex mcp-server (streamable HTTP) listening on http://{addr}{MCP_ENDPOINT_PATH}");
+}
+
+pub(crate) async fn run_streamable_http_server(
+    bind_address: SocketAddr,
+    bearer_token: Option<String>,
+    shared: SharedServerState,
+) -> IoResult<()> {
+    if !bind_address.ip().is_loopback() && bearer_token.is_none() {
+        warn!(
+            %bind_address,
+            "starting non-loopback MCP listener without auth because `--allow-unauthenticated-non-loopback` was passed"
+        );
+    }
+    let listener = TcpListener::bind(bind_address).await?;
+    let local_addr = listener.local_addr()?;
+    print_startup_banner(local_addr);
+    info!("mcp-server listening on http://{local_addr}{MCP_ENDPOINT_PATH}");
+
+    let state = Arc::new(HttpListenerState {
+        shared,
+        bearer_token,
+        sessions: Mutex::new(HashMap::new()),
+    });
+    let router = Router::new()
+        .route(
+            MCP_ENDPOINT_PATH,
+            post(post_handler).get(get_handler).delete(delete_handler),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_bearer_token,
+        ))
+        .route("/healthz", get(health_check_handler))
+        .layer(middleware::from_fn(reject_requests_with_origin_header))
+        .with_state(state.clone());
+    let sweeper = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HTTP_SESSION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let mut sessions = state
+                .sessions
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            remove_idle_sessions(&mut sessions, Instant::now());
+        }
+    });
+
+    let result = axum::serve(listener, router)
+        .with_graceful_shutdown(async {
+            if let Err(err) = tokio::signal::ctrl_c().await {
+                error!("failed to listen for ctrl-c: {err}");
+            }
+        })
+        .await;
+    sweeper.abort();
+    result?;
+    info!("mcp-server HTTP listener shutting down");
+    Ok(())
+}
+
+async fn health_check_handler() -> StatusCode {
+    StatusCode::OK
+}
+
+async fn reject_requests_with_origin_header(
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if request.headers().contains_key(ORIGIN) {
+        warn!(
+            method = %request.method(),
+            uri = %request.uri(),
+            "rejecting mcp-server request with Origin header"
+        );
+        Err(StatusCode::FORBIDDEN)
+    } else {
+        Ok(next.run(request).await)
+    }
+}
+
+async fn require_bearer_token(
+    State(state): State<Arc<HttpListenerState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(expected) = state.bearer_token.as_deref() else {
+        return next.run(request).await;
+    };
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| constant_time_eq(token.trim().as_bytes(), expected.as_bytes()));
+    if authorized {
+        next.run(request).await
+    } else {
+        warn!(uri = %request.uri(), "rejecting mcp-server request without a valid bearer token");
+        (StatusCode::UNAUTHORIZED, [(WWW_AUTHENTICATE, "Bearer")]).into_response()
+    }
+}
+
+async fn post_handler(
+    State(state): State<Arc<HttpListenerState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let message = match serde_json::from_slice::<IncomingMessage>(&body) {
+        Ok(message) => message,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("invalid JSON-RPC message: {err}"),
+            )
+                .into_response();
+        }
+    };
+    let is_initialize = matches!(
+        &message,
+        JsonRpcMessage::Request(request)
+            if matches!(request.request, ClientRequest::InitializeRequest(_))
+    );
+    let session = if is_initialize && !headers.contains_key(MCP_SESSION_ID_HEADER) {
+        match state.start_session() {
+            Some(session) => session,
+            None => {
+                return (StatusCode::SERVICE_UNAVAILABLE, "too many open sessions").into_response();
+            }
+        }
+    } else {
+        match state.session_for(&headers) {
+            Ok(session) => session,
+            Err(response) => return response,
+        }
+    };
+
+    let stream = match &message {
+        JsonRpcMessage::Request(request) => {
+            let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+            session
+                .streams
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .requests
+                .insert(request.id.clone(), stream_tx);
+            Some(stream_rx)
+        }
+        JsonRpcMessage::Response(_)
+        | JsonRpcMessage::Notification(_)
+        | JsonRpcMessage::Error(_) => None,
+    };
+    if session.incoming_tx.send(message).await.is_err() {
+        return (StatusCode::NOT_FOUND, "session closed").into_response();
+    }
+    match stream {
+        Some(stream_rx) => sse_response(&session.id, stream_rx),
+        None => StatusCode::ACCEPTED.into_response(),
+    }
+}
+
+async fn get_handler(State(state): State<Arc<HttpListenerState>>, headers: HeaderMap) -> Response {
+    let session = match state.session_for(&headers) {
+        Ok(session) => session,
+        Err(response) => return response,
+    };
+    let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+    session
+        .streams
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .standalone = Some(stream_tx);
+    sse_response(&session.id, stream_rx)
+}
+
+async fn delete_handler(
+    State(state): State<Arc<HttpListenerState>>,
+    headers: HeaderMap,
+) -> Response {
+    let session = match state.session_for(&headers) {
+        Ok(session) => session,
+        Err(response) => return response,
+    };
+    state
+        .sessions
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&session.id);
+    info!(session_id = %session.id, "mcp-server HTTP session closed by client");
+    StatusCode::NO_CONTENT.into_response()
+}
+
+fn sse_response(
+    session_id: &str,
+    stream_rx: mpsc::UnboundedReceiver<OutgoingJsonRpcMessage>,
+) -> Response {
+    let events = UnboundedReceiverStream::new(stream_rx)
+        .map(|message| Event::default().event("message").json_data(message));
+    let mut response = Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response();
+    if let Ok(session_id) = HeaderValue::from_str(session_id) {
+        response
+            .headers_mut()
+            .insert(HeaderName::from_static(MCP_SESSION_ID_HEADER), session_id);
+    }
+    response
+}
+
+impl HttpListenerState {
+    fn start_session(&self) -> Option<Arc<HttpSession>> {
+        let now = Instant::now();
+        let mut sessions = self.sessions.lock().unwrap_or_else(PoisonError::into_inner);
+        remove_idle_sessions(&mut sessions, now);
+        if sessions.len() >= MAX_HTTP_SESSIONS {
+            warn!("refusing mcp-server HTTP session; {MAX_HTTP_SESSIONS} sessions are open");
+            return None;
+        }
+
+        let (incoming_tx, incoming_rx) = mpsc::channel::<IncomingMessage>(CHANNEL_CAPACITY);
+        let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded_channel::<OutgoingMessage>();
+        let processor =
+            MessageProcessor::new(OutgoingMessageSender::new(outgoing_tx), &self.shared);
+        let streams = Arc::new(Mutex::new(SessionStreams::default()));
+        let router_handle = tokio::spawn({
+            let streams = streams.clone();
+            async move {
+                while let Some(message) = outgoing_rx.recv().await {
+                    streams
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .route(message);
+                }
+            }
+        });
+        let session = Arc::new(HttpSession {
+            id: Uuid::new_v4().to_string(),
+            incoming_tx,
+            streams,
+            tasks: vec![
+                tokio::spawn(run_message_processor(processor, incoming_rx)),
+                router_handle,
+            ],
+            last_active: Mutex::new(now),
+        });
+        info!(session_id = %session.id, "mcp-server HTTP session started");
+        sessions.insert(session.id.clone(), session.clone());
+        Some(session)
+    }
+
+    fn session_for(&self, headers: &HeaderMap) -> Result<Arc<HttpSession>, Response> {
+        let Some(session_id) = headers
+            .get(MCP_SESSION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        else {
+            return Err((StatusCode::BAD_REQUEST, "missing Mcp-Session-Id header").into_response());
+        };
+        let session = self
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(session_id)
+            .cloned()
+            .ok_or_else(|| (StatusCode::NOT_FOUND, "unknown session").into_response())?;
+        session.touch(Instant::now());
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outgoing_message::OutgoingNotification;
+    use crate::outgoing_message::OutgoingResponse;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn notification(request_id: Option<i64>) -> OutgoingMessage {
+        OutgoingMessage::Notification(OutgoingNotification {
+            method: "codex/event".to_string(),
+            params: Some(match request_id {
+                Some(request_id) => json!({ "_meta": { "requestId": request_id } }),
+                None => json!({}),
+            }),
+        })
+    }
+
+    fn method(message: OutgoingJsonRpcMessage) -> String {
+        serde_json::to_value(message).expect("message serializes")["method"]
+            .as_str()
+            .unwrap_or("response")
+            .to_string()
+    }
+
+    #[test]
+    fn request_stream_gets_its_events_and_ends_with_the_response() {
+        let mut streams = SessionStreams::default();
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        let (standalone_tx, mut standalone_rx) = mpsc::unbounded_channel();
+        streams.requests.insert(RequestId::Number(1), request_tx);
+        streams.standalone = Some(standalone_tx);
+
+        streams.route(notification(Some(1)));
+        streams.route(notification(/*request_id*/ None));
+        streams.route(OutgoingMessage::Response(OutgoingResponse {
+            id: RequestId::Number(1),
+            result: json!({}),
+        }));
+
+        let request_messages = std::iter::from_fn(|| request_rx.try_recv().ok())
+            .map(method)
+            .collect::<Vec<_>>();
+        assert_eq!(request_messages, vec!["codex/event", "response"]);
+        assert!(matches!(
+            request_rx.try_recv(),
+            Err(mpsc::error::TryRecvError::Disconnected)
+        ));
+        assert_eq!(
+            standalone_rx.try_recv().map(method).ok(),
+            Some("codex/event".to_string())
+        );
+    }
+
+    fn session(last_active: Instant) -> Arc<HttpSession> {
+        let (incoming_tx, _incoming_rx) = mpsc::channel(1);
+        Arc::new(HttpSession {
+            id: Uuid::new_v4().to_string(),
+            incoming_tx,
+            streams: Arc::new(Mutex::new(SessionStreams::default())),
+            tasks: Vec::new(),
+            last_active: Mutex::new(last_active),
+        })
+    }
+
+    #[test]
+    fn idle_sessions_without_an_open_stream_are_removed() {
+        let start = Instant::now();
+        let now = start + HTTP_SESSION_IDLE_TIMEOUT;
+        let streaming = session(start);
+        let (standalone_tx, _standalone_rx) = mpsc::unbounded_channel();
+        streaming
+            .streams
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .standalone = Some(standalone_tx);
+        let mut sessions = HashMap::from([
+            ("stale".to_string(), session(start)),
+            ("recent".to_string(), session(now)),
+            ("streaming".to_string(), streaming),
+        ]);
+
+        remove_idle_sessions(&mut sessions, now);
+
+        let mut remaining = sessions.into_keys().collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(remaining, vec!["recent", "streaming"]);
+    }
+
+    #[test]
+    fn closed_streams_do_not_keep_a_session_open() {
+        let start = Instant::now();
+        let session = session(start);
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        session
+            .streams
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .requests
+            .insert(RequestId::Number(1), request_tx);
+        drop(request_rx);
+
+        assert!(session.is_idle(start + HTTP_SESSION_IDLE_TIMEOUT));
+    }
+
+    #[test]
+    fn unrelated_messages_fall_back_to_an_open_request_stream() {
+        let mut streams = SessionStreams::default();
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        streams.requests.insert(RequestId::Number(1), request_tx);
+
+        streams.route(notification(/*request_id*/ None));
+
+        assert_eq!(
+            request_rx.try_recv().map(method).ok(),
+            Some("codex/event".to_string())
+        );
+    }
+}
@@ -32,11 +32,16 @@ use tracing_subscriber::prelude::*;
 mod codex_tool_config;
 mod codex_tool_runner;
 mod exec_approval;
+mod http_transport;
 pub(crate) mod message_processor;
 mod outgoing_message;
 mod patch_approval;
+mod prompts;
+mod thread_resources;
+mod transport;
 
 use crate::message_processor::MessageProcessor;
+use crate::message_processor::SharedServerState;
 use crate::outgoing_message::OutgoingJsonRpcMessage;
 use crate::outgoing_message::OutgoingMessage;
 use crate::outgoing_message::OutgoingMessageSender;
@@ -47,6 +52,9 @@ pub use crate::exec_approval::ExecApprovalElicitRequestParams;
 pub use crate::exec_approval::ExecApprovalResponse;
 pub use crate::patch_approval::PatchApprovalElicitRequestParams;
 pub use crate::patch_approval::PatchApprovalResponse;
+pub use crate::transport::McpServerArgs;
+pub use crate::transport::McpServerTransport;
+pub use crate::transport::McpServerTransportParseError;
 
 /// Size of the bounded channels used to communicate between tasks. The value
 /// is a balance between throughput and memory usage – 128 messages should be
@@ -60,7 +68,9 @@ type IncomingMessage = JsonRpcMessage<ClientRequest, Value, ClientNotification>;
 pub async fn run_main(
     arg0_paths: Arg0DispatchPaths,
     cli_config_overrides: CliConfigOverrides,
+    args: McpServerArgs,
 ) -> IoResult<()> {
+    let bearer_token = args.bearer_token()?;
     let environment_manager = Arc::new(
         EnvironmentManager::new(EnvironmentManagerArgs::new(
             ExecServerRuntimePaths::from_optional_paths(
@@ -111,10 +121,28 @@ pub async fn run_main(
         .with(otel_tracing_layer)
         .try_init();
 
+    let installation_id = resolve_installation_id(&config.codex_home).await?;
+    let shared = SharedServerState::new(
+        arg0_paths,
+        Arc::new(config),
+        environment_manager,
+        state_db,
+        installation_id,
+    )
+    .await;
+
+    match args.listen {
+        McpServerTransport::Stdio => run_stdio_transport(shared).await,
+        McpServerTransport::StreamableHttp { bind_address } => {
+            http_transport::run_streamable_http_server(bind_address, bearer_token, shared).await
+        }
+    }
+}
+
+async fn run_stdio_transport(shared: SharedServerState) -> IoResult<()> {
     // Set up channels.
-    let (incoming_tx, mut incoming_rx) = mpsc::channel::<IncomingMessage>(CHANNEL_CAPACITY);
+    let (incoming_tx, incoming_rx) = mpsc::channel::<IncomingMessage>(CHANNEL_CAPACITY);
     let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded_channel::<OutgoingMessage>();
-    let installation_id = resolve_installation_id(&config.codex_home).await?;
 
     // Task: read from stdin, push to `incoming_tx`.
     let stdin_reader_handle = tokio::spawn({
@@ -140,30 +168,8 @@ pub async fn run_main(
     });
 
     // Task: process incoming messages.
-    let processor_handle = tokio::spawn({
-        let outgoing_message_sender = OutgoingMessageSender::new(outgoing_tx);
-        let mut processor = MessageProcessor::new(
-            outgoing_message_sender,
-            arg0_paths,
-            Arc::new(config),
-            environment_manager,
-            state_db,
-            installation_id,
-        )
-        .await;
-        async move {
-            while let Some(msg) = incoming_rx.recv().await {
-                match msg {
-                    JsonRpcMessage::Request(r) => processor.process_request(r).await,
-                    JsonRpcMessage::Response(r) => processor.process_response(r).await,
-                    JsonRpcMessage::Notification(n) => processor.process_notification(n).await,
-                    JsonRpcMessage::Error(e) => processor.process_error(e),
-                }
-            }
-
-            info!("processor task exited (channel closed)");
-        }
-    });
+    let processor = MessageProcessor::new(OutgoingMessageSender::new(outgoing_tx), &shared);
+    let processor_handle = tokio::spawn(run_message_processor(processor, incoming_rx));
 
     // Task: write outgoing messages to stdout.
     let stdout_writer_handle = tokio::spawn(async move {
@@ -196,6 +202,23 @@ pub async fn run_main(
     Ok(())
 }
 
+/// Feeds one client's messages to its processor until the client goes away.
+pub(crate) async fn run_message_processor(
+    mut processor: MessageProcessor,
+    mut incoming_rx: mpsc::Receiver<IncomingMessage>,
+) {
+    while let Some(msg) = incoming_rx.recv().await {
+        match msg {
+            JsonRpcMessage::Request(r) => processor.process_request(r).await,
+            JsonRpcMessage::Response(r) => processor.process_response(r).await,
+            JsonRpcMessage::Notification(n) => processor.process_notification(n).await,
+            JsonRpcMessage::Error(e) => processor.process_error(e),
+        }
+    }
+
+    info!("processor task exited (channel closed)");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,11 +1,20 @@
+use clap::Parser;
 use codex_arg0::Arg0DispatchPaths;
 use codex_arg0::arg0_dispatch_or_else;
+use codex_mcp_server::McpServerArgs;
 use codex_mcp_server::run_main;
 use codex_utils_cli::CliConfigOverrides;
 
+#[derive(Debug, Parser)]
+struct McpServerCli {
+    #[command(flatten)]
+    args: McpServerArgs,
+}
+
 fn main() -> anyhow::Result<()> {
     arg0_dispatch_or_else(|arg0_paths: Arg0DispatchPaths| async move {
-        run_main(arg0_paths, CliConfigOverrides::default()).await?;
+        let McpServerCli { args } = McpServerCli::parse();
+        run_main(arg0_paths, CliConfigOverrides::default(), args).await?;
         Ok(())
     })
 }
@@ -13,6 +13,7 @@ use codex_login::default_client::get_codex_user_agent;
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::Submission;
+use codex_thread_store::ThreadStore;
 use rmcp::model::CallToolRequestParams;
 use rmcp::model::CallToolResult;
 use rmcp::model::ClientNotification;
@@ -27,7 +28,6 @@ use rmcp::model::JsonRpcRequest;
 use rmcp::model::JsonRpcResponse;
 use rmcp::model::RequestId;
 use rmcp::model::ServerCapabilities;
-use rmcp::model::ToolsCapability;
 use serde_json::json;
 use tokio::sync::Mutex;
 use tokio::task;
@@ -37,47 +37,80 @@ use crate::codex_tool_config::CodexToolCallReplyParam;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_param;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_reply_param;
 use crate::outgoing_message::OutgoingMessageSender;
-
-pub(crate) struct MessageProcessor {
-    outgoing: Arc<OutgoingMessageSender>,
-    initialized: bool,
+use crate::prompts;
+use crate::thread_resources::ThreadResources;
+
+/// State shared by every client of this server process. The stdio transport
+/// serves a single client; the HTTP transport hands the same threads to every
+/// session so clients can pick up each other's threads with `codex-reply`.
+#[derive(Clone)]
+pub(crate) struct SharedServerState {
     arg0_paths: Arg0DispatchPaths,
+    config: Arc<Config>,
     thread_manager: Arc<ThreadManager>,
-    running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    thread_store: Arc<dyn ThreadStore>,
 }
 
-impl MessageProcessor {
-    /// Create a new `MessageProcessor`, retaining a handle to the outgoing
-    /// `Sender` so handlers can enqueue messages to be written to stdout.
+impl SharedServerState {
     pub(crate) async fn new(
-        outgoing: OutgoingMessageSender,
         arg0_paths: Arg0DispatchPaths,
         config: Arc<Config>,
         environment_manager: Arc<EnvironmentManager>,
         state_db: Option<StateDbHandle>,
         installation_id: String,
     ) -> Self {
-        let outgoing = Arc::new(outgoing);
         let auth_manager = AuthManager::shared_from_config(
             config.as_ref(),
             /*enable_codex_api_key_env*/ false,
         )
         .await;
+        let thread_store = thread_store_from_config(config.as_ref(), state_db.clone());
         let thread_manager = Arc::new(ThreadManager::new(
             config.as_ref(),
             auth_manager,
             SessionSource::Mcp,
             environment_manager,
             /*analytics_events_client*/ None,
-            thread_store_from_config(config.as_ref(), state_db.clone()),
-            state_db.clone(),
+            Arc::clone(&thread_store),
+            state_db,
             installation_id,
         ));
         Self {
-            outgoing,
-            initialized: false,
             arg0_paths,
+            config,
             thread_manager,
+            thread_store,
+        }
+    }
+}
+
+pub(crate) struct MessageProcessor {
+    outgoing: Arc<OutgoingMessageSender>,
+    initialized: bool,
+    arg0_paths: Arg0DispatchPaths,
+    config: Arc<Config>,
+    thread_manager: Arc<ThreadManager>,
+    thread_resources: Arc<ThreadResources>,
+    running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+}
+
+impl MessageProcessor {
+    /// Create a new `MessageProcessor` for one client, retaining a handle to
+    /// the outgoing `Sender` so handlers can enqueue messages for it.
+    pub(crate) fn new(outgoing: OutgoingMessageSender, shared: &SharedServerState) -> Self {
+        let outgoing = Arc::new(outgoing);
+        let thread_resources = Arc::new(ThreadResources::new(
+            outgoing.clone(),
+            shared.thread_manager.clone(),
+            shared.thread_store.clone(),
+        ));
+        Self {
+            outgoing,
+            initialized: false,
+            arg0_paths: shared.arg0_paths.clone(),
+            config: shared.config.clone(),
+            thread_manager: shared.thread_manager.clone(),
+            thread_resources,
             running_requests_id_to_codex_uuid: Arc::new(Mutex::new(HashMap::new())),
         }
     }
@@ -94,25 +127,26 @@ impl MessageProcessor {
                 self.handle_ping(request_id).await;
             }
             ClientRequest::ListResourcesRequest(params) => {
-                self.handle_list_resources(params.params);
+                self.handle_list_resources(request_id, params.params).await;
             }
             ClientRequest::ListResourceTemplatesRequest(params) => {
-                self.handle_list_resource_templates(params.params);
+                self.handle_list_resource_templates(request_id, params.params)
+                    .await;
             }
             ClientRequest::ReadResourceRequest(params) => {
-                self.handle_read_resource(params.params);
+                self.handle_read_resource(request_id, params.params).await;
             }
             ClientRequest::SubscribeRequest(params) => {
-                self.handle_subscribe(params.params);
+                self.handle_subscribe(request_id, params.params).await;
             }
             ClientRequest::UnsubscribeRequest(params) => {
-                self.handle_unsubscribe(params.params);
+                self.handle_unsubscribe(request_id, params.params).await;
             }
             ClientRequest::ListPromptsRequest(params) => {
-                self.handle_list_prompts(params.params);
+                self.handle_list_prompts(request_id, params.params).await;
             }
             ClientRequest::GetPromptRequest(params) => {
-                self.handle_get_prompt(params.params);
+                self.handle_get_prompt(request_id, params.params).await;
             }
             ClientRequest::ListToolsRequest(params) => {
                 self.handle_list_tools(request_id, params.params).await;
@@ -246,12 +280,14 @@ impl MessageProcessor {
         }
 
         let mut result_value = match serde_json::to_value(InitializeResult {
-            capabilities: ServerCapabilities {
-                tools: Some(ToolsCapability {
-                    list_changed: Some(true),
-                }),
-                ..Default::default()
-            },
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_tool_list_changed()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_resources_list_changed()
+                .enable_prompts()
+                .build(),
             instructions: None,
             protocol_version: params.protocol_version.clone(),
             server_info,
@@ -284,32 +320,77 @@ impl MessageProcessor {
         self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_list_resources(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_resources(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("resources/list -> params: {:?}", params);
+        let cursor = params.and_then(|params| params.cursor);
+        match self.thread_resources.list(cursor).await {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(error) => self.outgoing.send_error(id, error).await,
+        }
     }
 
-    fn handle_list_resource_templates(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_resource_templates(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("resources/templates/list -> params: {:?}", params);
+        let result = self.thread_resources.list_templates();
+        self.outgoing.send_response(id, result).await;
     }
 
-    fn handle_read_resource(&self, params: rmcp::model::ReadResourceRequestParams) {
+    async fn handle_read_resource(
+        &self,
+        id: RequestId,
+        params: rmcp::model::ReadResourceRequestParams,
+    ) {
         tracing::info!("resources/read -> params: {:?}", params);
+        match self.thread_resources.read(&params.uri).await {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(error) => self.outgoing.send_error(id, error).await,
+        }
     }
 
-    fn handle_subscribe(&self, params: rmcp::model::SubscribeRequestParams) {
+    async fn handle_subscribe(&self, id: RequestId, params: rmcp::model::SubscribeRequestParams) {
         tracing::info!("resources/subscribe -> params: {:?}", params);
+        match self.thread_resources.subscribe(&params.uri).await {
+            Ok(()) => self.outgoing.send_response(id, json!({})).await,
+            Err(error) => self.outgoing.send_error(id, error).await,
+        }
     }
 
-    fn handle_unsubscribe(&self, params: rmcp::model::UnsubscribeRequestParams) {
+    async fn handle_unsubscribe(
+        &self,
+        id: RequestId,
+        params: rmcp::model::UnsubscribeRequestParams,
+    ) {
         tracing::info!("resources/unsubscribe -> params: {:?}", params);
+        match self.thread_resources.unsubscribe(&params.uri).await {
+            Ok(()) => self.outgoing.send_response(id, json!({})).await,
+            Err(error) => self.outgoing.send_error(id, error).await,
+        }
     }
 
-    fn handle_list_prompts(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_prompts(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("prompts/list -> params: {:?}", params);
+        let result = prompts::list_prompts(&self.config, &self.thread_manager).await;
+        self.outgoing.send_response(id, result).await;
     }
 
-    fn handle_get_prompt(&self, params: rmcp::model::GetPromptRequestParams) {
+    async fn handle_get_prompt(&self, id: RequestId, params: rmcp::model::GetPromptRequestParams) {
         tracing::info!("prompts/get -> params: {:?}", params);
+        match prompts::get_prompt(&self.config, &self.thread_manager, params).await {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(error) => self.outgoing.send_error(id, error).await,
+        }
     }
 
     async fn handle_list_tools(
@@ -407,6 +488,7 @@ impl MessageProcessor {
         // Clone outgoing and server to move into async task.
         let outgoing = self.outgoing.clone();
         let thread_manager = self.thread_manager.clone();
+        let thread_resources = self.thread_resources.clone();
         let running_requests_id_to_codex_uuid = self.running_requests_id_to_codex_uuid.clone();
 
         // Spawn an async task to handle the Codex session so that we do not
@@ -419,6 +501,7 @@ impl MessageProcessor {
                 config,
                 outgoing,
                 thread_manager,
+                thread_resources,
                 running_requests_id_to_codex_uuid,
             )
             .await;
@@ -487,6 +570,7 @@ impl MessageProcessor {
 
         // Clone outgoing to move into async task.
         let outgoing = self.outgoing.clone();
+        let thread_resources = self.thread_resources.clone();
         let running_requests_id_to_codex_uuid = self.running_requests_id_to_codex_uuid.clone();
 
         let codex = match self.thread_manager.get_thread(thread_id).await {
@@ -514,6 +598,7 @@ impl MessageProcessor {
                     thread_id,
                     codex,
                     outgoing,
+                    thread_resources,
                     request_id,
                     prompt,
                     running_requests_id_to_codex_uuid,
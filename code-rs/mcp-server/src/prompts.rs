@@ -0,0 +1,191 @@
+//! Skills and review presets exposed as MCP prompts.
+//!
+//! Each enabled skill becomes a `skill:{name}` prompt whose message mentions
+//! the skill, so passing it to the `codex` tool injects the skill into the
+//! turn. The `review-*` prompts render the same instructions as `/review`.
+
+use codex_core::TOOL_MENTION_SIGIL;
+use codex_core::ThreadManager;
+use codex_core::config::Config;
+use codex_core::review_prompts::review_prompt;
+use codex_core::review_prompts::user_facing_hint;
+use codex_core::skills::SkillsLoadInput;
+use codex_protocol::protocol::ReviewTarget;
+use rmcp::model::ErrorData;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
+use rmcp::model::ListPromptsResult;
+use rmcp::model::Prompt;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
+use serde_json::json;
+
+const SKILL_PROMPT_PREFIX: &str = "skill:";
+const REVIEW_UNCOMMITTED_PROMPT: &str = "review-uncommitted";
+const REVIEW_BASE_BRANCH_PROMPT: &str = "review-base-branch";
+const REVIEW_COMMIT_PROMPT: &str = "review-commit";
+
+pub(crate) async fn list_prompts(
+    config: &Config,
+    thread_manager: &ThreadManager,
+) -> ListPromptsResult {
+    let mut prompts = review_prompts();
+    prompts.extend(
+        enabled_skills(config, thread_manager)
+            .await
+            .into_iter()
+            .map(|(name, description)| {
+                Prompt::new(
+                    format!("{SKILL_PROMPT_PREFIX}{name}"),
+                    Some(description),
+                    Some(vec![argument(
+                        "task",
+                        "What to do with the skill.",
+                        /*required*/ false,
+                    )]),
+                )
+            }),
+    );
+    ListPromptsResult {
+        prompts,
+        next_cursor: None,
+        meta: None,
+    }
+}
+
+pub(crate) async fn get_prompt(
+    config: &Config,
+    thread_manager: &ThreadManager,
+    params: GetPromptRequestParams,
+) -> Result<GetPromptResult, ErrorData> {
+    let GetPromptRequestParams {
+        name, arguments, ..
+    } = params;
+    let arguments = arguments.unwrap_or_default();
+
+    if let Some(skill_name) = name.strip_prefix(SKILL_PROMPT_PREFIX) {
+        let Some((_, description)) = enabled_skills(config, thread_manager)
+            .await
+            .into_iter()
+            .find(|(name, _)| name == skill_name)
+        else {
+            return Err(prompt_not_found(&name));
+        };
+        let mut text = format!("{TOOL_MENTION_SIGIL}{skill_name}");
+        if let Some(task) = string_argument(&arguments, "task") {
+            text.push_str("\n\n");
+            text.push_str(task);
+        }
+        return Ok(GetPromptResult {
+            description: Some(description),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        });
+    }
+
+    let target = match name.as_str() {
+        REVIEW_UNCOMMITTED_PROMPT => ReviewTarget::UncommittedChanges,
+        REVIEW_BASE_BRANCH_PROMPT => ReviewTarget::BaseBranch {
+            branch: required_argument(&arguments, "branch")?.to_string(),
+        },
+        REVIEW_COMMIT_PROMPT => ReviewTarget::Commit {
+            sha: required_argument(&arguments, "sha")?.to_string(),
+            title: string_argument(&arguments, "title").map(str::to_string),
+        },
+        _ => return Err(prompt_not_found(&name)),
+    };
+    let text = review_prompt(&target, &config.cwd).map_err(|err| {
+        ErrorData::internal_error(format!("failed to render review prompt: {err}"), None)
+    })?;
+    Ok(GetPromptResult {
+        description: Some(format!("Review {}", user_facing_hint(&target))),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+fn review_prompts() -> Vec<Prompt> {
+    vec![
+        Prompt::new(
+            REVIEW_UNCOMMITTED_PROMPT,
+            Some("Review staged, unstaged and untracked changes."),
+            /*arguments*/ None,
+        ),
+        Prompt::new(
+            REVIEW_BASE_BRANCH_PROMPT,
+            Some("Review the changes against a base branch."),
+            Some(vec![argument(
+                "branch",
+                "Branch to compare against.",
+                /*required*/ true,
+            )]),
+        ),
+        Prompt::new(
+            REVIEW_COMMIT_PROMPT,
+            Some("Review the changes introduced by a commit."),
+            Some(vec![
+                argument("sha", "Commit to review.", /*required*/ true),
+                argument("title", "Commit title.", /*required*/ false),
+            ]),
+        ),
+    ]
+}
+
+/// Returns the name and description of every enabled skill for the server's
+/// configured cwd.
+async fn enabled_skills(config: &Config, thread_manager: &ThreadManager) -> Vec<(String, String)> {
+    let plugins_input = config.plugins_config_input();
+    let effective_skill_roots = thread_manager
+        .plugins_manager()
+        .plugins_for_config(&plugins_input)
+        .await
+        .effective_plugin_skill_roots();
+    let skills_input = SkillsLoadInput::new(
+        config.cwd.clone(),
+        effective_skill_roots,
+        config.config_layer_stack.clone(),
+        config.bundled_skills_enabled(),
+    );
+    let fs = thread_manager
+        .environment_manager()
+        .default_environment()
+        .map(|environment| environment.get_filesystem());
+    let outcome = thread_manager
+        .skills_manager()
+        .skills_for_config(&skills_input, fs)
+        .await;
+    outcome
+        .skills_with_enabled()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(skill, _)| (skill.name.clone(), skill.description.clone()))
+        .collect()
+}
+
+fn argument(name: &str, description: &str, required: bool) -> PromptArgument {
+    PromptArgument {
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        required: Some(required),
+    }
+}
+
+fn string_argument<'a>(arguments: &'a JsonObject, name: &str) -> Option<&'a str> {
+    arguments
+        .get(name)
+        .and_then(serde_json::Value::as_str)
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn required_argument<'a>(arguments: &'a JsonObject, name: &str) -> Result<&'a str, ErrorData> {
+    string_argument(arguments, name).ok_or_else(|| {
+        ErrorData::invalid_params(
+            format!("missing required argument: {name}"),
+            Some(json!({ "argument": name })),
+        )
+    })
+}
+
+fn prompt_not_found(name: &str) -> ErrorData {
+    ErrorData::invalid_params("prompt_not_found", Some(json!({ "name": name })))
+}
@@ -0,0 +1,539 @@
+//! Codex threads exposed as MCP resources.
+//!
+//! Every thread is addressable under `codex://threads/{threadId}`:
+//!
+//! - `codex://threads/{threadId}` is a JSON summary of the thread.
+//! - `codex://threads/{threadId}/rollout` is the persisted rollout JSONL.
+//! - `codex://threads/{threadId}/diff` is the latest turn diff. Turn diffs are
+//!   not written to the rollout, so this is only available for threads that
+//!   ran in this server.
+//!
+//! Clients may subscribe to the resources of threads that are live in this
+//! server and receive `notifications/resources/updated` as their turns
+//! progress.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use codex_core::ThreadManager;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::TurnDiffEvent;
+use codex_thread_store::ListThreadsParams;
+use codex_thread_store::ReadThreadParams;
+use codex_thread_store::SortDirection;
+use codex_thread_store::StoredThread;
+use codex_thread_store::ThreadSortKey;
+use codex_thread_store::ThreadStore;
+use rmcp::model::ErrorData;
+use rmcp::model::ListResourceTemplatesResult;
+use rmcp::model::ListResourcesResult;
+use rmcp::model::RawResource;
+use rmcp::model::RawResourceTemplate;
+use rmcp::model::ReadResourceResult;
+use rmcp::model::Resource;
+use rmcp::model::ResourceContents;
+use rmcp::model::ResourceTemplate;
+use serde_json::json;
+use tokio::sync::Mutex;
+
+use crate::outgoing_message::OutgoingMessageSender;
+use crate::outgoing_message::OutgoingNotification;
+
+const THREAD_URI_PREFIX: &str = "codex://threads/";
+const THREAD_LIST_PAGE_SIZE: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ThreadResourceKind {
+    Summary,
+    Rollout,
+    Diff,
+}
+
+impl ThreadResourceKind {
+    const ALL: [Self; 3] = [Self::Summary, Self::Rollout, Self::Diff];
+
+    fn suffix(self) -> Option<&'static str> {
+        match self {
+            Self::Summary => None,
+            Self::Rollout => Some("rollout"),
+            Self::Diff => Some("diff"),
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            Self::Summary => "application/json",
+            Self::Rollout => "application/jsonl",
+            Self::Diff => "text/x-diff",
+        }
+    }
+
+    fn title(self, thread_title: &str) -> String {
+        match self {
+            Self::Summary => thread_title.to_string(),
+            Self::Rollout => format!("{thread_title} (rollout)"),
+            Self::Diff => format!("{thread_title} (turn diff)"),
+        }
+    }
+
+    fn template(self) -> ResourceTemplate {
+        let (uri_template, name, description) = match self {
+            Self::Summary => (
+                format!("{THREAD_URI_PREFIX}{{threadId}}"),
+                "codex-thread",
+                "Summary of a Codex thread.",
+            ),
+            Self::Rollout => (
+                format!("{THREAD_URI_PREFIX}{{threadId}}/rollout"),
+                "codex-thread-rollout",
+                "Persisted rollout of a Codex thread, one JSON item per line.",
+            ),
+            Self::Diff => (
+                format!("{THREAD_URI_PREFIX}{{threadId}}/diff"),
+                "codex-thread-diff",
+                "Unified diff of the latest turn of a thread run by this server.",
+            ),
+        };
+        ResourceTemplate::new(
+            RawResourceTemplate {
+                uri_template,
+                name: name.to_string(),
+                title: None,
+                description: Some(description.to_string()),
+                mime_type: Some(self.mime_type().to_string()),
+                icons: None,
+            },
+            None,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ThreadResourceUri {
+    thread_id: ThreadId,
+    kind: ThreadResourceKind,
+}
+
+impl ThreadResourceUri {
+    fn parse(uri: &str) -> Option<Self> {
+        let rest = uri.strip_prefix(THREAD_URI_PREFIX)?;
+        let (thread_id, kind) = match rest.split_once('/') {
+            None => (rest, ThreadResourceKind::Summary),
+            Some((thread_id, suffix)) => {
+                let kind = ThreadResourceKind::ALL
+                    .into_iter()
+                    .find(|kind| kind.suffix() == Some(suffix))?;
+                (thread_id, kind)
+            }
+        };
+        let thread_id = ThreadId::from_string(thread_id).ok()?;
+        Some(Self { thread_id, kind })
+    }
+
+    fn name(self) -> String {
+        match self.kind.suffix() {
+            Some(suffix) => format!("thread-{}-{suffix}", self.thread_id),
+            None => format!("thread-{}", self.thread_id),
+        }
+    }
+
+    fn resource(self, thread_title: &str) -> Resource {
+        Resource::new(
+            RawResource {
+                uri: self.to_string(),
+                name: self.name(),
+                title: Some(self.kind.title(thread_title)),
+                description: None,
+                mime_type: Some(self.kind.mime_type().to_string()),
+                size: None,
+                icons: None,
+                meta: None,
+            },
+            None,
+        )
+    }
+}
+
+impl fmt::Display for ThreadResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{THREAD_URI_PREFIX}{}", self.thread_id)?;
+        if let Some(suffix) = self.kind.suffix() {
+            write!(f, "/{suffix}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serves `resources/*` requests and tracks the per-thread state that is only
+/// observable from the event stream.
+pub(crate) struct ThreadResources {
+    outgoing: Arc<OutgoingMessageSender>,
+    thread_manager: Arc<ThreadManager>,
+    thread_store: Arc<dyn ThreadStore>,
+    turn_diffs: Mutex<HashMap<ThreadId, String>>,
+    subscriptions: Mutex<HashSet<ThreadResourceUri>>,
+}
+
+impl ThreadResources {
+    pub(crate) fn new(
+        outgoing: Arc<OutgoingMessageSender>,
+        thread_manager: Arc<ThreadManager>,
+        thread_store: Arc<dyn ThreadStore>,
+    ) -> Self {
+        Self {
+            outgoing,
+            thread_manager,
+            thread_store,
+            turn_diffs: Mutex::new(HashMap::new()),
+            subscriptions: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub(crate) async fn list(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let threads_with_diffs = self
+            .turn_diffs
+            .lock()
+            .await
+            .keys()
+            .copied()
+            .collect::<HashSet<_>>();
+        let mut resources = Vec::new();
+        let mut listed = HashSet::new();
+
+        // Live threads lead the first page because they may not have been
+        // persisted yet.
+        if cursor.is_none() {
+            for thread_id in self.thread_manager.list_thread_ids().await {
+                let Ok(thread) = self.thread_manager.get_thread(thread_id).await else {
+                    continue;
+                };
+                let title = match thread
+                    .read_thread(
+                        /*include_archived*/ true, /*include_history*/ false,
+                    )
+                    .await
+                {
+                    Ok(stored) => thread_title(&stored),
+                    Err(_) => thread_id.to_string(),
+                };
+                resources.extend(thread_resources(
+                    thread_id,
+                    &title,
+                    thread.rollout_path().is_some(),
+                    threads_with_diffs.contains(&thread_id),
+                ));
+                listed.insert(thread_id);
+            }
+        }
+
+        let page = self
+            .thread_store
+            .list_threads(ListThreadsParams {
+                page_size: THREAD_LIST_PAGE_SIZE,
+                cursor,
+                sort_key: ThreadSortKey::UpdatedAt,
+                sort_direction: SortDirection::Desc,
+                allowed_sources: Vec::new(),
+                model_providers: Some(Vec::new()),
+                cwd_filters: None,
+                archived: false,
+                search_term: None,
+                use_state_db_only: false,
+            })
+            .await
+            .map_err(|err| {
+                ErrorData::internal_error(format!("failed to list threads: {err}"), None)
+            })?;
+        for thread in page
+            .items
+            .iter()
+            .filter(|thread| !listed.contains(&thread.thread_id))
+        {
+            resources.extend(thread_resources(
+                thread.thread_id,
+                &thread_title(thread),
+                thread.rollout_path.is_some(),
+                threads_with_diffs.contains(&thread.thread_id),
+            ));
+        }
+
+        Ok(ListResourcesResult {
+            resources,
+            next_cursor: page.next_cursor,
+            meta: None,
+        })
+    }
+
+    pub(crate) fn list_templates(&self) -> ListResourceTemplatesResult {
+        ListResourceTemplatesResult {
+            resource_templates: ThreadResourceKind::ALL
+                .into_iter()
+                .map(ThreadResourceKind::template)
+                .collect(),
+            next_cursor: None,
+            meta: None,
+        }
+    }
+
+    pub(crate) async fn read(&self, uri: &str) -> Result<ReadResourceResult, ErrorData> {
+        let resource = parse_uri(uri)?;
+        let thread_id = resource.thread_id;
+        let text = match resource.kind {
+            ThreadResourceKind::Summary => {
+                let (thread, live) = self.read_thread(thread_id).await?;
+                let summary = json!({
+                    "threadId": thread.thread_id,
+                    "name": thread.name,
+                    "preview": thread.preview,
+                    "cwd": thread.cwd,
+                    "model": thread.model,
+                    "source": thread.source,
+                    "forkedFromId": thread.forked_from_id,
+                    "createdAt": thread.created_at,
+                    "updatedAt": thread.updated_at,
+                    "live": live,
+                });
+                serde_json::to_string_pretty(&summary).map_err(|err| {
+                    ErrorData::internal_error(
+                        format!("failed to serialize thread summary: {err}"),
+                        None,
+                    )
+                })?
+            }
+            ThreadResourceKind::Rollout => {
+                let rollout_path = match self.thread_manager.get_thread(thread_id).await {
+                    Ok(thread) => {
+                        if let Err(err) = thread.flush_rollout().await {
+                            tracing::warn!("failed to flush rollout for {thread_id}: {err}");
+                        }
+                        thread.rollout_path()
+                    }
+                    Err(_) => self.read_thread(thread_id).await?.0.rollout_path,
+                };
+                let Some(rollout_path) = rollout_path else {
+                    return Err(resource_not_found(
+                        uri,
+                        format!("thread {thread_id} has no rollout"),
+                    ));
+                };
+                tokio::fs::read_to_string(&rollout_path)
+                    .await
+                    .map_err(|err| {
+                        ErrorData::internal_error(
+                            format!("failed to read {}: {err}", rollout_path.display()),
+                            None,
+                        )
+                    })?
+            }
+            ThreadResourceKind::Diff => {
+                let Some(diff) = self.turn_diffs.lock().await.get(&thread_id).cloned() else {
+                    return Err(resource_not_found(
+                        uri,
+                        format!("no turn diff recorded for thread {thread_id}"),
+                    ));
+                };
+                diff
+            }
+        };
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: resource.to_string(),
+                mime_type: Some(resource.kind.mime_type().to_string()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
+    pub(crate) async fn subscribe(&self, uri: &str) -> Result<(), ErrorData> {
+        let resource = parse_uri(uri)?;
+        if self
+            .thread_manager
+            .get_thread(resource.thread_id)
+            .await
+            .is_err()
+        {
+            return Err(ErrorData::invalid_params(
+                format!(
+                    "thread {} is not running in this server",
+                    resource.thread_id
+                ),
+                Some(json!({ "uri": uri })),
+            ));
+        }
+        self.subscriptions.lock().await.insert(resource);
+        Ok(())
+    }
+
+    pub(crate) async fn unsubscribe(&self, uri: &str) -> Result<(), ErrorData> {
+        let resource = parse_uri(uri)?;
+        self.subscriptions.lock().await.remove(&resource);
+        Ok(())
+    }
+
+    /// Announce a newly started thread to clients that cache the list.
+    pub(crate) async fn thread_started(&self) {
+        self.send_list_changed().await;
+    }
+
+    /// Record event-derived state and notify subscribers of the resources it
+    /// touches.
+    pub(crate) async fn observe_event(&self, thread_id: ThreadId, msg: &EventMsg) {
+        let updated: &[ThreadResourceKind] = match msg {
+            EventMsg::TurnDiff(TurnDiffEvent { unified_diff }) => {
+                let previous = self
+                    .turn_diffs
+                    .lock()
+                    .await
+                    .insert(thread_id, unified_diff.clone());
+                if previous.is_none() {
+                    self.send_list_changed().await;
+                }
+                &[ThreadResourceKind::Diff]
+            }
+            EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_) => {
+                &[ThreadResourceKind::Summary, ThreadResourceKind::Rollout]
+            }
+            _ => return,
+        };
+
+        let updated = {
+            let subscriptions = self.subscriptions.lock().await;
+            updated
+                .iter()
+                .map(|kind| ThreadResourceUri {
+                    thread_id,
+                    kind: *kind,
+                })
+                .filter(|uri| subscriptions.contains(uri))
+                .collect::<Vec<_>>()
+        };
+        for uri in updated {
+            self.outgoing
+                .send_notification(OutgoingNotification {
+                    method: "notifications/resources/updated".to_string(),
+                    params: Some(json!({ "uri": uri.to_string() })),
+                })
+                .await;
+        }
+    }
+
+    async fn send_list_changed(&self) {
+        self.outgoing
+            .send_notification(OutgoingNotification {
+                method: "notifications/resources/list_changed".to_string(),
+                params: None,
+            })
+            .await;
+    }
+
+    /// Returns the stored thread and whether it is live in this server.
+    async fn read_thread(&self, thread_id: ThreadId) -> Result<(StoredThread, bool), ErrorData> {
+        let (result, live) = match self.thread_manager.get_thread(thread_id).await {
+            Ok(thread) => (
+                thread
+                    .read_thread(
+                        /*include_archived*/ true, /*include_history*/ false,
+                    )
+                    .await,
+                true,
+            ),
+            Err(_) => (
+                self.thread_store
+                    .read_thread(ReadThreadParams {
+                        thread_id,
+                        include_archived: true,
+                        include_history: false,
+                    })
+                    .await,
+                false,
+            ),
+        };
+        let thread = result.map_err(|err| {
+            ErrorData::resource_not_found(
+                format!("failed to read thread {thread_id}: {err}"),
+                Some(json!({ "threadId": thread_id })),
+            )
+        })?;
+        Ok((thread, live))
+    }
+}
+
+fn parse_uri(uri: &str) -> Result<ThreadResourceUri, ErrorData> {
+    ThreadResourceUri::parse(uri)
+        .ok_or_else(|| resource_not_found(uri, format!("unknown resource: {uri}")))
+}
+
+fn resource_not_found(uri: &str, message: String) -> ErrorData {
+    ErrorData::resource_not_found(message, Some(json!({ "uri": uri })))
+}
+
+fn thread_resources(
+    thread_id: ThreadId,
+    title: &str,
+    has_rollout: bool,
+    has_diff: bool,
+) -> Vec<Resource> {
+    ThreadResourceKind::ALL
+        .into_iter()
+        .filter(|kind| match kind {
+            ThreadResourceKind::Summary => true,
+            ThreadResourceKind::Rollout => has_rollout,
+            ThreadResourceKind::Diff => has_diff,
+        })
+        .map(|kind| ThreadResourceUri { thread_id, kind }.resource(title))
+        .collect()
+}
+
+fn thread_title(thread: &StoredThread) -> String {
+    thread
+        .name
+        .clone()
+        .or_else(|| thread.preview.lines().next().map(str::to_string))
+        .filter(|title| !title.trim().is_empty())
+        .unwrap_or_else(|| thread.thread_id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn thread_resource_uris_round_trip() {
+        let thread_id = ThreadId::new();
+        for kind in ThreadResourceKind::ALL {
+            let uri = ThreadResourceUri { thread_id, kind };
+            assert_eq!(ThreadResourceUri::parse(&uri.to_string()), Some(uri));
+        }
+        assert_eq!(
+            ThreadResourceUri {
+                thread_id,
+                kind: ThreadResourceKind::Rollout,
+            }
+            .to_string(),
+            format!("codex://threads/{thread_id}/rollout")
+        );
+    }
+
+    #[test]
+    fn thread_resource_uri_rejects_unknown_shapes() {
+        let thread_id = ThreadId::new();
+        assert_eq!(ThreadResourceUri::parse("codex://threads/not-a-uuid"), None);
+        assert_eq!(
+            ThreadResourceUri::parse(&format!("codex://threads/{thread_id}/turns")),
+            None
+        );
+        assert_eq!(
+            ThreadResourceUri::parse(&format!("file:///threads/{thread_id}")),
+            None
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Command-line options that pick how `codex mcp-server` talks to clients.
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use clap::Args;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Args)]
+pub struct McpServerArgs {
+    /// Transport endpoint URL. Supported values: `stdio://` (default),
+    /// `http://IP:PORT` (MCP streamable HTTP, served at `/mcp`).
+    #[arg(
+        long = "listen",
+        value_name = "URL",
+        default_value = McpServerTransport::DEFAULT_LISTEN_URL
+    )]
+    pub listen: McpServerTransport,
+
+    /// Environment variable holding the bearer token HTTP clients must send
+    /// in the `Authorization` header.
+    #[arg(long = "bearer-token-env-var", value_name = "ENV_VAR")]
+    pub bearer_token_env_var: Option<String>,
+
+    /// Serve a non-loopback `--listen` address without a bearer token. Anyone
+    /// who can reach the address can then drive Codex.
+    #[arg(long = "allow-unauthenticated-non-loopback")]
+    pub allow_unauthenticated_non_loopback: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum McpServerTransport {
+    #[default]
+    Stdio,
+    StreamableHttp {
+        bind_address: SocketAddr,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum McpServerTransportParseError {
+    UnsupportedListenUrl(String),
+    InvalidHttpListenUrl(String),
+}
+
+impl std::fmt::Display for McpServerTransportParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            McpServerTransportParseError::UnsupportedListenUrl(listen_url) => write!(
+                f,
+                "unsupported --listen URL `{listen_url}`; expected `stdio://` or `http://IP:PORT`"
+            ),
+            McpServerTransportParseError::InvalidHttpListenUrl(listen_url) => write!(
+                f,
+                "invalid http --listen URL `{listen_url}`; expected `http://IP:PORT`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for McpServerTransportParseError {}
+
+impl McpServerTransport {
+    pub const DEFAULT_LISTEN_URL: &'static str = "stdio://";
+
+    pub fn from_listen_url(listen_url: &str) -> Result<Self, McpServerTransportParseError> {
+        if listen_url == Self::DEFAULT_LISTEN_URL {
+            return Ok(Self::Stdio);
+        }
+
+        if let Some(socket_addr) = listen_url.strip_prefix("http://") {
+            // Accept the endpoint path clients are configured with as well.
+            let socket_addr = socket_addr
+                .strip_suffix("/mcp")
+                .or_else(|| socket_addr.strip_suffix('/'))
+                .unwrap_or(socket_addr);
+            let bind_address = socket_addr.parse::<SocketAddr>().map_err(|_| {
+                McpServerTransportParseError::InvalidHttpListenUrl(listen_url.to_string())
+            })?;
+            return Ok(Self::StreamableHttp { bind_address });
+        }
+
+        Err(McpServerTransportParseError::UnsupportedListenUrl(
+            listen_url.to_string(),
+        ))
+    }
+}
+
+impl FromStr for McpServerTransport {
+    type Err = McpServerTransportParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_listen_url(s)
+    }
+}
+
+impl McpServerArgs {
+    /// Resolves the bearer token HTTP clients must present, if one was
+    /// configured. Refuses an unauthenticated non-loopback listener unless
+    /// `--allow-unauthenticated-non-loopback` was passed.
+    pub(crate) fn bearer_token(&self) -> std::io::Result<Option<String>> {
+        let Some(env_var) = self.bearer_token_env_var.as_deref() else {
+            if let McpServerTransport::StreamableHttp { bind_address } = self.listen
+                && !bind_address.ip().is_loopback()
+                && !self.allow_unauthenticated_non_loopback
+            {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "refusing to serve `http://{bind_address}` without auth; pass `--bearer-token-env-var`, or `--allow-unauthenticated-non-loopback` to accept unauthenticated clients"
+                    ),
+                ));
+            }
+            return Ok(None);
+        };
+        if self.listen == McpServerTransport::Stdio {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "`--bearer-token-env-var` requires `--listen http://IP:PORT`",
+            ));
+        }
+        match std::env::var(env_var) {
+            Ok(token) if !token.trim().is_empty() => Ok(Some(token.trim().to_string())),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("environment variable `{env_var}` must be set to a non-empty bearer token"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn listen_url_parses_stdio_and_http() {
+        assert_eq!(
+            McpServerTransport::from_listen_url("stdio://"),
+            Ok(McpServerTransport::Stdio)
+        );
+        let bind_address: SocketAddr = "127.0.0.1:8123".parse().expect("socket address");
+        for listen_url in [
+            "http://127.0.0.1:8123",
+            "http://127.0.0.1:8123/",
+            "http://127.0.0.1:8123/mcp",
+        ] {
+            assert_eq!(
+                McpServerTransport::from_listen_url(listen_url),
+                Ok(McpServerTransport::StreamableHttp { bind_address })
+            );
+        }
+    }
+
+    #[test]
+    fn listen_url_rejects_unsupported_and_malformed_urls() {
+        assert_eq!(
+            McpServerTransport::from_listen_url("ws://127.0.0.1:8123"),
+            Err(McpServerTransportParseError::UnsupportedListenUrl(
+                "ws://127.0.0.1:8123".to_string()
+            ))
+        );
+        assert_eq!(
+            McpServerTransport::from_listen_url("http://localhost"),
+            Err(McpServerTransportParseError::InvalidHttpListenUrl(
+                "http://localhost".to_string()
+            ))
+        );
+    }
+
+    fn listen_args(listen_url: &str) -> McpServerArgs {
+        McpServerArgs {
+            listen: McpServerTransport::from_listen_url(listen_url).expect("listen url"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unauthenticated_non_loopback_listener_requires_opt_in() {
+        let err = listen_args("http://0.0.0.0:8123")
+            .bearer_token()
+            .expect_err("non-loopback listener without a token should be refused");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "refusing to serve `http://0.0.0.0:8123` without auth; pass `--bearer-token-env-var`, or `--allow-unauthenticated-non-loopback` to accept unauthenticated clients"
+        );
+
+        let args = McpServerArgs {
+            allow_unauthenticated_non_loopback: true,
+            ..listen_args("http://0.0.0.0:8123")
+        };
+        assert_eq!(args.bearer_token().expect("opted in"), None);
+    }
+
+    #[test]
+    fn unauthenticated_loopback_listener_is_allowed() {
+        for listen_url in ["stdio://", "http://127.0.0.1:8123", "http://[::1]:8123"] {
+            assert_eq!(
+                listen_args(listen_url).bearer_token().expect("loopback"),
+                None
+            );
+        }
+    }
+}
@@ -173,6 +173,11 @@ impl McpProcess {
             result,
             json!({
                 "capabilities": {
+                    "prompts": {},
+                    "resources": {
+                        "listChanged": true,
+                        "subscribe": true
+                    },
                     "tools": {
                         "listChanged": true
                     },
@@ -219,6 +224,57 @@ impl McpProcess {
         .await
     }
 
+    pub async fn send_codex_reply_tool_call(
+        &mut self,
+        thread_id: &str,
+        prompt: &str,
+    ) -> anyhow::Result<i64> {
+        let codex_reply_params = CallToolRequestParams {
+            meta: None,
+            name: "codex-reply".into(),
+            arguments: Some(match json!({ "threadId": thread_id, "prompt": prompt }) {
+                serde_json::Value::Object(map) => map,
+                _ => unreachable!("params serialize to object"),
+            }),
+            task: None,
+        };
+        self.send_request(
+            "tools/call",
+            Some(serde_json::to_value(codex_reply_params)?),
+        )
+        .await
+    }
+
+    pub async fn send_list_resources_request(&mut self) -> anyhow::Result<i64> {
+        self.send_request("resources/list", None).await
+    }
+
+    pub async fn send_read_resource_request(&mut self, uri: &str) -> anyhow::Result<i64> {
+        self.send_request("resources/read", Some(json!({ "uri": uri })))
+            .await
+    }
+
+    pub async fn send_subscribe_request(&mut self, uri: &str) -> anyhow::Result<i64> {
+        self.send_request("resources/subscribe", Some(json!({ "uri": uri })))
+            .await
+    }
+
+    pub async fn send_list_prompts_request(&mut self) -> anyhow::Result<i64> {
+        self.send_request("prompts/list", None).await
+    }
+
+    pub async fn send_get_prompt_request(
+        &mut self,
+        name: &str,
+        arguments: serde_json::Value,
+    ) -> anyhow::Result<i64> {
+        self.send_request(
+            "prompts/get",
+            Some(json!({ "name": name, "arguments": arguments })),
+        )
+        .await
+    }
+
     async fn send_request(
         &mut self,
         method: &str,
@@ -324,6 +380,36 @@ impl McpProcess {
         }
     }
 
+    /// Reads messages until a notification with the given method is observed,
+    /// skipping responses to requests that are still in flight.
+    pub async fn read_stream_until_notification(
+        &mut self,
+        method: &str,
+    ) -> anyhow::Result<JsonRpcNotification<CustomNotification>> {
+        eprintln!("in read_stream_until_notification({method})");
+
+        loop {
+            let message = self.read_jsonrpc_message().await?;
+            match message {
+                JsonRpcMessage::Notification(notification) => {
+                    if notification.notification.method == method {
+                        return Ok(notification);
+                    }
+                    eprintln!("ignoring notification: {notification:?}");
+                }
+                JsonRpcMessage::Request(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Request: {message:?}");
+                }
+                JsonRpcMessage::Error(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Error: {message:?}");
+                }
+                JsonRpcMessage::Response(_) => {
+                    eprintln!("ignoring response: {message:?}");
+                }
+            }
+        }
+    }
+
     /// Reads notifications until a legacy TurnComplete event is observed:
     /// Method "codex/event" with params.msg.type == "task_complete".
     pub async fn read_stream_until_legacy_task_complete_notification(
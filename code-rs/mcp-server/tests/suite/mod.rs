@@ -1 +1,4 @@
 mod codex_tool;
+mod prompts;
+mod streamable_http;
+mod thread_resources;
@@ -0,0 +1,92 @@
+use pretty_assertions::assert_eq;
+use rmcp::model::RequestId;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+use mcp_test_support::McpProcess;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_skills_and_review_presets_are_prompts() {
+    if let Err(err) = skills_and_review_presets_are_prompts().await {
+        panic!("failure: {err}");
+    }
+}
+
+async fn skills_and_review_presets_are_prompts() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let skill_dir = codex_home.path().join("skills").join("tidy-docs");
+    std::fs::create_dir_all(&skill_dir)?;
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: tidy-docs\ndescription: Tidy documentation\n---\n\n# Body\n",
+    )?;
+    let mut mcp_process = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp_process.initialize()).await??;
+
+    let list_request_id = mcp_process.send_list_prompts_request().await?;
+    let list_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(list_request_id)),
+    )
+    .await??;
+    let names = list_response.result["prompts"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|prompt| prompt["name"].as_str())
+        .collect::<Vec<_>>();
+    for expected in [
+        "review-uncommitted",
+        "review-base-branch",
+        "review-commit",
+        "skill:tidy-docs",
+    ] {
+        assert!(names.contains(&expected), "missing {expected} in {names:?}");
+    }
+
+    let get_request_id = mcp_process
+        .send_get_prompt_request("skill:tidy-docs", json!({ "task": "Fix the README." }))
+        .await?;
+    let get_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(get_request_id)),
+    )
+    .await??;
+    assert_eq!(
+        get_response.result,
+        json!({
+            "description": "Tidy documentation",
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": "$tidy-docs\n\nFix the README." },
+            }],
+        })
+    );
+
+    let get_request_id = mcp_process
+        .send_get_prompt_request("review-commit", json!({ "sha": "abc1234def" }))
+        .await?;
+    let get_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(get_request_id)),
+    )
+    .await??;
+    assert_eq!(
+        get_response.result,
+        json!({
+            "description": "Review commit abc1234",
+            "messages": [{
+                "role": "user",
+                "content": {
+                    "type": "text",
+                    "text": "Review the code changes introduced by commit abc1234def. Provide prioritized, actionable findings.",
+                },
+            }],
+        })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Context;
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::CONTENT_TYPE;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::process::Child;
+use tokio::process::Command;
+use tokio::time::sleep;
+use tokio::time::timeout;
+
+use core_test_support::skip_if_no_network;
+use mcp_test_support::create_final_assistant_message_sse_response;
+use mcp_test_support::create_mock_responses_server;
+
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(60);
+const MCP_SESSION_ID_HEADER: &str = "mcp-session-id";
+const BEARER_TOKEN_ENV_VAR: &str = "CODEX_MCP_SERVER_TEST_TOKEN";
+const BEARER_TOKEN: &str = "test-bearer";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_streamable_http_sessions_share_threads() {
+    skip_if_no_network!();
+
+    if let Err(err) = streamable_http_sessions_share_threads().await {
+        panic!("failure: {err}");
+    }
+}
+
+async fn streamable_http_sessions_share_threads() -> anyhow::Result<()> {
+    let server = create_mock_responses_server(vec![
+        create_final_assistant_message_sse_response("First!")?,
+        create_final_assistant_message_sse_response("Second!")?,
+    ])
+    .await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+    let (_process, endpoint) = spawn_http_mcp_server(codex_home.path()).await?;
+    let client = reqwest::Client::new();
+
+    let unauthorized = client
+        .post(&endpoint)
+        .header(CONTENT_TYPE, "application/json")
+        .body(initialize_request().to_string())
+        .send()
+        .await?;
+    assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+
+    let first_session = initialize_session(&client, &endpoint).await?;
+    let missing_session = post(&client, &endpoint, None, tools_list_request(1)).await?;
+    assert_eq!(missing_session.status(), StatusCode::BAD_REQUEST);
+
+    // Events for the tool call stream on the POST before its response.
+    let codex_call = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": { "name": "codex", "arguments": { "prompt": "Say something." } },
+    });
+    let messages = timeout(
+        DEFAULT_READ_TIMEOUT,
+        post_for_messages(&client, &endpoint, &first_session, codex_call),
+    )
+    .await??;
+    let (response, events) = messages
+        .split_last()
+        .context("tool call stream should not be empty")?;
+    assert!(
+        events.iter().any(|event| event["method"] == "codex/event"),
+        "expected codex/event notifications before the response, got {events:?}"
+    );
+    for event in events
+        .iter()
+        .filter(|event| event["method"] == "codex/event")
+    {
+        assert_eq!(event["params"]["_meta"]["requestId"], json!(2));
+    }
+    assert_eq!(response["id"], json!(2));
+    assert_eq!(
+        response["result"]["content"],
+        json!([{ "type": "text", "text": "First!" }])
+    );
+    let thread_id = response["result"]["structuredContent"]["threadId"]
+        .as_str()
+        .context("codex tool response should include a threadId")?
+        .to_string();
+
+    // A second client continues the thread the first one started.
+    let second_session = initialize_session(&client, &endpoint).await?;
+    assert_ne!(first_session, second_session);
+    let reply_call = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "codex-reply",
+            "arguments": { "threadId": thread_id, "prompt": "Again." },
+        },
+    });
+    let messages = timeout(
+        DEFAULT_READ_TIMEOUT,
+        post_for_messages(&client, &endpoint, &second_session, reply_call),
+    )
+    .await??;
+    let response = messages
+        .last()
+        .context("reply stream should not be empty")?;
+    assert_eq!(
+        response["result"]["content"],
+        json!([{ "type": "text", "text": "Second!" }])
+    );
+
+    let deleted = client
+        .delete(&endpoint)
+        .header(AUTHORIZATION, format!("Bearer {BEARER_TOKEN}"))
+        .header(MCP_SESSION_ID_HEADER, &first_session)
+        .send()
+        .await?;
+    assert_eq!(deleted.status(), StatusCode::NO_CONTENT);
+    let closed_session = post(
+        &client,
+        &endpoint,
+        Some(&first_session),
+        tools_list_request(3),
+    )
+    .await?;
+    assert_eq!(closed_session.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+fn initialize_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": 0,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "streamable http test", "version": "0.0.0" },
+        },
+    })
+}
+
+fn tools_list_request(id: i64) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": "tools/list" })
+}
+
+/// Runs the initialize handshake and returns the new session id.
+async fn initialize_session(client: &reqwest::Client, endpoint: &str) -> anyhow::Result<String> {
+    let response = post(client, endpoint, None, initialize_request()).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let session_id = response
+        .headers()
+        .get(MCP_SESSION_ID_HEADER)
+        .context("initialize response should carry a session id")?
+        .to_str()?
+        .to_string();
+    let messages = sse_messages(&response.text().await?)?;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["id"], json!(0));
+    assert_eq!(
+        messages[0]["result"]["serverInfo"]["name"],
+        json!("codex-mcp-server")
+    );
+
+    let initialized = post(
+        client,
+        endpoint,
+        Some(&session_id),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await?;
+    assert_eq!(initialized.status(), StatusCode::ACCEPTED);
+
+    let tools = post_for_messages(client, endpoint, &session_id, tools_list_request(1)).await?;
+    let tool_names = tools[0]["result"]["tools"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(tool_names, vec!["codex", "codex-reply"]);
+
+    Ok(session_id)
+}
+
+async fn post(
+    client: &reqwest::Client,
+    endpoint: &str,
+    session_id: Option<&str>,
+    message: Value,
+) -> anyhow::Result<reqwest::Response> {
+    let mut request = client
+        .post(endpoint)
+        .header(AUTHORIZATION, format!("Bearer {BEARER_TOKEN}"))
+        .header(CONTENT_TYPE, "application/json")
+        .header("accept", "application/json, text/event-stream")
+        .body(message.to_string());
+    if let Some(session_id) = session_id {
+        request = request.header(MCP_SESSION_ID_HEADER, session_id);
+    }
+    Ok(request.send().await?)
+}
+
+/// Posts a request and collects every message on its SSE stream.
+async fn post_for_messages(
+    client: &reqwest::Client,
+    endpoint: &str,
+    session_id: &str,
+    message: Value,
+) -> anyhow::Result<Vec<Value>> {
+    let response = post(client, endpoint, Some(session_id), message).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    sse_messages(&response.text().await?)
+}
+
+fn sse_messages(body: &str) -> anyhow::Result<Vec<Value>> {
+    body.lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| Ok(serde_json::from_str(data.trim())?))
+        .collect()
+}
+
+async fn spawn_http_mcp_server(codex_home: &Path) -> anyhow::Result<(Child, String)> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let bind_addr = listener.local_addr()?;
+    drop(listener);
+
+    let program = codex_utils_cargo_bin::cargo_bin("codex-mcp-server")
+        .context("should find binary for codex-mcp-server")?;
+    let mut process = Command::new(program)
+        .args([
+            "--listen",
+            &format!("http://{bind_addr}"),
+            "--bearer-token-env-var",
+            BEARER_TOKEN_ENV_VAR,
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::inherit())
+        .env("CODEX_HOME", codex_home)
+        .env("RUST_LOG", "debug")
+        .env(BEARER_TOKEN_ENV_VAR, BEARER_TOKEN)
+        .kill_on_drop(true)
+        .spawn()
+        .context("codex-mcp-server proc should start")?;
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let health_url = format!("http://{bind_addr}/healthz");
+    loop {
+        if let Some(status) = process.try_wait()? {
+            anyhow::bail!("codex-mcp-server exited early with status {status}");
+        }
+        if reqwest::get(&health_url)
+            .await
+            .is_ok_and(|response| response.status().is_success())
+        {
+            return Ok((process, format!("http://{bind_addr}/mcp")));
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!("timed out waiting for codex-mcp-server at {bind_addr}");
+        }
+        sleep(Duration::from_millis(50)).await;
+    }
+}
+
+fn create_config_toml(codex_home: &Path, server_uri: &str) -> std::io::Result<()> {
+    std::fs::write(
+        codex_home.join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+"#
+        ),
+    )
+}
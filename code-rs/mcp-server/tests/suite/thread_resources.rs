@@ -0,0 +1,151 @@
+use std::path::Path;
+
+use codex_mcp_server::CodexToolCallParam;
+use pretty_assertions::assert_eq;
+use rmcp::model::RequestId;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+use core_test_support::skip_if_no_network;
+use mcp_test_support::McpProcess;
+use mcp_test_support::create_final_assistant_message_sse_response;
+use mcp_test_support::create_mock_responses_server;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_threads_are_readable_and_subscribable_resources() {
+    skip_if_no_network!();
+
+    if let Err(err) = threads_are_readable_and_subscribable_resources().await {
+        panic!("failure: {err}");
+    }
+}
+
+async fn threads_are_readable_and_subscribable_resources() -> anyhow::Result<()> {
+    let server = create_mock_responses_server(vec![
+        create_final_assistant_message_sse_response("First!")?,
+        create_final_assistant_message_sse_response("Second!")?,
+    ])
+    .await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+    let mut mcp_process = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp_process.initialize()).await??;
+
+    let codex_request_id = mcp_process
+        .send_codex_tool_call(CodexToolCallParam {
+            prompt: "Say something.".to_string(),
+            ..Default::default()
+        })
+        .await?;
+    let codex_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(codex_request_id)),
+    )
+    .await??;
+    let thread_id = codex_response.result["structuredContent"]["threadId"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("codex tool response should include a threadId"))?
+        .to_string();
+    let summary_uri = format!("codex://threads/{thread_id}");
+    let rollout_uri = format!("{summary_uri}/rollout");
+
+    let list_request_id = mcp_process.send_list_resources_request().await?;
+    let list_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(list_request_id)),
+    )
+    .await??;
+    let thread_uris = list_response.result["resources"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|resource| resource["uri"].as_str())
+        .filter(|uri| uri.contains(&thread_id))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        thread_uris,
+        vec![summary_uri.as_str(), rollout_uri.as_str()]
+    );
+
+    let read_request_id = mcp_process.send_read_resource_request(&summary_uri).await?;
+    let read_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(read_request_id)),
+    )
+    .await??;
+    let contents = &read_response.result["contents"][0];
+    assert_eq!(contents["uri"], json!(summary_uri));
+    assert_eq!(contents["mimeType"], json!("application/json"));
+    let summary: serde_json::Value = serde_json::from_str(
+        contents["text"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("summary should be text"))?,
+    )?;
+    assert_eq!(summary["threadId"], json!(thread_id));
+    assert_eq!(summary["live"], json!(true));
+
+    let read_request_id = mcp_process.send_read_resource_request(&rollout_uri).await?;
+    let read_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(read_request_id)),
+    )
+    .await??;
+    let rollout = read_response.result["contents"][0]["text"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("rollout should be text"))?;
+    let first_line: serde_json::Value = serde_json::from_str(
+        rollout
+            .lines()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("rollout should not be empty"))?,
+    )?;
+    assert_eq!(first_line["type"], json!("session_meta"));
+
+    let subscribe_request_id = mcp_process.send_subscribe_request(&summary_uri).await?;
+    let subscribe_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(subscribe_request_id)),
+    )
+    .await??;
+    assert_eq!(subscribe_response.result, json!({}));
+
+    mcp_process
+        .send_codex_reply_tool_call(&thread_id, "Say more.")
+        .await?;
+    let updated = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_notification("notifications/resources/updated"),
+    )
+    .await??;
+    assert_eq!(
+        updated.notification.params,
+        Some(json!({ "uri": summary_uri }))
+    );
+
+    Ok(())
+}
+
+fn create_config_toml(codex_home: &Path, server_uri: &str) -> std::io::Result<()> {
+    std::fs::write(
+        codex_home.join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+"#
+        ),
+    )
+}
@@ -107,16 +107,46 @@ env_http_headers = { "X-Example-Env-Header" = "EXAMPLE_ENV_VAR" }
 }
 
 #[test]
-fn test_deserialize_chat_wire_api_shows_helpful_error() {
+fn test_deserialize_chat_wire_api() {
     let provider_toml = r#"
-name = "OpenAI using Chat Completions"
-base_url = "https://api.openai.com/v1"
-env_key = "OPENAI_API_KEY"
+name = "vLLM"
+base_url = "http://localhost:8000/v1"
 wire_api = "chat"
         "#;
 
-    let err = toml::from_str::<ModelProviderInfo>(provider_toml).unwrap_err();
-    assert!(err.to_string().contains(CHAT_WIRE_API_REMOVED_ERROR));
+    let provider = toml::from_str::<ModelProviderInfo>(provider_toml).unwrap();
+    assert_eq!(provider.wire_api, WireApi::Chat);
+    assert_eq!(provider.wire_api.to_string(), "chat");
+}
+
+#[test]
+fn test_deserialize_anthropic_wire_api() {
+    let provider_toml = r#"
+name = "Anthropic"
+base_url = "https://api.anthropic.com/v1"
+env_key = "ANTHROPIC_API_KEY"
+wire_api = "anthropic"
+        "#;
+
+    let provider = toml::from_str::<ModelProviderInfo>(provider_toml).unwrap();
+    assert_eq!(provider.wire_api, WireApi::Anthropic);
+    assert_eq!(provider.wire_api.to_string(), "anthropic");
+}
+
+#[test]
+fn test_validate_chat_wire_api_rejects_websockets() {
+    let provider = ModelProviderInfo {
+        name: "vLLM".to_string(),
+        base_url: Some("http://localhost:8000/v1".to_string()),
+        wire_api: WireApi::Chat,
+        supports_websockets: true,
+        ..ModelProviderInfo::default()
+    };
+
+    assert_eq!(
+        provider.validate(),
+        Err("provider wire_api = \"chat\" cannot be combined with supports_websockets".to_string())
+    );
 }
 
 #[test]
@@ -382,6 +412,32 @@ fn test_merge_configured_model_providers_allows_amazon_bedrock_default_fields()
     );
 }
 
+#[test]
+fn test_merge_configured_model_providers_applies_oss_wire_api_override() {
+    let configured_model_providers = std::collections::HashMap::from([(
+        OLLAMA_OSS_PROVIDER_ID.to_string(),
+        ModelProviderInfo {
+            name: "Ignored".to_string(),
+            wire_api: WireApi::Chat,
+            ..ModelProviderInfo::default()
+        },
+    )]);
+
+    let mut expected = built_in_model_providers(/*openai_base_url*/ None);
+    expected
+        .get_mut(OLLAMA_OSS_PROVIDER_ID)
+        .expect("Ollama provider should be built in")
+        .wire_api = WireApi::Chat;
+
+    assert_eq!(
+        merge_configured_model_providers(
+            built_in_model_providers(/*openai_base_url*/ None),
+            configured_model_providers,
+        ),
+        Ok(expected)
+    );
+}
+
 #[test]
 fn test_validate_provider_aws_rejects_conflicting_auth() {
     let provider = ModelProviderInfo {
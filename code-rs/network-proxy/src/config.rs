@@ -114,6 +114,58 @@ pub struct NetworkUnixSocketPermissions {
     pub entries: BTreeMap<String, NetworkUnixSocketPermission>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkHttpRuleDecision {
+    Allow,
+    Deny,
+}
+
+/// A method- and path-level rule for HTTP requests the proxy can inspect: plain HTTP requests
+/// and requests inside MITM-terminated CONNECT tunnels.
+///
+/// Rules are evaluated in order and the first rule whose host, method and path all match decides
+/// the request. Requests no rule matches fall back to the method policy of [`NetworkMode`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NetworkHttpRule {
+    /// Host pattern, using the same syntax as the `domains` table.
+    pub host: String,
+    /// HTTP methods the rule applies to. Empty matches every method.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<String>,
+    /// Glob matched against the request path, e.g. `/repos/our-org/**`. `*` stays within one
+    /// path segment and `**` matches any number of segments. Absent matches every path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub decision: NetworkHttpRuleDecision,
+}
+
+/// A credential the proxy adds to HTTPS requests for a host, so sandboxed processes can reach it
+/// without ever holding the secret.
+///
+/// Credentials are only injected inside MITM-terminated CONNECT tunnels; requests to the host
+/// have any credentials the client supplied itself removed first.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NetworkCredential {
+    /// Host pattern, using the same syntax as the `domains` table.
+    pub host: String,
+    /// Header the credential is sent in.
+    #[serde(default = "default_credential_header")]
+    pub header: String,
+    /// Header value. The embedding app may substitute references in it (Codex resolves
+    /// `$secret:NAME`) when a request is sent.
+    pub value: String,
+}
+
+impl std::fmt::Debug for NetworkCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkCredential")
+            .field("host", &self.host)
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(default)]
 pub struct NetworkProxySettings {
@@ -134,11 +186,18 @@ pub struct NetworkProxySettings {
     pub mode: NetworkMode,
     #[serde(default)]
     pub domains: Option<NetworkDomainPermissions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub http_rules: Vec<NetworkHttpRule>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub credentials: Vec<NetworkCredential>,
     #[serde(default)]
     pub unix_sockets: Option<NetworkUnixSocketPermissions>,
     pub allow_local_binding: bool,
     #[serde(default)]
     pub mitm: bool,
+    /// Record every request the proxy sees in the traffic log under `$CODEX_HOME/log/network`.
+    /// Off unless enabled.
+    pub traffic_log: bool,
 }
 
 impl Default for NetworkProxySettings {
@@ -154,9 +213,12 @@ impl Default for NetworkProxySettings {
             dangerously_allow_all_unix_sockets: false,
             mode: NetworkMode::default(),
             domains: None,
+            http_rules: Vec::new(),
+            credentials: Vec::new(),
             unix_sockets: None,
             allow_local_binding: false,
             mitm: false,
+            traffic_log: false,
         }
     }
 }
@@ -292,6 +354,10 @@ fn default_proxy_url() -> String {
     "http://127.0.0.1:3128".to_string()
 }
 
+fn default_credential_header() -> String {
+    "authorization".to_string()
+}
+
 fn default_socks_url() -> String {
     "http://127.0.0.1:8081".to_string()
 }
@@ -585,9 +651,12 @@ mod tests {
                 dangerously_allow_all_unix_sockets: false,
                 mode: NetworkMode::Full,
                 domains: None,
+                http_rules: Vec::new(),
+                credentials: Vec::new(),
                 unix_sockets: None,
                 allow_local_binding: false,
                 mitm: false,
+                traffic_log: false,
             }
         );
     }
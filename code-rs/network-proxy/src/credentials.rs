@@ -0,0 +1,207 @@
+use crate::config::NetworkCredential;
+use crate::http_rules::globset_matches_host;
+use crate::policy::compile_allowlist_globset;
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::ensure;
+use globset::GlobSet;
+use rama_http::HeaderMap;
+use rama_http::HeaderName;
+use rama_http::HeaderValue;
+use rama_http::header::AUTHORIZATION;
+
+/// Turns a configured credential value into the header value sent upstream, e.g. by looking up
+/// the secrets it references. Called for every request that receives the credential, on a blocking
+/// thread.
+pub trait CredentialResolver: Send + Sync + 'static {
+    fn resolve(&self, value: &str) -> Result<String>;
+}
+
+impl<F> CredentialResolver for F
+where
+    F: Fn(&str) -> Result<String> + Send + Sync + 'static,
+{
+    fn resolve(&self, value: &str) -> Result<String> {
+        (self)(value)
+    }
+}
+
+/// Compiled form of `network.credentials`.
+#[derive(Clone, Default)]
+pub(crate) struct CredentialRules {
+    rules: Vec<CompiledCredential>,
+}
+
+#[derive(Clone)]
+struct CompiledCredential {
+    hosts: GlobSet,
+    header: HeaderName,
+    value: String,
+}
+
+impl CredentialRules {
+    pub(crate) fn compile(credentials: &[NetworkCredential]) -> Result<Self> {
+        let rules = credentials
+            .iter()
+            .enumerate()
+            .map(|(index, credential)| {
+                compile_credential(credential)
+                    .with_context(|| format!("invalid network.credentials[{index}]"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { rules })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub(crate) fn applies_to_host(&self, host: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| globset_matches_host(&rule.hosts, host))
+    }
+
+    /// The credentials to send to `host`, one per header; earlier entries win.
+    pub(crate) fn for_host(&self, host: &str) -> Vec<(HeaderName, String)> {
+        let mut credentials: Vec<(HeaderName, String)> = Vec::new();
+        for rule in &self.rules {
+            if globset_matches_host(&rule.hosts, host)
+                && !credentials.iter().any(|(header, _)| *header == rule.header)
+            {
+                credentials.push((rule.header.clone(), rule.value.clone()));
+            }
+        }
+        credentials
+    }
+}
+
+fn compile_credential(credential: &NetworkCredential) -> Result<CompiledCredential> {
+    let host = credential.host.trim();
+    ensure!(!host.is_empty(), "host must not be empty");
+    let hosts = compile_allowlist_globset(&[host.to_string()])?;
+    let header = HeaderName::from_bytes(credential.header.trim().as_bytes())
+        .with_context(|| format!("invalid header name {:?}", credential.header))?;
+    ensure!(!credential.value.is_empty(), "value must not be empty");
+    Ok(CompiledCredential {
+        hosts,
+        header,
+        value: credential.value.clone(),
+    })
+}
+
+/// Removes the credentials a client sent itself to a host the proxy holds credentials for: its
+/// `Authorization` header and every header a credential is sent in. Returns whether anything was
+/// removed.
+pub(crate) fn strip_client_credentials<'a>(
+    headers: &mut HeaderMap,
+    credential_headers: impl IntoIterator<Item = &'a HeaderName>,
+) -> bool {
+    let mut stripped = headers.remove(AUTHORIZATION).is_some();
+    for header in credential_headers {
+        stripped |= headers.remove(header).is_some();
+    }
+    stripped
+}
+
+/// Replaces whatever credentials the client sent with `credentials`.
+pub(crate) fn apply_credentials(
+    headers: &mut HeaderMap,
+    credentials: Vec<(HeaderName, HeaderValue)>,
+) {
+    strip_client_credentials(headers, credentials.iter().map(|(header, _)| header));
+    for (header, value) in credentials {
+        headers.insert(header, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn credential(host: &str, header: &str, value: &str) -> NetworkCredential {
+        NetworkCredential {
+            host: host.to_string(),
+            header: header.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn credentials_match_configured_hosts_only() {
+        let rules = CredentialRules::compile(&[
+            credential("npm.internal.example.com", "Authorization", "Bearer first"),
+            credential("*.internal.example.com", "authorization", "Bearer second"),
+            credential("*.internal.example.com", "x-api-key", "key"),
+        ])
+        .expect("credentials should compile");
+
+        assert_eq!(
+            rules.for_host("npm.internal.example.com"),
+            vec![
+                (AUTHORIZATION, "Bearer first".to_string()),
+                (HeaderName::from_static("x-api-key"), "key".to_string()),
+            ]
+        );
+        assert_eq!(
+            rules.for_host("git.internal.example.com"),
+            vec![
+                (AUTHORIZATION, "Bearer second".to_string()),
+                (HeaderName::from_static("x-api-key"), "key".to_string()),
+            ]
+        );
+        assert_eq!(rules.for_host("registry.npmjs.org"), Vec::new());
+        assert!(!rules.applies_to_host("internal.example.com.evil.test"));
+    }
+
+    #[test]
+    fn apply_credentials_replaces_client_credentials() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer client"));
+        headers.insert("x-api-key", HeaderValue::from_static("client-key"));
+        headers.insert("accept", HeaderValue::from_static("*/*"));
+
+        apply_credentials(
+            &mut headers,
+            vec![(
+                HeaderName::from_static("x-api-key"),
+                HeaderValue::from_static("proxy-key"),
+            )],
+        );
+
+        assert_eq!(headers.get(AUTHORIZATION), None);
+        assert_eq!(
+            headers.get("x-api-key"),
+            Some(&HeaderValue::from_static("proxy-key"))
+        );
+        assert_eq!(
+            headers.get("accept"),
+            Some(&HeaderValue::from_static("*/*"))
+        );
+    }
+
+    #[test]
+    fn strip_client_credentials_reports_whether_anything_was_removed() {
+        let credential_headers = [HeaderName::from_static("x-api-key")];
+        let mut headers = HeaderMap::new();
+        headers.insert("accept", HeaderValue::from_static("*/*"));
+
+        assert!(!strip_client_credentials(&mut headers, &credential_headers));
+
+        headers.insert("x-api-key", HeaderValue::from_static("client-key"));
+        assert!(strip_client_credentials(&mut headers, &credential_headers));
+        assert_eq!(headers.get("x-api-key"), None);
+    }
+
+    #[test]
+    fn invalid_credentials_are_rejected() {
+        for invalid in [
+            credential("", "authorization", "Bearer token"),
+            credential("example.com", "bad header", "Bearer token"),
+            credential("example.com", "authorization", ""),
+        ] {
+            assert!(CredentialRules::compile(&[invalid]).is_err());
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::config::NetworkMode;
 use crate::connect_policy::TargetCheckedTcpConnector;
+use crate::credentials::strip_client_credentials;
+use crate::http_rules::HttpRequestDecision;
 use crate::mitm;
 use crate::network_policy::BlockDecisionAuditEventArgs;
 use crate::network_policy::NetworkDecision;
@@ -27,6 +29,7 @@ use crate::runtime::unix_socket_permissions_supported;
 use crate::state::BlockedRequest;
 use crate::state::BlockedRequestArgs;
 use crate::state::NetworkProxyState;
+use crate::traffic_log::TrafficEntry;
 use crate::upstream::UpstreamClient;
 use crate::upstream::proxy_for_connect;
 use anyhow::Context as _;
@@ -228,6 +231,7 @@ async fn http_connect_accept(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(authority.port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -257,13 +261,21 @@ async fn http_connect_accept(
         }
     };
 
-    if mode == NetworkMode::Limited && mitm_state.is_none() {
-        // Limited mode is designed to be read-only. Without MITM, a CONNECT tunnel would hide the
-        // inner HTTP method/headers from the proxy, effectively bypassing method policy.
+    let mitm_requirement = app_state
+        .connect_mitm_requirement(&host)
+        .await
+        .map_err(|err| internal_error("failed to evaluate HTTP rules", err))?;
+
+    if let Some(source) = mitm_requirement
+        && mitm_state.is_none()
+    {
+        // Limited mode is designed to be read-only, HTTP rules match on method and path, and
+        // credentials are added to the inner requests. Without MITM, a CONNECT tunnel would hide
+        // the inner HTTP method/headers from the proxy, effectively bypassing all three.
         emit_http_block_decision_audit_event(
             &app_state,
             BlockDecisionAuditEventArgs {
-                source: NetworkDecisionSource::ModeGuard,
+                source,
                 reason: REASON_MITM_REQUIRED,
                 protocol: NetworkProtocol::HttpsConnect,
                 server_address: host.as_str(),
@@ -275,7 +287,7 @@ async fn http_connect_accept(
         let details = PolicyDecisionDetails {
             decision: NetworkPolicyDecision::Deny,
             reason: REASON_MITM_REQUIRED,
-            source: NetworkDecisionSource::ModeGuard,
+            source,
             protocol: NetworkProtocol::HttpsConnect,
             host: &host,
             port: authority.port,
@@ -286,23 +298,40 @@ async fn http_connect_accept(
                 reason: REASON_MITM_REQUIRED.to_string(),
                 client: client.clone(),
                 method: Some("CONNECT".to_string()),
-                mode: Some(NetworkMode::Limited),
+                mode: Some(mode),
                 protocol: "http-connect".to_string(),
                 decision: Some(details.decision.as_str().to_string()),
                 source: Some(details.source.as_str().to_string()),
                 port: Some(authority.port),
+                path: None,
             }))
             .await;
         let client = client.as_deref().unwrap_or_default();
-        warn!(
-            "CONNECT blocked; MITM required for read-only HTTPS in limited mode (client={client}, host={host}, mode=limited, allowed_methods=GET, HEAD, OPTIONS)"
-        );
+        match source {
+            NetworkDecisionSource::ModeGuard => warn!(
+                "CONNECT blocked; MITM required for read-only HTTPS in limited mode (client={client}, host={host}, mode=limited, allowed_methods=GET, HEAD, OPTIONS)"
+            ),
+            NetworkDecisionSource::Credentials => warn!(
+                "CONNECT blocked; MITM required to inject configured credentials (client={client}, host={host})"
+            ),
+            _ => warn!(
+                "CONNECT blocked; MITM required to enforce HTTP rules (client={client}, host={host})"
+            ),
+        }
         return Err(blocked_text_with_details(REASON_MITM_REQUIRED, &details));
     }
 
+    app_state
+        .record_traffic(
+            TrafficEntry::allowed("http-connect", &host, authority.port, client)
+                .with_request("CONNECT", /*path*/ None),
+        )
+        .await;
     req.extensions_mut().insert(ProxyTarget(authority));
     req.extensions_mut().insert(mode);
-    if let Some(mitm_state) = mitm_state {
+    if mitm_requirement.is_some()
+        && let Some(mitm_state) = mitm_state
+    {
         req.extensions_mut().insert(mitm_state);
     }
 
@@ -331,11 +360,11 @@ async fn http_connect_proxy(upgraded: Upgraded) -> Result<(), Infallible> {
         return Ok(());
     };
 
-    if mode == NetworkMode::Limited
-        && upgraded
-            .extensions()
-            .get::<Arc<mitm::MitmState>>()
-            .is_some()
+    // `http_connect_accept` only attaches the MITM state when the tunnel must be intercepted.
+    if upgraded
+        .extensions()
+        .get::<Arc<mitm::MitmState>>()
+        .is_some()
     {
         let host = normalize_host(&target.host.to_string());
         let port = target.port;
@@ -698,6 +727,7 @@ async fn http_plain_proxy(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: Some(req.uri().path().to_string()),
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -711,12 +741,33 @@ async fn http_plain_proxy(
         }
     }
 
-    if !method_allowed {
+    let mode = match app_state
+        .network_mode()
+        .await
+        .map_err(|err| internal_error("failed to read network mode", err))
+    {
+        Ok(mode) => mode,
+        Err(resp) => return Ok(resp),
+    };
+    let request_decision = match app_state
+        .http_request_decision(mode, req.method().as_str(), &host, req.uri().path())
+        .await
+        .map_err(|err| internal_error("failed to evaluate HTTP rules", err))
+    {
+        Ok(decision) => decision,
+        Err(resp) => return Ok(resp),
+    };
+    if let HttpRequestDecision::Deny {
+        reason,
+        source,
+        rule,
+    } = request_decision
+    {
         emit_http_block_decision_audit_event(
             &app_state,
             BlockDecisionAuditEventArgs {
-                source: NetworkDecisionSource::ModeGuard,
-                reason: REASON_METHOD_NOT_ALLOWED,
+                source,
+                reason,
                 protocol: NetworkProtocol::Http,
                 server_address: host.as_str(),
                 server_port: port,
@@ -726,8 +777,8 @@ async fn http_plain_proxy(
         );
         let details = PolicyDecisionDetails {
             decision: NetworkPolicyDecision::Deny,
-            reason: REASON_METHOD_NOT_ALLOWED,
-            source: NetworkDecisionSource::ModeGuard,
+            reason,
+            source,
             protocol: NetworkProtocol::Http,
             host: &host,
             port,
@@ -735,28 +786,40 @@ async fn http_plain_proxy(
         let _ = app_state
             .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                 host: host.clone(),
-                reason: REASON_METHOD_NOT_ALLOWED.to_string(),
+                reason: reason.to_string(),
                 client: client.clone(),
                 method: Some(req.method().as_str().to_string()),
-                mode: Some(NetworkMode::Limited),
+                mode: Some(mode),
                 protocol: "http".to_string(),
                 decision: Some(details.decision.as_str().to_string()),
                 source: Some(details.source.as_str().to_string()),
                 port: Some(port),
+                path: Some(req.uri().path().to_string()),
             }))
             .await;
         let client = client.as_deref().unwrap_or_default();
         let method = req.method();
-        warn!(
-            "request blocked by method policy (client={client}, host={host}, method={method}, mode=limited, allowed_methods=GET, HEAD, OPTIONS)"
-        );
-        return Ok(json_blocked(
-            &host,
-            REASON_METHOD_NOT_ALLOWED,
-            Some(&details),
-        ));
+        let path = req.uri().path();
+        match rule {
+            Some(rule) => warn!(
+                "request blocked by HTTP rule (client={client}, host={host}, method={method}, path={path}, rule={rule})"
+            ),
+            None => warn!(
+                "request blocked by method policy (client={client}, host={host}, method={method}, mode=limited, allowed_methods=GET, HEAD, OPTIONS)"
+            ),
+        }
+        return Ok(json_blocked(&host, reason, Some(&details)));
     }
 
+    let mut traffic = app_state
+        .begin_traffic(
+            TrafficEntry::allowed("http", &host, port, client.clone())
+                .with_request(req.method().as_str(), Some(req.uri().path())),
+        )
+        .await;
+    if let Some(traffic) = traffic.as_mut() {
+        traffic.set_request_size(req.headers());
+    }
     let client = client.as_deref().unwrap_or_default();
     let method = req.method();
     info!("request allowed (client={client}, host={host}, method={method})");
@@ -775,12 +838,35 @@ async fn http_plain_proxy(
         UpstreamClient::direct(app_state.clone())
     };
 
+    let credential_headers = match app_state
+        .credential_header_names(&host)
+        .await
+        .map_err(|err| internal_error("failed to evaluate network credentials", err))
+    {
+        Ok(credential_headers) => credential_headers,
+        Err(resp) => return Ok(resp),
+    };
+    if !credential_headers.is_empty() {
+        // Configured credentials are only sent over HTTPS, and the client may not send its own.
+        if strip_client_credentials(req.headers_mut(), &credential_headers) {
+            warn!(
+                "stripped client credentials from plain HTTP request; credentials are only injected over HTTPS (host={host})"
+            );
+        }
+    }
+
     // Strip hop-by-hop headers only after extracting metadata used for policy correlation.
     remove_hop_by_hop_request_headers(req.headers_mut());
     match client.serve(req).await {
-        Ok(resp) => Ok(resp),
+        Ok(resp) => Ok(match traffic {
+            Some(traffic) => traffic.finish_with_response(resp),
+            None => resp,
+        }),
         Err(err) => {
             warn!("upstream request failed: {err}");
+            if let Some(traffic) = traffic {
+                traffic.finish_without_response(StatusCode::BAD_GATEWAY.as_u16());
+            }
             Ok(text_response(StatusCode::BAD_GATEWAY, "upstream failure"))
         }
     }
@@ -960,6 +1046,7 @@ async fn proxy_disabled_response(
             decision: Some("deny".to_string()),
             source: Some("proxy_state".to_string()),
             port: Some(port),
+            path: None,
         }))
         .await;
 
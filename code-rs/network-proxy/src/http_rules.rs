@@ -0,0 +1,375 @@
+use crate::config::NetworkHttpRule;
+use crate::config::NetworkHttpRuleDecision;
+use crate::config::NetworkMode;
+use crate::network_policy::NetworkDecisionSource;
+use crate::policy::compile_allowlist_globset;
+use crate::policy::unscoped_ip_literal;
+use crate::reasons::REASON_HTTP_RULE_DENIED;
+use crate::reasons::REASON_METHOD_NOT_ALLOWED;
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::ensure;
+use globset::GlobBuilder;
+use globset::GlobMatcher;
+use globset::GlobSet;
+
+/// Compiled form of `network.http_rules`.
+#[derive(Clone, Default)]
+pub(crate) struct HttpRules {
+    rules: Vec<CompiledHttpRule>,
+}
+
+#[derive(Clone)]
+struct CompiledHttpRule {
+    hosts: GlobSet,
+    methods: Vec<String>,
+    path: Option<GlobMatcher>,
+    decision: NetworkHttpRuleDecision,
+    description: String,
+}
+
+/// Outcome of checking one HTTP request against the rules and the network mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HttpRequestDecision {
+    Allow,
+    Deny {
+        reason: &'static str,
+        source: NetworkDecisionSource,
+        /// The rule that denied the request, for logs.
+        rule: Option<String>,
+    },
+}
+
+impl HttpRules {
+    pub(crate) fn compile(rules: &[NetworkHttpRule]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| {
+                compile_rule(rule).with_context(|| format!("invalid network.http_rules[{index}]"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Returns true when some rule may apply to requests for `host`. CONNECT tunnels to such
+    /// hosts must be intercepted so the rules can see the inner requests.
+    pub(crate) fn applies_to_host(&self, host: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| globset_matches_host(&rule.hosts, host))
+    }
+
+    /// Decides an HTTP request. The first rule matching host, method and path wins; when none
+    /// matches, the method policy of `mode` applies.
+    pub(crate) fn decide(
+        &self,
+        mode: NetworkMode,
+        method: &str,
+        host: &str,
+        path: &str,
+    ) -> HttpRequestDecision {
+        let method = method.to_ascii_uppercase();
+        let path = normalize_request_path(path);
+        let matched = self.rules.iter().find(|rule| {
+            globset_matches_host(&rule.hosts, host)
+                && (rule.methods.is_empty() || rule.methods.contains(&method))
+                && rule.path.as_ref().is_none_or(|glob| glob.is_match(&path))
+        });
+        match matched {
+            Some(rule) => match rule.decision {
+                NetworkHttpRuleDecision::Allow => HttpRequestDecision::Allow,
+                NetworkHttpRuleDecision::Deny => HttpRequestDecision::Deny {
+                    reason: REASON_HTTP_RULE_DENIED,
+                    source: NetworkDecisionSource::HttpRule,
+                    rule: Some(rule.description.clone()),
+                },
+            },
+            None if mode.allows_method(&method) => HttpRequestDecision::Allow,
+            None => HttpRequestDecision::Deny {
+                reason: REASON_METHOD_NOT_ALLOWED,
+                source: NetworkDecisionSource::ModeGuard,
+                rule: None,
+            },
+        }
+    }
+}
+
+fn compile_rule(rule: &NetworkHttpRule) -> Result<CompiledHttpRule> {
+    let host = rule.host.trim();
+    ensure!(!host.is_empty(), "host must not be empty");
+    let hosts = compile_allowlist_globset(&[host.to_string()])?;
+
+    let mut methods = Vec::with_capacity(rule.methods.len());
+    for method in &rule.methods {
+        let method = method.trim().to_ascii_uppercase();
+        ensure!(
+            !method.is_empty() && method.bytes().all(|byte| byte.is_ascii_alphabetic()),
+            "invalid HTTP method {method:?}"
+        );
+        if !methods.contains(&method) {
+            methods.push(method);
+        }
+    }
+
+    let path = match rule.path.as_deref() {
+        Some(path) => {
+            ensure!(path.starts_with('/'), "path {path:?} must start with `/`");
+            let glob = GlobBuilder::new(path)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("invalid path glob {path:?}"))?;
+            Some(glob.compile_matcher())
+        }
+        None => None,
+    };
+
+    let decision = match rule.decision {
+        NetworkHttpRuleDecision::Allow => "allow",
+        NetworkHttpRuleDecision::Deny => "deny",
+    };
+    let methods_label = if methods.is_empty() {
+        "*".to_string()
+    } else {
+        methods.join(",")
+    };
+    let description = format!(
+        "{decision} {methods_label} {host}{}",
+        rule.path.as_deref().unwrap_or("/**")
+    );
+
+    Ok(CompiledHttpRule {
+        hosts,
+        methods,
+        path,
+        decision: rule.decision,
+        description,
+    })
+}
+
+pub(crate) fn globset_matches_host(set: &GlobSet, host: &str) -> bool {
+    set.is_match(host) || unscoped_ip_literal(host).is_some_and(|ip| set.is_match(ip))
+}
+
+/// Reduces a request path to the form rules are matched against, so that encodings and dot
+/// segments cannot be used to slip past a path glob: the query is dropped, percent escapes are
+/// decoded, empty and `.` segments are removed and `..` segments are resolved.
+fn normalize_request_path(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let decoded = percent_decode(path);
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in decoded.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    let mut normalized = format!("/{}", segments.join("/"));
+    if decoded.ends_with('/') && !segments.is_empty() {
+        normalized.push('/');
+    }
+    normalized
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && let Some(hex) = input.get(index + 1..index + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn rule(
+        host: &str,
+        methods: &[&str],
+        path: Option<&str>,
+        decision: NetworkHttpRuleDecision,
+    ) -> NetworkHttpRule {
+        NetworkHttpRule {
+            host: host.to_string(),
+            methods: methods.iter().map(|method| (*method).to_string()).collect(),
+            path: path.map(str::to_string),
+            decision,
+        }
+    }
+
+    fn denied_by_rule(rule: &str) -> HttpRequestDecision {
+        HttpRequestDecision::Deny {
+            reason: REASON_HTTP_RULE_DENIED,
+            source: NetworkDecisionSource::HttpRule,
+            rule: Some(rule.to_string()),
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_decides_and_mode_applies_otherwise() {
+        let rules = HttpRules::compile(&[
+            rule(
+                "api.github.com",
+                &["post"],
+                Some("/repos/our-org/**"),
+                NetworkHttpRuleDecision::Allow,
+            ),
+            rule(
+                "registry.npmjs.org",
+                &["GET", "HEAD"],
+                None,
+                NetworkHttpRuleDecision::Allow,
+            ),
+            rule(
+                "registry.npmjs.org",
+                &[],
+                None,
+                NetworkHttpRuleDecision::Deny,
+            ),
+        ])
+        .expect("rules should compile");
+
+        assert_eq!(
+            rules.decide(
+                NetworkMode::Limited,
+                "POST",
+                "api.github.com",
+                "/repos/our-org/app/issues"
+            ),
+            HttpRequestDecision::Allow
+        );
+        assert_eq!(
+            rules.decide(
+                NetworkMode::Limited,
+                "POST",
+                "api.github.com",
+                "/repos/other-org/app/issues"
+            ),
+            HttpRequestDecision::Deny {
+                reason: REASON_METHOD_NOT_ALLOWED,
+                source: NetworkDecisionSource::ModeGuard,
+                rule: None,
+            }
+        );
+        assert_eq!(
+            rules.decide(NetworkMode::Full, "GET", "registry.npmjs.org", "/left-pad"),
+            HttpRequestDecision::Allow
+        );
+        assert_eq!(
+            rules.decide(NetworkMode::Full, "PUT", "registry.npmjs.org", "/left-pad"),
+            denied_by_rule("deny * registry.npmjs.org/**")
+        );
+        assert_eq!(
+            rules.decide(NetworkMode::Full, "DELETE", "example.com", "/"),
+            HttpRequestDecision::Allow
+        );
+    }
+
+    #[test]
+    fn encoded_and_dotted_paths_cannot_escape_path_globs() {
+        let rules = HttpRules::compile(&[rule(
+            "**.example.com",
+            &[],
+            Some("/admin/**"),
+            NetworkHttpRuleDecision::Deny,
+        )])
+        .expect("rules should compile");
+        let denied = denied_by_rule("deny * **.example.com/admin/**");
+
+        for path in [
+            "/admin/users",
+            "/public/../admin/users",
+            "//admin/./users",
+            "/%61dmin/users?x=1",
+        ] {
+            assert_eq!(
+                rules.decide(NetworkMode::Full, "GET", "api.example.com", path),
+                denied,
+                "path {path} should be denied"
+            );
+        }
+        assert_eq!(
+            rules.decide(
+                NetworkMode::Full,
+                "GET",
+                "api.example.com",
+                "/administrator"
+            ),
+            HttpRequestDecision::Allow
+        );
+        assert!(rules.applies_to_host("example.com"));
+        assert!(!rules.applies_to_host("example.org"));
+    }
+
+    #[test]
+    fn single_star_stays_within_one_path_segment() {
+        let rules = HttpRules::compile(&[rule(
+            "api.github.com",
+            &[],
+            Some("/repos/*/issues"),
+            NetworkHttpRuleDecision::Deny,
+        )])
+        .expect("rules should compile");
+
+        assert_eq!(
+            rules.decide(
+                NetworkMode::Full,
+                "GET",
+                "api.github.com",
+                "/repos/app/issues"
+            ),
+            denied_by_rule("deny * api.github.com/repos/*/issues")
+        );
+        assert_eq!(
+            rules.decide(
+                NetworkMode::Full,
+                "GET",
+                "api.github.com",
+                "/repos/our-org/app/issues"
+            ),
+            HttpRequestDecision::Allow
+        );
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        for invalid in [
+            rule("", &[], None, NetworkHttpRuleDecision::Deny),
+            rule(
+                "example.com",
+                &["GET /"],
+                None,
+                NetworkHttpRuleDecision::Deny,
+            ),
+            rule(
+                "example.com",
+                &[],
+                Some("admin/*"),
+                NetworkHttpRuleDecision::Deny,
+            ),
+            rule(
+                "example.com",
+                &[],
+                Some("/admin/[a-"),
+                NetworkHttpRuleDecision::Deny,
+            ),
+        ] {
+            assert!(HttpRules::compile(&[invalid]).is_err());
+        }
+    }
+}
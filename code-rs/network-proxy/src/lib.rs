@@ -3,7 +3,9 @@
 mod certs;
 mod config;
 mod connect_policy;
+mod credentials;
 mod http_proxy;
+mod http_rules;
 mod mitm;
 mod network_policy;
 mod policy;
@@ -13,16 +15,21 @@ mod responses;
 mod runtime;
 mod socks5;
 mod state;
+mod traffic_log;
 mod upstream;
 
+pub use config::NetworkCredential;
 pub use config::NetworkDomainPermission;
 pub use config::NetworkDomainPermissionEntry;
 pub use config::NetworkDomainPermissions;
+pub use config::NetworkHttpRule;
+pub use config::NetworkHttpRuleDecision;
 pub use config::NetworkMode;
 pub use config::NetworkProxyConfig;
 pub use config::NetworkUnixSocketPermission;
 pub use config::NetworkUnixSocketPermissions;
 pub use config::host_and_port_from_network_addr;
+pub use credentials::CredentialResolver;
 pub use network_policy::NetworkDecision;
 pub use network_policy::NetworkDecisionSource;
 pub use network_policy::NetworkPolicyDecider;
@@ -61,3 +68,9 @@ pub use state::PartialNetworkConfig;
 pub use state::PartialNetworkProxyConfig;
 pub use state::build_config_state;
 pub use state::validate_policy_against_constraints;
+pub use traffic_log::NETWORK_TRAFFIC_LOG_DIR;
+pub use traffic_log::NetworkTrafficLog;
+pub use traffic_log::NetworkTrafficRecord;
+pub use traffic_log::default_traffic_log_dir;
+pub use traffic_log::read_traffic_log;
+pub use traffic_log::traffic_har;
@@ -1,7 +1,12 @@
 use crate::certs::ManagedMitmCa;
 use crate::config::NetworkMode;
+use crate::credentials::apply_credentials;
+use crate::http_rules::HttpRequestDecision;
+use crate::network_policy::BlockDecisionAuditEventArgs;
+use crate::network_policy::NetworkPolicyDecision;
+use crate::network_policy::NetworkProtocol;
+use crate::network_policy::emit_block_decision_audit_event;
 use crate::policy::normalize_host;
-use crate::reasons::REASON_METHOD_NOT_ALLOWED;
 use crate::responses::blocked_text_response;
 use crate::responses::text_response;
 use crate::runtime::HostBlockDecision;
@@ -9,6 +14,7 @@ use crate::runtime::HostBlockReason;
 use crate::state::BlockedRequest;
 use crate::state::BlockedRequestArgs;
 use crate::state::NetworkProxyState;
+use crate::traffic_log::TrafficEntry;
 use crate::upstream::UpstreamClient;
 use anyhow::Context as _;
 use anyhow::Result;
@@ -211,8 +217,46 @@ async fn forward_request(req: Request, request_ctx: &MitmRequestContext) -> Resu
     let method = req.method().as_str().to_string();
     let path = path_and_query(req.uri());
     let log_path = path_for_log(req.uri());
+    let client = req
+        .extensions()
+        .get::<SocketInfo>()
+        .map(|info| info.peer_addr().to_string());
+    let mut traffic = request_ctx
+        .policy
+        .app_state
+        .begin_traffic(
+            TrafficEntry::allowed("https", &target_host, target_port, client)
+                .with_request(&method, Some(log_path.as_str())),
+        )
+        .await;
 
     let (mut parts, body) = req.into_parts();
+    if let Some(traffic) = traffic.as_mut() {
+        traffic.set_request_size(&parts.headers);
+    }
+    match request_ctx
+        .policy
+        .app_state
+        .credential_headers(&target_host)
+        .await
+    {
+        Ok(credentials) if !credentials.is_empty() => {
+            apply_credentials(&mut parts.headers, credentials);
+        }
+        Ok(_) => {}
+        Err(err) => {
+            warn!(
+                "MITM credential injection failed (host={target_host}, method={method}, path={log_path}): {err:#}"
+            );
+            if let Some(traffic) = traffic {
+                traffic.finish_without_response(StatusCode::BAD_GATEWAY.as_u16());
+            }
+            return Ok(text_response(
+                StatusCode::BAD_GATEWAY,
+                "credential injection failed",
+            ));
+        }
+    }
     let authority = authority_header_value(&target_host, target_port);
     parts.uri = build_https_uri(&authority, &path)?;
     parts
@@ -236,15 +280,27 @@ async fn forward_request(req: Request, request_ctx: &MitmRequestContext) -> Resu
     };
 
     let upstream_req = Request::from_parts(parts, body);
-    let upstream_resp = mitm.upstream.serve(upstream_req).await?;
-    respond_with_inspection(
+    let upstream_resp = match mitm.upstream.serve(upstream_req).await {
+        Ok(resp) => resp,
+        Err(err) => {
+            if let Some(traffic) = traffic {
+                traffic.finish_without_response(StatusCode::BAD_GATEWAY.as_u16());
+            }
+            return Err(err.into());
+        }
+    };
+    let resp = respond_with_inspection(
         upstream_resp,
         inspect,
         max_body_bytes,
         &method,
         &log_path,
         &authority,
-    )
+    )?;
+    Ok(match traffic {
+        Some(traffic) => traffic.finish_with_response(resp),
+        None => resp,
+    })
 }
 
 async fn mitm_blocking_response(
@@ -301,6 +357,7 @@ async fn mitm_blocking_response(
                 decision: None,
                 source: None,
                 port: Some(policy.target_port),
+                path: Some(log_path.clone()),
             }))
             .await;
         warn!(
@@ -310,26 +367,53 @@ async fn mitm_blocking_response(
         return Ok(Some(blocked_text_response(reason)));
     }
 
-    if !policy.mode.allows_method(&method) {
+    if let HttpRequestDecision::Deny {
+        reason,
+        source,
+        rule,
+    } = policy
+        .app_state
+        .http_request_decision(policy.mode, &method, &policy.target_host, req.uri().path())
+        .await?
+    {
+        emit_block_decision_audit_event(
+            &policy.app_state,
+            BlockDecisionAuditEventArgs {
+                source,
+                reason,
+                protocol: NetworkProtocol::HttpsConnect,
+                server_address: policy.target_host.as_str(),
+                server_port: policy.target_port,
+                method: Some(method.as_str()),
+                client_addr: client.as_deref(),
+            },
+        );
         let _ = policy
             .app_state
             .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                 host: policy.target_host.clone(),
-                reason: REASON_METHOD_NOT_ALLOWED.to_string(),
+                reason: reason.to_string(),
                 client: client.clone(),
                 method: Some(method.clone()),
                 mode: Some(policy.mode),
                 protocol: "https".to_string(),
-                decision: None,
-                source: None,
+                decision: Some(NetworkPolicyDecision::Deny.as_str().to_string()),
+                source: Some(source.as_str().to_string()),
                 port: Some(policy.target_port),
+                path: Some(log_path.clone()),
             }))
             .await;
-        warn!(
-            "MITM blocked by method policy (host={}, method={method}, path={log_path}, mode={:?}, allowed_methods=GET, HEAD, OPTIONS)",
-            policy.target_host, policy.mode
-        );
-        return Ok(Some(blocked_text_response(REASON_METHOD_NOT_ALLOWED)));
+        match rule {
+            Some(rule) => warn!(
+                "MITM blocked by HTTP rule (host={}, method={method}, path={log_path}, rule={rule})",
+                policy.target_host
+            ),
+            None => warn!(
+                "MITM blocked by method policy (host={}, method={method}, path={log_path}, mode={:?}, allowed_methods=GET, HEAD, OPTIONS)",
+                policy.target_host, policy.mode
+            ),
+        }
+        return Ok(Some(blocked_text_response(reason)));
     }
 
     Ok(None)
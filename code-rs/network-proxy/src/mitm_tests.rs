@@ -1,6 +1,12 @@
 use super::*;
 
+use crate::config::NetworkHttpRule;
+use crate::config::NetworkHttpRuleDecision;
 use crate::config::NetworkProxySettings;
+use crate::network_policy::test_support::POLICY_DECISION_EVENT_NAME;
+use crate::network_policy::test_support::capture_events;
+use crate::network_policy::test_support::find_event_by_name;
+use crate::reasons::REASON_HTTP_RULE_DENIED;
 use crate::reasons::REASON_METHOD_NOT_ALLOWED;
 use crate::reasons::REASON_NOT_ALLOWED_LOCAL;
 use crate::runtime::network_proxy_state_for_policy;
@@ -126,3 +132,90 @@ async fn mitm_policy_rechecks_local_private_target_after_connect() {
     assert_eq!(blocked[0].host, "10.0.0.1");
     assert_eq!(blocked[0].port, Some(443));
 }
+
+#[tokio::test]
+async fn mitm_policy_applies_http_rules_before_mode() {
+    let app_state = Arc::new(network_proxy_state_for_policy({
+        let mut network = NetworkProxySettings::default();
+        network.set_allowed_domains(vec!["api.github.com".to_string()]);
+        network.http_rules = vec![
+            NetworkHttpRule {
+                host: "api.github.com".to_string(),
+                methods: vec!["POST".to_string()],
+                path: Some("/repos/our-org/**".to_string()),
+                decision: NetworkHttpRuleDecision::Allow,
+            },
+            NetworkHttpRule {
+                host: "api.github.com".to_string(),
+                methods: Vec::new(),
+                path: Some("/admin/**".to_string()),
+                decision: NetworkHttpRuleDecision::Deny,
+            },
+        ];
+        network
+    }));
+    let ctx = policy_ctx(
+        app_state.clone(),
+        NetworkMode::Limited,
+        "api.github.com",
+        /*target_port*/ 443,
+    );
+    let request = |method: Method, uri: &str| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(HOST, "api.github.com")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let allowed = mitm_blocking_response(&request(Method::POST, "/repos/our-org/app/issues"), &ctx)
+        .await
+        .unwrap();
+    assert!(
+        allowed.is_none(),
+        "allow rule should permit POST in limited mode"
+    );
+
+    let (response, events) = capture_events(|| async {
+        mitm_blocking_response(&request(Method::GET, "/admin/users"), &ctx)
+            .await
+            .unwrap()
+            .expect("deny rule should block GET")
+    })
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        response.headers().get("x-proxy-error").unwrap(),
+        "blocked-by-http-rule"
+    );
+    let event = find_event_by_name(&events, POLICY_DECISION_EVENT_NAME)
+        .expect("expected policy decision audit event");
+    assert_eq!(event.field("network.policy.decision"), Some("deny"));
+    assert_eq!(event.field("network.policy.source"), Some("http_rule"));
+    assert_eq!(
+        event.field("network.policy.reason"),
+        Some(REASON_HTTP_RULE_DENIED)
+    );
+
+    let blocked = mitm_blocking_response(&request(Method::POST, "/repos/other-org/app"), &ctx)
+        .await
+        .unwrap()
+        .expect("POST outside the allow rule should fall back to limited mode");
+    assert_eq!(
+        blocked.headers().get("x-proxy-error").unwrap(),
+        "blocked-by-method-policy"
+    );
+
+    let blocked = app_state.drain_blocked().await.unwrap();
+    assert_eq!(
+        blocked
+            .iter()
+            .map(|entry| (entry.reason.as_str(), entry.source.as_deref()))
+            .collect::<Vec<_>>(),
+        vec![
+            (REASON_HTTP_RULE_DENIED, Some("http_rule")),
+            (REASON_METHOD_NOT_ALLOWED, Some("mode_guard")),
+        ]
+    );
+}
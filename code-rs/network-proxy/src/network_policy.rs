@@ -61,6 +61,8 @@ pub enum NetworkDecisionSource {
     ModeGuard,
     ProxyState,
     Decider,
+    HttpRule,
+    Credentials,
 }
 
 impl NetworkDecisionSource {
@@ -70,6 +72,8 @@ impl NetworkDecisionSource {
             Self::ModeGuard => "mode_guard",
             Self::ProxyState => "proxy_state",
             Self::Decider => "decider",
+            Self::HttpRule => "http_rule",
+            Self::Credentials => "credentials",
         }
     }
 }
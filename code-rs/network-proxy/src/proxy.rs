@@ -1,4 +1,5 @@
 use crate::config;
+use crate::credentials::CredentialResolver;
 use crate::http_proxy;
 use crate::network_policy::NetworkPolicyDecider;
 use crate::runtime::BlockedRequestObserver;
@@ -6,6 +7,7 @@ use crate::runtime::ConfigState;
 use crate::runtime::unix_socket_permissions_supported;
 use crate::socks5;
 use crate::state::NetworkProxyState;
+use crate::traffic_log::NetworkTrafficLog;
 use anyhow::Context;
 use anyhow::Result;
 use clap::Parser;
@@ -98,6 +100,8 @@ pub struct NetworkProxyBuilder {
     managed_by_codex: bool,
     policy_decider: Option<Arc<dyn NetworkPolicyDecider>>,
     blocked_request_observer: Option<Arc<dyn BlockedRequestObserver>>,
+    traffic_log: Option<Arc<NetworkTrafficLog>>,
+    credential_resolver: Option<Arc<dyn CredentialResolver>>,
 }
 
 impl Default for NetworkProxyBuilder {
@@ -109,6 +113,8 @@ impl Default for NetworkProxyBuilder {
             managed_by_codex: true,
             policy_decider: None,
             blocked_request_observer: None,
+            traffic_log: None,
+            credential_resolver: None,
         }
     }
 }
@@ -163,6 +169,18 @@ impl NetworkProxyBuilder {
         self
     }
 
+    /// Records every request the proxy sees to `traffic_log`.
+    pub fn traffic_log(mut self, traffic_log: Arc<NetworkTrafficLog>) -> Self {
+        self.traffic_log = Some(traffic_log);
+        self
+    }
+
+    /// Resolves `network.credentials` values before they are injected into requests.
+    pub fn credential_resolver(mut self, resolver: Arc<dyn CredentialResolver>) -> Self {
+        self.credential_resolver = Some(resolver);
+        self
+    }
+
     pub async fn build(self) -> Result<NetworkProxy> {
         let state = self.state.ok_or_else(|| {
             anyhow::anyhow!(
@@ -172,6 +190,10 @@ impl NetworkProxyBuilder {
         state
             .set_blocked_request_observer(self.blocked_request_observer.clone())
             .await;
+        state.set_traffic_log(self.traffic_log.clone()).await;
+        state
+            .set_credential_resolver(self.credential_resolver.clone())
+            .await;
         let current_cfg = state.current_cfg().await?;
         let (requested_http_addr, requested_socks_addr, reserved_listeners) = if self
             .managed_by_codex
@@ -579,6 +601,19 @@ impl NetworkProxy {
         self.state.add_denied_domain(host).await
     }
 
+    pub async fn set_traffic_log(&self, traffic_log: Option<Arc<NetworkTrafficLog>>) {
+        self.state.set_traffic_log(traffic_log).await;
+    }
+
+    pub async fn set_credential_resolver(&self, resolver: Option<Arc<dyn CredentialResolver>>) {
+        self.state.set_credential_resolver(resolver).await;
+    }
+
+    /// Attributes subsequent traffic records to `turn_id`.
+    pub async fn set_active_turn_id(&self, turn_id: Option<String>) {
+        self.state.set_active_turn_id(turn_id).await;
+    }
+
     pub fn allow_local_binding(&self) -> bool {
         self.runtime_settings().allow_local_binding
     }
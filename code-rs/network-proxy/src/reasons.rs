@@ -1,4 +1,5 @@
 pub(crate) const REASON_DENIED: &str = "denied";
+pub(crate) const REASON_HTTP_RULE_DENIED: &str = "http_rule_denied";
 pub(crate) const REASON_METHOD_NOT_ALLOWED: &str = "method_not_allowed";
 pub(crate) const REASON_MITM_REQUIRED: &str = "mitm_required";
 pub(crate) const REASON_NOT_ALLOWED: &str = "not_allowed";
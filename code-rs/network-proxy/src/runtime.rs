@@ -2,7 +2,11 @@ use crate::config::NetworkDomainPermission;
 use crate::config::NetworkMode;
 use crate::config::NetworkProxyConfig;
 use crate::config::ValidatedUnixSocketPath;
+use crate::credentials::CredentialResolver;
+use crate::credentials::CredentialRules;
+use crate::http_rules::HttpRequestDecision;
 use crate::mitm::MitmState;
+use crate::network_policy::NetworkDecisionSource;
 use crate::policy::Host;
 use crate::policy::is_loopback_host;
 use crate::policy::is_non_public_ip;
@@ -15,11 +19,16 @@ use crate::state::NetworkProxyConstraintError;
 use crate::state::NetworkProxyConstraints;
 use crate::state::build_config_state;
 use crate::state::validate_policy_against_constraints;
+use crate::traffic_log::NetworkTrafficLog;
+use crate::traffic_log::PendingTraffic;
+use crate::traffic_log::TrafficEntry;
 use anyhow::Context;
 use anyhow::Result;
 use async_trait::async_trait;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use globset::GlobSet;
+use rama_http::HeaderName;
+use rama_http::HeaderValue;
 use serde::Serialize;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -97,6 +106,8 @@ pub struct BlockedRequest {
     pub source: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
     pub timestamp: i64,
 }
 
@@ -110,6 +121,7 @@ pub struct BlockedRequestArgs {
     pub decision: Option<String>,
     pub source: Option<String>,
     pub port: Option<u16>,
+    pub path: Option<String>,
 }
 
 impl BlockedRequest {
@@ -124,6 +136,7 @@ impl BlockedRequest {
             decision,
             source,
             port,
+            path,
         } = args;
         Self {
             host,
@@ -135,6 +148,7 @@ impl BlockedRequest {
             decision,
             source,
             port,
+            path,
             timestamp: unix_timestamp(),
         }
     }
@@ -158,6 +172,8 @@ pub struct ConfigState {
     pub config: NetworkProxyConfig,
     pub allow_set: GlobSet,
     pub deny_set: GlobSet,
+    pub(crate) http_rules: HttpRules,
+    pub(crate) credentials: CredentialRules,
     pub mitm: Option<Arc<MitmState>>,
     pub constraints: NetworkProxyConstraints,
     pub blocked: VecDeque<BlockedRequest>,
@@ -203,6 +219,9 @@ pub struct NetworkProxyState {
     state: Arc<RwLock<ConfigState>>,
     reloader: Arc<dyn ConfigReloader>,
     blocked_request_observer: Arc<RwLock<Option<Arc<dyn BlockedRequestObserver>>>>,
+    traffic_log: Arc<RwLock<Option<Arc<NetworkTrafficLog>>>>,
+    credential_resolver: Arc<RwLock<Option<Arc<dyn CredentialResolver>>>>,
+    active_turn_id: Arc<RwLock<Option<String>>>,
     audit_metadata: NetworkProxyAuditMetadata,
 }
 
@@ -220,6 +239,9 @@ impl Clone for NetworkProxyState {
             state: self.state.clone(),
             reloader: self.reloader.clone(),
             blocked_request_observer: self.blocked_request_observer.clone(),
+            traffic_log: self.traffic_log.clone(),
+            credential_resolver: self.credential_resolver.clone(),
+            active_turn_id: self.active_turn_id.clone(),
             audit_metadata: self.audit_metadata.clone(),
         }
     }
@@ -270,6 +292,9 @@ impl NetworkProxyState {
             state: Arc::new(RwLock::new(state)),
             reloader,
             blocked_request_observer: Arc::new(RwLock::new(blocked_request_observer)),
+            traffic_log: Arc::new(RwLock::new(None)),
+            credential_resolver: Arc::new(RwLock::new(None)),
+            active_turn_id: Arc::new(RwLock::new(None)),
             audit_metadata,
         }
     }
@@ -282,6 +307,25 @@ impl NetworkProxyState {
         *observer = blocked_request_observer;
     }
 
+    pub async fn set_traffic_log(&self, traffic_log: Option<Arc<NetworkTrafficLog>>) {
+        let mut guard = self.traffic_log.write().await;
+        *guard = traffic_log;
+    }
+
+    pub async fn set_credential_resolver(
+        &self,
+        credential_resolver: Option<Arc<dyn CredentialResolver>>,
+    ) {
+        let mut guard = self.credential_resolver.write().await;
+        *guard = credential_resolver;
+    }
+
+    /// Sets the turn that traffic records are attributed to until the next call.
+    pub async fn set_active_turn_id(&self, turn_id: Option<String>) {
+        let mut guard = self.active_turn_id.write().await;
+        *guard = turn_id;
+    }
+
     pub fn audit_metadata(&self) -> &NetworkProxyAuditMetadata {
         &self.audit_metadata
     }
@@ -454,12 +498,33 @@ impl NetworkProxyState {
         );
         debug!("{violation_line}");
 
+        self.record_traffic(TrafficEntry::from_blocked(&blocked_for_observer))
+            .await;
         if let Some(observer) = blocked_request_observer {
             observer.on_blocked_request(blocked_for_observer).await;
         }
         Ok(())
     }
 
+    /// Appends a record for a request whose outcome is known at decision time.
+    pub(crate) async fn record_traffic(&self, entry: TrafficEntry) {
+        if let Some(pending) = self.begin_traffic(entry).await {
+            pending.finish();
+        }
+    }
+
+    /// Starts a record for a request that is about to be forwarded; `None` when traffic is not
+    /// being logged.
+    pub(crate) async fn begin_traffic(&self, entry: TrafficEntry) -> Option<PendingTraffic> {
+        let traffic_log = self.traffic_log.read().await.clone()?;
+        let turn_id = self.active_turn_id.read().await.clone();
+        let thread_id = self.audit_metadata.conversation_id.clone();
+        Some(PendingTraffic::new(
+            traffic_log,
+            entry.into_record(thread_id, turn_id),
+        ))
+    }
+
     /// Returns a snapshot of buffered blocked-request entries without consuming
     /// them.
     pub async fn blocked_snapshot(&self) -> Result<Vec<BlockedRequest>> {
@@ -536,6 +601,95 @@ impl NetworkProxyState {
         Ok(guard.config.network.mode.allows_method(method))
     }
 
+    /// Decides an HTTP request the proxy can see in full against `network.http_rules`, falling
+    /// back to the method policy of `mode`.
+    pub(crate) async fn http_request_decision(
+        &self,
+        mode: NetworkMode,
+        method: &str,
+        host: &str,
+        path: &str,
+    ) -> Result<HttpRequestDecision> {
+        self.reload_if_needed().await?;
+        let guard = self.state.read().await;
+        Ok(guard.http_rules.decide(mode, method, host, path))
+    }
+
+    /// Returns true when CONNECT tunnels to `host` must be intercepted, either because limited
+    /// mode needs to see inner methods or because HTTP rules or credentials apply to the host.
+    /// Why a CONNECT to `host` must be intercepted, as the source to report when MITM is not
+    /// available. `None` when the tunnel can be passed through.
+    pub(crate) async fn connect_mitm_requirement(
+        &self,
+        host: &str,
+    ) -> Result<Option<NetworkDecisionSource>> {
+        self.reload_if_needed().await?;
+        let guard = self.state.read().await;
+        Ok(if guard.config.network.mode == NetworkMode::Limited {
+            Some(NetworkDecisionSource::ModeGuard)
+        } else if guard.http_rules.applies_to_host(host) {
+            Some(NetworkDecisionSource::HttpRule)
+        } else if guard.credentials.applies_to_host(host) {
+            Some(NetworkDecisionSource::Credentials)
+        } else {
+            None
+        })
+    }
+
+    /// Headers that carry credentials for `host`, which clients must not set themselves.
+    pub(crate) async fn credential_header_names(&self, host: &str) -> Result<Vec<HeaderName>> {
+        self.reload_if_needed().await?;
+        let guard = self.state.read().await;
+        Ok(guard
+            .credentials
+            .for_host(host)
+            .into_iter()
+            .map(|(header, _)| header)
+            .collect())
+    }
+
+    /// The credentials to add to a request for `host`, resolved through the credential resolver
+    /// when one is set. Empty when no credential is configured for the host.
+    pub(crate) async fn credential_headers(
+        &self,
+        host: &str,
+    ) -> Result<Vec<(HeaderName, HeaderValue)>> {
+        self.reload_if_needed().await?;
+        let credentials = {
+            let guard = self.state.read().await;
+            guard.credentials.for_host(host)
+        };
+        if credentials.is_empty() {
+            return Ok(Vec::new());
+        }
+        let credentials = match self.credential_resolver.read().await.clone() {
+            // Resolvers may read the OS keyring or files, so they run off the proxy's async tasks.
+            Some(resolver) => tokio::task::spawn_blocking(move || {
+                credentials
+                    .into_iter()
+                    .map(|(header, value)| {
+                        let value = resolver
+                            .resolve(&value)
+                            .with_context(|| format!("failed to resolve {header} credential"))?;
+                        Ok((header, value))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .await
+            .context("credential resolver task failed")??,
+            None => credentials,
+        };
+        credentials
+            .into_iter()
+            .map(|(header, value)| {
+                let mut value = HeaderValue::from_str(&value)
+                    .with_context(|| format!("{header} credential is not a valid header value"))?;
+                value.set_sensitive(true);
+                Ok((header, value))
+            })
+            .collect()
+    }
+
     pub async fn allow_upstream_proxy(&self) -> Result<bool> {
         self.reload_if_needed().await?;
         let guard = self.state.read().await;
@@ -912,6 +1066,99 @@ mod tests {
         network
     }
 
+    #[tokio::test]
+    async fn connect_mitm_requirement_names_what_needs_interception() {
+        let mut network = network_settings(&["*.example.com"], &[]);
+        network.http_rules = vec![crate::config::NetworkHttpRule {
+            host: "rules.example.com".to_string(),
+            methods: Vec::new(),
+            path: None,
+            decision: crate::config::NetworkHttpRuleDecision::Deny,
+        }];
+        let mut config_state = build_config_state(
+            NetworkProxyConfig { network },
+            NetworkProxyConstraints::default(),
+        )
+        .unwrap();
+        // Loading credentials from config also turns on MITM, which needs a CA on disk.
+        config_state.credentials = CredentialRules::compile(&[crate::config::NetworkCredential {
+            host: "creds.example.com".to_string(),
+            header: "authorization".to_string(),
+            value: "Bearer proxy".to_string(),
+        }])
+        .unwrap();
+        let state = NetworkProxyState::with_reloader(config_state, Arc::new(NoopReloader));
+
+        assert_eq!(
+            state
+                .connect_mitm_requirement("rules.example.com")
+                .await
+                .unwrap(),
+            Some(NetworkDecisionSource::HttpRule)
+        );
+        assert_eq!(
+            state
+                .connect_mitm_requirement("creds.example.com")
+                .await
+                .unwrap(),
+            Some(NetworkDecisionSource::Credentials)
+        );
+        assert_eq!(
+            state
+                .connect_mitm_requirement("other.example.com")
+                .await
+                .unwrap(),
+            None
+        );
+
+        state.set_network_mode(NetworkMode::Limited).await.unwrap();
+        assert_eq!(
+            state
+                .connect_mitm_requirement("other.example.com")
+                .await
+                .unwrap(),
+            Some(NetworkDecisionSource::ModeGuard)
+        );
+    }
+
+    #[tokio::test]
+    async fn credential_headers_resolve_values_through_the_resolver() {
+        let mut config_state = build_config_state(
+            NetworkProxyConfig {
+                network: network_settings(&["api.example.com"], &[]),
+            },
+            NetworkProxyConstraints::default(),
+        )
+        .unwrap();
+        config_state.credentials = CredentialRules::compile(&[crate::config::NetworkCredential {
+            host: "api.example.com".to_string(),
+            header: "authorization".to_string(),
+            value: "Bearer $secret:API_TOKEN".to_string(),
+        }])
+        .unwrap();
+        let state = NetworkProxyState::with_reloader(config_state, Arc::new(NoopReloader));
+        state
+            .set_credential_resolver(Some(Arc::new(|value: &str| {
+                Ok::<_, anyhow::Error>(value.replace("$secret:API_TOKEN", "resolved"))
+            })))
+            .await;
+
+        let headers = state.credential_headers("api.example.com").await.unwrap();
+
+        assert_eq!(
+            headers,
+            vec![(
+                rama_http::header::AUTHORIZATION,
+                HeaderValue::from_static("Bearer resolved")
+            )]
+        );
+        assert!(headers[0].1.is_sensitive());
+        assert_eq!(
+            state.credential_headers("other.example.com").await.unwrap(),
+            Vec::new()
+        );
+    }
+
     #[tokio::test]
     async fn host_blocked_denied_wins_over_allowed() {
         let state =
@@ -1109,6 +1356,7 @@ mod tests {
                 decision: Some("ask".to_string()),
                 source: Some("decider".to_string()),
                 port: Some(80),
+                path: None,
             }))
             .await
             .expect("entry should be recorded");
@@ -1149,6 +1397,7 @@ mod tests {
                     decision: Some("ask".to_string()),
                     source: Some("decider".to_string()),
                     port: Some(80),
+                    path: None,
                 }))
                 .await
                 .expect("entry should be recorded");
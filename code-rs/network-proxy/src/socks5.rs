@@ -18,6 +18,7 @@ use crate::responses::blocked_message_with_policy;
 use crate::state::BlockedRequest;
 use crate::state::BlockedRequestArgs;
 use crate::state::NetworkProxyState;
+use crate::traffic_log::TrafficEntry;
 use anyhow::Context as _;
 use anyhow::Result;
 use rama_core::Layer;
@@ -183,6 +184,7 @@ async fn handle_socks5_tcp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -225,6 +227,7 @@ async fn handle_socks5_tcp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -275,6 +278,7 @@ async fn handle_socks5_tcp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -282,6 +286,9 @@ async fn handle_socks5_tcp(
             return Err(policy_denied_error(&reason, &details).into());
         }
         Ok(NetworkDecision::Allow) => {
+            app_state
+                .record_traffic(TrafficEntry::allowed("socks5", &host, port, client.clone()))
+                .await;
             let client = client.as_deref().unwrap_or_default();
             info!("SOCKS allowed (client={client}, host={host}, port={port})");
         }
@@ -360,6 +367,7 @@ async fn inspect_socks5_udp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
@@ -402,6 +410,7 @@ async fn inspect_socks5_udp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             return Err(policy_denied_error(REASON_METHOD_NOT_ALLOWED, &details));
@@ -448,6 +457,7 @@ async fn inspect_socks5_udp(
                     decision: Some(details.decision.as_str().to_string()),
                     source: Some(details.source.as_str().to_string()),
                     port: Some(port),
+                    path: None,
                 }))
                 .await;
             let client = client.as_deref().unwrap_or_default();
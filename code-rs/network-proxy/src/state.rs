@@ -1,7 +1,10 @@
 use crate::config::NetworkDomainPermissions;
+use crate::config::NetworkHttpRuleDecision;
 use crate::config::NetworkMode;
 use crate::config::NetworkProxyConfig;
 use crate::config::NetworkUnixSocketPermissions;
+use crate::credentials::CredentialRules;
+use crate::http_rules::HttpRules;
 use crate::mitm::MitmState;
 use crate::mitm::MitmUpstreamConfig;
 use crate::policy::DomainPattern;
@@ -66,6 +69,12 @@ pub fn build_config_state(
         .map_err(NetworkProxyConstraintError::into_anyhow)?;
     let deny_set = compile_denylist_globset(&denied_domains)?;
     let allow_set = compile_allowlist_globset(&allowed_domains)?;
+    let http_rules = HttpRules::compile(&config.network.http_rules)?;
+    let credentials = CredentialRules::compile(&config.network.credentials)?;
+    anyhow::ensure!(
+        credentials.is_empty() || config.network.mitm,
+        "network.credentials requires network.mitm = true"
+    );
     let mitm = if config.network.mitm {
         Some(Arc::new(MitmState::new(MitmUpstreamConfig {
             allow_upstream_proxy: config.network.allow_upstream_proxy,
@@ -78,6 +87,8 @@ pub fn build_config_state(
         config,
         allow_set,
         deny_set,
+        http_rules,
+        credentials,
         mitm,
         constraints,
         blocked: std::collections::VecDeque::new(),
@@ -145,6 +156,30 @@ pub fn validate_policy_against_constraints(
         })?;
     }
 
+    // Allow rules override the method policy of the mode, so under a managed limited mode they
+    // may only allow methods limited mode already permits.
+    if constraints.mode == Some(NetworkMode::Limited) {
+        for rule in &config.network.http_rules {
+            let widens_limited_mode = rule.decision == NetworkHttpRuleDecision::Allow
+                && (rule.methods.is_empty()
+                    || rule.methods.iter().any(|method| {
+                        !NetworkMode::Limited.allows_method(&method.to_ascii_uppercase())
+                    }));
+            if widens_limited_mode {
+                let methods = if rule.methods.is_empty() {
+                    "*".to_string()
+                } else {
+                    rule.methods.join(",")
+                };
+                return Err(invalid_value(
+                    "network.http_rules",
+                    format!("allow {methods} {}", rule.host),
+                    "allow rules for GET, HEAD or OPTIONS only (limited mode required by managed config)",
+                ));
+            }
+        }
+    }
+
     let allow_upstream_proxy = constraints.allow_upstream_proxy;
     validate(
         config.network.allow_upstream_proxy,
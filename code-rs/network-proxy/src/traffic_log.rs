@@ -0,0 +1,711 @@
+use crate::runtime::BlockedRequest;
+use anyhow::Context as _;
+use anyhow::Result;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use codex_utils_home_dir::find_codex_home;
+use rama_core::bytes::Bytes;
+use rama_core::error::BoxError;
+use rama_core::futures::stream::Stream;
+use rama_http::Body;
+use rama_http::BodyDataStream;
+use rama_http::HeaderMap;
+use rama_http::Response;
+use rama_http::header::CONTENT_LENGTH;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::BufRead as _;
+use std::io::BufReader;
+use std::io::Write as _;
+use std::path::Path;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::Weak;
+use std::sync::mpsc;
+use std::task::Context as TaskContext;
+use std::task::Poll;
+use std::time::Instant;
+use tracing::warn;
+
+/// Directory under `CODEX_HOME` that holds the traffic log.
+pub const NETWORK_TRAFFIC_LOG_DIR: &str = "log/network";
+
+const TRAFFIC_LOG_FILE_STEM: &str = "traffic";
+const TRAFFIC_LOG_FILE_EXTENSION: &str = "jsonl";
+const TRAFFIC_LOG_LOCK_FILE: &str = "traffic.lock";
+const DEFAULT_MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_ROTATED_FILES: usize = 5;
+
+/// One request seen by the proxy, as written to the traffic log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkTrafficRecord {
+    /// When the proxy received the request, in RFC 3339 format.
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turn_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    pub protocol: String,
+    pub host: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Request path, only known for plain HTTP and MITM'd HTTPS requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub decision: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+/// Request details known when the proxy reaches a decision.
+pub(crate) struct TrafficEntry {
+    pub(crate) protocol: String,
+    pub(crate) host: String,
+    pub(crate) port: Option<u16>,
+    pub(crate) client: Option<String>,
+    pub(crate) method: Option<String>,
+    pub(crate) path: Option<String>,
+    pub(crate) decision: String,
+    pub(crate) reason: Option<String>,
+}
+
+impl TrafficEntry {
+    pub(crate) fn allowed(protocol: &str, host: &str, port: u16, client: Option<String>) -> Self {
+        Self {
+            protocol: protocol.to_string(),
+            host: host.to_string(),
+            port: Some(port),
+            client,
+            method: None,
+            path: None,
+            decision: "allow".to_string(),
+            reason: None,
+        }
+    }
+
+    pub(crate) fn with_request(mut self, method: &str, path: Option<&str>) -> Self {
+        self.method = Some(method.to_string());
+        self.path = path.map(str::to_string);
+        self
+    }
+
+    pub(crate) fn from_blocked(entry: &BlockedRequest) -> Self {
+        Self {
+            protocol: entry.protocol.clone(),
+            host: entry.host.clone(),
+            port: entry.port,
+            client: entry.client.clone(),
+            method: entry.method.clone(),
+            path: entry.path.clone(),
+            decision: entry.decision.clone().unwrap_or_else(|| "deny".to_string()),
+            reason: Some(entry.reason.clone()),
+        }
+    }
+
+    pub(crate) fn into_record(
+        self,
+        thread_id: Option<String>,
+        turn_id: Option<String>,
+    ) -> NetworkTrafficRecord {
+        NetworkTrafficRecord {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            thread_id,
+            turn_id,
+            client: self.client,
+            protocol: self.protocol,
+            host: self.host,
+            port: self.port,
+            method: self.method,
+            path: self.path,
+            decision: self.decision,
+            reason: self.reason,
+            status: None,
+            request_bytes: None,
+            response_bytes: None,
+            duration_ms: None,
+        }
+    }
+}
+
+/// Append-only JSONL log of proxy traffic, rotated by size.
+///
+/// The active file is `traffic.jsonl`; when it would grow past the size limit it becomes
+/// `traffic.1.jsonl`, older files shift up by one and the oldest beyond the limit is removed.
+///
+/// Records are written by a background thread so the proxy's async tasks never block on the
+/// file system; [`Self::append`] only queues the record. Sessions in one process share a writer
+/// through [`Self::shared`], and writers in different processes serialize on an advisory lock
+/// on `traffic.lock` so one never rotates a file another is appending to.
+#[derive(Debug)]
+pub struct NetworkTrafficLog {
+    dir: PathBuf,
+    sender: mpsc::Sender<WriterCommand>,
+}
+
+enum WriterCommand {
+    Append(NetworkTrafficRecord),
+    #[cfg(test)]
+    Flush(mpsc::Sender<()>),
+}
+
+impl NetworkTrafficLog {
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        Self::open_with_limits(dir, DEFAULT_MAX_FILE_BYTES, DEFAULT_MAX_ROTATED_FILES)
+    }
+
+    /// Opens the log in `$CODEX_HOME/log/network`.
+    pub fn open_default() -> Result<Self> {
+        Self::open(default_traffic_log_dir()?)
+    }
+
+    /// Returns the process-wide log for `dir`, opening it if no session holds it.
+    pub fn shared(dir: impl Into<PathBuf>) -> Result<Arc<Self>> {
+        static SHARED_LOGS: LazyLock<Mutex<HashMap<PathBuf, Weak<NetworkTrafficLog>>>> =
+            LazyLock::new(Mutex::default);
+
+        let dir = dir.into();
+        let mut logs = SHARED_LOGS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(log) = logs.get(&dir).and_then(Weak::upgrade) {
+            return Ok(log);
+        }
+        let log = Arc::new(Self::open(dir.clone())?);
+        logs.retain(|_, log| log.strong_count() > 0);
+        logs.insert(dir, Arc::downgrade(&log));
+        Ok(log)
+    }
+
+    fn open_with_limits(
+        dir: impl Into<PathBuf>,
+        max_file_bytes: u64,
+        max_rotated_files: usize,
+    ) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("failed to create traffic log dir {}", dir.display()))?;
+        let lock_path = dir.join(TRAFFIC_LOG_LOCK_FILE);
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .with_context(|| format!("failed to open traffic log lock {}", lock_path.display()))?;
+        let writer = TrafficLogWriter {
+            dir: dir.clone(),
+            lock_file,
+            max_file_bytes,
+            max_rotated_files,
+        };
+        let (sender, receiver) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("network-traffic-log".to_string())
+            .spawn(move || writer.run(receiver))
+            .context("failed to start traffic log writer")?;
+        Ok(Self { dir, sender })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Queues `record` for the background writer.
+    pub fn append(&self, record: NetworkTrafficRecord) {
+        if self.sender.send(WriterCommand::Append(record)).is_err() {
+            warn!("network traffic log writer stopped; dropping record");
+        }
+    }
+
+    /// Waits until every record queued so far has been written.
+    #[cfg(test)]
+    fn flush(&self) {
+        let (done_tx, done_rx) = mpsc::channel();
+        if self.sender.send(WriterCommand::Flush(done_tx)).is_ok() {
+            let _ = done_rx.recv();
+        }
+    }
+}
+
+/// Owns the log files on the background thread.
+struct TrafficLogWriter {
+    dir: PathBuf,
+    lock_file: fs::File,
+    max_file_bytes: u64,
+    max_rotated_files: usize,
+}
+
+impl TrafficLogWriter {
+    /// Writes queued records until every [`NetworkTrafficLog`] handle is dropped.
+    fn run(self, receiver: mpsc::Receiver<WriterCommand>) {
+        for command in receiver {
+            match command {
+                WriterCommand::Append(record) => {
+                    if let Err(err) = self.append(&record) {
+                        warn!("failed to write network traffic record: {err:#}");
+                    }
+                }
+                #[cfg(test)]
+                WriterCommand::Flush(done) => {
+                    let _ = done.send(());
+                }
+            }
+        }
+    }
+
+    fn append(&self, record: &NetworkTrafficRecord) -> Result<()> {
+        let mut line = serde_json::to_vec(record).context("failed to serialize traffic record")?;
+        line.push(b'\n');
+
+        self.lock_file
+            .lock()
+            .context("failed to lock traffic log")?;
+        let result = self.append_locked(&line);
+        if let Err(err) = self.lock_file.unlock() {
+            warn!("failed to unlock traffic log: {err}");
+        }
+        result
+    }
+
+    /// Appends `line`, rotating first if it would not fit. Callers hold the file lock.
+    fn append_locked(&self, line: &[u8]) -> Result<()> {
+        let path = traffic_log_file(&self.dir, /*index*/ 0);
+        let len = match fs::metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err).context("failed to stat traffic log"),
+        };
+        if len > 0 && len.saturating_add(line.len() as u64) > self.max_file_bytes {
+            self.rotate().context("failed to rotate traffic log")?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("failed to open traffic log {}", path.display()))?;
+        file.write_all(line)
+            .context("failed to write traffic record")?;
+        Ok(())
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        let current = traffic_log_file(&self.dir, /*index*/ 0);
+        if self.max_rotated_files == 0 {
+            return remove_if_exists(&current);
+        }
+        remove_if_exists(&traffic_log_file(&self.dir, self.max_rotated_files))?;
+        for index in (1..self.max_rotated_files).rev() {
+            let from = traffic_log_file(&self.dir, index);
+            if from.exists() {
+                fs::rename(&from, traffic_log_file(&self.dir, index + 1))?;
+            }
+        }
+        fs::rename(&current, traffic_log_file(&self.dir, /*index*/ 1))
+    }
+}
+
+pub fn default_traffic_log_dir() -> Result<PathBuf> {
+    let codex_home =
+        find_codex_home().context("failed to resolve CODEX_HOME for the traffic log")?;
+    Ok(codex_home.join(NETWORK_TRAFFIC_LOG_DIR).to_path_buf())
+}
+
+fn traffic_log_file(dir: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        dir.join(format!(
+            "{TRAFFIC_LOG_FILE_STEM}.{TRAFFIC_LOG_FILE_EXTENSION}"
+        ))
+    } else {
+        dir.join(format!(
+            "{TRAFFIC_LOG_FILE_STEM}.{index}.{TRAFFIC_LOG_FILE_EXTENSION}"
+        ))
+    }
+}
+
+/// Parses the rotation index out of a log file name: 0 for the active file.
+fn traffic_log_file_index(file_name: &str) -> Option<usize> {
+    let rest = file_name
+        .strip_prefix(TRAFFIC_LOG_FILE_STEM)?
+        .strip_suffix(TRAFFIC_LOG_FILE_EXTENSION)?
+        .strip_suffix('.')?;
+    if rest.is_empty() {
+        return Some(0);
+    }
+    rest.strip_prefix('.')?
+        .parse()
+        .ok()
+        .filter(|index| *index > 0)
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Reads every record in the traffic log in `dir`, oldest first. Lines that do not parse are
+/// skipped so a partially written record does not hide the rest of the log.
+pub fn read_traffic_log(dir: &Path) -> Result<Vec<NetworkTrafficRecord>> {
+    let mut files = Vec::new();
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("failed to read traffic log dir {}", dir.display()));
+        }
+    };
+    for entry in entries {
+        let entry = entry?;
+        if let Some(index) = entry.file_name().to_str().and_then(traffic_log_file_index) {
+            files.push((index, entry.path()));
+        }
+    }
+    files.sort_by(|(left, _), (right, _)| right.cmp(left));
+
+    let mut records = Vec::new();
+    for (_, path) in files {
+        let file = fs::File::open(&path)
+            .with_context(|| format!("failed to open traffic log {}", path.display()))?;
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(record) => records.push(record),
+                Err(err) => warn!("skipping malformed traffic log line: {err}"),
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Converts traffic records to a HAR 1.2 log. Headers and bodies are not recorded, so entries
+/// carry only the request line, status and sizes; proxy decisions are kept in `_`-prefixed
+/// custom fields.
+pub fn traffic_har(records: &[NetworkTrafficRecord]) -> JsonValue {
+    let entries = records.iter().map(har_entry).collect::<Vec<_>>();
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "codex-network-proxy",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "entries": entries,
+        }
+    })
+}
+
+fn har_entry(record: &NetworkTrafficRecord) -> JsonValue {
+    let scheme = match record.protocol.as_str() {
+        "http" => "http",
+        "socks5" | "socks5-udp" => "socks5",
+        _ => "https",
+    };
+    let authority = match record.port {
+        Some(port) if record.host.contains(':') => format!("[{}]:{port}", record.host),
+        Some(port) => format!("{}:{port}", record.host),
+        None => record.host.clone(),
+    };
+    let url = format!(
+        "{scheme}://{authority}{}",
+        record.path.as_deref().unwrap_or("/")
+    );
+    let time = record.duration_ms.unwrap_or(0);
+    let response_bytes = record
+        .response_bytes
+        .map_or(-1, |bytes| i64::try_from(bytes).unwrap_or(i64::MAX));
+    let request_bytes = record
+        .request_bytes
+        .map_or(-1, |bytes| i64::try_from(bytes).unwrap_or(i64::MAX));
+    json!({
+        "startedDateTime": record.timestamp,
+        "time": time,
+        "request": {
+            "method": record.method.as_deref().unwrap_or("CONNECT"),
+            "url": url,
+            "httpVersion": "",
+            "cookies": [],
+            "headers": [],
+            "queryString": [],
+            "headersSize": -1,
+            "bodySize": request_bytes,
+        },
+        "response": {
+            "status": record.status.unwrap_or(0),
+            "statusText": "",
+            "httpVersion": "",
+            "cookies": [],
+            "headers": [],
+            "content": {
+                "size": response_bytes.max(0),
+                "mimeType": "",
+            },
+            "redirectURL": "",
+            "headersSize": -1,
+            "bodySize": response_bytes,
+        },
+        "cache": {},
+        "timings": {
+            "send": 0,
+            "wait": time,
+            "receive": 0,
+        },
+        "_protocol": record.protocol,
+        "_decision": record.decision,
+        "_reason": record.reason,
+        "_client": record.client,
+        "_turnId": record.turn_id,
+    })
+}
+
+/// An allowed HTTP request whose record is written once its response has been sent.
+pub(crate) struct PendingTraffic {
+    log: Arc<NetworkTrafficLog>,
+    record: NetworkTrafficRecord,
+    started_at: Instant,
+}
+
+impl PendingTraffic {
+    pub(crate) fn new(log: Arc<NetworkTrafficLog>, record: NetworkTrafficRecord) -> Self {
+        Self {
+            log,
+            record,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Takes the request size from its headers. Bodies without a `Content-Length` are not
+    /// counted so the request is forwarded unchanged; their size is left unknown.
+    pub(crate) fn set_request_size(&mut self, headers: &HeaderMap) {
+        self.record.request_bytes = request_size(headers);
+    }
+
+    /// Wraps the response body so the record is written when the body finishes or is dropped.
+    pub(crate) fn finish_with_response(mut self, resp: Response) -> Response {
+        let (parts, body) = resp.into_parts();
+        self.record.status = Some(parts.status.as_u16());
+        let body = Body::from_stream(RecordingStream {
+            inner: Box::pin(body.into_data_stream()),
+            pending: Some(self),
+            len: 0,
+        });
+        Response::from_parts(parts, body)
+    }
+
+    /// Writes the record for a request that produced no upstream response.
+    pub(crate) fn finish_without_response(mut self, status: u16) {
+        self.record.status = Some(status);
+        self.record_duration();
+        self.finish();
+    }
+
+    /// Writes the record as it stands, for traffic whose outcome is known at decision time.
+    pub(crate) fn finish(self) {
+        self.log.append(self.record);
+    }
+
+    fn finish_with_response_bytes(mut self, response_bytes: u64) {
+        self.record.response_bytes = Some(response_bytes);
+        self.record_duration();
+        self.finish();
+    }
+
+    fn record_duration(&mut self) {
+        self.record.duration_ms =
+            Some(u64::try_from(self.started_at.elapsed().as_millis()).unwrap_or(u64::MAX));
+    }
+}
+
+fn request_size(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+struct RecordingStream {
+    inner: Pin<Box<BodyDataStream>>,
+    pending: Option<PendingTraffic>,
+    len: u64,
+}
+
+impl Stream for RecordingStream {
+    type Item = Result<Bytes, BoxError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match this.inner.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(bytes))) => {
+                this.len = this.len.saturating_add(bytes.len() as u64);
+                Poll::Ready(Some(Ok(bytes)))
+            }
+            Poll::Ready(Some(Err(err))) => Poll::Ready(Some(Err(err))),
+            Poll::Ready(None) => {
+                if let Some(pending) = this.pending.take() {
+                    pending.finish_with_response_bytes(this.len);
+                }
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for RecordingStream {
+    fn drop(&mut self) {
+        // The client went away before the body finished; keep what was sent.
+        if let Some(pending) = self.pending.take() {
+            pending.finish_with_response_bytes(self.len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn record(thread_id: &str, path: &str) -> NetworkTrafficRecord {
+        NetworkTrafficRecord {
+            timestamp: "2026-01-02T03:04:05.678Z".to_string(),
+            thread_id: Some(thread_id.to_string()),
+            turn_id: Some("turn-1".to_string()),
+            client: Some("127.0.0.1:50000".to_string()),
+            protocol: "https".to_string(),
+            host: "api.example.com".to_string(),
+            port: Some(443),
+            method: Some("GET".to_string()),
+            path: Some(path.to_string()),
+            decision: "allow".to_string(),
+            reason: None,
+            status: Some(200),
+            request_bytes: Some(0),
+            response_bytes: Some(12),
+            duration_ms: Some(34),
+        }
+    }
+
+    #[test]
+    fn rotation_keeps_the_newest_files_and_reads_oldest_first() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let log = NetworkTrafficLog::open_with_limits(
+            dir.path(),
+            /*max_file_bytes*/ 1,
+            /*max_rotated_files*/ 2,
+        )
+        .expect("open log");
+
+        for index in 0..4 {
+            log.append(record("thread", &format!("/{index}")));
+        }
+        log.flush();
+
+        assert!(traffic_log_file(dir.path(), 2).exists());
+        assert!(!traffic_log_file(dir.path(), 3).exists());
+        let paths = read_traffic_log(dir.path())
+            .expect("read log")
+            .into_iter()
+            .map(|record| record.path.unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["/1", "/2", "/3"]);
+    }
+
+    #[test]
+    fn shared_logs_reuse_one_writer_per_dir() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let other_dir = tempfile::tempdir().expect("tempdir");
+        let log = NetworkTrafficLog::shared(dir.path()).expect("open shared log");
+
+        assert!(Arc::ptr_eq(
+            &log,
+            &NetworkTrafficLog::shared(dir.path()).expect("reopen shared log")
+        ));
+        assert!(!Arc::ptr_eq(
+            &log,
+            &NetworkTrafficLog::shared(other_dir.path()).expect("open other log")
+        ));
+    }
+
+    #[test]
+    fn read_skips_malformed_lines_and_unrelated_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let log = NetworkTrafficLog::open(dir.path()).expect("open log");
+        log.append(record("thread", "/ok"));
+        log.flush();
+        fs::write(dir.path().join("other.jsonl"), "{}\n").expect("write other file");
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(traffic_log_file(dir.path(), 0))
+            .expect("open active file");
+        file.write_all(b"{\"truncated\n")
+            .expect("write partial line");
+
+        assert_eq!(
+            read_traffic_log(dir.path()).expect("read log"),
+            vec![record("thread", "/ok")]
+        );
+    }
+
+    #[test]
+    fn request_size_is_unknown_without_a_content_length() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(request_size(&headers), None);
+
+        headers.insert(CONTENT_LENGTH, "42".parse().expect("header value"));
+        assert_eq!(request_size(&headers), Some(42));
+
+        headers.insert(CONTENT_LENGTH, "chunked".parse().expect("header value"));
+        assert_eq!(request_size(&headers), None);
+    }
+
+    #[test]
+    fn har_entries_carry_request_line_status_and_decision() {
+        let mut denied = record("thread", "/admin");
+        denied.decision = "deny".to_string();
+        denied.reason = Some("http_rule_denied".to_string());
+        denied.status = None;
+        denied.response_bytes = None;
+        denied.duration_ms = None;
+
+        let har = traffic_har(&[record("thread", "/v1/items"), denied]);
+
+        let entries = har["log"]["entries"].as_array().expect("entries");
+        assert_eq!(har["log"]["version"], "1.2");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0]["request"]["url"],
+            "https://api.example.com:443/v1/items"
+        );
+        assert_eq!(entries[0]["response"]["status"], 200);
+        assert_eq!(entries[0]["response"]["bodySize"], 12);
+        assert_eq!(entries[0]["time"], 34);
+        assert_eq!(entries[0]["_turnId"], "turn-1");
+        assert_eq!(entries[1]["response"]["status"], 0);
+        assert_eq!(entries[1]["response"]["bodySize"], -1);
+        assert_eq!(entries[1]["_decision"], "deny");
+        assert_eq!(entries[1]["_reason"], "http_rule_denied");
+    }
+}
@@ -54,6 +54,10 @@ pub enum SandboxErr {
     #[error("command timed out")]
     Timeout { output: Box<ExecToolCallOutput> },
 
+    /// Command was killed for exceeding a sandbox resource limit
+    #[error("command exceeded a sandbox resource limit")]
+    ResourceLimitExceeded { output: Box<ExecToolCallOutput> },
+
     /// Command was killed by a signal
     #[error("command was killed by a signal")]
     Signal(i32),
@@ -621,6 +625,15 @@ pub fn get_error_message_ui(e: &CodexErr) -> String {
                 output.duration.as_millis()
             )
         }
+        CodexErr::Sandbox(SandboxErr::ResourceLimitExceeded { output }) => {
+            match output.resource_limit_exceeded {
+                Some(limit) => format!(
+                    "error: command exceeded the sandbox {} limit",
+                    limit.description()
+                ),
+                None => e.to_string(),
+            }
+        }
         _ => e.to_string(),
     };
 
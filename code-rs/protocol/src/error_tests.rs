@@ -81,6 +81,8 @@ fn sandbox_denied_uses_aggregated_output_when_stderr_empty() {
         aggregated_output: StreamOutput::new("aggregate detail".to_string()),
         duration: Duration::from_millis(10),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let err = CodexErr::Sandbox(SandboxErr::Denied {
         output: Box::new(output),
@@ -98,6 +100,8 @@ fn sandbox_denied_reports_both_streams_when_available() {
         aggregated_output: StreamOutput::new(String::new()),
         duration: Duration::from_millis(10),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let err = CodexErr::Sandbox(SandboxErr::Denied {
         output: Box::new(output),
@@ -115,6 +119,8 @@ fn sandbox_denied_reports_stdout_when_no_stderr() {
         aggregated_output: StreamOutput::new(String::new()),
         duration: Duration::from_millis(8),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let err = CodexErr::Sandbox(SandboxErr::Denied {
         output: Box::new(output),
@@ -159,6 +165,8 @@ fn sandbox_denied_reports_exit_code_when_no_output_available() {
         aggregated_output: StreamOutput::new(String::new()),
         duration: Duration::from_millis(5),
         timed_out: false,
+        resource_limit_exceeded: None,
+        sandbox_denials: Vec::new(),
     };
     let err = CodexErr::Sandbox(SandboxErr::Denied {
         output: Box::new(output),
@@ -170,6 +178,27 @@ fn sandbox_denied_reports_exit_code_when_no_output_available() {
     );
 }
 
+#[test]
+fn sandbox_resource_limit_names_the_exceeded_limit() {
+    let output = ExecToolCallOutput {
+        exit_code: 128 + 24,
+        stdout: StreamOutput::new(String::new()),
+        stderr: StreamOutput::new(String::new()),
+        aggregated_output: StreamOutput::new(String::new()),
+        duration: Duration::from_millis(5),
+        timed_out: false,
+        resource_limit_exceeded: Some(crate::permissions::ResourceLimitKind::CpuTime),
+        sandbox_denials: Vec::new(),
+    };
+    let err = CodexErr::Sandbox(SandboxErr::ResourceLimitExceeded {
+        output: Box::new(output),
+    });
+    assert_eq!(
+        get_error_message_ui(&err),
+        "error: command exceeded the sandbox CPU time limit"
+    );
+}
+
 #[test]
 fn usage_limit_reached_error_formats_free_plan() {
     let err = UsageLimitReachedError {
@@ -12,6 +12,8 @@ use encoding_rs::IBM866;
 use encoding_rs::WINDOWS_1252;
 use std::time::Duration;
 
+use crate::permissions::ResourceLimitKind;
+
 #[derive(Debug, Clone)]
 pub struct StreamOutput<T: Clone> {
     pub text: T,
@@ -44,6 +46,11 @@ pub struct ExecToolCallOutput {
     pub aggregated_output: StreamOutput<String>,
     pub duration: Duration,
     pub timed_out: bool,
+    /// Set when the sandbox killed the command for exceeding a resource limit.
+    pub resource_limit_exceeded: Option<ResourceLimitKind>,
+    /// Operations the Linux sandbox helper recorded as blocked in its sandbox
+    /// report.
+    pub sandbox_denials: Vec<String>,
 }
 
 impl Default for ExecToolCallOutput {
@@ -55,6 +62,8 @@ impl Default for ExecToolCallOutput {
             aggregated_output: StreamOutput::new(String::new()),
             duration: Duration::ZERO,
             timed_out: false,
+            resource_limit_exceeded: None,
+            sandbox_denials: Vec::new(),
         }
     }
 }
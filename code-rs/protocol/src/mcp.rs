@@ -134,6 +134,76 @@ pub struct ResourceTemplate {
     pub mime_type: Option<String>,
 }
 
+/// A prompt or prompt template that the server offers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct Prompt {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub arguments: Option<Vec<PromptArgument>>,
+}
+
+/// An argument that a prompt template accepts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptArgument {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub required: Option<bool>,
+}
+
+/// The sender of a prompt message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(rename_all = "lowercase")]
+pub enum PromptMessageRole {
+    User,
+    Assistant,
+}
+
+/// A single message returned when rendering a prompt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptMessage {
+    pub role: PromptMessageRole,
+    pub content: serde_json::Value,
+}
+
+/// The server's response to a `prompts/get` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPromptResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub description: Option<String>,
+    pub messages: Vec<PromptMessage>,
+}
+
+impl PromptMessage {
+    /// Returns the text of this message when its content is a text block.
+    pub fn text(&self) -> Option<&str> {
+        let content = self.content.as_object()?;
+        if content.get("type").and_then(serde_json::Value::as_str) != Some("text") {
+            return None;
+        }
+        content.get("text").and_then(serde_json::Value::as_str)
+    }
+}
+
 /// The server's response to a tool call.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
@@ -322,6 +392,18 @@ impl ResourceTemplate {
     }
 }
 
+impl Prompt {
+    pub fn from_mcp_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl GetPromptResult {
+    pub fn from_mcp_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -357,4 +439,22 @@ mod tests {
         let parsed = Resource::from_mcp_value(resource).expect("should deserialize");
         assert_eq!(parsed.size, None);
     }
+
+    #[test]
+    fn prompt_message_text_reads_text_blocks_only() {
+        let result = GetPromptResult::from_mcp_value(serde_json::json!({
+            "messages": [
+                { "role": "user", "content": { "type": "text", "text": "hello" } },
+                {
+                    "role": "assistant",
+                    "content": { "type": "image", "data": "AAAA", "mimeType": "image/png" }
+                },
+            ],
+        }))
+        .expect("should deserialize");
+
+        let texts: Vec<Option<&str>> = result.messages.iter().map(PromptMessage::text).collect();
+        assert_eq!(texts, vec![Some("hello"), None]);
+        assert_eq!(result.messages[1].role, PromptMessageRole::Assistant);
+    }
 }
@@ -1,5 +1,6 @@
 pub const APPROVAL_KIND_KEY: &str = "codex_approval_kind";
 pub const APPROVAL_KIND_MCP_TOOL_CALL: &str = "mcp_tool_call";
+pub const APPROVAL_KIND_MCP_SAMPLING: &str = "mcp_sampling";
 pub const APPROVAL_KIND_TOOL_SUGGESTION: &str = "tool_suggestion";
 pub const REQUEST_TYPE_KEY: &str = "codex_request_type";
 pub const REQUEST_TYPE_APPROVAL_REQUEST: &str = "approval_request";
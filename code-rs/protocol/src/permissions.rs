@@ -12,6 +12,7 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use strum_macros::Display;
+use strum_macros::EnumString;
 use tracing::error;
 use ts_rs::TS;
 
@@ -92,6 +93,54 @@ impl NetworkSandboxPolicy {
     }
 }
 
+/// Resource limits applied to a sandboxed command and its children.
+///
+/// `None` leaves the corresponding limit inherited from the parent process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cpu_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_processes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_open_files: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_size_bytes: Option<u64>,
+}
+
+impl ResourceLimits {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The resource limit that caused a sandboxed command to be killed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ResourceLimitKind {
+    Memory,
+    CpuTime,
+    Processes,
+    OpenFiles,
+    FileSize,
+}
+
+impl ResourceLimitKind {
+    /// Human-readable name used in messages shown to users and the model.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Memory => "memory",
+            Self::CpuTime => "CPU time",
+            Self::Processes => "process count",
+            Self::OpenFiles => "open file",
+            Self::FileSize => "file size",
+        }
+    }
+}
+
 /// Access mode for a filesystem entry.
 ///
 /// When two equally specific entries target the same path, we compare these by
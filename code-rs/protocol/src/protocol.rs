@@ -1335,6 +1335,16 @@ pub enum EventMsg {
     /// Aggregate MCP startup completion summary.
     McpStartupComplete(McpStartupCompleteEvent),
 
+    /// An MCP server reported that its prompt list changed.
+    McpPromptListChanged(McpPromptListChangedEvent),
+
+    /// An MCP server reported that its tool list changed and the tools were
+    /// re-fetched for the next turn.
+    McpToolListChanged(McpToolListChangedEvent),
+
+    /// An MCP server reported that its resource list changed.
+    McpResourceListChanged(McpResourceListChangedEvent),
+
     McpToolCallBegin(McpToolCallBeginEvent),
 
     McpToolCallEnd(McpToolCallEndEvent),
@@ -1462,6 +1472,10 @@ pub enum HookEventName {
     SessionStart,
     UserPromptSubmit,
     Stop,
+    SessionEnd,
+    SubagentStart,
+    SubagentStop,
+    Notification,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
@@ -1470,6 +1484,7 @@ pub enum HookHandlerType {
     Command,
     Prompt,
     Agent,
+    Http,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
@@ -3275,6 +3290,26 @@ pub struct McpStartupCompleteEvent {
     pub cancelled: Vec<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpPromptListChangedEvent {
+    /// Name of the server whose prompt list changed.
+    pub server: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpToolListChangedEvent {
+    /// Name of the server whose tool list changed.
+    pub server: String,
+    /// Names of the tools the server now exposes, after tool filters.
+    pub tools: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpResourceListChangedEvent {
+    /// Name of the server whose resource list changed.
+    pub server: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct McpStartupFailure {
     pub server: String,
@@ -3666,6 +3701,10 @@ impl ReviewDecision {
 pub enum FileChange {
     Add {
         content: String,
+        /// Set when the patch also changes the file's executable bit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        executable: Option<bool>,
     },
     Delete {
         content: String,
@@ -3673,6 +3712,22 @@ pub enum FileChange {
     Update {
         unified_diff: String,
         move_path: Option<PathBuf>,
+        /// Set when the patch also changes the file's executable bit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        executable: Option<bool>,
+    },
+    /// Sets or clears the executable bit without touching the contents.
+    SetMode {
+        executable: bool,
+    },
+    AddSymlink {
+        target: PathBuf,
+    },
+    /// Binary contents are reported by size only.
+    AddBinary {
+        #[ts(type = "number")]
+        size: u64,
     },
 }
 
@@ -4603,6 +4658,7 @@ mod tests {
                     PathBuf::from("new.txt"),
                     FileChange::Add {
                         content: "hello".into(),
+                        executable: None,
                     },
                 )]
                 .into_iter()
@@ -4706,6 +4762,7 @@ mod tests {
                     PathBuf::from("new.txt"),
                     FileChange::Add {
                         content: "hello".into(),
+                        executable: None,
                     },
                 )]
                 .into_iter()
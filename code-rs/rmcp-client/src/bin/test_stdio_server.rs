@@ -11,11 +11,18 @@ use rmcp::ServiceExt;
 use rmcp::handler::server::ServerHandler;
 use rmcp::model::CallToolRequestParams;
 use rmcp::model::CallToolResult;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::JsonObject;
+use rmcp::model::ListPromptsResult;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::Prompt;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
 use rmcp::model::RawResource;
 use rmcp::model::RawResourceTemplate;
 use rmcp::model::ReadResourceRequestParams;
@@ -28,6 +35,7 @@ use rmcp::model::ServerInfo;
 use rmcp::model::Tool;
 use rmcp::model::ToolAnnotations;
 use serde::Deserialize;
+use serde::Serialize;
 use serde_json::json;
 use tokio::sync::Barrier;
 use tokio::task;
@@ -38,8 +46,10 @@ struct TestToolServer {
     tools: Arc<Vec<Tool>>,
     resources: Arc<Vec<Resource>>,
     resource_templates: Arc<Vec<ResourceTemplate>>,
+    prompts: Arc<Vec<Prompt>>,
 }
 
+const SUMMARIZE_PROMPT_NAME: &str = "summarize";
 const MEMO_URI: &str = "memo://codex/example-note";
 const MEMO_CONTENT: &str = "This is a sample MCP resource served by the rmcp test server.";
 const SANDBOX_STATE_META_CAPABILITY: &str = "codex/sandbox-state-meta";
@@ -72,14 +82,18 @@ impl TestToolServer {
             Self::sync_tool(),
             Self::image_tool(),
             Self::image_scenario_tool(),
+            Self::roots_tool(),
+            Self::sample_tool(),
             sandbox_meta_tool,
         ];
         let resources = vec![Self::memo_resource()];
         let resource_templates = vec![Self::memo_template()];
+        let prompts = vec![Self::summarize_prompt()];
         Self {
             tools: Arc::new(tools),
             resources: Arc::new(resources),
             resource_templates: Arc::new(resource_templates),
+            prompts: Arc::new(prompts),
         }
     }
 
@@ -165,6 +179,41 @@ impl TestToolServer {
         tool
     }
 
+    fn roots_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }))
+        .expect("roots tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("roots"),
+            Cow::Borrowed("Return the roots reported by the client via roots/list."),
+            Arc::new(schema),
+        )
+    }
+
+    fn sample_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "prompt": { "type": "string" }
+            },
+            "required": ["prompt"],
+            "additionalProperties": false
+        }))
+        .expect("sample tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("sample"),
+            Cow::Borrowed("Ask the client to sample a completion via sampling/createMessage."),
+            Arc::new(schema),
+        )
+    }
+
     fn sync_tool() -> Tool {
         #[expect(clippy::expect_used)]
         let schema: JsonObject = serde_json::from_value(json!({
@@ -317,6 +366,27 @@ impl TestToolServer {
     fn memo_text() -> &'static str {
         MEMO_CONTENT
     }
+
+    fn summarize_prompt() -> Prompt {
+        Prompt::new(
+            SUMMARIZE_PROMPT_NAME,
+            Some("Summarize a topic in a given style."),
+            Some(vec![
+                PromptArgument {
+                    name: "topic".to_string(),
+                    title: None,
+                    description: Some("What to summarize.".to_string()),
+                    required: Some(true),
+                },
+                PromptArgument {
+                    name: "style".to_string(),
+                    title: None,
+                    description: Some("Optional writing style.".to_string()),
+                    required: Some(false),
+                },
+            ]),
+        )
+    }
 }
 
 #[derive(Deserialize)]
@@ -325,6 +395,11 @@ struct EchoArgs {
     env_var: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct SampleArgs {
+    prompt: String,
+}
+
 const DEFAULT_SYNC_TIMEOUT_MS: u64 = 1_000;
 
 static SYNC_BARRIERS: OnceLock<tokio::sync::Mutex<HashMap<String, SyncBarrierState>>> =
@@ -394,6 +469,7 @@ impl ServerHandler for TestToolServer {
             .enable_tools()
             .enable_tool_list_changed()
             .enable_resources()
+            .enable_prompts()
             .build();
         capabilities.experimental = Some(BTreeMap::from([(
             SANDBOX_STATE_META_CAPABILITY.to_string(),
@@ -471,6 +547,48 @@ impl ServerHandler for TestToolServer {
         }
     }
 
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: (*self.prompts).clone(),
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        GetPromptRequestParams {
+            name, arguments, ..
+        }: GetPromptRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        if name != SUMMARIZE_PROMPT_NAME {
+            return Err(McpError::invalid_params(
+                "prompt_not_found",
+                Some(json!({ "name": name })),
+            ));
+        }
+        let arguments = arguments.unwrap_or_default();
+        let Some(topic) = arguments.get("topic").and_then(|value| value.as_str()) else {
+            return Err(McpError::invalid_params(
+                "missing required argument: topic",
+                None,
+            ));
+        };
+        let text = match arguments.get("style").and_then(|value| value.as_str()) {
+            Some(style) => format!("Summarize {topic} in a {style} style."),
+            None => format!("Summarize {topic}."),
+        };
+        Ok(GetPromptResult {
+            description: Some("Summarize a topic in a given style.".to_string()),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+
     async fn call_tool(
         &self,
         request: CallToolRequestParams,
@@ -551,6 +669,31 @@ impl ServerHandler for TestToolServer {
                 let args = Self::parse_call_args::<SyncArgs>(&request, "sync")?;
                 Self::sync_result(args).await
             }
+            "roots" => {
+                let result = context
+                    .peer
+                    .list_roots()
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                Self::structured_result(&result)
+            }
+            "sample" => {
+                let args = Self::parse_call_args::<SampleArgs>(&request, "sample")?;
+                let params = serde_json::from_value(json!({
+                    "messages": [{
+                        "role": "user",
+                        "content": { "type": "text", "text": args.prompt },
+                    }],
+                    "maxTokens": 64,
+                }))
+                .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                let result = context
+                    .peer
+                    .create_message(params)
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                Self::structured_result(&result)
+            }
             other => Err(McpError::invalid_params(
                 format!("unknown tool: {other}"),
                 None,
@@ -560,6 +703,17 @@ impl ServerHandler for TestToolServer {
 }
 
 impl TestToolServer {
+    fn structured_result<T: Serialize>(value: &T) -> Result<CallToolResult, McpError> {
+        let structured_content = serde_json::to_value(value)
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+        Ok(CallToolResult {
+            content: Vec::new(),
+            structured_content: Some(structured_content),
+            is_error: Some(false),
+            meta: None,
+        })
+    }
+
     fn parse_call_args<T: for<'de> Deserialize<'de>>(
         request: &CallToolRequestParams,
         tool_name: &'static str,
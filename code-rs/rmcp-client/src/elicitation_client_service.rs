@@ -3,10 +3,14 @@ use std::sync::Arc;
 use rmcp::RoleClient;
 use rmcp::model::ClientInfo;
 use rmcp::model::ClientResult;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
 use rmcp::model::CustomResult;
 use rmcp::model::ElicitationAction;
+use rmcp::model::ListRootsResult;
 use rmcp::model::Meta;
 use rmcp::model::RequestParamsMeta;
+use rmcp::model::Root;
 use rmcp::model::ServerNotification;
 use rmcp::model::ServerRequest;
 use rmcp::service::NotificationContext;
@@ -14,12 +18,16 @@ use rmcp::service::RequestContext;
 use rmcp::service::Service;
 use serde::Serialize;
 use serde_json::Value;
+use tokio::sync::watch;
 
+use crate::logging_client_handler::ListChangedNotifiers;
 use crate::logging_client_handler::LoggingClientHandler;
 use crate::rmcp_client::Elicitation;
 use crate::rmcp_client::ElicitationPauseState;
 use crate::rmcp_client::ElicitationResponse;
 use crate::rmcp_client::SendElicitation;
+use crate::rmcp_client::SendSampling;
+use crate::rmcp_client::ServerRequestHandlers;
 
 const MCP_PROGRESS_TOKEN_META_KEY: &str = "progressToken";
 
@@ -27,6 +35,8 @@ const MCP_PROGRESS_TOKEN_META_KEY: &str = "progressToken";
 pub(crate) struct ElicitationClientService {
     handler: LoggingClientHandler,
     send_elicitation: Arc<SendElicitation>,
+    send_sampling: Option<Arc<SendSampling>>,
+    roots: Option<watch::Receiver<Vec<Root>>>,
     pause_state: ElicitationPauseState,
 }
 
@@ -35,14 +45,23 @@ impl ElicitationClientService {
         client_info: ClientInfo,
         send_elicitation: SendElicitation,
         pause_state: ElicitationPauseState,
+        list_changed: ListChangedNotifiers,
+        handlers: ServerRequestHandlers,
     ) -> Self {
+        let ServerRequestHandlers {
+            send_sampling,
+            roots,
+        } = handlers;
         let send_elicitation = Arc::new(send_elicitation);
         Self {
             handler: LoggingClientHandler::new(
                 client_info,
                 clone_send_elicitation(Arc::clone(&send_elicitation)),
+                list_changed,
             ),
             send_elicitation,
+            send_sampling: send_sampling.map(Arc::new),
+            roots,
             pause_state,
         }
     }
@@ -59,6 +78,25 @@ impl ElicitationClientService {
             .await
             .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
     }
+
+    async fn create_message(
+        &self,
+        request: CreateMessageRequestParams,
+        context: RequestContext<RoleClient>,
+    ) -> Result<CreateMessageResult, rmcp::ErrorData> {
+        let Some(send_sampling) = self.send_sampling.as_ref() else {
+            return Err(rmcp::ErrorData::invalid_request(
+                "sampling is not supported by this client",
+                None,
+            ));
+        };
+        // Sampling waits on user approval and a model turn, so it must not
+        // count against the request timeout of the call that triggered it.
+        let _pause = self.pause_state.enter();
+        send_sampling(context.id, request)
+            .await
+            .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+    }
 }
 
 fn clone_send_elicitation(send_elicitation: Arc<SendElicitation>) -> SendElicitation {
@@ -78,6 +116,18 @@ impl Service<RoleClient> for ElicitationClientService {
                 let result = elicitation_response_result(response)?;
                 Ok(ClientResult::CustomResult(result))
             }
+            ServerRequest::CreateMessageRequest(request) => {
+                let result = self.create_message(request.params, context).await?;
+                Ok(ClientResult::CreateMessageResult(result))
+            }
+            ServerRequest::ListRootsRequest(_) => {
+                let roots = self
+                    .roots
+                    .as_ref()
+                    .map(|roots| roots.borrow().clone())
+                    .unwrap_or_default();
+                Ok(ClientResult::ListRootsResult(ListRootsResult { roots }))
+            }
             request => {
                 <LoggingClientHandler as Service<RoleClient>>::handle_request(
                     &self.handler,
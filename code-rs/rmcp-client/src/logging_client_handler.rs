@@ -12,6 +12,7 @@ use rmcp::model::ProgressNotificationParam;
 use rmcp::model::ResourceUpdatedNotificationParam;
 use rmcp::service::NotificationContext;
 use rmcp::service::RequestContext;
+use tokio::sync::watch;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
@@ -19,17 +20,41 @@ use tracing::warn;
 
 use crate::rmcp_client::SendElicitation;
 
+/// Watch channels signalled when the server sends a `notifications/*/list_changed`.
+#[derive(Clone)]
+pub(crate) struct ListChangedNotifiers {
+    pub(crate) tools: watch::Sender<()>,
+    pub(crate) resources: watch::Sender<()>,
+    pub(crate) prompts: watch::Sender<()>,
+}
+
+impl Default for ListChangedNotifiers {
+    fn default() -> Self {
+        Self {
+            tools: watch::channel(()).0,
+            resources: watch::channel(()).0,
+            prompts: watch::channel(()).0,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct LoggingClientHandler {
     client_info: ClientInfo,
     send_elicitation: Arc<SendElicitation>,
+    list_changed: ListChangedNotifiers,
 }
 
 impl LoggingClientHandler {
-    pub(crate) fn new(client_info: ClientInfo, send_elicitation: SendElicitation) -> Self {
+    pub(crate) fn new(
+        client_info: ClientInfo,
+        send_elicitation: SendElicitation,
+        list_changed: ListChangedNotifiers,
+    ) -> Self {
         Self {
             client_info,
             send_elicitation: Arc::new(send_elicitation),
+            list_changed,
         }
     }
 }
@@ -78,14 +103,17 @@ impl ClientHandler for LoggingClientHandler {
 
     async fn on_resource_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server resource list changed");
+        self.list_changed.resources.send_replace(());
     }
 
     async fn on_tool_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server tool list changed");
+        self.list_changed.tools.send_replace(());
     }
 
     async fn on_prompt_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server prompt list changed");
+        self.list_changed.prompts.send_replace(());
     }
 
     fn get_info(&self) -> ClientInfo {
@@ -26,12 +26,17 @@ use rmcp::model::ClientNotification;
 use rmcp::model::ClientRequest;
 use rmcp::model::CreateElicitationRequestParams;
 use rmcp::model::CreateElicitationResult;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
 use rmcp::model::CustomNotification;
 use rmcp::model::CustomRequest;
 use rmcp::model::ElicitationAction;
 use rmcp::model::Extensions;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::InitializeRequestParams;
 use rmcp::model::InitializeResult;
+use rmcp::model::ListPromptsResult;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
@@ -39,6 +44,7 @@ use rmcp::model::PaginatedRequestParams;
 use rmcp::model::ReadResourceRequestParams;
 use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
+use rmcp::model::Root;
 use rmcp::model::ServerResult;
 use rmcp::model::Tool;
 use rmcp::service::RoleClient;
@@ -64,6 +70,7 @@ use crate::http_client_adapter::StreamableHttpClientAdapter;
 use crate::http_client_adapter::StreamableHttpClientAdapterError;
 use crate::in_process_transport::InProcessTransportFactory;
 use crate::load_oauth_tokens;
+use crate::logging_client_handler::ListChangedNotifiers;
 use crate::oauth::OAuthPersistor;
 use crate::oauth::StoredOAuthTokens;
 use crate::stdio_server_launcher::StdioServerCommand;
@@ -258,6 +265,27 @@ pub type SendElicitation = Box<
     dyn Fn(RequestId, Elicitation) -> BoxFuture<'static, Result<ElicitationResponse>> + Send + Sync,
 >;
 
+/// Interface for running `sampling/createMessage` requests against a model.
+pub type SendSampling = Box<
+    dyn Fn(RequestId, CreateMessageRequestParams) -> BoxFuture<'static, Result<CreateMessageResult>>
+        + Send
+        + Sync,
+>;
+
+/// Optional handlers for server-to-client requests beyond elicitation.
+///
+/// Servers that call a feature whose handler is unset get an error response,
+/// so callers should only advertise the matching client capabilities when the
+/// handler is provided.
+#[derive(Default)]
+pub struct ServerRequestHandlers {
+    /// Handles `sampling/createMessage`.
+    pub send_sampling: Option<SendSampling>,
+    /// Roots returned from `roots/list`. Callers announce changes with
+    /// [`RmcpClient::notify_roots_list_changed`].
+    pub roots: Option<watch::Receiver<Vec<Root>>>,
+}
+
 pub struct ToolWithConnectorId {
     pub tool: Tool,
     pub connector_id: Option<String>,
@@ -279,6 +307,7 @@ pub struct RmcpClient {
     initialize_context: Mutex<Option<InitializeContext>>,
     session_recovery_lock: Semaphore,
     elicitation_pause_state: ElicitationPauseState,
+    list_changed: ListChangedNotifiers,
 }
 
 impl RmcpClient {
@@ -299,6 +328,7 @@ impl RmcpClient {
             initialize_context: Mutex::new(None),
             session_recovery_lock: Semaphore::new(/*permits*/ 1),
             elicitation_pause_state: ElicitationPauseState::new(),
+            list_changed: ListChangedNotifiers::default(),
         })
     }
 
@@ -333,6 +363,7 @@ impl RmcpClient {
             initialize_context: Mutex::new(None),
             session_recovery_lock: Semaphore::new(/*permits*/ 1),
             elicitation_pause_state: ElicitationPauseState::new(),
+            list_changed: ListChangedNotifiers::default(),
         })
     }
 
@@ -367,6 +398,7 @@ impl RmcpClient {
             initialize_context: Mutex::new(None),
             session_recovery_lock: Semaphore::new(/*permits*/ 1),
             elicitation_pause_state: ElicitationPauseState::new(),
+            list_changed: ListChangedNotifiers::default(),
         })
     }
 
@@ -377,11 +409,31 @@ impl RmcpClient {
         params: InitializeRequestParams,
         timeout: Option<Duration>,
         send_elicitation: SendElicitation,
+    ) -> Result<InitializeResult> {
+        self.initialize_with_handlers(
+            params,
+            timeout,
+            send_elicitation,
+            ServerRequestHandlers::default(),
+        )
+        .await
+    }
+
+    /// Like [`Self::initialize`], additionally answering sampling and roots
+    /// requests from the server with `handlers`.
+    pub async fn initialize_with_handlers(
+        &self,
+        params: InitializeRequestParams,
+        timeout: Option<Duration>,
+        send_elicitation: SendElicitation,
+        handlers: ServerRequestHandlers,
     ) -> Result<InitializeResult> {
         let client_service = ElicitationClientService::new(
             params.clone(),
             send_elicitation,
             self.elicitation_pause_state.clone(),
+            self.list_changed.clone(),
+            handlers,
         );
         let pending_transport = {
             let mut guard = self.state.lock().await;
@@ -542,6 +594,70 @@ impl RmcpClient {
         Ok(result)
     }
 
+    pub async fn list_prompts(
+        &self,
+        params: Option<PaginatedRequestParams>,
+        timeout: Option<Duration>,
+    ) -> Result<ListPromptsResult> {
+        self.refresh_oauth_if_needed().await;
+        let result = self
+            .run_service_operation("prompts/list", timeout, move |service| {
+                let params = params.clone();
+                async move { service.list_prompts(params).await }.boxed()
+            })
+            .await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
+    pub async fn get_prompt(
+        &self,
+        params: GetPromptRequestParams,
+        timeout: Option<Duration>,
+    ) -> Result<GetPromptResult> {
+        self.refresh_oauth_if_needed().await;
+        let result = self
+            .run_service_operation("prompts/get", timeout, move |service| {
+                let params = params.clone();
+                async move { service.get_prompt(params).await }.boxed()
+            })
+            .await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
+    /// Subscribe to `notifications/prompts/list_changed` from the server.
+    ///
+    /// The receiver is marked as changed each time the server reports that its
+    /// prompt list changed; callers should re-run `prompts/list` in response.
+    pub fn subscribe_prompt_list_changed(&self) -> watch::Receiver<()> {
+        self.list_changed.prompts.subscribe()
+    }
+
+    /// Subscribe to `notifications/tools/list_changed` from the server.
+    ///
+    /// The receiver is marked as changed each time the server reports that its
+    /// tool list changed; callers should re-run `tools/list` in response.
+    pub fn subscribe_tool_list_changed(&self) -> watch::Receiver<()> {
+        self.list_changed.tools.subscribe()
+    }
+
+    /// Subscribe to `notifications/resources/list_changed` from the server.
+    pub fn subscribe_resource_list_changed(&self) -> watch::Receiver<()> {
+        self.list_changed.resources.subscribe()
+    }
+
+    /// Send `notifications/roots/list_changed` so the server re-runs `roots/list`.
+    pub async fn notify_roots_list_changed(&self) -> Result<()> {
+        self.run_service_operation(
+            "notifications/roots/list_changed",
+            /*timeout*/ None,
+            move |service| async move { service.notify_roots_list_changed().await }.boxed(),
+        )
+        .await?;
+        Ok(())
+    }
+
     pub async fn call_tool(
         &self,
         name: String,
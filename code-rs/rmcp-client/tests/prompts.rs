@@ -0,0 +1,141 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::LocalStdioServerLauncher;
+use codex_rmcp_client::RmcpClient;
+use codex_utils_cargo_bin::CargoBinError;
+use futures::FutureExt as _;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::ElicitationCapability;
+use rmcp::model::FormElicitationCapability;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParams;
+use rmcp::model::JsonObject;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
+use rmcp::model::ProtocolVersion;
+use serde_json::json;
+
+const PROMPT_NAME: &str = "summarize";
+
+fn stdio_server_bin() -> Result<PathBuf, CargoBinError> {
+    codex_utils_cargo_bin::cargo_bin("test_stdio_server")
+}
+
+fn init_params() -> InitializeRequestParams {
+    InitializeRequestParams {
+        meta: None,
+        capabilities: ClientCapabilities {
+            experimental: None,
+            extensions: None,
+            roots: None,
+            sampling: None,
+            elicitation: Some(ElicitationCapability {
+                form: Some(FormElicitationCapability {
+                    schema_validation: None,
+                }),
+                url: None,
+            }),
+            tasks: None,
+        },
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp prompt test".into()),
+            description: None,
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rmcp_client_can_list_and_get_prompts() -> anyhow::Result<()> {
+    let client = RmcpClient::new_stdio_client(
+        stdio_server_bin()?.into(),
+        Vec::<OsString>::new(),
+        /*env*/ None,
+        &[],
+        /*cwd*/ None,
+        Arc::new(LocalStdioServerLauncher::new(std::env::current_dir()?)),
+    )
+    .await?;
+
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Accept,
+                        content: Some(json!({})),
+                        meta: None,
+                    })
+                }
+                .boxed()
+            }),
+        )
+        .await?;
+
+    let list = client
+        .list_prompts(/*params*/ None, Some(Duration::from_secs(5)))
+        .await?;
+    let prompt = list
+        .prompts
+        .iter()
+        .find(|prompt| prompt.name == PROMPT_NAME)
+        .expect("summarize prompt present");
+    assert_eq!(
+        prompt.description.as_deref(),
+        Some("Summarize a topic in a given style.")
+    );
+    assert_eq!(
+        prompt.arguments,
+        Some(vec![
+            PromptArgument {
+                name: "topic".to_string(),
+                title: None,
+                description: Some("What to summarize.".to_string()),
+                required: Some(true),
+            },
+            PromptArgument {
+                name: "style".to_string(),
+                title: None,
+                description: Some("Optional writing style.".to_string()),
+                required: Some(false),
+            },
+        ])
+    );
+
+    let arguments: JsonObject = serde_json::from_value(json!({
+        "topic": "the release notes",
+        "style": "terse",
+    }))?;
+    let result = client
+        .get_prompt(
+            GetPromptRequestParams {
+                meta: None,
+                name: PROMPT_NAME.to_string(),
+                arguments: Some(arguments),
+            },
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(
+        result.messages,
+        vec![PromptMessage::new_text(
+            PromptMessageRole::User,
+            "Summarize the release notes in a terse style.",
+        )]
+    );
+
+    Ok(())
+}
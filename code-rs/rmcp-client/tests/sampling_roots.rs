@@ -0,0 +1,156 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::LocalStdioServerLauncher;
+use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::ServerRequestHandlers;
+use codex_utils_cargo_bin::CargoBinError;
+use futures::FutureExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParams;
+use rmcp::model::ProtocolVersion;
+use rmcp::model::Root;
+use serde_json::json;
+use tokio::sync::watch;
+
+fn stdio_server_bin() -> Result<PathBuf, CargoBinError> {
+    codex_utils_cargo_bin::cargo_bin("test_stdio_server")
+}
+
+fn init_params() -> anyhow::Result<InitializeRequestParams> {
+    Ok(InitializeRequestParams {
+        meta: None,
+        capabilities: ClientCapabilities {
+            experimental: None,
+            extensions: None,
+            roots: Some(serde_json::from_value(json!({ "listChanged": true }))?),
+            sampling: Some(serde_json::from_value(json!({}))?),
+            elicitation: None,
+            tasks: None,
+        },
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp sampling test".into()),
+            description: None,
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    })
+}
+
+fn root(uri: &str, name: &str) -> anyhow::Result<Root> {
+    Ok(serde_json::from_value(json!({ "uri": uri, "name": name }))?)
+}
+
+/// Answers a sampling request by echoing the first user message.
+fn echo_sampling(params: CreateMessageRequestParams) -> anyhow::Result<CreateMessageResult> {
+    let params = serde_json::to_value(params)?;
+    let prompt = params["messages"][0]["content"]["text"]
+        .as_str()
+        .unwrap_or_default();
+    Ok(serde_json::from_value(json!({
+        "model": "test-model",
+        "stopReason": "endTurn",
+        "role": "assistant",
+        "content": { "type": "text", "text": format!("sampled: {prompt}") },
+    }))?)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rmcp_client_answers_roots_and_sampling_requests() -> anyhow::Result<()> {
+    let client = RmcpClient::new_stdio_client(
+        stdio_server_bin()?.into(),
+        Vec::<OsString>::new(),
+        /*env*/ None,
+        &[],
+        /*cwd*/ None,
+        Arc::new(LocalStdioServerLauncher::new(std::env::current_dir()?)),
+    )
+    .await?;
+
+    let (roots_tx, roots_rx) = watch::channel(vec![root("file:///workspace", "workspace")?]);
+    client
+        .initialize_with_handlers(
+            init_params()?,
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Decline,
+                        content: None,
+                        meta: None,
+                    })
+                }
+                .boxed()
+            }),
+            ServerRequestHandlers {
+                send_sampling: Some(Box::new(|_, params| {
+                    async move { echo_sampling(params) }.boxed()
+                })),
+                roots: Some(roots_rx),
+            },
+        )
+        .await?;
+
+    let result = client
+        .call_tool(
+            "roots".to_string(),
+            Some(json!({})),
+            /*meta*/ None,
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(
+        result.structured_content,
+        Some(json!({ "roots": [{ "uri": "file:///workspace", "name": "workspace" }] }))
+    );
+
+    roots_tx.send_replace(vec![root("file:///other", "other")?]);
+    client.notify_roots_list_changed().await?;
+    let result = client
+        .call_tool(
+            "roots".to_string(),
+            Some(json!({})),
+            /*meta*/ None,
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(
+        result.structured_content,
+        Some(json!({ "roots": [{ "uri": "file:///other", "name": "other" }] }))
+    );
+
+    let result = client
+        .call_tool(
+            "sample".to_string(),
+            Some(json!({ "prompt": "hello" })),
+            /*meta*/ None,
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    let structured = result.structured_content.unwrap_or_default();
+    assert_eq!(
+        (
+            &structured["model"],
+            &structured["role"],
+            &structured["content"]["text"]
+        ),
+        (
+            &json!("test-model"),
+            &json!("assistant"),
+            &json!("sampled: hello")
+        )
+    );
+
+    Ok(())
+}
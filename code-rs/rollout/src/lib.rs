@@ -52,6 +52,7 @@ pub use list::read_session_meta_line;
 pub use list::read_thread_item_from_rollout;
 pub use list::rollout_date_parts;
 pub use metadata::builder_from_items;
+pub use metadata::collect_rollout_paths;
 pub use policy::EventPersistenceMode;
 pub use policy::is_persisted_rollout_item;
 pub use policy::should_persist_response_item_for_memories;
@@ -23,6 +23,7 @@ use codex_state::DB_METRIC_BACKFILL_DURATION_MS;
 use codex_state::ExtractionOutcome;
 use codex_state::ThreadMetadataBuilder;
 use codex_state::apply_rollout_item;
+use codex_state::extract_thread_content;
 use std::path::Path;
 use std::path::PathBuf;
 use tracing::info;
@@ -128,6 +129,7 @@ pub async fn extract_metadata_from_rollout(
             | RolloutItem::TurnContext(_)
             | RolloutItem::EventMsg(_) => None,
         }),
+        content: extract_thread_content(&items, &mut None),
         parse_errors,
     })
 }
@@ -286,6 +288,15 @@ pub(crate) async fn backfill_sessions_with_lease(
                             continue;
                         }
                         stats.upserted = stats.upserted.saturating_add(1);
+                        if let Err(err) = runtime
+                            .replace_thread_content(metadata.id, &outcome.content)
+                            .await
+                        {
+                            warn!(
+                                "failed to backfill thread content {}: {err}",
+                                rollout.path.display()
+                            );
+                        }
                         if let Ok(meta_line) = list::read_session_meta_line(&rollout.path).await {
                             if let Err(err) = runtime
                                 .persist_dynamic_tools(
@@ -401,7 +412,8 @@ fn parse_timestamp_to_utc(ts: &str) -> Option<DateTime<Utc>> {
     None
 }
 
-async fn collect_rollout_paths(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+/// Recursively collect rollout JSONL files under `root`.
+pub async fn collect_rollout_paths(root: &Path) -> std::io::Result<Vec<PathBuf>> {
     let mut stack = vec![root.to_path_buf()];
     let mut paths = Vec::new();
     while let Some(dir) = stack.pop() {
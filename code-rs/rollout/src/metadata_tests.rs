@@ -72,6 +72,7 @@ async fn extract_metadata_from_rollout_uses_session_meta() {
 
     assert_eq!(outcome.metadata, expected);
     assert_eq!(outcome.memory_mode, None);
+    assert_eq!(outcome.content, Vec::new());
     assert_eq!(outcome.parse_errors, 0);
 }
 
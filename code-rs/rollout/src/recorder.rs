@@ -1434,6 +1434,8 @@ struct RolloutWriterState {
     default_provider: String,
     generate_memories: bool,
     thread_updated_at_touch: ThreadUpdatedAtTouch,
+    /// Turn that newly written content is indexed under for search.
+    content_turn_id: Option<String>,
     last_logged_error: Option<String>,
 }
 
@@ -1483,6 +1485,7 @@ impl RolloutWriterState {
             default_provider,
             generate_memories,
             thread_updated_at_touch: ThreadUpdatedAtTouch::default(),
+            content_turn_id: None,
             last_logged_error: None,
         }
     }
@@ -1651,6 +1654,22 @@ impl RolloutWriterState {
                 &mut self.thread_updated_at_touch,
             )
             .await;
+            let thread_id = self
+                .state_builder
+                .as_ref()
+                .map(|builder| builder.id)
+                .or_else(|| {
+                    metadata::builder_from_items(written_items.as_slice(), &self.rollout_path)
+                        .map(|builder| builder.id)
+                });
+            state_db::append_thread_content(
+                self.state_db_ctx.as_deref(),
+                thread_id,
+                written_items.as_slice(),
+                &mut self.content_turn_id,
+                "rollout_writer",
+            )
+            .await;
         }
 
         write_result
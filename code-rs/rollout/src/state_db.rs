@@ -11,6 +11,7 @@ use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::SessionSource;
 pub use codex_state::LogEntry;
+use codex_state::ThreadContentEntry;
 use codex_state::ThreadMetadataBuilder;
 use codex_utils_path::normalize_for_path_comparison;
 use serde_json::Value;
@@ -230,7 +231,8 @@ async fn require_backfill_complete(
     }
 }
 
-fn cursor_to_anchor(cursor: Option<&Cursor>) -> Option<codex_state::Anchor> {
+/// Convert a listing cursor into the state DB keyset anchor it encodes.
+pub fn cursor_to_anchor(cursor: Option<&Cursor>) -> Option<codex_state::Anchor> {
     let cursor = cursor?;
     let millis = cursor.timestamp().unix_timestamp_nanos() / 1_000_000;
     let millis = i64::try_from(millis).ok()?;
@@ -386,6 +388,86 @@ pub async fn list_threads_db(
     }
 }
 
+/// Search indexed thread content in SQLite.
+#[allow(clippy::too_many_arguments)]
+pub async fn search_thread_content_db(
+    context: Option<&codex_state::StateRuntime>,
+    query: &str,
+    page_size: usize,
+    cursor: Option<&Cursor>,
+    sort_key: ThreadSortKey,
+    sort_direction: SortDirection,
+    allowed_sources: &[SessionSource],
+    model_providers: Option<&[String]>,
+    cwd_filters: Option<&[PathBuf]>,
+    archived: bool,
+) -> Option<codex_state::ThreadContentSearchPage> {
+    let ctx = context?;
+    let anchor = cursor_to_anchor(cursor);
+    let allowed_sources: Vec<String> = allowed_sources
+        .iter()
+        .map(|value| match serde_json::to_value(value) {
+            Ok(Value::String(s)) => s,
+            Ok(other) => other.to_string(),
+            Err(_) => String::new(),
+        })
+        .collect();
+    let normalized_cwd_filters = cwd_filters.map(|filters| {
+        filters
+            .iter()
+            .map(|cwd| normalize_cwd_for_state_db(cwd))
+            .collect::<Vec<_>>()
+    });
+    match ctx
+        .search_thread_content(
+            query,
+            page_size,
+            codex_state::ThreadFilterOptions {
+                archived_only: archived,
+                allowed_sources: allowed_sources.as_slice(),
+                model_providers,
+                cwd_filters: normalized_cwd_filters.as_deref(),
+                anchor: anchor.as_ref(),
+                sort_key: match sort_key {
+                    ThreadSortKey::CreatedAt => codex_state::SortKey::CreatedAt,
+                    ThreadSortKey::UpdatedAt => codex_state::SortKey::UpdatedAt,
+                },
+                sort_direction: match sort_direction {
+                    SortDirection::Asc => codex_state::SortDirection::Asc,
+                    SortDirection::Desc => codex_state::SortDirection::Desc,
+                },
+                search_term: None,
+            },
+        )
+        .await
+    {
+        Ok(mut page) => {
+            let mut valid_items = Vec::with_capacity(page.items.len());
+            for item in page.items {
+                if tokio::fs::try_exists(&item.metadata.rollout_path)
+                    .await
+                    .unwrap_or(false)
+                {
+                    valid_items.push(item);
+                } else {
+                    warn!(
+                        "state db search_thread_content returned stale rollout path for thread {}: {}",
+                        item.metadata.id,
+                        item.metadata.rollout_path.display()
+                    );
+                    let _ = ctx.delete_thread(item.metadata.id).await;
+                }
+            }
+            page.items = valid_items;
+            Some(page)
+        }
+        Err(err) => {
+            warn!("state db search_thread_content failed: {err}");
+            None
+        }
+    }
+}
+
 /// Look up the rollout path for a thread id using SQLite.
 pub async fn find_rollout_path_by_id(
     context: Option<&codex_state::StateRuntime>,
@@ -433,6 +515,46 @@ pub async fn persist_dynamic_tools(
     }
 }
 
+/// Index searchable content from rollout items that were just written.
+///
+/// `turn_id` tracks the active turn across calls; see
+/// [`codex_state::extract_thread_content`].
+pub async fn append_thread_content(
+    context: Option<&codex_state::StateRuntime>,
+    thread_id: Option<ThreadId>,
+    items: &[RolloutItem],
+    turn_id: &mut Option<String>,
+    stage: &str,
+) {
+    let Some(ctx) = context else {
+        return;
+    };
+    let entries = codex_state::extract_thread_content(items, turn_id);
+    if entries.is_empty() {
+        return;
+    }
+    let Some(thread_id) = thread_id else {
+        return;
+    };
+    if let Err(err) = ctx.append_thread_content(thread_id, &entries).await {
+        warn!("state db append_thread_content failed during {stage} for {thread_id}: {err}");
+    }
+}
+
+pub async fn replace_thread_content(
+    context: Option<&codex_state::StateRuntime>,
+    thread_id: ThreadId,
+    entries: &[ThreadContentEntry],
+    stage: &str,
+) {
+    let Some(ctx) = context else {
+        return;
+    };
+    if let Err(err) = ctx.replace_thread_content(thread_id, entries).await {
+        warn!("state db replace_thread_content failed during {stage} for {thread_id}: {err}");
+    }
+}
+
 pub async fn mark_thread_memory_mode_polluted(
     context: Option<&codex_state::StateRuntime>,
     thread_id: ThreadId,
@@ -516,6 +638,13 @@ pub async fn reconcile_rollout(
         );
         return;
     }
+    replace_thread_content(
+        Some(ctx),
+        metadata.id,
+        &outcome.content,
+        "reconcile_rollout",
+    )
+    .await;
     if let Ok(meta_line) = crate::list::read_session_meta_line(rollout_path).await {
         persist_dynamic_tools(
             Some(ctx),
@@ -1,10 +1,24 @@
 use codex_protocol::models::PermissionProfile;
+use codex_protocol::permissions::ResourceLimitKind;
+use codex_protocol::permissions::ResourceLimits;
+use std::fs::File;
+use std::io;
+use std::io::Read;
 use std::path::Path;
+use std::path::PathBuf;
 
 /// Basename used when the Codex executable self-invokes as the Linux sandbox
 /// helper.
 pub const CODEX_LINUX_SANDBOX_ARG0: &str = "codex-linux-sandbox";
 
+/// Prefix of the sandbox report line the Linux sandbox helper writes when its
+/// cgroup scope recorded that the command exceeded a resource limit.
+const RESOURCE_LIMIT_EXCEEDED_REPORT_PREFIX: &str = "resource-limit-exceeded ";
+
+/// Prefix of the sandbox report lines the Linux sandbox helper writes for
+/// each operation it blocked when run with `--log-denials`.
+const SANDBOX_DENIAL_REPORT_PREFIX: &str = "denied ";
+
 pub fn allow_network_for_proxy(enforce_managed_network: bool) -> bool {
     // When managed network requirements are active, request proxy-only
     // networking from the Linux sandbox helper. Without managed requirements,
@@ -24,9 +38,12 @@ pub fn create_linux_sandbox_command_args_for_permission_profile(
     command: Vec<String>,
     command_cwd: &Path,
     permission_profile: &PermissionProfile,
+    resource_limits: &ResourceLimits,
     sandbox_policy_cwd: &Path,
     use_legacy_landlock: bool,
+    log_denials: bool,
     allow_network_for_proxy: bool,
+    report_file: Option<&Path>,
 ) -> Vec<String> {
     let permission_profile_json = serde_json::to_string(permission_profile)
         .unwrap_or_else(|err| panic!("failed to serialize permission profile: {err}"));
@@ -47,17 +64,146 @@ pub fn create_linux_sandbox_command_args_for_permission_profile(
         "--permission-profile".to_string(),
         permission_profile_json,
     ];
+    if !resource_limits.is_empty() {
+        let resource_limits_json = serde_json::to_string(resource_limits)
+            .unwrap_or_else(|err| panic!("failed to serialize resource limits: {err}"));
+        linux_cmd.push("--resource-limits".to_string());
+        linux_cmd.push(resource_limits_json);
+    }
     if use_legacy_landlock {
         linux_cmd.push("--use-legacy-landlock".to_string());
     }
+    if log_denials {
+        linux_cmd.push("--log-denials".to_string());
+    }
     if allow_network_for_proxy {
         linux_cmd.push("--allow-network-for-proxy".to_string());
     }
+    if let Some(report_file) = report_file {
+        let report_file = report_file
+            .to_str()
+            .unwrap_or_else(|| panic!("sandbox report path must be valid UTF-8"))
+            .to_string();
+        linux_cmd.push("--report-file".to_string());
+        linux_cmd.push(report_file);
+    }
     linux_cmd.push("--".to_string());
     linux_cmd.extend(command);
     linux_cmd
 }
 
+/// File the Linux sandbox helper records what it observed about a run in.
+///
+/// The helper opens the file before it applies any restriction and unlinks it
+/// straight away, so the sandboxed command never gets a path or descriptor it
+/// could use to forge an entry. Only the helper and this handle can reach it.
+/// Dropping the handle leaves the path for the helper, which may still be
+/// starting when a caller that does not read the report moves on.
+#[derive(Debug)]
+pub struct SandboxReport {
+    file: File,
+    path: PathBuf,
+}
+
+impl SandboxReport {
+    pub fn new() -> io::Result<Self> {
+        let (file, path) = tempfile::Builder::new()
+            .prefix("codex-sandbox-report-")
+            .tempfile()?
+            .keep()
+            .map_err(|err| err.error)?;
+        Ok(Self { file, path })
+    }
+
+    /// Path passed to the helper with `--report-file`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads what the helper recorded. Call once the helper has exited.
+    pub fn read(&self) -> io::Result<SandboxReportContents> {
+        // The helper has unlinked the path, so read through the descriptor
+        // created with the file.
+        let mut contents = String::new();
+        let mut file: &File = &self.file;
+        file.read_to_string(&mut contents)?;
+        Ok(parse_sandbox_report(&contents))
+    }
+}
+
+/// What the Linux sandbox helper recorded in a [`SandboxReport`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SandboxReportContents {
+    /// The resource limit the command's cgroup scope recorded as exceeded.
+    pub resource_limit_exceeded: Option<ResourceLimitKind>,
+    /// Operations the sandbox blocked, in the order they were first seen.
+    pub denials: Vec<String>,
+}
+
+/// Sandbox report line recording that the command exceeded `limit`.
+pub fn resource_limit_exceeded_report_line(limit: ResourceLimitKind) -> String {
+    format!("{RESOURCE_LIMIT_EXCEEDED_REPORT_PREFIX}{limit}\n")
+}
+
+/// Sandbox report line recording that the sandbox blocked `denial`.
+///
+/// Denials quote paths and addresses chosen by the command, so control
+/// characters are escaped to keep one denial from spilling into another line.
+pub fn sandbox_denial_report_line(denial: &str) -> String {
+    let mut line = String::from(SANDBOX_DENIAL_REPORT_PREFIX);
+    for ch in denial.chars() {
+        if ch.is_control() {
+            line.extend(ch.escape_default());
+        } else {
+            line.push(ch);
+        }
+    }
+    line.push('\n');
+    line
+}
+
+fn parse_sandbox_report(contents: &str) -> SandboxReportContents {
+    let mut report = SandboxReportContents::default();
+    for line in contents.lines() {
+        if let Some(limit) = line.strip_prefix(RESOURCE_LIMIT_EXCEEDED_REPORT_PREFIX) {
+            report.resource_limit_exceeded = limit.parse().ok();
+        } else if let Some(denial) = line.strip_prefix(SANDBOX_DENIAL_REPORT_PREFIX) {
+            report.denials.push(denial.to_string());
+        }
+    }
+    report
+}
+
+/// Determines whether a command run under the Linux sandbox helper was killed
+/// for exceeding a resource limit.
+///
+/// Only evidence the command cannot fake is considered: the signal that
+/// terminated the helper itself (`SIGXCPU` / `SIGXFSZ`) and the helper's
+/// sandbox report. Exit codes and stderr are under the command's control, so
+/// a command exiting with `128 + SIGXCPU` is not mistaken for a limit kill.
+pub fn resource_limit_exceeded(
+    signal: Option<i32>,
+    report: Option<&SandboxReportContents>,
+) -> Option<ResourceLimitKind> {
+    report
+        .and_then(|report| report.resource_limit_exceeded)
+        .or_else(|| signal.and_then(resource_limit_for_signal))
+}
+
+#[cfg(unix)]
+fn resource_limit_for_signal(signal: i32) -> Option<ResourceLimitKind> {
+    match signal {
+        libc::SIGXCPU => Some(ResourceLimitKind::CpuTime),
+        libc::SIGXFSZ => Some(ResourceLimitKind::FileSize),
+        _ => None,
+    }
+}
+
+#[cfg(not(unix))]
+fn resource_limit_for_signal(_signal: i32) -> Option<ResourceLimitKind> {
+    None
+}
+
 /// Converts the sandbox cwd and execution options into the CLI invocation for
 /// `codex-linux-sandbox`.
 #[cfg_attr(not(test), allow(dead_code))]
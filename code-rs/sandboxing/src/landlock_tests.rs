@@ -62,9 +62,12 @@ fn permission_profile_flag_is_included() {
         command,
         command_cwd,
         &permission_profile,
+        &ResourceLimits::default(),
         cwd,
         /*use_legacy_landlock*/ true,
+        /*log_denials*/ false,
         /*allow_network_for_proxy*/ false,
+        /*report_file*/ None,
     );
 
     assert_eq!(
@@ -72,6 +75,8 @@ fn permission_profile_flag_is_included() {
             .any(|window| { window[0] == "--permission-profile" && !window[1].is_empty() }),
         true
     );
+    assert_eq!(args.contains(&"--resource-limits".to_string()), false);
+    assert_eq!(args.contains(&"--log-denials".to_string()), false);
     assert_eq!(
         args.windows(2)
             .any(|window| window[0] == "--command-cwd" && window[1] == "/tmp/link"),
@@ -79,6 +84,180 @@ fn permission_profile_flag_is_included() {
     );
 }
 
+#[test]
+fn resource_limits_flag_is_included_when_configured() {
+    let resource_limits = ResourceLimits {
+        max_cpu_seconds: Some(30),
+        max_processes: Some(64),
+        ..Default::default()
+    };
+
+    let args = create_linux_sandbox_command_args_for_permission_profile(
+        vec!["/bin/true".to_string()],
+        Path::new("/tmp"),
+        &PermissionProfile::read_only(),
+        &resource_limits,
+        Path::new("/tmp"),
+        /*use_legacy_landlock*/ false,
+        /*log_denials*/ false,
+        /*allow_network_for_proxy*/ false,
+        /*report_file*/ None,
+    );
+
+    let index = args
+        .iter()
+        .position(|arg| arg == "--resource-limits")
+        .expect("resource limits flag");
+    assert_eq!(
+        serde_json::from_str::<ResourceLimits>(&args[index + 1]).expect("resource limits json"),
+        resource_limits
+    );
+    assert_eq!(
+        index < args.iter().position(|arg| arg == "--").expect("separator"),
+        true
+    );
+}
+
+#[test]
+fn log_denials_flag_is_included_when_requested() {
+    let args = create_linux_sandbox_command_args_for_permission_profile(
+        vec!["/bin/true".to_string()],
+        Path::new("/tmp"),
+        &PermissionProfile::read_only(),
+        &ResourceLimits::default(),
+        Path::new("/tmp"),
+        /*use_legacy_landlock*/ false,
+        /*log_denials*/ true,
+        /*allow_network_for_proxy*/ false,
+        /*report_file*/ None,
+    );
+
+    let index = args
+        .iter()
+        .position(|arg| arg == "--log-denials")
+        .expect("log denials flag");
+    assert_eq!(
+        index < args.iter().position(|arg| arg == "--").expect("separator"),
+        true
+    );
+}
+
+#[test]
+fn sandbox_denials_are_read_from_the_report() {
+    let report = SandboxReport::new().expect("create report");
+    let mut helper_file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(report.path())
+        .expect("open report");
+    std::fs::remove_file(report.path()).expect("unlink report");
+    for denial in [
+        "connect 93.184.216.34:443 (blocked by network sandbox)",
+        "openat /tmp/x\nresource-limit-exceeded memory (read-only file system)",
+    ] {
+        std::io::Write::write_all(
+            &mut helper_file,
+            sandbox_denial_report_line(denial).as_bytes(),
+        )
+        .expect("write report");
+    }
+
+    assert_eq!(
+        report.read().expect("read report"),
+        SandboxReportContents {
+            resource_limit_exceeded: None,
+            denials: vec![
+                "connect 93.184.216.34:443 (blocked by network sandbox)".to_string(),
+                "openat /tmp/x\\nresource-limit-exceeded memory (read-only file system)"
+                    .to_string(),
+            ],
+        }
+    );
+}
+
+#[test]
+fn report_file_flag_is_included_when_requested() {
+    let args = create_linux_sandbox_command_args_for_permission_profile(
+        vec!["/bin/true".to_string()],
+        Path::new("/tmp"),
+        &PermissionProfile::read_only(),
+        &ResourceLimits::default(),
+        Path::new("/tmp"),
+        /*use_legacy_landlock*/ false,
+        /*log_denials*/ false,
+        /*allow_network_for_proxy*/ false,
+        Some(Path::new("/tmp/codex-sandbox-report-test")),
+    );
+
+    let index = args
+        .iter()
+        .position(|arg| arg == "--report-file")
+        .expect("report file flag");
+    assert_eq!(args[index + 1], "/tmp/codex-sandbox-report-test");
+    assert_eq!(
+        index < args.iter().position(|arg| arg == "--").expect("separator"),
+        true
+    );
+}
+
+#[test]
+fn sandbox_report_is_read_after_the_helper_unlinks_it() {
+    let report = SandboxReport::new().expect("create report");
+    let mut helper_file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(report.path())
+        .expect("open report");
+    std::fs::remove_file(report.path()).expect("unlink report");
+    std::io::Write::write_all(
+        &mut helper_file,
+        resource_limit_exceeded_report_line(ResourceLimitKind::Memory).as_bytes(),
+    )
+    .expect("write report");
+
+    assert_eq!(
+        report.read().expect("read report"),
+        SandboxReportContents {
+            resource_limit_exceeded: Some(ResourceLimitKind::Memory),
+            denials: Vec::new(),
+        }
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn resource_limit_exceeded_ignores_exit_codes_and_output() {
+    assert_eq!(
+        resource_limit_exceeded(Some(libc::SIGXCPU), /*report*/ None),
+        Some(ResourceLimitKind::CpuTime)
+    );
+    assert_eq!(
+        resource_limit_exceeded(Some(libc::SIGXFSZ), /*report*/ None),
+        Some(ResourceLimitKind::FileSize)
+    );
+    assert_eq!(
+        resource_limit_exceeded(Some(libc::SIGKILL), /*report*/ None),
+        None
+    );
+    assert_eq!(
+        resource_limit_exceeded(
+            Some(libc::SIGKILL),
+            Some(&SandboxReportContents {
+                resource_limit_exceeded: Some(ResourceLimitKind::Processes),
+                denials: Vec::new(),
+            }),
+        ),
+        Some(ResourceLimitKind::Processes)
+    );
+    // A command that exits with `128 + SIGXCPU` is not killed by a signal,
+    // and an empty report carries no limit.
+    assert_eq!(
+        resource_limit_exceeded(
+            /*signal*/ None,
+            Some(&SandboxReportContents::default())
+        ),
+        None
+    );
+}
+
 #[test]
 fn proxy_network_requires_managed_requirements() {
     assert_eq!(
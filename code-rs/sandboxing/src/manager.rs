@@ -3,6 +3,7 @@ use crate::bwrap::WSL1_BWRAP_WARNING;
 #[cfg(target_os = "linux")]
 use crate::bwrap::is_wsl1;
 use crate::landlock::CODEX_LINUX_SANDBOX_ARG0;
+use crate::landlock::SandboxReport;
 use crate::landlock::allow_network_for_proxy;
 use crate::landlock::create_linux_sandbox_command_args_for_permission_profile;
 use crate::policy_transforms::effective_permission_profile;
@@ -13,6 +14,7 @@ use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::HashMap;
@@ -83,6 +85,11 @@ pub struct SandboxExecRequest {
     pub file_system_sandbox_policy: FileSystemSandboxPolicy,
     pub network_sandbox_policy: NetworkSandboxPolicy,
     pub arg0: Option<String>,
+    /// Limits enforced by the Linux sandbox helper.
+    pub resource_limits: ResourceLimits,
+    /// Report the Linux sandbox helper records limit kills and denials in;
+    /// read it once the command has exited.
+    pub sandbox_report: Option<SandboxReport>,
 }
 
 /// Bundled arguments for sandbox transformation.
@@ -91,6 +98,8 @@ pub struct SandboxExecRequest {
 pub struct SandboxTransformRequest<'a> {
     pub command: SandboxCommand,
     pub permissions: &'a PermissionProfile,
+    /// Limits enforced by the Linux sandbox helper; ignored by other sandboxes.
+    pub resource_limits: ResourceLimits,
     pub sandbox: SandboxType,
     pub enforce_managed_network: bool,
     // TODO(viyatb): Evaluate switching this to Option<Arc<NetworkProxy>>
@@ -99,6 +108,9 @@ pub struct SandboxTransformRequest<'a> {
     pub sandbox_policy_cwd: &'a Path,
     pub codex_linux_sandbox_exe: Option<&'a Path>,
     pub use_legacy_landlock: bool,
+    /// Asks the Linux sandbox helper to record the operations it blocked in
+    /// the sandbox report; ignored by other sandboxes.
+    pub log_denials: bool,
     pub windows_sandbox_level: WindowsSandboxLevel,
     pub windows_sandbox_private_desktop: bool,
 }
@@ -172,12 +184,14 @@ impl SandboxManager {
         let SandboxTransformRequest {
             mut command,
             permissions,
+            resource_limits,
             sandbox,
             enforce_managed_network,
             network,
             sandbox_policy_cwd,
             codex_linux_sandbox_exe,
             use_legacy_landlock,
+            log_denials,
             windows_sandbox_level,
             windows_sandbox_private_desktop,
         } = request;
@@ -190,8 +204,8 @@ impl SandboxManager {
         argv.push(command.program);
         argv.extend(command.args.into_iter().map(OsString::from));
 
-        let (argv, arg0_override) = match sandbox {
-            SandboxType::None => (os_argv_to_strings(argv), None),
+        let (argv, arg0_override, sandbox_report) = match sandbox {
+            SandboxType::None => (os_argv_to_strings(argv), None, None),
             #[cfg(target_os = "macos")]
             SandboxType::MacosSeatbelt => {
                 use crate::seatbelt::CreateSeatbeltCommandArgsParams;
@@ -210,7 +224,7 @@ impl SandboxManager {
                 let mut full_command = Vec::with_capacity(1 + args.len());
                 full_command.push(MACOS_PATH_TO_SEATBELT_EXECUTABLE.to_string());
                 full_command.append(&mut args);
-                (full_command, None)
+                (full_command, None, None)
             }
             #[cfg(not(target_os = "macos"))]
             SandboxType::MacosSeatbelt => return Err(SandboxTransformError::SeatbeltUnavailable),
@@ -225,23 +239,35 @@ impl SandboxManager {
                     allow_proxy_network,
                     is_wsl1(),
                 )?;
+                let sandbox_report = if resource_limits.is_empty() && !log_denials {
+                    None
+                } else {
+                    create_sandbox_report()
+                };
                 let mut args = create_linux_sandbox_command_args_for_permission_profile(
                     os_argv_to_strings(argv),
                     command.cwd.as_path(),
                     &effective_permission_profile,
+                    &resource_limits,
                     sandbox_policy_cwd,
                     use_legacy_landlock,
+                    log_denials,
                     allow_proxy_network,
+                    sandbox_report.as_ref().map(SandboxReport::path),
                 );
                 let mut full_command = Vec::with_capacity(1 + args.len());
                 full_command.push(os_string_to_command_component(exe.as_os_str().to_owned()));
                 full_command.append(&mut args);
-                (full_command, Some(linux_sandbox_arg0_override(exe)))
+                (
+                    full_command,
+                    Some(linux_sandbox_arg0_override(exe)),
+                    sandbox_report,
+                )
             }
             #[cfg(target_os = "windows")]
-            SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None),
+            SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None, None),
             #[cfg(not(target_os = "windows"))]
-            SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None),
+            SandboxType::WindowsRestrictedToken => (os_argv_to_strings(argv), None, None),
         };
 
         Ok(SandboxExecRequest {
@@ -256,10 +282,26 @@ impl SandboxManager {
             file_system_sandbox_policy: effective_file_system_policy,
             network_sandbox_policy: effective_network_policy,
             arg0: arg0_override,
+            resource_limits,
+            sandbox_report,
         })
     }
 }
 
+/// Creates the report the Linux sandbox helper records limit kills and
+/// denials in. The command still runs without one; its limit kills are then
+/// only recognized from the helper's own termination signal and its denials
+/// are not reported.
+fn create_sandbox_report() -> Option<SandboxReport> {
+    match SandboxReport::new() {
+        Ok(report) => Some(report),
+        Err(err) => {
+            tracing::warn!("failed to create Linux sandbox report: {err}");
+            None
+        }
+    }
+}
+
 pub fn compatibility_sandbox_policy_for_permission_profile(
     permissions: &PermissionProfile,
     file_system_policy: &FileSystemSandboxPolicy,
@@ -15,6 +15,7 @@ use codex_protocol::permissions::FileSystemSandboxEntry;
 use codex_protocol::permissions::FileSystemSandboxPolicy;
 use codex_protocol::permissions::FileSystemSpecialPath;
 use codex_protocol::permissions::NetworkSandboxPolicy;
+use codex_protocol::permissions::ResourceLimits;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use dunce::canonicalize;
 use pretty_assertions::assert_eq;
@@ -87,12 +88,14 @@ fn transform_preserves_unrestricted_file_system_policy_for_restricted_network()
                 additional_permissions: None,
             },
             permissions: &permissions,
+            resource_limits: ResourceLimits::default(),
             sandbox: SandboxType::None,
             enforce_managed_network: false,
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
             use_legacy_landlock: false,
+            log_denials: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
         })
@@ -138,12 +141,14 @@ fn transform_additional_permissions_enable_network_for_external_sandbox() {
                 }),
             },
             permissions: &permissions,
+            resource_limits: ResourceLimits::default(),
             sandbox: SandboxType::None,
             enforce_managed_network: false,
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
             use_legacy_landlock: false,
+            log_denials: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
         })
@@ -206,12 +211,14 @@ fn transform_additional_permissions_preserves_denied_entries() {
                 }),
             },
             permissions: &permissions,
+            resource_limits: ResourceLimits::default(),
             sandbox: SandboxType::None,
             enforce_managed_network: false,
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: None,
             use_legacy_landlock: false,
+            log_denials: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
         })
@@ -259,12 +266,14 @@ fn transform_linux_seccomp_request(
                 additional_permissions: None,
             },
             permissions: &permissions,
+            resource_limits: ResourceLimits::default(),
             sandbox: SandboxType::LinuxSeccomp,
             enforce_managed_network: false,
             network: None,
             sandbox_policy_cwd: cwd.as_path(),
             codex_linux_sandbox_exe: Some(codex_linux_sandbox_exe),
             use_legacy_landlock: false,
+            log_denials: false,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
             windows_sandbox_private_desktop: false,
         })
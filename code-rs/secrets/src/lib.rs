@@ -14,9 +14,25 @@ use sha2::Digest;
 use sha2::Sha256;
 
 mod local;
+mod redaction;
+mod reference;
 mod sanitizer;
 
 pub use local::LocalSecretsBackend;
+pub use redaction::EntropyRule;
+pub use redaction::OutputRedactor;
+pub use redaction::RedactionHits;
+pub use redaction::RedactionKind;
+pub use redaction::RedactionRules;
+pub use redaction::validate_redaction_pattern;
+pub use reference::SECRET_ENV_VAR_PREFIX;
+pub use reference::SECRET_REFERENCE_PREFIX;
+pub use reference::SecretRedactor;
+pub use reference::expand_secret_references;
+pub use reference::secret_env_var_name;
+pub use reference::secret_references;
+pub use reference::substitute_secret_references;
+pub use sanitizer::REDACTED_PLACEHOLDER;
 pub use sanitizer::redact_secrets;
 
 const KEYRING_SERVICE: &str = "codex";
@@ -137,6 +153,16 @@ impl SecretsManager {
     pub fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
         self.backend.list(scope_filter)
     }
+
+    /// Looks `name` up in the scope of `environment_id`, falling back to the
+    /// global scope.
+    pub fn resolve(&self, environment_id: &str, name: &SecretName) -> Result<Option<String>> {
+        let scope = SecretScope::environment(environment_id)?;
+        if let Some(value) = self.get(&scope, name)? {
+            return Ok(Some(value));
+        }
+        self.get(&SecretScope::Global, name)
+    }
 }
 
 pub fn environment_id_from_cwd(cwd: &Path) -> String {
@@ -227,4 +253,33 @@ mod tests {
         assert_eq!(manager.get(&scope, &name)?, None);
         Ok(())
     }
+
+    #[test]
+    fn resolve_prefers_environment_scope_over_global() -> Result<()> {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let keyring = Arc::new(MockKeyringStore::default());
+        let manager = SecretsManager::new_with_keyring_store(
+            codex_home.path().to_path_buf(),
+            SecretsBackendKind::Local,
+            keyring,
+        );
+        let name = SecretName::new("NPM_TOKEN")?;
+
+        assert_eq!(manager.resolve("repo", &name)?, None);
+        manager.set(&SecretScope::Global, &name, "global-token")?;
+        assert_eq!(
+            manager.resolve("repo", &name)?,
+            Some("global-token".to_string())
+        );
+        manager.set(&SecretScope::environment("repo")?, &name, "repo-token")?;
+        assert_eq!(
+            manager.resolve("repo", &name)?,
+            Some("repo-token".to_string())
+        );
+        assert_eq!(
+            manager.resolve("other", &name)?,
+            Some("global-token".to_string())
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Captures;
+use regex::Regex;
+
+use super::SecretName;
+use super::sanitizer::REDACTED_PLACEHOLDER;
+use super::sanitizer::compile_regex;
+
+/// Prefix marking a secret reference in a command, as in `$secret:NPM_TOKEN`.
+pub const SECRET_REFERENCE_PREFIX: &str = "$secret:";
+
+/// Prefix of the environment variables secrets are passed to commands in, so
+/// an injected secret can never replace a variable like `PATH`.
+pub const SECRET_ENV_VAR_PREFIX: &str = "CODE_SECRET_";
+
+static SECRET_REFERENCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| compile_regex(r"\$secret:([A-Z0-9_]+)"));
+
+/// Returns the secrets referenced in `text`, in order of first appearance.
+pub fn secret_references(text: &str) -> Vec<SecretName> {
+    let mut names = Vec::new();
+    for captures in SECRET_REFERENCE_REGEX.captures_iter(text) {
+        if let Ok(name) = SecretName::new(&captures[1])
+            && !names.contains(&name)
+        {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Environment variable the value of secret `name` is passed to commands in.
+pub fn secret_env_var_name(name: &SecretName) -> String {
+    format!("{SECRET_ENV_VAR_PREFIX}{name}")
+}
+
+/// Rewrites every `$secret:NAME` reference into a `${CODE_SECRET_NAME}` shell
+/// expansion so the value can be passed through the environment instead of
+/// the command.
+pub fn expand_secret_references(text: &str) -> String {
+    SECRET_REFERENCE_REGEX
+        .replace_all(text, |captures: &Captures<'_>| {
+            format!("${{{SECRET_ENV_VAR_PREFIX}{}}}", &captures[1])
+        })
+        .into_owned()
+}
+
+/// Replaces every `$secret:NAME` reference in `text` with the value `resolve`
+/// returns for it.
+pub fn substitute_secret_references(
+    text: &str,
+    mut resolve: impl FnMut(&SecretName) -> anyhow::Result<String>,
+) -> anyhow::Result<String> {
+    let mut substituted = String::with_capacity(text.len());
+    let mut last_end = 0;
+    for captures in SECRET_REFERENCE_REGEX.captures_iter(text) {
+        let Some(reference) = captures.get(0) else {
+            continue;
+        };
+        let name = SecretName::new(&captures[1])?;
+        substituted.push_str(&text[last_end..reference.start()]);
+        substituted.push_str(&resolve(&name)?);
+        last_end = reference.end();
+    }
+    substituted.push_str(&text[last_end..]);
+    Ok(substituted)
+}
+
+/// Replaces known secret values with `[REDACTED_SECRET]`.
+///
+/// Unlike [`super::redact_secrets`], which guesses at secrets from their
+/// shape, this only redacts values it was given.
+#[derive(Clone, Default)]
+pub struct SecretRedactor {
+    values: Vec<String>,
+}
+
+impl SecretRedactor {
+    pub fn add(&mut self, value: &str) {
+        if value.is_empty() || self.values.iter().any(|known| known == value) {
+            return;
+        }
+        self.values.push(value.to_string());
+        // Redact longer values first so a value containing another one is
+        // not left partially visible.
+        self.values
+            .sort_by_key(|known| std::cmp::Reverse(known.len()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn redact(&self, text: &str) -> String {
+        self.redact_counting(text).0
+    }
+
+    /// Like [`Self::redact`], also returning how many values were replaced.
+    pub(crate) fn redact_counting(&self, text: &str) -> (String, usize) {
+        let mut redacted = text.to_string();
+        let mut hits = 0;
+        for value in &self.values {
+            let matches = redacted.matches(value.as_str()).count();
+            if matches > 0 {
+                hits += matches;
+                redacted = redacted.replace(value.as_str(), REDACTED_PLACEHOLDER);
+            }
+        }
+        (redacted, hits)
+    }
+}
+
+impl fmt::Debug for SecretRedactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretRedactor")
+            .field("values", &self.values.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn secret_references_are_found_once_in_order() -> anyhow::Result<()> {
+        let command = "npm publish --token $secret:NPM_TOKEN && echo $secret:GH_TOKEN$secret:NPM_TOKEN $secret:lower";
+        assert_eq!(
+            secret_references(command),
+            vec![SecretName::new("NPM_TOKEN")?, SecretName::new("GH_TOKEN")?]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expand_secret_references_rewrites_to_shell_expansions() {
+        assert_eq!(
+            expand_secret_references("curl -H \"Authorization: $secret:API_TOKEN\" $HOME"),
+            "curl -H \"Authorization: ${CODE_SECRET_API_TOKEN}\" $HOME"
+        );
+    }
+
+    #[test]
+    fn substitute_secret_references_inserts_values() -> anyhow::Result<()> {
+        let substituted = substitute_secret_references(
+            "Bearer $secret:API_TOKEN:$secret:API_TOKEN_2",
+            |name| Ok(format!("<{name}>")),
+        )?;
+        assert_eq!(substituted, "Bearer <API_TOKEN>:<API_TOKEN_2>");
+
+        let err = substitute_secret_references("$secret:MISSING", |name| {
+            Err(anyhow::anyhow!("secret `{name}` is not set"))
+        })
+        .expect_err("missing secret should fail");
+        assert_eq!(err.to_string(), "secret `MISSING` is not set");
+        Ok(())
+    }
+
+    #[test]
+    fn redactor_replaces_longest_values_first() {
+        let mut redactor = SecretRedactor::default();
+        redactor.add("abc");
+        redactor.add("abcdef");
+        redactor.add("");
+
+        assert_eq!(
+            redactor.redact("token=abcdef other=abc"),
+            "token=[REDACTED_SECRET] other=[REDACTED_SECRET]"
+        );
+        assert_eq!(format!("{redactor:?}"), "SecretRedactor { values: 2 }");
+    }
+}
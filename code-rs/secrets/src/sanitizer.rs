@@ -1,6 +1,9 @@
 use regex::Regex;
 use std::sync::LazyLock;
 
+/// Text that replaces every redacted value.
+pub const REDACTED_PLACEHOLDER: &str = "[REDACTED_SECRET]";
+
 static OPENAI_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| compile_regex(r"sk-[A-Za-z0-9]{20,}"));
 static AWS_ACCESS_KEY_ID_REGEX: LazyLock<Regex> =
     LazyLock::new(|| compile_regex(r"\bAKIA[0-9A-Z]{16}\b"));
@@ -13,15 +16,35 @@ static SECRET_ASSIGNMENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 /// Remove secret and keys from a String. This is done on best effort basis following some
 /// well-known REGEX.
 pub fn redact_secrets(input: String) -> String {
-    let redacted = OPENAI_KEY_REGEX.replace_all(&input, "[REDACTED_SECRET]");
-    let redacted = AWS_ACCESS_KEY_ID_REGEX.replace_all(&redacted, "[REDACTED_SECRET]");
-    let redacted = BEARER_TOKEN_REGEX.replace_all(&redacted, "Bearer [REDACTED_SECRET]");
-    let redacted = SECRET_ASSIGNMENT_REGEX.replace_all(&redacted, "$1$2$3[REDACTED_SECRET]");
+    redact_builtin_patterns(&input).0
+}
 
-    redacted.to_string()
+/// Applies the well-known patterns and returns the redacted text together with
+/// the number of matches that were replaced.
+pub(crate) fn redact_builtin_patterns(input: &str) -> (String, usize) {
+    let patterns: [(&Regex, &str); 4] = [
+        (&OPENAI_KEY_REGEX, REDACTED_PLACEHOLDER),
+        (&AWS_ACCESS_KEY_ID_REGEX, REDACTED_PLACEHOLDER),
+        (&BEARER_TOKEN_REGEX, "Bearer [REDACTED_SECRET]"),
+        (&SECRET_ASSIGNMENT_REGEX, "$1$2$3[REDACTED_SECRET]"),
+    ];
+
+    let mut redacted = input.to_string();
+    let mut hits = 0;
+    for (regex, replacement) in patterns {
+        let matches = regex
+            .find_iter(&redacted)
+            .filter(|found| !found.as_str().contains(REDACTED_PLACEHOLDER))
+            .count();
+        if matches > 0 {
+            hits += matches;
+            redacted = regex.replace_all(&redacted, replacement).into_owned();
+        }
+    }
+    (redacted, hits)
 }
 
-fn compile_regex(pattern: &str) -> Regex {
+pub(crate) fn compile_regex(pattern: &str) -> Regex {
     match Regex::new(pattern) {
         Ok(regex) => regex,
         // Panic is ok thanks to `load_regex` test.
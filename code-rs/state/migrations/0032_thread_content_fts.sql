@@ -0,0 +1,19 @@
+CREATE VIRTUAL TABLE thread_content_fts USING fts5(
+    content,
+    thread_id UNINDEXED,
+    turn_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+CREATE TRIGGER threads_delete_content_fts AFTER DELETE ON threads
+BEGIN
+    DELETE FROM thread_content_fts WHERE thread_id = old.id;
+END;
+
+-- Existing rollouts were backfilled before content was indexed; rerun the
+-- backfill so their content becomes searchable.
+UPDATE backfill_state
+SET status = 'pending',
+    last_watermark = NULL,
+    updated_at = CAST(strftime('%s', 'now') AS INTEGER)
+WHERE id = 1;
@@ -0,0 +1,23 @@
+CREATE TABLE thread_turns (
+    thread_id TEXT NOT NULL REFERENCES threads(id) ON DELETE CASCADE,
+    turn_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    status TEXT NOT NULL CHECK(status IN ('in_progress', 'completed', 'interrupted', 'failed')),
+    error_message TEXT,
+    started_at INTEGER,
+    completed_at INTEGER,
+    duration_ms INTEGER,
+    PRIMARY KEY(thread_id, turn_id)
+);
+
+CREATE UNIQUE INDEX idx_thread_turns_position ON thread_turns(thread_id, position);
+
+CREATE TABLE thread_items (
+    thread_id TEXT NOT NULL REFERENCES threads(id) ON DELETE CASCADE,
+    seq INTEGER NOT NULL,
+    turn_id TEXT,
+    item TEXT NOT NULL,
+    PRIMARY KEY(thread_id, seq)
+);
+
+CREATE INDEX idx_thread_items_turn ON thread_items(thread_id, turn_id, seq);
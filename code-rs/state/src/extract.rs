@@ -1,4 +1,6 @@
+use crate::model::ThreadContentEntry;
 use crate::model::ThreadMetadata;
+use codex_protocol::models::LocalShellAction;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::RolloutItem;
@@ -10,6 +12,8 @@ use serde::Serialize;
 use serde_json::Value;
 
 const IMAGE_ONLY_USER_MESSAGE_PLACEHOLDER: &str = "[Image]";
+/// Tool output can be arbitrarily large; only its head is indexed.
+const MAX_INDEXED_TOOL_OUTPUT_BYTES: usize = 4 * 1024;
 
 /// Apply a rollout item to the metadata structure.
 pub fn apply_rollout_item(
@@ -40,6 +44,81 @@ pub fn rollout_item_affects_thread_metadata(item: &RolloutItem) -> bool {
     }
 }
 
+/// Extract searchable content from rollout items.
+///
+/// `turn_id` carries the active turn across calls so items appended in later
+/// batches are attributed to the turn that produced them.
+pub fn extract_thread_content(
+    items: &[RolloutItem],
+    turn_id: &mut Option<String>,
+) -> Vec<ThreadContentEntry> {
+    let mut entries = Vec::new();
+    for item in items {
+        match item {
+            RolloutItem::TurnContext(TurnContextItem {
+                turn_id: Some(id), ..
+            }) => *turn_id = Some(id.clone()),
+            RolloutItem::EventMsg(EventMsg::TurnStarted(event)) => {
+                *turn_id = Some(event.turn_id.clone());
+            }
+            _ => {}
+        }
+        if let Some(content) = searchable_content(item)
+            && !content.trim().is_empty()
+        {
+            entries.push(ThreadContentEntry {
+                turn_id: turn_id.clone(),
+                content,
+            });
+        }
+    }
+    entries
+}
+
+fn searchable_content(item: &RolloutItem) -> Option<String> {
+    match item {
+        // Messages are indexed from events rather than response items so each
+        // one is only indexed once.
+        RolloutItem::EventMsg(EventMsg::UserMessage(user)) => {
+            Some(strip_user_message_prefix(user.message.as_str()).to_string())
+        }
+        RolloutItem::EventMsg(EventMsg::AgentMessage(agent)) => Some(agent.message.clone()),
+        RolloutItem::EventMsg(EventMsg::Error(error)) => Some(error.message.clone()),
+        RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+            name, arguments, ..
+        }) => Some(format!("{name} {arguments}")),
+        RolloutItem::ResponseItem(ResponseItem::CustomToolCall { name, input, .. }) => {
+            Some(format!("{name} {input}"))
+        }
+        RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+            action: LocalShellAction::Exec(exec),
+            ..
+        }) => Some(exec.command.join(" ")),
+        RolloutItem::ResponseItem(
+            ResponseItem::FunctionCallOutput { output, .. }
+            | ResponseItem::CustomToolCallOutput { output, .. },
+        ) => output
+            .text_content()
+            .map(|text| truncate_to_char_boundary(text, MAX_INDEXED_TOOL_OUTPUT_BYTES).to_string()),
+        RolloutItem::SessionMeta(_)
+        | RolloutItem::TurnContext(_)
+        | RolloutItem::Compacted(_)
+        | RolloutItem::EventMsg(_)
+        | RolloutItem::ResponseItem(_) => None,
+    }
+}
+
+fn truncate_to_char_boundary(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
 fn apply_session_meta_from_item(metadata: &mut ThreadMetadata, meta_line: &SessionMetaLine) {
     if metadata.id != meta_line.meta.id {
         // Ignore session_meta lines that don't match the canonical thread ID,
@@ -136,14 +215,18 @@ pub(crate) fn enum_to_string<T: Serialize>(value: &T) -> String {
 #[cfg(test)]
 mod tests {
     use super::apply_rollout_item;
+    use super::extract_thread_content;
+    use crate::model::ThreadContentEntry;
     use crate::model::ThreadMetadata;
     use chrono::DateTime;
     use chrono::Utc;
     use codex_protocol::ThreadId;
     use codex_protocol::config_types::ReasoningSummary;
     use codex_protocol::models::ContentItem;
+    use codex_protocol::models::FunctionCallOutputPayload;
     use codex_protocol::models::ResponseItem;
     use codex_protocol::openai_models::ReasoningEffort;
+    use codex_protocol::protocol::AgentMessageEvent;
     use codex_protocol::protocol::AskForApproval;
     use codex_protocol::protocol::EventMsg;
     use codex_protocol::protocol::RolloutItem;
@@ -152,6 +235,7 @@ mod tests {
     use codex_protocol::protocol::SessionMetaLine;
     use codex_protocol::protocol::SessionSource;
     use codex_protocol::protocol::TurnContextItem;
+    use codex_protocol::protocol::TurnStartedEvent;
     use codex_protocol::protocol::USER_MESSAGE_BEGIN;
     use codex_protocol::protocol::UserMessageEvent;
 
@@ -402,6 +486,70 @@ mod tests {
         assert_eq!(metadata.reasoning_effort, None);
     }
 
+    #[test]
+    fn thread_content_is_attributed_to_the_active_turn() {
+        let mut turn_id = None;
+        let first_batch = vec![
+            RolloutItem::EventMsg(EventMsg::TurnStarted(TurnStartedEvent {
+                turn_id: "turn-1".to_string(),
+                started_at: None,
+                model_context_window: None,
+                collaboration_mode_kind: Default::default(),
+            })),
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: format!("{USER_MESSAGE_BEGIN} fix the linker error"),
+                images: None,
+                local_images: vec![],
+                text_elements: vec![],
+            })),
+            RolloutItem::ResponseItem(ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "fix the linker error".to_string(),
+                }],
+                phase: None,
+            }),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: r#"{"command":["cargo","build"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text(
+                    "error: linking with `cc` failed".to_string(),
+                ),
+            }),
+        ];
+        let second_batch = vec![RolloutItem::EventMsg(EventMsg::AgentMessage(
+            AgentMessageEvent {
+                message: "Added the missing -lssl flag.".to_string(),
+                phase: None,
+                memory_citation: None,
+            },
+        ))];
+
+        let mut entries = extract_thread_content(&first_batch, &mut turn_id);
+        entries.extend(extract_thread_content(&second_batch, &mut turn_id));
+
+        let entry = |content: &str| ThreadContentEntry {
+            turn_id: Some("turn-1".to_string()),
+            content: content.to_string(),
+        };
+        assert_eq!(
+            entries,
+            vec![
+                entry("fix the linker error"),
+                entry(r#"shell {"command":["cargo","build"]}"#),
+                entry("error: linking with `cc` failed"),
+                entry("Added the missing -lssl flag."),
+            ]
+        );
+    }
+
     fn metadata_for_test() -> ThreadMetadata {
         let id = ThreadId::from_string(&Uuid::from_u128(42).to_string()).expect("thread id");
         let created_at = DateTime::<Utc>::from_timestamp(1_735_689_600, 0).expect("timestamp");
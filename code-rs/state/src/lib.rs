@@ -22,6 +22,7 @@ pub use runtime::StateRuntime;
 ///
 /// Most consumers should prefer [`StateRuntime`].
 pub use extract::apply_rollout_item;
+pub use extract::extract_thread_content;
 pub use extract::rollout_item_affects_thread_metadata;
 pub use model::AgentJob;
 pub use model::AgentJobCreateParams;
@@ -42,10 +43,16 @@ pub use model::Stage1JobClaim;
 pub use model::Stage1JobClaimOutcome;
 pub use model::Stage1Output;
 pub use model::Stage1StartupClaimParams;
+pub use model::ThreadContentEntry;
+pub use model::ThreadContentMatch;
+pub use model::ThreadContentSearchPage;
 pub use model::ThreadGoal;
 pub use model::ThreadGoalStatus;
+pub use model::ThreadItemRecord;
 pub use model::ThreadMetadata;
 pub use model::ThreadMetadataBuilder;
+pub use model::ThreadTurn;
+pub use model::ThreadTurnStatus;
 pub use model::ThreadsPage;
 pub use runtime::RemoteControlEnrollmentRecord;
 pub use runtime::ThreadFilterOptions;
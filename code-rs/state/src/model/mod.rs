@@ -3,7 +3,9 @@ mod backfill_state;
 mod graph;
 mod log;
 mod memories;
+mod thread_content;
 mod thread_goal;
+mod thread_item;
 mod thread_metadata;
 
 pub use agent_job::AgentJob;
@@ -24,8 +26,14 @@ pub use memories::Stage1JobClaim;
 pub use memories::Stage1JobClaimOutcome;
 pub use memories::Stage1Output;
 pub use memories::Stage1StartupClaimParams;
+pub use thread_content::ThreadContentEntry;
+pub use thread_content::ThreadContentMatch;
+pub use thread_content::ThreadContentSearchPage;
 pub use thread_goal::ThreadGoal;
 pub use thread_goal::ThreadGoalStatus;
+pub use thread_item::ThreadItemRecord;
+pub use thread_item::ThreadTurn;
+pub use thread_item::ThreadTurnStatus;
 pub use thread_metadata::Anchor;
 pub use thread_metadata::BackfillStats;
 pub use thread_metadata::ExtractionOutcome;
@@ -0,0 +1,33 @@
+use super::Anchor;
+use super::ThreadMetadata;
+
+/// Searchable text extracted from a single rollout item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadContentEntry {
+    /// The turn that produced the text, if known.
+    pub turn_id: Option<String>,
+    /// The text to index.
+    pub content: String,
+}
+
+/// A thread whose indexed content matched a search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadContentMatch {
+    /// The matching thread's metadata.
+    pub metadata: ThreadMetadata,
+    /// The turn containing the best-ranked hit, if known.
+    pub turn_id: Option<String>,
+    /// An excerpt of the best-ranked hit.
+    pub snippet: String,
+}
+
+/// A single page of content search results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadContentSearchPage {
+    /// The matching threads in this page.
+    pub items: Vec<ThreadContentMatch>,
+    /// The next anchor to use for pagination, if any.
+    pub next_anchor: Option<Anchor>,
+    /// The number of rows scanned to produce this page.
+    pub num_scanned_rows: usize,
+}
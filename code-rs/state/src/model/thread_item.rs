@@ -0,0 +1,62 @@
+use anyhow::Result;
+use codex_protocol::protocol::RolloutItem;
+
+/// Lifecycle state of a stored turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadTurnStatus {
+    InProgress,
+    Completed,
+    Interrupted,
+    Failed,
+}
+
+impl ThreadTurnStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ThreadTurnStatus::InProgress => "in_progress",
+            ThreadTurnStatus::Completed => "completed",
+            ThreadTurnStatus::Interrupted => "interrupted",
+            ThreadTurnStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "in_progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "interrupted" => Ok(Self::Interrupted),
+            "failed" => Ok(Self::Failed),
+            _ => Err(anyhow::anyhow!("invalid thread turn status: {value}")),
+        }
+    }
+}
+
+/// A turn derived from the items stored for a thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadTurn {
+    /// The turn identifier.
+    pub turn_id: String,
+    /// Zero-based position of the turn within its thread.
+    pub position: i64,
+    /// The turn's lifecycle state.
+    pub status: ThreadTurnStatus,
+    /// The error that failed the turn, if any.
+    pub error_message: Option<String>,
+    /// Unix timestamp (seconds) when the turn started.
+    pub started_at: Option<i64>,
+    /// Unix timestamp (seconds) when the turn completed.
+    pub completed_at: Option<i64>,
+    /// Duration between turn start and completion in milliseconds.
+    pub duration_ms: Option<i64>,
+}
+
+/// A rollout item stored for a thread.
+#[derive(Debug, Clone)]
+pub struct ThreadItemRecord {
+    /// Zero-based position of the item within its thread.
+    pub seq: i64,
+    /// The turn that produced the item, if any.
+    pub turn_id: Option<String>,
+    /// The stored item.
+    pub item: RolloutItem,
+}
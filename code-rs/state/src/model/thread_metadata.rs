@@ -11,6 +11,8 @@ use sqlx::Row;
 use sqlx::sqlite::SqliteRow;
 use std::path::PathBuf;
 
+use super::ThreadContentEntry;
+
 /// The sort key to use when listing threads.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortKey {
@@ -52,6 +54,8 @@ pub struct ExtractionOutcome {
     pub metadata: ThreadMetadata,
     /// The explicit thread memory mode from rollout metadata, if present.
     pub memory_mode: Option<String>,
+    /// Searchable content extracted from the rollout.
+    pub content: Vec<ThreadContentEntry>,
     /// The number of rollout lines that failed to parse.
     pub parse_errors: usize,
 }
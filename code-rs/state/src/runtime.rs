@@ -62,6 +62,8 @@ mod memories;
 mod remote_control;
 #[cfg(test)]
 mod test_support;
+mod thread_content;
+mod thread_items;
 mod threads;
 
 pub use goals::ThreadGoalAccountingMode;
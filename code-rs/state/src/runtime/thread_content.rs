@@ -0,0 +1,296 @@
+use super::threads::push_thread_filters;
+use super::threads::push_thread_order_and_limit;
+use super::threads::push_thread_select_columns;
+use super::*;
+use crate::ThreadContentEntry;
+use crate::ThreadContentMatch;
+use crate::ThreadContentSearchPage;
+
+impl StateRuntime {
+    /// Append searchable content for a thread.
+    pub async fn append_thread_content(
+        &self,
+        thread_id: ThreadId,
+        entries: &[ThreadContentEntry],
+    ) -> anyhow::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut tx = self.pool.begin().await?;
+        insert_thread_content(&mut tx, thread_id, entries).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Replace all searchable content for a thread, e.g. after re-reading its
+    /// rollout from disk.
+    pub async fn replace_thread_content(
+        &self,
+        thread_id: ThreadId,
+        entries: &[ThreadContentEntry],
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM thread_content_fts WHERE thread_id = ?")
+            .bind(thread_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        insert_thread_content(&mut tx, thread_id, entries).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Search indexed thread content.
+    ///
+    /// Each whitespace-separated word of `query` must appear in the same
+    /// rollout item; the last word also matches as a prefix so results keep
+    /// up while the query is being typed. Every matching thread is returned
+    /// once, with its best-ranked hit. `filters.search_term` is ignored.
+    pub async fn search_thread_content(
+        &self,
+        query: &str,
+        page_size: usize,
+        filters: ThreadFilterOptions<'_>,
+    ) -> anyhow::Result<ThreadContentSearchPage> {
+        let Some(match_expression) = fts_match_expression(query) else {
+            return Ok(ThreadContentSearchPage {
+                items: Vec::new(),
+                next_anchor: None,
+                num_scanned_rows: 0,
+            });
+        };
+        let limit = page_size.saturating_add(1);
+        let sort_key = filters.sort_key;
+        let sort_direction = filters.sort_direction;
+
+        // `snippet()` cannot share a SELECT with a window function, so the
+        // matches are materialized before picking each thread's best hit.
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+WITH matches AS MATERIALIZED (
+    SELECT
+        thread_id,
+        turn_id,
+        snippet(thread_content_fts, 0, '', '', '…', 16) AS snippet,
+        rank
+    FROM thread_content_fts
+    WHERE thread_content_fts MATCH "#,
+        );
+        builder.push_bind(match_expression);
+        builder.push(
+            r#"
+),
+hits AS (
+    SELECT
+        thread_id,
+        turn_id,
+        snippet,
+        ROW_NUMBER() OVER (PARTITION BY thread_id ORDER BY rank, turn_id) AS hit_rank
+    FROM matches
+)
+"#,
+        );
+        push_thread_select_columns(&mut builder);
+        builder.push(
+            r#",
+    hits.turn_id AS hit_turn_id,
+    hits.snippet AS hit_snippet
+FROM threads
+JOIN hits ON hits.thread_id = threads.id AND hits.hit_rank = 1"#,
+        );
+        push_thread_filters(
+            &mut builder,
+            ThreadFilterOptions {
+                search_term: None,
+                ..filters
+            },
+        );
+        push_thread_order_and_limit(&mut builder, sort_key, sort_direction, limit);
+
+        let rows = builder.build().fetch_all(self.pool.as_ref()).await?;
+        let mut items = rows
+            .into_iter()
+            .map(|row| {
+                let metadata = ThreadRow::try_from_row(&row).and_then(ThreadMetadata::try_from)?;
+                Ok(ThreadContentMatch {
+                    metadata,
+                    turn_id: row.try_get("hit_turn_id")?,
+                    snippet: row.try_get("hit_snippet")?,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let num_scanned_rows = items.len();
+        let next_anchor = if items.len() > page_size {
+            items.pop();
+            items
+                .last()
+                .and_then(|item| anchor_from_item(&item.metadata, sort_key))
+        } else {
+            None
+        };
+        Ok(ThreadContentSearchPage {
+            items,
+            next_anchor,
+            num_scanned_rows,
+        })
+    }
+}
+
+pub(super) async fn insert_thread_content(
+    tx: &mut SqliteConnection,
+    thread_id: ThreadId,
+    entries: &[ThreadContentEntry],
+) -> anyhow::Result<()> {
+    let thread_id = thread_id.to_string();
+    for entry in entries {
+        sqlx::query(
+            "INSERT INTO thread_content_fts (content, thread_id, turn_id) VALUES (?, ?, ?)",
+        )
+        .bind(entry.content.as_str())
+        .bind(thread_id.as_str())
+        .bind(entry.turn_id.as_deref())
+        .execute(&mut *tx)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Turn free-form user input into an FTS5 match expression.
+///
+/// Every word is quoted so FTS5 operators and punctuation in the input are
+/// matched literally instead of being parsed as query syntax.
+fn fts_match_expression(query: &str) -> Option<String> {
+    let words = query.split_whitespace().collect::<Vec<_>>();
+    let (last, rest) = words.split_last()?;
+    let quote = |word: &str| format!("\"{}\"", word.replace('"', "\"\""));
+    let mut expression = rest.iter().map(|word| quote(word)).collect::<Vec<_>>();
+    expression.push(format!("{}*", quote(last)));
+    Some(expression.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SortDirection;
+    use crate::runtime::test_support::test_thread_metadata;
+    use crate::runtime::test_support::unique_temp_dir;
+    use pretty_assertions::assert_eq;
+
+    fn filters() -> ThreadFilterOptions<'static> {
+        ThreadFilterOptions {
+            archived_only: false,
+            allowed_sources: &[],
+            model_providers: None,
+            cwd_filters: None,
+            anchor: None,
+            sort_key: SortKey::UpdatedAt,
+            sort_direction: SortDirection::Desc,
+            search_term: None,
+        }
+    }
+
+    fn entry(turn_id: &str, content: &str) -> ThreadContentEntry {
+        ThreadContentEntry {
+            turn_id: Some(turn_id.to_string()),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn match_expression_quotes_words_and_prefixes_the_last_one() {
+        assert_eq!(
+            fts_match_expression(r#"  linker "error" cargo te "#),
+            Some(r#""linker" """error""" "cargo" "te"*"#.to_string())
+        );
+        assert_eq!(fts_match_expression("   "), None);
+    }
+
+    #[tokio::test]
+    async fn search_returns_best_hit_per_thread() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("state db should initialize");
+        let linker_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000201").expect("valid thread id");
+        let other_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000202").expect("valid thread id");
+        for thread_id in [linker_id, other_id] {
+            runtime
+                .upsert_thread(&test_thread_metadata(
+                    &codex_home,
+                    thread_id,
+                    codex_home.clone(),
+                ))
+                .await
+                .expect("thread insert should succeed");
+        }
+        runtime
+            .append_thread_content(
+                linker_id,
+                &[
+                    entry("turn-1", "update the readme"),
+                    entry("turn-2", "error: linking with `cc` failed"),
+                ],
+            )
+            .await
+            .expect("append should succeed");
+        runtime
+            .append_thread_content(other_id, &[entry("turn-1", "add a changelog entry")])
+            .await
+            .expect("append should succeed");
+
+        let page = runtime
+            .search_thread_content("linking fail", /*page_size*/ 10, filters())
+            .await
+            .expect("search should succeed");
+
+        assert_eq!(page.items.len(), 1);
+        let hit = &page.items[0];
+        assert_eq!(hit.metadata.id, linker_id);
+        assert_eq!(hit.turn_id.as_deref(), Some("turn-2"));
+        assert_eq!(hit.snippet, "error: linking with `cc` failed");
+        assert_eq!(page.next_anchor, None);
+    }
+
+    #[tokio::test]
+    async fn replace_and_delete_drop_stale_content() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("state db should initialize");
+        let thread_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000203").expect("valid thread id");
+        runtime
+            .upsert_thread(&test_thread_metadata(
+                &codex_home,
+                thread_id,
+                codex_home.clone(),
+            ))
+            .await
+            .expect("thread insert should succeed");
+        runtime
+            .append_thread_content(thread_id, &[entry("turn-1", "flaky websocket test")])
+            .await
+            .expect("append should succeed");
+
+        runtime
+            .replace_thread_content(thread_id, &[entry("turn-1", "stable websocket test")])
+            .await
+            .expect("replace should succeed");
+        let page = runtime
+            .search_thread_content("flaky", /*page_size*/ 10, filters())
+            .await
+            .expect("search should succeed");
+        assert_eq!(page.items, Vec::new());
+
+        runtime
+            .delete_thread(thread_id)
+            .await
+            .expect("delete should succeed");
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM thread_content_fts")
+            .fetch_one(runtime.pool.as_ref())
+            .await
+            .expect("count should succeed");
+        assert_eq!(remaining, 0);
+    }
+}
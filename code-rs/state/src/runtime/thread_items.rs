@@ -0,0 +1,637 @@
+use super::thread_content::insert_thread_content;
+use super::threads::rollout_updated_at;
+use super::*;
+use crate::SortDirection;
+use crate::ThreadContentEntry;
+use crate::ThreadItemRecord;
+use crate::ThreadTurn;
+use crate::ThreadTurnStatus;
+use codex_protocol::protocol::EventMsg;
+
+impl StateRuntime {
+    /// Append rollout items to a thread's stored history.
+    ///
+    /// Items are numbered after the ones already stored for the thread and
+    /// attributed to the active turn; turn lifecycle events among them update
+    /// the thread's turns. The batch is written in a single transaction, so a
+    /// crash never leaves it partially applied.
+    pub async fn append_thread_items(
+        &self,
+        thread_id: ThreadId,
+        items: &[RolloutItem],
+    ) -> anyhow::Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let mut tx = self.pool.begin().await?;
+        insert_thread_items(&mut tx, thread_id, items).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Apply rollout items to a thread's metadata, append them to its stored
+    /// history and index `content` for search, all in one transaction.
+    pub async fn append_thread_rollout_items(
+        &self,
+        builder: &ThreadMetadataBuilder,
+        items: &[RolloutItem],
+        content: &[ThreadContentEntry],
+        new_thread_memory_mode: Option<&str>,
+        updated_at_override: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+        let updated_at = rollout_updated_at(builder, updated_at_override).await;
+        let mut tx = self.pool.begin().await?;
+        self.apply_rollout_items_with_connection(
+            &mut tx,
+            builder,
+            items,
+            new_thread_memory_mode,
+            updated_at,
+        )
+        .await?;
+        insert_thread_items(&mut tx, builder.id, items).await?;
+        insert_thread_content(&mut tx, builder.id, content).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Replace a thread's stored history, e.g. when importing a rollout file.
+    pub async fn replace_thread_items(
+        &self,
+        thread_id: ThreadId,
+        items: &[RolloutItem],
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM thread_items WHERE thread_id = ?")
+            .bind(thread_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM thread_turns WHERE thread_id = ?")
+            .bind(thread_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        insert_thread_items(&mut tx, thread_id, items).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Load every stored item for a thread, in append order.
+    pub async fn thread_items(&self, thread_id: ThreadId) -> anyhow::Result<Vec<RolloutItem>> {
+        let rows =
+            sqlx::query("SELECT item FROM thread_items WHERE thread_id = ? ORDER BY seq ASC")
+                .bind(thread_id.to_string())
+                .fetch_all(self.pool.as_ref())
+                .await?;
+        rows.into_iter()
+            .map(|row| {
+                let item: String = row.try_get("item")?;
+                Ok(serde_json::from_str(&item)?)
+            })
+            .collect()
+    }
+
+    /// List up to `limit` stored items for a thread, optionally restricted to
+    /// a single turn.
+    ///
+    /// `after_seq` is an exclusive keyset anchor interpreted in
+    /// `sort_direction` order.
+    pub async fn list_thread_items(
+        &self,
+        thread_id: ThreadId,
+        turn_id: Option<&str>,
+        after_seq: Option<i64>,
+        limit: usize,
+        sort_direction: SortDirection,
+    ) -> anyhow::Result<Vec<ThreadItemRecord>> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            "SELECT seq, turn_id, item FROM thread_items WHERE thread_id = ",
+        );
+        builder.push_bind(thread_id.to_string());
+        if let Some(turn_id) = turn_id {
+            builder.push(" AND turn_id = ");
+            builder.push_bind(turn_id.to_string());
+        }
+        push_keyset_page(&mut builder, "seq", after_seq, limit, sort_direction);
+
+        let rows = builder.build().fetch_all(self.pool.as_ref()).await?;
+        rows.into_iter()
+            .map(|row| {
+                let item: String = row.try_get("item")?;
+                Ok(ThreadItemRecord {
+                    seq: row.try_get("seq")?,
+                    turn_id: row.try_get("turn_id")?,
+                    item: serde_json::from_str(&item)?,
+                })
+            })
+            .collect()
+    }
+
+    /// List up to `limit` turns of a thread.
+    ///
+    /// `after_position` is an exclusive keyset anchor interpreted in
+    /// `sort_direction` order.
+    pub async fn list_thread_turns(
+        &self,
+        thread_id: ThreadId,
+        after_position: Option<i64>,
+        limit: usize,
+        sort_direction: SortDirection,
+    ) -> anyhow::Result<Vec<ThreadTurn>> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+SELECT
+    turn_id,
+    position,
+    status,
+    error_message,
+    started_at,
+    completed_at,
+    duration_ms
+FROM thread_turns
+WHERE thread_id = "#,
+        );
+        builder.push_bind(thread_id.to_string());
+        push_keyset_page(
+            &mut builder,
+            "position",
+            after_position,
+            limit,
+            sort_direction,
+        );
+
+        let rows = builder.build().fetch_all(self.pool.as_ref()).await?;
+        rows.into_iter()
+            .map(|row| {
+                let status: String = row.try_get("status")?;
+                Ok(ThreadTurn {
+                    turn_id: row.try_get("turn_id")?,
+                    position: row.try_get("position")?,
+                    status: ThreadTurnStatus::parse(&status)?,
+                    error_message: row.try_get("error_message")?,
+                    started_at: row.try_get("started_at")?,
+                    completed_at: row.try_get("completed_at")?,
+                    duration_ms: row.try_get("duration_ms")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Checkpoint the write-ahead log into the main database file.
+    ///
+    /// Commits are already durable across process crashes; this also makes
+    /// them survive an OS crash or power loss.
+    pub async fn checkpoint(&self) -> anyhow::Result<()> {
+        sqlx::query("PRAGMA wal_checkpoint(FULL)")
+            .execute(self.pool.as_ref())
+            .await?;
+        Ok(())
+    }
+}
+
+async fn insert_thread_items(
+    tx: &mut SqliteConnection,
+    thread_id: ThreadId,
+    items: &[RolloutItem],
+) -> anyhow::Result<()> {
+    let thread_id = thread_id.to_string();
+    let last_item: Option<(i64, Option<String>)> = sqlx::query_as(
+        "SELECT seq, turn_id FROM thread_items WHERE thread_id = ? ORDER BY seq DESC LIMIT 1",
+    )
+    .bind(thread_id.as_str())
+    .fetch_optional(&mut *tx)
+    .await?;
+    let (mut seq, mut turn_id) = match last_item {
+        Some((seq, turn_id)) => (seq + 1, turn_id),
+        None => (0, None),
+    };
+    for item in items {
+        match item {
+            RolloutItem::TurnContext(context) if context.turn_id.is_some() => {
+                turn_id = context.turn_id.clone();
+            }
+            RolloutItem::EventMsg(EventMsg::TurnStarted(event)) => {
+                turn_id = Some(event.turn_id.clone());
+            }
+            _ => {}
+        }
+        if let Some(turn_id) = turn_id.as_deref() {
+            ensure_thread_turn(tx, thread_id.as_str(), turn_id).await?;
+        }
+        if let RolloutItem::EventMsg(event) = item {
+            apply_turn_event(tx, thread_id.as_str(), turn_id.as_deref(), event).await?;
+        }
+        sqlx::query("INSERT INTO thread_items (thread_id, seq, turn_id, item) VALUES (?, ?, ?, ?)")
+            .bind(thread_id.as_str())
+            .bind(seq)
+            .bind(turn_id.as_deref())
+            .bind(serde_json::to_string(item)?)
+            .execute(&mut *tx)
+            .await?;
+        seq += 1;
+    }
+    Ok(())
+}
+
+async fn ensure_thread_turn(
+    tx: &mut SqliteConnection,
+    thread_id: &str,
+    turn_id: &str,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+INSERT INTO thread_turns (thread_id, turn_id, position, status)
+SELECT ?, ?, COALESCE(MAX(position) + 1, 0), ?
+FROM thread_turns
+WHERE thread_id = ?
+ON CONFLICT(thread_id, turn_id) DO NOTHING
+        "#,
+    )
+    .bind(thread_id)
+    .bind(turn_id)
+    .bind(ThreadTurnStatus::InProgress.as_str())
+    .bind(thread_id)
+    .execute(&mut *tx)
+    .await?;
+    Ok(())
+}
+
+async fn apply_turn_event(
+    tx: &mut SqliteConnection,
+    thread_id: &str,
+    active_turn_id: Option<&str>,
+    event: &EventMsg,
+) -> anyhow::Result<()> {
+    match event {
+        EventMsg::TurnStarted(event) => {
+            sqlx::query(
+                r#"
+UPDATE thread_turns
+SET status = ?, started_at = COALESCE(?, started_at)
+WHERE thread_id = ? AND turn_id = ?
+                "#,
+            )
+            .bind(ThreadTurnStatus::InProgress.as_str())
+            .bind(event.started_at)
+            .bind(thread_id)
+            .bind(event.turn_id.as_str())
+            .execute(&mut *tx)
+            .await?;
+        }
+        EventMsg::TurnComplete(event) => {
+            ensure_thread_turn(tx, thread_id, event.turn_id.as_str()).await?;
+            // A turn that hit an error still completes; keep it marked failed.
+            sqlx::query(
+                r#"
+UPDATE thread_turns
+SET
+    status = CASE WHEN status = ? THEN status ELSE ? END,
+    completed_at = ?,
+    duration_ms = ?
+WHERE thread_id = ? AND turn_id = ?
+                "#,
+            )
+            .bind(ThreadTurnStatus::Failed.as_str())
+            .bind(ThreadTurnStatus::Completed.as_str())
+            .bind(event.completed_at)
+            .bind(event.duration_ms)
+            .bind(thread_id)
+            .bind(event.turn_id.as_str())
+            .execute(&mut *tx)
+            .await?;
+        }
+        EventMsg::TurnAborted(event) => {
+            let Some(turn_id) = event.turn_id.as_deref().or(active_turn_id) else {
+                return Ok(());
+            };
+            ensure_thread_turn(tx, thread_id, turn_id).await?;
+            sqlx::query(
+                r#"
+UPDATE thread_turns
+SET status = ?, completed_at = ?, duration_ms = ?
+WHERE thread_id = ? AND turn_id = ?
+                "#,
+            )
+            .bind(ThreadTurnStatus::Interrupted.as_str())
+            .bind(event.completed_at)
+            .bind(event.duration_ms)
+            .bind(thread_id)
+            .bind(turn_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        EventMsg::Error(event) if event.affects_turn_status() => {
+            let Some(turn_id) = active_turn_id else {
+                return Ok(());
+            };
+            sqlx::query(
+                r#"
+UPDATE thread_turns
+SET status = ?, error_message = ?
+WHERE thread_id = ? AND turn_id = ?
+                "#,
+            )
+            .bind(ThreadTurnStatus::Failed.as_str())
+            .bind(event.message.as_str())
+            .bind(thread_id)
+            .bind(turn_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn push_keyset_page(
+    builder: &mut QueryBuilder<'_, Sqlite>,
+    column: &'static str,
+    after: Option<i64>,
+    limit: usize,
+    sort_direction: SortDirection,
+) {
+    let (comparison, order_direction) = match sort_direction {
+        SortDirection::Asc => (" > ", "ASC"),
+        SortDirection::Desc => (" < ", "DESC"),
+    };
+    if let Some(after) = after {
+        builder.push(" AND ");
+        builder.push(column);
+        builder.push(comparison);
+        builder.push_bind(after);
+    }
+    builder.push(" ORDER BY ");
+    builder.push(column);
+    builder.push(" ");
+    builder.push(order_direction);
+    builder.push(" LIMIT ");
+    builder.push_bind(i64::try_from(limit).unwrap_or(i64::MAX));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::test_support::test_thread_metadata;
+    use crate::runtime::test_support::unique_temp_dir;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::ErrorEvent;
+    use codex_protocol::protocol::SessionSource;
+    use codex_protocol::protocol::TurnCompleteEvent;
+    use codex_protocol::protocol::TurnStartedEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    fn turn_started(turn_id: &str, started_at: i64) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::TurnStarted(TurnStartedEvent {
+            turn_id: turn_id.to_string(),
+            started_at: Some(started_at),
+            model_context_window: None,
+            collaboration_mode_kind: Default::default(),
+        }))
+    }
+
+    fn turn_complete(turn_id: &str, completed_at: i64) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
+            turn_id: turn_id.to_string(),
+            last_agent_message: None,
+            completed_at: Some(completed_at),
+            duration_ms: Some(1_000),
+            time_to_first_token_ms: None,
+        }))
+    }
+
+    fn user_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: message.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    fn agent_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+            message: message.to_string(),
+            phase: None,
+            memory_citation: None,
+        }))
+    }
+
+    async fn runtime_with_thread(thread_id: ThreadId) -> Arc<StateRuntime> {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("state db should initialize");
+        runtime
+            .upsert_thread(&test_thread_metadata(
+                &codex_home,
+                thread_id,
+                codex_home.clone(),
+            ))
+            .await
+            .expect("thread insert should succeed");
+        runtime
+    }
+
+    #[tokio::test]
+    async fn appended_items_are_attributed_to_turns_across_batches() {
+        let thread_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000301").expect("valid thread id");
+        let runtime = runtime_with_thread(thread_id).await;
+
+        runtime
+            .append_thread_items(
+                thread_id,
+                &[
+                    turn_started("turn-1", 10),
+                    user_message("first"),
+                    agent_message("first reply"),
+                    turn_complete("turn-1", 11),
+                    turn_started("turn-2", 20),
+                ],
+            )
+            .await
+            .expect("append should succeed");
+        runtime
+            .append_thread_items(
+                thread_id,
+                &[
+                    user_message("second"),
+                    RolloutItem::EventMsg(EventMsg::Error(ErrorEvent {
+                        message: "stream disconnected".to_string(),
+                        codex_error_info: None,
+                    })),
+                    turn_complete("turn-2", 21),
+                ],
+            )
+            .await
+            .expect("append should succeed");
+
+        let turns = runtime
+            .list_thread_turns(thread_id, None, /*limit*/ 10, SortDirection::Asc)
+            .await
+            .expect("list turns should succeed");
+        assert_eq!(
+            turns,
+            vec![
+                ThreadTurn {
+                    turn_id: "turn-1".to_string(),
+                    position: 0,
+                    status: ThreadTurnStatus::Completed,
+                    error_message: None,
+                    started_at: Some(10),
+                    completed_at: Some(11),
+                    duration_ms: Some(1_000),
+                },
+                ThreadTurn {
+                    turn_id: "turn-2".to_string(),
+                    position: 1,
+                    status: ThreadTurnStatus::Failed,
+                    error_message: Some("stream disconnected".to_string()),
+                    started_at: Some(20),
+                    completed_at: Some(21),
+                    duration_ms: Some(1_000),
+                },
+            ]
+        );
+
+        let second_turn = runtime
+            .list_thread_items(
+                thread_id,
+                Some("turn-2"),
+                None,
+                /*limit*/ 10,
+                SortDirection::Asc,
+            )
+            .await
+            .expect("list items should succeed");
+        assert_eq!(
+            second_turn
+                .iter()
+                .map(|record| record.seq)
+                .collect::<Vec<_>>(),
+            vec![4, 5, 6, 7]
+        );
+
+        let newest = runtime
+            .list_thread_items(
+                thread_id,
+                None,
+                Some(7),
+                /*limit*/ 2,
+                SortDirection::Desc,
+            )
+            .await
+            .expect("list items should succeed");
+        assert_eq!(
+            newest.iter().map(|record| record.seq).collect::<Vec<_>>(),
+            vec![6, 5]
+        );
+        assert_eq!(
+            runtime
+                .thread_items(thread_id)
+                .await
+                .expect("load items should succeed")
+                .len(),
+            8
+        );
+    }
+
+    #[tokio::test]
+    async fn rollout_items_create_the_thread_with_its_items_and_content() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("state db should initialize");
+        let thread_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000303").expect("valid thread id");
+        let builder = ThreadMetadataBuilder::new(
+            thread_id,
+            codex_home.join("rollout.jsonl"),
+            Utc::now(),
+            SessionSource::Cli,
+        );
+
+        runtime
+            .append_thread_rollout_items(
+                &builder,
+                &[turn_started("turn-1", 10), user_message("hello")],
+                &[ThreadContentEntry {
+                    turn_id: Some("turn-1".to_string()),
+                    content: "hello".to_string(),
+                }],
+                Some("disabled"),
+                Some(Utc::now()),
+            )
+            .await
+            .expect("append should succeed");
+
+        assert_eq!(
+            runtime
+                .get_thread_memory_mode(thread_id)
+                .await
+                .expect("memory mode lookup should succeed"),
+            Some("disabled".to_string())
+        );
+        assert_eq!(
+            runtime
+                .thread_items(thread_id)
+                .await
+                .expect("load items should succeed")
+                .len(),
+            2
+        );
+        let indexed: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM thread_content_fts WHERE thread_id = ?")
+                .bind(thread_id.to_string())
+                .fetch_one(runtime.pool.as_ref())
+                .await
+                .expect("count should succeed");
+        assert_eq!(indexed, 1);
+    }
+
+    #[tokio::test]
+    async fn replace_and_delete_drop_stored_items() {
+        let thread_id =
+            ThreadId::from_string("00000000-0000-0000-0000-000000000302").expect("valid thread id");
+        let runtime = runtime_with_thread(thread_id).await;
+        runtime
+            .append_thread_items(
+                thread_id,
+                &[turn_started("turn-1", 10), user_message("stale")],
+            )
+            .await
+            .expect("append should succeed");
+
+        runtime
+            .replace_thread_items(
+                thread_id,
+                &[turn_started("turn-9", 90), user_message("fresh")],
+            )
+            .await
+            .expect("replace should succeed");
+        let turns = runtime
+            .list_thread_turns(thread_id, None, /*limit*/ 10, SortDirection::Asc)
+            .await
+            .expect("list turns should succeed");
+        assert_eq!(
+            turns
+                .iter()
+                .map(|turn| (turn.turn_id.as_str(), turn.position))
+                .collect::<Vec<_>>(),
+            vec![("turn-9", 0)]
+        );
+
+        runtime
+            .delete_thread(thread_id)
+            .await
+            .expect("delete should succeed");
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM thread_items")
+            .fetch_one(runtime.pool.as_ref())
+            .await
+            .expect("count should succeed");
+        assert_eq!(remaining, 0);
+    }
+}
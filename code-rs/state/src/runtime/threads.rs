@@ -1,45 +1,12 @@
 use super::*;
 use crate::SortDirection;
 use codex_protocol::protocol::SessionSource;
+use sqlx::Executor;
 use std::sync::atomic::Ordering;
 
 impl StateRuntime {
     pub async fn get_thread(&self, id: ThreadId) -> anyhow::Result<Option<crate::ThreadMetadata>> {
-        let row = sqlx::query(
-            r#"
-SELECT
-    threads.id,
-    threads.rollout_path,
-    threads.created_at_ms AS created_at,
-    threads.updated_at_ms AS updated_at,
-    threads.source,
-    threads.thread_source,
-    threads.agent_nickname,
-    threads.agent_role,
-    threads.agent_path,
-    threads.model_provider,
-    threads.model,
-    threads.reasoning_effort,
-    threads.cwd,
-    threads.cli_version,
-    threads.title,
-    threads.sandbox_policy,
-    threads.approval_mode,
-    threads.tokens_used,
-    threads.first_user_message,
-    threads.archived_at,
-    threads.git_sha,
-    threads.git_branch,
-    threads.git_origin_url
-FROM threads
-WHERE threads.id = ?
-            "#,
-        )
-        .bind(id.to_string())
-        .fetch_optional(self.pool.as_ref())
-        .await?;
-        row.map(|row| ThreadRow::try_from_row(&row).and_then(ThreadMetadata::try_from))
-            .transpose()
+        get_thread_with_executor(self.pool.as_ref(), id).await
     }
 
     pub async fn get_thread_memory_mode(&self, id: ThreadId) -> anyhow::Result<Option<String>> {
@@ -291,41 +258,6 @@ ORDER BY depth ASC, child_thread_id ASC
             .collect()
     }
 
-    async fn insert_thread_spawn_edge_if_absent(
-        &self,
-        parent_thread_id: ThreadId,
-        child_thread_id: ThreadId,
-    ) -> anyhow::Result<()> {
-        sqlx::query(
-            r#"
-INSERT INTO thread_spawn_edges (
-    parent_thread_id,
-    child_thread_id,
-    status
-) VALUES (?, ?, ?)
-ON CONFLICT(child_thread_id) DO NOTHING
-            "#,
-        )
-        .bind(parent_thread_id.to_string())
-        .bind(child_thread_id.to_string())
-        .bind(crate::DirectionalThreadSpawnEdgeStatus::Open.as_ref())
-        .execute(self.pool.as_ref())
-        .await?;
-        Ok(())
-    }
-
-    async fn insert_thread_spawn_edge_from_source_if_absent(
-        &self,
-        child_thread_id: ThreadId,
-        source: &str,
-    ) -> anyhow::Result<()> {
-        let Some(parent_thread_id) = thread_spawn_parent_thread_id_from_source_str(source) else {
-            return Ok(());
-        };
-        self.insert_thread_spawn_edge_if_absent(parent_thread_id, child_thread_id)
-            .await
-    }
-
     /// Find a rollout path by thread id using the underlying database.
     pub async fn find_rollout_path_by_id(
         &self,
@@ -549,8 +481,12 @@ ON CONFLICT(id) DO NOTHING
         .bind("enabled")
         .execute(self.pool.as_ref())
         .await?;
-        self.insert_thread_spawn_edge_from_source_if_absent(metadata.id, metadata.source.as_str())
-            .await?;
+        insert_thread_spawn_edge_from_source_if_absent(
+            self.pool.as_ref(),
+            metadata.id,
+            metadata.source.as_str(),
+        )
+        .await?;
         Ok(result.rows_affected() > 0)
     }
 
@@ -559,12 +495,7 @@ ON CONFLICT(id) DO NOTHING
         thread_id: ThreadId,
         memory_mode: &str,
     ) -> anyhow::Result<bool> {
-        let result = sqlx::query("UPDATE threads SET memory_mode = ? WHERE id = ?")
-            .bind(memory_mode)
-            .bind(thread_id.to_string())
-            .execute(self.pool.as_ref())
-            .await?;
-        Ok(result.rows_affected() > 0)
+        set_thread_memory_mode_with_executor(self.pool.as_ref(), thread_id, memory_mode).await
     }
 
     pub async fn update_thread_title(
@@ -675,6 +606,17 @@ WHERE id = ?
         &self,
         metadata: &crate::ThreadMetadata,
         creation_memory_mode: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        self.upsert_thread_with_connection(&mut conn, metadata, creation_memory_mode)
+            .await
+    }
+
+    async fn upsert_thread_with_connection(
+        &self,
+        conn: &mut SqliteConnection,
+        metadata: &crate::ThreadMetadata,
+        creation_memory_mode: Option<&str>,
     ) -> anyhow::Result<()> {
         let updated_at = self.allocate_thread_updated_at(metadata.updated_at)?;
         // Backfill/reconcile callers merge existing git info before upserting, but that
@@ -775,10 +717,14 @@ ON CONFLICT(id) DO UPDATE SET
         .bind(metadata.git_branch.as_deref())
         .bind(metadata.git_origin_url.as_deref())
         .bind(creation_memory_mode.unwrap_or("enabled"))
-        .execute(self.pool.as_ref())
+        .execute(&mut *conn)
+        .await?;
+        insert_thread_spawn_edge_from_source_if_absent(
+            &mut *conn,
+            metadata.id,
+            metadata.source.as_str(),
+        )
         .await?;
-        self.insert_thread_spawn_edge_from_source_if_absent(metadata.id, metadata.source.as_str())
-            .await?;
         Ok(())
     }
 
@@ -797,40 +743,16 @@ ON CONFLICT(id) DO UPDATE SET
         if tools.is_empty() {
             return Ok(());
         }
-        let thread_id = thread_id.to_string();
         let mut tx = self.pool.begin().await?;
-        for (idx, tool) in tools.iter().enumerate() {
-            let position = i64::try_from(idx).unwrap_or(i64::MAX);
-            let input_schema = serde_json::to_string(&tool.input_schema)?;
-            sqlx::query(
-                r#"
-INSERT INTO thread_dynamic_tools (
-    thread_id,
-    position,
-    namespace,
-    name,
-    description,
-    input_schema,
-    defer_loading
-) VALUES (?, ?, ?, ?, ?, ?, ?)
-ON CONFLICT(thread_id, position) DO NOTHING
-                "#,
-            )
-            .bind(thread_id.as_str())
-            .bind(position)
-            .bind(tool.namespace.as_deref())
-            .bind(tool.name.as_str())
-            .bind(tool.description.as_str())
-            .bind(input_schema)
-            .bind(tool.defer_loading)
-            .execute(&mut *tx)
-            .await?;
-        }
+        insert_dynamic_tools(&mut tx, thread_id, tools).await?;
         tx.commit().await?;
         Ok(())
     }
 
     /// Apply rollout items incrementally using the underlying database.
+    ///
+    /// The thread row, memory mode and dynamic tools are updated in a single
+    /// transaction.
     pub async fn apply_rollout_items(
         &self,
         builder: &ThreadMetadataBuilder,
@@ -841,7 +763,29 @@ ON CONFLICT(thread_id, position) DO NOTHING
         if items.is_empty() {
             return Ok(());
         }
-        let existing_metadata = self.get_thread(builder.id).await?;
+        let updated_at = rollout_updated_at(builder, updated_at_override).await;
+        let mut tx = self.pool.begin().await?;
+        self.apply_rollout_items_with_connection(
+            &mut tx,
+            builder,
+            items,
+            new_thread_memory_mode,
+            updated_at,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub(super) async fn apply_rollout_items_with_connection(
+        &self,
+        conn: &mut SqliteConnection,
+        builder: &ThreadMetadataBuilder,
+        items: &[RolloutItem],
+        new_thread_memory_mode: Option<&str>,
+        updated_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let existing_metadata = get_thread_with_executor(&mut *conn, builder.id).await?;
         let mut metadata = existing_metadata
             .clone()
             .unwrap_or_else(|| builder.build(&self.default_provider));
@@ -852,36 +796,26 @@ ON CONFLICT(thread_id, position) DO NOTHING
         if let Some(existing_metadata) = existing_metadata.as_ref() {
             metadata.prefer_existing_git_info(existing_metadata);
         }
-        let updated_at = match updated_at_override {
-            Some(updated_at) => Some(updated_at),
-            None => file_modified_time_utc(builder.rollout_path.as_path()).await,
-        };
         if let Some(updated_at) = updated_at {
             metadata.updated_at = updated_at;
         }
         // Keep the thread upsert before dynamic tools to satisfy the foreign key constraint:
         // thread_dynamic_tools.thread_id -> threads.id.
-        let upsert_result = if existing_metadata.is_none() {
-            self.upsert_thread_with_creation_memory_mode(&metadata, new_thread_memory_mode)
-                .await
+        let creation_memory_mode = if existing_metadata.is_none() {
+            new_thread_memory_mode
         } else {
-            self.upsert_thread(&metadata).await
+            None
         };
-        upsert_result?;
-        if let Some(memory_mode) = extract_memory_mode(items)
-            && let Err(err) = self
-                .set_thread_memory_mode(builder.id, memory_mode.as_str())
-                .await
-        {
-            return Err(err);
+        self.upsert_thread_with_connection(&mut *conn, &metadata, creation_memory_mode)
+            .await?;
+        if let Some(memory_mode) = extract_memory_mode(items) {
+            set_thread_memory_mode_with_executor(&mut *conn, builder.id, memory_mode.as_str())
+                .await?;
         }
-        let dynamic_tools = extract_dynamic_tools(items);
-        if let Some(dynamic_tools) = dynamic_tools
-            && let Err(err) = self
-                .persist_dynamic_tools(builder.id, dynamic_tools.as_deref())
-                .await
+        if let Some(Some(dynamic_tools)) = extract_dynamic_tools(items)
+            && !dynamic_tools.is_empty()
         {
-            return Err(err);
+            insert_dynamic_tools(&mut *conn, builder.id, &dynamic_tools).await?;
         }
         Ok(())
     }
@@ -943,6 +877,143 @@ ON CONFLICT(thread_id, position) DO NOTHING
     }
 }
 
+async fn get_thread_with_executor<'e, E>(
+    executor: E,
+    id: ThreadId,
+) -> anyhow::Result<Option<crate::ThreadMetadata>>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let row = sqlx::query(
+        r#"
+SELECT
+    threads.id,
+    threads.rollout_path,
+    threads.created_at_ms AS created_at,
+    threads.updated_at_ms AS updated_at,
+    threads.source,
+    threads.thread_source,
+    threads.agent_nickname,
+    threads.agent_role,
+    threads.agent_path,
+    threads.model_provider,
+    threads.model,
+    threads.reasoning_effort,
+    threads.cwd,
+    threads.cli_version,
+    threads.title,
+    threads.sandbox_policy,
+    threads.approval_mode,
+    threads.tokens_used,
+    threads.first_user_message,
+    threads.archived_at,
+    threads.git_sha,
+    threads.git_branch,
+    threads.git_origin_url
+FROM threads
+WHERE threads.id = ?
+        "#,
+    )
+    .bind(id.to_string())
+    .fetch_optional(executor)
+    .await?;
+    row.map(|row| ThreadRow::try_from_row(&row).and_then(ThreadMetadata::try_from))
+        .transpose()
+}
+
+async fn insert_thread_spawn_edge_from_source_if_absent<'e, E>(
+    executor: E,
+    child_thread_id: ThreadId,
+    source: &str,
+) -> anyhow::Result<()>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let Some(parent_thread_id) = thread_spawn_parent_thread_id_from_source_str(source) else {
+        return Ok(());
+    };
+    sqlx::query(
+        r#"
+INSERT INTO thread_spawn_edges (
+    parent_thread_id,
+    child_thread_id,
+    status
+) VALUES (?, ?, ?)
+ON CONFLICT(child_thread_id) DO NOTHING
+        "#,
+    )
+    .bind(parent_thread_id.to_string())
+    .bind(child_thread_id.to_string())
+    .bind(crate::DirectionalThreadSpawnEdgeStatus::Open.as_ref())
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+async fn set_thread_memory_mode_with_executor<'e, E>(
+    executor: E,
+    thread_id: ThreadId,
+    memory_mode: &str,
+) -> anyhow::Result<bool>
+where
+    E: Executor<'e, Database = Sqlite>,
+{
+    let result = sqlx::query("UPDATE threads SET memory_mode = ? WHERE id = ?")
+        .bind(memory_mode)
+        .bind(thread_id.to_string())
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+async fn insert_dynamic_tools(
+    tx: &mut SqliteConnection,
+    thread_id: ThreadId,
+    tools: &[DynamicToolSpec],
+) -> anyhow::Result<()> {
+    let thread_id = thread_id.to_string();
+    for (idx, tool) in tools.iter().enumerate() {
+        let position = i64::try_from(idx).unwrap_or(i64::MAX);
+        let input_schema = serde_json::to_string(&tool.input_schema)?;
+        sqlx::query(
+            r#"
+INSERT INTO thread_dynamic_tools (
+    thread_id,
+    position,
+    namespace,
+    name,
+    description,
+    input_schema,
+    defer_loading
+) VALUES (?, ?, ?, ?, ?, ?, ?)
+ON CONFLICT(thread_id, position) DO NOTHING
+            "#,
+        )
+        .bind(thread_id.as_str())
+        .bind(position)
+        .bind(tool.namespace.as_deref())
+        .bind(tool.name.as_str())
+        .bind(tool.description.as_str())
+        .bind(input_schema)
+        .bind(tool.defer_loading)
+        .execute(&mut *tx)
+        .await?;
+    }
+    Ok(())
+}
+
+/// The `updated_at` to record for rollout items: the override when given,
+/// otherwise the rollout file's modification time.
+pub(super) async fn rollout_updated_at(
+    builder: &ThreadMetadataBuilder,
+    updated_at_override: Option<DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    match updated_at_override {
+        Some(updated_at) => Some(updated_at),
+        None => file_modified_time_utc(builder.rollout_path.as_path()).await,
+    }
+}
+
 fn one_thread_id_from_rows(
     rows: Vec<sqlx::sqlite::SqliteRow>,
     agent_path: &str,
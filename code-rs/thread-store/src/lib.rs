@@ -1,13 +1,14 @@
 //! Storage-neutral thread persistence interfaces.
 //!
 //! Application code should treat [`codex_protocol::ThreadId`] as the only durable thread handle.
-//! Implementations are responsible for resolving that id to local rollout files, RPC requests, or
-//! any other backing store.
+//! Implementations are responsible for resolving that id to local rollout files, SQLite rows, RPC
+//! requests, or any other backing store.
 
 mod error;
 mod in_memory;
 mod live_thread;
 mod local;
+mod sqlite;
 mod store;
 mod types;
 
@@ -19,6 +20,8 @@ pub use live_thread::LiveThread;
 pub use live_thread::LiveThreadInitGuard;
 pub use local::LocalThreadStore;
 pub use local::LocalThreadStoreConfig;
+pub use sqlite::RolloutImportStats;
+pub use sqlite::SqliteThreadStore;
 pub use store::ThreadStore;
 pub use types::AppendThreadItemsParams;
 pub use types::ArchiveThreadParams;
@@ -44,6 +47,8 @@ pub use types::ThreadEventPersistenceMode;
 pub use types::ThreadMetadataPatch;
 pub use types::ThreadPage;
 pub use types::ThreadPersistenceMetadata;
+pub use types::ThreadSearchHit;
+pub use types::ThreadSearchPage;
 pub use types::ThreadSortKey;
 pub use types::TurnPage;
 pub use types::UpdateThreadMetadataParams;
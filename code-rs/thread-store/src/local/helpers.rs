@@ -143,7 +143,7 @@ pub(super) fn stored_thread_from_rollout_item(
     })
 }
 
-pub(super) fn distinct_thread_metadata_title(metadata: &ThreadMetadata) -> Option<String> {
+pub(crate) fn distinct_thread_metadata_title(metadata: &ThreadMetadata) -> Option<String> {
     let title = metadata.title.trim();
     if title.is_empty() || metadata.first_user_message.as_deref().map(str::trim) == Some(title) {
         None
@@ -159,13 +159,28 @@ pub(super) fn set_thread_name_from_title(thread: &mut StoredThread, title: Strin
     thread.name = Some(title);
 }
 
+pub(crate) fn parse_session_source(source: &str) -> SessionSource {
+    serde_json::from_str(source)
+        .or_else(|_| serde_json::from_value(serde_json::Value::String(source.to_string())))
+        .unwrap_or(SessionSource::Unknown)
+}
+
+pub(crate) fn parse_or_default<T>(value: &str, default: T) -> T
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_str(value)
+        .or_else(|_| serde_json::from_value(serde_json::Value::String(value.to_string())))
+        .unwrap_or(default)
+}
+
 fn parse_rfc3339(value: Option<&str>) -> Option<DateTime<Utc>> {
     DateTime::parse_from_rfc3339(value?)
         .ok()
         .map(|dt| dt.with_timezone(&Utc))
 }
 
-pub(super) fn git_info_from_parts(
+pub(crate) fn git_info_from_parts(
     sha: Option<String>,
     branch: Option<String>,
     origin_url: Option<String>,
@@ -1,14 +1,15 @@
 mod archive_thread;
 mod create_thread;
-mod helpers;
+pub(crate) mod helpers;
 mod list_threads;
 mod live_writer;
 mod read_thread;
+mod search_threads;
 mod unarchive_thread;
 mod update_thread_metadata;
 
 #[cfg(test)]
-mod test_support;
+pub(crate) mod test_support;
 
 use async_trait::async_trait;
 use codex_protocol::ThreadId;
@@ -31,6 +32,7 @@ use crate::ResumeThreadParams;
 use crate::StoredThread;
 use crate::StoredThreadHistory;
 use crate::ThreadPage;
+use crate::ThreadSearchPage;
 use crate::ThreadStore;
 use crate::ThreadStoreError;
 use crate::ThreadStoreResult;
@@ -249,6 +251,13 @@ impl ThreadStore for LocalThreadStore {
         list_threads::list_threads(self, params).await
     }
 
+    async fn search_threads(
+        &self,
+        params: ListThreadsParams,
+    ) -> ThreadStoreResult<ThreadSearchPage> {
+        search_threads::search_threads(self, params).await
+    }
+
     async fn update_thread_metadata(
         &self,
         params: UpdateThreadMetadataParams,
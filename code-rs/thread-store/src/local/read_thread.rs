@@ -3,7 +3,6 @@ use chrono::Utc;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::SandboxPolicy;
 use codex_protocol::protocol::SessionMetaLine;
-use codex_protocol::protocol::SessionSource;
 use codex_rollout::RolloutRecorder;
 use codex_rollout::find_archived_thread_path_by_id_str;
 use codex_rollout::find_thread_name_by_id;
@@ -15,6 +14,8 @@ use codex_state::ThreadMetadata;
 use super::LocalThreadStore;
 use super::helpers::distinct_thread_metadata_title;
 use super::helpers::git_info_from_parts;
+use super::helpers::parse_or_default;
+use super::helpers::parse_session_source;
 use super::helpers::rollout_path_is_archived;
 use super::helpers::set_thread_name_from_title;
 use super::helpers::stored_thread_from_rollout_item;
@@ -264,7 +265,7 @@ async fn read_sqlite_metadata(
     runtime.get_thread(thread_id).await.ok().flatten()
 }
 
-async fn stored_thread_from_sqlite_metadata(
+pub(super) async fn stored_thread_from_sqlite_metadata(
     store: &LocalThreadStore,
     metadata: ThreadMetadata,
 ) -> StoredThread {
@@ -378,21 +379,6 @@ fn stored_thread_from_meta_line(
     }
 }
 
-fn parse_session_source(source: &str) -> SessionSource {
-    serde_json::from_str(source)
-        .or_else(|_| serde_json::from_value(serde_json::Value::String(source.to_string())))
-        .unwrap_or(SessionSource::Unknown)
-}
-
-fn parse_or_default<T>(value: &str, default: T) -> T
-where
-    T: serde::de::DeserializeOwned,
-{
-    serde_json::from_str(value)
-        .or_else(|_| serde_json::from_value(serde_json::Value::String(value.to_string())))
-        .unwrap_or(default)
-}
-
 fn parse_rfc3339_non_optional(value: &str) -> Option<DateTime<Utc>> {
     DateTime::parse_from_rfc3339(value)
         .ok()
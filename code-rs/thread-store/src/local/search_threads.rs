@@ -0,0 +1,186 @@
+use codex_rollout::parse_cursor;
+
+use super::LocalThreadStore;
+use super::read_thread::stored_thread_from_sqlite_metadata;
+use crate::ListThreadsParams;
+use crate::SortDirection;
+use crate::ThreadSearchHit;
+use crate::ThreadSearchPage;
+use crate::ThreadSortKey;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+
+pub(super) async fn search_threads(
+    store: &LocalThreadStore,
+    params: ListThreadsParams,
+) -> ThreadStoreResult<ThreadSearchPage> {
+    let Some(query) = params.search_term.as_deref() else {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: "thread content search requires a search term".to_string(),
+        });
+    };
+    // Content is only indexed in the state DB; there is no rollout scan to
+    // fall back to.
+    let Some(state_db) = store.state_db().await else {
+        return Err(ThreadStoreError::Unsupported {
+            operation: "search_threads",
+        });
+    };
+    let cursor = params
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            parse_cursor(cursor).ok_or_else(|| ThreadStoreError::InvalidRequest {
+                message: format!("invalid cursor: {cursor}"),
+            })
+        })
+        .transpose()?;
+    let sort_key = match params.sort_key {
+        ThreadSortKey::CreatedAt => codex_rollout::ThreadSortKey::CreatedAt,
+        ThreadSortKey::UpdatedAt => codex_rollout::ThreadSortKey::UpdatedAt,
+    };
+    let sort_direction = match params.sort_direction {
+        SortDirection::Asc => codex_rollout::SortDirection::Asc,
+        SortDirection::Desc => codex_rollout::SortDirection::Desc,
+    };
+    let page = codex_rollout::state_db::search_thread_content_db(
+        Some(state_db.as_ref()),
+        query,
+        params.page_size,
+        cursor.as_ref(),
+        sort_key,
+        sort_direction,
+        params.allowed_sources.as_slice(),
+        params.model_providers.as_deref(),
+        params.cwd_filters.as_deref(),
+        params.archived,
+    )
+    .await
+    .ok_or_else(|| ThreadStoreError::Internal {
+        message: "failed to search thread content".to_string(),
+    })?;
+
+    let next_cursor = page
+        .next_anchor
+        .map(codex_rollout::Cursor::from)
+        .and_then(|cursor| serde_json::to_value(cursor).ok())
+        .and_then(|value| value.as_str().map(str::to_owned));
+    let mut items = Vec::with_capacity(page.items.len());
+    for hit in page.items {
+        items.push(ThreadSearchHit {
+            thread: stored_thread_from_sqlite_metadata(store, hit.metadata).await,
+            turn_id: hit.turn_id,
+            snippet: hit.snippet,
+        });
+    }
+    Ok(ThreadSearchPage { items, next_cursor })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::SessionSource;
+    use codex_state::ThreadContentEntry;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::ThreadStore;
+    use crate::local::test_support::test_config;
+
+    fn search_params(search_term: &str) -> ListThreadsParams {
+        ListThreadsParams {
+            page_size: 10,
+            cursor: None,
+            sort_key: ThreadSortKey::UpdatedAt,
+            sort_direction: SortDirection::Desc,
+            allowed_sources: Vec::new(),
+            model_providers: None,
+            cwd_filters: None,
+            archived: false,
+            search_term: Some(search_term.to_string()),
+            use_state_db_only: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn search_threads_matches_indexed_content() {
+        let home = TempDir::new().expect("temp dir");
+        let config = test_config(home.path());
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(301).to_string()).expect("valid thread id");
+        let rollout_path = home.path().join("rollout-content-search.jsonl");
+        fs::write(&rollout_path, "").expect("placeholder rollout file");
+
+        let runtime = codex_state::StateRuntime::init(
+            home.path().to_path_buf(),
+            config.default_model_provider_id.clone(),
+        )
+        .await
+        .expect("state db should initialize");
+        let store = LocalThreadStore::new(config.clone(), Some(runtime.clone()));
+        let mut builder = codex_state::ThreadMetadataBuilder::new(
+            thread_id,
+            rollout_path,
+            Utc::now(),
+            SessionSource::Cli,
+        );
+        builder.cwd = home.path().to_path_buf();
+        let mut metadata = builder.build(config.default_model_provider_id.as_str());
+        metadata.first_user_message = Some("plain preview".to_string());
+        runtime
+            .upsert_thread(&metadata)
+            .await
+            .expect("state db upsert should succeed");
+        runtime
+            .append_thread_content(
+                thread_id,
+                &[ThreadContentEntry {
+                    turn_id: Some("turn-7".to_string()),
+                    content: "undefined reference to `SSL_new`".to_string(),
+                }],
+            )
+            .await
+            .expect("content append should succeed");
+
+        let page = store
+            .search_threads(search_params("SSL_new"))
+            .await
+            .expect("thread search");
+
+        assert_eq!(page.items.len(), 1);
+        let hit = &page.items[0];
+        assert_eq!(hit.thread.thread_id, thread_id);
+        assert_eq!(hit.thread.preview, "plain preview");
+        assert_eq!(hit.turn_id.as_deref(), Some("turn-7"));
+        assert_eq!(hit.snippet, "undefined reference to `SSL_new`");
+        assert_eq!(page.next_cursor, None);
+
+        let page = store
+            .search_threads(search_params("openssl"))
+            .await
+            .expect("thread search");
+        assert_eq!(page.items.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn search_threads_requires_state_db() {
+        let home = TempDir::new().expect("temp dir");
+        let store = LocalThreadStore::new(test_config(home.path()), /*state_db*/ None);
+
+        let err = store
+            .search_threads(search_params("anything"))
+            .await
+            .expect_err("search without state db should fail");
+
+        assert!(matches!(
+            err,
+            ThreadStoreError::Unsupported {
+                operation: "search_threads"
+            }
+        ));
+    }
+}
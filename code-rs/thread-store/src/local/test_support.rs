@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use super::LocalThreadStoreConfig;
 
-pub(super) fn test_config(codex_home: &Path) -> LocalThreadStoreConfig {
+pub(crate) fn test_config(codex_home: &Path) -> LocalThreadStoreConfig {
     LocalThreadStoreConfig {
         codex_home: codex_home.to_path_buf(),
         sqlite_home: codex_home.to_path_buf(),
@@ -16,7 +16,7 @@ pub(super) fn test_config(codex_home: &Path) -> LocalThreadStoreConfig {
     }
 }
 
-pub(super) fn write_session_file(root: &Path, ts: &str, uuid: Uuid) -> std::io::Result<PathBuf> {
+pub(crate) fn write_session_file(root: &Path, ts: &str, uuid: Uuid) -> std::io::Result<PathBuf> {
     write_session_file_with(
         root,
         root.join("sessions/2025/01/03"),
@@ -27,7 +27,7 @@ pub(super) fn write_session_file(root: &Path, ts: &str, uuid: Uuid) -> std::io::
     )
 }
 
-pub(super) fn write_archived_session_file(
+pub(crate) fn write_archived_session_file(
     root: &Path,
     ts: &str,
     uuid: Uuid,
@@ -42,7 +42,7 @@ pub(super) fn write_archived_session_file(
     )
 }
 
-pub(super) fn write_session_file_with(
+pub(crate) fn write_session_file_with(
     root: &Path,
     day_dir: PathBuf,
     ts: &str,
@@ -61,7 +61,7 @@ pub(super) fn write_session_file_with(
     )
 }
 
-pub(super) fn write_session_file_with_fork(
+pub(crate) fn write_session_file_with_fork(
     root: &Path,
     day_dir: PathBuf,
     ts: &str,
@@ -0,0 +1,187 @@
+use std::path::Path;
+
+use chrono::DateTime;
+use chrono::Utc;
+use codex_rollout::ARCHIVED_SESSIONS_SUBDIR;
+use codex_rollout::RolloutRecorder;
+use codex_rollout::SESSIONS_SUBDIR;
+use codex_rollout::builder_from_items;
+use codex_rollout::collect_rollout_paths;
+use tracing::warn;
+
+use super::SqliteThreadStore;
+use super::internal_error;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+
+/// Outcome of importing rollout JSONL files into a [`SqliteThreadStore`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RolloutImportStats {
+    /// Rollouts imported into the store.
+    pub imported: usize,
+    /// Rollouts skipped because their thread is currently live in the store.
+    pub skipped: usize,
+    /// Rollouts that could not be read or imported.
+    pub failed: usize,
+}
+
+pub(super) async fn import_rollouts(
+    store: &SqliteThreadStore,
+    codex_home: &Path,
+) -> ThreadStoreResult<RolloutImportStats> {
+    let mut stats = RolloutImportStats::default();
+    for (subdir, archived) in [(SESSIONS_SUBDIR, false), (ARCHIVED_SESSIONS_SUBDIR, true)] {
+        let paths = collect_rollout_paths(codex_home.join(subdir).as_path())
+            .await
+            .map_err(|err| internal_error("failed to scan rollouts", err))?;
+        for path in paths {
+            match import_rollout(store, path.as_path(), archived).await {
+                Ok(true) => stats.imported += 1,
+                Ok(false) => stats.skipped += 1,
+                Err(err) => {
+                    warn!("failed to import rollout {}: {err}", path.display());
+                    stats.failed += 1;
+                }
+            }
+        }
+    }
+    Ok(stats)
+}
+
+/// Import one rollout file, returning `false` when its thread is live.
+async fn import_rollout(
+    store: &SqliteThreadStore,
+    path: &Path,
+    archived: bool,
+) -> ThreadStoreResult<bool> {
+    let (items, thread_id, _) = RolloutRecorder::load_rollout_items(path)
+        .await
+        .map_err(|err| internal_error("failed to read rollout", err))?;
+    let (Some(thread_id), Some(builder)) = (
+        thread_id,
+        builder_from_items(items.as_slice(), Path::new("")),
+    ) else {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: "rollout has no session metadata".to_string(),
+        });
+    };
+    if store.has_live_thread(thread_id).await {
+        return Ok(false);
+    }
+    let updated_at = tokio::fs::metadata(path)
+        .await
+        .and_then(|metadata| metadata.modified())
+        .map(DateTime::<Utc>::from)
+        .unwrap_or_else(|_| Utc::now());
+
+    let state_db = store.state_db().await?;
+    state_db
+        .apply_rollout_items(
+            &builder,
+            items.as_slice(),
+            /*new_thread_memory_mode*/ None,
+            Some(updated_at),
+        )
+        .await
+        .map_err(|err| internal_error("failed to import thread metadata", err))?;
+    let mut metadata = state_db
+        .get_thread(thread_id)
+        .await
+        .map_err(|err| internal_error("failed to read thread metadata", err))?
+        .ok_or(ThreadStoreError::ThreadNotFound { thread_id })?;
+    metadata.archived_at = archived.then_some(updated_at);
+    state_db
+        .upsert_thread(&metadata)
+        .await
+        .map_err(|err| internal_error("failed to import thread metadata", err))?;
+    state_db
+        .replace_thread_items(thread_id, items.as_slice())
+        .await
+        .map_err(|err| internal_error("failed to import thread items", err))?;
+    let entries = codex_state::extract_thread_content(items.as_slice(), &mut None);
+    state_db
+        .replace_thread_content(thread_id, &entries)
+        .await
+        .map_err(|err| internal_error("failed to index thread content", err))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::ThreadId;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::ReadThreadParams;
+    use crate::ThreadStore;
+    use crate::local::test_support::write_archived_session_file;
+    use crate::local::test_support::write_session_file;
+    use crate::sqlite::test_support::test_store;
+
+    #[tokio::test]
+    async fn import_rollouts_copies_sessions_and_archive_state() {
+        let codex_home = TempDir::new().expect("temp dir");
+        let sqlite_home = TempDir::new().expect("temp dir");
+        let active_uuid = Uuid::from_u128(701);
+        let archived_uuid = Uuid::from_u128(702);
+        write_session_file(codex_home.path(), "2025-01-03T12-00-00", active_uuid)
+            .expect("session file");
+        write_archived_session_file(codex_home.path(), "2025-01-03T13-00-00", archived_uuid)
+            .expect("archived session file");
+        std::fs::create_dir_all(codex_home.path().join("sessions/2025/01/04"))
+            .expect("sessions dir");
+        std::fs::write(
+            codex_home
+                .path()
+                .join("sessions/2025/01/04/rollout-2025-01-04T00-00-00-broken.jsonl"),
+            "not json\n",
+        )
+        .expect("broken rollout");
+        let store = test_store(sqlite_home.path()).await;
+
+        let stats = store
+            .import_rollouts(codex_home.path())
+            .await
+            .expect("import rollouts");
+        assert_eq!(
+            stats,
+            RolloutImportStats {
+                imported: 2,
+                skipped: 0,
+                failed: 1,
+            }
+        );
+
+        let active = store
+            .read_thread(ReadThreadParams {
+                thread_id: ThreadId::from_string(&active_uuid.to_string())
+                    .expect("valid thread id"),
+                include_archived: false,
+                include_history: true,
+            })
+            .await
+            .expect("read imported thread");
+        assert_eq!(active.preview, "Hello from user");
+        assert_eq!(active.rollout_path, None);
+        assert_eq!(active.history.expect("history").items.len(), 2);
+
+        let archived = store
+            .read_thread(ReadThreadParams {
+                thread_id: ThreadId::from_string(&archived_uuid.to_string())
+                    .expect("valid thread id"),
+                include_archived: true,
+                include_history: false,
+            })
+            .await
+            .expect("read imported archived thread");
+        assert!(archived.archived_at.is_some());
+
+        let stats = store
+            .import_rollouts(codex_home.path())
+            .await
+            .expect("re-import rollouts");
+        assert_eq!(stats.imported, 2);
+    }
+}
@@ -0,0 +1,272 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::Utc;
+use codex_git_utils::collect_git_info;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::GitInfo;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionMeta;
+use codex_protocol::protocol::SessionMetaLine;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::ThreadMemoryMode;
+use codex_rollout::EventPersistenceMode;
+use codex_rollout::builder_from_items;
+use codex_rollout::is_persisted_rollout_item;
+use codex_state::SortDirection;
+use codex_state::ThreadMetadataBuilder;
+
+use super::SqliteThreadStore;
+use super::internal_error;
+use crate::AppendThreadItemsParams;
+use crate::CreateThreadParams;
+use crate::ResumeThreadParams;
+use crate::ThreadEventPersistenceMode;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+
+/// Writer state for a thread that is open for appends.
+pub(crate) struct SqliteLiveThread {
+    builder: ThreadMetadataBuilder,
+    event_persistence_mode: EventPersistenceMode,
+    memory_mode: ThreadMemoryMode,
+    /// Items queued until the thread is materialized; `None` once it has been.
+    pending_items: Option<Vec<RolloutItem>>,
+    /// Turn that searchable content from the next batch belongs to.
+    content_turn_id: Option<String>,
+}
+
+pub(super) async fn create_thread(
+    store: &SqliteThreadStore,
+    params: CreateThreadParams,
+) -> ThreadStoreResult<()> {
+    let thread_id = params.thread_id;
+    if store.has_live_thread(thread_id).await {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: format!("thread {thread_id} already has a live sqlite writer"),
+        });
+    }
+    let cwd = params.metadata.cwd.clone().unwrap_or_default();
+    let git = match params.metadata.cwd.as_deref() {
+        Some(cwd) => collect_git_info(cwd).await.map(|info| GitInfo {
+            commit_hash: info.commit_hash,
+            branch: info.branch,
+            repository_url: info.repository_url,
+        }),
+        None => None,
+    };
+    let source = params.source;
+    let session_meta = SessionMeta {
+        id: thread_id,
+        forked_from_id: params.forked_from_id,
+        timestamp: Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+        cwd,
+        originator: codex_login::default_client::originator().value,
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        agent_nickname: source.get_nickname(),
+        agent_role: source.get_agent_role(),
+        agent_path: source.get_agent_path().map(Into::into),
+        source,
+        thread_source: params.thread_source,
+        model_provider: Some(params.metadata.model_provider.clone()),
+        base_instructions: Some(params.base_instructions),
+        dynamic_tools: if params.dynamic_tools.is_empty() {
+            None
+        } else {
+            Some(params.dynamic_tools)
+        },
+        memory_mode: matches!(params.metadata.memory_mode, ThreadMemoryMode::Disabled)
+            .then_some("disabled".to_string()),
+    };
+    let pending_items = vec![RolloutItem::SessionMeta(SessionMetaLine {
+        meta: session_meta,
+        git,
+    })];
+    let builder = builder_from_items(pending_items.as_slice(), Path::new(""))
+        .unwrap_or_else(|| fallback_builder(thread_id));
+    store
+        .insert_live_thread(
+            thread_id,
+            SqliteLiveThread {
+                builder,
+                event_persistence_mode: event_persistence_mode(params.event_persistence_mode),
+                memory_mode: params.metadata.memory_mode,
+                pending_items: Some(pending_items),
+                content_turn_id: None,
+            },
+        )
+        .await
+}
+
+pub(super) async fn resume_thread(
+    store: &SqliteThreadStore,
+    params: ResumeThreadParams,
+) -> ThreadStoreResult<()> {
+    let thread_id = params.thread_id;
+    if store.has_live_thread(thread_id).await {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: format!("thread {thread_id} already has a live sqlite writer"),
+        });
+    }
+    let metadata = store
+        .state_db()
+        .await?
+        .get_thread(thread_id)
+        .await
+        .map_err(|err| internal_error("failed to read thread metadata", err))?
+        .ok_or(ThreadStoreError::ThreadNotFound { thread_id })?;
+    if metadata.archived_at.is_some() && !params.include_archived {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: format!("thread {thread_id} is archived"),
+        });
+    }
+    let content_turn_id = store
+        .state_db()
+        .await?
+        .list_thread_items(
+            thread_id,
+            /*turn_id*/ None,
+            /*after_seq*/ None,
+            /*limit*/ 1,
+            SortDirection::Desc,
+        )
+        .await
+        .map_err(|err| internal_error("failed to read thread items", err))?
+        .pop()
+        .and_then(|record| record.turn_id);
+    store
+        .insert_live_thread(
+            thread_id,
+            SqliteLiveThread {
+                builder: fallback_builder(thread_id),
+                event_persistence_mode: event_persistence_mode(params.event_persistence_mode),
+                memory_mode: params.metadata.memory_mode,
+                pending_items: None,
+                content_turn_id,
+            },
+        )
+        .await
+}
+
+pub(super) async fn append_items(
+    store: &SqliteThreadStore,
+    params: AppendThreadItemsParams,
+) -> ThreadStoreResult<()> {
+    let live_thread = store.live_thread(params.thread_id).await?;
+    let mut live_thread = live_thread.lock().await;
+    let items = params
+        .items
+        .into_iter()
+        .filter(|item| is_persisted_rollout_item(item, live_thread.event_persistence_mode))
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        return Ok(());
+    }
+    match live_thread.pending_items.as_mut() {
+        Some(pending_items) => {
+            pending_items.extend(items);
+            Ok(())
+        }
+        None => write_items(store, &mut live_thread, items.as_slice()).await,
+    }
+}
+
+pub(super) async fn persist_thread(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+) -> ThreadStoreResult<()> {
+    let live_thread = store.live_thread(thread_id).await?;
+    let mut live_thread = live_thread.lock().await;
+    let Some(pending_items) = live_thread.pending_items.take() else {
+        return Ok(());
+    };
+    if let Err(err) = write_items(store, &mut live_thread, &pending_items).await {
+        live_thread.pending_items = Some(pending_items);
+        return Err(err);
+    }
+    Ok(())
+}
+
+pub(super) async fn flush_thread(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+) -> ThreadStoreResult<()> {
+    let live_thread = store.live_thread(thread_id).await?;
+    let live_thread = live_thread.lock().await;
+    // Lazily persisted threads have nothing durable to flush yet.
+    if live_thread.pending_items.is_some() {
+        return Ok(());
+    }
+    store
+        .state_db()
+        .await?
+        .checkpoint()
+        .await
+        .map_err(|err| internal_error("failed to flush thread", err))
+}
+
+pub(super) async fn shutdown_thread(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+) -> ThreadStoreResult<()> {
+    flush_thread(store, thread_id).await?;
+    store.remove_live_thread(thread_id).await;
+    Ok(())
+}
+
+pub(super) async fn discard_thread(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+) -> ThreadStoreResult<()> {
+    store
+        .remove_live_thread(thread_id)
+        .await
+        .map(|_| ())
+        .ok_or(ThreadStoreError::ThreadNotFound { thread_id })
+}
+
+async fn write_items(
+    store: &SqliteThreadStore,
+    live_thread: &mut SqliteLiveThread,
+    items: &[RolloutItem],
+) -> ThreadStoreResult<()> {
+    let state_db = store.state_db().await?;
+    let mut content_turn_id = live_thread.content_turn_id.clone();
+    let entries = codex_state::extract_thread_content(items, &mut content_turn_id);
+    // Metadata, items and search content land together or not at all.
+    state_db
+        .append_thread_rollout_items(
+            &live_thread.builder,
+            items,
+            &entries,
+            Some(memory_mode_as_str(live_thread.memory_mode)),
+            Some(Utc::now()),
+        )
+        .await
+        .map_err(|err| internal_error("failed to append thread items", err))?;
+    live_thread.content_turn_id = content_turn_id;
+    Ok(())
+}
+
+fn fallback_builder(thread_id: ThreadId) -> ThreadMetadataBuilder {
+    ThreadMetadataBuilder::new(
+        thread_id,
+        PathBuf::new(),
+        Utc::now(),
+        SessionSource::Unknown,
+    )
+}
+
+fn event_persistence_mode(mode: ThreadEventPersistenceMode) -> EventPersistenceMode {
+    match mode {
+        ThreadEventPersistenceMode::Limited => EventPersistenceMode::Limited,
+        ThreadEventPersistenceMode::Extended => EventPersistenceMode::Extended,
+    }
+}
+
+pub(super) fn memory_mode_as_str(mode: ThreadMemoryMode) -> &'static str {
+    match mode {
+        ThreadMemoryMode::Enabled => "enabled",
+        ThreadMemoryMode::Disabled => "disabled",
+    }
+}
@@ -0,0 +1,305 @@
+mod import;
+mod live_writer;
+mod read_thread;
+mod turns;
+mod update_thread_metadata;
+
+use async_trait::async_trait;
+use codex_protocol::ThreadId;
+use codex_rollout::StateDbHandle;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::sync::OnceCell;
+
+use crate::AppendThreadItemsParams;
+use crate::ArchiveThreadParams;
+use crate::CreateThreadParams;
+use crate::ItemPage;
+use crate::ListItemsParams;
+use crate::ListThreadsParams;
+use crate::ListTurnsParams;
+use crate::LoadThreadHistoryParams;
+use crate::ReadThreadByRolloutPathParams;
+use crate::ReadThreadParams;
+use crate::ResumeThreadParams;
+use crate::StoredThread;
+use crate::StoredThreadHistory;
+use crate::ThreadPage;
+use crate::ThreadSearchPage;
+use crate::ThreadStore;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+use crate::TurnPage;
+use crate::UpdateThreadMetadataParams;
+
+pub use import::RolloutImportStats;
+use live_writer::SqliteLiveThread;
+
+/// SQLite-backed implementation of [`ThreadStore`].
+///
+/// Metadata, turns, and items are all stored in the state DB, so no rollout
+/// files are written. The store must own its state DB: the local store treats
+/// threads without a rollout file on disk as stale and deletes them, so
+/// [`SqliteThreadStore::open`] keeps it in a directory of its own.
+#[derive(Clone)]
+pub struct SqliteThreadStore {
+    state_db: Arc<OnceCell<StateDbHandle>>,
+    sqlite_home: Option<PathBuf>,
+    default_model_provider_id: String,
+    live_threads: Arc<Mutex<HashMap<ThreadId, Arc<Mutex<SqliteLiveThread>>>>>,
+}
+
+impl std::fmt::Debug for SqliteThreadStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteThreadStore")
+            .field("sqlite_home", &self.sqlite_home)
+            .field("default_model_provider_id", &self.default_model_provider_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SqliteThreadStore {
+    /// Create a SQLite store on top of an already initialized state DB handle.
+    pub fn new(state_db: StateDbHandle, default_model_provider_id: String) -> Self {
+        Self {
+            state_db: Arc::new(OnceCell::new_with(Some(state_db))),
+            sqlite_home: None,
+            default_model_provider_id,
+            live_threads: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Create a SQLite store whose state DB lives in `sqlite_home`.
+    ///
+    /// The DB is opened (and migrated) on first use. `sqlite_home` must not be
+    /// the directory of the local store's state DB.
+    pub fn open(sqlite_home: PathBuf, default_model_provider_id: String) -> Self {
+        Self {
+            state_db: Arc::new(OnceCell::new()),
+            sqlite_home: Some(sqlite_home),
+            default_model_provider_id,
+            live_threads: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Import rollout JSONL files from `codex_home` into this store.
+    ///
+    /// Both `sessions` and `archived_sessions` are scanned. Imported threads
+    /// replace any previously stored items for the same id, so re-running the
+    /// import is safe. Threads that are currently live are skipped.
+    pub async fn import_rollouts(
+        &self,
+        codex_home: &Path,
+    ) -> ThreadStoreResult<RolloutImportStats> {
+        import::import_rollouts(self, codex_home).await
+    }
+
+    pub(super) async fn state_db(&self) -> ThreadStoreResult<&StateDbHandle> {
+        self.state_db
+            .get_or_try_init(|| async {
+                let Some(sqlite_home) = self.sqlite_home.clone() else {
+                    return Err(ThreadStoreError::Internal {
+                        message: "sqlite thread store has no state DB".to_string(),
+                    });
+                };
+                codex_state::StateRuntime::init(sqlite_home, self.default_model_provider_id.clone())
+                    .await
+                    .map_err(|err| internal_error("failed to open sqlite thread store", err))
+            })
+            .await
+    }
+
+    pub(super) async fn live_thread(
+        &self,
+        thread_id: ThreadId,
+    ) -> ThreadStoreResult<Arc<Mutex<SqliteLiveThread>>> {
+        self.live_threads
+            .lock()
+            .await
+            .get(&thread_id)
+            .cloned()
+            .ok_or(ThreadStoreError::ThreadNotFound { thread_id })
+    }
+
+    pub(super) async fn insert_live_thread(
+        &self,
+        thread_id: ThreadId,
+        live_thread: SqliteLiveThread,
+    ) -> ThreadStoreResult<()> {
+        match self.live_threads.lock().await.entry(thread_id) {
+            Entry::Occupied(entry) => Err(ThreadStoreError::InvalidRequest {
+                message: format!("thread {} already has a live sqlite writer", entry.key()),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::new(Mutex::new(live_thread)));
+                Ok(())
+            }
+        }
+    }
+
+    pub(super) async fn remove_live_thread(
+        &self,
+        thread_id: ThreadId,
+    ) -> Option<Arc<Mutex<SqliteLiveThread>>> {
+        self.live_threads.lock().await.remove(&thread_id)
+    }
+
+    pub(super) async fn has_live_thread(&self, thread_id: ThreadId) -> bool {
+        self.live_threads.lock().await.contains_key(&thread_id)
+    }
+}
+
+#[async_trait]
+impl ThreadStore for SqliteThreadStore {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn create_thread(&self, params: CreateThreadParams) -> ThreadStoreResult<()> {
+        live_writer::create_thread(self, params).await
+    }
+
+    async fn resume_thread(&self, params: ResumeThreadParams) -> ThreadStoreResult<()> {
+        live_writer::resume_thread(self, params).await
+    }
+
+    async fn append_items(&self, params: AppendThreadItemsParams) -> ThreadStoreResult<()> {
+        live_writer::append_items(self, params).await
+    }
+
+    async fn persist_thread(&self, thread_id: ThreadId) -> ThreadStoreResult<()> {
+        live_writer::persist_thread(self, thread_id).await
+    }
+
+    async fn flush_thread(&self, thread_id: ThreadId) -> ThreadStoreResult<()> {
+        live_writer::flush_thread(self, thread_id).await
+    }
+
+    async fn shutdown_thread(&self, thread_id: ThreadId) -> ThreadStoreResult<()> {
+        live_writer::shutdown_thread(self, thread_id).await
+    }
+
+    async fn discard_thread(&self, thread_id: ThreadId) -> ThreadStoreResult<()> {
+        live_writer::discard_thread(self, thread_id).await
+    }
+
+    async fn load_history(
+        &self,
+        params: LoadThreadHistoryParams,
+    ) -> ThreadStoreResult<StoredThreadHistory> {
+        read_thread::read_thread(
+            self,
+            ReadThreadParams {
+                thread_id: params.thread_id,
+                include_archived: params.include_archived,
+                include_history: true,
+            },
+        )
+        .await?
+        .history
+        .ok_or_else(|| ThreadStoreError::Internal {
+            message: format!("failed to load history for thread {}", params.thread_id),
+        })
+    }
+
+    async fn read_thread(&self, params: ReadThreadParams) -> ThreadStoreResult<StoredThread> {
+        read_thread::read_thread(self, params).await
+    }
+
+    async fn read_thread_by_rollout_path(
+        &self,
+        _params: ReadThreadByRolloutPathParams,
+    ) -> ThreadStoreResult<StoredThread> {
+        Err(ThreadStoreError::Unsupported {
+            operation: "read_thread_by_rollout_path",
+        })
+    }
+
+    async fn list_threads(&self, params: ListThreadsParams) -> ThreadStoreResult<ThreadPage> {
+        read_thread::list_threads(self, params).await
+    }
+
+    async fn search_threads(
+        &self,
+        params: ListThreadsParams,
+    ) -> ThreadStoreResult<ThreadSearchPage> {
+        read_thread::search_threads(self, params).await
+    }
+
+    async fn list_turns(&self, params: ListTurnsParams) -> ThreadStoreResult<TurnPage> {
+        turns::list_turns(self, params).await
+    }
+
+    async fn list_items(&self, params: ListItemsParams) -> ThreadStoreResult<ItemPage> {
+        turns::list_items(self, params).await
+    }
+
+    async fn update_thread_metadata(
+        &self,
+        params: UpdateThreadMetadataParams,
+    ) -> ThreadStoreResult<StoredThread> {
+        update_thread_metadata::update_thread_metadata(self, params).await
+    }
+
+    async fn archive_thread(&self, params: ArchiveThreadParams) -> ThreadStoreResult<()> {
+        update_thread_metadata::set_archived(self, params.thread_id, /*archived*/ true).await?;
+        Ok(())
+    }
+
+    async fn unarchive_thread(
+        &self,
+        params: ArchiveThreadParams,
+    ) -> ThreadStoreResult<StoredThread> {
+        update_thread_metadata::set_archived(self, params.thread_id, /*archived*/ false).await
+    }
+}
+
+fn internal_error(context: &str, err: impl std::fmt::Display) -> ThreadStoreError {
+    ThreadStoreError::Internal {
+        message: format!("{context}: {err}"),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::path::Path;
+
+    use codex_protocol::ThreadId;
+    use codex_protocol::models::BaseInstructions;
+    use codex_protocol::protocol::SessionSource;
+    use codex_protocol::protocol::ThreadMemoryMode;
+
+    use super::SqliteThreadStore;
+    use crate::CreateThreadParams;
+    use crate::ThreadEventPersistenceMode;
+    use crate::ThreadPersistenceMetadata;
+
+    pub(crate) async fn test_store(sqlite_home: &Path) -> SqliteThreadStore {
+        let state_db =
+            codex_state::StateRuntime::init(sqlite_home.to_path_buf(), "test-provider".to_string())
+                .await
+                .expect("state db should initialize");
+        SqliteThreadStore::new(state_db, "test-provider".to_string())
+    }
+
+    pub(crate) fn create_params(thread_id: ThreadId, cwd: &Path) -> CreateThreadParams {
+        CreateThreadParams {
+            thread_id,
+            forked_from_id: None,
+            source: SessionSource::Cli,
+            thread_source: None,
+            base_instructions: BaseInstructions::default(),
+            dynamic_tools: Vec::new(),
+            metadata: ThreadPersistenceMetadata {
+                cwd: Some(cwd.to_path_buf()),
+                model_provider: "test-provider".to_string(),
+                memory_mode: ThreadMemoryMode::Enabled,
+            },
+            event_persistence_mode: ThreadEventPersistenceMode::Limited,
+        }
+    }
+}
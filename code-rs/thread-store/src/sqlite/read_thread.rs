@@ -0,0 +1,403 @@
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::SessionSource;
+use codex_rollout::parse_cursor;
+use codex_rollout::state_db::cursor_to_anchor;
+use codex_rollout::state_db::normalize_cwd_for_state_db;
+use codex_state::ThreadFilterOptions;
+use codex_state::ThreadMetadata;
+
+use super::SqliteThreadStore;
+use super::internal_error;
+use crate::ListThreadsParams;
+use crate::ReadThreadParams;
+use crate::SortDirection;
+use crate::StoredThread;
+use crate::StoredThreadHistory;
+use crate::ThreadPage;
+use crate::ThreadSearchHit;
+use crate::ThreadSearchPage;
+use crate::ThreadSortKey;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+use crate::local::helpers::distinct_thread_metadata_title;
+use crate::local::helpers::git_info_from_parts;
+use crate::local::helpers::parse_or_default;
+use crate::local::helpers::parse_session_source;
+
+pub(super) async fn read_thread(
+    store: &SqliteThreadStore,
+    params: ReadThreadParams,
+) -> ThreadStoreResult<StoredThread> {
+    let metadata = read_metadata(store, params.thread_id, params.include_archived).await?;
+    let items = store
+        .state_db()
+        .await?
+        .thread_items(params.thread_id)
+        .await
+        .map_err(|err| internal_error("failed to read thread items", err))?;
+    let mut thread = stored_thread_from_metadata(store, metadata, forked_from_id(&items));
+    if params.include_history {
+        thread.history = Some(StoredThreadHistory {
+            thread_id: params.thread_id,
+            items,
+        });
+    }
+    Ok(thread)
+}
+
+/// Read a thread's metadata row, honoring `include_archived`.
+pub(super) async fn read_metadata(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+    include_archived: bool,
+) -> ThreadStoreResult<ThreadMetadata> {
+    let metadata = store
+        .state_db()
+        .await?
+        .get_thread(thread_id)
+        .await
+        .map_err(|err| internal_error("failed to read thread metadata", err))?
+        .ok_or(ThreadStoreError::ThreadNotFound { thread_id })?;
+    if metadata.archived_at.is_some() && !include_archived {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: format!("thread {thread_id} is archived"),
+        });
+    }
+    Ok(metadata)
+}
+
+pub(super) async fn list_threads(
+    store: &SqliteThreadStore,
+    params: ListThreadsParams,
+) -> ThreadStoreResult<ThreadPage> {
+    let filters = ThreadListFilters::from_params(&params)?;
+    let page = store
+        .state_db()
+        .await?
+        .list_threads(
+            params.page_size,
+            filters.options(&params, params.search_term.as_deref()),
+        )
+        .await
+        .map_err(|err| internal_error("failed to list threads", err))?;
+    let items = page
+        .items
+        .into_iter()
+        .map(|metadata| stored_thread_from_metadata(store, metadata, /*forked_from_id*/ None))
+        .collect();
+    Ok(ThreadPage {
+        items,
+        next_cursor: cursor_from_anchor(page.next_anchor),
+    })
+}
+
+pub(super) async fn search_threads(
+    store: &SqliteThreadStore,
+    params: ListThreadsParams,
+) -> ThreadStoreResult<ThreadSearchPage> {
+    let Some(query) = params.search_term.as_deref() else {
+        return Err(ThreadStoreError::InvalidRequest {
+            message: "thread content search requires a search term".to_string(),
+        });
+    };
+    let filters = ThreadListFilters::from_params(&params)?;
+    let page = store
+        .state_db()
+        .await?
+        .search_thread_content(
+            query,
+            params.page_size,
+            filters.options(&params, /*search_term*/ None),
+        )
+        .await
+        .map_err(|err| internal_error("failed to search thread content", err))?;
+    let items = page
+        .items
+        .into_iter()
+        .map(|hit| ThreadSearchHit {
+            thread: stored_thread_from_metadata(store, hit.metadata, /*forked_from_id*/ None),
+            turn_id: hit.turn_id,
+            snippet: hit.snippet,
+        })
+        .collect();
+    Ok(ThreadSearchPage {
+        items,
+        next_cursor: cursor_from_anchor(page.next_anchor),
+    })
+}
+
+/// Owned filter values borrowed by [`ThreadFilterOptions`].
+struct ThreadListFilters {
+    anchor: Option<codex_state::Anchor>,
+    allowed_sources: Vec<String>,
+    cwd_filters: Option<Vec<PathBuf>>,
+}
+
+impl ThreadListFilters {
+    fn from_params(params: &ListThreadsParams) -> ThreadStoreResult<Self> {
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(|cursor| {
+                parse_cursor(cursor).ok_or_else(|| ThreadStoreError::InvalidRequest {
+                    message: format!("invalid cursor: {cursor}"),
+                })
+            })
+            .transpose()?;
+        let allowed_sources = params
+            .allowed_sources
+            .iter()
+            .map(|source| match serde_json::to_value(source) {
+                Ok(serde_json::Value::String(source)) => source,
+                Ok(other) => other.to_string(),
+                Err(_) => String::new(),
+            })
+            .collect();
+        let cwd_filters = params.cwd_filters.as_ref().map(|filters| {
+            filters
+                .iter()
+                .map(|cwd| normalize_cwd_for_state_db(cwd))
+                .collect()
+        });
+        Ok(Self {
+            anchor: cursor_to_anchor(cursor.as_ref()),
+            allowed_sources,
+            cwd_filters,
+        })
+    }
+
+    fn options<'a>(
+        &'a self,
+        params: &'a ListThreadsParams,
+        search_term: Option<&'a str>,
+    ) -> ThreadFilterOptions<'a> {
+        ThreadFilterOptions {
+            archived_only: params.archived,
+            allowed_sources: self.allowed_sources.as_slice(),
+            model_providers: params.model_providers.as_deref(),
+            cwd_filters: self.cwd_filters.as_deref(),
+            anchor: self.anchor.as_ref(),
+            sort_key: match params.sort_key {
+                ThreadSortKey::CreatedAt => codex_state::SortKey::CreatedAt,
+                ThreadSortKey::UpdatedAt => codex_state::SortKey::UpdatedAt,
+            },
+            sort_direction: match params.sort_direction {
+                SortDirection::Asc => codex_state::SortDirection::Asc,
+                SortDirection::Desc => codex_state::SortDirection::Desc,
+            },
+            search_term,
+        }
+    }
+}
+
+fn cursor_from_anchor(anchor: Option<codex_state::Anchor>) -> Option<String> {
+    anchor
+        .map(codex_rollout::Cursor::from)
+        .and_then(|cursor| serde_json::to_value(cursor).ok())
+        .and_then(|value| value.as_str().map(str::to_owned))
+}
+
+fn forked_from_id(items: &[RolloutItem]) -> Option<ThreadId> {
+    items.iter().find_map(|item| match item {
+        RolloutItem::SessionMeta(meta_line) => Some(meta_line.meta.forked_from_id),
+        RolloutItem::ResponseItem(_)
+        | RolloutItem::Compacted(_)
+        | RolloutItem::TurnContext(_)
+        | RolloutItem::EventMsg(_) => None,
+    })?
+}
+
+pub(super) fn stored_thread_from_metadata(
+    store: &SqliteThreadStore,
+    metadata: ThreadMetadata,
+    forked_from_id: Option<ThreadId>,
+) -> StoredThread {
+    StoredThread {
+        thread_id: metadata.id,
+        rollout_path: None,
+        forked_from_id,
+        preview: metadata.first_user_message.clone().unwrap_or_default(),
+        name: distinct_thread_metadata_title(&metadata),
+        model_provider: if metadata.model_provider.is_empty() {
+            store.default_model_provider_id.clone()
+        } else {
+            metadata.model_provider
+        },
+        model: metadata.model,
+        reasoning_effort: metadata.reasoning_effort,
+        created_at: metadata.created_at,
+        updated_at: metadata.updated_at,
+        archived_at: metadata.archived_at,
+        cwd: metadata.cwd,
+        cli_version: metadata.cli_version,
+        source: parse_session_source(&metadata.source),
+        thread_source: metadata.thread_source,
+        agent_nickname: metadata.agent_nickname,
+        agent_role: metadata.agent_role,
+        agent_path: metadata.agent_path,
+        git_info: git_info_from_parts(
+            metadata.git_sha,
+            metadata.git_branch,
+            metadata.git_origin_url,
+        ),
+        approval_mode: parse_or_default(&metadata.approval_mode, AskForApproval::OnRequest),
+        sandbox_policy: parse_or_default(
+            &metadata.sandbox_policy,
+            SandboxPolicy::new_read_only_policy(),
+        ),
+        token_usage: None,
+        first_user_message: metadata.first_user_message,
+        history: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::AppendThreadItemsParams;
+    use crate::ThreadStore;
+    use crate::sqlite::test_support::create_params;
+    use crate::sqlite::test_support::test_store;
+
+    fn list_params(archived: bool) -> ListThreadsParams {
+        ListThreadsParams {
+            page_size: 10,
+            cursor: None,
+            sort_key: ThreadSortKey::CreatedAt,
+            sort_direction: SortDirection::Desc,
+            allowed_sources: vec![SessionSource::Cli],
+            model_providers: None,
+            cwd_filters: None,
+            archived,
+            search_term: None,
+            use_state_db_only: true,
+        }
+    }
+
+    fn user_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: message.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn threads_are_listed_once_persisted() {
+        let home = TempDir::new().expect("temp dir");
+        let store = test_store(home.path()).await;
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(401).to_string()).expect("valid thread id");
+
+        store
+            .create_thread(create_params(thread_id, home.path()))
+            .await
+            .expect("create thread");
+        store
+            .append_items(AppendThreadItemsParams {
+                thread_id,
+                items: vec![user_message("fix the flaky test")],
+            })
+            .await
+            .expect("append items");
+        let page = store
+            .list_threads(list_params(/*archived*/ false))
+            .await
+            .expect("list threads");
+        assert_eq!(page.items.len(), 0);
+
+        store.persist_thread(thread_id).await.expect("persist");
+        let page = store
+            .list_threads(list_params(/*archived*/ false))
+            .await
+            .expect("list threads");
+        assert_eq!(page.items.len(), 1);
+        let thread = &page.items[0];
+        assert_eq!(thread.thread_id, thread_id);
+        assert_eq!(thread.preview, "fix the flaky test");
+        assert_eq!(thread.rollout_path, None);
+        assert_eq!(thread.model_provider, "test-provider");
+
+        let thread = store
+            .read_thread(ReadThreadParams {
+                thread_id,
+                include_archived: false,
+                include_history: true,
+            })
+            .await
+            .expect("read thread");
+        let history = thread.history.expect("history");
+        assert_eq!(history.items.len(), 2);
+        assert!(matches!(history.items[0], RolloutItem::SessionMeta(_)));
+    }
+
+    #[tokio::test]
+    async fn archived_threads_move_between_listings() {
+        let home = TempDir::new().expect("temp dir");
+        let store = test_store(home.path()).await;
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(402).to_string()).expect("valid thread id");
+        store
+            .create_thread(create_params(thread_id, home.path()))
+            .await
+            .expect("create thread");
+        store
+            .append_items(AppendThreadItemsParams {
+                thread_id,
+                items: vec![user_message("archive me")],
+            })
+            .await
+            .expect("append items");
+        store.persist_thread(thread_id).await.expect("persist");
+
+        store
+            .archive_thread(crate::ArchiveThreadParams { thread_id })
+            .await
+            .expect("archive");
+        assert_eq!(
+            store
+                .list_threads(list_params(/*archived*/ false))
+                .await
+                .expect("list threads")
+                .items
+                .len(),
+            0
+        );
+        assert_eq!(
+            store
+                .list_threads(list_params(/*archived*/ true))
+                .await
+                .expect("list threads")
+                .items
+                .len(),
+            1
+        );
+        let err = store
+            .read_thread(ReadThreadParams {
+                thread_id,
+                include_archived: false,
+                include_history: false,
+            })
+            .await
+            .expect_err("archived thread should be hidden");
+        assert!(matches!(err, ThreadStoreError::InvalidRequest { .. }));
+
+        let thread = store
+            .unarchive_thread(crate::ArchiveThreadParams { thread_id })
+            .await
+            .expect("unarchive");
+        assert_eq!(thread.archived_at, None);
+    }
+}
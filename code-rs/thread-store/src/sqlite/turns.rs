@@ -0,0 +1,404 @@
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_state::ThreadTurn;
+use codex_state::ThreadTurnStatus;
+
+use super::SqliteThreadStore;
+use super::internal_error;
+use super::read_thread::read_metadata;
+use crate::ItemPage;
+use crate::ListItemsParams;
+use crate::ListTurnsParams;
+use crate::SortDirection;
+use crate::StoredTurn;
+use crate::StoredTurnError;
+use crate::StoredTurnItemsView;
+use crate::StoredTurnStatus;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+use crate::TurnPage;
+
+pub(super) async fn list_turns(
+    store: &SqliteThreadStore,
+    params: ListTurnsParams,
+) -> ThreadStoreResult<TurnPage> {
+    read_metadata(store, params.thread_id, params.include_archived).await?;
+    let after_position = parse_position_cursor(params.cursor.as_deref())?;
+    let mut turns = store
+        .state_db()
+        .await?
+        .list_thread_turns(
+            params.thread_id,
+            after_position,
+            params.page_size.saturating_add(1),
+            state_sort_direction(params.sort_direction),
+        )
+        .await
+        .map_err(|err| internal_error("failed to list thread turns", err))?;
+    let next_cursor = next_cursor(&mut turns, params.page_size, |turn| turn.position);
+    let backwards_cursor = after_position
+        .and(turns.first())
+        .map(|turn| turn.position.to_string());
+
+    let mut stored_turns = Vec::with_capacity(turns.len());
+    for turn in turns {
+        let items = match params.items_view {
+            StoredTurnItemsView::NotLoaded => Vec::new(),
+            StoredTurnItemsView::Summary => {
+                summary_items(turn_items(store, &params, turn.turn_id.as_str()).await?)
+            }
+            StoredTurnItemsView::Full => turn_items(store, &params, turn.turn_id.as_str()).await?,
+        };
+        stored_turns.push(stored_turn(turn, items, params.items_view));
+    }
+    Ok(TurnPage {
+        turns: stored_turns,
+        next_cursor,
+        backwards_cursor,
+    })
+}
+
+pub(super) async fn list_items(
+    store: &SqliteThreadStore,
+    params: ListItemsParams,
+) -> ThreadStoreResult<ItemPage> {
+    read_metadata(store, params.thread_id, params.include_archived).await?;
+    let after_seq = parse_position_cursor(params.cursor.as_deref())?;
+    let mut records = store
+        .state_db()
+        .await?
+        .list_thread_items(
+            params.thread_id,
+            Some(params.turn_id.as_str()),
+            after_seq,
+            params.page_size.saturating_add(1),
+            state_sort_direction(params.sort_direction),
+        )
+        .await
+        .map_err(|err| internal_error("failed to list thread items", err))?;
+    let next_cursor = next_cursor(&mut records, params.page_size, |record| record.seq);
+    let backwards_cursor = after_seq
+        .and(records.first())
+        .map(|record| record.seq.to_string());
+    Ok(ItemPage {
+        items: records.into_iter().map(|record| record.item).collect(),
+        next_cursor,
+        backwards_cursor,
+    })
+}
+
+async fn turn_items(
+    store: &SqliteThreadStore,
+    params: &ListTurnsParams,
+    turn_id: &str,
+) -> ThreadStoreResult<Vec<RolloutItem>> {
+    let records = store
+        .state_db()
+        .await?
+        .list_thread_items(
+            params.thread_id,
+            Some(turn_id),
+            /*after_seq*/ None,
+            usize::MAX,
+            codex_state::SortDirection::Asc,
+        )
+        .await
+        .map_err(|err| internal_error("failed to list thread items", err))?;
+    Ok(records.into_iter().map(|record| record.item).collect())
+}
+
+/// Keep the first user message and the final agent message of a turn.
+fn summary_items(items: Vec<RolloutItem>) -> Vec<RolloutItem> {
+    let first_user_message = items
+        .iter()
+        .position(|item| matches!(item, RolloutItem::EventMsg(EventMsg::UserMessage(_))));
+    let final_agent_message = items
+        .iter()
+        .rposition(|item| matches!(item, RolloutItem::EventMsg(EventMsg::AgentMessage(_))));
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            Some(*index) == first_user_message || Some(*index) == final_agent_message
+        })
+        .map(|(_, item)| item)
+        .collect()
+}
+
+fn stored_turn(
+    turn: ThreadTurn,
+    items: Vec<RolloutItem>,
+    items_view: StoredTurnItemsView,
+) -> StoredTurn {
+    StoredTurn {
+        turn_id: turn.turn_id,
+        items,
+        items_view,
+        status: match turn.status {
+            ThreadTurnStatus::InProgress => StoredTurnStatus::InProgress,
+            ThreadTurnStatus::Completed => StoredTurnStatus::Completed,
+            ThreadTurnStatus::Interrupted => StoredTurnStatus::Interrupted,
+            ThreadTurnStatus::Failed => StoredTurnStatus::Failed,
+        },
+        error: turn.error_message.map(|message| StoredTurnError {
+            message,
+            additional_details: None,
+        }),
+        started_at: turn.started_at,
+        completed_at: turn.completed_at,
+        duration_ms: turn.duration_ms,
+    }
+}
+
+/// Trim the extra row fetched to detect another page and return its cursor.
+fn next_cursor<T>(rows: &mut Vec<T>, page_size: usize, key: impl Fn(&T) -> i64) -> Option<String> {
+    if rows.len() <= page_size {
+        return None;
+    }
+    rows.truncate(page_size);
+    rows.last().map(|row| key(row).to_string())
+}
+
+fn parse_position_cursor(cursor: Option<&str>) -> ThreadStoreResult<Option<i64>> {
+    cursor
+        .map(|cursor| {
+            cursor
+                .parse::<i64>()
+                .map_err(|_| ThreadStoreError::InvalidRequest {
+                    message: format!("invalid cursor: {cursor}"),
+                })
+        })
+        .transpose()
+}
+
+fn state_sort_direction(direction: SortDirection) -> codex_state::SortDirection {
+    match direction {
+        SortDirection::Asc => codex_state::SortDirection::Asc,
+        SortDirection::Desc => codex_state::SortDirection::Desc,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::TurnAbortReason;
+    use codex_protocol::protocol::TurnAbortedEvent;
+    use codex_protocol::protocol::TurnCompleteEvent;
+    use codex_protocol::protocol::TurnStartedEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::AppendThreadItemsParams;
+    use crate::ThreadStore;
+    use crate::sqlite::test_support::create_params;
+    use crate::sqlite::test_support::test_store;
+
+    fn turn_started(turn_id: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::TurnStarted(TurnStartedEvent {
+            turn_id: turn_id.to_string(),
+            started_at: Some(1_700_000_000),
+            model_context_window: None,
+            collaboration_mode_kind: Default::default(),
+        }))
+    }
+
+    fn turn_complete(turn_id: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
+            turn_id: turn_id.to_string(),
+            last_agent_message: None,
+            completed_at: Some(1_700_000_001),
+            duration_ms: Some(1_000),
+            time_to_first_token_ms: None,
+        }))
+    }
+
+    fn user_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: message.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    fn agent_message(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+            message: message.to_string(),
+            phase: None,
+            memory_citation: None,
+        }))
+    }
+
+    fn message_text(item: &RolloutItem) -> &str {
+        match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => event.message.as_str(),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => event.message.as_str(),
+            _ => panic!("expected a message item, got {item:?}"),
+        }
+    }
+
+    async fn store_with_turns(home: &TempDir, thread_id: ThreadId) -> SqliteThreadStore {
+        let store = test_store(home.path()).await;
+        store
+            .create_thread(create_params(thread_id, home.path()))
+            .await
+            .expect("create thread");
+        let mut items = Vec::new();
+        for turn in 0..3 {
+            let turn_id = format!("turn-{turn}");
+            items.push(turn_started(&turn_id));
+            items.push(user_message(&format!("question {turn}")));
+            items.push(agent_message(&format!("draft {turn}")));
+            items.push(agent_message(&format!("answer {turn}")));
+            items.push(turn_complete(&turn_id));
+        }
+        items.push(turn_started("turn-3"));
+        items.push(user_message("never mind"));
+        items.push(RolloutItem::EventMsg(EventMsg::TurnAborted(
+            TurnAbortedEvent {
+                turn_id: Some("turn-3".to_string()),
+                reason: TurnAbortReason::Interrupted,
+                completed_at: None,
+                duration_ms: None,
+            },
+        )));
+        store
+            .append_items(AppendThreadItemsParams { thread_id, items })
+            .await
+            .expect("append items");
+        store.persist_thread(thread_id).await.expect("persist");
+        store.flush_thread(thread_id).await.expect("flush");
+        store
+    }
+
+    fn turns_params(
+        thread_id: ThreadId,
+        cursor: Option<String>,
+        sort_direction: SortDirection,
+    ) -> ListTurnsParams {
+        ListTurnsParams {
+            thread_id,
+            include_archived: false,
+            cursor,
+            page_size: 2,
+            sort_direction,
+            items_view: StoredTurnItemsView::Summary,
+        }
+    }
+
+    #[tokio::test]
+    async fn list_turns_pages_with_summaries() {
+        let home = TempDir::new().expect("temp dir");
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(501).to_string()).expect("valid thread id");
+        let store = store_with_turns(&home, thread_id).await;
+
+        let first = store
+            .list_turns(turns_params(thread_id, None, SortDirection::Desc))
+            .await
+            .expect("list turns");
+        assert_eq!(
+            first
+                .turns
+                .iter()
+                .map(|turn| (turn.turn_id.as_str(), turn.status))
+                .collect::<Vec<_>>(),
+            vec![
+                ("turn-3", StoredTurnStatus::Interrupted),
+                ("turn-2", StoredTurnStatus::Completed),
+            ]
+        );
+        assert_eq!(
+            first.turns[1]
+                .items
+                .iter()
+                .map(message_text)
+                .collect::<Vec<_>>(),
+            vec!["question 2", "answer 2"]
+        );
+        assert_eq!(first.backwards_cursor, None);
+
+        let second = store
+            .list_turns(turns_params(
+                thread_id,
+                first.next_cursor.clone(),
+                SortDirection::Desc,
+            ))
+            .await
+            .expect("list turns");
+        assert_eq!(
+            second
+                .turns
+                .iter()
+                .map(|turn| turn.turn_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["turn-1", "turn-0"]
+        );
+        assert_eq!(second.next_cursor, None);
+
+        let back = store
+            .list_turns(turns_params(
+                thread_id,
+                second.backwards_cursor,
+                SortDirection::Asc,
+            ))
+            .await
+            .expect("list turns");
+        assert_eq!(
+            back.turns
+                .iter()
+                .map(|turn| turn.turn_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["turn-2", "turn-3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_items_pages_within_a_turn() {
+        let home = TempDir::new().expect("temp dir");
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(502).to_string()).expect("valid thread id");
+        let store = store_with_turns(&home, thread_id).await;
+        let params = |cursor| ListItemsParams {
+            thread_id,
+            turn_id: "turn-1".to_string(),
+            include_archived: false,
+            cursor,
+            page_size: 2,
+            sort_direction: SortDirection::Asc,
+        };
+
+        let first = store.list_items(params(None)).await.expect("list items");
+        assert_eq!(first.items.len(), 2);
+        assert!(matches!(
+            first.items[0],
+            RolloutItem::EventMsg(EventMsg::TurnStarted(_))
+        ));
+        assert_eq!(message_text(&first.items[1]), "question 1");
+
+        let second = store
+            .list_items(params(first.next_cursor))
+            .await
+            .expect("list items");
+        assert_eq!(
+            second.items.iter().map(message_text).collect::<Vec<_>>(),
+            vec!["draft 1", "answer 1"]
+        );
+        let third = store
+            .list_items(params(second.next_cursor))
+            .await
+            .expect("list items");
+        assert_eq!(third.items.len(), 1);
+        assert_eq!(third.next_cursor, None);
+
+        let err = store
+            .list_items(params(Some("not-a-cursor".to_string())))
+            .await
+            .expect_err("invalid cursor should fail");
+        assert!(matches!(err, ThreadStoreError::InvalidRequest { .. }));
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::Utc;
+use codex_protocol::ThreadId;
+
+use super::SqliteThreadStore;
+use super::internal_error;
+use super::live_writer;
+use super::live_writer::memory_mode_as_str;
+use super::read_thread;
+use crate::ReadThreadParams;
+use crate::StoredThread;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+use crate::UpdateThreadMetadataParams;
+
+pub(super) async fn update_thread_metadata(
+    store: &SqliteThreadStore,
+    params: UpdateThreadMetadataParams,
+) -> ThreadStoreResult<StoredThread> {
+    let thread_id = params.thread_id;
+    if store.has_live_thread(thread_id).await {
+        live_writer::persist_thread(store, thread_id).await?;
+    }
+    let metadata = read_thread::read_metadata(store, thread_id, params.include_archived).await?;
+
+    if let Some(name) = params.patch.name {
+        store
+            .state_db()
+            .await?
+            .update_thread_title(thread_id, &name)
+            .await
+            .map_err(|err| internal_error("failed to set thread name", err))?;
+    }
+    if let Some(memory_mode) = params.patch.memory_mode {
+        store
+            .state_db()
+            .await?
+            .set_thread_memory_mode(thread_id, memory_mode_as_str(memory_mode))
+            .await
+            .map_err(|err| internal_error("failed to set thread memory mode", err))?;
+    }
+    if let Some(git_info) = params.patch.git_info {
+        let sha = git_info.sha.unwrap_or(metadata.git_sha);
+        let branch = git_info.branch.unwrap_or(metadata.git_branch);
+        let origin_url = git_info.origin_url.unwrap_or(metadata.git_origin_url);
+        store
+            .state_db()
+            .await?
+            .update_thread_git_info(
+                thread_id,
+                Some(sha.as_deref()),
+                Some(branch.as_deref()),
+                Some(origin_url.as_deref()),
+            )
+            .await
+            .map_err(|err| internal_error("failed to update git metadata", err))?;
+    }
+
+    read_thread::read_thread(
+        store,
+        ReadThreadParams {
+            thread_id,
+            include_archived: params.include_archived,
+            include_history: false,
+        },
+    )
+    .await
+}
+
+pub(super) async fn set_archived(
+    store: &SqliteThreadStore,
+    thread_id: ThreadId,
+    archived: bool,
+) -> ThreadStoreResult<StoredThread> {
+    let mut metadata = store
+        .state_db()
+        .await?
+        .get_thread(thread_id)
+        .await
+        .map_err(|err| internal_error("failed to read thread metadata", err))?
+        .ok_or(ThreadStoreError::ThreadNotFound { thread_id })?;
+    let now = Utc::now();
+    metadata.archived_at = archived.then_some(now);
+    metadata.updated_at = now;
+    store
+        .state_db()
+        .await?
+        .upsert_thread(&metadata)
+        .await
+        .map_err(|err| internal_error("failed to update thread archive state", err))?;
+    read_thread::read_thread(
+        store,
+        ReadThreadParams {
+            thread_id,
+            include_archived: true,
+            include_history: false,
+        },
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::RolloutItem;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::AppendThreadItemsParams;
+    use crate::GitInfoPatch;
+    use crate::ThreadMetadataPatch;
+    use crate::ThreadStore;
+    use crate::sqlite::test_support::create_params;
+    use crate::sqlite::test_support::test_store;
+
+    #[tokio::test]
+    async fn update_thread_metadata_applies_every_patched_field() {
+        let home = TempDir::new().expect("temp dir");
+        let store = test_store(home.path()).await;
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(601).to_string()).expect("valid thread id");
+        store
+            .create_thread(create_params(thread_id, home.path()))
+            .await
+            .expect("create thread");
+        store
+            .append_items(AppendThreadItemsParams {
+                thread_id,
+                items: vec![RolloutItem::EventMsg(EventMsg::UserMessage(
+                    UserMessageEvent {
+                        message: "rename me".to_string(),
+                        images: None,
+                        local_images: Vec::new(),
+                        text_elements: Vec::new(),
+                    },
+                ))],
+            })
+            .await
+            .expect("append items");
+
+        let thread = store
+            .update_thread_metadata(UpdateThreadMetadataParams {
+                thread_id,
+                patch: ThreadMetadataPatch {
+                    name: Some("Release prep".to_string()),
+                    memory_mode: Some(codex_protocol::protocol::ThreadMemoryMode::Disabled),
+                    git_info: Some(GitInfoPatch {
+                        sha: None,
+                        branch: Some(Some("release".to_string())),
+                        origin_url: Some(None),
+                    }),
+                },
+                include_archived: false,
+            })
+            .await
+            .expect("update metadata");
+
+        assert_eq!(thread.name.as_deref(), Some("Release prep"));
+        assert_eq!(
+            thread
+                .git_info
+                .as_ref()
+                .and_then(|info| info.branch.as_deref()),
+            Some("release")
+        );
+        assert_eq!(
+            thread
+                .git_info
+                .as_ref()
+                .and_then(|info| info.repository_url.as_deref()),
+            None
+        );
+        assert_eq!(
+            store
+                .state_db()
+                .await
+                .expect("state db")
+                .get_thread_memory_mode(thread_id)
+                .await
+                .expect("memory mode")
+                .as_deref(),
+            Some("disabled")
+        );
+    }
+
+    #[tokio::test]
+    async fn archiving_an_unknown_thread_fails() {
+        let home = TempDir::new().expect("temp dir");
+        let store = test_store(home.path()).await;
+        let thread_id =
+            ThreadId::from_string(&Uuid::from_u128(602).to_string()).expect("valid thread id");
+
+        let err = store
+            .archive_thread(crate::ArchiveThreadParams { thread_id })
+            .await
+            .expect_err("unknown thread should fail");
+
+        assert!(matches!(err, ThreadStoreError::ThreadNotFound { .. }));
+    }
+}
@@ -16,6 +16,7 @@ use crate::ResumeThreadParams;
 use crate::StoredThread;
 use crate::StoredThreadHistory;
 use crate::ThreadPage;
+use crate::ThreadSearchPage;
 use crate::ThreadStoreError;
 use crate::ThreadStoreResult;
 use crate::TurnPage;
@@ -72,6 +73,20 @@ pub trait ThreadStore: Any + Send + Sync {
     /// Lists stored threads matching the supplied filters.
     async fn list_threads(&self, params: ListThreadsParams) -> ThreadStoreResult<ThreadPage>;
 
+    /// Searches persisted thread content for `params.search_term`.
+    ///
+    /// Matches messages, tool calls, and tool output rather than only the
+    /// title and preview, and applies the remaining filters the same way as
+    /// [`ThreadStore::list_threads`].
+    async fn search_threads(
+        &self,
+        _params: ListThreadsParams,
+    ) -> ThreadStoreResult<ThreadSearchPage> {
+        Err(ThreadStoreError::Unsupported {
+            operation: "search_threads",
+        })
+    }
+
     /// Lists turns within a stored thread.
     async fn list_turns(&self, _params: ListTurnsParams) -> ThreadStoreResult<TurnPage> {
         Err(ThreadStoreError::Unsupported {
@@ -183,6 +183,26 @@ pub struct ThreadPage {
     pub next_cursor: Option<String>,
 }
 
+/// A stored thread whose persisted content matched a search.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadSearchHit {
+    /// The matching thread.
+    pub thread: StoredThread,
+    /// Turn containing the best-ranked match, if known.
+    pub turn_id: Option<String>,
+    /// Excerpt of the best-ranked match.
+    pub snippet: String,
+}
+
+/// A page of thread content search results.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadSearchPage {
+    /// Matching threads returned for this page.
+    pub items: Vec<ThreadSearchHit>,
+    /// Opaque cursor to continue searching.
+    pub next_cursor: Option<String>,
+}
+
 /// Requested amount of item detail for stored turns.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StoredTurnItemsView {
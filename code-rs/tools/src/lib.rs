@@ -80,4 +80,5 @@ pub use tool_spec::ConfiguredToolSpec;
 pub use tool_spec::ResponsesApiWebSearchFilters;
 pub use tool_spec::ResponsesApiWebSearchUserLocation;
 pub use tool_spec::ToolSpec;
+pub use tool_spec::create_function_tools_for_api;
 pub use tool_spec::create_tools_json_for_responses_api;
@@ -2,13 +2,18 @@ use crate::FreeformTool;
 use crate::JsonSchema;
 use crate::LoadableToolSpec;
 use crate::ResponsesApiNamespace;
+use crate::ResponsesApiNamespaceTool;
 use crate::ResponsesApiTool;
+use codex_api::FREEFORM_TOOL_INPUT_PARAM;
+use codex_api::FunctionTool;
+use codex_api::FunctionToolSource;
 use codex_protocol::config_types::WebSearchContextSize;
 use codex_protocol::config_types::WebSearchFilters as ConfigWebSearchFilters;
 use codex_protocol::config_types::WebSearchUserLocation as ConfigWebSearchUserLocation;
 use codex_protocol::config_types::WebSearchUserLocationType;
 use serde::Serialize;
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// When serialized as JSON, this produces a valid "Tool" in the OpenAI
 /// Responses API.
@@ -110,6 +115,83 @@ pub fn create_tools_json_for_responses_api(
     Ok(tools_json)
 }
 
+/// Returns the tools that can be expressed as plain function tools, for wire
+/// APIs without namespaces or freeform tools (Chat Completions, Anthropic
+/// Messages).
+///
+/// Namespaced tools are flattened to one function each. Freeform tools become
+/// functions taking a single string argument, with their grammar appended to
+/// the description. Hosted tools have no function form and are dropped.
+pub fn create_function_tools_for_api(
+    tools: &[ToolSpec],
+) -> Result<Vec<FunctionTool>, serde_json::Error> {
+    let mut function_tools = Vec::new();
+
+    for tool in tools {
+        match tool {
+            ToolSpec::Function(tool) => {
+                function_tools.push(responses_api_tool_to_function_tool(
+                    tool, /*namespace*/ None,
+                )?);
+            }
+            ToolSpec::Namespace(namespace) => {
+                for ResponsesApiNamespaceTool::Function(tool) in &namespace.tools {
+                    function_tools.push(responses_api_tool_to_function_tool(
+                        tool,
+                        Some(namespace.name.clone()),
+                    )?);
+                }
+            }
+            ToolSpec::Freeform(tool) => {
+                function_tools.push(freeform_tool_to_function_tool(tool)?);
+            }
+            ToolSpec::ToolSearch { .. }
+            | ToolSpec::LocalShell {}
+            | ToolSpec::ImageGeneration { .. }
+            | ToolSpec::WebSearch { .. } => {}
+        }
+    }
+
+    Ok(function_tools)
+}
+
+fn responses_api_tool_to_function_tool(
+    tool: &ResponsesApiTool,
+    namespace: Option<String>,
+) -> Result<FunctionTool, serde_json::Error> {
+    Ok(FunctionTool {
+        source: FunctionToolSource::Function {
+            namespace,
+            name: tool.name.clone(),
+        },
+        description: tool.description.clone(),
+        parameters: serde_json::to_value(&tool.parameters)?,
+        strict: tool.strict,
+    })
+}
+
+fn freeform_tool_to_function_tool(tool: &FreeformTool) -> Result<FunctionTool, serde_json::Error> {
+    let parameters = JsonSchema::object(
+        BTreeMap::from([(
+            FREEFORM_TOOL_INPUT_PARAM.to_string(),
+            JsonSchema::string(/*description*/ None),
+        )]),
+        Some(vec![FREEFORM_TOOL_INPUT_PARAM.to_string()]),
+        Some(false.into()),
+    );
+    Ok(FunctionTool {
+        source: FunctionToolSource::Freeform {
+            name: tool.name.clone(),
+        },
+        description: format!(
+            "{}\n\nThe `{FREEFORM_TOOL_INPUT_PARAM}` argument must match this grammar:\n{}",
+            tool.description, tool.format.definition
+        ),
+        parameters: serde_json::to_value(parameters)?,
+        strict: false,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct ResponsesApiWebSearchFilters {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -9,7 +9,10 @@ use crate::FreeformToolFormat;
 use crate::JsonSchema;
 use crate::ResponsesApiNamespaceTool;
 use crate::ResponsesApiTool;
+use crate::create_function_tools_for_api;
 use crate::create_tools_json_for_responses_api;
+use codex_api::FunctionTool;
+use codex_api::FunctionToolSource;
 use codex_protocol::config_types::WebSearchContextSize;
 use codex_protocol::config_types::WebSearchFilters as ConfigWebSearchFilters;
 use codex_protocol::config_types::WebSearchUserLocation as ConfigWebSearchUserLocation;
@@ -291,3 +294,90 @@ fn tool_search_tool_spec_serializes_expected_wire_shape() {
         })
     );
 }
+
+#[test]
+fn create_function_tools_for_api_flattens_namespaces_and_freeform_tools() {
+    let parameters = JsonSchema::object(
+        BTreeMap::new(),
+        /*required*/ None,
+        /*additional_properties*/ None,
+    );
+    let search = ResponsesApiTool {
+        name: "search".to_string(),
+        description: "search docs".to_string(),
+        strict: false,
+        defer_loading: None,
+        parameters: parameters.clone(),
+        output_schema: None,
+    };
+    let tools = vec![
+        ToolSpec::Function(ResponsesApiTool {
+            name: "shell".to_string(),
+            description: "run a command".to_string(),
+            strict: true,
+            defer_loading: None,
+            parameters,
+            output_schema: None,
+        }),
+        ToolSpec::Namespace(ResponsesApiNamespace {
+            name: "mcp__docs__".to_string(),
+            description: "docs".to_string(),
+            tools: vec![ResponsesApiNamespaceTool::Function(search)],
+        }),
+        ToolSpec::Freeform(FreeformTool {
+            name: "apply_patch".to_string(),
+            description: "edit files".to_string(),
+            format: FreeformToolFormat {
+                r#type: "grammar".to_string(),
+                syntax: "lark".to_string(),
+                definition: "start: patch".to_string(),
+            },
+        }),
+        ToolSpec::WebSearch {
+            external_web_access: None,
+            filters: None,
+            user_location: None,
+            search_context_size: None,
+            search_content_types: None,
+        },
+    ];
+
+    assert_eq!(
+        create_function_tools_for_api(&tools).expect("function tools"),
+        vec![
+            FunctionTool {
+                source: FunctionToolSource::Function {
+                    namespace: None,
+                    name: "shell".to_string(),
+                },
+                description: "run a command".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: true,
+            },
+            FunctionTool {
+                source: FunctionToolSource::Function {
+                    namespace: Some("mcp__docs__".to_string()),
+                    name: "search".to_string(),
+                },
+                description: "search docs".to_string(),
+                parameters: json!({ "type": "object", "properties": {} }),
+                strict: false,
+            },
+            FunctionTool {
+                source: FunctionToolSource::Freeform {
+                    name: "apply_patch".to_string(),
+                },
+                description:
+                    "edit files\n\nThe `input` argument must match this grammar:\nstart: patch"
+                        .to_string(),
+                parameters: json!({
+                    "type": "object",
+                    "properties": { "input": { "type": "string" } },
+                    "required": ["input"],
+                    "additionalProperties": false,
+                }),
+                strict: false,
+            },
+        ]
+    );
+}
@@ -140,6 +140,15 @@ pub(super) fn server_notification_thread_target(
         }
         ServerNotification::Warning(notification) => notification.thread_id.as_deref(),
         ServerNotification::GuardianWarning(notification) => Some(notification.thread_id.as_str()),
+        ServerNotification::McpServerPromptListChanged(notification) => {
+            Some(notification.thread_id.as_str())
+        }
+        ServerNotification::McpServerToolListChanged(notification) => {
+            Some(notification.thread_id.as_str())
+        }
+        ServerNotification::McpServerResourceListChanged(notification) => {
+            Some(notification.thread_id.as_str())
+        }
         ServerNotification::SkillsChanged(_)
         | ServerNotification::McpServerStatusUpdated(_)
         | ServerNotification::McpServerOauthLoginCompleted(_)
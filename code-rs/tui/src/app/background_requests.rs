@@ -1,6 +1,6 @@
 //! Background app-server requests launched by the TUI app.
 //!
-//! This module owns fire-and-forget fetch/write helpers for MCP inventory, skills, plugins, rate
+//! This module owns fire-and-forget fetch/write helpers for MCP inventory and prompts, skills, plugins, rate
 //! limits, add-credit nudges, and feedback uploads. Results are routed back through `AppEvent` so
 //! the main event loop remains single-threaded.
 
@@ -12,6 +12,11 @@ use codex_app_server_protocol::MarketplaceRemoveParams;
 use codex_app_server_protocol::MarketplaceRemoveResponse;
 use codex_app_server_protocol::MarketplaceUpgradeParams;
 use codex_app_server_protocol::MarketplaceUpgradeResponse;
+use codex_app_server_protocol::McpServerPromptGetParams;
+use codex_app_server_protocol::McpServerPromptGetResponse;
+use codex_app_server_protocol::McpServerPromptListParams;
+use codex_app_server_protocol::McpServerPromptListResponse;
+use codex_app_server_protocol::McpServerPrompts;
 
 use codex_app_server_protocol::RequestId;
 
@@ -33,6 +38,40 @@ impl App {
         });
     }
 
+    pub(super) fn fetch_mcp_prompts(&mut self, app_server: &AppServerSession, thread_id: ThreadId) {
+        let request_handle = app_server.request_handle();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_mcp_prompts(request_handle, thread_id)
+                .await
+                .map_err(|err| err.to_string());
+            app_event_tx.send(AppEvent::McpPromptsLoaded { thread_id, result });
+        });
+    }
+
+    pub(super) fn run_mcp_prompt(
+        &mut self,
+        app_server: &AppServerSession,
+        thread_id: ThreadId,
+        server: String,
+        name: String,
+        arguments: HashMap<String, String>,
+    ) {
+        let request_handle = app_server.request_handle();
+        let app_event_tx = self.app_event_tx.clone();
+        let command_name = format!("{server}:{name}");
+        tokio::spawn(async move {
+            let result = get_mcp_prompt(request_handle, thread_id, server, name, arguments)
+                .await
+                .map_err(|err| format!("{err:#}"));
+            app_event_tx.send(AppEvent::McpPromptLoaded {
+                thread_id,
+                command_name,
+                result,
+            });
+        });
+    }
+
     /// Spawns a background task to fetch account rate limits and deliver the
     /// result as a `RateLimitsLoaded` event.
     ///
@@ -604,6 +643,45 @@ pub(super) async fn fetch_all_mcp_server_statuses(
     Ok(statuses)
 }
 
+async fn fetch_mcp_prompts(
+    request_handle: AppServerRequestHandle,
+    thread_id: ThreadId,
+) -> Result<Vec<McpServerPrompts>> {
+    let request_id = RequestId::String(format!("mcp-prompts-{}", Uuid::new_v4()));
+    let response: McpServerPromptListResponse = request_handle
+        .request_typed(ClientRequest::McpServerPromptList {
+            request_id,
+            params: McpServerPromptListParams {
+                thread_id: thread_id.to_string(),
+            },
+        })
+        .await
+        .wrap_err("mcpServer/prompt/list failed in TUI")?;
+    Ok(response.data)
+}
+
+async fn get_mcp_prompt(
+    request_handle: AppServerRequestHandle,
+    thread_id: ThreadId,
+    server: String,
+    name: String,
+    arguments: HashMap<String, String>,
+) -> Result<McpServerPromptGetResponse> {
+    let request_id = RequestId::String(format!("mcp-prompt-get-{}", Uuid::new_v4()));
+    request_handle
+        .request_typed(ClientRequest::McpServerPromptGet {
+            request_id,
+            params: McpServerPromptGetParams {
+                thread_id: thread_id.to_string(),
+                server,
+                name,
+                arguments: Some(arguments),
+            },
+        })
+        .await
+        .wrap_err("mcpServer/prompt/get failed in TUI")
+}
+
 pub(super) async fn fetch_account_rate_limits(
     request_handle: AppServerRequestHandle,
 ) -> Result<Vec<RateLimitSnapshot>> {
@@ -1059,6 +1137,7 @@ mod tests {
                 )]),
                 resources: Vec::new(),
                 resource_templates: Vec::new(),
+                prompts: Vec::new(),
                 auth_status: codex_app_server_protocol::McpAuthStatus::Unsupported,
             },
             McpServerStatus {
@@ -1066,6 +1145,7 @@ mod tests {
                 tools: HashMap::new(),
                 resources: Vec::new(),
                 resource_templates: Vec::new(),
+                prompts: Vec::new(),
                 auth_status: codex_app_server_protocol::McpAuthStatus::Unsupported,
             },
         ];
@@ -654,6 +654,34 @@ impl App {
             AppEvent::McpInventoryLoaded { result, detail } => {
                 self.handle_mcp_inventory_result(result, detail);
             }
+            AppEvent::FetchMcpPrompts { thread_id } => {
+                self.fetch_mcp_prompts(app_server, thread_id);
+            }
+            AppEvent::McpPromptsLoaded { thread_id, result } => {
+                if self.chat_widget.thread_id() == Some(thread_id) {
+                    match result {
+                        Ok(data) => self.chat_widget.set_mcp_prompts(data),
+                        Err(err) => tracing::warn!("failed to load MCP prompts: {err}"),
+                    }
+                }
+            }
+            AppEvent::RunMcpPrompt {
+                thread_id,
+                server,
+                name,
+                arguments,
+            } => {
+                self.run_mcp_prompt(app_server, thread_id, server, name, arguments);
+            }
+            AppEvent::McpPromptLoaded {
+                thread_id,
+                command_name,
+                result,
+            } => {
+                if self.chat_widget.thread_id() == Some(thread_id) {
+                    self.chat_widget.on_mcp_prompt_loaded(command_name, result);
+                }
+            }
             AppEvent::SkillsListLoaded { result } => {
                 self.handle_skills_list_result(
                     result.map_err(|err| color_eyre::eyre::eyre!(err)),
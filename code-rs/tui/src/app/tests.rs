@@ -116,6 +116,7 @@ async fn handle_mcp_inventory_result_clears_committed_loading_cell() {
             tools: HashMap::new(),
             resources: Vec::new(),
             resource_templates: Vec::new(),
+            prompts: Vec::new(),
             auth_status: codex_app_server_protocol::McpAuthStatus::Unsupported,
         }]),
         McpServerStatusDetail::ToolsAndAuthOnly,
@@ -2662,7 +2663,7 @@ async fn inactive_thread_file_change_approval_recovers_buffered_changes() {
                 id: "patch-approval".to_string(),
                 changes: vec![FileUpdateChange {
                     path: "README.md".to_string(),
-                    kind: PatchChangeKind::Add,
+                    kind: PatchChangeKind::Add { executable: None },
                     diff: "hello\n".to_string(),
                 }],
                 status: codex_app_server_protocol::PatchApplyStatus::InProgress,
@@ -2701,6 +2702,7 @@ async fn inactive_thread_file_change_approval_recovers_buffered_changes() {
             PathBuf::from("README.md"),
             FileChange::Add {
                 content: "hello\n".to_string(),
+                executable: None,
             },
         )])
     );
@@ -8,6 +8,7 @@
 //! Exit is modelled explicitly via `AppEvent::Exit(ExitMode)` so callers can request shutdown-first
 //! quits without reaching into the app loop or coupling to shutdown/exit sequencing.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use codex_app_server_protocol::AddCreditsNudgeCreditType;
@@ -16,6 +17,8 @@ use codex_app_server_protocol::AppInfo;
 use codex_app_server_protocol::MarketplaceAddResponse;
 use codex_app_server_protocol::MarketplaceRemoveResponse;
 use codex_app_server_protocol::MarketplaceUpgradeResponse;
+use codex_app_server_protocol::McpServerPromptGetResponse;
+use codex_app_server_protocol::McpServerPrompts;
 use codex_app_server_protocol::McpServerStatus;
 use codex_app_server_protocol::McpServerStatusDetail;
 use codex_app_server_protocol::PluginInstallResponse;
@@ -490,6 +493,32 @@ pub(crate) enum AppEvent {
         detail: McpServerStatusDetail,
     },
 
+    /// Fetch the MCP prompts offered to a thread via app-server RPCs.
+    FetchMcpPrompts {
+        thread_id: ThreadId,
+    },
+
+    /// Result of fetching a thread's MCP prompts.
+    McpPromptsLoaded {
+        thread_id: ThreadId,
+        result: Result<Vec<McpServerPrompts>, String>,
+    },
+
+    /// Render an MCP prompt with the given arguments and submit its text.
+    RunMcpPrompt {
+        thread_id: ThreadId,
+        server: String,
+        name: String,
+        arguments: HashMap<String, String>,
+    },
+
+    /// Result of rendering an MCP prompt for `/server:prompt`.
+    McpPromptLoaded {
+        thread_id: ThreadId,
+        command_name: String,
+        result: Result<McpServerPromptGetResponse, String>,
+    },
+
     /// Result of the startup skills refresh that runs after the first frame is scheduled.
     ///
     /// This event is startup-only. Interactive skills refreshes are handled synchronously through the app
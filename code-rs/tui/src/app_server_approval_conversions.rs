@@ -33,16 +33,24 @@ pub(crate) fn file_update_changes_to_display(
         .map(|change| {
             let path = PathBuf::from(change.path);
             let file_change = match change.kind {
-                PatchChangeKind::Add => FileChange::Add {
+                PatchChangeKind::Add { executable } => FileChange::Add {
                     content: change.diff,
+                    executable,
                 },
                 PatchChangeKind::Delete => FileChange::Delete {
                     content: change.diff,
                 },
-                PatchChangeKind::Update { move_path } => FileChange::Update {
+                PatchChangeKind::Update {
+                    move_path,
+                    executable,
+                } => FileChange::Update {
                     unified_diff: change.diff,
                     move_path,
+                    executable,
                 },
+                PatchChangeKind::SetMode { executable } => FileChange::SetMode { executable },
+                PatchChangeKind::AddSymlink { target } => FileChange::AddSymlink { target },
+                PatchChangeKind::AddBinary { size } => FileChange::AddBinary { size },
             };
             (path, file_change)
         })
@@ -71,13 +79,14 @@ mod tests {
         assert_eq!(
             file_update_changes_to_display(vec![FileUpdateChange {
                 path: "foo.txt".to_string(),
-                kind: PatchChangeKind::Add,
+                kind: PatchChangeKind::Add { executable: None },
                 diff: "hello\n".to_string(),
             }]),
             HashMap::from([(
                 PathBuf::from("foo.txt"),
                 FileChange::Add {
                     content: "hello\n".to_string(),
+                    executable: None,
                 },
             )])
         );
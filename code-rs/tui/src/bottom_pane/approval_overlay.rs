@@ -1973,6 +1973,7 @@ mod tests {
             PathBuf::from("bug1.txt"),
             FileChange::Add {
                 content: "one\ntwo\nthree\n".to_string(),
+                executable: None,
             },
         );
         let request = ApprovalRequest::ApplyPatch {
@@ -162,6 +162,7 @@ use super::chat_composer_history::HistoryEntryResponse;
 use super::command_popup::CommandItem;
 use super::command_popup::CommandPopup;
 use super::command_popup::CommandPopupFlags;
+use super::command_popup::McpPromptCommand;
 use super::file_search_popup::FileSearchPopup;
 use super::footer::CollaborationModeIndicator;
 use super::footer::FooterKeyHints;
@@ -282,6 +283,11 @@ pub enum InputResult {
     /// command-history entry still represents the original command invocation that should be
     /// committed only if dispatch accepts it.
     CommandWithArgs(SlashCommand, String, Vec<TextElement>),
+    /// An MCP prompt invoked as `/server:prompt`, with its raw argument text.
+    McpPrompt {
+        prompt: McpPromptCommand,
+        args: String,
+    },
     None,
 }
 
@@ -387,6 +393,7 @@ pub(crate) struct ChatComposer {
     next_element_id: u64,
     context_window_used_tokens: Option<i64>,
     skills: Option<Vec<SkillMetadata>>,
+    mcp_prompts: Vec<McpPromptCommand>,
     plugins: Option<Vec<PluginCapabilitySummary>>,
     connectors_snapshot: Option<ConnectorsSnapshot>,
     dismissed_mention_popup_token: Option<String>,
@@ -568,6 +575,7 @@ impl ChatComposer {
             next_element_id: 0,
             context_window_used_tokens: None,
             skills: None,
+            mcp_prompts: Vec::new(),
             plugins: None,
             connectors_snapshot: None,
             dismissed_mention_popup_token: None,
@@ -651,6 +659,20 @@ impl ChatComposer {
         self.sync_popups();
     }
 
+    /// Replace the MCP prompts offered as `/server:prompt` slash commands.
+    pub(crate) fn set_mcp_prompts(&mut self, mcp_prompts: Vec<McpPromptCommand>) {
+        if let ActivePopup::Command(popup) = &mut self.active_popup {
+            popup.set_mcp_prompts(mcp_prompts.clone());
+        }
+        self.mcp_prompts = mcp_prompts;
+    }
+
+    fn find_mcp_prompt(&self, name: &str) -> Option<&McpPromptCommand> {
+        self.mcp_prompts
+            .iter()
+            .find(|mcp_prompt| mcp_prompt.command_name() == name)
+    }
+
     pub fn set_plugins_command_enabled(&mut self, enabled: bool) {
         self.plugins_command_enabled = enabled;
     }
@@ -1762,10 +1784,21 @@ impl ChatComposer {
                 // before applying completion.
                 let first_line = self.textarea.text().lines().next().unwrap_or("");
                 popup.on_composer_text_change(first_line.to_string());
-                let selected_cmd = popup.selected_item().map(|sel| {
-                    let CommandItem::Builtin(cmd) = sel;
-                    cmd
-                });
+                let selected_cmd = match popup.selected_item() {
+                    Some(CommandItem::Builtin(cmd)) => Some(cmd),
+                    Some(CommandItem::McpPrompt(idx)) => {
+                        if let Some(mcp_prompt) = popup.mcp_prompt(idx) {
+                            let command_text = format!("/{} ", mcp_prompt.command_name());
+                            if !first_line.trim_start().starts_with(command_text.trim_end()) {
+                                self.textarea.set_text_clearing_elements(&command_text);
+                                self.textarea.set_cursor(self.textarea.text().len());
+                                return (InputResult::None, true);
+                            }
+                        }
+                        None
+                    }
+                    None => None,
+                };
                 if let Some(cmd) = selected_cmd {
                     if cmd == SlashCommand::Skills {
                         self.stage_selected_slash_command_history(cmd);
@@ -1800,17 +1833,18 @@ impl ChatComposer {
                 // while the slash-command popup is active.
                 let first_line = self.textarea.text().lines().next().unwrap_or("");
                 popup.on_composer_text_change(first_line.to_string());
-                let selected_cmd = popup.selected_item().map(|sel| {
-                    let CommandItem::Builtin(cmd) = sel;
-                    cmd
-                });
-                if let Some(cmd) = selected_cmd {
-                    let starts_with_cmd = first_line
-                        .trim_start()
-                        .starts_with(&format!("/{}", cmd.command()));
+                let selected_name = match popup.selected_item() {
+                    Some(CommandItem::Builtin(cmd)) => Some(cmd.command().to_string()),
+                    Some(CommandItem::McpPrompt(idx)) => {
+                        popup.mcp_prompt(idx).map(McpPromptCommand::command_name)
+                    }
+                    None => None,
+                };
+                if let Some(name) = selected_name {
+                    let starts_with_cmd = first_line.trim_start().starts_with(&format!("/{name}"));
                     if !starts_with_cmd {
                         self.textarea
-                            .set_text_clearing_elements(&format!("/{} ", cmd.command()));
+                            .set_text_clearing_elements(&format!("/{name} "));
                         self.is_bash_mode = false;
                     }
                     if !self.textarea.text().is_empty() {
@@ -1824,12 +1858,25 @@ impl ChatComposer {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => {
-                if let Some(sel) = popup.selected_item() {
-                    let CommandItem::Builtin(cmd) = sel;
-                    self.stage_selected_slash_command_history(cmd);
-                    self.textarea.set_text_clearing_elements("");
-                    self.is_bash_mode = false;
-                    return (InputResult::Command(cmd), true);
+                match popup.selected_item() {
+                    Some(CommandItem::Builtin(cmd)) => {
+                        self.stage_selected_slash_command_history(cmd);
+                        self.textarea.set_text_clearing_elements("");
+                        self.is_bash_mode = false;
+                        return (InputResult::Command(cmd), true);
+                    }
+                    Some(CommandItem::McpPrompt(idx)) => {
+                        if let Some(mcp_prompt) = popup.mcp_prompt(idx) {
+                            let result = InputResult::McpPrompt {
+                                prompt: mcp_prompt.clone(),
+                                args: String::new(),
+                            };
+                            self.textarea.set_text_clearing_elements("");
+                            self.is_bash_mode = false;
+                            return (result, true);
+                        }
+                    }
+                    None => {}
                 }
                 // Fallback to default newline handling if no command selected.
                 self.handle_key_event_without_popup(key_event)
@@ -2706,6 +2753,7 @@ impl ChatComposer {
                 | InputResult::Queued { .. }
                 | InputResult::Command(_)
                 | InputResult::CommandWithArgs(_, _, _)
+                | InputResult::McpPrompt { .. }
         ) {
             self.textarea.enter_vim_normal_mode();
         }
@@ -2717,6 +2765,9 @@ impl ChatComposer {
         now: Instant,
     ) -> (InputResult, bool) {
         if should_queue {
+            if let Some(result) = self.try_dispatch_mcp_prompt() {
+                return (result, true);
+            }
             let raw_text = self.textarea.text();
             let defer_slash_validation =
                 self.should_parse_as_slash_on_dequeue_from_raw_text(raw_text);
@@ -2751,6 +2802,9 @@ impl ChatComposer {
         if let Some(result) = self.try_dispatch_bare_slash_command() {
             return (result, true);
         }
+        if let Some(result) = self.try_dispatch_mcp_prompt() {
+            return (result, true);
+        }
 
         // If we're in a paste-like burst capture, treat Enter/Ctrl+Shift+Q as part of the burst
         // and accumulate it rather than submitting or inserting immediately.
@@ -2862,6 +2916,27 @@ impl ChatComposer {
         Some(InputResult::Command(cmd))
     }
 
+    /// Check if the input invokes an MCP prompt (`/server:prompt key=value ...`) and dispatch it.
+    /// Returns Some(InputResult) if a prompt was dispatched, None otherwise.
+    fn try_dispatch_mcp_prompt(&mut self) -> Option<InputResult> {
+        if !self.slash_commands_enabled() || self.is_bash_mode {
+            return None;
+        }
+        let text = self.textarea.text().to_string();
+        if text.starts_with(' ') {
+            return None;
+        }
+        let (name, rest, _rest_offset) = parse_slash_name(&text)?;
+        let mcp_prompt = self.find_mcp_prompt(name)?;
+        let result = InputResult::McpPrompt {
+            prompt: mcp_prompt.clone(),
+            args: rest.trim().to_string(),
+        };
+        self.textarea.set_text_clearing_elements("");
+        self.is_bash_mode = false;
+        Some(result)
+    }
+
     /// Check if the input is a slash command with args (e.g., /review args) and dispatch it.
     /// Returns Some(InputResult) if a command was dispatched, None otherwise.
     fn try_dispatch_slash_command_with_args(&mut self) -> Option<InputResult> {
@@ -3739,6 +3814,7 @@ impl ChatComposer {
 
     fn is_known_slash_name(&self, name: &str) -> bool {
         slash_commands::find_builtin_command(name, self.builtin_command_flags()).is_some()
+            || self.find_mcp_prompt(name).is_some()
     }
 
     /// If the cursor is currently within a slash command on the first line,
@@ -3843,6 +3919,7 @@ impl ChatComposer {
                         windows_degraded_sandbox_active: self.windows_degraded_sandbox_active,
                         side_conversation_active: self.side_conversation_active,
                     });
+                    command_popup.set_mcp_prompts(self.mcp_prompts.clone());
                     command_popup.on_composer_text_change(first_line.to_string());
                     self.active_popup = ActivePopup::Command(command_popup);
                 }
@@ -5545,6 +5622,47 @@ mod tests {
         assert!(matches!(result, InputResult::Command(SlashCommand::Diff)));
     }
 
+    #[test]
+    fn mcp_prompt_with_inline_args_dispatches_as_mcp_prompt() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            /*has_input_focus*/ true,
+            sender,
+            /*enhanced_keys_supported*/ true,
+            "Ask Codex to do anything".to_string(),
+            /*disable_paste_burst*/ true,
+        );
+        composer.set_mcp_prompts(vec![McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: codex_protocol::mcp::Prompt {
+                name: "summarize".to_string(),
+                title: None,
+                description: None,
+                arguments: None,
+            },
+        }]);
+
+        composer
+            .textarea
+            .set_text_clearing_elements("/docs:summarize topic=notes");
+        let (result, _needs_redraw) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(composer.is_empty());
+        match result {
+            InputResult::McpPrompt { prompt, args } => {
+                assert_eq!(prompt.command_name(), "docs:summarize");
+                assert_eq!(args, "topic=notes");
+            }
+            other => panic!("expected MCP prompt dispatch, got {other:?}"),
+        }
+    }
+
     #[test]
     fn inline_slash_command_dispatch_resets_vim_mode_to_normal() {
         use crossterm::event::KeyCode;
@@ -13,6 +13,7 @@ use super::slash_commands;
 use crate::render::Insets;
 use crate::render::RectExt;
 use crate::slash_command::SlashCommand;
+use codex_protocol::mcp::Prompt;
 
 // Hide alias commands in the default popup list so each unique action appears once.
 // `quit` is an alias of `exit`, so we skip `quit` here.
@@ -26,11 +27,36 @@ const COMMAND_COLUMN_WIDTH: ColumnWidthConfig = ColumnWidthConfig::new(
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum CommandItem {
     Builtin(SlashCommand),
+    /// Index into the popup's MCP prompt list.
+    McpPrompt(usize),
+}
+
+/// An MCP prompt offered as a `/server:prompt` slash command.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct McpPromptCommand {
+    pub(crate) server: String,
+    pub(crate) prompt: Prompt,
+}
+
+impl McpPromptCommand {
+    /// The command name typed after the leading `/`.
+    pub(crate) fn command_name(&self) -> String {
+        format!("{}:{}", self.server, self.prompt.name)
+    }
+
+    fn description(&self) -> String {
+        self.prompt
+            .description
+            .clone()
+            .or_else(|| self.prompt.title.clone())
+            .unwrap_or_else(|| format!("run prompt from {}", self.server))
+    }
 }
 
 pub(crate) struct CommandPopup {
     command_filter: String,
     builtins: Vec<(&'static str, SlashCommand)>,
+    mcp_prompts: Vec<McpPromptCommand>,
     state: ScrollState,
 }
 
@@ -77,10 +103,24 @@ impl CommandPopup {
         Self {
             command_filter: String::new(),
             builtins,
+            mcp_prompts: Vec::new(),
             state: ScrollState::new(),
         }
     }
 
+    /// Replace the MCP prompts listed after the built-in commands.
+    pub(crate) fn set_mcp_prompts(&mut self, mcp_prompts: Vec<McpPromptCommand>) {
+        self.mcp_prompts = mcp_prompts;
+        let matches_len = self.filtered_items().len();
+        self.state.clamp_selection(matches_len);
+        self.state
+            .ensure_visible(matches_len, MAX_POPUP_ROWS.min(matches_len));
+    }
+
+    pub(crate) fn mcp_prompt(&self, idx: usize) -> Option<&McpPromptCommand> {
+        self.mcp_prompts.get(idx)
+    }
+
     /// Update the filter string based on the current composer text. The text
     /// passed in is expected to start with a leading '/'. Everything after the
     /// *first* '/' on the *first* line becomes the active filter that is used
@@ -139,6 +179,9 @@ impl CommandPopup {
                 }
                 out.push((CommandItem::Builtin(*cmd), None));
             }
+            for idx in 0..self.mcp_prompts.len() {
+                out.push((CommandItem::McpPrompt(idx), None));
+            }
             return out;
         }
 
@@ -172,6 +215,15 @@ impl CommandPopup {
         for (_, cmd) in self.builtins.iter() {
             push_match(CommandItem::Builtin(*cmd), cmd.command(), None, 0);
         }
+        // MCP prompts match on either `server:prompt` or the bare prompt name.
+        for (idx, mcp_prompt) in self.mcp_prompts.iter().enumerate() {
+            push_match(
+                CommandItem::McpPrompt(idx),
+                &mcp_prompt.command_name(),
+                Some(&mcp_prompt.prompt.name),
+                mcp_prompt.server.chars().count() + 1,
+            );
+        }
 
         out.extend(exact);
         out.extend(prefix);
@@ -188,11 +240,20 @@ impl CommandPopup {
     ) -> Vec<GenericDisplayRow> {
         matches
             .into_iter()
-            .map(|(item, indices)| {
-                let CommandItem::Builtin(cmd) = item;
-                let name = format!("/{}", cmd.command());
-                let description = cmd.description().to_string();
-                GenericDisplayRow {
+            .filter_map(|(item, indices)| {
+                let (name, description) = match item {
+                    CommandItem::Builtin(cmd) => {
+                        (format!("/{}", cmd.command()), cmd.description().to_string())
+                    }
+                    CommandItem::McpPrompt(idx) => {
+                        let mcp_prompt = self.mcp_prompts.get(idx)?;
+                        (
+                            format!("/{}", mcp_prompt.command_name()),
+                            mcp_prompt.description(),
+                        )
+                    }
+                };
+                Some(GenericDisplayRow {
                     name,
                     name_prefix_spans: Vec::new(),
                     match_indices: indices.map(|v| v.into_iter().map(|i| i + 1).collect()),
@@ -202,7 +263,7 @@ impl CommandPopup {
                     wrap_indent: None,
                     is_disabled: false,
                     disabled_reason: None,
-                }
+                })
             })
             .collect()
     }
@@ -265,6 +326,7 @@ mod tests {
         let matches = popup.filtered_items();
         let has_init = matches.iter().any(|item| match item {
             CommandItem::Builtin(cmd) => cmd.command() == "init",
+            CommandItem::McpPrompt(_) => false,
         });
         assert!(
             has_init,
@@ -282,7 +344,7 @@ mod tests {
         let selected = popup.selected_item();
         match selected {
             Some(CommandItem::Builtin(cmd)) => assert_eq!(cmd.command(), "init"),
-            None => panic!("expected a selected command for exact match"),
+            other => panic!("expected a selected command for exact match, got {other:?}"),
         }
     }
 
@@ -293,7 +355,7 @@ mod tests {
         let matches = popup.filtered_items();
         match matches.first() {
             Some(CommandItem::Builtin(cmd)) => assert_eq!(cmd.command(), "model"),
-            None => panic!("expected at least one match for '/mo'"),
+            other => panic!("expected '/model' as the first match for '/mo', got {other:?}"),
         }
     }
 
@@ -305,8 +367,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert_eq!(cmds, vec!["model", "memories", "mention", "mcp"]);
@@ -320,8 +383,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -350,8 +414,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -427,8 +492,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -478,8 +544,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
 
@@ -495,8 +562,9 @@ mod tests {
         let cmds: Vec<&str> = popup
             .filtered_items()
             .into_iter()
-            .map(|item| match item {
-                CommandItem::Builtin(cmd) => cmd.command(),
+            .filter_map(|item| match item {
+                CommandItem::Builtin(cmd) => Some(cmd.command()),
+                CommandItem::McpPrompt(_) => None,
             })
             .collect();
 
@@ -505,4 +573,48 @@ mod tests {
             "expected no /debug* command in popup menu, got {cmds:?}"
         );
     }
+
+    fn mcp_prompt_command(server: &str, name: &str) -> McpPromptCommand {
+        McpPromptCommand {
+            server: server.to_string(),
+            prompt: Prompt {
+                name: name.to_string(),
+                title: None,
+                description: Some(format!("{name} prompt")),
+                arguments: None,
+            },
+        }
+    }
+
+    #[test]
+    fn mcp_prompts_follow_builtins_in_empty_filter() {
+        let mut popup = CommandPopup::new(CommandPopupFlags::default());
+        popup.set_mcp_prompts(vec![mcp_prompt_command("docs", "summarize")]);
+        popup.on_composer_text_change("/".to_string());
+
+        let items = popup.filtered_items();
+        assert_eq!(items.last(), Some(&CommandItem::McpPrompt(0)));
+    }
+
+    #[test]
+    fn mcp_prompt_matches_server_prefix_and_prompt_name() {
+        let mut popup = CommandPopup::new(CommandPopupFlags::default());
+        popup.set_mcp_prompts(vec![
+            mcp_prompt_command("docs", "summarize"),
+            mcp_prompt_command("github", "triage"),
+        ]);
+
+        popup.on_composer_text_change("/docs:".to_string());
+        assert_eq!(popup.filtered_items(), vec![CommandItem::McpPrompt(0)]);
+
+        popup.on_composer_text_change("/triage".to_string());
+        assert_eq!(popup.selected_item(), Some(CommandItem::McpPrompt(1)));
+        assert_eq!(
+            popup
+                .mcp_prompt(1)
+                .map(McpPromptCommand::command_name)
+                .as_deref(),
+            Some("github:triage")
+        );
+    }
 }
@@ -667,6 +667,10 @@ fn event_label(event_name: HookEventName) -> &'static str {
         HookEventName::SessionStart => "SessionStart",
         HookEventName::UserPromptSubmit => "UserPromptSubmit",
         HookEventName::Stop => "Stop",
+        HookEventName::SessionEnd => "SessionEnd",
+        HookEventName::SubagentStart => "SubagentStart",
+        HookEventName::SubagentStop => "SubagentStop",
+        HookEventName::Notification => "Notification",
     }
 }
 
@@ -680,6 +684,10 @@ fn event_description(event_name: HookEventName) -> &'static str {
         HookEventName::SessionStart => "When a new session starts",
         HookEventName::UserPromptSubmit => "When the user submits a prompt",
         HookEventName::Stop => "Right before Codex ends its turn",
+        HookEventName::SessionEnd => "When the session shuts down",
+        HookEventName::SubagentStart => "When a sub-agent is spawned",
+        HookEventName::SubagentStop => "When a sub-agent finishes",
+        HookEventName::Notification => "When Codex needs the user's attention",
     }
 }
 
@@ -13,6 +13,7 @@ use codex_app_server_protocol::McpServerElicitationRequestParams;
 use codex_app_server_protocol::RequestId as AppServerRequestId;
 use codex_protocol::ThreadId;
 use codex_protocol::mcp_approval_meta::APPROVAL_KIND_KEY as APPROVAL_META_KIND_KEY;
+use codex_protocol::mcp_approval_meta::APPROVAL_KIND_MCP_SAMPLING as APPROVAL_META_KIND_MCP_SAMPLING;
 use codex_protocol::mcp_approval_meta::APPROVAL_KIND_MCP_TOOL_CALL as APPROVAL_META_KIND_MCP_TOOL_CALL;
 use codex_protocol::mcp_approval_meta::APPROVAL_KIND_TOOL_SUGGESTION as APPROVAL_META_KIND_TOOL_SUGGESTION;
 use codex_protocol::mcp_approval_meta::PERSIST_ALWAYS as APPROVAL_PERSIST_ALWAYS_VALUE;
@@ -241,12 +242,13 @@ impl McpServerElicitationFormRequest {
         requested_schema: Value,
     ) -> Option<Self> {
         let tool_suggestion = parse_tool_suggestion_request(meta.as_ref());
-        let is_tool_approval = meta
+        let approval_kind = meta
             .as_ref()
             .and_then(Value::as_object)
             .and_then(|meta| meta.get(APPROVAL_META_KIND_KEY))
-            .and_then(Value::as_str)
-            == Some(APPROVAL_META_KIND_MCP_TOOL_CALL);
+            .and_then(Value::as_str);
+        let is_tool_approval = approval_kind == Some(APPROVAL_META_KIND_MCP_TOOL_CALL);
+        let is_sampling_approval = approval_kind == Some(APPROVAL_META_KIND_MCP_SAMPLING);
         let is_empty_object_schema = requested_schema.as_object().is_some_and(|schema| {
             schema.get("type").and_then(Value::as_str) == Some("object")
                 && schema
@@ -256,6 +258,7 @@ impl McpServerElicitationFormRequest {
         });
         let is_message_only_schema = requested_schema.is_null() || is_empty_object_schema;
         let is_tool_approval_action = is_tool_approval && is_message_only_schema;
+        let is_sampling_approval_action = is_sampling_approval && is_message_only_schema;
         let approval_display_params = if is_tool_approval_action {
             parse_tool_approval_display_params(meta.as_ref())
         } else {
@@ -267,6 +270,8 @@ impl McpServerElicitationFormRequest {
         } else if is_message_only_schema {
             let allow_description = if is_tool_approval_action {
                 "Run the tool and continue."
+            } else if is_sampling_approval_action {
+                "Run the model request for the server and continue."
             } else {
                 "Allow this request and continue."
             };
@@ -305,6 +310,12 @@ impl McpServerElicitationFormRequest {
                     description: Some("Cancel this tool call".to_string()),
                     value: Value::String(APPROVAL_CANCEL_VALUE.to_string()),
                 });
+            } else if is_sampling_approval_action {
+                options.push(McpServerElicitationOption {
+                    label: "Deny".to_string(),
+                    description: Some("Refuse the model request and continue.".to_string()),
+                    value: Value::String(APPROVAL_DECLINE_VALUE.to_string()),
+                });
             } else {
                 options.extend([
                     McpServerElicitationOption {
@@ -1989,6 +2000,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn empty_sampling_approval_schema_uses_allow_and_deny() {
+        let thread_id = ThreadId::default();
+        let request = from_form_request(
+            thread_id,
+            form_request(
+                "Allow this request?",
+                empty_object_schema(),
+                Some(serde_json::json!({
+                    APPROVAL_META_KIND_KEY: APPROVAL_META_KIND_MCP_SAMPLING,
+                })),
+            ),
+        )
+        .expect("expected approval fallback");
+
+        assert_eq!(
+            request,
+            McpServerElicitationFormRequest {
+                thread_id,
+                server_name: "server-1".to_string(),
+                request_id: request_id("request-1"),
+                message: "Allow this request?".to_string(),
+                approval_display_params: Vec::new(),
+                response_mode: McpServerElicitationResponseMode::ApprovalAction,
+                fields: vec![McpServerElicitationField {
+                    id: APPROVAL_FIELD_ID.to_string(),
+                    label: String::new(),
+                    prompt: String::new(),
+                    required: true,
+                    input: McpServerElicitationFieldInput::Select {
+                        options: vec![
+                            McpServerElicitationOption {
+                                label: "Allow".to_string(),
+                                description: Some(
+                                    "Run the model request for the server and continue."
+                                        .to_string(),
+                                ),
+                                value: Value::String(APPROVAL_ACCEPT_ONCE_VALUE.to_string()),
+                            },
+                            McpServerElicitationOption {
+                                label: "Deny".to_string(),
+                                description: Some(
+                                    "Refuse the model request and continue.".to_string(),
+                                ),
+                                value: Value::String(APPROVAL_DECLINE_VALUE.to_string()),
+                            },
+                        ],
+                        default_idx: Some(0),
+                    },
+                }],
+                tool_suggestion: None,
+            }
+        );
+    }
+
     #[test]
     fn tool_suggestion_meta_is_parsed_into_request_payload() {
         let request = from_form_request(
@@ -2514,6 +2580,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn approval_form_sampling_approval_snapshot() {
+        let (tx, _rx) = test_sender();
+        let request = from_form_request(
+            ThreadId::default(),
+            form_request(
+                "MCP server `docs` wants to run a model request: Summarize the README.",
+                empty_object_schema(),
+                Some(serde_json::json!({
+                    APPROVAL_META_KIND_KEY: APPROVAL_META_KIND_MCP_SAMPLING,
+                })),
+            ),
+        )
+        .expect("expected approval fallback");
+        let overlay = McpServerElicitationOverlay::new(
+            request, tx, /*has_input_focus*/ true, /*enhanced_keys_supported*/ false,
+            /*disable_paste_burst*/ false,
+        );
+
+        insta::assert_snapshot!(
+            "mcp_server_elicitation_sampling_approval_form",
+            render_snapshot(&overlay, Rect::new(0, 0, 120, 16))
+        );
+    }
+
     #[test]
     fn message_only_form_snapshot() {
         let (tx, _rx) = test_sender();
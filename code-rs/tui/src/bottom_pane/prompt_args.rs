@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// Parse a first-line slash command of the form `/name <rest>`.
 /// Returns `(name, rest_after_name, rest_offset)` if the line begins with `/`
 /// and contains a non-empty name; otherwise returns `None`.
@@ -23,3 +25,49 @@ pub fn parse_slash_name(line: &str) -> Option<(&str, &str, usize)> {
     let rest_offset = rest_start_in_stripped + 1;
     Some((name, rest, rest_offset))
 }
+
+/// Parse MCP prompt arguments written as `key=value` pairs.
+///
+/// Values may be quoted with shell rules so they can contain spaces
+/// (`topic="release notes"`). Later pairs override earlier ones.
+pub(crate) fn parse_prompt_args(input: &str) -> Result<HashMap<String, String>, String> {
+    let tokens =
+        shlex::split(input).ok_or_else(|| "Unbalanced quotes in arguments.".to_string())?;
+    let mut args = HashMap::new();
+    for token in tokens {
+        let Some((key, value)) = token.split_once('=') else {
+            return Err(format!("Expected key=value, got '{token}'."));
+        };
+        if key.is_empty() {
+            return Err(format!("Missing argument name in '{token}'."));
+        }
+        args.insert(key.to_string(), value.to_string());
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_prompt_args_accepts_quoted_values() {
+        assert_eq!(
+            parse_prompt_args(r#"topic="release notes" style=terse"#),
+            Ok(HashMap::from([
+                ("topic".to_string(), "release notes".to_string()),
+                ("style".to_string(), "terse".to_string()),
+            ]))
+        );
+        assert_eq!(parse_prompt_args(""), Ok(HashMap::new()));
+    }
+
+    #[test]
+    fn parse_prompt_args_rejects_bare_words() {
+        assert_eq!(
+            parse_prompt_args("topic=notes terse"),
+            Err("Expected key=value, got 'terse'.".to_string())
+        );
+    }
+}
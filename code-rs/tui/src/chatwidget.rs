@@ -327,6 +327,7 @@ use self::ide_context::IdeContextState;
 mod interrupts;
 use self::interrupts::InterruptManager;
 mod keymap_picker;
+mod mcp_prompts;
 mod mcp_startup;
 use self::mcp_startup::McpStartupStatus;
 mod session_header;
@@ -5333,6 +5334,9 @@ impl ChatWidget {
                     InputResult::CommandWithArgs(cmd, args, text_elements) => {
                         self.handle_slash_command_with_args_dispatch(cmd, args, text_elements);
                     }
+                    InputResult::McpPrompt { prompt, args } => {
+                        self.handle_mcp_prompt_dispatch(prompt, args);
+                    }
                     InputResult::None => {}
                 }
                 if had_modal_or_popup && self.bottom_pane.no_modal_or_popup_active() {
@@ -6392,6 +6396,23 @@ impl ChatWidget {
             ServerNotification::McpServerStatusUpdated(notification) => {
                 self.on_mcp_server_status_updated(notification)
             }
+            ServerNotification::McpServerPromptListChanged(_) => {
+                if !from_replay {
+                    self.refresh_mcp_prompts();
+                }
+            }
+            ServerNotification::McpServerToolListChanged(notification) => {
+                if !from_replay {
+                    self.add_info_message(
+                        format!(
+                            "MCP server `{}` updated its tools ({} available).",
+                            notification.name,
+                            notification.tools.len()
+                        ),
+                        /*hint*/ None,
+                    );
+                }
+            }
             ServerNotification::ItemGuardianApprovalReviewStarted(notification) => {
                 self.on_guardian_review_notification(
                     notification.review_id,
@@ -6461,6 +6482,7 @@ impl ChatWidget {
             | ServerNotification::FileChangePatchUpdated(_)
             | ServerNotification::McpToolCallProgress(_)
             | ServerNotification::McpServerOauthLoginCompleted(_)
+            | ServerNotification::McpServerResourceListChanged(_)
             | ServerNotification::AppListUpdated(_)
             | ServerNotification::RemoteControlStatusChanged(_)
             | ServerNotification::ExternalAgentConfigImportCompleted(_)
@@ -0,0 +1,166 @@
+//! MCP prompts exposed as `/server:prompt` slash commands.
+//!
+//! The chat widget keeps the composer's prompt list in sync with the thread's
+//! MCP servers, collects prompt arguments, and submits the rendered prompt
+//! text as a regular user turn once the app server returns it.
+
+use std::collections::HashMap;
+
+use codex_app_server_protocol::McpServerPromptGetResponse;
+use codex_app_server_protocol::McpServerPrompts;
+use codex_protocol::mcp::PromptMessage;
+
+use super::*;
+use crate::bottom_pane::McpPromptCommand;
+use crate::bottom_pane::prompt_args::parse_prompt_args;
+
+impl ChatWidget {
+    /// Ask the app server for the current thread's MCP prompts.
+    pub(crate) fn refresh_mcp_prompts(&self) {
+        if let Some(thread_id) = self.thread_id {
+            self.app_event_tx
+                .send(AppEvent::FetchMcpPrompts { thread_id });
+        }
+    }
+
+    pub(crate) fn set_mcp_prompts(&mut self, data: Vec<McpServerPrompts>) {
+        let mcp_prompts = data
+            .into_iter()
+            .flat_map(|entry| {
+                let server = entry.server;
+                entry
+                    .prompts
+                    .into_iter()
+                    .map(move |prompt| McpPromptCommand {
+                        server: server.clone(),
+                        prompt,
+                    })
+            })
+            .collect();
+        self.bottom_pane.set_mcp_prompts(mcp_prompts);
+    }
+
+    /// Run an MCP prompt chosen from the composer.
+    ///
+    /// Prompts that take arguments open an input view when none were typed
+    /// inline; otherwise the inline `key=value` pairs are validated and sent.
+    pub(super) fn handle_mcp_prompt_dispatch(&mut self, prompt: McpPromptCommand, args: String) {
+        let Some(thread_id) = self.thread_id else {
+            self.add_error_message(format!(
+                "'/{}' is unavailable until the session starts.",
+                prompt.command_name()
+            ));
+            return;
+        };
+        let arguments = prompt.prompt.arguments.as_deref().unwrap_or_default();
+        if args.is_empty() && !arguments.is_empty() {
+            self.open_mcp_prompt_args_view(thread_id, prompt);
+            return;
+        }
+        match validate_prompt_args(&prompt, &args) {
+            Ok(arguments) => self.app_event_tx.send(AppEvent::RunMcpPrompt {
+                thread_id,
+                server: prompt.server,
+                name: prompt.prompt.name,
+                arguments,
+            }),
+            Err(err) => self.add_error_message(err),
+        }
+    }
+
+    fn open_mcp_prompt_args_view(&mut self, thread_id: ThreadId, prompt: McpPromptCommand) {
+        let tx = self.app_event_tx.clone();
+        let context_label = prompt
+            .prompt
+            .arguments
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|argument| {
+                if argument.required.unwrap_or(false) {
+                    format!("{}*", argument.name)
+                } else {
+                    argument.name.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let view = CustomPromptView::new(
+            format!("/{}", prompt.command_name()),
+            "key=value, quote values with spaces".to_string(),
+            /*initial_text*/ String::new(),
+            Some(format!("Arguments (* required): {context_label}")),
+            Box::new(
+                move |args: String| match validate_prompt_args(&prompt, args.trim()) {
+                    Ok(arguments) => tx.send(AppEvent::RunMcpPrompt {
+                        thread_id,
+                        server: prompt.server.clone(),
+                        name: prompt.prompt.name.clone(),
+                        arguments,
+                    }),
+                    Err(err) => tx.send(AppEvent::InsertHistoryCell(Box::new(
+                        history_cell::new_error_event(err),
+                    ))),
+                },
+            ),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    /// Submit the text of a rendered MCP prompt as the next user turn.
+    pub(crate) fn on_mcp_prompt_loaded(
+        &mut self,
+        command_name: String,
+        result: Result<McpServerPromptGetResponse, String>,
+    ) {
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                self.add_error_message(format!("Failed to run '/{command_name}': {err}"));
+                return;
+            }
+        };
+        let text = response
+            .messages
+            .iter()
+            .filter_map(PromptMessage::text)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        if text.is_empty() {
+            self.add_error_message(format!(
+                "'/{command_name}' did not return any text to send."
+            ));
+            return;
+        }
+        // Prompt text comes from the MCP server, so never treat it as a `!` shell escape.
+        let _ = self
+            .submit_user_message_with_shell_escape_policy(text.into(), ShellEscapePolicy::Disallow);
+    }
+}
+
+/// Parse `key=value` prompt arguments and check that required ones are present.
+fn validate_prompt_args(
+    prompt: &McpPromptCommand,
+    args: &str,
+) -> Result<HashMap<String, String>, String> {
+    let command_name = prompt.command_name();
+    let arguments = parse_prompt_args(args).map_err(|err| format!("'/{command_name}': {err}"))?;
+    let missing = prompt
+        .prompt
+        .arguments
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|argument| argument.required.unwrap_or(false))
+        .filter(|argument| !arguments.contains_key(&argument.name))
+        .map(|argument| argument.name.as_str())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(arguments)
+    } else {
+        Err(format!(
+            "'/{command_name}' is missing required arguments: {}.",
+            missing.join(", ")
+        ))
+    }
+}
@@ -240,7 +240,11 @@ impl ChatWidget {
     ) {
         let status = match notification.status {
             McpServerStartupState::Starting => McpStartupStatus::Starting,
-            McpServerStartupState::Ready => McpStartupStatus::Ready,
+            McpServerStartupState::Ready => {
+                // A newly ready server may offer prompts for the slash popup.
+                self.refresh_mcp_prompts();
+                McpStartupStatus::Ready
+            }
             McpServerStartupState::Failed => McpStartupStatus::Failed {
                 error: notification.error.unwrap_or_else(|| {
                     format!("MCP client for `{}` failed to start", notification.name)
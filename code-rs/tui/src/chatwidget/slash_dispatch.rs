@@ -440,6 +440,7 @@ impl ChatWidget {
                                 PathBuf::from("/tmp/test.txt"),
                                 FileChange::Add {
                                     content: "test".to_string(),
+                                    executable: None,
                                 },
                             ),
                             (
@@ -447,6 +448,7 @@ impl ChatWidget {
                                 FileChange::Update {
                                     unified_diff: "+test\n-test2".to_string(),
                                     move_path: None,
+                                    executable: None,
                                 },
                             ),
                         ]),
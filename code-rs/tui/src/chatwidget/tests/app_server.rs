@@ -335,7 +335,7 @@ async fn live_app_server_file_change_item_started_preserves_changes() {
                 id: "patch-1".to_string(),
                 changes: vec![FileUpdateChange {
                     path: "foo.txt".to_string(),
-                    kind: PatchChangeKind::Add,
+                    kind: PatchChangeKind::Add { executable: None },
                     diff: "hello\n".to_string(),
                 }],
                 status: AppServerPatchApplyStatus::InProgress,
@@ -924,10 +924,7 @@ async fn dynamic_tool_call_screenshot_adds_history_cell_with_image_marker() {
     let cells = drain_insert_history(&mut rx);
     assert_eq!(cells.len(), 1, "expected a single history cell");
     let combined = lines_to_single_string(&cells[0]);
-    assert_chatwidget_snapshot!(
-        "dynamic_tool_call_screenshot_history_snapshot",
-        combined
-    );
+    assert_chatwidget_snapshot!("dynamic_tool_call_screenshot_history_snapshot", combined);
 }
 
 #[tokio::test]
@@ -1321,6 +1318,7 @@ async fn approval_modal_patch_snapshot() -> anyhow::Result<()> {
         PathBuf::from("README.md"),
         FileChange::Add {
             content: "hello\nworld\n".into(),
+            executable: None,
         },
     );
     let ev = ApplyPatchApprovalRequestEvent {
@@ -1441,6 +1439,7 @@ async fn apply_patch_events_emit_history_cells() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     let ev = ApplyPatchApprovalRequestEvent {
@@ -1462,6 +1461,7 @@ async fn apply_patch_events_emit_history_cells() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_patch_apply_begin(&mut chat, "c1", "turn-c1", changes2);
@@ -1479,6 +1479,7 @@ async fn apply_patch_events_emit_history_cells() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_patch_apply_end(
@@ -1504,6 +1505,7 @@ async fn apply_patch_manual_approval_adjusts_header() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_apply_patch_approval_request(
@@ -1524,6 +1526,7 @@ async fn apply_patch_manual_approval_adjusts_header() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_patch_apply_begin(&mut chat, "c1", "turn-c1", apply_changes);
@@ -1546,6 +1549,7 @@ async fn apply_patch_manual_flow_snapshot() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_apply_patch_approval_request(
@@ -1570,6 +1574,7 @@ async fn apply_patch_manual_flow_snapshot() {
         PathBuf::from("foo.txt"),
         FileChange::Add {
             content: "hello\n".to_string(),
+            executable: None,
         },
     );
     handle_patch_apply_begin(&mut chat, "c1", "turn-c1", apply_changes);
@@ -1592,6 +1597,7 @@ async fn apply_patch_approval_sends_op_with_call_id() {
         PathBuf::from("file.rs"),
         FileChange::Add {
             content: "fn main(){}\n".into(),
+            executable: None,
         },
     );
     let ev = ApplyPatchApprovalRequestEvent {
@@ -1634,7 +1640,10 @@ async fn apply_patch_full_flow_integration_like() {
     let mut changes = HashMap::new();
     changes.insert(
         PathBuf::from("pkg.rs"),
-        FileChange::Add { content: "".into() },
+        FileChange::Add {
+            content: "".into(),
+            executable: None,
+        },
     );
     handle_apply_patch_approval_request(
         &mut chat,
@@ -1679,13 +1688,19 @@ async fn apply_patch_full_flow_integration_like() {
     let mut changes2 = HashMap::new();
     changes2.insert(
         PathBuf::from("pkg.rs"),
-        FileChange::Add { content: "".into() },
+        FileChange::Add {
+            content: "".into(),
+            executable: None,
+        },
     );
     handle_patch_apply_begin(&mut chat, "call-1", "turn-call-1", changes2);
     let mut end_changes = HashMap::new();
     end_changes.insert(
         PathBuf::from("pkg.rs"),
-        FileChange::Add { content: "".into() },
+        FileChange::Add {
+            content: "".into(),
+            executable: None,
+        },
     );
     handle_patch_apply_end(
         &mut chat,
@@ -1709,7 +1724,10 @@ async fn apply_patch_untrusted_shows_approval_modal() -> anyhow::Result<()> {
     let mut changes = HashMap::new();
     changes.insert(
         PathBuf::from("a.rs"),
-        FileChange::Add { content: "".into() },
+        FileChange::Add {
+            content: "".into(),
+            executable: None,
+        },
     );
     handle_apply_patch_approval_request(
         &mut chat,
@@ -1764,6 +1782,7 @@ async fn apply_patch_request_omits_diff_summary_from_modal() -> anyhow::Result<(
         FileChange::Add {
             // Two lines (no trailing empty line counted)
             content: "line one\nline two\n".into(),
+            executable: None,
         },
     );
     handle_apply_patch_approval_request(
@@ -744,12 +744,34 @@ fn file_update_changes_from_tui(changes: HashMap<PathBuf, FileChange>) -> Vec<Fi
         .into_iter()
         .map(|(path, change)| {
             let (kind, diff) = match change {
-                FileChange::Add { content } => (PatchChangeKind::Add, content),
+                FileChange::Add {
+                    content,
+                    executable,
+                } => (PatchChangeKind::Add { executable }, content),
                 FileChange::Delete { content } => (PatchChangeKind::Delete, content),
                 FileChange::Update {
                     unified_diff,
                     move_path,
-                } => (PatchChangeKind::Update { move_path }, unified_diff),
+                    executable,
+                } => (
+                    PatchChangeKind::Update {
+                        move_path,
+                        executable,
+                    },
+                    unified_diff,
+                ),
+                FileChange::SetMode { executable } => {
+                    (PatchChangeKind::SetMode { executable }, String::new())
+                }
+                FileChange::AddSymlink { target } => (
+                    PatchChangeKind::AddSymlink {
+                        target: target.clone(),
+                    },
+                    target.display().to_string(),
+                ),
+                FileChange::AddBinary { size } => {
+                    (PatchChangeKind::AddBinary { size }, String::new())
+                }
             };
             FileUpdateChange {
                 path: path.display().to_string(),
@@ -1747,5 +1769,9 @@ fn hook_event_label(event_name: codex_app_server_protocol::HookEventName) -> &'s
         codex_app_server_protocol::HookEventName::SessionStart => "SessionStart",
         codex_app_server_protocol::HookEventName::UserPromptSubmit => "UserPromptSubmit",
         codex_app_server_protocol::HookEventName::Stop => "Stop",
+        codex_app_server_protocol::HookEventName::SessionEnd => "SessionEnd",
+        codex_app_server_protocol::HookEventName::SubagentStart => "SubagentStart",
+        codex_app_server_protocol::HookEventName::SubagentStop => "SubagentStop",
+        codex_app_server_protocol::HookEventName::Notification => "Notification",
     }
 }
@@ -10,6 +10,9 @@ use serde::Serialize;
 pub(crate) enum FileChange {
     Add {
         content: String,
+        /// Set when the patch also changes the file's executable bit.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        executable: Option<bool>,
     },
     Delete {
         content: String,
@@ -17,5 +20,16 @@ pub(crate) enum FileChange {
     Update {
         unified_diff: String,
         move_path: Option<PathBuf>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        executable: Option<bool>,
+    },
+    SetMode {
+        executable: bool,
+    },
+    AddSymlink {
+        target: PathBuf,
+    },
+    AddBinary {
+        size: u64,
     },
 }
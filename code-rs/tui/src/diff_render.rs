@@ -1,11 +1,12 @@
 //! Renders unified diffs with line numbers, gutter signs, and optional syntax
 //! highlighting.
 //!
-//! Each `FileChange` variant (Add / Delete / Update) is rendered as a block of
-//! diff lines, each prefixed by a right-aligned line number, a gutter sign
-//! (`+` / `-` / ` `), and the content text.  When a recognized file extension
-//! is present, the content text is syntax-highlighted using
-//! [`crate::render::highlight`].
+//! Each textual `FileChange` variant (Add / Delete / Update) is rendered as a
+//! block of diff lines, each prefixed by a right-aligned line number, a gutter
+//! sign (`+` / `-` / ` `), and the content text.  Mode changes, symlinks, and
+//! binary files have no lines to show and get a one-line description instead.
+//! When a recognized file extension is present, the content text is
+//! syntax-highlighted using [`crate::render::highlight`].
 //!
 //! **Theme-aware styling:** diff backgrounds adapt to the terminal's
 //! background lightness via [`DiffTheme`].  Dark terminals get muted tints
@@ -367,9 +368,12 @@ fn collect_rows(changes: &HashMap<PathBuf, FileChange>) -> Vec<Row> {
     let mut rows: Vec<Row> = Vec::new();
     for (path, change) in changes.iter() {
         let (added, removed) = match change {
-            FileChange::Add { content } => (content.lines().count(), 0),
+            FileChange::Add { content, .. } => (content.lines().count(), 0),
             FileChange::Delete { content } => (0, content.lines().count()),
             FileChange::Update { unified_diff, .. } => calculate_add_remove_from_diff(unified_diff),
+            FileChange::SetMode { .. }
+            | FileChange::AddSymlink { .. }
+            | FileChange::AddBinary { .. } => (0, 0),
         };
         let move_path = match change {
             FileChange::Update {
@@ -420,7 +424,9 @@ fn render_changes_block(rows: Vec<Row>, wrap_cols: usize, cwd: &Path) -> Vec<RtL
     let mut header_spans: Vec<RtSpan<'static>> = vec!["• ".dim()];
     if let [row] = &rows[..] {
         let verb = match &row.change {
-            FileChange::Add { .. } => "Added",
+            FileChange::Add { .. }
+            | FileChange::AddSymlink { .. }
+            | FileChange::AddBinary { .. } => "Added",
             FileChange::Delete { .. } => "Deleted",
             _ => "Edited",
         };
@@ -479,8 +485,19 @@ fn render_change(
     lang: Option<&str>,
 ) {
     let style_context = current_diff_render_style_context();
+    if let FileChange::Add {
+        executable: Some(executable),
+        ..
+    }
+    | FileChange::Update {
+        executable: Some(executable),
+        ..
+    } = change
+    {
+        out.push(RtLine::from(mode_description(*executable).dim()));
+    }
     match change {
-        FileChange::Add { content } => {
+        FileChange::Add { content, .. } => {
             // Pre-highlight the entire file content as a whole.
             let syntax_lines = lang.and_then(|l| highlight_code_to_styled_spans(content, l));
             let line_number_width = line_number_width(content.lines().count());
@@ -733,6 +750,25 @@ fn render_change(
                 }
             }
         }
+        FileChange::SetMode { executable } => {
+            out.push(RtLine::from(mode_description(*executable).dim()));
+        }
+        FileChange::AddSymlink { target } => {
+            out.push(RtLine::from(
+                format!("symlink → {}", target.display()).dim(),
+            ));
+        }
+        FileChange::AddBinary { size } => {
+            out.push(RtLine::from(format!("binary file, {size} bytes").dim()));
+        }
+    }
+}
+
+fn mode_description(executable: bool) -> &'static str {
+    if executable {
+        "mode set to 100755 (executable)"
+    } else {
+        "mode set to 100644 (not executable)"
     }
 }
 
@@ -1414,6 +1450,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: rust_patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1425,6 +1462,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: py_patch,
                 move_path: Some(PathBuf::from("scripts/calc.py")),
+                executable: None,
             },
         );
 
@@ -1432,6 +1470,7 @@ mod tests {
             PathBuf::from("assets/banner.txt"),
             FileChange::Add {
                 content: "HEADER\tVALUE\nrocket\t🚀\ncity\t東京\n".to_string(),
+                executable: None,
             },
         );
         changes.insert(
@@ -1439,6 +1478,7 @@ mod tests {
             FileChange::Add {
                 content: "pub fn greet(name: &str) {\n    println!(\"Hello, {name}!\");\n}\n"
                     .to_string(),
+                executable: None,
             },
         );
 
@@ -1523,6 +1563,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1548,6 +1589,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: Some(PathBuf::from("new_name.rs")),
+                executable: None,
             },
         );
 
@@ -1573,6 +1615,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch_a,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1581,6 +1624,7 @@ mod tests {
             PathBuf::from("b.txt"),
             FileChange::Add {
                 content: "new\n".to_string(),
+                executable: None,
             },
         );
 
@@ -1601,6 +1645,7 @@ mod tests {
             PathBuf::from("new_file.txt"),
             FileChange::Add {
                 content: "alpha\nbeta\n".to_string(),
+                executable: None,
             },
         );
 
@@ -1646,6 +1691,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1674,6 +1720,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1681,6 +1728,26 @@ mod tests {
         snapshot_lines_text("apply_update_block_wraps_long_lines_text", &lines);
     }
 
+    #[test]
+    fn ui_snapshot_apply_mode_symlink_and_binary_text() {
+        let changes: HashMap<PathBuf, FileChange> = HashMap::from([
+            (
+                PathBuf::from("bin/run.sh"),
+                FileChange::SetMode { executable: true },
+            ),
+            (PathBuf::from("blob.bin"), FileChange::AddBinary { size: 4 }),
+            (
+                PathBuf::from("latest"),
+                FileChange::AddSymlink {
+                    target: PathBuf::from("releases/v2"),
+                },
+            ),
+        ]);
+
+        let lines = create_diff_summary(&changes, &PathBuf::from("/"), /*wrap_cols*/ 80);
+        snapshot_lines_text("apply_mode_symlink_and_binary_text", &lines);
+    }
+
     #[test]
     fn ui_snapshot_apply_update_block_line_numbers_three_digits_text() {
         let original = (1..=110).map(|i| format!("line {i}\n")).collect::<String>();
@@ -1701,6 +1768,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -1724,6 +1792,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: Some(abs_new),
+                executable: None,
             },
         );
 
@@ -2185,6 +2254,7 @@ mod tests {
             PathBuf::from("highlight_add.rs"),
             FileChange::Add {
                 content: "pub fn sum(a: i32, b: i32) -> i32 { a + b }\n".to_string(),
+                executable: None,
             },
         );
 
@@ -2380,6 +2450,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -2426,6 +2497,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: Some(PathBuf::from("foo.rs")),
+                executable: None,
             },
         );
 
@@ -2453,6 +2525,7 @@ mod tests {
             FileChange::Update {
                 unified_diff: patch,
                 move_path: None,
+                executable: None,
             },
         );
 
@@ -4389,6 +4389,7 @@ mod tests {
             )]),
             resources: Vec::new(),
             resource_templates: Vec::new(),
+            prompts: Vec::new(),
             auth_status: codex_app_server_protocol::McpAuthStatus::Unsupported,
         }];
 
@@ -4447,6 +4448,7 @@ mod tests {
                 description: None,
                 mime_type: None,
             }],
+            prompts: Vec::new(),
             auth_status: codex_app_server_protocol::McpAuthStatus::Unsupported,
         }];
 
@@ -560,6 +560,7 @@ async fn lookup_session_target_by_name_with_app_server(
                 cwd: None,
                 use_state_db_only: false,
                 search_term: Some(name.to_string()),
+                search_content: false,
             })
             .await?;
         if let Some(thread) = response
@@ -653,6 +654,7 @@ fn latest_session_lookup_params(
         cwd: cwd_filter.map(|cwd| ThreadListCwdFilter::One(cwd.to_string_lossy().to_string())),
         use_state_db_only: false,
         search_term: None,
+        search_content: false,
     }
 }
 
@@ -31,6 +31,7 @@ use codex_app_server_protocol::Thread;
 use codex_app_server_protocol::ThreadItem;
 use codex_app_server_protocol::ThreadListCwdFilter;
 use codex_app_server_protocol::ThreadListParams;
+use codex_app_server_protocol::ThreadSearchMatch;
 use codex_app_server_protocol::ThreadSortKey;
 use codex_app_server_protocol::ThreadSourceKind;
 use codex_config::types::SessionPickerViewMode;
@@ -57,7 +58,7 @@ use tokio_stream::StreamExt;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::warn;
 use transcript::RawReasoningVisibility;
-use transcript::TranscriptCells;
+use transcript::SessionTranscript;
 use transcript::load_session_transcript;
 use unicode_width::UnicodeWidthStr;
 
@@ -145,8 +146,17 @@ struct PageLoadRequest {
     sort_key: ThreadSortKey,
 }
 
+#[derive(Clone)]
+struct ContentSearchRequest {
+    query: String,
+    cwd_filter: Option<PathBuf>,
+    provider_filter: ProviderFilter,
+    sort_key: ThreadSortKey,
+}
+
 enum PickerLoadRequest {
     Page(PageLoadRequest),
+    ContentSearch(ContentSearchRequest),
     Preview { thread_id: ThreadId },
     Transcript { thread_id: ThreadId },
 }
@@ -249,7 +259,11 @@ enum BackgroundEvent {
     },
     Transcript {
         thread_id: ThreadId,
-        transcript: std::io::Result<TranscriptCells>,
+        transcript: std::io::Result<SessionTranscript>,
+    },
+    ContentMatches {
+        query: String,
+        matches: std::io::Result<Vec<(Row, ThreadSearchMatch)>>,
     },
 }
 
@@ -298,7 +312,8 @@ struct SessionPickerRunOptions {
 ///
 /// Filtering happens in two layers:
 /// 1. Provider, source, and eligible working-directory filtering at the backend.
-/// 2. Typed search filtering over loaded rows in the picker.
+/// 2. Typed search filtering over loaded rows in the picker, merged with
+///    full-text hits from the backend's thread content index.
 pub async fn run_resume_picker_with_app_server(
     tui: &mut Tui,
     config: &Config,
@@ -565,6 +580,16 @@ fn spawn_app_server_page_loader(
                         page,
                     });
                 }
+                PickerLoadRequest::ContentSearch(request) => {
+                    let query = request.query.clone();
+                    let matches = load_app_server_content_matches(
+                        &mut app_server,
+                        request,
+                        include_non_interactive,
+                    )
+                    .await;
+                    let _ = bg_tx.send(BackgroundEvent::ContentMatches { query, matches });
+                }
                 PickerLoadRequest::Preview { thread_id } => {
                     let preview = load_transcript_preview(&mut app_server, thread_id).await;
                     let _ = bg_tx.send(BackgroundEvent::Preview { thread_id, preview });
@@ -632,6 +657,10 @@ struct PickerState {
     frozen_footer_percent: Option<u8>,
     query: String,
     search_state: SearchState,
+    /// Rows found only by content search for the current query.
+    content_rows: Vec<Row>,
+    /// Content-search hits for the current query, keyed by thread.
+    content_matches: HashMap<ThreadId, ThreadSearchMatch>,
     next_request_token: usize,
     next_search_token: usize,
     picker_loader: PickerLoader,
@@ -691,7 +720,7 @@ enum TranscriptPreviewState {
 
 enum SessionTranscriptState {
     Loading,
-    Loaded(TranscriptCells),
+    Loaded(SessionTranscript),
     Failed,
 }
 
@@ -750,6 +779,36 @@ async fn load_app_server_page(
     })
 }
 
+async fn load_app_server_content_matches(
+    app_server: &mut AppServerSession,
+    request: ContentSearchRequest,
+    include_non_interactive: bool,
+) -> std::io::Result<Vec<(Row, ThreadSearchMatch)>> {
+    let response = app_server
+        .thread_list(content_search_params(
+            request.query,
+            request.cwd_filter.as_deref(),
+            request.provider_filter,
+            request.sort_key,
+            include_non_interactive,
+        ))
+        .await
+        .map_err(std::io::Error::other)?;
+    let mut search_matches = response
+        .search_matches
+        .into_iter()
+        .map(|search_match| (search_match.thread_id.clone(), search_match))
+        .collect::<HashMap<_, _>>();
+    Ok(response
+        .data
+        .into_iter()
+        .filter_map(|thread| {
+            let search_match = search_matches.remove(&thread.id)?;
+            Some((row_from_app_server_thread(thread)?, search_match))
+        })
+        .collect())
+}
+
 async fn load_transcript_preview(
     app_server: &mut AppServerSession,
     thread_id: ThreadId,
@@ -904,6 +963,8 @@ impl PickerState {
             frozen_footer_percent: None,
             query: String::new(),
             search_state: SearchState::Idle,
+            content_rows: Vec::new(),
+            content_matches: HashMap::new(),
             next_request_token: 0,
             next_search_token: 0,
             picker_loader,
@@ -954,14 +1015,25 @@ impl PickerState {
         let Some(thread_id) = self.pending_transcript_open else {
             return;
         };
-        let Some(SessionTranscriptState::Loaded(cells)) = self.transcript_cells.get(&thread_id)
+        let Some(SessionTranscriptState::Loaded(transcript)) =
+            self.transcript_cells.get(&thread_id)
         else {
             return;
         };
-        self.overlay = Some(Overlay::new_transcript(
-            cells.clone(),
-            self.pager_keymap.clone(),
-        ));
+        let mut overlay =
+            Overlay::new_transcript(transcript.cells.clone(), self.pager_keymap.clone());
+        let hit_cell = self
+            .content_matches
+            .get(&thread_id)
+            .and_then(|search_match| search_match.turn_id.as_ref())
+            .and_then(|turn_id| transcript.turn_start_cells.get(turn_id))
+            .copied();
+        if let Some(cell) = hit_cell
+            && let Overlay::Transcript(transcript_overlay) = &mut overlay
+        {
+            transcript_overlay.set_highlight_cell(Some(cell));
+        }
+        self.overlay = Some(overlay);
         self.pending_transcript_open = None;
         self.transcript_loading_frame_shown = false;
         self.request_frame();
@@ -1277,6 +1349,7 @@ impl PickerState {
             self.search_state = SearchState::Active { token };
             Some(token)
         };
+        self.start_content_search();
 
         let request_token = self.allocate_request_token();
         self.pagination.loading = LoadingState::Pending(PendingLoad {
@@ -1330,10 +1403,10 @@ impl PickerState {
                 thread_id,
                 transcript,
             } => match transcript {
-                Ok(cells) => {
+                Ok(transcript) => {
                     let should_open = self.pending_transcript_open == Some(thread_id);
                     self.transcript_cells
-                        .insert(thread_id, SessionTranscriptState::Loaded(cells.clone()));
+                        .insert(thread_id, SessionTranscriptState::Loaded(transcript));
                     if should_open {
                         self.open_pending_transcript_if_ready();
                     }
@@ -1350,6 +1423,17 @@ impl PickerState {
                     self.request_frame();
                 }
             },
+            BackgroundEvent::ContentMatches { query, matches } => {
+                if query != self.query {
+                    return Ok(());
+                }
+                match matches {
+                    Ok(matches) => self.ingest_content_matches(matches),
+                    // Content search needs the backend's state DB; keep the
+                    // local filter results when it is unavailable.
+                    Err(err) => warn!(%err, "Session content search failed"),
+                }
+            }
         }
         Ok(())
     }
@@ -1389,6 +1473,38 @@ impl PickerState {
         self.apply_filter();
     }
 
+    fn start_content_search(&mut self) {
+        self.content_rows.clear();
+        self.content_matches.clear();
+        if self.query.trim().is_empty() {
+            return;
+        }
+        (self.picker_loader)(PickerLoadRequest::ContentSearch(ContentSearchRequest {
+            query: self.query.clone(),
+            cwd_filter: self.active_cwd_filter(),
+            provider_filter: self.provider_filter.clone(),
+            sort_key: self.sort_key,
+        }));
+    }
+
+    fn ingest_content_matches(&mut self, matches: Vec<(Row, ThreadSearchMatch)>) {
+        for (row, search_match) in matches {
+            let Some(thread_id) = row.thread_id else {
+                continue;
+            };
+            if !self
+                .all_rows
+                .iter()
+                .any(|loaded| loaded.thread_id == Some(thread_id))
+            {
+                self.content_rows.push(row);
+            }
+            self.content_matches.insert(thread_id, search_match);
+        }
+        self.apply_filter();
+        self.continue_search_if_needed();
+    }
+
     fn complete_pending_page_down(&mut self) {
         let Some(target) = self.pending_page_down_target else {
             return;
@@ -1419,7 +1535,23 @@ impl PickerState {
             self.filtered_rows = base_iter.cloned().collect();
         } else {
             let q = self.query.to_lowercase();
-            self.filtered_rows = base_iter.filter(|r| r.matches_query(&q)).cloned().collect();
+            self.filtered_rows = base_iter
+                .filter(|r| r.matches_query(&q) || self.content_match(r).is_some())
+                .cloned()
+                .collect();
+            let seen = self
+                .filtered_rows
+                .iter()
+                .filter_map(Row::seen_key)
+                .collect::<HashSet<_>>();
+            let content_rows = self
+                .content_rows
+                .iter()
+                .filter(|row| self.row_matches_filter(row))
+                .filter(|row| row.seen_key().is_none_or(|key| !seen.contains(&key)))
+                .cloned()
+                .collect::<Vec<_>>();
+            self.filtered_rows.extend(content_rows);
         }
         if self.selected >= self.filtered_rows.len() {
             self.selected = self.filtered_rows.len().saturating_sub(1);
@@ -1431,6 +1563,10 @@ impl PickerState {
         self.request_frame();
     }
 
+    fn content_match(&self, row: &Row) -> Option<&ThreadSearchMatch> {
+        self.content_matches.get(&row.thread_id?)
+    }
+
     fn row_matches_filter(&self, row: &Row) -> bool {
         if self.filter_mode == SessionFilterMode::All {
             return true;
@@ -1450,6 +1586,7 @@ impl PickerState {
         }
         self.query = new_query;
         self.selected = 0;
+        self.start_content_search();
         self.apply_filter();
         if self.query.is_empty() {
             self.search_state = SearchState::Idle;
@@ -1475,6 +1612,7 @@ impl PickerState {
             .and_then(Row::seen_key);
         self.query.clear();
         self.search_state = SearchState::Idle;
+        self.start_content_search();
         self.apply_filter();
         if let Some(selected_key) = selected_key
             && let Some(index) = self
@@ -1842,6 +1980,27 @@ fn thread_list_params(
         cwd: cwd_filter.map(|cwd| ThreadListCwdFilter::One(cwd.to_string_lossy().into_owned())),
         use_state_db_only: false,
         search_term: None,
+        search_content: false,
+    }
+}
+
+fn content_search_params(
+    query: String,
+    cwd_filter: Option<&Path>,
+    provider_filter: ProviderFilter,
+    sort_key: ThreadSortKey,
+    include_non_interactive: bool,
+) -> ThreadListParams {
+    ThreadListParams {
+        search_term: Some(query),
+        search_content: true,
+        ..thread_list_params(
+            /*cursor*/ None,
+            cwd_filter,
+            provider_filter,
+            sort_key,
+            include_non_interactive,
+        )
     }
 }
 
@@ -2553,6 +2712,7 @@ fn render_comfortable_session_lines(
     };
     let title_line = Line::from(vec![marker, title]);
     let mut lines = vec![title_line];
+    lines.extend(search_match_line(row, state, width));
     let row_style = if is_selected {
         Some(dense_selected_style())
     } else if is_zebra {
@@ -2593,6 +2753,18 @@ fn render_comfortable_session_lines(
     lines
 }
 
+/// Renders the content-search snippet that made `row` match the query.
+fn search_match_line(row: &Row, state: &PickerState, width: u16) -> Option<Line<'static>> {
+    let search_match = state.content_match(row)?;
+    let snippet = search_match
+        .snippet
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let snippet = truncate_text(&snippet, width.saturating_sub(4) as usize);
+    Some(vec!["  ".into(), "↳ ".dim(), snippet.italic().dim()].into())
+}
+
 fn apply_session_row_background(
     lines: Vec<Line<'static>>,
     style: Style,
@@ -4359,13 +4531,15 @@ mod tests {
             SessionPickerAction::Resume,
         );
         state.pending_transcript_open = Some(thread_id);
-        let cells: TranscriptCells =
-            vec![Arc::new(PlainHistoryCell::new(vec!["transcript".into()]))];
+        let transcript = SessionTranscript {
+            cells: vec![Arc::new(PlainHistoryCell::new(vec!["transcript".into()]))],
+            turn_start_cells: HashMap::new(),
+        };
 
         state
             .handle_background_event(BackgroundEvent::Transcript {
                 thread_id,
-                transcript: Ok(cells),
+                transcript: Ok(transcript),
             })
             .await
             .unwrap();
@@ -4410,9 +4584,10 @@ mod tests {
         }];
         state.transcript_cells.insert(
             thread_id,
-            SessionTranscriptState::Loaded(vec![Arc::new(PlainHistoryCell::new(vec![
-                "transcript".into(),
-            ]))]),
+            SessionTranscriptState::Loaded(SessionTranscript {
+                cells: vec![Arc::new(PlainHistoryCell::new(vec!["transcript".into()]))],
+                turn_start_cells: HashMap::new(),
+            }),
         );
 
         state
@@ -5712,8 +5887,8 @@ session_picker_view = "dense"
     }
 
     #[test]
-    fn thread_to_transcript_cells_renders_core_message_types() {
-        use transcript::thread_to_transcript_cells;
+    fn thread_to_session_transcript_renders_core_message_types() {
+        use transcript::thread_to_session_transcript;
 
         let thread_id = ThreadId::new();
         let thread = Thread {
@@ -5765,7 +5940,13 @@ session_picker_view = "dense"
             }],
         };
 
-        let rendered = thread_to_transcript_cells(&thread, RawReasoningVisibility::Visible)
+        let transcript = thread_to_session_transcript(&thread, RawReasoningVisibility::Visible);
+        assert_eq!(
+            transcript.turn_start_cells,
+            HashMap::from([(String::from("turn-1"), 0)])
+        );
+        let rendered = transcript
+            .cells
             .into_iter()
             .flat_map(|cell| cell.transcript_lines(/*width*/ 80))
             .map(|line| line.to_string())
@@ -5779,8 +5960,8 @@ session_picker_view = "dense"
     }
 
     #[test]
-    fn thread_to_transcript_cells_hides_raw_reasoning_when_not_enabled() {
-        use transcript::thread_to_transcript_cells;
+    fn thread_to_session_transcript_hides_raw_reasoning_when_not_enabled() {
+        use transcript::thread_to_session_transcript;
 
         let thread_id = ThreadId::new();
         let thread = Thread {
@@ -5818,13 +5999,15 @@ session_picker_view = "dense"
             }],
         };
 
-        let hidden = thread_to_transcript_cells(&thread, RawReasoningVisibility::Hidden)
+        let hidden = thread_to_session_transcript(&thread, RawReasoningVisibility::Hidden)
+            .cells
             .into_iter()
             .flat_map(|cell| cell.transcript_lines(/*width*/ 80))
             .map(|line| line.to_string())
             .collect::<Vec<_>>()
             .join("\n");
-        let visible = thread_to_transcript_cells(&thread, RawReasoningVisibility::Visible)
+        let visible = thread_to_session_transcript(&thread, RawReasoningVisibility::Visible)
+            .cells
             .into_iter()
             .flat_map(|cell| cell.transcript_lines(/*width*/ 80))
             .map(|line| line.to_string())
@@ -5836,8 +6019,8 @@ session_picker_view = "dense"
     }
 
     #[test]
-    fn thread_to_transcript_cells_shows_raw_reasoning_over_summary_when_enabled() {
-        use transcript::thread_to_transcript_cells;
+    fn thread_to_session_transcript_shows_raw_reasoning_over_summary_when_enabled() {
+        use transcript::thread_to_session_transcript;
 
         let thread_id = ThreadId::new();
         let thread = Thread {
@@ -5875,7 +6058,8 @@ session_picker_view = "dense"
             }],
         };
 
-        let rendered = thread_to_transcript_cells(&thread, RawReasoningVisibility::Visible)
+        let rendered = thread_to_session_transcript(&thread, RawReasoningVisibility::Visible)
+            .cells
             .into_iter()
             .flat_map(|cell| cell.transcript_lines(/*width*/ 80))
             .map(|line| line.to_string())
@@ -6044,6 +6228,89 @@ session_picker_view = "dense"
         assert!(state.has_more_below(/*viewport_height*/ 5));
     }
 
+    #[tokio::test]
+    async fn content_search_hits_are_listed_with_their_snippet() {
+        let recorded_queries: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let query_sink = recorded_queries.clone();
+        let loader: PickerLoader = Arc::new(move |request| {
+            if let PickerLoadRequest::ContentSearch(request) = request {
+                query_sink.lock().unwrap().push(request.query);
+            }
+        });
+        let mut state = PickerState::new(
+            FrameRequester::test_dummy(),
+            loader,
+            ProviderFilter::MatchDefault(String::from("openai")),
+            /*show_all*/ true,
+            /*filter_cwd*/ None,
+            SessionPickerAction::Resume,
+        );
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![make_row(
+                "/tmp/start.jsonl",
+                "2025-01-01T00:00:00Z",
+                "alpha",
+            )],
+            /*next_cursor*/ None,
+            /*num_scanned_files*/ 1,
+            /*reached_scan_cap*/ false,
+        ));
+
+        state.set_query("linking".to_string());
+        assert_eq!(*recorded_queries.lock().unwrap(), vec!["linking"]);
+        assert!(state.filtered_rows.is_empty());
+
+        let thread_id = ThreadId::new();
+        let mut hit_row = make_row("/tmp/hit.jsonl", "2025-01-02T00:00:00Z", "fix the build");
+        hit_row.thread_id = Some(thread_id);
+        let search_match = ThreadSearchMatch {
+            thread_id: thread_id.to_string(),
+            turn_id: Some(String::from("turn-2")),
+            snippet: String::from("error: linking with `cc`\n failed"),
+        };
+        state
+            .handle_background_event(BackgroundEvent::ContentMatches {
+                query: String::from("link"),
+                matches: Ok(vec![(hit_row.clone(), search_match.clone())]),
+            })
+            .await
+            .unwrap();
+        assert!(state.filtered_rows.is_empty());
+
+        state
+            .handle_background_event(BackgroundEvent::ContentMatches {
+                query: String::from("linking"),
+                matches: Ok(vec![(hit_row, search_match.clone())]),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(state.filtered_rows.len(), 1);
+        assert_eq!(state.filtered_rows[0].thread_id, Some(thread_id));
+        assert_eq!(
+            state.content_match(&state.filtered_rows[0]),
+            Some(&search_match)
+        );
+        let rendered = render_session_lines(
+            &state.filtered_rows[0],
+            &state,
+            /*is_selected*/ false,
+            /*is_expanded*/ false,
+            /*is_zebra*/ false,
+            /*width*/ 60,
+        )
+        .into_iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+        assert!(rendered.contains(&String::from("  ↳ error: linking with `cc` failed")));
+
+        state.clear_query_preserving_selection();
+        assert!(state.content_matches.is_empty());
+        assert_eq!(state.filtered_rows.len(), 1);
+        assert_eq!(state.filtered_rows[0].preview, "alpha");
+    }
+
     #[tokio::test]
     async fn set_query_loads_until_match_and_respects_scan_cap() {
         let recorded_requests: Arc<Mutex<Vec<PageLoadRequest>>> = Arc::new(Mutex::new(Vec::new()));
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::app_server_session::AppServerSession;
@@ -15,6 +17,14 @@ use ratatui::text::Line;
 
 pub(crate) type TranscriptCells = Vec<Arc<dyn HistoryCell>>;
 
+/// Transcript cells for a session plus the index of the first cell rendered
+/// for each turn, so the picker can scroll straight to a search hit.
+#[derive(Clone, Default)]
+pub(crate) struct SessionTranscript {
+    pub(crate) cells: TranscriptCells,
+    pub(crate) turn_start_cells: HashMap<String, usize>,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum RawReasoningVisibility {
     Hidden,
@@ -25,86 +35,108 @@ pub(crate) async fn load_session_transcript(
     app_server: &mut AppServerSession,
     thread_id: ThreadId,
     raw_reasoning_visibility: RawReasoningVisibility,
-) -> std::io::Result<TranscriptCells> {
+) -> std::io::Result<SessionTranscript> {
     let thread = app_server
         .thread_read(thread_id, /*include_turns*/ true)
         .await
         .map_err(std::io::Error::other)?;
-    Ok(thread_to_transcript_cells(
+    Ok(thread_to_session_transcript(
         &thread,
         raw_reasoning_visibility,
     ))
 }
 
-pub(crate) fn thread_to_transcript_cells(
+pub(crate) fn thread_to_session_transcript(
     thread: &Thread,
     raw_reasoning_visibility: RawReasoningVisibility,
-) -> TranscriptCells {
+) -> SessionTranscript {
     let cwd = thread.cwd.as_path();
     let mut cells: TranscriptCells = Vec::new();
-    for item in thread.turns.iter().flat_map(|turn| turn.items.iter()) {
-        match item {
-            ThreadItem::UserMessage { id, content } => {
-                let item = UserMessageItem {
-                    id: id.clone(),
-                    content: content
-                        .iter()
-                        .cloned()
-                        .map(codex_app_server_protocol::UserInput::into_core)
-                        .collect(),
-                };
-                cells.push(Arc::new(UserHistoryCell {
-                    message: item.message(),
-                    text_elements: item.text_elements(),
-                    local_image_paths: item.local_image_paths(),
-                    remote_image_urls: item.image_urls(),
-                }));
-            }
-            ThreadItem::AgentMessage { text, .. } => {
-                if !text.trim().is_empty() {
-                    cells.push(Arc::new(AgentMarkdownCell::new(text.clone(), cwd)));
-                }
-            }
-            ThreadItem::Plan { text, .. } => {
-                if !text.trim().is_empty() {
-                    cells.push(Arc::new(crate::history_cell::new_proposed_plan(
-                        text.clone(),
-                        cwd,
-                    )));
-                }
-            }
-            ThreadItem::Reasoning {
-                summary, content, ..
-            } => {
-                let text = if matches!(raw_reasoning_visibility, RawReasoningVisibility::Visible)
-                    && !content.is_empty()
-                {
-                    content.join("\n\n")
-                } else {
-                    summary.join("\n\n")
-                };
-                if !text.trim().is_empty() {
-                    cells.push(Arc::new(ReasoningSummaryCell::new(
-                        "Reasoning".to_string(),
-                        text,
-                        cwd,
-                        /*transcript_only*/ false,
-                    )));
-                }
-            }
-            other => {
-                if let Some(cell) = fallback_transcript_cell(other) {
-                    cells.push(Arc::new(cell));
-                }
-            }
+    let mut turn_start_cells = HashMap::new();
+    for turn in &thread.turns {
+        turn_start_cells.insert(turn.id.clone(), cells.len());
+        for item in &turn.items {
+            push_transcript_item_cells(&mut cells, item, cwd, raw_reasoning_visibility);
         }
     }
+    // Turns that rendered nothing point at the next turn's first cell; clamp
+    // so trailing empty turns still land on a real cell.
+    let last_cell = cells.len().saturating_sub(1);
+    for start in turn_start_cells.values_mut() {
+        *start = (*start).min(last_cell);
+    }
     if cells.is_empty() {
         cells.push(Arc::new(PlainHistoryCell::new(vec![
             "No transcript content available".italic().dim().into(),
         ])));
     }
-    cells
+    SessionTranscript {
+        cells,
+        turn_start_cells,
+    }
+}
+
+fn push_transcript_item_cells(
+    cells: &mut TranscriptCells,
+    item: &ThreadItem,
+    cwd: &Path,
+    raw_reasoning_visibility: RawReasoningVisibility,
+) {
+    match item {
+        ThreadItem::UserMessage { id, content } => {
+            let item = UserMessageItem {
+                id: id.clone(),
+                content: content
+                    .iter()
+                    .cloned()
+                    .map(codex_app_server_protocol::UserInput::into_core)
+                    .collect(),
+            };
+            cells.push(Arc::new(UserHistoryCell {
+                message: item.message(),
+                text_elements: item.text_elements(),
+                local_image_paths: item.local_image_paths(),
+                remote_image_urls: item.image_urls(),
+            }));
+        }
+        ThreadItem::AgentMessage { text, .. } => {
+            if !text.trim().is_empty() {
+                cells.push(Arc::new(AgentMarkdownCell::new(text.clone(), cwd)));
+            }
+        }
+        ThreadItem::Plan { text, .. } => {
+            if !text.trim().is_empty() {
+                cells.push(Arc::new(crate::history_cell::new_proposed_plan(
+                    text.clone(),
+                    cwd,
+                )));
+            }
+        }
+        ThreadItem::Reasoning {
+            summary, content, ..
+        } => {
+            let text = if matches!(raw_reasoning_visibility, RawReasoningVisibility::Visible)
+                && !content.is_empty()
+            {
+                content.join("\n\n")
+            } else {
+                summary.join("\n\n")
+            };
+            if !text.trim().is_empty() {
+                cells.push(Arc::new(ReasoningSummaryCell::new(
+                    "Reasoning".to_string(),
+                    text,
+                    cwd,
+                    /*transcript_only*/ false,
+                )));
+            }
+        }
+        other => {
+            if let Some(cell) = fallback_transcript_cell(other) {
+                cells.push(Arc::new(cell));
+            }
+        }
+    }
 }
 
 fn fallback_transcript_cell(item: &ThreadItem) -> Option<PlainHistoryCell> {